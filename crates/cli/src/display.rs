@@ -0,0 +1,424 @@
+//! Human-readable output for accounts and events.
+
+use cryptoscore_client::CryptoscoreEvent;
+use cryptoscore_common::OddsSample;
+use cryptoscore_factory_interface::{Factory, LiveScore};
+use cryptoscore_governance_interface::{Dispute, Proposal};
+use cryptoscore_market_interface::{Participant, SettlementReport};
+use cryptoscore_sdk::{PointsSummary, ReputationSummary, WinnerProof};
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+
+pub fn sol(lamports: u64) -> String {
+    format!("{} lamports ({} SOL)", lamports, lamports_to_sol(lamports))
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn event(event: &CryptoscoreEvent) -> String {
+    match event {
+        CryptoscoreEvent::MarketCreated(e) => format!(
+            "MarketCreated market={} creator={} match_id={} entry_fee={} kickoff_time={} public={}",
+            e.market, e.creator, e.match_id, sol(e.entry_fee), e.kickoff_time, e.is_public
+        ),
+        CryptoscoreEvent::PredictionMade(e) => format!(
+            "PredictionMade market={} user={} prediction={:?} timestamp={} entry_index={}",
+            e.market, e.user, e.prediction, e.timestamp, e.entry_index
+        ),
+        CryptoscoreEvent::MarketResolved(e) => format!(
+            "MarketResolved market={} outcome={:?} winners={} total_pool={}",
+            e.market, e.outcome, e.winner_count, sol(e.total_pool)
+        ),
+        CryptoscoreEvent::ResultAttested(e) => format!(
+            "ResultAttested market={} signer={} outcome={:?} attested_at={}",
+            e.market, e.signer, e.outcome, e.attested_at
+        ),
+        CryptoscoreEvent::ResultBridged(e) => format!(
+            "ResultBridged market={} emitter_chain={} sequence={} outcome={:?} attested_at={}",
+            e.market, e.emitter_chain, e.sequence, e.outcome, e.attested_at
+        ),
+        CryptoscoreEvent::ResultCorrected(e) => format!(
+            "ResultCorrected market={} signer={} score={}-{} resolved={:?} outcome={:?} re_resolved={}",
+            e.market, e.signer, e.home_goals, e.away_goals, e.resolved_outcome, e.outcome, e.re_resolved
+        ),
+        CryptoscoreEvent::RewardClaimed(e) => format!(
+            "RewardClaimed market={} user={} amount={}",
+            e.market, e.user, sol(e.amount)
+        ),
+        CryptoscoreEvent::RewardRolled(e) => format!(
+            "RewardRolled market={} follow_up={} user={} reward={} entry_fee={} streak={}",
+            e.market, e.follow_up, e.user, sol(e.reward), sol(e.entry_fee), e.streak
+        ),
+        CryptoscoreEvent::RewardSwapped(e) => format!(
+            "RewardSwapped market={} user={} lamports={} output_mint={} amount_out={}",
+            e.market, e.user, sol(e.lamports), e.output_mint, e.amount_out
+        ),
+        CryptoscoreEvent::ClaimReceiptMinted(e) => format!(
+            "ClaimReceiptMinted market={} user={} tree={} prediction={:?} stake={} payout={}",
+            e.market, e.user, e.merkle_tree, e.prediction, sol(e.stake), sol(e.payout)
+        ),
+        CryptoscoreEvent::FeesDistributed(e) => format!(
+            "FeesDistributed market={} creator_fee={} platform_fee={} total_fees={}",
+            e.market, sol(e.creator_fee), sol(e.platform_fee), sol(e.total_fees)
+        ),
+        CryptoscoreEvent::UnclaimedSwept(e) => format!(
+            "UnclaimedSwept market={} platform={} amount={}",
+            e.market, e.platform, sol(e.amount)
+        ),
+        CryptoscoreEvent::MarketCancelled(e) => format!(
+            "MarketCancelled market={} cancelled_by={} total_pool={}",
+            e.market, e.cancelled_by, sol(e.total_pool)
+        ),
+        CryptoscoreEvent::RefundClaimed(e) => format!(
+            "RefundClaimed market={} user={} amount={}",
+            e.market, e.user, sol(e.amount)
+        ),
+        CryptoscoreEvent::MarketClosed(e) => format!("MarketClosed market={}", e.market),
+        CryptoscoreEvent::SettlementReportCompiled(e) => format!(
+            "SettlementReportCompiled market={} total_in={} fees_out={} rewards_out={} refunds_out={} dust={}",
+            e.market, sol(e.total_in), sol(e.fees_out), sol(e.rewards_out), sol(e.refunds_out), sol(e.dust)
+        ),
+        CryptoscoreEvent::SolvencyViolation(e) => format!(
+            "SolvencyViolation market={} available={} liability={}",
+            e.market, sol(e.available), sol(e.liability)
+        ),
+        CryptoscoreEvent::PlatformFeeCollected(e) => format!(
+            "PlatformFeeCollected market={} amount={} total_collected={}",
+            e.market, sol(e.amount), sol(e.total_collected)
+        ),
+        CryptoscoreEvent::TreasuryWithdrawn(e) => format!(
+            "TreasuryWithdrawn recipient={} amount={} total_withdrawn={}",
+            e.recipient, sol(e.amount), sol(e.total_withdrawn)
+        ),
+        CryptoscoreEvent::PoolStaked(e) => format!(
+            "PoolStaked market={} stake_pool={} lamports={} pool_tokens={}",
+            e.market, e.stake_pool, sol(e.lamports), e.pool_tokens
+        ),
+        CryptoscoreEvent::PoolUnwound(e) => format!(
+            "PoolUnwound market={} staked={} returned={}",
+            e.market, sol(e.staked), sol(e.returned)
+        ),
+        CryptoscoreEvent::ResolutionSigned(e) => format!(
+            "ResolutionSigned market={} signer={} outcome={:?}",
+            e.market, e.signer, e.outcome
+        ),
+        CryptoscoreEvent::ResolutionCoSigned(e) => format!(
+            "ResolutionCoSigned market={} outcome={:?} signers={},{}",
+            e.market, e.outcome, e.signers[0], e.signers[1]
+        ),
+        CryptoscoreEvent::InsuranceFunded(e) => format!(
+            "InsuranceFunded amount={} total_funded={}",
+            sol(e.amount), sol(e.total_funded)
+        ),
+        CryptoscoreEvent::MisresolutionRuled(e) => format!(
+            "MisresolutionRuled market={} resolved={:?} correct={:?} claim_cap={} payout_cap={}",
+            e.market, e.resolved_outcome, e.correct_outcome, sol(e.claim_cap), sol(e.payout_cap)
+        ),
+        CryptoscoreEvent::InsuranceClaimPaid(e) => format!(
+            "InsuranceClaimPaid market={} user={} amount={} fund_balance={}",
+            e.market, e.user, sol(e.amount), sol(e.fund_balance)
+        ),
+        CryptoscoreEvent::JoinQueued(e) => format!(
+            "JoinQueued market={} user={} prediction={:?} min_payout_bps={}",
+            e.market, e.user, e.prediction, e.min_payout_bps
+        ),
+        CryptoscoreEvent::QueuedJoinRefunded(e) => format!(
+            "QueuedJoinRefunded market={} user={} amount={}",
+            e.market, e.user, sol(e.amount)
+        ),
+        CryptoscoreEvent::CopyJoined(e) => format!(
+            "CopyJoined market={} follower={} leader={} prediction={:?} amount={}",
+            e.market, e.follower, e.leader, e.prediction, sol(e.amount)
+        ),
+        CryptoscoreEvent::ResultsPublished(e) => format!(
+            "ResultsPublished results_batch={} batch_id={} results={}",
+            e.results_batch, e.batch_id, e.result_count
+        ),
+        CryptoscoreEvent::LiveScoreUpdated(e) => format!(
+            "LiveScoreUpdated live_score={} match_id={} score={}-{} minute={} final={}",
+            e.live_score, e.match_id, e.home_goals, e.away_goals, e.minute, e.is_final
+        ),
+        CryptoscoreEvent::StreakRebatePaid(e) => format!(
+            "StreakRebatePaid market={} user={} amount={} epoch_paid={}",
+            e.market, e.user, sol(e.amount), sol(e.epoch_paid)
+        ),
+        CryptoscoreEvent::FeeDiscountPaid(e) => format!(
+            "FeeDiscountPaid market={} user={} discount_bps={} amount={} epoch_paid={}",
+            e.market, e.user, e.discount_bps, sol(e.amount), sol(e.epoch_paid)
+        ),
+        CryptoscoreEvent::KeeperTipPaid(e) => format!(
+            "KeeperTipPaid market={} keeper={} amount={} market_paid={}",
+            e.market, e.keeper, sol(e.amount), sol(e.market_paid)
+        ),
+        CryptoscoreEvent::VoucherRedeemed(e) => format!(
+            "VoucherRedeemed market={} holder={} campaign={} amount={} campaign_spent={}",
+            e.market, e.holder, e.campaign, sol(e.amount), sol(e.campaign_spent)
+        ),
+        CryptoscoreEvent::PlatformParamSet(e) => format!("PlatformParamSet param={:?} caller={}", e.param, e.caller),
+        CryptoscoreEvent::MarketRegistryInvalidated(e) => format!(
+            "MarketRegistryInvalidated market_registry={} market={} creator={} match_id={}",
+            e.market_registry, e.market, e.creator, e.match_id
+        ),
+        CryptoscoreEvent::ProposalCreated(e) => format!(
+            "ProposalCreated proposal={} id={} proposer={} param={:?} voting_ends_at={} executable_at={}",
+            e.proposal, e.id, e.proposer, e.param, e.voting_ends_at, e.executable_at
+        ),
+        CryptoscoreEvent::VoteCast(e) => format!(
+            "VoteCast proposal={} voter={} approve={} weight={} votes_for={} votes_against={}",
+            e.proposal,
+            e.voter,
+            e.approve,
+            sol(e.weight),
+            sol(e.votes_for),
+            sol(e.votes_against)
+        ),
+        CryptoscoreEvent::ProposalExecuted(e) => {
+            format!("ProposalExecuted proposal={} id={} param={:?}", e.proposal, e.id, e.param)
+        }
+        CryptoscoreEvent::DisputeOpened(e) => format!(
+            "DisputeOpened dispute={} market={} disputer={} resolved_outcome={:?} claimed_outcome={:?}",
+            e.dispute, e.market, e.disputer, e.resolved_outcome, e.claimed_outcome
+        ),
+        CryptoscoreEvent::JuryDrawn(e) => format!(
+            "JuryDrawn dispute={} market={} jurors={} voting_ends_at={}",
+            e.dispute,
+            e.market,
+            e.jurors.iter().map(|juror| juror.to_string()).collect::<Vec<_>>().join(","),
+            e.voting_ends_at
+        ),
+        CryptoscoreEvent::JurorVoted(e) => {
+            format!("JurorVoted dispute={} juror={} outcome={:?}", e.dispute, e.juror, e.outcome)
+        }
+        CryptoscoreEvent::DisputeSettled(e) => format!(
+            "DisputeSettled dispute={} market={} verdict={:?} overturned={} slashed={} juror_reward={}",
+            e.dispute,
+            e.market,
+            e.verdict,
+            e.overturned,
+            sol(e.slashed),
+            sol(e.juror_reward)
+        ),
+        CryptoscoreEvent::CoAdminsUpdated(e) => format!(
+            "CoAdminsUpdated market={} co_admins={}",
+            e.market,
+            e.co_admins
+                .iter()
+                .filter(|co_admin| **co_admin != Pubkey::default())
+                .map(|co_admin| co_admin.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        CryptoscoreEvent::PredictionCommitted(e) => format!(
+            "PredictionCommitted market={} user={} timestamp={}",
+            e.market, e.user, e.timestamp
+        ),
+        CryptoscoreEvent::WinnersRootPublished(e) => format!(
+            "WinnersRootPublished market={} root={} winners={} reward={}",
+            e.market,
+            hex(&e.root),
+            e.winners,
+            sol(e.reward)
+        ),
+        CryptoscoreEvent::CharityPledged(e) => format!(
+            "CharityPledged market={} charity={} pool_share_bps={}",
+            e.market, e.charity, e.pool_share_bps
+        ),
+        CryptoscoreEvent::CharityDonated(e) => format!(
+            "CharityDonated market={} charity={} fees={} pool_share={} prize_pool={}",
+            e.market,
+            e.charity,
+            sol(e.fees),
+            sol(e.pool_share),
+            sol(e.prize_pool)
+        ),
+        CryptoscoreEvent::InPlayEnabled(e) => format!(
+            "InPlayEnabled market={} live_score={} lock_minute={} lock_on_goal={}",
+            e.market, e.live_score, e.lock_minute, e.lock_on_goal
+        ),
+        CryptoscoreEvent::PropPlayersListed(e) => format!(
+            "PropPlayersListed market={} kind={:?} players={:?}",
+            e.market, e.kind, e.player_ids
+        ),
+        CryptoscoreEvent::PropPicked(e) => format!(
+            "PropPicked market={} user={} player_id={}",
+            e.market, e.user, e.player_id
+        ),
+        CryptoscoreEvent::PropResolved(e) => format!(
+            "PropResolved market={} kind={:?} player_id={:?} winners={} pool={}",
+            e.market,
+            e.kind,
+            e.player_id,
+            e.winner_count,
+            sol(e.total_pool)
+        ),
+        CryptoscoreEvent::OutrightCheckpointed(e) => format!(
+            "OutrightCheckpointed market={} checkpoint={} eliminated={:?} remaining={}",
+            e.market, e.checkpoint, e.eliminated, e.remaining
+        ),
+        CryptoscoreEvent::CorrectScoreEnabled(e) => format!("CorrectScoreEnabled market={}", e.market),
+        CryptoscoreEvent::ScorePicked(e) => format!(
+            "ScorePicked market={} user={} scoreline={:?}",
+            e.market, e.user, e.scoreline
+        ),
+        CryptoscoreEvent::CorrectScoreResolved(e) => format!(
+            "CorrectScoreResolved market={} score={}-{} scoreline={:?} winners={} pool={}",
+            e.market,
+            e.home_goals,
+            e.away_goals,
+            e.scoreline,
+            e.winner_count,
+            sol(e.total_pool)
+        ),
+        CryptoscoreEvent::HandicapSet(e) => format!(
+            "HandicapSet market={} style={:?} home_line={} away_line={}",
+            e.market,
+            e.style,
+            e.home_line as f64 / 2.0,
+            e.away_line as f64 / 2.0
+        ),
+        CryptoscoreEvent::HandicapResolved(e) => format!(
+            "HandicapResolved market={} score={}-{} home_line={} outcome={:?}",
+            e.market,
+            e.home_goals,
+            e.away_goals,
+            e.home_line as f64 / 2.0,
+            e.outcome
+        ),
+    }
+}
+
+pub fn factory(factory: &Factory) -> String {
+    format!(
+        "authority: {}\nmarket_count: {}\nplatform_fee_bps: {}\nmin_leaderboard_markets: {}\nmin_leaderboard_pool: {}",
+        factory.authority,
+        factory.market_count,
+        factory.platform_fee_bps,
+        factory.min_leaderboard_markets,
+        sol(factory.min_leaderboard_pool),
+    )
+}
+
+pub fn live_score(score: &LiveScore) -> String {
+    format!(
+        "score: {}-{}\nminute: {}\nis_final: {}\nupdated_at: {}",
+        score.home_goals, score.away_goals, score.minute, score.is_final, score.updated_at,
+    )
+}
+
+pub fn participant(participant: &Participant) -> String {
+    format!(
+        "market: {}\nuser: {}\nprediction: {:?}\njoined_at: {}\nhas_withdrawn: {}\nnote_hash: {}\nentry_index: {}",
+        participant.market,
+        participant.user,
+        participant.prediction,
+        participant.joined_at,
+        participant.has_withdrawn,
+        hex(&participant.note_hash),
+        participant.entry_index,
+    )
+}
+
+pub fn settlement_report(report: &SettlementReport) -> String {
+    format!(
+        "market: {}\nstatus: {:?}\nparticipants: {}\nwinners: {}\ntotal_in: {}\nfees_out: {}\nrewards_out: {}\n\
+         refunds_out: {}\ndust: {}\npaid_out: {}\nvault_remaining: {}\ncompiled_at: {}",
+        report.market,
+        report.status,
+        report.participants,
+        report.winners,
+        sol(report.total_in),
+        sol(report.fees_out),
+        sol(report.rewards_out),
+        sol(report.refunds_out),
+        sol(report.dust),
+        sol(report.paid_out),
+        sol(report.vault_remaining),
+        report.compiled_at,
+    )
+}
+
+pub fn proposal(proposal: &Proposal) -> String {
+    format!(
+        "id: {}\nproposer: {}\nparam: {:?}\nvotes_for: {}\nvotes_against: {}\nquorum_votes: {}\n\
+         voting_ends_at: {}\nexecutable_at: {}\npassed: {}\nexecuted: {}",
+        proposal.id,
+        proposal.proposer,
+        proposal.param,
+        sol(proposal.votes_for),
+        sol(proposal.votes_against),
+        sol(proposal.quorum_votes),
+        proposal.voting_ends_at,
+        proposal.executable_at,
+        proposal.passed(),
+        proposal.executed,
+    )
+}
+
+pub fn dispute(dispute: &Dispute) -> String {
+    let votes = dispute
+        .jurors
+        .iter()
+        .zip(&dispute.votes)
+        .map(|(juror, vote)| {
+            let vote = vote.as_ref().map_or_else(|| "-".to_string(), |vote| format!("{:?}", vote));
+            format!("\n  {}: {}", juror, vote)
+        })
+        .collect::<String>();
+    format!(
+        "market: {}\ndisputer: {}\nresolved_outcome: {:?}\nclaimed_outcome: {:?}\nstatus: {:?}\n\
+         voting_ends_at: {}\nverdict: {:?}\njuror_reward: {}\njurors:{}",
+        dispute.market,
+        dispute.disputer,
+        dispute.resolved_outcome,
+        dispute.claimed_outcome,
+        dispute.status,
+        dispute.voting_ends_at,
+        dispute.verdict,
+        sol(dispute.juror_reward),
+        votes,
+    )
+}
+
+pub fn points(points: &PointsSummary) -> String {
+    let held = |value: Option<u64>| value.map_or_else(|| "no longer on chain".to_string(), |value| value.to_string());
+    format!(
+        "wager_points: {}\ncreator_points: {}\nepoch: {}\nepoch_points: {}\nepoch_total: {}\nshare_bps: {}",
+        points.wager_points,
+        points.creator_points,
+        points.epoch,
+        held(points.epoch_points),
+        held(points.epoch_total),
+        held(points.share_bps()),
+    )
+}
+
+pub fn reputation(reputation: &ReputationSummary) -> String {
+    format!(
+        "account: {}\nbalance: {}\nearned: {}\nsynced: {}",
+        reputation.account,
+        reputation.balance,
+        reputation.earned,
+        reputation.is_synced(),
+    )
+}
+
+pub fn odds_sample(sample: &OddsSample) -> String {
+    format!(
+        "{} home={} draw={} away={} pool={}",
+        sample.timestamp,
+        sample.home_count,
+        sample.draw_count,
+        sample.away_count,
+        sol(sample.total_pool),
+    )
+}
+
+pub fn winner_proof(proof: &WinnerProof) -> String {
+    let mut out = format!("  index:  {}\n  reward: {}\n  proof:", proof.index, sol(proof.reward));
+    for sibling in &proof.proof {
+        out.push_str(&format!("\n    {}", hex(sibling)));
+    }
+    out
+}
@@ -0,0 +1,110 @@
+//! Market fixture lists loaded from CSV or JSON.
+
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+
+use cryptoscore_common::tags::{encode_tag, Tag, TagCategory};
+use cryptoscore_common::{HandicapStyle, OutcomeSet};
+use serde::Deserialize;
+
+/// A handicap style with the home and away lines in half goals
+pub type HandicapLines = (HandicapStyle, i8, i8);
+
+/// One market to create; CSV files use these field names as headers
+#[derive(Deserialize, Debug)]
+pub struct MarketFixture {
+    pub match_id: String,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    #[serde(default = "default_public")]
+    pub is_public: bool,
+    /// For sports without draws, e.g. tennis, basketball or best-of-three e-sports
+    #[serde(default)]
+    pub no_draw: bool,
+    /// Seconds after `end_time` during which only the creator can resolve
+    #[serde(default)]
+    pub resolution_grace: i64,
+    /// Discovery tags, each registered with the factory for its category
+    pub league: Option<String>,
+    pub country: Option<String>,
+    pub stage: Option<String>,
+    pub sport: Option<String>,
+    /// Handicap line in goals for each team, e.g. -1.5 and 1.5; set both or neither
+    pub home_line: Option<f64>,
+    pub away_line: Option<f64>,
+    /// Settle a level handicap as a draw rather than refunding entries
+    #[serde(default)]
+    pub european_handicap: bool,
+}
+
+impl MarketFixture {
+    /// The fixture's set tags, encoded
+    pub fn tags(&self) -> Result<Vec<(TagCategory, Tag)>, Box<dyn Error>> {
+        [
+            (TagCategory::League, &self.league),
+            (TagCategory::Country, &self.country),
+            (TagCategory::Stage, &self.stage),
+            (TagCategory::Sport, &self.sport),
+        ]
+        .into_iter()
+        .filter_map(|(category, value)| Some((category, value.as_deref().filter(|value| !value.is_empty())?)))
+        .map(|(category, value)| {
+            let tag = encode_tag(value).ok_or_else(|| format!("invalid {:?} tag {:?}", category, value))?;
+            Ok((category, tag))
+        })
+        .collect()
+    }
+
+    pub fn outcome_set(&self) -> OutcomeSet {
+        if self.no_draw {
+            OutcomeSet::WinLose
+        } else {
+            OutcomeSet::WinDrawWin
+        }
+    }
+
+    /// The fixture's handicap style and lines in half goals, if it has one
+    pub fn handicap(&self) -> Result<Option<HandicapLines>, Box<dyn Error>> {
+        let (home_line, away_line) = match (self.home_line, self.away_line) {
+            (None, None) => return Ok(None),
+            (Some(home_line), Some(away_line)) => (home_line, away_line),
+            _ => return Err("home_line and away_line must be set together".into()),
+        };
+        let style = if self.european_handicap {
+            HandicapStyle::European
+        } else {
+            HandicapStyle::Asian
+        };
+
+        Ok(Some((style, half_goals(home_line)?, half_goals(away_line)?)))
+    }
+}
+
+fn half_goals(line: f64) -> Result<i8, Box<dyn Error>> {
+    let half_goals = line * 2.0;
+    if half_goals.fract() != 0.0 || half_goals.abs() > i8::MAX as f64 {
+        return Err(format!("invalid handicap line {} (expected a multiple of 0.5)", line).into());
+    }
+    Ok(half_goals as i8)
+}
+
+fn default_public() -> bool {
+    true
+}
+
+/// Load fixtures from `path`, choosing the format by its extension
+pub fn load(path: &Path) -> Result<Vec<MarketFixture>, Box<dyn Error>> {
+    let file = File::open(path)?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => Ok(serde_json::from_reader(file)?),
+        Some("csv") => csv::Reader::from_reader(file)
+            .deserialize()
+            .map(|row| row.map_err(Into::into))
+            .collect(),
+        _ => Err(format!("Unsupported fixture file {} (expected .csv or .json)", path.display()).into()),
+    }
+}
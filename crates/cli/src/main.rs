@@ -0,0 +1,1853 @@
+//! Operator CLI for the CryptoScore programs.
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use cryptoscore_client::lookup_tables::common_addresses;
+use cryptoscore_client::{ClientError, Confirmed, CryptoscoreClient, PriorityFee, SendOptions, TokenGateRule};
+use cryptoscore_common::attestation::attestation_message;
+use cryptoscore_common::commitment::SALT_LEN;
+use cryptoscore_common::fees::{implied_payout_bps, BPS_DENOMINATOR};
+use cryptoscore_common::jury::{committed_slot_hash, draw_jurors, jury_seed, SlotHashLookup};
+use cryptoscore_common::pda::{
+    find_court_address, find_dispute_address, find_factory_address, find_governance_address, find_live_score_address,
+    find_market_address, find_participant_address, find_platform_address, find_platform_factory_address,
+    find_proposal_address, find_results_batch_address, find_settlement_report_address, find_winners_root_address,
+    kickoff_day,
+};
+use cryptoscore_common::tags::{encode_tag, TagCategory};
+use cryptoscore_common::{
+    FinalScore, MarketStatus, MatchOutcome, MatchResult, PropKind, Scoreline, CLAIM_WINDOW_SECONDS, MAX_BATCH_RESULTS,
+    MAX_CO_ADMINS, MAX_PAYOUT_SWAP_MINTS, MAX_PROP_PLAYERS, QUEUED_JOIN_LEAD_SECONDS, SCORE_GRID_MAX_GOALS,
+};
+use cryptoscore_factory_interface::{DiscountTier, Factory, LiveScore, ResultsBatch};
+use cryptoscore_governance_interface::{Court, CourtTerms, Dispute, Governance, PlatformParam, Proposal};
+use cryptoscore_market_interface::{Market, Participant, SettlementReport, WinnersRoot};
+use cryptoscore_sdk::{ErrorDecoder, MarketQuery, SortOption};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::native_token::sol_to_lamports;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::sysvar::slot_hashes;
+
+mod display;
+mod fixtures;
+mod route;
+mod seed;
+
+#[derive(Parser)]
+#[command(name = "cryptoscore-cli", about = "Operate CryptoScore markets from the command line")]
+struct Cli {
+    /// RPC URL or cluster name (localnet, devnet, testnet, mainnet-beta)
+    #[arg(long, short = 'u', global = true, default_value = "devnet")]
+    url: String,
+
+    /// Keypair file that signs and pays for transactions
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<PathBuf>,
+
+    /// Compute unit price in micro-lamports
+    #[arg(long, global = true, conflicts_with = "priority_fee_percentile")]
+    priority_fee: Option<u64>,
+
+    /// Estimate the compute unit price as this percentile of recent fees
+    #[arg(long, global = true, value_parser = clap::value_parser!(u8).range(0..=100))]
+    priority_fee_percentile: Option<u8>,
+
+    /// Cap on the estimated compute unit price in micro-lamports
+    #[arg(long, global = true, default_value_t = 1_000_000)]
+    max_priority_fee: u64,
+
+    /// Times to re-sign and resend a transaction whose blockhash expired
+    #[arg(long, global = true, default_value_t = 2)]
+    resubmissions: u32,
+
+    /// Send without simulating first
+    #[arg(long, global = true)]
+    skip_simulation: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the factory with the signer as authority
+    InitFactory {
+        #[arg(long)]
+        platform_fee_bps: u16,
+    },
+    /// Register a branded platform with its own factory, treasury and fee, the signer as its operator
+    CreatePlatform {
+        /// Platform ID, up to 32 bytes, e.g. acme-sports
+        platform_id: String,
+        #[arg(long)]
+        platform_fee_bps: u16,
+    },
+    /// Rebate part of a user's next entry fee from the treasury once they lose this many tracked markets
+    /// in a row; a zero streak turns rebates off (factory authority only)
+    SetRebatePolicy {
+        loss_streak: u16,
+        #[arg(long)]
+        rebate_bps: u16,
+        /// Most the treasury pays out in rebates per epoch, in SOL
+        #[arg(long)]
+        epoch_cap: f64,
+    },
+    /// Pay back part of the entry fee on direct joins by users whose verified record earns reputation or who
+    /// hold creator badges, at the best tier they reach; no tiers turns discounts off (factory authority only)
+    SetDiscountPolicy {
+        /// MIN_REPUTATION:MIN_BADGES:DISCOUNT_BPS, a zero threshold ignoring that holding; up to 4 tiers
+        #[arg(long = "tier", value_parser = parse_discount_tier)]
+        tiers: Vec<DiscountTier>,
+        /// Most the treasury pays out in discounts per epoch, in SOL
+        #[arg(long)]
+        epoch_cap: f64,
+    },
+    /// Tip whoever runs a permissionless crank (relayed resolutions, queued joins, pending claims and rebate
+    /// settlements) from the treasury; a zero tip turns tips off (factory authority only)
+    SetKeeperTipPolicy {
+        /// Tip per crank, in SOL
+        tip: f64,
+        /// Most tipped per market, in SOL, further capped by the platform fee its pool owes
+        #[arg(long)]
+        market_budget: f64,
+    },
+    /// Let winners take their payouts swapped into these tokens, e.g. USDC, through Jupiter; no mints turns swaps
+    /// off (factory authority only)
+    SetPayoutSwapPolicy {
+        #[arg(num_args = 0..=MAX_PAYOUT_SWAP_MINTS)]
+        mints: Vec<Pubkey>,
+    },
+    /// Mint compressed NFT receipts of claims into a Bubblegum tree delegated to the market program's receipt
+    /// authority; the default pubkey turns receipts off (factory authority only)
+    SetReceiptPolicy {
+        merkle_tree: Pubkey,
+        /// Start of every receipt's URI, followed by the claim's details
+        #[arg(long)]
+        uri_base: String,
+    },
+    /// Bound the grace period after a market ends during which only its creator can resolve it (factory
+    /// authority only)
+    SetResolutionGracePolicy {
+        /// Shortest grace period in seconds
+        min_grace_seconds: i64,
+        /// Longest grace period in seconds, at most 7 days
+        max_grace_seconds: i64,
+    },
+    /// Restrict the characters match IDs may contain, e.g. "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_" (factory
+    /// authority only)
+    SetMatchIdPolicy { allowed_characters: String },
+    /// Let each wallet create at most `max_markets` markets per sliding window, 0 to lift the limit (factory
+    /// authority only)
+    SetCreationRatePolicy {
+        max_markets: u32,
+        /// Window length in seconds
+        #[arg(long, default_value_t = 24 * 60 * 60)]
+        window_seconds: i64,
+    },
+    /// Run a market without creator and platform fees, e.g. a community or promotional pool; it can only go back
+    /// to charging fees before anyone joins (factory authority only)
+    SetRakeFree {
+        market: Pubkey,
+        /// Charge fees again instead
+        #[arg(long)]
+        off: bool,
+    },
+    /// Open a promotional campaign whose single-use vouchers cover entry fees from the treasury (factory
+    /// authority only)
+    CreateVoucherCampaign {
+        campaign_id: u64,
+        /// Most the campaign's vouchers cover in total, in SOL
+        #[arg(long)]
+        budget: f64,
+        /// Largest entry fee a voucher covers, in SOL
+        #[arg(long)]
+        max_entry_fee: f64,
+        /// Unix timestamp from which vouchers can no longer be issued or redeemed
+        #[arg(long)]
+        expires_at: i64,
+    },
+    /// Issue a user a single-use voucher from a campaign, redeemable on their next join (factory authority only)
+    IssueVoucher { campaign_id: u64, holder: Pubkey },
+    /// Designate the key whose signed results can resolve markets (factory authority only)
+    SetResultsSigner {
+        signer: Pubkey,
+        /// Platform ID whose factory the signer resolves markets for, instead of the default factory
+        #[arg(long)]
+        platform: Option<String>,
+    },
+    /// Register the source-chain oracle whose Wormhole messages can resolve markets (factory authority only)
+    SetWormholeEmitter {
+        /// Wormhole chain ID of the oracle's chain, e.g. 2 for Ethereum
+        emitter_chain: u16,
+        /// Oracle contract address, left-padded to 32 bytes, as hex
+        #[arg(value_parser = parse_hex::<32>)]
+        emitter_address: [u8; 32],
+        /// Wormhole core bridge program that posts verified VAAs
+        #[arg(long)]
+        core_bridge: Pubkey,
+    },
+    /// Require markets with pools of at least this many SOL to be resolved by two of their creator, the
+    /// results signer and the arbiter; zero turns it off (factory authority only)
+    SetResolutionPolicy {
+        /// Pool size in SOL
+        high_stakes_pool: f64,
+        #[arg(long)]
+        arbiter: Pubkey,
+    },
+    /// Let another key, e.g. the governance PDA, change platform parameters alongside the factory
+    /// authority; the default pubkey removes it (factory authority only)
+    SetGovernor {
+        governor: Pubkey,
+    },
+    /// Change a platform parameter directly (factory authority or governor only)
+    SetPlatformParam {
+        #[command(subcommand)]
+        param: ParamArg,
+    },
+    /// Put platform parameters under stake-weighted governance (factory authority only)
+    InitGovernance {
+        /// Seconds a proposal is open for voting
+        #[arg(long)]
+        voting_period: i64,
+        /// Seconds between the end of voting and when a passed proposal can execute
+        #[arg(long)]
+        timelock: i64,
+        /// Share of the stake that has to vote on a proposal
+        #[arg(long)]
+        quorum_bps: u16,
+        /// Stake needed to create a proposal, in SOL
+        #[arg(long, default_value_t = 0.0)]
+        min_proposal_stake: f64,
+    },
+    /// Stake SOL for governance voting power
+    Stake {
+        /// Amount in SOL
+        amount: f64,
+    },
+    /// Withdraw staked SOL once voting has ended on every proposal it voted on
+    Unstake {
+        /// Amount in SOL
+        amount: f64,
+    },
+    /// Propose a platform parameter change for staked users to vote on
+    Propose {
+        #[command(subcommand)]
+        param: ParamArg,
+    },
+    /// Vote on a proposal with all of the signer's stake
+    Vote {
+        proposal: u64,
+        /// Vote against the change instead of for it
+        #[arg(long)]
+        against: bool,
+    },
+    /// Make a passed proposal's change once its timelock has run out
+    ExecuteProposal {
+        proposal: u64,
+    },
+    /// Sign a final score with the results signer's keypair and relay it to the market
+    Attest {
+        market: Pubkey,
+        home_goals: u8,
+        away_goals: u8,
+        /// Keypair file of the factory's results signer
+        #[arg(long)]
+        results_keypair: PathBuf,
+    },
+    /// Relay a result bridged through Wormhole, from a VAA the core bridge has already posted
+    ResolveWithVaa {
+        market: Pubkey,
+        posted_vaa: Pubkey,
+    },
+    /// Report a match's score while it's played, signing with the keypair as the factory's results
+    /// signer
+    LiveScore {
+        match_id: String,
+        home_goals: u8,
+        away_goals: u8,
+        /// Minute of play
+        minute: u16,
+        /// Mark this the final score, which markets on the match can then resolve from
+        #[arg(long = "final")]
+        is_final: bool,
+    },
+    /// Relay the final live score of a market's match to resolve it
+    ResolveWithLiveScore {
+        market: Pubkey,
+    },
+    /// Publish final results for several matches, signing with the keypair as the factory's
+    /// results signer
+    PublishResults {
+        /// Batch identifier, e.g. a matchweek number
+        batch_id: u64,
+        /// Final scores as MATCH_ID=HOME-AWAY, e.g. EPL-ARS-CHE=2-1
+        #[arg(required = true, num_args = 1..=MAX_BATCH_RESULTS, value_parser = parse_result)]
+        results: Vec<MatchResult>,
+    },
+    /// Resolve every unresolved market a published results batch covers
+    ResolveBatch {
+        batch_id: u64,
+    },
+    /// List every unclaimed winner of a resolved market in their dashboard pending claims
+    RecordClaims {
+        market: Pubkey,
+    },
+    /// Fold a resolved market's participant roster into the merkle root of its winners, a batch per
+    /// transaction, until the root is published
+    WinnersRoot {
+        market: Pubkey,
+    },
+    /// Settle every opted-in participant's tracked entry in a finished market against their losing streak
+    SettleRebates {
+        market: Pubkey,
+    },
+    /// Register a discovery tag markets may use in a category's slot (factory authority only)
+    AddTag {
+        #[arg(value_enum)]
+        category: Category,
+        tag: String,
+        /// Stop allowing the tag on new markets instead
+        #[arg(long)]
+        remove: bool,
+    },
+    /// Register and initialize every market in a CSV or JSON fixture list
+    CreateMarkets {
+        fixtures: PathBuf,
+        /// Platform ID to create the markets on, instead of the default factory
+        #[arg(long)]
+        platform: Option<String>,
+    },
+    /// Escrow a join that executes shortly before kickoff only if the payout is still at least
+    /// `min_payout` times the entry fee
+    QueueJoin {
+        market: Pubkey,
+        #[arg(value_enum)]
+        prediction: Outcome,
+        /// Smallest acceptable payout as a multiple of the entry fee, e.g. 2.5
+        #[arg(long)]
+        min_payout: f64,
+    },
+    /// Withdraw the signer's queued join on a market
+    CancelQueuedJoin {
+        market: Pubkey,
+    },
+    /// Execute a market's due queued joins whose payout condition holds and refund the rest
+    SettleQueue {
+        market: Pubkey,
+    },
+    /// Follow a predictor so their picks can be copied
+    Follow {
+        leader: Pubkey,
+        /// Stop following them instead
+        #[arg(long)]
+        remove: bool,
+    },
+    /// Join a market on the same prediction as a followed predictor
+    CopyJoin {
+        market: Pubkey,
+        leader: Pubkey,
+        /// NFT proving membership of the collection the market is gated on
+        #[arg(long)]
+        gate_nft: Option<Pubkey>,
+    },
+    /// Replace the co-admins who can cancel and configure a market alongside its creator (creator
+    /// only); pass none to remove them all
+    CoAdmins {
+        market: Pubkey,
+        #[arg(num_args = 0..=MAX_CO_ADMINS)]
+        co_admins: Vec<Pubkey>,
+    },
+    /// Hide predictions on a private market until kickoff (creator or co-admin, before any entries)
+    EnableCommitReveal {
+        market: Pubkey,
+    },
+    /// List a market's participants on-chain, page by page (creator or co-admin, before any
+    /// entries)
+    EnableRoster {
+        market: Pubkey,
+    },
+    /// Give a market's fees, and a share of its prize pool, to a charity at resolution (creator or
+    /// co-admin, before any entries)
+    Charity {
+        market: Pubkey,
+        charity: Pubkey,
+        /// Share of the prize pool donated on top of the fees, in basis points (at most 5000)
+        #[arg(long, default_value_t = 0)]
+        pool_share_bps: u16,
+    },
+    /// Pay a share of the creator fee to the earliest entry on the winning outcome (creator or
+    /// co-admin, before any entries)
+    FirstCorrectBonus {
+        market: Pubkey,
+        /// Share of the creator fee paid as the bonus, in basis points (1 to 10000)
+        bonus_bps: u16,
+    },
+    /// Let a market's winners roll their rewards into another of your markets kicking off after it
+    /// ends (creator or co-admin, before resolution)
+    FollowUp {
+        market: Pubkey,
+        follow_up: Pubkey,
+    },
+    /// Keep a market taking joins after kickoff until its match's live score reaches a minute
+    /// (creator or co-admin, before kickoff)
+    InPlay {
+        market: Pubkey,
+        /// Minute of play from which joins are refused
+        lock_minute: u16,
+        /// Also stop joins at the first goal
+        #[arg(long)]
+        lock_on_goal: bool,
+    },
+    /// Make a market take picks on players, or on teams for a season outright, instead of match
+    /// predictions (creator or co-admin, before any entries)
+    PropPlayers {
+        market: Pubkey,
+        #[arg(value_enum)]
+        kind: Prop,
+        /// Oracle IDs of the players, or outright teams, entries can pick
+        #[arg(required = true, num_args = 1..=MAX_PROP_PLAYERS)]
+        player_ids: Vec<u32>,
+    },
+    /// Join a player prop market on one of its listed players
+    JoinProp {
+        market: Pubkey,
+        player_id: u32,
+        /// NFT proving membership of the collection the market is gated on
+        #[arg(long)]
+        gate_nft: Option<Pubkey>,
+    },
+    /// Resolve a player prop market with the player the oracle reported, signing with the keypair
+    /// as the factory's results signer
+    ResolveProp {
+        market: Pubkey,
+        /// Player who did it; omit if no listed player did, e.g. no goal was scored
+        player_id: Option<u32>,
+    },
+    /// Record an outright market's standings checkpoint, signing with the keypair as the
+    /// factory's results signer
+    OutrightCheckpoint {
+        market: Pubkey,
+        /// Team IDs that can no longer win; teams eliminated earlier stay eliminated
+        eliminated: Vec<u32>,
+    },
+    /// Settle an outright market before the season ends once one team is left able to win
+    SettleOutright { market: Pubkey },
+    /// Make a market take final-score picks instead of match predictions (creator or co-admin,
+    /// before any entries)
+    CorrectScore { market: Pubkey },
+    /// Join a correct-score market on a final score
+    JoinScore {
+        market: Pubkey,
+        /// Final score as HOME-AWAY on the grid, e.g. 2-1, or "other" for any score off it
+        #[arg(value_parser = parse_scoreline)]
+        scoreline: Scoreline,
+        /// NFT proving membership of the collection the market is gated on
+        #[arg(long)]
+        gate_nft: Option<Pubkey>,
+    },
+    /// Resolve a correct-score market from its match's final live score
+    ResolveScore { market: Pubkey },
+    /// Resolve a handicap market from its match's final live score, refunding entries on an
+    /// Asian push
+    ResolveHandicap { market: Pubkey },
+    /// Limit a market with no entries yet to holders of a token or of an NFT from a verified
+    /// Metaplex collection (creator or co-admin)
+    TokenGate {
+        market: Pubkey,
+        /// Token joiners must hold, e.g. a club's fan token
+        #[arg(long, required_unless_present = "collection", conflicts_with = "collection")]
+        mint: Option<Pubkey>,
+        /// Smallest balance that qualifies, in the token's base units
+        #[arg(long, default_value_t = 1)]
+        min_amount: u64,
+        /// Collection whose NFTs qualify
+        #[arg(long)]
+        collection: Option<Pubkey>,
+    },
+    /// Enter a commit–reveal market without revealing the prediction; prints the salt to reveal with
+    Commit {
+        market: Pubkey,
+        #[arg(value_enum)]
+        prediction: Outcome,
+    },
+    /// Reveal a committed prediction after kickoff
+    Reveal {
+        market: Pubkey,
+        #[arg(value_enum)]
+        prediction: Outcome,
+        /// Hex salt printed by `commit`
+        #[arg(long, value_parser = parse_hex::<SALT_LEN>)]
+        salt: [u8; SALT_LEN],
+    },
+    /// Resolve a market from its match's final score as its creator or a participant
+    Resolve {
+        market: Pubkey,
+        home_goals: u8,
+        away_goals: u8,
+    },
+    /// Sign the final score of a high-stakes market as its creator, the results signer or the arbiter
+    SignResolution {
+        market: Pubkey,
+        home_goals: u8,
+        away_goals: u8,
+    },
+    /// Resolve a high-stakes market on a final score another of its resolvers signed
+    CoSignResolution {
+        market: Pubkey,
+        home_goals: u8,
+        away_goals: u8,
+    },
+    /// Correct the final score of a resolved market within the correction window (results signer only)
+    CorrectResult {
+        market: Pubkey,
+        home_goals: u8,
+        away_goals: u8,
+    },
+    /// Withdraw collected platform fees from the factory treasury (factory authority only)
+    WithdrawTreasury {
+        /// Amount in SOL
+        amount: f64,
+        /// Account receiving the fees (defaults to the signer)
+        #[arg(long)]
+        recipient: Option<Pubkey>,
+    },
+    /// Rule a resolved market mis-resolved so winners of the correct outcome can claim insurance
+    /// (factory authority only)
+    RuleMisresolution {
+        market: Pubkey,
+        /// Outcome the market should have resolved to
+        #[arg(value_enum)]
+        outcome: Outcome,
+        /// Most one participant can claim, in SOL
+        #[arg(long)]
+        claim_cap: f64,
+        /// Most all claims on the market can pay, in SOL
+        #[arg(long)]
+        payout_cap: f64,
+    },
+    /// Roll a winning reward into the market's follow-up market as an entry, taking the rest
+    LetItRide {
+        market: Pubkey,
+        #[arg(value_enum)]
+        prediction: Outcome,
+    },
+    /// Withdraw a winning reward swapped into another token through a Jupiter route
+    ClaimSwapped {
+        market: Pubkey,
+        output_mint: Pubkey,
+        /// JSON file holding Jupiter's swap-instructions response for the reward
+        #[arg(long)]
+        route: PathBuf,
+        /// Fewest base units of the output mint to accept
+        #[arg(long)]
+        min_amount_out: u64,
+    },
+    /// Mint a compressed NFT receipt of a withdrawn reward or refund, for your records
+    ClaimReceipt {
+        market: Pubkey,
+    },
+    /// Claim insurance for a market ruled mis-resolved
+    ClaimInsurance {
+        market: Pubkey,
+    },
+    /// Set up the dispute court that hears disputes over market resolutions (factory authority only)
+    InitCourt {
+        /// Jurors per dispute, odd and at most 9
+        #[arg(long)]
+        jury_size: u8,
+        /// Seconds jurors have to vote once drawn
+        #[arg(long)]
+        voting_period: i64,
+        /// Stake an arbiter needs to be drawn, in SOL
+        #[arg(long)]
+        min_arbiter_stake: f64,
+        /// Fee a disputer pays to the jurors voting for the verdict, in SOL
+        #[arg(long, default_value_t = 0.0)]
+        dispute_fee: f64,
+        /// Share of stake jurors not voting for the verdict lose
+        #[arg(long)]
+        slash_bps: u16,
+        /// Most one participant can claim on an overturned market, in SOL
+        #[arg(long)]
+        claim_cap: f64,
+        /// Most all claims on an overturned market can pay, in SOL
+        #[arg(long)]
+        payout_cap: f64,
+    },
+    /// Stake SOL as an arbiter in the dispute court
+    JoinCourt {
+        /// Amount in SOL
+        amount: f64,
+    },
+    /// Leave the dispute court and withdraw the arbiter stake
+    LeaveCourt,
+    /// Dispute a resolved market's outcome, paying the court's dispute fee
+    Dispute {
+        market: Pubkey,
+        /// Outcome the market should have resolved to
+        #[arg(value_enum)]
+        outcome: Outcome,
+    },
+    /// Draw a dispute's jury from the hash of the slot it committed to, or commit to a new slot once that
+    /// hash has expired
+    DrawJury {
+        market: Pubkey,
+    },
+    /// Vote on a disputed market's outcome as one of its jurors
+    JurorVote {
+        market: Pubkey,
+        #[arg(value_enum)]
+        outcome: Outcome,
+    },
+    /// Settle a dispute once jury voting has ended, paying and slashing jurors
+    SettleDispute {
+        market: Pubkey,
+    },
+    /// Cancel a market as its creator or a co-admin before kickoff, or as the factory authority
+    Cancel {
+        market: Pubkey,
+    },
+    /// Kill switch: send markets to refunds during an incident, even after resolution
+    Void {
+        markets: Vec<Pubkey>,
+    },
+    /// Mark a cancelled market closed once every entry fee has been refunded
+    Close {
+        market: Pubkey,
+    },
+    /// Compile a swept or closed market's settlement report, splitting its pool into fees, rewards, refunds and
+    /// dust, and print it
+    SettlementReport {
+        market: Pubkey,
+    },
+    /// Stake a market's pool in an SPL stake pool for the match (factory authority only)
+    StakePool {
+        market: Pubkey,
+        stake_pool: Pubkey,
+        /// Fail unless the deposit mints at least this many pool tokens
+        #[arg(long, default_value_t = 0)]
+        min_pool_tokens: u64,
+    },
+    /// Bring a staked pool back into its market's vault
+    UnwindPool {
+        market: Pubkey,
+        /// Fail unless at least this much SOL comes back
+        #[arg(long, default_value_t = 0.0)]
+        min_sol: f64,
+    },
+    /// Record markets' current home/draw/away split in their on-chain odds history
+    SampleOdds {
+        markets: Vec<Pubkey>,
+        /// Sample every open market before kickoff
+        #[arg(long, conflicts_with = "markets")]
+        all: bool,
+    },
+    /// Sweep unclaimed funds from markets whose claim window has closed
+    Sweep {
+        markets: Vec<Pubkey>,
+        /// Sweep every resolved market past its claim window
+        #[arg(long, conflicts_with = "markets")]
+        all: bool,
+        /// Account receiving the funds (defaults to the signer)
+        #[arg(long)]
+        platform: Option<Pubkey>,
+    },
+    /// Populate a devnet or local cluster with markets, players and mock results
+    SeedDevnet {
+        /// Markets created in each league
+        #[arg(long, default_value_t = 4)]
+        markets_per_league: u32,
+        /// Generated players, funded by the signer, joining the markets
+        #[arg(long, default_value_t = 8)]
+        players: u32,
+        /// Markets that end within minutes and are resolved with mock oracle results
+        #[arg(long, default_value_t = 3)]
+        resolve: u32,
+        /// SOL sent to each player
+        #[arg(long, default_value_t = 1.0)]
+        player_sol: f64,
+        /// Seed for fixtures, predictions and results
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Directory to write the player keypairs to
+        #[arg(long)]
+        keypair_dir: Option<PathBuf>,
+    },
+    /// Quote the payout for joining a market with a prediction
+    Quote {
+        market: Pubkey,
+        #[arg(value_enum)]
+        prediction: Outcome,
+    },
+    /// Simulate resolving a market to an outcome and print what it would pay
+    PreviewSettlement {
+        market: Pubkey,
+        #[arg(value_enum)]
+        outcome: Outcome,
+    },
+    /// Print a program account
+    Inspect {
+        #[command(subcommand)]
+        account: InspectTarget,
+    },
+    /// Decode the CryptoScore events emitted by a transaction
+    DecodeEvents {
+        signature: Signature,
+    },
+    /// Explain why a transaction failed, with the values the program compared
+    DecodeError {
+        signature: Signature,
+    },
+    /// Manage address lookup tables for batched transactions
+    LookupTable {
+        #[command(subcommand)]
+        action: LookupTableAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum ParamArg {
+    /// Platform fee in basis points, at most 1000
+    FeeBps { bps: u16 },
+    /// Seconds after a match ends during which a market can be ruled mis-resolved; zero for no limit
+    DisputeWindow { seconds: i64 },
+    /// The only account treasury withdrawals can go to; the default pubkey allows any
+    TreasuryRecipient { recipient: Pubkey },
+}
+
+impl From<ParamArg> for PlatformParam {
+    fn from(param: ParamArg) -> Self {
+        match param {
+            ParamArg::FeeBps { bps } => PlatformParam::PlatformFeeBps(bps),
+            ParamArg::DisputeWindow { seconds } => PlatformParam::DisputeWindow(seconds),
+            ParamArg::TreasuryRecipient { recipient } => PlatformParam::TreasuryRecipient(recipient),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum LookupTableAction {
+    /// Create a table holding the shared CryptoScore accounts plus ADDRESSES
+    Create { addresses: Vec<Pubkey> },
+    /// Append ADDRESSES to an existing table
+    Extend { table: Pubkey, addresses: Vec<Pubkey> },
+    /// List a table's addresses
+    Show { table: Pubkey },
+}
+
+#[derive(Subcommand)]
+enum InspectTarget {
+    Factory,
+    Market { address: Pubkey },
+    Participant { market: Pubkey, user: Pubkey },
+    /// A market's recorded odds samples, oldest first
+    Odds { market: Pubkey },
+    /// Markets where a user has an unclaimed win recorded
+    PendingClaims { user: Pubkey },
+    /// The participants listed on a market's roster, in joining order
+    Roster { market: Pubkey },
+    /// A winner's proof against a market's published winners root
+    WinnerProof { market: Pubkey, user: Pubkey },
+    /// A governance proposal and its vote tally
+    Proposal { id: u64 },
+    /// A market's dispute, its jury and their votes
+    Dispute { market: Pubkey },
+    /// A user's points and share of an epoch's points, the current one by default
+    Points {
+        user: Pubkey,
+        /// Week number since the unix epoch
+        #[arg(long)]
+        epoch: Option<u32>,
+    },
+    /// A user's soulbound reputation balance and what their record earns
+    Reputation { user: Pubkey },
+    /// The latest reported score of a match
+    LiveScore { match_id: String },
+    /// Markets kicking off today and over the following days
+    Kickoffs {
+        /// Days to list, starting today
+        #[arg(long, default_value_t = 1)]
+        days: u32,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Outcome {
+    Home,
+    Draw,
+    Away,
+}
+
+impl From<Outcome> for MatchOutcome {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Home => MatchOutcome::Home,
+            Outcome::Draw => MatchOutcome::Draw,
+            Outcome::Away => MatchOutcome::Away,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Prop {
+    NextGoalscorer,
+    Booked,
+    Outright,
+}
+
+impl From<Prop> for PropKind {
+    fn from(prop: Prop) -> Self {
+        match prop {
+            Prop::NextGoalscorer => PropKind::NextGoalscorer,
+            Prop::Booked => PropKind::Booked,
+            Prop::Outright => PropKind::Outright,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Category {
+    League,
+    Country,
+    Stage,
+    Sport,
+}
+
+impl From<Category> for TagCategory {
+    fn from(category: Category) -> Self {
+        match category {
+            Category::League => TagCategory::League,
+            Category::Country => TagCategory::Country,
+            Category::Stage => TagCategory::Stage,
+            Category::Sport => TagCategory::Sport,
+        }
+    }
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("Error: {}", err);
+        if let Some(program_error) = program_error(err.as_ref()) {
+            eprintln!("Program error: {}", program_error);
+        }
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let rpc = RpcClient::new_with_commitment(cluster_url(&cli.url), CommitmentConfig::confirmed());
+    let priority_fee = match (cli.priority_fee, cli.priority_fee_percentile) {
+        (Some(micro_lamports), _) => PriorityFee::Fixed(micro_lamports),
+        (None, Some(percentile)) => PriorityFee::Percentile {
+            percentile,
+            max_micro_lamports: cli.max_priority_fee,
+        },
+        (None, None) => PriorityFee::None,
+    };
+    let client = CryptoscoreClient::new(rpc, load_keypair(cli.keypair)?).with_options(SendOptions {
+        priority_fee,
+        compute_unit_limit: None,
+        simulate: !cli.skip_simulation,
+        resubmissions: cli.resubmissions,
+    });
+
+    match cli.command {
+        Command::InitFactory { platform_fee_bps } => {
+            let ix = cryptoscore_factory_interface::instruction::initialize_factory(
+                cryptoscore_factory_interface::accounts::InitializeFactory::new(client.payer()),
+                platform_fee_bps,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::CreatePlatform {
+            platform_id,
+            platform_fee_bps,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::create_platform(
+                cryptoscore_factory_interface::accounts::CreatePlatform::new(client.payer(), &platform_id),
+                platform_id.clone(),
+                platform_fee_bps,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+            println!(
+                "Platform factory: {}",
+                find_platform_factory_address(&find_platform_address(&platform_id).0).0
+            );
+        }
+        Command::SetRebatePolicy {
+            loss_streak,
+            rebate_bps,
+            epoch_cap,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::set_rebate_policy(
+                cryptoscore_factory_interface::accounts::SetRebatePolicy::new(client.payer()),
+                loss_streak,
+                rebate_bps,
+                sol_to_lamports(epoch_cap),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetDiscountPolicy { tiers, epoch_cap } => {
+            let ix = cryptoscore_factory_interface::instruction::set_discount_policy(
+                cryptoscore_factory_interface::accounts::SetDiscountPolicy::new(client.payer()),
+                tiers,
+                sol_to_lamports(epoch_cap),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetKeeperTipPolicy { tip, market_budget } => {
+            let ix = cryptoscore_factory_interface::instruction::set_keeper_tip_policy(
+                cryptoscore_factory_interface::accounts::SetKeeperTipPolicy::new(client.payer()),
+                sol_to_lamports(tip),
+                sol_to_lamports(market_budget),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetPayoutSwapPolicy { mints } => {
+            let ix = cryptoscore_factory_interface::instruction::set_payout_swap_policy(
+                cryptoscore_factory_interface::accounts::SetPayoutSwapPolicy::new(client.payer()),
+                mints,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetReceiptPolicy { merkle_tree, uri_base } => {
+            let ix = cryptoscore_factory_interface::instruction::set_receipt_policy(
+                cryptoscore_factory_interface::accounts::SetReceiptPolicy::new(client.payer()),
+                merkle_tree,
+                uri_base,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetResolutionGracePolicy { min_grace_seconds, max_grace_seconds } => {
+            let ix = cryptoscore_factory_interface::instruction::set_resolution_grace_policy(
+                cryptoscore_factory_interface::accounts::SetResolutionGracePolicy::new(client.payer()),
+                min_grace_seconds,
+                max_grace_seconds,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetMatchIdPolicy { allowed_characters } => {
+            let ix = cryptoscore_factory_interface::instruction::set_match_id_policy(
+                cryptoscore_factory_interface::accounts::SetMatchIdPolicy::new(client.payer()),
+                allowed_characters,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetCreationRatePolicy { max_markets, window_seconds } => {
+            let ix = cryptoscore_factory_interface::instruction::set_creation_rate_policy(
+                cryptoscore_factory_interface::accounts::SetCreationRatePolicy::new(client.payer()),
+                max_markets,
+                window_seconds,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetRakeFree { market, off } => {
+            let ix = cryptoscore_factory_interface::instruction::set_rake_free(
+                cryptoscore_factory_interface::accounts::SetRakeFree::new(client.payer(), market),
+                !off,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::CreateVoucherCampaign {
+            campaign_id,
+            budget,
+            max_entry_fee,
+            expires_at,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::create_voucher_campaign(
+                cryptoscore_factory_interface::accounts::CreateVoucherCampaign::new(client.payer(), campaign_id),
+                cryptoscore_factory_interface::instruction::CreateVoucherCampaign {
+                    campaign_id,
+                    budget: sol_to_lamports(budget),
+                    max_entry_fee: sol_to_lamports(max_entry_fee),
+                    expires_at,
+                },
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::IssueVoucher { campaign_id, holder } => {
+            let ix = cryptoscore_factory_interface::instruction::issue_voucher(
+                cryptoscore_factory_interface::accounts::IssueVoucher::new(client.payer(), campaign_id, holder),
+                holder,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetResultsSigner { signer, platform } => {
+            let accounts = match platform {
+                Some(platform_id) => cryptoscore_factory_interface::accounts::SetResultsSigner::on_factory(
+                    find_platform_factory_address(&find_platform_address(&platform_id).0).0,
+                    client.payer(),
+                ),
+                None => cryptoscore_factory_interface::accounts::SetResultsSigner::new(client.payer()),
+            };
+            let ix = cryptoscore_factory_interface::instruction::set_results_signer(accounts, signer);
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetWormholeEmitter {
+            emitter_chain,
+            emitter_address,
+            core_bridge,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::set_wormhole_emitter(
+                cryptoscore_factory_interface::accounts::SetWormholeEmitter::new(client.payer()),
+                core_bridge,
+                emitter_chain,
+                emitter_address,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetResolutionPolicy {
+            high_stakes_pool,
+            arbiter,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::set_resolution_policy(
+                cryptoscore_factory_interface::accounts::SetResolutionPolicy::new(client.payer()),
+                sol_to_lamports(high_stakes_pool),
+                arbiter,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetGovernor { governor } => {
+            let ix = cryptoscore_factory_interface::instruction::set_governor(
+                cryptoscore_factory_interface::accounts::SetGovernor::new(client.payer()),
+                governor,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SetPlatformParam { param } => {
+            let ix = cryptoscore_factory_interface::instruction::set_platform_param(
+                cryptoscore_factory_interface::accounts::SetPlatformParam::new(client.payer()),
+                param.into(),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::InitGovernance {
+            voting_period,
+            timelock,
+            quorum_bps,
+            min_proposal_stake,
+        } => {
+            let ix = cryptoscore_governance_interface::instruction::initialize_governance(
+                cryptoscore_governance_interface::accounts::InitializeGovernance::new(client.payer()),
+                cryptoscore_governance_interface::instruction::InitializeGovernance {
+                    voting_period,
+                    timelock,
+                    quorum_bps,
+                    min_proposal_stake: sol_to_lamports(min_proposal_stake),
+                },
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Stake { amount } => {
+            let ix = cryptoscore_governance_interface::instruction::stake(
+                cryptoscore_governance_interface::accounts::Stake::new(client.payer()),
+                sol_to_lamports(amount),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Unstake { amount } => {
+            let ix = cryptoscore_governance_interface::instruction::unstake(
+                cryptoscore_governance_interface::accounts::Unstake::new(client.payer()),
+                sol_to_lamports(amount),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Propose { param } => {
+            let governance: Governance = client.fetch(&find_governance_address(&find_factory_address().0).0)?;
+            let ix = cryptoscore_governance_interface::instruction::create_proposal(
+                cryptoscore_governance_interface::accounts::CreateProposal::new(
+                    client.payer(),
+                    governance.proposal_count,
+                ),
+                param.into(),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Vote { proposal, against } => {
+            let ix = cryptoscore_governance_interface::instruction::cast_vote(
+                cryptoscore_governance_interface::accounts::CastVote::new(client.payer(), proposal),
+                !against,
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::ExecuteProposal { proposal } => {
+            let ix = cryptoscore_governance_interface::instruction::execute_proposal(
+                cryptoscore_governance_interface::accounts::ExecuteProposal::new(proposal),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Attest {
+            market,
+            home_goals,
+            away_goals,
+            results_keypair,
+        } => {
+            let results_signer = load_keypair(Some(results_keypair))?;
+            let score = FinalScore::new(home_goals, away_goals);
+            let match_id = client.fetch::<Market>(&market)?.match_id;
+
+            // Cluster time, so the attestation never runs ahead of the program's clock
+            let attested_at = client.rpc().get_block_time(client.rpc().get_slot()?)?;
+            let signature = results_signer.sign_message(&attestation_message(&match_id, &score, attested_at));
+
+            report(&client.resolve_with_attestation(market, score, attested_at, signature).send()?);
+        }
+        Command::ResolveWithVaa { market, posted_vaa } => report(&client.resolve_with_vaa(market, posted_vaa).send()?),
+        Command::LiveScore {
+            match_id,
+            home_goals,
+            away_goals,
+            minute,
+            is_final,
+        } => {
+            let update = client.update_live_score(&match_id, (home_goals, away_goals), minute);
+            let update = if is_final { update.final_score() } else { update };
+            report(&update.send()?)
+        }
+        Command::ResolveWithLiveScore { market } => report(&client.resolve_with_live_score(market).send()?),
+        Command::PublishResults { batch_id, results } => {
+            report(&client.publish_results(batch_id, results).send()?)
+        }
+        Command::ResolveBatch { batch_id } => resolve_batch(&client, batch_id)?,
+        Command::RecordClaims { market } => {
+            let winners = cryptoscore_sdk::fetch_unclaimed_winners(client.rpc(), &market)?;
+            println!("Recording {} unclaimed wins", winners.len());
+            for user in winners {
+                match client.record_pending_claim(market, user).send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => eprintln!("{}: {}", user, err),
+                }
+            }
+        }
+        Command::WinnersRoot { market } => loop {
+            report(&client.build_winners_root(market).send()?);
+            let winners_root: WinnersRoot = client.fetch(&find_winners_root_address(&market).0)?;
+            if winners_root.published {
+                println!("Winners root {} ({} winners)", display::hex(&winners_root.root), winners_root.winners);
+                break;
+            }
+        },
+        Command::SettleRebates { market } => {
+            let users = cryptoscore_sdk::fetch_tracked_rebate_entries(client.rpc(), &market)?;
+            println!("Settling {} tracked entries", users.len());
+            for user in users {
+                match client.settle_rebate_entry(market, user).send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => eprintln!("{}: {}", user, err),
+                }
+            }
+        }
+        Command::AddTag { category, tag, remove } => {
+            let category = TagCategory::from(category);
+            let tag = encode_tag(&tag).ok_or("Tags are 1-16 lowercase letters, digits or hyphens")?;
+            let ix = if remove {
+                cryptoscore_factory_interface::instruction::remove_tag(
+                    cryptoscore_factory_interface::accounts::RemoveTag::new(client.payer(), category, &tag),
+                )
+            } else {
+                cryptoscore_factory_interface::instruction::add_tag(
+                    cryptoscore_factory_interface::accounts::AddTag::new(client.payer(), category, &tag),
+                    category,
+                    tag,
+                )
+            };
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::CreateMarkets { fixtures, platform } => {
+            let fixtures = fixtures::load(&fixtures)?;
+            println!("Creating {} markets", fixtures.len());
+
+            let mut failed = 0;
+            for fixture in fixtures {
+                let (tags, handicap) = match fixture.tags().and_then(|tags| Ok((tags, fixture.handicap()?))) {
+                    Ok(parsed) => parsed,
+                    Err(err) => {
+                        failed += 1;
+                        eprintln!("{}: {}", fixture.match_id, err);
+                        continue;
+                    }
+                };
+                let mut builder = client
+                    .create_market(fixture.match_id.clone())
+                    .entry_fee(fixture.entry_fee)
+                    .schedule(fixture.kickoff_time, fixture.end_time)
+                    .outcome_set(fixture.outcome_set())
+                    .resolution_grace(fixture.resolution_grace);
+                if !fixture.is_public {
+                    builder = builder.private();
+                }
+                if let Some(platform_id) = &platform {
+                    builder = builder.platform(platform_id);
+                }
+                for (category, tag) in tags {
+                    builder = builder.tag(category, tag);
+                }
+                if let Some((style, home_line, away_line)) = handicap {
+                    builder = builder.handicap(style, home_line, away_line);
+                }
+
+                // Keep going so one bad row doesn't block the rest of the list
+                match builder.send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => {
+                        failed += 1;
+                        eprintln!("{}: {}", fixture.match_id, err);
+                    }
+                }
+            }
+
+            if failed > 0 {
+                return Err(format!("{} markets failed", failed).into());
+            }
+        }
+        Command::QueueJoin {
+            market,
+            prediction,
+            min_payout,
+        } => {
+            let min_payout_bps = (min_payout * BPS_DENOMINATOR as f64).round() as u64;
+            report(&client.queue_join(market, prediction.into(), min_payout_bps).send()?)
+        }
+        Command::CancelQueuedJoin { market } => {
+            report(&client.refund_queued_join(market, client.payer()).send()?)
+        }
+        Command::SettleQueue { market } => settle_queue(&client, market)?,
+        Command::Follow { leader, remove } => {
+            if remove {
+                report(&client.unfollow(leader).send()?)
+            } else {
+                report(&client.follow(leader).send()?)
+            }
+        }
+        Command::CopyJoin {
+            market,
+            leader,
+            gate_nft,
+        } => {
+            let builder = client.copy_join(market, leader);
+            report(&match gate_nft {
+                Some(mint) => builder.gate_nft(mint),
+                None => builder,
+            }
+            .send()?)
+        }
+        Command::CoAdmins { market, co_admins } => {
+            let mut slots = [Pubkey::default(); MAX_CO_ADMINS];
+            slots[..co_admins.len()].copy_from_slice(&co_admins);
+            report(&client.set_co_admins(market, slots).send()?)
+        }
+        Command::EnableCommitReveal { market } => report(&client.enable_commit_reveal(market).send()?),
+        Command::EnableRoster { market } => report(&client.enable_participant_roster(market).send()?),
+        Command::Charity {
+            market,
+            charity,
+            pool_share_bps,
+        } => report(&client.pledge_to_charity(market, charity, pool_share_bps).send()?),
+        Command::FirstCorrectBonus { market, bonus_bps } => {
+            report(&client.offer_first_correct_bonus(market, bonus_bps).send()?)
+        }
+        Command::FollowUp { market, follow_up } => report(&client.set_follow_up_market(market, follow_up).send()?),
+        Command::InPlay {
+            market,
+            lock_minute,
+            lock_on_goal,
+        } => {
+            let enable = client.enable_in_play(market, lock_minute);
+            let enable = if lock_on_goal { enable.lock_on_goal() } else { enable };
+            report(&enable.send()?)
+        }
+        Command::PropPlayers {
+            market,
+            kind,
+            player_ids,
+        } => report(&client.list_prop_players(market, kind.into(), player_ids).send()?),
+        Command::JoinProp {
+            market,
+            player_id,
+            gate_nft,
+        } => {
+            let builder = client.join_prop(market, player_id);
+            report(&match gate_nft {
+                Some(mint) => builder.gate_nft(mint),
+                None => builder,
+            }
+            .send()?)
+        }
+        Command::ResolveProp { market, player_id } => report(&client.resolve_prop(market, player_id).send()?),
+        Command::OutrightCheckpoint { market, eliminated } => {
+            report(&client.record_outright_checkpoint(market, eliminated).send()?)
+        }
+        Command::SettleOutright { market } => report(&client.settle_outright_early(market).send()?),
+        Command::CorrectScore { market } => report(&client.enable_correct_score(market).send()?),
+        Command::JoinScore {
+            market,
+            scoreline,
+            gate_nft,
+        } => {
+            let builder = client.join_correct_score(market, scoreline);
+            report(&match gate_nft {
+                Some(mint) => builder.gate_nft(mint),
+                None => builder,
+            }
+            .send()?)
+        }
+        Command::ResolveScore { market } => report(&client.resolve_correct_score(market).send()?),
+        Command::ResolveHandicap { market } => report(&client.resolve_handicap(market).send()?),
+        Command::TokenGate {
+            market,
+            mint,
+            min_amount,
+            collection,
+        } => {
+            let rule = match (mint, collection) {
+                (Some(mint), _) => TokenGateRule::Token { mint, min_amount },
+                (None, Some(collection)) => TokenGateRule::Collection { collection },
+                (None, None) => return Err("Pass --mint or --collection".into()),
+            };
+            report(&client.set_token_gate(market, rule).send()?)
+        }
+        Command::Commit { market, prediction } => {
+            let salt: [u8; SALT_LEN] = rand::random();
+            report(&client.commit_prediction(market, prediction.into(), salt).send()?);
+            // The salt never goes on chain; without it the entry can't be revealed
+            println!("Salt: {}", salt.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+        }
+        Command::Reveal {
+            market,
+            prediction,
+            salt,
+        } => report(&client.reveal_prediction(market, prediction.into(), salt).send()?),
+        Command::Resolve { market, home_goals, away_goals } => {
+            report(&client.resolve_market(market, FinalScore::new(home_goals, away_goals)).send()?)
+        }
+        Command::SignResolution { market, home_goals, away_goals } => {
+            report(&client.sign_resolution(market, FinalScore::new(home_goals, away_goals)).send()?)
+        }
+        Command::CoSignResolution { market, home_goals, away_goals } => {
+            report(&client.co_sign_resolution(market, FinalScore::new(home_goals, away_goals)).send()?)
+        }
+        Command::CorrectResult { market, home_goals, away_goals } => {
+            report(&client.correct_result(market, FinalScore::new(home_goals, away_goals)).send()?)
+        }
+        Command::WithdrawTreasury { amount, recipient } => {
+            let ix = cryptoscore_factory_interface::instruction::withdraw_treasury(
+                cryptoscore_factory_interface::accounts::WithdrawTreasury::new(
+                    client.payer(),
+                    recipient.unwrap_or(client.payer()),
+                ),
+                sol_to_lamports(amount),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::RuleMisresolution {
+            market,
+            outcome,
+            claim_cap,
+            payout_cap,
+        } => {
+            let ix = cryptoscore_factory_interface::instruction::rule_misresolution(
+                cryptoscore_factory_interface::accounts::RuleMisresolution::new(client.payer(), client.payer(), market),
+                cryptoscore_factory_interface::instruction::RuleMisresolution {
+                    correct_outcome: outcome.into(),
+                    claim_cap: sol_to_lamports(claim_cap),
+                    payout_cap: sol_to_lamports(payout_cap),
+                },
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::LetItRide { market, prediction } => report(&client.roll_rewards(market, prediction.into()).send()?),
+        Command::ClaimSwapped {
+            market,
+            output_mint,
+            route,
+            min_amount_out,
+        } => report(
+            &client
+                .withdraw_rewards_swapped(market, output_mint, route::load(&route)?)
+                .min_amount_out(min_amount_out)
+                .send()?,
+        ),
+        Command::ClaimReceipt { market } => report(&client.mint_claim_receipt(market).send()?),
+        Command::ClaimInsurance { market } => report(&client.claim_insurance(market).send()?),
+        Command::InitCourt {
+            jury_size,
+            voting_period,
+            min_arbiter_stake,
+            dispute_fee,
+            slash_bps,
+            claim_cap,
+            payout_cap,
+        } => {
+            let ix = cryptoscore_governance_interface::instruction::initialize_court(
+                cryptoscore_governance_interface::accounts::InitializeCourt::new(client.payer()),
+                CourtTerms {
+                    jury_size,
+                    voting_period,
+                    min_arbiter_stake: sol_to_lamports(min_arbiter_stake),
+                    dispute_fee: sol_to_lamports(dispute_fee),
+                    slash_bps,
+                    claim_cap: sol_to_lamports(claim_cap),
+                    payout_cap: sol_to_lamports(payout_cap),
+                },
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::JoinCourt { amount } => {
+            let ix = cryptoscore_governance_interface::instruction::join_court(
+                cryptoscore_governance_interface::accounts::JoinCourt::new(client.payer()),
+                sol_to_lamports(amount),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::LeaveCourt => {
+            let ix = cryptoscore_governance_interface::instruction::leave_court(
+                cryptoscore_governance_interface::accounts::LeaveCourt::new(client.payer()),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Dispute { market, outcome } => {
+            let ix = cryptoscore_governance_interface::instruction::open_dispute(
+                cryptoscore_governance_interface::accounts::OpenDispute::new(client.payer(), market),
+                outcome.into(),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::DrawJury { market } => {
+            let court_address = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+            let court: Court = client.fetch(&court_address)?;
+            let dispute_address = find_dispute_address(&court_address, &market).0;
+            let dispute: Dispute = client.fetch(&dispute_address)?;
+            let slot_hashes = client.rpc().get_account_data(&slot_hashes::ID)?;
+            let jurors = match committed_slot_hash(&slot_hashes, dispute.draw_slot) {
+                SlotHashLookup::Hash(hash) => {
+                    let seed = jury_seed(&hash, &dispute_address);
+                    draw_jurors(&court.arbiters, &dispute.disputer, court.terms.jury_size as usize, &seed)
+                        .ok_or("not enough arbiters to draw a jury")?
+                }
+                SlotHashLookup::Pending => {
+                    return Err(format!("slot {} has not been hashed yet", dispute.draw_slot).into());
+                }
+                SlotHashLookup::Expired => Vec::new(),
+            };
+            let ix = cryptoscore_governance_interface::instruction::draw_jury(
+                cryptoscore_governance_interface::accounts::DrawJury::new(market, &jurors),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::JurorVote { market, outcome } => {
+            let ix = cryptoscore_governance_interface::instruction::cast_juror_vote(
+                cryptoscore_governance_interface::accounts::CastJurorVote::new(client.payer(), market),
+                outcome.into(),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::SettleDispute { market } => {
+            let court_address = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+            let dispute: Dispute = client.fetch(&find_dispute_address(&court_address, &market).0)?;
+            let ix = cryptoscore_governance_interface::instruction::settle_dispute(
+                cryptoscore_governance_interface::accounts::SettleDispute::new(
+                    client.payer(),
+                    market,
+                    dispute.disputer,
+                    &dispute.jurors,
+                ),
+            );
+            report(&client.send(vec![ix], &client.options())?);
+        }
+        Command::Cancel { market } => report(&client.cancel_market(market).send()?),
+        Command::Void { markets } => {
+            for market in markets {
+                match client.emergency_void(market).send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => eprintln!("{}: {}", market, err),
+                }
+            }
+        }
+        Command::Close { market } => report(&client.close_market(market).send()?),
+        Command::SettlementReport { market } => {
+            let address = find_settlement_report_address(&market).0;
+            let settlement = match client.fetch::<SettlementReport>(&address) {
+                Ok(settlement) => settlement,
+                Err(ClientError::AccountNotFound(_)) => {
+                    report(&client.compile_settlement_report(market).send()?);
+                    client.fetch(&address)?
+                }
+                Err(err) => return Err(err.into()),
+            };
+            println!("Settlement report {}\n{}", address, display::settlement_report(&settlement));
+        }
+        Command::StakePool {
+            market,
+            stake_pool,
+            min_pool_tokens,
+        } => report(&client.stake_idle_pool(market, stake_pool).min_pool_tokens(min_pool_tokens).send()?),
+        Command::UnwindPool { market, min_sol } => {
+            report(&client.unwind_idle_pool(market).min_lamports(sol_to_lamports(min_sol)).send()?)
+        }
+        Command::SampleOdds { markets, all } => {
+            let markets = if all { open_markets(&client)? } else { markets };
+            println!("Sampling {} markets", markets.len());
+
+            for market in markets {
+                match client.record_odds_sample(market).send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => eprintln!("{}: {}", market, err),
+                }
+            }
+        }
+        Command::Sweep { markets, all, platform } => {
+            let markets = if all { sweepable_markets(&client)? } else { markets };
+            println!("Sweeping {} markets", markets.len());
+
+            for market in markets {
+                let mut builder = client.sweep_unclaimed(market);
+                if let Some(platform) = platform {
+                    builder = builder.platform(platform);
+                }
+                match builder.send() {
+                    Ok(confirmed) => report(&confirmed),
+                    Err(err) => eprintln!("{}: {}", market, err),
+                }
+            }
+        }
+        Command::SeedDevnet {
+            markets_per_league,
+            players,
+            resolve,
+            player_sol,
+            seed,
+            keypair_dir,
+        } => {
+            if cli.url.contains("mainnet") {
+                return Err("Refusing to seed fixtures on mainnet".into());
+            }
+            seed::run(
+                &client,
+                &seed::SeedConfig {
+                    markets_per_league,
+                    players,
+                    resolve,
+                    player_lamports: sol_to_lamports(player_sol),
+                    seed,
+                    keypair_dir,
+                },
+            )?;
+        }
+        Command::Quote { market, prediction } => {
+            println!("{:#?}", cryptoscore_sdk::quote_join(client.rpc(), &market, prediction.into())?);
+        }
+        Command::PreviewSettlement { market, outcome } => {
+            let preview = cryptoscore_sdk::preview_settlement(client.rpc(), &market, outcome.into(), &client.payer())?;
+            println!("{:#?}", preview);
+        }
+        Command::Inspect { account } => match account {
+            InspectTarget::Factory => {
+                let address = find_factory_address().0;
+                let factory: Factory = client.fetch(&address)?;
+                println!("Factory {}\n{}", address, display::factory(&factory));
+            }
+            InspectTarget::Market { address } => {
+                println!("{:#?}", cryptoscore_sdk::fetch_market_details(client.rpc(), &address)?);
+            }
+            InspectTarget::Participant { market, user } => {
+                let address = find_participant_address(&market, &user).0;
+                let participant: Participant = client.fetch(&address)?;
+                println!("Participant {}\n{}", address, display::participant(&participant));
+            }
+            InspectTarget::Odds { market } => {
+                for sample in cryptoscore_sdk::fetch_odds_history(client.rpc(), &market)? {
+                    println!("{}", display::odds_sample(&sample));
+                }
+            }
+            InspectTarget::PendingClaims { user } => {
+                let markets = cryptoscore_sdk::fetch_pending_claims(client.rpc(), &user)?;
+                println!("{} unclaimed wins", markets.len());
+                for market in markets {
+                    println!("{}", market);
+                }
+            }
+            InspectTarget::Roster { market } => {
+                let users = cryptoscore_sdk::fetch_participant_roster(client.rpc(), &market)?;
+                println!("{} listed participants", users.len());
+                for user in users {
+                    println!("{}", user);
+                }
+            }
+            InspectTarget::WinnerProof { market, user } => {
+                match cryptoscore_sdk::fetch_winner_proof(client.rpc(), &market, &user)? {
+                    Some(proof) => println!("Winner {}\n{}", user, display::winner_proof(&proof)),
+                    None => println!("{} has no proof of winning {}", user, market),
+                }
+            }
+            InspectTarget::Proposal { id } => {
+                let address = find_proposal_address(&find_governance_address(&find_factory_address().0).0, id).0;
+                let proposal: Proposal = client.fetch(&address)?;
+                println!("Proposal {}\n{}", address, display::proposal(&proposal));
+            }
+            InspectTarget::Dispute { market } => {
+                let court_address = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+                let address = find_dispute_address(&court_address, &market).0;
+                let dispute: Dispute = client.fetch(&address)?;
+                println!("Dispute {}\n{}", address, display::dispute(&dispute));
+            }
+            InspectTarget::Points { user, epoch } => {
+                let points = cryptoscore_sdk::fetch_points(client.rpc(), &user, epoch)?;
+                println!("Points of {}\n{}", user, display::points(&points));
+            }
+            InspectTarget::Reputation { user } => {
+                let reputation = cryptoscore_sdk::fetch_reputation(client.rpc(), &user)?;
+                println!("Reputation of {}\n{}", user, display::reputation(&reputation));
+            }
+            InspectTarget::LiveScore { match_id } => {
+                let address = find_live_score_address(&find_factory_address().0, &match_id).0;
+                match client.fetch::<LiveScore>(&address) {
+                    Ok(score) => println!("Live score of {}\n{}", match_id, display::live_score(&score)),
+                    Err(ClientError::AccountNotFound(_)) => println!("No live score reported for {}", match_id),
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            InspectTarget::Kickoffs { days } => {
+                let today = kickoff_day(client.rpc().get_block_time(client.rpc().get_slot()?)?);
+                let last = today.saturating_add(days.saturating_sub(1));
+                let factory = find_factory_address().0;
+                let markets = cryptoscore_sdk::fetch_kickoff_markets(client.rpc(), &factory, today..=last)?;
+                println!("{} markets kicking off", markets.len());
+                for market in markets {
+                    println!("{}", market);
+                }
+            }
+        },
+        Command::DecodeEvents { signature } => {
+            for event in client.transaction_events(&signature)? {
+                println!("{}", display::event(&event));
+            }
+        }
+        Command::DecodeError { signature } => {
+            let logs = client.transaction_logs(&signature)?;
+            match ErrorDecoder::default().decode_context(&logs) {
+                Some(context) => println!("{}", context),
+                None => println!("No CryptoScore program error in {}", signature),
+            }
+        }
+        Command::LookupTable { action } => match action {
+            LookupTableAction::Create { addresses } => {
+                let mut entries = common_addresses();
+                for address in addresses {
+                    if !entries.contains(&address) {
+                        entries.push(address);
+                    }
+                }
+                let table = client.create_lookup_table(&entries)?;
+                println!("Created lookup table {} with {} addresses", table, entries.len());
+            }
+            LookupTableAction::Extend { table, addresses } => {
+                client.extend_lookup_table(table, &addresses)?;
+                println!("Added {} addresses to {}", addresses.len(), table);
+            }
+            LookupTableAction::Show { table } => {
+                for (index, address) in client.fetch_lookup_table(&table)?.addresses.iter().enumerate() {
+                    println!("{:>3} {}", index, address);
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Resolved or claiming markets whose claim window closed, as seen by the signer
+fn sweepable_markets(client: &CryptoscoreClient<Keypair>) -> Result<Vec<Pubkey>, Box<dyn Error>> {
+    let now = client.rpc().get_block_time(client.rpc().get_slot()?)?;
+    let query = MarketQuery {
+        sort_by: SortOption::EndingSoon,
+        page_size: u32::MAX,
+        ..MarketQuery::default()
+    };
+    let sweepable = [MarketStatus::Resolved as u8, MarketStatus::Claiming as u8];
+
+    Ok(cryptoscore_sdk::fetch_all_markets(client.rpc(), &query)?
+        .into_iter()
+        .filter(|summary| sweepable.contains(&summary.status))
+        .filter(|summary| summary.end_time.saturating_add(CLAIM_WINDOW_SECONDS) <= now)
+        .map(|summary| summary.market_address)
+        .collect())
+}
+
+/// Markets still taking entries
+fn open_markets(client: &CryptoscoreClient<Keypair>) -> Result<Vec<Pubkey>, Box<dyn Error>> {
+    let now = client.rpc().get_block_time(client.rpc().get_slot()?)?;
+    let query = MarketQuery {
+        status: Some(MarketStatus::Open),
+        page_size: u32::MAX,
+        ..MarketQuery::default()
+    };
+
+    Ok(cryptoscore_sdk::fetch_all_markets(client.rpc(), &query)?
+        .into_iter()
+        .filter(|summary| summary.kickoff_time > now)
+        .map(|summary| summary.market_address)
+        .collect())
+}
+
+/// Execute or refund every join queued on `market`, oldest first
+///
+/// Each executed join lowers the payout later entries on its side would see,
+/// so conditions are checked against locally updated counts.
+fn settle_queue(client: &CryptoscoreClient<Keypair>, market_address: Pubkey) -> Result<(), Box<dyn Error>> {
+    let mut market: Market = client.fetch(&market_address)?;
+    let now = client.rpc().get_block_time(client.rpc().get_slot()?)?;
+    let open = market.status == MarketStatus::Open && now < market.kickoff_time;
+    if open && now < market.kickoff_time.saturating_sub(QUEUED_JOIN_LEAD_SECONDS) {
+        return Err("Queued joins aren't due until five minutes before kickoff".into());
+    }
+
+    let queued = cryptoscore_sdk::fetch_queued_joins(client.rpc(), &market_address)?;
+    println!("Settling {} queued joins", queued.len());
+
+    for (_, queued) in queued {
+        let side_count = match queued.prediction {
+            MatchOutcome::Home => &mut market.home_count,
+            MatchOutcome::Draw => &mut market.draw_count,
+            MatchOutcome::Away => &mut market.away_count,
+        };
+        let payout_bps = implied_payout_bps(market.total_pool, *side_count, queued.entry_fee).unwrap_or(0);
+        let result = if open && payout_bps >= queued.min_payout_bps {
+            *side_count += 1;
+            market.total_pool += queued.entry_fee;
+            client.execute_queued_join(market_address, queued.user).send()
+        } else {
+            client.refund_queued_join(market_address, queued.user).send()
+        };
+        match result {
+            Ok(confirmed) => report(&confirmed),
+            Err(err) => eprintln!("{}: {}", queued.user, err),
+        }
+    }
+
+    Ok(())
+}
+
+/// Markets per `resolve_markets_batch` transaction, keeping it under the size limit
+const RESOLVE_BATCH_MARKETS: usize = 3;
+/// Compute units requested for each of those transactions
+const RESOLVE_BATCH_COMPUTE_UNITS: u32 = 600_000;
+
+/// Resolve the markets results batch `batch_id` covers that are still open
+fn resolve_batch(client: &CryptoscoreClient<Keypair>, batch_id: u64) -> Result<(), Box<dyn Error>> {
+    let factory = find_factory_address().0;
+    let batch: ResultsBatch = client.fetch(&find_results_batch_address(&factory, batch_id).0)?;
+
+    let mut markets = Vec::new();
+    for result in &batch.results {
+        let address = find_market_address(&factory, &result.match_id).0;
+        match client.fetch::<Market>(&address) {
+            Ok(market) if !market.status.is_resolved() && !market.status.is_cancelled() => markets.push(address),
+            Ok(_) => {}
+            Err(err) => eprintln!("{}: {}", result.match_id, err),
+        }
+    }
+    println!("Resolving {} markets", markets.len());
+
+    for chunk in markets.chunks(RESOLVE_BATCH_MARKETS) {
+        let builder = client.resolve_markets_batch(batch_id, chunk.to_vec());
+        report(&builder.compute_unit_limit(RESOLVE_BATCH_COMPUTE_UNITS).send()?);
+    }
+
+    Ok(())
+}
+
+/// Parse a `MATCH_ID=HOME-AWAY` final score
+fn parse_result(result: &str) -> Result<MatchResult, String> {
+    let (match_id, score) = result.rsplit_once('=').ok_or("expected MATCH_ID=HOME-AWAY")?;
+    let (home, away) = score.split_once('-').ok_or("expected MATCH_ID=HOME-AWAY")?;
+    let home_goals = home.parse().map_err(|err| format!("home goals: {}", err))?;
+    let away_goals = away.parse().map_err(|err| format!("away goals: {}", err))?;
+    Ok(MatchResult {
+        match_id: match_id.to_string(),
+        score: FinalScore::new(home_goals, away_goals),
+    })
+}
+
+/// Parse a `HOME-AWAY` grid scoreline or `other`
+fn parse_scoreline(scoreline: &str) -> Result<Scoreline, String> {
+    if scoreline.eq_ignore_ascii_case("other") {
+        return Ok(Scoreline::AnyOther);
+    }
+    let (home, away) = scoreline.split_once('-').ok_or("expected HOME-AWAY or other")?;
+    let home = home.parse().map_err(|err| format!("home goals: {}", err))?;
+    let away = away.parse().map_err(|err| format!("away goals: {}", err))?;
+    let scoreline = Scoreline::Exact { home, away };
+    if scoreline.bucket().is_none() {
+        return Err(format!("scores above {} a side are picked as other", SCORE_GRID_MAX_GOALS));
+    }
+    Ok(scoreline)
+}
+
+/// Parse `N` hex-encoded bytes, e.g. a salt or an oracle address
+fn parse_hex<const N: usize>(hex: &str) -> Result<[u8; N], String> {
+    if hex.len() != N * 2 || !hex.is_ascii() {
+        return Err(format!("expected {} hex characters", N * 2));
+    }
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|err| err.to_string())?;
+    }
+    Ok(bytes)
+}
+
+fn parse_discount_tier(tier: &str) -> Result<DiscountTier, String> {
+    let parts: Vec<&str> = tier.split(':').collect();
+    let [min_reputation, min_badges, discount_bps] = parts[..] else {
+        return Err("expected MIN_REPUTATION:MIN_BADGES:DISCOUNT_BPS".to_string());
+    };
+    Ok(DiscountTier {
+        min_reputation: min_reputation.parse().map_err(|err| format!("min reputation: {}", err))?,
+        min_badges: min_badges.parse().map_err(|err| format!("min badges: {}", err))?,
+        discount_bps: discount_bps.parse().map_err(|err| format!("discount bps: {}", err))?,
+    })
+}
+
+/// The CryptoScore program error behind a failed send, if any
+fn program_error(err: &(dyn Error + 'static)) -> Option<String> {
+    let decoder = ErrorDecoder::default();
+    match err.downcast_ref::<ClientError>()? {
+        ClientError::Simulation { logs, .. } => decoder.decode_context(logs).map(|context| context.to_string()),
+        ClientError::Rpc(err) => decoder.decode_client_error(err).map(|err| err.to_string()),
+        _ => None,
+    }
+}
+
+fn report(confirmed: &Confirmed) {
+    println!("Confirmed {}", confirmed.signature);
+    for event in &confirmed.events {
+        println!("  {}", display::event(event));
+    }
+}
+
+fn cluster_url(url: &str) -> String {
+    match url {
+        "localnet" | "localhost" => "http://127.0.0.1:8899",
+        "devnet" => "https://api.devnet.solana.com",
+        "testnet" => "https://api.testnet.solana.com",
+        "mainnet-beta" | "mainnet" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn load_keypair(path: Option<PathBuf>) -> Result<Keypair, Box<dyn Error>> {
+    let path = match path {
+        Some(path) => path,
+        None => {
+            let home = std::env::var("HOME").map_err(|_| "HOME is not set; pass --keypair")?;
+            PathBuf::from(home).join(".config/solana/id.json")
+        }
+    };
+
+    read_keypair_file(&path)
+        .map_err(|err| format!("Failed to read keypair {}: {}", path.display(), err).into())
+}
@@ -0,0 +1,55 @@
+//! Jupiter swap routes saved from its swap-instructions API.
+
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SwapInstructions {
+    swap_instruction: RouteInstruction,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RouteInstruction {
+    program_id: String,
+    accounts: Vec<RouteAccount>,
+    /// Base64-encoded instruction data
+    data: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RouteAccount {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+/// The swap instruction of a saved swap-instructions response
+pub fn load(path: &Path) -> Result<Instruction, Box<dyn Error>> {
+    let response: SwapInstructions = serde_json::from_reader(File::open(path)?)?;
+    let route = response.swap_instruction;
+    Ok(Instruction {
+        program_id: route.program_id.parse::<Pubkey>()?,
+        accounts: route
+            .accounts
+            .into_iter()
+            .map(|account| {
+                Ok(AccountMeta {
+                    pubkey: account.pubkey.parse::<Pubkey>()?,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?,
+        data: STANDARD.decode(route.data)?,
+    })
+}
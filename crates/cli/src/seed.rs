@@ -0,0 +1,228 @@
+//! Devnet fixture seeder: markets across leagues, joins from generated players
+//! and mock oracle results for a resolved subset.
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+use cryptoscore_client::{CryptoscoreClient, FinalScore, MatchOutcome};
+use cryptoscore_common::pda::{find_factory_address, find_market_address};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{write_keypair_file, Keypair};
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+
+use crate::display;
+
+const LEAGUES: &[(&str, &[&str])] = &[
+    ("EPL", &["ARS", "AVL", "CHE", "LIV", "MCI", "MUN", "NEW", "TOT"]),
+    ("LALIGA", &["ATM", "BAR", "BET", "RMA", "RSO", "SEV", "VIL"]),
+    ("SERIEA", &["ATA", "INT", "JUV", "LAZ", "MIL", "NAP", "ROM"]),
+    ("BUND", &["BAY", "BVB", "LEV", "RBL", "SGE", "VFB"]),
+    ("LIGUE1", &["LIL", "LYO", "MAR", "MON", "PSG", "REN"]),
+];
+
+const ENTRY_FEES: &[u64] = &[
+    LAMPORTS_PER_SOL / 100,
+    LAMPORTS_PER_SOL / 20,
+    LAMPORTS_PER_SOL / 10,
+    LAMPORTS_PER_SOL / 4,
+];
+
+/// Transfers per funding transaction
+const FUNDING_BATCH: usize = 16;
+/// Time given to create and join quick markets before their kickoff
+const QUICK_KICKOFF_SECONDS: i64 = 120;
+/// Quick markets end this long after kickoff so they can be resolved
+const QUICK_MATCH_SECONDS: i64 = 30;
+
+pub struct SeedConfig {
+    pub markets_per_league: u32,
+    pub players: u32,
+    /// Markets scheduled to end shortly and resolved with mock results
+    pub resolve: u32,
+    pub player_lamports: u64,
+    pub seed: u64,
+    /// Where to write the generated player keypairs
+    pub keypair_dir: Option<PathBuf>,
+}
+
+/// Goals a side scores in a mock result, one drawn uniformly; home sides
+/// score a little more often, as in real leagues
+const HOME_GOALS: &[u8] = &[0, 0, 1, 1, 1, 2, 2, 3, 4];
+const AWAY_GOALS: &[u8] = &[0, 0, 0, 1, 1, 1, 2, 2, 3];
+
+/// Stand-in for a results feed: draws final scores with roughly real-world
+/// home/draw/away frequencies, reproducibly from the seed
+struct MockOracle {
+    rng: StdRng,
+}
+
+impl MockOracle {
+    fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed.rotate_left(32)),
+        }
+    }
+
+    fn result(&mut self) -> FinalScore {
+        let home_goals = *HOME_GOALS.choose(&mut self.rng).unwrap_or(&0);
+        let away_goals = *AWAY_GOALS.choose(&mut self.rng).unwrap_or(&0);
+        FinalScore::new(home_goals, away_goals)
+    }
+}
+
+struct SeededMarket {
+    address: Pubkey,
+    match_id: String,
+    end_time: i64,
+    quick: bool,
+}
+
+pub fn run(client: &CryptoscoreClient<Keypair>, config: &SeedConfig) -> Result<(), Box<dyn Error>> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let now = client.rpc().get_block_time(client.rpc().get_slot()?)?;
+    // Keep match IDs unique across runs against the same factory
+    let run_id = format!("{:x}", now);
+
+    let players: Vec<Keypair> = (0..config.players).map(|_| Keypair::new()).collect();
+    fund_players(client, &players, config.player_lamports)?;
+
+    // Markets come from their own creator: fees can't go to the creator as
+    // platform, and the signer is usually the factory authority
+    let creator = Keypair::new();
+    fund_players(client, std::slice::from_ref(&creator), config.player_lamports)?;
+    let creator_client = player_client(client, &creator);
+    if let Some(dir) = &config.keypair_dir {
+        std::fs::create_dir_all(dir)?;
+        for (i, player) in players.iter().enumerate() {
+            write_keypair_file(player, dir.join(format!("player-{}.json", i)))?;
+        }
+        println!("Wrote {} player keypairs to {}", players.len(), dir.display());
+    }
+
+    // Regular markets first, then the quick ones, so their short countdown
+    // to kickoff starts as late as possible
+    let total = config.markets_per_league as usize * LEAGUES.len();
+    let quick_count = (config.resolve as usize).min(total);
+    let factory = find_factory_address().0;
+    let mut markets = Vec::new();
+    for index in 0..total {
+        let (league, teams) = LEAGUES[index % LEAGUES.len()];
+        let pair: Vec<&&str> = teams.choose_multiple(&mut rng, 2).collect();
+        let match_id = format!("{}-{}-{}-{}{}", league, pair[0], pair[1], run_id, index);
+
+        let quick = index >= total - quick_count;
+        let kickoff = if quick {
+            client.rpc().get_block_time(client.rpc().get_slot()?)? + QUICK_KICKOFF_SECONDS
+        } else {
+            now + rng.gen_range(1..=14) * 24 * 60 * 60 + rng.gen_range(12..=21) * 60 * 60
+        };
+        let end_time = kickoff + if quick { QUICK_MATCH_SECONDS } else { 2 * 60 * 60 };
+
+        let mut builder = creator_client
+            .create_market(match_id.clone())
+            .entry_fee(*ENTRY_FEES.choose(&mut rng).unwrap())
+            .schedule(kickoff, end_time);
+        if rng.gen_bool(0.15) {
+            builder = builder.private();
+        }
+        match builder.send() {
+            Ok(_) => {
+                let address = find_market_address(&factory, &match_id).0;
+                println!("Created {} {}", match_id, address);
+                markets.push(SeededMarket {
+                    address,
+                    match_id,
+                    end_time,
+                    quick,
+                });
+            }
+            Err(err) => eprintln!("{}: {}", match_id, err),
+        }
+    }
+
+    let player_clients: Vec<CryptoscoreClient<Keypair>> =
+        players.iter().map(|player| player_client(client, player)).collect();
+
+    // Quick markets first, so every join lands before their kickoff
+    let mut joins = 0;
+    for market in markets.iter().rev() {
+        for player_client in &player_clients {
+            if !market.quick && !rng.gen_bool(0.5) {
+                continue;
+            }
+            let prediction = match rng.gen_range(0..3) {
+                0 => MatchOutcome::Home,
+                1 => MatchOutcome::Draw,
+                _ => MatchOutcome::Away,
+            };
+            match player_client.join_market(market.address, prediction).send() {
+                Ok(_) => joins += 1,
+                Err(err) => eprintln!("{} joining {}: {}", player_client.payer(), market.match_id, err),
+            }
+        }
+    }
+    println!("Joined {} times from {} players", joins, players.len());
+
+    let quick: Vec<&SeededMarket> = markets.iter().filter(|market| market.quick).collect();
+    if let Some(last_end) = quick.iter().map(|market| market.end_time).max() {
+        wait_until(client.rpc(), last_end)?;
+    }
+
+    let mut oracle = MockOracle::new(config.seed);
+    for market in quick {
+        let score = oracle.result();
+        println!("Oracle: {} -> {}-{}", market.match_id, score.home_goals, score.away_goals);
+        match creator_client.resolve_market(market.address, score).send() {
+            Ok(confirmed) => {
+                for event in &confirmed.events {
+                    println!("  {}", display::event(event));
+                }
+            }
+            Err(err) => eprintln!("{}: {}", market.match_id, err),
+        }
+    }
+
+    Ok(())
+}
+
+/// Client signing as `keypair` over the same cluster and options as `client`
+fn player_client(client: &CryptoscoreClient<Keypair>, keypair: &Keypair) -> CryptoscoreClient<Keypair> {
+    CryptoscoreClient::new(
+        RpcClient::new_with_commitment(client.rpc().url(), client.rpc().commitment()),
+        keypair.insecure_clone(),
+    )
+    .with_options(client.options())
+}
+
+fn fund_players(client: &CryptoscoreClient<Keypair>, players: &[Keypair], lamports: u64) -> Result<(), Box<dyn Error>> {
+    let payer = client.payer();
+    for batch in players.chunks(FUNDING_BATCH) {
+        let transfers = batch
+            .iter()
+            .map(|player| system_instruction::transfer(&payer, &player.pubkey(), lamports))
+            .collect();
+        client.send(transfers, &client.options())?;
+    }
+    println!("Funded {} accounts with {} each", players.len(), display::sol(lamports));
+    Ok(())
+}
+
+/// Block until the cluster clock passes `timestamp`
+fn wait_until(rpc: &RpcClient, timestamp: i64) -> Result<(), Box<dyn Error>> {
+    loop {
+        let now = rpc.get_block_time(rpc.get_slot()?)?;
+        if now > timestamp {
+            return Ok(());
+        }
+        println!("Waiting {}s for quick markets to end", timestamp - now + 1);
+        sleep(Duration::from_secs((timestamp - now + 1).min(10) as u64));
+    }
+}
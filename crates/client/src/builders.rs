@@ -0,0 +1,2562 @@
+//! Transaction builders with automatic PDA derivation.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::attestation::{attestation_message, ed25519_instruction_data};
+use cryptoscore_common::commitment::{note_hash, prediction_commitment, SALT_LEN};
+use cryptoscore_common::pda::{
+    find_charity_pledge_address, find_factory_address, find_in_play_lock_address, find_market_address,
+    find_market_chain_address, find_participant_roster_address, find_platform_activity_address, find_platform_address,
+    find_platform_factory_address, find_receipt_policy_address, find_results_signer_address, find_roster_page_address,
+    find_token_gate_address, find_voucher_campaign_address, find_winners_root_address, find_yield_position_address,
+};
+use cryptoscore_common::swap::{create_associated_token_account, NATIVE_MINT};
+use cryptoscore_common::tags::{Tag, TagCategory, Tags, NO_TAG, TAG_SLOTS};
+use cryptoscore_common::token_gate::{find_associated_token_address, find_metadata_address, TokenGateRule};
+use cryptoscore_common::{
+    FinalScore, HandicapStyle, MatchOutcome, MatchResult, OutcomeSet, PropKind, Scoreline, MAX_CO_ADMINS,
+    ROSTER_PAGE_SIZE,
+};
+use cryptoscore_factory_interface::instruction::SeriesFixture;
+use cryptoscore_factory_interface::{MarketRegistry, ReceiptPolicy, ResultsSigner};
+use cryptoscore_market_interface::accounts::RosterSlot;
+use cryptoscore_market_interface::{
+    CharityPledge, InPlayLock, Market, MarketChain, ParticipantRoster, RosterPage, TokenGate, WinnersRoot,
+    YieldPosition,
+};
+use solana_sdk::address_lookup_table::AddressLookupTableAccount;
+use solana_sdk::ed25519_program;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+
+use crate::{ClientError, Confirmed, CryptoscoreClient, PriorityFee, SendOptions};
+
+/// Per-transaction overrides of the client's `SendOptions`
+macro_rules! send_options {
+    () => {
+        send_options!(setters);
+
+        pub fn send(self) -> Result<Confirmed, ClientError> {
+            let options = self.options;
+            let instructions = self.instructions()?;
+            self.client.send(instructions, &options)
+        }
+    };
+    (setters) => {
+        /// Attach a compute unit price in micro-lamports
+        pub fn priority_fee(mut self, micro_lamports: u64) -> Self {
+            self.options.priority_fee = PriorityFee::Fixed(micro_lamports);
+            self
+        }
+
+        /// Price at a percentile of recent fees for the accounts this transaction writes
+        pub fn priority_fee_percentile(mut self, percentile: u8, max_micro_lamports: u64) -> Self {
+            self.options.priority_fee = PriorityFee::Percentile {
+                percentile,
+                max_micro_lamports,
+            };
+            self
+        }
+
+        /// Times to re-sign and resend after the blockhash expires unconfirmed
+        pub fn resubmissions(mut self, resubmissions: u32) -> Self {
+            self.options.resubmissions = resubmissions;
+            self
+        }
+
+        pub fn compute_unit_limit(mut self, units: u32) -> Self {
+            self.options.compute_unit_limit = Some(units);
+            self
+        }
+
+        /// Send without the simulation preflight
+        pub fn skip_simulation(mut self) -> Self {
+            self.options.simulate = false;
+            self
+        }
+    };
+}
+
+pub struct CreateMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    factory: Pubkey,
+    args: cryptoscore_market_interface::instruction::InitializeMarket,
+    tags: Tags,
+    handicap: Option<cryptoscore_market_interface::instruction::SetHandicap>,
+}
+
+impl<'a, S: Signer> CreateMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, match_id: String) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            factory: find_factory_address().0,
+            args: market_args(match_id),
+            tags: [NO_TAG; TAG_SLOTS],
+            handicap: None,
+        }
+    }
+
+    /// Create the market on an operator's platform instead of the default one
+    pub fn platform(mut self, platform_id: &str) -> Self {
+        self.factory = find_platform_factory_address(&find_platform_address(platform_id).0).0;
+        self
+    }
+
+    /// Fill `category`'s discovery slot; the factory must have registered `tag` for it
+    pub fn tag(mut self, category: TagCategory, tag: Tag) -> Self {
+        self.tags[category.slot()] = tag;
+        self
+    }
+
+    pub fn entry_fee(mut self, lamports: u64) -> Self {
+        self.args.entry_fee = lamports;
+        self
+    }
+
+    pub fn schedule(mut self, kickoff_time: i64, end_time: i64) -> Self {
+        self.args.kickoff_time = kickoff_time;
+        self.args.end_time = end_time;
+        self
+    }
+
+    pub fn private(mut self) -> Self {
+        self.args.is_public = false;
+        self
+    }
+
+    /// Offer only `outcome_set`'s outcomes, e.g. `OutcomeSet::WinLose` for sports without draws
+    pub fn outcome_set(mut self, outcome_set: OutcomeSet) -> Self {
+        self.args.outcome_set = outcome_set;
+        self
+    }
+
+    /// Keep resolution to the creator for `seconds` after the market ends,
+    /// within the factory's resolution grace policy
+    pub fn resolution_grace(mut self, seconds: i64) -> Self {
+        self.args.resolution_grace_seconds = seconds;
+        self
+    }
+
+    /// Settle the market on a `style` handicap, quoting `home_line` and
+    /// `away_line` half goals for each side, e.g. -3 and 3 for home -1.5
+    pub fn handicap(mut self, style: HandicapStyle, home_line: i8, away_line: i8) -> Self {
+        self.handicap = Some(cryptoscore_market_interface::instruction::SetHandicap {
+            style,
+            home_line,
+            away_line,
+        });
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let creator = self.client.payer();
+        let accounts = cryptoscore_factory_interface::accounts::CreateMarket::on_factory(
+            self.factory,
+            creator,
+            &self.args.match_id,
+            self.args.kickoff_time,
+        );
+
+        let register = cryptoscore_factory_interface::instruction::create_market(
+            accounts.tagged(&self.tags),
+            cryptoscore_factory_interface::instruction::CreateMarket {
+                match_id: self.args.match_id.clone(),
+                entry_fee: self.args.entry_fee,
+                kickoff_time: self.args.kickoff_time,
+                end_time: self.args.end_time,
+                is_public: self.args.is_public,
+                tags: self.tags,
+            },
+        );
+        let initialize = initialize_market_ix(self.factory, creator, &self.args);
+        let mut instructions = vec![register, initialize];
+
+        if let Some(handicap) = &self.handicap {
+            let market = find_market_address(&self.factory, &self.args.match_id).0;
+            instructions.push(cryptoscore_market_interface::instruction::set_handicap(
+                cryptoscore_market_interface::accounts::SetHandicap::new(market, creator),
+                handicap.style,
+                handicap.home_line,
+                handicap.away_line,
+            ));
+        }
+
+        Ok(instructions)
+    }
+
+    send_options!();
+}
+
+/// Several markets sharing an entry fee, visibility, outcome set, resolution grace period and tags, listed in one
+/// instruction
+///
+/// Attach lookup tables (see `lookup_tables::common_addresses`) to fit a
+/// full matchweek under the transaction size limit.
+pub struct CreateMarketSeriesBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    args: cryptoscore_factory_interface::instruction::CreateMarketSeries,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl<'a, S: Signer> CreateMarketSeriesBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            args: cryptoscore_factory_interface::instruction::CreateMarketSeries {
+                fixtures: Vec::new(),
+                entry_fee: 0,
+                is_public: true,
+                outcome_set: OutcomeSet::WinDrawWin,
+                resolution_grace_seconds: 0,
+                tags: [NO_TAG; TAG_SLOTS],
+            },
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    pub fn fixture(mut self, match_id: impl Into<String>, kickoff_time: i64, end_time: i64) -> Self {
+        self.args.fixtures.push(SeriesFixture {
+            match_id: match_id.into(),
+            kickoff_time,
+            end_time,
+        });
+        self
+    }
+
+    /// Fill `category`'s discovery slot on every market in the series
+    pub fn tag(mut self, category: TagCategory, tag: Tag) -> Self {
+        self.args.tags[category.slot()] = tag;
+        self
+    }
+
+    pub fn entry_fee(mut self, lamports: u64) -> Self {
+        self.args.entry_fee = lamports;
+        self
+    }
+
+    pub fn private(mut self) -> Self {
+        self.args.is_public = false;
+        self
+    }
+
+    /// Offer only `outcome_set`'s outcomes, e.g. `OutcomeSet::WinLose` for sports without draws
+    pub fn outcome_set(mut self, outcome_set: OutcomeSet) -> Self {
+        self.args.outcome_set = outcome_set;
+        self
+    }
+
+    /// Keep resolution to the creator for `seconds` after the market ends,
+    /// within the factory's resolution grace policy
+    pub fn resolution_grace(mut self, seconds: i64) -> Self {
+        self.args.resolution_grace_seconds = seconds;
+        self
+    }
+
+    pub fn lookup_table(mut self, table: AddressLookupTableAccount) -> Self {
+        self.lookup_tables.push(table);
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let accounts = cryptoscore_factory_interface::accounts::CreateMarketSeries::new(
+            self.client.payer(),
+            self.args.fixtures.iter().map(|fixture| (fixture.match_id.as_str(), fixture.kickoff_time)),
+        )
+        .tagged(&self.args.tags);
+
+        Ok(vec![cryptoscore_factory_interface::instruction::create_market_series(
+            accounts,
+            cryptoscore_factory_interface::instruction::CreateMarketSeries {
+                fixtures: self.args.fixtures.clone(),
+                entry_fee: self.args.entry_fee,
+                is_public: self.args.is_public,
+                outcome_set: self.args.outcome_set,
+                resolution_grace_seconds: self.args.resolution_grace_seconds,
+                tags: self.args.tags,
+            },
+        )])
+    }
+
+    send_options!(setters);
+
+    pub fn send(self) -> Result<Confirmed, ClientError> {
+        let instructions = self.instructions()?;
+        self.client.send_v0(instructions, &self.lookup_tables, &self.options)
+    }
+}
+
+pub struct InitializeMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    args: cryptoscore_market_interface::instruction::InitializeMarket,
+    factory: Option<Pubkey>,
+}
+
+impl<'a, S: Signer> InitializeMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, match_id: String) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            args: market_args(match_id),
+            factory: None,
+        }
+    }
+
+    pub fn entry_fee(mut self, lamports: u64) -> Self {
+        self.args.entry_fee = lamports;
+        self
+    }
+
+    pub fn schedule(mut self, kickoff_time: i64, end_time: i64) -> Self {
+        self.args.kickoff_time = kickoff_time;
+        self.args.end_time = end_time;
+        self
+    }
+
+    pub fn private(mut self) -> Self {
+        self.args.is_public = false;
+        self
+    }
+
+    /// Offer only `outcome_set`'s outcomes, e.g. `OutcomeSet::WinLose` for sports without draws
+    pub fn outcome_set(mut self, outcome_set: OutcomeSet) -> Self {
+        self.args.outcome_set = outcome_set;
+        self
+    }
+
+    /// Keep resolution to the creator for `seconds` after the market ends,
+    /// within the factory's resolution grace policy
+    pub fn resolution_grace(mut self, seconds: i64) -> Self {
+        self.args.resolution_grace_seconds = seconds;
+        self
+    }
+
+    pub fn factory(mut self, factory: Pubkey) -> Self {
+        self.factory = Some(factory);
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let factory = self.factory.unwrap_or_else(|| find_factory_address().0);
+        Ok(vec![initialize_market_ix(factory, self.client.payer(), &self.args)])
+    }
+
+    send_options!();
+}
+
+pub struct JoinMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    prediction: MatchOutcome,
+    gate_nft: Option<Pubkey>,
+    note_hash: [u8; 32],
+    voucher_campaign_id: Option<u64>,
+}
+
+impl<'a, S: Signer> JoinMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, prediction: MatchOutcome) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            prediction,
+            gate_nft: None,
+            note_hash: [0; 32],
+            voucher_campaign_id: None,
+        }
+    }
+
+    /// Attach the hash of `note`, e.g. a written rationale for the
+    /// prediction, to publish and check against the participant later
+    pub fn note(mut self, note: impl AsRef<[u8]>) -> Self {
+        self.note_hash = note_hash(&self.market, &self.client.payer(), note.as_ref());
+        self
+    }
+
+    /// NFT mint proving membership of the collection the market is gated on
+    pub fn gate_nft(mut self, mint: Pubkey) -> Self {
+        self.gate_nft = Some(mint);
+        self
+    }
+
+    /// Have the payer's voucher from the factory's campaign `campaign_id` pay
+    /// the entry fee
+    pub fn voucher(mut self, campaign_id: u64) -> Self {
+        self.voucher_campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Fetches the market for the factory it was created under, its token
+    /// gate, if any, to prove the payer's holdings, its participant roster, if
+    /// any, to list them, and its in-play lock, if any, to pass the live score
+    /// it follows
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let user = self.client.payer();
+        let factory = self.client.fetch::<Market>(&self.market)?.factory;
+        let mut defaults = cryptoscore_market_interface::accounts::JoinMarket::on_factory(factory, self.market, user);
+        if let Some(campaign_id) = self.voucher_campaign_id {
+            defaults = defaults.with_voucher(find_voucher_campaign_address(&factory, campaign_id).0);
+        }
+        Ok(vec![cryptoscore_market_interface::instruction::join_market(
+            cryptoscore_market_interface::accounts::JoinMarket {
+                live_score: followed_live_score(self.client, &self.market)?.unwrap_or(defaults.live_score),
+                roster: roster_slot(self.client, &self.market)?,
+                gate_proof: gate_proof(self.client, &self.market, &user, self.gate_nft)?,
+                ..defaults
+            },
+            self.prediction.clone(),
+            self.note_hash,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Escrows a join that only executes if the payout holds until just before kickoff
+pub struct QueueJoinBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    prediction: MatchOutcome,
+    min_payout_bps: u64,
+}
+
+impl<'a, S: Signer> QueueJoinBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        min_payout_bps: u64,
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            prediction,
+            min_payout_bps,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::queue_join(
+            cryptoscore_market_interface::accounts::QueueJoin::new(self.market, self.client.payer()),
+            self.prediction.clone(),
+            self.min_payout_bps,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Executes another user's queued join, reimbursing the payer the participant rent
+pub struct ExecuteQueuedJoinBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    user: Pubkey,
+}
+
+impl<'a, S: Signer> ExecuteQueuedJoinBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            user,
+        }
+    }
+
+    /// Fetches the market's participant roster, if any, to list the user
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::execute_queued_join(
+            cryptoscore_market_interface::accounts::ExecuteQueuedJoin {
+                roster: roster_slot(self.client, &self.market)?,
+                ..cryptoscore_market_interface::accounts::ExecuteQueuedJoin::new(
+                    self.market,
+                    self.user,
+                    self.client.payer(),
+                )
+            },
+        )])
+    }
+
+    send_options!();
+}
+
+/// Returns a queued join's escrow to its user
+pub struct RefundQueuedJoinBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    user: Pubkey,
+}
+
+impl<'a, S: Signer> RefundQueuedJoinBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            user,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::refund_queued_join(
+            cryptoscore_market_interface::accounts::RefundQueuedJoin::new(self.market, self.user, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Follows a leader whose predictions the payer can then copy
+pub struct FollowBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    leader: Pubkey,
+}
+
+impl<'a, S: Signer> FollowBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, leader: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            leader,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::follow(
+            cryptoscore_market_interface::accounts::FollowLeader::new(self.client.payer(), self.leader),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct UnfollowBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    leader: Pubkey,
+}
+
+impl<'a, S: Signer> UnfollowBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, leader: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            leader,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::unfollow(
+            cryptoscore_market_interface::accounts::UnfollowLeader::new(self.client.payer(), self.leader),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Joins a market on a followed leader's prediction
+pub struct CopyJoinBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    leader: Pubkey,
+    gate_nft: Option<Pubkey>,
+}
+
+impl<'a, S: Signer> CopyJoinBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, leader: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            leader,
+            gate_nft: None,
+        }
+    }
+
+    /// NFT mint proving membership of the collection the market is gated on
+    pub fn gate_nft(mut self, mint: Pubkey) -> Self {
+        self.gate_nft = Some(mint);
+        self
+    }
+
+    /// Fetches the market's token gate, if any, to prove the payer's holdings,
+    /// its participant roster, if any, to list them, and its in-play lock, if
+    /// any, to pass the live score it follows
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let user = self.client.payer();
+        let defaults = cryptoscore_market_interface::accounts::CopyJoin::new(self.market, user, self.leader);
+        Ok(vec![cryptoscore_market_interface::instruction::copy_join(
+            cryptoscore_market_interface::accounts::CopyJoin {
+                live_score: followed_live_score(self.client, &self.market)?.unwrap_or(defaults.live_score),
+                roster: roster_slot(self.client, &self.market)?,
+                gate_proof: gate_proof(self.client, &self.market, &user, self.gate_nft)?,
+                ..defaults
+            },
+        )])
+    }
+
+    send_options!();
+}
+
+/// Replaces a market's co-admins (creator only)
+pub struct SetCoAdminsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    co_admins: [Pubkey; MAX_CO_ADMINS],
+}
+
+impl<'a, S: Signer> SetCoAdminsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, co_admins: [Pubkey; MAX_CO_ADMINS]) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            co_admins,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::set_co_admins(
+            cryptoscore_market_interface::accounts::SetCoAdmins {
+                market: self.market,
+                creator: self.client.payer(),
+            },
+            self.co_admins,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Limits a market to holders of a token or NFT collection (creator or co-admin)
+pub struct SetTokenGateBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    rule: TokenGateRule,
+}
+
+impl<'a, S: Signer> SetTokenGateBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, rule: TokenGateRule) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            rule,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::set_token_gate(
+            cryptoscore_market_interface::accounts::SetTokenGate::new(self.market, self.client.payer()),
+            self.rule.clone(),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Switches a private market to committed predictions (creator or co-admin)
+pub struct EnableCommitRevealBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> EnableCommitRevealBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::enable_commit_reveal(
+            cryptoscore_market_interface::accounts::EnableCommitReveal::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Lists a market's participants on-chain, page by page (creator or co-admin)
+pub struct EnableParticipantRosterBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> EnableParticipantRosterBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::enable_participant_roster(
+            cryptoscore_market_interface::accounts::EnableParticipantRoster::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Gives a market's fees and a share of its prize pool to a charity at
+/// resolution (creator or co-admin)
+pub struct PledgeToCharityBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    charity: Pubkey,
+    pool_share_bps: u16,
+}
+
+impl<'a, S: Signer> PledgeToCharityBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, charity: Pubkey, pool_share_bps: u16) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            charity,
+            pool_share_bps,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::pledge_to_charity(
+            cryptoscore_market_interface::accounts::PledgeToCharity::new(self.market, self.client.payer()),
+            self.charity,
+            self.pool_share_bps,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Pays a share of a market's creator fee to its earliest correct entry
+/// (creator or co-admin)
+pub struct OfferFirstCorrectBonusBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    bonus_bps: u16,
+}
+
+impl<'a, S: Signer> OfferFirstCorrectBonusBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, bonus_bps: u16) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            bonus_bps,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::offer_first_correct_bonus(
+            cryptoscore_market_interface::accounts::OfferFirstCorrectBonus::new(self.market, self.client.payer()),
+            self.bonus_bps,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Chains a market to a follow-up market its winners can roll their rewards
+/// into (creator or co-admin)
+pub struct SetFollowUpMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    follow_up: Pubkey,
+}
+
+impl<'a, S: Signer> SetFollowUpMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, follow_up: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            follow_up,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::set_follow_up_market(
+            cryptoscore_market_interface::accounts::SetFollowUpMarket::new(
+                self.market,
+                self.follow_up,
+                self.client.payer(),
+            ),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Keeps a market taking joins in play until a minute mark or the first goal
+pub struct EnableInPlayBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    lock_minute: u16,
+    lock_on_goal: bool,
+}
+
+impl<'a, S: Signer> EnableInPlayBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, lock_minute: u16) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            lock_minute,
+            lock_on_goal: false,
+        }
+    }
+
+    /// Also stop joins at the first goal
+    pub fn lock_on_goal(mut self) -> Self {
+        self.lock_on_goal = true;
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::enable_in_play(
+            cryptoscore_market_interface::accounts::EnableInPlay::new(self.market, self.client.payer()),
+            self.lock_minute,
+            self.lock_on_goal,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Makes a market a player prop market on a list of player IDs (creator or co-admin)
+pub struct ListPropPlayersBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    kind: PropKind,
+    player_ids: Vec<u32>,
+}
+
+impl<'a, S: Signer> ListPropPlayersBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, kind: PropKind, player_ids: Vec<u32>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            kind,
+            player_ids,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::list_prop_players(
+            cryptoscore_market_interface::accounts::ListPropPlayers::new(self.market, self.client.payer()),
+            self.kind,
+            self.player_ids.clone(),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Enters a player prop market on one of its listed players
+pub struct JoinPropBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    player_id: u32,
+    gate_nft: Option<Pubkey>,
+}
+
+impl<'a, S: Signer> JoinPropBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, player_id: u32) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            player_id,
+            gate_nft: None,
+        }
+    }
+
+    /// NFT mint proving membership of the collection the market is gated on
+    pub fn gate_nft(mut self, mint: Pubkey) -> Self {
+        self.gate_nft = Some(mint);
+        self
+    }
+
+    /// Fetches the market's token gate, if any, to prove the payer's holdings,
+    /// and its in-play lock, if any, to pass the live score it follows
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let user = self.client.payer();
+        let defaults = cryptoscore_market_interface::accounts::JoinProp::new(self.market, user);
+        Ok(vec![cryptoscore_market_interface::instruction::join_prop(
+            cryptoscore_market_interface::accounts::JoinProp {
+                live_score: followed_live_score(self.client, &self.market)?.unwrap_or(defaults.live_score),
+                gate_proof: gate_proof(self.client, &self.market, &user, self.gate_nft)?,
+                ..defaults
+            },
+            self.player_id,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Makes a market a correct-score market (creator or co-admin)
+pub struct EnableCorrectScoreBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> EnableCorrectScoreBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::enable_correct_score(
+            cryptoscore_market_interface::accounts::EnableCorrectScore::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Enters a correct-score market on a final score
+pub struct JoinCorrectScoreBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    scoreline: Scoreline,
+    gate_nft: Option<Pubkey>,
+}
+
+impl<'a, S: Signer> JoinCorrectScoreBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, scoreline: Scoreline) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            scoreline,
+            gate_nft: None,
+        }
+    }
+
+    /// NFT mint proving membership of the collection the market is gated on
+    pub fn gate_nft(mut self, mint: Pubkey) -> Self {
+        self.gate_nft = Some(mint);
+        self
+    }
+
+    /// Fetches the market's token gate, if any, to prove the payer's holdings,
+    /// and its in-play lock, if any, to pass the live score it follows
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let user = self.client.payer();
+        let defaults = cryptoscore_market_interface::accounts::JoinCorrectScore::new(self.market, user);
+        Ok(vec![cryptoscore_market_interface::instruction::join_correct_score(
+            cryptoscore_market_interface::accounts::JoinCorrectScore {
+                live_score: followed_live_score(self.client, &self.market)?.unwrap_or(defaults.live_score),
+                gate_proof: gate_proof(self.client, &self.market, &user, self.gate_nft)?,
+                ..defaults
+            },
+            self.scoreline,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Enters a commit–reveal market with a hidden prediction
+pub struct CommitPredictionBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    prediction: MatchOutcome,
+    salt: [u8; SALT_LEN],
+}
+
+impl<'a, S: Signer> CommitPredictionBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            prediction,
+            salt,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let user = self.client.payer();
+        Ok(vec![cryptoscore_market_interface::instruction::commit_prediction(
+            cryptoscore_market_interface::accounts::CommitPrediction::new(self.market, user),
+            prediction_commitment(&self.market, &user, &self.prediction, &self.salt),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Reveals a committed prediction after kickoff
+pub struct RevealPredictionBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    prediction: MatchOutcome,
+    salt: [u8; SALT_LEN],
+}
+
+impl<'a, S: Signer> RevealPredictionBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            prediction,
+            salt,
+        }
+    }
+
+    /// Fetches the market's participant roster, if any, to list the payer
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::reveal_prediction(
+            cryptoscore_market_interface::accounts::RevealPrediction {
+                roster: roster_slot(self.client, &self.market)?,
+                ..cryptoscore_market_interface::accounts::RevealPrediction::new(self.market, self.client.payer())
+            },
+            self.prediction.clone(),
+            self.salt,
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct ResolveMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    score: FinalScore,
+}
+
+impl<'a, S: Signer> ResolveMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, score: FinalScore) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            score,
+        }
+    }
+
+    /// Fetches the market to find its creator, resolving as a participant if
+    /// the payer isn't it, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let resolver = self.client.payer();
+        let mut accounts = if resolver == market.creator {
+            cryptoscore_market_interface::accounts::ResolveMarket::by_creator(
+                self.market,
+                market.creator,
+                market.factory,
+            )
+        } else {
+            cryptoscore_market_interface::accounts::ResolveMarket::by_participant(
+                self.market,
+                resolver,
+                market.creator,
+                market.factory,
+            )
+        };
+        accounts.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_market(
+            accounts,
+            self.score.home_goals,
+            self.score.away_goals,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Relays a result signed by the factory's results signer
+pub struct ResolveWithAttestationBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    score: FinalScore,
+    attested_at: i64,
+    signature: Signature,
+}
+
+impl<'a, S: Signer> ResolveWithAttestationBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        market: Pubkey,
+        score: FinalScore,
+        attested_at: i64,
+        signature: Signature,
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            score,
+            attested_at,
+            signature,
+        }
+    }
+
+    /// Fetches the market, any charity pledge and its factory's results
+    /// signer; the ed25519 check comes first so the program can find it
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+        let results_signer: ResultsSigner = self.client.fetch(&find_results_signer_address(&market.factory).0)?;
+
+        let message = attestation_message(&market.match_id, &self.score, self.attested_at);
+        let verify = Instruction {
+            program_id: ed25519_program::id(),
+            accounts: vec![],
+            data: ed25519_instruction_data(&results_signer.signer, &self.signature.into(), &message),
+        };
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveWithAttestation::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+        let resolve = cryptoscore_market_interface::instruction::resolve_with_attestation(
+            accounts,
+            self.score.home_goals,
+            self.score.away_goals,
+            self.attested_at,
+        );
+
+        Ok(vec![verify, resolve])
+    }
+
+    send_options!();
+}
+
+/// Relays a match result bridged through Wormhole from the factory's
+/// registered source-chain oracle
+pub struct ResolveWithVaaBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    posted_vaa: Pubkey,
+}
+
+impl<'a, S: Signer> ResolveWithVaaBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, posted_vaa: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            posted_vaa,
+        }
+    }
+
+    /// Fetches the market to find its creator and factory, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveWithVaa::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+            self.posted_vaa,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_with_vaa(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Relays the final live score of a market's match
+pub struct ResolveWithLiveScoreBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> ResolveWithLiveScoreBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its creator, factory and match, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveWithLiveScore::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_with_live_score(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Resolves a player prop market by player ID as the factory's results signer
+pub struct ResolvePropBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    player_id: Option<u32>,
+}
+
+impl<'a, S: Signer> ResolvePropBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, player_id: Option<u32>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            player_id,
+        }
+    }
+
+    /// Fetches the market to find its creator and factory, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveProp::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_prop(accounts, self.player_id)])
+    }
+
+    send_options!();
+}
+
+/// Records a standings checkpoint on an outright market as the factory's results signer
+pub struct RecordOutrightCheckpointBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    eliminated: Vec<u32>,
+}
+
+impl<'a, S: Signer> RecordOutrightCheckpointBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, eliminated: Vec<u32>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            eliminated,
+        }
+    }
+
+    /// Fetches the market to find its factory
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let accounts = cryptoscore_market_interface::accounts::RecordOutrightCheckpoint::new(
+            self.market,
+            self.client.payer(),
+            market.factory,
+        );
+
+        Ok(vec![cryptoscore_market_interface::instruction::record_outright_checkpoint(
+            accounts,
+            self.eliminated.clone(),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Settles a decided outright market on its last team standing
+pub struct SettleOutrightEarlyBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> SettleOutrightEarlyBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its creator and factory, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::SettleOutrightEarly::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::settle_outright_early(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Relays the final live score of a correct-score market's match
+pub struct ResolveCorrectScoreBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> ResolveCorrectScoreBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its creator, factory and match, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveCorrectScore::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_correct_score(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Relays the final live score of a handicap market's match
+pub struct ResolveHandicapBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> ResolveHandicapBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its creator, factory and match, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveHandicap::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_handicap(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Publishes a batch of match results as the factory's results signer
+pub struct PublishResultsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    batch_id: u64,
+    results: Vec<MatchResult>,
+}
+
+impl<'a, S: Signer> PublishResultsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, batch_id: u64, results: Vec<MatchResult>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            batch_id,
+            results,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_factory_interface::instruction::publish_results(
+            cryptoscore_factory_interface::accounts::PublishResults::new(self.client.payer(), self.batch_id),
+            self.batch_id,
+            self.results.clone(),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Reports a match's score as the factory's results signer
+pub struct UpdateLiveScoreBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    match_id: String,
+    home_goals: u8,
+    away_goals: u8,
+    minute: u16,
+    is_final: bool,
+}
+
+impl<'a, S: Signer> UpdateLiveScoreBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        match_id: String,
+        (home_goals, away_goals): (u8, u8),
+        minute: u16,
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            match_id,
+            home_goals,
+            away_goals,
+            minute,
+            is_final: false,
+        }
+    }
+
+    /// Mark this the final score, letting markets on the match resolve from it
+    pub fn final_score(mut self) -> Self {
+        self.is_final = true;
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_factory_interface::instruction::update_live_score(
+            cryptoscore_factory_interface::accounts::UpdateLiveScore::new(self.client.payer(), &self.match_id),
+            self.match_id.clone(),
+            self.home_goals,
+            self.away_goals,
+            self.minute,
+            self.is_final,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Resolves several markets from one published results batch
+///
+/// Each market adds its full set of resolution accounts, so more than a
+/// few markets need a raised compute unit limit, or a `batch()` with
+/// lookup tables to fit the transaction size limit.
+pub struct ResolveMarketsBatchBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    batch_id: u64,
+    markets: Vec<Pubkey>,
+}
+
+impl<'a, S: Signer> ResolveMarketsBatchBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, batch_id: u64, markets: Vec<Pubkey>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            batch_id,
+            markets,
+        }
+    }
+
+    /// Fetches each market for its creator and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let mut markets = Vec::with_capacity(self.markets.len());
+        for address in &self.markets {
+            let market: Market = self.client.fetch(address)?;
+            markets.push((*address, market.creator));
+        }
+
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveMarketsBatch::new(
+            find_factory_address().0,
+            self.batch_id,
+            self.client.payer(),
+            markets,
+        );
+        for resolution in &mut accounts.markets {
+            resolution.charity = pledged_charity(self.client, &resolution.market)?;
+        }
+
+        Ok(vec![cryptoscore_market_interface::instruction::resolve_markets_batch(accounts)])
+    }
+
+    send_options!();
+}
+
+/// Signs a final score as one of a high-stakes market's co-signers
+pub struct SignResolutionBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    score: FinalScore,
+}
+
+impl<'a, S: Signer> SignResolutionBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, score: FinalScore) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            score,
+        }
+    }
+
+    /// Fetches the market to find its factory
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::sign_resolution(
+            cryptoscore_market_interface::accounts::SignResolution::new(
+                self.market,
+                self.client.payer(),
+                market.factory,
+            ),
+            self.score.home_goals,
+            self.score.away_goals,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Settles a high-stakes market on a final score another co-signer already signed
+pub struct CoSignResolutionBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    score: FinalScore,
+}
+
+impl<'a, S: Signer> CoSignResolutionBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, score: FinalScore) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            score,
+        }
+    }
+
+    /// Fetches the market to find its creator and factory, and any charity pledge
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let mut accounts = cryptoscore_market_interface::accounts::CoSignResolution::new(
+            self.market,
+            self.client.payer(),
+            market.creator,
+            market.factory,
+        );
+        accounts.resolution.charity = pledged_charity(self.client, &self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::co_sign_resolution(
+            accounts,
+            self.score.home_goals,
+            self.score.away_goals,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Corrects the final score of a resolved market as its factory's results signer
+pub struct CorrectResultBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    score: FinalScore,
+}
+
+impl<'a, S: Signer> CorrectResultBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, score: FinalScore) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            score,
+        }
+    }
+
+    /// Fetches the market to find its factory
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        let accounts = cryptoscore_market_interface::accounts::CorrectResult::new(
+            self.market,
+            market.factory,
+            self.client.payer(),
+        );
+
+        Ok(vec![cryptoscore_market_interface::instruction::correct_result(
+            accounts,
+            self.score.home_goals,
+            self.score.away_goals,
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct SettleRebateEntryBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    user: Pubkey,
+}
+
+impl<'a, S: Signer> SettleRebateEntryBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            user,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let keeper = self.client.payer();
+        Ok(vec![cryptoscore_market_interface::instruction::settle_rebate_entry(
+            cryptoscore_market_interface::accounts::SettleRebateEntry::new(self.market, self.user, keeper),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct RecordPendingClaimBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    user: Pubkey,
+}
+
+impl<'a, S: Signer> RecordPendingClaimBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            user,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let cranker = self.client.payer();
+        Ok(vec![cryptoscore_market_interface::instruction::record_pending_claim(
+            cryptoscore_market_interface::accounts::RecordPendingClaim::new(self.market, self.user, cranker),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Roster entries folded into a winners root per transaction, keeping it
+/// within the legacy transaction size
+pub const WINNERS_ROOT_BATCH: usize = 16;
+
+/// Folds the next roster entries of a resolved market into its winners root,
+/// publishing it once the roster is exhausted
+pub struct BuildWinnersRootBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> BuildWinnersRootBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the root's progress and the roster page it has reached to
+    /// pass up to `WINNERS_ROOT_BATCH` of its entries
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let cursor = match self.client.fetch::<WinnersRoot>(&find_winners_root_address(&self.market).0) {
+            Ok(winners_root) => winners_root.cursor,
+            Err(ClientError::AccountNotFound(_)) => 0,
+            Err(err) => return Err(err),
+        };
+        let roster: ParticipantRoster = self.client.fetch(&find_participant_roster_address(&self.market).0)?;
+
+        let mut users = vec![];
+        if cursor < roster.count {
+            let page: RosterPage =
+                self.client.fetch(&find_roster_page_address(&self.market, cursor / ROSTER_PAGE_SIZE as u32).0)?;
+            let first = cursor as usize % ROSTER_PAGE_SIZE;
+            users.extend(page.users[first..page.len as usize].iter().take(WINNERS_ROOT_BATCH));
+        }
+
+        Ok(vec![cryptoscore_market_interface::instruction::build_winners_root(
+            cryptoscore_market_interface::accounts::BuildWinnersRoot::new(
+                self.market,
+                self.client.payer(),
+                cursor,
+                &users,
+            ),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct WithdrawRewardsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    receipt: bool,
+}
+
+impl<'a, S: Signer> WithdrawRewardsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            receipt: false,
+        }
+    }
+
+    /// Also mint a compressed NFT receipt of the reward, paid for by the payer
+    pub fn with_receipt(mut self) -> Self {
+        self.receipt = true;
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let mut instructions = vec![cryptoscore_market_interface::instruction::withdraw_rewards(
+            cryptoscore_market_interface::accounts::WithdrawRewards::new(self.market, self.client.payer()),
+        )];
+        if self.receipt {
+            instructions.push(claim_receipt(self.client, &self.market)?);
+        }
+        Ok(instructions)
+    }
+
+    send_options!();
+}
+
+/// Rolls a winning reward into the market's follow-up market as an entry on a prediction
+pub struct RollRewardsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    prediction: MatchOutcome,
+}
+
+impl<'a, S: Signer> RollRewardsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, prediction: MatchOutcome) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            prediction,
+        }
+    }
+
+    /// Fetches the market chain to find the follow-up, and the follow-up to
+    /// find its factory and participant roster, if any
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let chain: MarketChain = self.client.fetch(&find_market_chain_address(&self.market).0)?;
+        let follow_up: Market = self.client.fetch(&chain.follow_up)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::roll_rewards(
+            cryptoscore_market_interface::accounts::RollRewards {
+                platform_activity: find_platform_activity_address(&follow_up.factory).0,
+                roster: roster_slot(self.client, &chain.follow_up)?,
+                ..cryptoscore_market_interface::accounts::RollRewards::new(
+                    self.market,
+                    chain.follow_up,
+                    self.client.payer(),
+                )
+            },
+            self.prediction.clone(),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Withdraws a winning reward swapped into one of the factory's payout tokens
+///
+/// `route` is the Jupiter swap instruction quoted off-chain for the reward,
+/// e.g. the `swapInstruction` of Jupiter's swap-instructions API, swapping
+/// from the payer's wrapped SOL account into their `output_mint` account.
+/// Both associated token accounts are opened first if missing.
+pub struct WithdrawRewardsSwappedBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    output_mint: Pubkey,
+    route: Instruction,
+    min_amount_out: u64,
+}
+
+impl<'a, S: Signer> WithdrawRewardsSwappedBuilder<'a, S> {
+    pub(crate) fn new(
+        client: &'a CryptoscoreClient<S>,
+        market: Pubkey,
+        output_mint: Pubkey,
+        route: Instruction,
+    ) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            output_mint,
+            route,
+            min_amount_out: 0,
+        }
+    }
+
+    /// Fail unless the swap pays out at least this many base units of the output mint
+    pub fn min_amount_out(mut self, min_amount_out: u64) -> Self {
+        self.min_amount_out = min_amount_out;
+        self
+    }
+
+    /// Fetches the market to find the factory whose payout swap policy applies
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+        let user = self.client.payer();
+
+        Ok(vec![
+            create_associated_token_account(&user, &user, &NATIVE_MINT),
+            create_associated_token_account(&user, &user, &self.output_mint),
+            cryptoscore_market_interface::instruction::withdraw_rewards_swapped(
+                cryptoscore_market_interface::accounts::WithdrawRewardsSwapped::new(
+                    self.market,
+                    market.factory,
+                    user,
+                    &self.output_mint,
+                    self.route.accounts.clone(),
+                ),
+                self.route.data.clone(),
+                self.min_amount_out,
+            ),
+        ])
+    }
+
+    send_options!();
+}
+
+/// Mints a compressed NFT receipt of the payer's withdrawn reward or refund
+/// into the factory's receipt tree
+pub struct MintClaimReceiptBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> MintClaimReceiptBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market and its factory's receipt policy to find the tree
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![claim_receipt(self.client, &self.market)?])
+    }
+
+    send_options!();
+}
+
+/// Withdraws a player prop pick's reward, or its refund if the market was cancelled
+pub struct WithdrawPropRewardsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> WithdrawPropRewardsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::withdraw_prop_rewards(
+            cryptoscore_market_interface::accounts::WithdrawPropRewards::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Withdraws a correct-score pick's reward, or its refund if the market was cancelled
+pub struct WithdrawScoreRewardsBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> WithdrawScoreRewardsBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::withdraw_score_rewards(
+            cryptoscore_market_interface::accounts::WithdrawScoreRewards::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct SweepUnclaimedBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    platform: Option<Pubkey>,
+}
+
+impl<'a, S: Signer> SweepUnclaimedBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            platform: None,
+        }
+    }
+
+    /// Account receiving the swept funds; defaults to the factory authority
+    pub fn platform(mut self, platform: Pubkey) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+        let authority = self.client.payer();
+
+        Ok(vec![cryptoscore_market_interface::instruction::sweep_unclaimed(
+            cryptoscore_market_interface::accounts::SweepUnclaimed::new(
+                self.market,
+                market.factory,
+                authority,
+                self.platform.unwrap_or(authority),
+            ),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct CancelMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> CancelMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its factory
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::cancel_market(
+            cryptoscore_market_interface::accounts::CancelMarket::new(self.market, market.factory, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct ClaimRefundBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    receipt: bool,
+}
+
+impl<'a, S: Signer> ClaimRefundBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            receipt: false,
+        }
+    }
+
+    /// Also mint a compressed NFT receipt of the refund, paid for by the payer
+    pub fn with_receipt(mut self) -> Self {
+        self.receipt = true;
+        self
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let mut instructions = vec![cryptoscore_market_interface::instruction::claim_refund(
+            cryptoscore_market_interface::accounts::ClaimRefund::new(self.market, self.client.payer()),
+        )];
+        if self.receipt {
+            instructions.push(claim_receipt(self.client, &self.market)?);
+        }
+        Ok(instructions)
+    }
+
+    send_options!();
+}
+
+pub struct ClaimInsuranceBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> ClaimInsuranceBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_factory_interface::instruction::insurance_claim(
+            cryptoscore_factory_interface::accounts::ClaimInsurance::new(self.client.payer(), self.market),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct CloseMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> CloseMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::close_market(
+            cryptoscore_market_interface::accounts::CloseMarket::new(self.market),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Compiles a swept or closed market's settlement report, paid for by the payer
+pub struct CompileSettlementReportBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> CompileSettlementReportBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::compile_settlement_report(
+            cryptoscore_market_interface::accounts::CompileSettlementReport::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Kill switch sending a market to refunds (factory authority only)
+pub struct EmergencyVoidBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> EmergencyVoidBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    /// Fetches the market to find its factory
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::emergency_void(
+            cryptoscore_market_interface::accounts::EmergencyVoid::new(
+                self.market,
+                market.factory,
+                self.client.payer(),
+            ),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Checks a market's vault covers what it owes; simulate it to monitor solvency
+pub struct AssertSolvencyBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> AssertSolvencyBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::assert_solvency(
+            cryptoscore_market_interface::accounts::AssertSolvency { market: self.market },
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct MigrateMarketBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> MigrateMarketBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::migrate_market(
+            cryptoscore_market_interface::accounts::MigrateMarket::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct MigrateParticipantBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    participant: Pubkey,
+}
+
+impl<'a, S: Signer> MigrateParticipantBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, participant: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            participant,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::migrate_participant(
+            cryptoscore_market_interface::accounts::MigrateParticipant::new(self.participant, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+pub struct InvalidateRegistryBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market_registry: Pubkey,
+}
+
+impl<'a, S: Signer> InvalidateRegistryBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market_registry: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market_registry,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let registry: MarketRegistry = self.client.fetch(&self.market_registry)?;
+        Ok(vec![cryptoscore_factory_interface::instruction::invalidate_registry(
+            cryptoscore_factory_interface::accounts::InvalidateRegistry::new(
+                registry.factory,
+                registry.creator,
+                &registry.match_id,
+            ),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Stakes a market's pool in an SPL stake pool between kickoff and the end of
+/// the match (factory authority only)
+///
+/// The stake pool must already be updated for the current epoch.
+pub struct StakeIdlePoolBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    stake_pool: Pubkey,
+    min_pool_tokens: u64,
+}
+
+impl<'a, S: Signer> StakeIdlePoolBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey, stake_pool: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            stake_pool,
+            min_pool_tokens: 0,
+        }
+    }
+
+    /// Fail unless the deposit mints at least this many pool tokens
+    pub fn min_pool_tokens(mut self, min_pool_tokens: u64) -> Self {
+        self.min_pool_tokens = min_pool_tokens;
+        self
+    }
+
+    /// Fetches the market to find its factory and the stake pool for its accounts
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+        let pool = self.client.fetch_stake_pool(&self.stake_pool)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::stake_idle_pool(
+            cryptoscore_market_interface::accounts::StakeIdlePool::new(
+                self.market,
+                market.factory,
+                self.client.payer(),
+                &pool,
+            ),
+            self.min_pool_tokens,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Brings a staked pool back into its market's vault, by the factory authority
+/// at any time or by anyone once the match has ended
+pub struct UnwindIdlePoolBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+    min_lamports: u64,
+}
+
+impl<'a, S: Signer> UnwindIdlePoolBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+            min_lamports: 0,
+        }
+    }
+
+    /// Fail unless at least this many lamports come back to the vault
+    pub fn min_lamports(mut self, min_lamports: u64) -> Self {
+        self.min_lamports = min_lamports;
+        self
+    }
+
+    /// Fetches the market, its yield position and the stake pool holding it
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        let market: Market = self.client.fetch(&self.market)?;
+        let position: YieldPosition = self.client.fetch(&find_yield_position_address(&self.market).0)?;
+        let pool = self.client.fetch_stake_pool(&position.stake_pool)?;
+
+        Ok(vec![cryptoscore_market_interface::instruction::unwind_idle_pool(
+            cryptoscore_market_interface::accounts::UnwindIdlePool::new(
+                self.market,
+                market.factory,
+                self.client.payer(),
+                &pool,
+            ),
+            self.min_lamports,
+        )])
+    }
+
+    send_options!();
+}
+
+/// Records a market's current prediction distribution in its odds history
+pub struct RecordOddsSampleBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    market: Pubkey,
+}
+
+impl<'a, S: Signer> RecordOddsSampleBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>, market: Pubkey) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            market,
+        }
+    }
+
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ClientError> {
+        Ok(vec![cryptoscore_market_interface::instruction::record_odds_sample(
+            cryptoscore_market_interface::accounts::RecordOddsSample::new(self.market, self.client.payer()),
+        )])
+    }
+
+    send_options!();
+}
+
+/// Instructions from several builders sent together as one v0 transaction
+///
+/// Attach lookup tables (see `lookup_tables::common_addresses`) so batches
+/// such as multi-market claims fit under the transaction size limit.
+pub struct BatchBuilder<'a, S: Signer> {
+    client: &'a CryptoscoreClient<S>,
+    options: SendOptions,
+    instructions: Vec<Instruction>,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl<'a, S: Signer> BatchBuilder<'a, S> {
+    pub(crate) fn new(client: &'a CryptoscoreClient<S>) -> Self {
+        Self {
+            client,
+            options: client.options(),
+            instructions: Vec::new(),
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    /// Append instructions, typically another builder's `instructions()`
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, instructions: impl IntoIterator<Item = Instruction>) -> Self {
+        self.instructions.extend(instructions);
+        self
+    }
+
+    pub fn lookup_table(mut self, table: AddressLookupTableAccount) -> Self {
+        self.lookup_tables.push(table);
+        self
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    send_options!(setters);
+
+    pub fn send(self) -> Result<Confirmed, ClientError> {
+        self.client.send_v0(self.instructions, &self.lookup_tables, &self.options)
+    }
+}
+
+fn market_args(match_id: String) -> cryptoscore_market_interface::instruction::InitializeMarket {
+    cryptoscore_market_interface::instruction::InitializeMarket {
+        match_id,
+        entry_fee: 0,
+        kickoff_time: 0,
+        end_time: 0,
+        is_public: true,
+        outcome_set: OutcomeSet::WinDrawWin,
+        resolution_grace_seconds: 0,
+    }
+}
+
+fn initialize_market_ix(
+    factory: Pubkey,
+    creator: Pubkey,
+    args: &cryptoscore_market_interface::instruction::InitializeMarket,
+) -> Instruction {
+    cryptoscore_market_interface::instruction::initialize_market(
+        cryptoscore_market_interface::accounts::InitializeMarket::new(factory, creator, &args.match_id),
+        cryptoscore_market_interface::instruction::InitializeMarket {
+            match_id: args.match_id.clone(),
+            entry_fee: args.entry_fee,
+            kickoff_time: args.kickoff_time,
+            end_time: args.end_time,
+            is_public: args.is_public,
+            outcome_set: args.outcome_set,
+            resolution_grace_seconds: args.resolution_grace_seconds,
+        },
+    )
+}
+
+/// Accounts proving `user` may join `market`: none unless it's token-gated,
+/// else the user's associated token account for the gated mint or, for
+/// collection gates, for `nft` followed by the NFT's metadata
+fn gate_proof<S: Signer>(
+    client: &CryptoscoreClient<S>,
+    market: &Pubkey,
+    user: &Pubkey,
+    nft: Option<Pubkey>,
+) -> Result<Vec<Pubkey>, ClientError> {
+    let gate: TokenGate = match client.fetch(&find_token_gate_address(market).0) {
+        Ok(gate) => gate,
+        Err(ClientError::AccountNotFound(_)) => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    Ok(match gate.rule {
+        TokenGateRule::Token { mint, .. } => vec![find_associated_token_address(user, &mint).0],
+        TokenGateRule::Collection { .. } => {
+            let nft = nft.ok_or(ClientError::MissingGateNft(*market))?;
+            vec![find_associated_token_address(user, &nft).0, find_metadata_address(&nft).0]
+        }
+    })
+}
+
+/// Roster accounts listing a new participant of `market`: the page its
+/// roster's count falls on, or the first page if it has no roster
+fn roster_slot<S: Signer>(client: &CryptoscoreClient<S>, market: &Pubkey) -> Result<RosterSlot, ClientError> {
+    let roster: ParticipantRoster = match client.fetch(&find_participant_roster_address(market).0) {
+        Ok(roster) => roster,
+        Err(ClientError::AccountNotFound(_)) => return Ok(RosterSlot::new(*market)),
+        Err(err) => return Err(err),
+    };
+
+    Ok(RosterSlot::at_page(*market, roster.count / ROSTER_PAGE_SIZE as u32))
+}
+
+/// Live score a market's in-play joins are checked against, if its creator enabled in-play joins
+fn followed_live_score<S: Signer>(
+    client: &CryptoscoreClient<S>,
+    market: &Pubkey,
+) -> Result<Option<Pubkey>, ClientError> {
+    match client.fetch::<InPlayLock>(&find_in_play_lock_address(market).0) {
+        Ok(lock) => Ok(Some(lock.live_score)),
+        Err(ClientError::AccountNotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Receipt mint for the payer's claim on `market`, into the tree of its factory's receipt policy
+fn claim_receipt<S: Signer>(client: &CryptoscoreClient<S>, market: &Pubkey) -> Result<Instruction, ClientError> {
+    let factory = client.fetch::<Market>(market)?.factory;
+    let policy: ReceiptPolicy = client.fetch(&find_receipt_policy_address(&factory).0)?;
+
+    Ok(cryptoscore_market_interface::instruction::mint_claim_receipt(
+        cryptoscore_market_interface::accounts::MintClaimReceipt::new(
+            *market,
+            factory,
+            client.payer(),
+            policy.merkle_tree,
+        ),
+    ))
+}
+
+/// Charity a market's resolution has to pay, if its creator pledged one
+fn pledged_charity<S: Signer>(client: &CryptoscoreClient<S>, market: &Pubkey) -> Result<Option<Pubkey>, ClientError> {
+    match client.fetch::<CharityPledge>(&find_charity_pledge_address(market).0) {
+        Ok(pledge) => Ok(Some(pledge.charity)),
+        Err(ClientError::AccountNotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use solana_sdk::message::CompileError;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::SignerError;
+use solana_sdk::transaction::TransactionError;
+
+/// Errors returned while building, simulating or sending transactions
+#[derive(Debug)]
+pub enum ClientError {
+    /// RPC request failed
+    Rpc(solana_client::client_error::ClientError),
+    /// Preflight simulation rejected the transaction
+    Simulation {
+        err: TransactionError,
+        logs: Vec<String>,
+    },
+    /// Account data did not deserialize as the expected type
+    Deserialize(Pubkey, anchor_lang::error::Error),
+    /// Requested account does not exist
+    AccountNotFound(Pubkey),
+    /// Account is not an address lookup table
+    InvalidLookupTable(Pubkey),
+    /// Account is not an SPL stake pool
+    InvalidStakePool(Pubkey),
+    /// Instructions could not be compiled into a v0 message
+    Compile(CompileError),
+    /// Transaction could not be signed
+    Signing(SignerError),
+    /// Transaction landed but failed on-chain
+    TransactionFailed {
+        signature: Signature,
+        err: TransactionError,
+    },
+    /// Every blockhash expired before the transaction was confirmed
+    BlockhashExpired { attempts: u32 },
+    /// Market is gated on an NFT collection and no NFT was given to prove holdings with
+    MissingGateNft(Pubkey),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Rpc(err) => write!(f, "RPC error: {}", err),
+            ClientError::Simulation { err, logs } => {
+                write!(f, "Simulation failed: {}", err)?;
+                for log in logs {
+                    write!(f, "\n  {}", log)?;
+                }
+                Ok(())
+            }
+            ClientError::Deserialize(address, err) => {
+                write!(f, "Failed to deserialize account {}: {}", address, err)
+            }
+            ClientError::AccountNotFound(address) => write!(f, "Account {} not found", address),
+            ClientError::InvalidLookupTable(address) => {
+                write!(f, "Account {} is not an address lookup table", address)
+            }
+            ClientError::InvalidStakePool(address) => write!(f, "Account {} is not an SPL stake pool", address),
+            ClientError::Compile(err) => write!(f, "Failed to compile message: {}", err),
+            ClientError::Signing(err) => write!(f, "Failed to sign transaction: {}", err),
+            ClientError::TransactionFailed { signature, err } => {
+                write!(f, "Transaction {} failed: {}", signature, err)
+            }
+            ClientError::BlockhashExpired { attempts } => {
+                write!(f, "Transaction not confirmed after {} attempts; blockhash expired", attempts)
+            }
+            ClientError::MissingGateNft(market) => {
+                write!(f, "Market {} is gated on an NFT collection; name the NFT to join with", market)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<solana_client::client_error::ClientError> for ClientError {
+    fn from(err: solana_client::client_error::ClientError) -> Self {
+        ClientError::Rpc(err)
+    }
+}
+
+impl From<CompileError> for ClientError {
+    fn from(err: CompileError) -> Self {
+        ClientError::Compile(err)
+    }
+}
+
+impl From<SignerError> for ClientError {
+    fn from(err: SignerError) -> Self {
+        ClientError::Signing(err)
+    }
+}
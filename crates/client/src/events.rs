@@ -0,0 +1,316 @@
+//! Typed decoding of factory, market and governance events from transaction logs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cryptoscore_factory_interface::events::{
+    FeeDiscountPaid, InsuranceClaimPaid, InsuranceFunded, KeeperTipPaid, LiveScoreUpdated, MarketCreated,
+    MarketRegistryInvalidated, MisresolutionRuled, PlatformFeeCollected, PlatformParamSet, ResultsPublished,
+    StreakRebatePaid, TreasuryWithdrawn, VoucherRedeemed,
+};
+use cryptoscore_governance_interface::events::{
+    DisputeOpened, DisputeSettled, JuryDrawn, JurorVoted, ProposalCreated, ProposalExecuted, VoteCast,
+};
+use cryptoscore_market_interface::events::{
+    CharityDonated, CharityPledged, ClaimReceiptMinted, CoAdminsUpdated, CopyJoined, CorrectScoreEnabled,
+    CorrectScoreResolved, FeesDistributed, HandicapResolved, HandicapSet, InPlayEnabled, JoinQueued, MarketCancelled,
+    MarketClosed, MarketResolved, OutrightCheckpointed, PoolStaked, PoolUnwound, PredictionCommitted, PredictionMade,
+    PropPicked, PropPlayersListed, PropResolved, QueuedJoinRefunded, RefundClaimed, ResolutionCoSigned,
+    ResolutionSigned, ResultAttested, ResultBridged, ResultCorrected, RewardClaimed, RewardRolled, RewardSwapped,
+    ScorePicked, SettlementReportCompiled, SolvencyViolation, UnclaimedSwept, WinnersRootPublished,
+};
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+pub enum CryptoscoreEvent {
+    MarketCreated(MarketCreated),
+    PredictionMade(PredictionMade),
+    MarketResolved(MarketResolved),
+    ResultAttested(ResultAttested),
+    ResultBridged(ResultBridged),
+    ResultCorrected(ResultCorrected),
+    RewardClaimed(RewardClaimed),
+    RewardRolled(RewardRolled),
+    RewardSwapped(RewardSwapped),
+    ClaimReceiptMinted(ClaimReceiptMinted),
+    FeesDistributed(FeesDistributed),
+    UnclaimedSwept(UnclaimedSwept),
+    MarketCancelled(MarketCancelled),
+    RefundClaimed(RefundClaimed),
+    MarketClosed(MarketClosed),
+    SettlementReportCompiled(SettlementReportCompiled),
+    SolvencyViolation(SolvencyViolation),
+    PlatformFeeCollected(PlatformFeeCollected),
+    TreasuryWithdrawn(TreasuryWithdrawn),
+    PoolStaked(PoolStaked),
+    PoolUnwound(PoolUnwound),
+    ResolutionSigned(ResolutionSigned),
+    ResolutionCoSigned(ResolutionCoSigned),
+    InsuranceFunded(InsuranceFunded),
+    MisresolutionRuled(MisresolutionRuled),
+    InsuranceClaimPaid(InsuranceClaimPaid),
+    JoinQueued(JoinQueued),
+    QueuedJoinRefunded(QueuedJoinRefunded),
+    CopyJoined(CopyJoined),
+    PredictionCommitted(PredictionCommitted),
+    CoAdminsUpdated(CoAdminsUpdated),
+    WinnersRootPublished(WinnersRootPublished),
+    CharityPledged(CharityPledged),
+    CharityDonated(CharityDonated),
+    InPlayEnabled(InPlayEnabled),
+    PropPlayersListed(PropPlayersListed),
+    PropPicked(PropPicked),
+    PropResolved(PropResolved),
+    OutrightCheckpointed(OutrightCheckpointed),
+    CorrectScoreEnabled(CorrectScoreEnabled),
+    ScorePicked(ScorePicked),
+    CorrectScoreResolved(CorrectScoreResolved),
+    HandicapSet(HandicapSet),
+    HandicapResolved(HandicapResolved),
+    ResultsPublished(ResultsPublished),
+    LiveScoreUpdated(LiveScoreUpdated),
+    StreakRebatePaid(StreakRebatePaid),
+    FeeDiscountPaid(FeeDiscountPaid),
+    KeeperTipPaid(KeeperTipPaid),
+    VoucherRedeemed(VoucherRedeemed),
+    PlatformParamSet(PlatformParamSet),
+    MarketRegistryInvalidated(MarketRegistryInvalidated),
+    ProposalCreated(ProposalCreated),
+    VoteCast(VoteCast),
+    ProposalExecuted(ProposalExecuted),
+    DisputeOpened(DisputeOpened),
+    JuryDrawn(JuryDrawn),
+    JurorVoted(JurorVoted),
+    DisputeSettled(DisputeSettled),
+}
+
+impl CryptoscoreEvent {
+    /// Decode one `emit!` payload from `program_id`, or `None` if it isn't ours
+    pub fn decode(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, mut payload) = data.split_at(8);
+
+        if *program_id == cryptoscore_factory_interface::ID {
+            if discriminator == MarketCreated::DISCRIMINATOR {
+                return MarketCreated::deserialize(&mut payload).ok().map(Self::MarketCreated);
+            }
+            if discriminator == PlatformFeeCollected::DISCRIMINATOR {
+                return PlatformFeeCollected::deserialize(&mut payload).ok().map(Self::PlatformFeeCollected);
+            }
+            if discriminator == TreasuryWithdrawn::DISCRIMINATOR {
+                return TreasuryWithdrawn::deserialize(&mut payload).ok().map(Self::TreasuryWithdrawn);
+            }
+            if discriminator == InsuranceFunded::DISCRIMINATOR {
+                return InsuranceFunded::deserialize(&mut payload).ok().map(Self::InsuranceFunded);
+            }
+            if discriminator == MisresolutionRuled::DISCRIMINATOR {
+                return MisresolutionRuled::deserialize(&mut payload).ok().map(Self::MisresolutionRuled);
+            }
+            if discriminator == InsuranceClaimPaid::DISCRIMINATOR {
+                return InsuranceClaimPaid::deserialize(&mut payload).ok().map(Self::InsuranceClaimPaid);
+            }
+            if discriminator == ResultsPublished::DISCRIMINATOR {
+                return ResultsPublished::deserialize(&mut payload).ok().map(Self::ResultsPublished);
+            }
+            if discriminator == LiveScoreUpdated::DISCRIMINATOR {
+                return LiveScoreUpdated::deserialize(&mut payload).ok().map(Self::LiveScoreUpdated);
+            }
+            if discriminator == StreakRebatePaid::DISCRIMINATOR {
+                return StreakRebatePaid::deserialize(&mut payload).ok().map(Self::StreakRebatePaid);
+            }
+            if discriminator == FeeDiscountPaid::DISCRIMINATOR {
+                return FeeDiscountPaid::deserialize(&mut payload).ok().map(Self::FeeDiscountPaid);
+            }
+            if discriminator == KeeperTipPaid::DISCRIMINATOR {
+                return KeeperTipPaid::deserialize(&mut payload).ok().map(Self::KeeperTipPaid);
+            }
+            if discriminator == VoucherRedeemed::DISCRIMINATOR {
+                return VoucherRedeemed::deserialize(&mut payload).ok().map(Self::VoucherRedeemed);
+            }
+            if discriminator == PlatformParamSet::DISCRIMINATOR {
+                return PlatformParamSet::deserialize(&mut payload).ok().map(Self::PlatformParamSet);
+            }
+            if discriminator == MarketRegistryInvalidated::DISCRIMINATOR {
+                return MarketRegistryInvalidated::deserialize(&mut payload).ok().map(Self::MarketRegistryInvalidated);
+            }
+        } else if *program_id == cryptoscore_governance_interface::ID {
+            if discriminator == ProposalCreated::DISCRIMINATOR {
+                return ProposalCreated::deserialize(&mut payload).ok().map(Self::ProposalCreated);
+            }
+            if discriminator == VoteCast::DISCRIMINATOR {
+                return VoteCast::deserialize(&mut payload).ok().map(Self::VoteCast);
+            }
+            if discriminator == ProposalExecuted::DISCRIMINATOR {
+                return ProposalExecuted::deserialize(&mut payload).ok().map(Self::ProposalExecuted);
+            }
+            if discriminator == DisputeOpened::DISCRIMINATOR {
+                return DisputeOpened::deserialize(&mut payload).ok().map(Self::DisputeOpened);
+            }
+            if discriminator == JuryDrawn::DISCRIMINATOR {
+                return JuryDrawn::deserialize(&mut payload).ok().map(Self::JuryDrawn);
+            }
+            if discriminator == JurorVoted::DISCRIMINATOR {
+                return JurorVoted::deserialize(&mut payload).ok().map(Self::JurorVoted);
+            }
+            if discriminator == DisputeSettled::DISCRIMINATOR {
+                return DisputeSettled::deserialize(&mut payload).ok().map(Self::DisputeSettled);
+            }
+        } else if *program_id == cryptoscore_market_interface::ID {
+            if discriminator == PredictionMade::DISCRIMINATOR {
+                return PredictionMade::deserialize(&mut payload).ok().map(Self::PredictionMade);
+            }
+            if discriminator == MarketResolved::DISCRIMINATOR {
+                return MarketResolved::deserialize(&mut payload).ok().map(Self::MarketResolved);
+            }
+            if discriminator == ResultAttested::DISCRIMINATOR {
+                return ResultAttested::deserialize(&mut payload).ok().map(Self::ResultAttested);
+            }
+            if discriminator == ResultBridged::DISCRIMINATOR {
+                return ResultBridged::deserialize(&mut payload).ok().map(Self::ResultBridged);
+            }
+            if discriminator == ResultCorrected::DISCRIMINATOR {
+                return ResultCorrected::deserialize(&mut payload).ok().map(Self::ResultCorrected);
+            }
+            if discriminator == RewardClaimed::DISCRIMINATOR {
+                return RewardClaimed::deserialize(&mut payload).ok().map(Self::RewardClaimed);
+            }
+            if discriminator == RewardRolled::DISCRIMINATOR {
+                return RewardRolled::deserialize(&mut payload).ok().map(Self::RewardRolled);
+            }
+            if discriminator == RewardSwapped::DISCRIMINATOR {
+                return RewardSwapped::deserialize(&mut payload).ok().map(Self::RewardSwapped);
+            }
+            if discriminator == ClaimReceiptMinted::DISCRIMINATOR {
+                return ClaimReceiptMinted::deserialize(&mut payload).ok().map(Self::ClaimReceiptMinted);
+            }
+            if discriminator == FeesDistributed::DISCRIMINATOR {
+                return FeesDistributed::deserialize(&mut payload).ok().map(Self::FeesDistributed);
+            }
+            if discriminator == UnclaimedSwept::DISCRIMINATOR {
+                return UnclaimedSwept::deserialize(&mut payload).ok().map(Self::UnclaimedSwept);
+            }
+            if discriminator == MarketCancelled::DISCRIMINATOR {
+                return MarketCancelled::deserialize(&mut payload).ok().map(Self::MarketCancelled);
+            }
+            if discriminator == RefundClaimed::DISCRIMINATOR {
+                return RefundClaimed::deserialize(&mut payload).ok().map(Self::RefundClaimed);
+            }
+            if discriminator == MarketClosed::DISCRIMINATOR {
+                return MarketClosed::deserialize(&mut payload).ok().map(Self::MarketClosed);
+            }
+            if discriminator == SettlementReportCompiled::DISCRIMINATOR {
+                return SettlementReportCompiled::deserialize(&mut payload).ok().map(Self::SettlementReportCompiled);
+            }
+            if discriminator == SolvencyViolation::DISCRIMINATOR {
+                return SolvencyViolation::deserialize(&mut payload).ok().map(Self::SolvencyViolation);
+            }
+            if discriminator == PoolStaked::DISCRIMINATOR {
+                return PoolStaked::deserialize(&mut payload).ok().map(Self::PoolStaked);
+            }
+            if discriminator == PoolUnwound::DISCRIMINATOR {
+                return PoolUnwound::deserialize(&mut payload).ok().map(Self::PoolUnwound);
+            }
+            if discriminator == ResolutionSigned::DISCRIMINATOR {
+                return ResolutionSigned::deserialize(&mut payload).ok().map(Self::ResolutionSigned);
+            }
+            if discriminator == ResolutionCoSigned::DISCRIMINATOR {
+                return ResolutionCoSigned::deserialize(&mut payload).ok().map(Self::ResolutionCoSigned);
+            }
+            if discriminator == JoinQueued::DISCRIMINATOR {
+                return JoinQueued::deserialize(&mut payload).ok().map(Self::JoinQueued);
+            }
+            if discriminator == QueuedJoinRefunded::DISCRIMINATOR {
+                return QueuedJoinRefunded::deserialize(&mut payload).ok().map(Self::QueuedJoinRefunded);
+            }
+            if discriminator == CopyJoined::DISCRIMINATOR {
+                return CopyJoined::deserialize(&mut payload).ok().map(Self::CopyJoined);
+            }
+            if discriminator == PredictionCommitted::DISCRIMINATOR {
+                return PredictionCommitted::deserialize(&mut payload).ok().map(Self::PredictionCommitted);
+            }
+            if discriminator == CoAdminsUpdated::DISCRIMINATOR {
+                return CoAdminsUpdated::deserialize(&mut payload).ok().map(Self::CoAdminsUpdated);
+            }
+            if discriminator == WinnersRootPublished::DISCRIMINATOR {
+                return WinnersRootPublished::deserialize(&mut payload).ok().map(Self::WinnersRootPublished);
+            }
+            if discriminator == CharityPledged::DISCRIMINATOR {
+                return CharityPledged::deserialize(&mut payload).ok().map(Self::CharityPledged);
+            }
+            if discriminator == CharityDonated::DISCRIMINATOR {
+                return CharityDonated::deserialize(&mut payload).ok().map(Self::CharityDonated);
+            }
+            if discriminator == InPlayEnabled::DISCRIMINATOR {
+                return InPlayEnabled::deserialize(&mut payload).ok().map(Self::InPlayEnabled);
+            }
+            if discriminator == PropPlayersListed::DISCRIMINATOR {
+                return PropPlayersListed::deserialize(&mut payload).ok().map(Self::PropPlayersListed);
+            }
+            if discriminator == PropPicked::DISCRIMINATOR {
+                return PropPicked::deserialize(&mut payload).ok().map(Self::PropPicked);
+            }
+            if discriminator == PropResolved::DISCRIMINATOR {
+                return PropResolved::deserialize(&mut payload).ok().map(Self::PropResolved);
+            }
+            if discriminator == OutrightCheckpointed::DISCRIMINATOR {
+                return OutrightCheckpointed::deserialize(&mut payload).ok().map(Self::OutrightCheckpointed);
+            }
+            if discriminator == CorrectScoreEnabled::DISCRIMINATOR {
+                return CorrectScoreEnabled::deserialize(&mut payload).ok().map(Self::CorrectScoreEnabled);
+            }
+            if discriminator == ScorePicked::DISCRIMINATOR {
+                return ScorePicked::deserialize(&mut payload).ok().map(Self::ScorePicked);
+            }
+            if discriminator == CorrectScoreResolved::DISCRIMINATOR {
+                return CorrectScoreResolved::deserialize(&mut payload).ok().map(Self::CorrectScoreResolved);
+            }
+            if discriminator == HandicapSet::DISCRIMINATOR {
+                return HandicapSet::deserialize(&mut payload).ok().map(Self::HandicapSet);
+            }
+            if discriminator == HandicapResolved::DISCRIMINATOR {
+                return HandicapResolved::deserialize(&mut payload).ok().map(Self::HandicapResolved);
+            }
+        }
+
+        None
+    }
+}
+
+/// Decode every CryptoScore event in a transaction's log messages
+///
+/// Events are attributed to the program on top of the invocation stack, so
+/// payloads logged by other programs in the same transaction are skipped.
+pub fn parse_logs(logs: &[String]) -> Vec<CryptoscoreEvent> {
+    let mut stack: Vec<Pubkey> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            let (Some(program_id), Ok(bytes)) = (stack.last(), STANDARD.decode(data)) else {
+                continue;
+            };
+            events.extend(CryptoscoreEvent::decode(program_id, &bytes));
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut parts = rest.split_whitespace();
+            let (Some(program), Some(status)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            match status {
+                "invoke" => {
+                    if let Ok(program_id) = program.parse() {
+                        stack.push(program_id);
+                    }
+                }
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
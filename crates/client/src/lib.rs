@@ -0,0 +1,670 @@
+//! Off-chain client for the CryptoScore programs.
+//!
+//! Wraps `solana-client` with typed builders that derive every PDA, attach
+//! fixed or estimated priority fees, simulate before sending and decode the
+//! factory, market and governance events from the confirmed transaction's logs.
+//! Transactions whose blockhash expires before they land are re-signed and
+//! resubmitted. Batches can be sent as v0 transactions that resolve common
+//! accounts through address lookup tables.
+
+#![allow(clippy::result_large_err)]
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use cryptoscore_common::commitment::SALT_LEN;
+use cryptoscore_common::{MatchResult, MAX_CO_ADMINS};
+use cryptoscore_common::stake_pool::{StakePoolAccounts, STAKE_POOL_PROGRAM_ID};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_client::SerializableTransaction;
+use solana_client::rpc_config::{RpcSendTransactionConfig, RpcTransactionConfig};
+use solana_sdk::address_lookup_table::AddressLookupTableAccount;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use solana_transaction_status::UiTransactionEncoding;
+
+pub mod builders;
+pub mod error;
+pub mod events;
+pub mod lookup_tables;
+pub mod priority_fees;
+
+pub use builders::{
+    AssertSolvencyBuilder, BatchBuilder, BuildWinnersRootBuilder, CancelMarketBuilder, ClaimInsuranceBuilder,
+    ClaimRefundBuilder, CloseMarketBuilder, CoSignResolutionBuilder, CommitPredictionBuilder,
+    CompileSettlementReportBuilder, CopyJoinBuilder, CorrectResultBuilder, CreateMarketBuilder,
+    CreateMarketSeriesBuilder, EmergencyVoidBuilder, EnableCommitRevealBuilder, EnableCorrectScoreBuilder,
+    EnableInPlayBuilder, EnableParticipantRosterBuilder, ExecuteQueuedJoinBuilder, FollowBuilder,
+    InitializeMarketBuilder, InvalidateRegistryBuilder, JoinCorrectScoreBuilder, JoinMarketBuilder, JoinPropBuilder,
+    ListPropPlayersBuilder, MigrateMarketBuilder, MigrateParticipantBuilder, MintClaimReceiptBuilder,
+    OfferFirstCorrectBonusBuilder, PledgeToCharityBuilder, PublishResultsBuilder, QueueJoinBuilder,
+    RecordOddsSampleBuilder, RecordOutrightCheckpointBuilder, RecordPendingClaimBuilder, RefundQueuedJoinBuilder,
+    ResolveCorrectScoreBuilder, ResolveHandicapBuilder, ResolveMarketBuilder, ResolveMarketsBatchBuilder,
+    ResolvePropBuilder, ResolveWithAttestationBuilder, ResolveWithLiveScoreBuilder, ResolveWithVaaBuilder,
+    RevealPredictionBuilder, RollRewardsBuilder, SetCoAdminsBuilder, SetFollowUpMarketBuilder, SetTokenGateBuilder,
+    SettleOutrightEarlyBuilder, SettleRebateEntryBuilder, SignResolutionBuilder, StakeIdlePoolBuilder,
+    SweepUnclaimedBuilder, UnfollowBuilder, UnwindIdlePoolBuilder, UpdateLiveScoreBuilder, WithdrawPropRewardsBuilder,
+    WithdrawRewardsBuilder, WithdrawRewardsSwappedBuilder, WithdrawScoreRewardsBuilder, WINNERS_ROOT_BATCH,
+};
+pub use cryptoscore_common::token_gate::TokenGateRule;
+pub use cryptoscore_common::{FinalScore, HandicapStyle, MatchOutcome, OutcomeSet, PropKind, Scoreline};
+pub use error::ClientError;
+pub use events::CryptoscoreEvent;
+pub use priority_fees::PriorityFee;
+
+/// Delay between signature status checks while waiting for confirmation
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Status checks between rebroadcasts of an unconfirmed transaction
+const POLLS_PER_BROADCAST: u32 = 4;
+
+/// How a transaction is prepared and sent
+#[derive(Clone, Copy, Debug)]
+pub struct SendOptions {
+    /// Compute unit price prepended as a priority fee
+    pub priority_fee: PriorityFee,
+    /// Compute unit limit requested for the transaction
+    pub compute_unit_limit: Option<u32>,
+    /// Simulate before sending and surface the program logs on failure
+    pub simulate: bool,
+    /// Times to re-sign with a fresh blockhash (and re-estimated fee) after
+    /// the previous one expires without the transaction landing
+    pub resubmissions: u32,
+}
+
+impl Default for SendOptions {
+    fn default() -> Self {
+        Self {
+            priority_fee: PriorityFee::None,
+            compute_unit_limit: None,
+            simulate: true,
+            resubmissions: 2,
+        }
+    }
+}
+
+/// A confirmed transaction and the CryptoScore events it emitted
+pub struct Confirmed {
+    pub signature: Signature,
+    pub events: Vec<CryptoscoreEvent>,
+}
+
+pub struct CryptoscoreClient<S: Signer> {
+    rpc: RpcClient,
+    payer: S,
+    options: SendOptions,
+}
+
+impl<S: Signer> CryptoscoreClient<S> {
+    pub fn new(rpc: RpcClient, payer: S) -> Self {
+        Self {
+            rpc,
+            payer,
+            options: SendOptions::default(),
+        }
+    }
+
+    /// Default options applied to every builder created by this client
+    pub fn with_options(mut self, options: SendOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn options(&self) -> SendOptions {
+        self.options
+    }
+
+    /// Register a market with the factory and initialize it in one transaction
+    pub fn create_market(&self, match_id: impl Into<String>) -> CreateMarketBuilder<'_, S> {
+        CreateMarketBuilder::new(self, match_id.into())
+    }
+
+    /// Register and initialize a series of markets, such as a matchweek, in one instruction
+    pub fn create_market_series(&self) -> CreateMarketSeriesBuilder<'_, S> {
+        CreateMarketSeriesBuilder::new(self)
+    }
+
+    /// Initialize a market without a factory registry entry
+    pub fn initialize_market(&self, match_id: impl Into<String>) -> InitializeMarketBuilder<'_, S> {
+        InitializeMarketBuilder::new(self, match_id.into())
+    }
+
+    pub fn join_market(&self, market: Pubkey, prediction: MatchOutcome) -> JoinMarketBuilder<'_, S> {
+        JoinMarketBuilder::new(self, market, prediction)
+    }
+
+    /// Queue a join that executes shortly before kickoff only if the payout on
+    /// `prediction` is still at least `min_payout_bps` of the entry fee
+    pub fn queue_join(&self, market: Pubkey, prediction: MatchOutcome, min_payout_bps: u64) -> QueueJoinBuilder<'_, S> {
+        QueueJoinBuilder::new(self, market, prediction, min_payout_bps)
+    }
+
+    /// Execute `user`'s queued join once it's due and its payout condition holds
+    pub fn execute_queued_join(&self, market: Pubkey, user: Pubkey) -> ExecuteQueuedJoinBuilder<'_, S> {
+        ExecuteQueuedJoinBuilder::new(self, market, user)
+    }
+
+    /// Cancel or refund `user`'s queued join
+    pub fn refund_queued_join(&self, market: Pubkey, user: Pubkey) -> RefundQueuedJoinBuilder<'_, S> {
+        RefundQueuedJoinBuilder::new(self, market, user)
+    }
+
+    /// Follow `leader` so their predictions can be copied
+    pub fn follow(&self, leader: Pubkey) -> FollowBuilder<'_, S> {
+        FollowBuilder::new(self, leader)
+    }
+
+    pub fn unfollow(&self, leader: Pubkey) -> UnfollowBuilder<'_, S> {
+        UnfollowBuilder::new(self, leader)
+    }
+
+    /// Join `market` on the prediction of a followed `leader`, who is credited
+    /// with the entry in a `CopyJoined` event
+    pub fn copy_join(&self, market: Pubkey, leader: Pubkey) -> CopyJoinBuilder<'_, S> {
+        CopyJoinBuilder::new(self, market, leader)
+    }
+
+    /// Appoint up to `MAX_CO_ADMINS` users who can cancel and configure
+    /// `market` alongside its creator, with the default pubkey in unused slots
+    pub fn set_co_admins(&self, market: Pubkey, co_admins: [Pubkey; MAX_CO_ADMINS]) -> SetCoAdminsBuilder<'_, S> {
+        SetCoAdminsBuilder::new(self, market, co_admins)
+    }
+
+    /// Limit a `market` that has no entries yet to holders of a token or NFT collection
+    pub fn set_token_gate(&self, market: Pubkey, rule: TokenGateRule) -> SetTokenGateBuilder<'_, S> {
+        SetTokenGateBuilder::new(self, market, rule)
+    }
+
+    /// Require committed predictions on a private `market` that has no entries yet
+    pub fn enable_commit_reveal(&self, market: Pubkey) -> EnableCommitRevealBuilder<'_, S> {
+        EnableCommitRevealBuilder::new(self, market)
+    }
+
+    /// List the participants of a `market` that has no entries yet on-chain,
+    /// so they can be enumerated without scanning program accounts
+    pub fn enable_participant_roster(&self, market: Pubkey) -> EnableParticipantRosterBuilder<'_, S> {
+        EnableParticipantRosterBuilder::new(self, market)
+    }
+
+    /// Give a `market` that has no entries yet to `charity`: its fees, and
+    /// `pool_share_bps` of its prize pool, go there at resolution
+    pub fn pledge_to_charity(
+        &self,
+        market: Pubkey,
+        charity: Pubkey,
+        pool_share_bps: u16,
+    ) -> PledgeToCharityBuilder<'_, S> {
+        PledgeToCharityBuilder::new(self, market, charity, pool_share_bps)
+    }
+
+    /// Set aside `bonus_bps` of a `market`'s creator fee, at resolution, for
+    /// its earliest entry on the winning outcome; the market must have no
+    /// entries yet
+    pub fn offer_first_correct_bonus(&self, market: Pubkey, bonus_bps: u16) -> OfferFirstCorrectBonusBuilder<'_, S> {
+        OfferFirstCorrectBonusBuilder::new(self, market, bonus_bps)
+    }
+
+    /// Chain `market` to `follow_up`, another of its creator's markets on the
+    /// same factory kicking off after it ends, so its winners can roll their
+    /// rewards into it; the market must not be resolved yet
+    pub fn set_follow_up_market(&self, market: Pubkey, follow_up: Pubkey) -> SetFollowUpMarketBuilder<'_, S> {
+        SetFollowUpMarketBuilder::new(self, market, follow_up)
+    }
+
+    /// Keep `market` taking joins after kickoff until its match's live score
+    /// reaches `lock_minute`
+    pub fn enable_in_play(&self, market: Pubkey, lock_minute: u16) -> EnableInPlayBuilder<'_, S> {
+        EnableInPlayBuilder::new(self, market, lock_minute)
+    }
+
+    /// Make a `market` that has no entries yet take picks on `player_ids`
+    /// instead of match predictions, settled on `kind`
+    pub fn list_prop_players(
+        &self,
+        market: Pubkey,
+        kind: PropKind,
+        player_ids: Vec<u32>,
+    ) -> ListPropPlayersBuilder<'_, S> {
+        ListPropPlayersBuilder::new(self, market, kind, player_ids)
+    }
+
+    /// Enter a player prop `market` on `player_id`
+    pub fn join_prop(&self, market: Pubkey, player_id: u32) -> JoinPropBuilder<'_, S> {
+        JoinPropBuilder::new(self, market, player_id)
+    }
+
+    /// Resolve a player prop `market` with the player the oracle reported,
+    /// or `None` if no listed player did it, signing as the factory's results signer
+    pub fn resolve_prop(&self, market: Pubkey, player_id: Option<u32>) -> ResolvePropBuilder<'_, S> {
+        ResolvePropBuilder::new(self, market, player_id)
+    }
+
+    /// Record a standings checkpoint on an outright `market`, eliminating the
+    /// teams in `eliminated`, signing as the factory's results signer
+    pub fn record_outright_checkpoint(
+        &self,
+        market: Pubkey,
+        eliminated: Vec<u32>,
+    ) -> RecordOutrightCheckpointBuilder<'_, S> {
+        RecordOutrightCheckpointBuilder::new(self, market, eliminated)
+    }
+
+    /// Settle an outright `market` whose checkpoints leave one team able to win
+    pub fn settle_outright_early(&self, market: Pubkey) -> SettleOutrightEarlyBuilder<'_, S> {
+        SettleOutrightEarlyBuilder::new(self, market)
+    }
+
+    pub fn withdraw_prop_rewards(&self, market: Pubkey) -> WithdrawPropRewardsBuilder<'_, S> {
+        WithdrawPropRewardsBuilder::new(self, market)
+    }
+
+    /// Make a `market` that has no entries yet take final-score picks
+    /// instead of match predictions
+    pub fn enable_correct_score(&self, market: Pubkey) -> EnableCorrectScoreBuilder<'_, S> {
+        EnableCorrectScoreBuilder::new(self, market)
+    }
+
+    /// Enter a correct-score `market` on `scoreline`
+    pub fn join_correct_score(&self, market: Pubkey, scoreline: Scoreline) -> JoinCorrectScoreBuilder<'_, S> {
+        JoinCorrectScoreBuilder::new(self, market, scoreline)
+    }
+
+    /// Relay the final live score of a correct-score `market`'s match to resolve it
+    pub fn resolve_correct_score(&self, market: Pubkey) -> ResolveCorrectScoreBuilder<'_, S> {
+        ResolveCorrectScoreBuilder::new(self, market)
+    }
+
+    /// Relay the final live score of a handicap `market`'s match to resolve it
+    pub fn resolve_handicap(&self, market: Pubkey) -> ResolveHandicapBuilder<'_, S> {
+        ResolveHandicapBuilder::new(self, market)
+    }
+
+    pub fn withdraw_score_rewards(&self, market: Pubkey) -> WithdrawScoreRewardsBuilder<'_, S> {
+        WithdrawScoreRewardsBuilder::new(self, market)
+    }
+
+    /// Enter `market` with a commitment to `prediction`; keep `salt` to reveal
+    /// it after kickoff
+    pub fn commit_prediction(
+        &self,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> CommitPredictionBuilder<'_, S> {
+        CommitPredictionBuilder::new(self, market, prediction, salt)
+    }
+
+    pub fn reveal_prediction(
+        &self,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> RevealPredictionBuilder<'_, S> {
+        RevealPredictionBuilder::new(self, market, prediction, salt)
+    }
+
+    /// Resolve `market` from its match's final score, which the program
+    /// derives the outcome from
+    pub fn resolve_market(&self, market: Pubkey, score: FinalScore) -> ResolveMarketBuilder<'_, S> {
+        ResolveMarketBuilder::new(self, market, score)
+    }
+
+    /// Relay a result the factory's results signer signed over
+    /// `attestation_message(match_id, score, attested_at)`
+    pub fn resolve_with_attestation(
+        &self,
+        market: Pubkey,
+        score: FinalScore,
+        attested_at: i64,
+        signature: Signature,
+    ) -> ResolveWithAttestationBuilder<'_, S> {
+        ResolveWithAttestationBuilder::new(self, market, score, attested_at, signature)
+    }
+
+    /// Relay the result in a VAA the Wormhole core bridge has already posted
+    pub fn resolve_with_vaa(&self, market: Pubkey, posted_vaa: Pubkey) -> ResolveWithVaaBuilder<'_, S> {
+        ResolveWithVaaBuilder::new(self, market, posted_vaa)
+    }
+
+    /// Relay the final live score the results signer reported for `market`'s match
+    pub fn resolve_with_live_score(&self, market: Pubkey) -> ResolveWithLiveScoreBuilder<'_, S> {
+        ResolveWithLiveScoreBuilder::new(self, market)
+    }
+
+    /// Report `match_id` standing at `score` (home, away) in `minute`,
+    /// signing as the factory's results signer; call `final_score` on the
+    /// builder for the last update
+    pub fn update_live_score(&self, match_id: &str, score: (u8, u8), minute: u16) -> UpdateLiveScoreBuilder<'_, S> {
+        UpdateLiveScoreBuilder::new(self, match_id.to_string(), score, minute)
+    }
+
+    /// Publish up to `MAX_BATCH_RESULTS` match results under `batch_id`,
+    /// signing as the factory's results signer
+    pub fn publish_results(&self, batch_id: u64, results: Vec<MatchResult>) -> PublishResultsBuilder<'_, S> {
+        PublishResultsBuilder::new(self, batch_id, results)
+    }
+
+    /// Resolve every market in `markets` from the results published under `batch_id`
+    pub fn resolve_markets_batch(&self, batch_id: u64, markets: Vec<Pubkey>) -> ResolveMarketsBatchBuilder<'_, S> {
+        ResolveMarketsBatchBuilder::new(self, batch_id, markets)
+    }
+
+    /// Sign the final score of a market whose pool needs two of its
+    /// creator, the results signer and the factory's arbiter to resolve
+    pub fn sign_resolution(&self, market: Pubkey, score: FinalScore) -> SignResolutionBuilder<'_, S> {
+        SignResolutionBuilder::new(self, market, score)
+    }
+
+    /// Resolve a high-stakes market on a final score another of its resolvers signed
+    pub fn co_sign_resolution(&self, market: Pubkey, score: FinalScore) -> CoSignResolutionBuilder<'_, S> {
+        CoSignResolutionBuilder::new(self, market, score)
+    }
+
+    /// Correct the final score of a resolved market as the results signer, within the correction
+    /// window; winners who already claimed are covered by a mis-resolution ruling instead
+    pub fn correct_result(&self, market: Pubkey, score: FinalScore) -> CorrectResultBuilder<'_, S> {
+        CorrectResultBuilder::new(self, market, score)
+    }
+
+    /// Settle `user`'s tracked entry in a finished market against their rebate streak
+    pub fn settle_rebate_entry(&self, market: Pubkey, user: Pubkey) -> SettleRebateEntryBuilder<'_, S> {
+        SettleRebateEntryBuilder::new(self, market, user)
+    }
+
+    /// List `user`'s unclaimed win on a resolved market in their dashboard pending claims
+    pub fn record_pending_claim(&self, market: Pubkey, user: Pubkey) -> RecordPendingClaimBuilder<'_, S> {
+        RecordPendingClaimBuilder::new(self, market, user)
+    }
+
+    /// Fold the next roster entries of a resolved `market` into the merkle
+    /// root of its winners; send until the root is published
+    pub fn build_winners_root(&self, market: Pubkey) -> BuildWinnersRootBuilder<'_, S> {
+        BuildWinnersRootBuilder::new(self, market)
+    }
+
+    pub fn withdraw_rewards(&self, market: Pubkey) -> WithdrawRewardsBuilder<'_, S> {
+        WithdrawRewardsBuilder::new(self, market)
+    }
+
+    /// Let a winning reward on `market` ride into its follow-up market as an
+    /// entry on `prediction`, paying out what the entry fee leaves
+    pub fn roll_rewards(&self, market: Pubkey, prediction: MatchOutcome) -> RollRewardsBuilder<'_, S> {
+        RollRewardsBuilder::new(self, market, prediction)
+    }
+
+    /// Mint a compressed NFT receipt of the payer's withdrawn reward or
+    /// refund on `market`, if its factory mints receipts
+    pub fn mint_claim_receipt(&self, market: Pubkey) -> MintClaimReceiptBuilder<'_, S> {
+        MintClaimReceiptBuilder::new(self, market)
+    }
+
+    /// Withdraw a winning reward on `market` swapped into `output_mint`
+    /// through the Jupiter instruction `route`
+    pub fn withdraw_rewards_swapped(
+        &self,
+        market: Pubkey,
+        output_mint: Pubkey,
+        route: Instruction,
+    ) -> WithdrawRewardsSwappedBuilder<'_, S> {
+        WithdrawRewardsSwappedBuilder::new(self, market, output_mint, route)
+    }
+
+    /// Sweep a market's unclaimed funds once its claim window has closed (factory authority only)
+    pub fn sweep_unclaimed(&self, market: Pubkey) -> SweepUnclaimedBuilder<'_, S> {
+        SweepUnclaimedBuilder::new(self, market)
+    }
+
+    /// Call off a market (its creator before kickoff, or the factory authority)
+    pub fn cancel_market(&self, market: Pubkey) -> CancelMarketBuilder<'_, S> {
+        CancelMarketBuilder::new(self, market)
+    }
+
+    /// Reclaim the payer's entry fee from a cancelled market
+    pub fn claim_refund(&self, market: Pubkey) -> ClaimRefundBuilder<'_, S> {
+        ClaimRefundBuilder::new(self, market)
+    }
+
+    /// Claim compensation for a market the factory authority ruled mis-resolved
+    pub fn claim_insurance(&self, market: Pubkey) -> ClaimInsuranceBuilder<'_, S> {
+        ClaimInsuranceBuilder::new(self, market)
+    }
+
+    /// Close a cancelled market once every entry fee has been refunded
+    pub fn close_market(&self, market: Pubkey) -> CloseMarketBuilder<'_, S> {
+        CloseMarketBuilder::new(self, market)
+    }
+
+    /// Compile the settlement report of a market that has been swept, or
+    /// closed after refunds, accounting for where its pool went
+    pub fn compile_settlement_report(&self, market: Pubkey) -> CompileSettlementReportBuilder<'_, S> {
+        CompileSettlementReportBuilder::new(self, market)
+    }
+
+    /// Send a market to refunds during an incident, even after resolution (factory authority only)
+    pub fn emergency_void(&self, market: Pubkey) -> EmergencyVoidBuilder<'_, S> {
+        EmergencyVoidBuilder::new(self, market)
+    }
+
+    /// Check a market's vault still covers its fees and payouts
+    pub fn assert_solvency(&self, market: Pubkey) -> AssertSolvencyBuilder<'_, S> {
+        AssertSolvencyBuilder::new(self, market)
+    }
+
+    /// Move a market created before payout tracking to the current layout
+    pub fn migrate_market(&self, market: Pubkey) -> MigrateMarketBuilder<'_, S> {
+        MigrateMarketBuilder::new(self, market)
+    }
+
+    /// Move a participant created before join notes to the current layout
+    pub fn migrate_participant(&self, participant: Pubkey) -> MigrateParticipantBuilder<'_, S> {
+        MigrateParticipantBuilder::new(self, participant)
+    }
+
+    /// Close a registry entry whose market was never initialized, refunding its creator
+    pub fn invalidate_registry(&self, market_registry: Pubkey) -> InvalidateRegistryBuilder<'_, S> {
+        InvalidateRegistryBuilder::new(self, market_registry)
+    }
+
+    /// Stake a market's pool in an SPL stake pool for the match (factory authority only)
+    pub fn stake_idle_pool(&self, market: Pubkey, stake_pool: Pubkey) -> StakeIdlePoolBuilder<'_, S> {
+        StakeIdlePoolBuilder::new(self, market, stake_pool)
+    }
+
+    /// Bring a staked pool back into its market's vault
+    pub fn unwind_idle_pool(&self, market: Pubkey) -> UnwindIdlePoolBuilder<'_, S> {
+        UnwindIdlePoolBuilder::new(self, market)
+    }
+
+    /// Append a market's current home/draw/away split to its on-chain odds
+    /// history, at most once per `ODDS_SAMPLE_INTERVAL_SECONDS` before kickoff
+    pub fn record_odds_sample(&self, market: Pubkey) -> RecordOddsSampleBuilder<'_, S> {
+        RecordOddsSampleBuilder::new(self, market)
+    }
+
+    /// Send several builders' instructions as one v0 transaction
+    pub fn batch(&self) -> BatchBuilder<'_, S> {
+        BatchBuilder::new(self)
+    }
+
+    /// Fetch and deserialize a program account
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T, ClientError> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value
+            .ok_or(ClientError::AccountNotFound(*address))?;
+
+        T::try_deserialize(&mut &account.data[..])
+            .map_err(|err| ClientError::Deserialize(*address, err))
+    }
+
+    /// Fetch the accounts an SPL stake pool's SOL deposits and withdrawals go through
+    pub fn fetch_stake_pool(&self, address: &Pubkey) -> Result<StakePoolAccounts, ClientError> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value
+            .ok_or(ClientError::AccountNotFound(*address))?;
+        if account.owner != STAKE_POOL_PROGRAM_ID {
+            return Err(ClientError::InvalidStakePool(*address));
+        }
+
+        StakePoolAccounts::parse(*address, &account.data).ok_or(ClientError::InvalidStakePool(*address))
+    }
+
+    /// Sign, optionally simulate, send and confirm `instructions`, then decode events
+    pub fn send(&self, instructions: Vec<Instruction>, options: &SendOptions) -> Result<Confirmed, ClientError> {
+        self.submit(&instructions, options, |instructions, blockhash| {
+            Ok(Transaction::new_signed_with_payer(
+                instructions,
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+        })
+    }
+
+    /// Like `send`, but as a v0 transaction resolving accounts through `lookup_tables`
+    pub fn send_v0(
+        &self,
+        instructions: Vec<Instruction>,
+        lookup_tables: &[AddressLookupTableAccount],
+        options: &SendOptions,
+    ) -> Result<Confirmed, ClientError> {
+        self.submit(&instructions, options, |instructions, blockhash| {
+            let message = v0::Message::try_compile(&self.payer.pubkey(), instructions, lookup_tables, blockhash)?;
+            Ok(VersionedTransaction::try_new(VersionedMessage::V0(message), &[&self.payer])?)
+        })
+    }
+
+    /// Sign `instructions` with a fresh blockhash via `sign` and send until
+    /// confirmed, resubmitting up to `options.resubmissions` times
+    fn submit<T: SerializableTransaction>(
+        &self,
+        instructions: &[Instruction],
+        options: &SendOptions,
+        sign: impl Fn(&[Instruction], Hash) -> Result<T, ClientError>,
+    ) -> Result<Confirmed, ClientError> {
+        for attempt in 0..=options.resubmissions {
+            let instructions = self.with_compute_budget(instructions, options)?;
+            let (blockhash, last_valid_block_height) =
+                self.rpc.get_latest_blockhash_with_commitment(self.rpc.commitment())?;
+            let transaction = sign(&instructions, blockhash)?;
+
+            // A rejected simulation won't pass on a later blockhash either
+            if options.simulate && attempt == 0 {
+                let simulation = self.rpc.simulate_transaction(&transaction)?.value;
+                if let Some(err) = simulation.err {
+                    return Err(ClientError::Simulation {
+                        err,
+                        logs: simulation.logs.unwrap_or_default(),
+                    });
+                }
+            }
+
+            if let Some(signature) = self.send_until_expired(&transaction, last_valid_block_height)? {
+                return Ok(Confirmed {
+                    signature,
+                    events: self.transaction_events(&signature)?,
+                });
+            }
+        }
+
+        Err(ClientError::BlockhashExpired {
+            attempts: options.resubmissions + 1,
+        })
+    }
+
+    /// Broadcast `transaction` until it confirms or its blockhash expires
+    ///
+    /// Returns `None` only once the transaction can no longer land, so it's
+    /// safe to re-sign with a new blockhash without risking a double send.
+    fn send_until_expired(
+        &self,
+        transaction: &impl SerializableTransaction,
+        last_valid_block_height: u64,
+    ) -> Result<Option<Signature>, ClientError> {
+        let signature = *transaction.get_signature();
+        // Preflight already ran in `submit` if requested; rebroadcasting is handled here
+        let config = RpcSendTransactionConfig {
+            skip_preflight: true,
+            max_retries: Some(0),
+            ..RpcSendTransactionConfig::default()
+        };
+
+        loop {
+            let expired = self.rpc.get_block_height()? > last_valid_block_height;
+            if !expired {
+                self.rpc.send_transaction_with_config(transaction, config)?;
+            }
+
+            for _ in 0..POLLS_PER_BROADCAST {
+                if let Some(status) =
+                    self.rpc.get_signature_status_with_commitment(&signature, self.rpc.commitment())?
+                {
+                    return match status {
+                        Ok(()) => Ok(Some(signature)),
+                        Err(err) => Err(ClientError::TransactionFailed { signature, err }),
+                    };
+                }
+                // The status was checked after the block height, so an expired transaction is final
+                if expired {
+                    return Ok(None);
+                }
+                sleep(CONFIRM_POLL_INTERVAL);
+            }
+        }
+    }
+
+    /// Prepend the compute budget instructions `options` asks for
+    fn with_compute_budget(
+        &self,
+        instructions: &[Instruction],
+        options: &SendOptions,
+    ) -> Result<Vec<Instruction>, ClientError> {
+        let mut all_instructions = Vec::with_capacity(instructions.len() + 2);
+        if let Some(limit) = options.compute_unit_limit {
+            all_instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+        }
+        if let Some(price) = options.priority_fee.price(&self.rpc, instructions)? {
+            all_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+        }
+        all_instructions.extend_from_slice(instructions);
+        Ok(all_instructions)
+    }
+
+    /// Decode the CryptoScore events emitted by a confirmed transaction
+    pub fn transaction_events(&self, signature: &Signature) -> Result<Vec<CryptoscoreEvent>, ClientError> {
+        Ok(events::parse_logs(&self.transaction_logs(signature)?))
+    }
+
+    /// Log messages of a confirmed transaction, e.g. for `ErrorDecoder::decode_context`
+    pub fn transaction_logs(&self, signature: &Signature) -> Result<Vec<String>, ClientError> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Json),
+            commitment: Some(self.rpc.commitment()),
+            max_supported_transaction_version: Some(0),
+        };
+        let transaction = self.rpc.get_transaction_with_config(signature, config)?;
+
+        Ok(transaction
+            .transaction
+            .meta
+            .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,80 @@
+//! Address lookup table management for batched v0 transactions.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::pda::{find_factory_address, find_platform_stats_address, find_points_totals_address};
+use cryptoscore_common::{DASHBOARD_PROGRAM_ID, FACTORY_PROGRAM_ID, MARKET_PROGRAM_ID};
+use solana_sdk::address_lookup_table::instruction::{create_lookup_table, extend_lookup_table};
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::address_lookup_table::AddressLookupTableAccount;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signer::Signer;
+use solana_sdk::{compute_budget, system_program, sysvar};
+
+use crate::{ClientError, CryptoscoreClient};
+
+/// Addresses that fit in one `extend` transaction alongside its signatures
+const EXTEND_CHUNK: usize = 30;
+
+/// Accounts shared by most CryptoScore transactions
+pub fn common_addresses() -> Vec<Pubkey> {
+    vec![
+        FACTORY_PROGRAM_ID,
+        MARKET_PROGRAM_ID,
+        DASHBOARD_PROGRAM_ID,
+        find_factory_address().0,
+        find_platform_stats_address().0,
+        find_points_totals_address().0,
+        system_program::ID,
+        sysvar::instructions::ID,
+        compute_budget::ID,
+    ]
+}
+
+impl<S: Signer> CryptoscoreClient<S> {
+    /// Create a lookup table owned by the payer holding `addresses`
+    ///
+    /// New entries can only be used from the slot after they're added, so
+    /// wait a slot before sending transactions that rely on them.
+    pub fn create_lookup_table(&self, addresses: &[Pubkey]) -> Result<Pubkey, ClientError> {
+        let payer = self.payer();
+        // The table address is derived from a slot that must still be in the SlotHashes sysvar
+        let recent_slot = self.rpc().get_slot_with_commitment(CommitmentConfig::finalized())?;
+        let (create, table) = create_lookup_table(payer, payer, recent_slot);
+
+        let first = addresses.len().min(EXTEND_CHUNK);
+        let mut instructions = vec![create];
+        if first > 0 {
+            instructions.push(extend_lookup_table(table, payer, Some(payer), addresses[..first].to_vec()));
+        }
+        self.send(instructions, &self.options())?;
+
+        self.extend_lookup_table(table, &addresses[first..])?;
+        Ok(table)
+    }
+
+    /// Append `addresses` to a lookup table owned by the payer
+    pub fn extend_lookup_table(&self, table: Pubkey, addresses: &[Pubkey]) -> Result<(), ClientError> {
+        let payer = self.payer();
+        for chunk in addresses.chunks(EXTEND_CHUNK) {
+            let extend = extend_lookup_table(table, payer, Some(payer), chunk.to_vec());
+            self.send(vec![extend], &self.options())?;
+        }
+        Ok(())
+    }
+
+    /// Fetch a lookup table for use with `send_v0` or `BatchBuilder::lookup_table`
+    pub fn fetch_lookup_table(&self, table: &Pubkey) -> Result<AddressLookupTableAccount, ClientError> {
+        let account = self
+            .rpc()
+            .get_account_with_commitment(table, self.rpc().commitment())?
+            .value
+            .ok_or(ClientError::AccountNotFound(*table))?;
+        let lookup_table =
+            AddressLookupTable::deserialize(&account.data).map_err(|_| ClientError::InvalidLookupTable(*table))?;
+
+        Ok(AddressLookupTableAccount {
+            key: *table,
+            addresses: lookup_table.addresses.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,74 @@
+//! Compute unit price selection from recent prioritization fees.
+
+use anchor_lang::prelude::Pubkey;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::instruction::Instruction;
+
+use crate::ClientError;
+
+/// Most accounts `getRecentPrioritizationFees` accepts per request
+const MAX_FEE_ACCOUNTS: usize = 128;
+
+/// How the compute unit price of a transaction is chosen
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriorityFee {
+    /// No compute unit price instruction
+    #[default]
+    None,
+    /// Fixed price in micro-lamports per compute unit
+    Fixed(u64),
+    /// Percentile (0-100) of the fees recently paid to write the transaction's
+    /// accounts, capped at `max_micro_lamports`
+    Percentile { percentile: u8, max_micro_lamports: u64 },
+}
+
+impl PriorityFee {
+    /// Compute unit price to attach to `instructions`, if any
+    pub fn price(&self, rpc: &RpcClient, instructions: &[Instruction]) -> Result<Option<u64>, ClientError> {
+        match *self {
+            PriorityFee::None => Ok(None),
+            PriorityFee::Fixed(micro_lamports) => Ok(Some(micro_lamports)),
+            PriorityFee::Percentile { percentile, max_micro_lamports } => {
+                let estimate = estimate(rpc, &writable_accounts(instructions), percentile)?;
+                Ok(Some(estimate.min(max_micro_lamports)))
+            }
+        }
+    }
+}
+
+/// Percentile of the prioritization fees paid in recent slots by transactions
+/// writing any of `accounts`
+///
+/// Fees are local to the accounts being written, so a busy market's price
+/// is estimated from that market rather than the whole cluster.
+pub fn estimate(rpc: &RpcClient, accounts: &[Pubkey], percentile: u8) -> Result<u64, ClientError> {
+    let accounts = &accounts[..accounts.len().min(MAX_FEE_ACCOUNTS)];
+    let mut fees: Vec<u64> = rpc
+        .get_recent_prioritization_fees(accounts)?
+        .into_iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+
+    Ok(percentile_of(&mut fees, percentile))
+}
+
+/// Nearest-rank percentile, or 0 with no samples
+pub fn percentile_of(fees: &mut [u64], percentile: u8) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let rank = (fees.len() * percentile.min(100) as usize).div_ceil(100);
+    fees[rank.saturating_sub(1)]
+}
+
+/// Writable accounts of `instructions`, without duplicates
+pub fn writable_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts = Vec::new();
+    for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+        if meta.is_writable && !accounts.contains(&meta.pubkey) {
+            accounts.push(meta.pubkey);
+        }
+    }
+    accounts
+}
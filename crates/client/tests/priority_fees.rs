@@ -0,0 +1,38 @@
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_client::priority_fees::{percentile_of, writable_accounts};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+#[test]
+fn percentile_uses_nearest_rank() {
+    let mut fees = vec![500, 0, 100, 0, 10_000, 200, 0, 300, 50, 0];
+
+    assert_eq!(percentile_of(&mut fees, 0), 0);
+    assert_eq!(percentile_of(&mut fees, 50), 50);
+    assert_eq!(percentile_of(&mut fees, 75), 300);
+    assert_eq!(percentile_of(&mut fees, 90), 500);
+    assert_eq!(percentile_of(&mut fees, 100), 10_000);
+    assert_eq!(percentile_of(&mut fees, 255), 10_000);
+    assert_eq!(percentile_of(&mut [], 75), 0);
+}
+
+#[test]
+fn fee_accounts_are_writable_and_unique() {
+    let market = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let readonly = Pubkey::new_unique();
+    let instructions = vec![
+        Instruction::new_with_bytes(
+            program,
+            &[],
+            vec![AccountMeta::new(market, false), AccountMeta::new_readonly(readonly, false)],
+        ),
+        Instruction::new_with_bytes(
+            program,
+            &[],
+            vec![AccountMeta::new(user, true), AccountMeta::new(market, false)],
+        ),
+    ];
+
+    assert_eq!(writable_accounts(&instructions), vec![market, user]);
+}
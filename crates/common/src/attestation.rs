@@ -0,0 +1,90 @@
+//! Match results signed off-chain by the platform's results signer.
+//!
+//! The signer signs `attestation_message` with ed25519 and the result is
+//! posted alongside an ed25519 program instruction built by
+//! `ed25519_instruction_data`, which the market program inspects through the
+//! instructions sysvar.
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::FinalScore;
+
+/// Prefix keeping result signatures from verifying as any other message;
+/// `v1` results carried an outcome instead of the final score
+pub const ATTESTATION_DOMAIN: &[u8] = b"cryptoscore-result-v2";
+
+/// Ed25519 public key length
+pub const ED25519_PUBKEY_LEN: usize = 32;
+/// Ed25519 signature length
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Signature count and padding, then the offsets of one signature
+const ED25519_HEADER_LEN: usize = 2 + 14;
+/// Offset index meaning "this instruction's own data"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Bytes the results signer signs to attest that `match_id` finished at `score`
+///
+/// The match ID is length-prefixed so it can't run into the goal counts.
+pub fn attestation_message(match_id: &str, score: &FinalScore, timestamp: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(ATTESTATION_DOMAIN.len() + 4 + match_id.len() + 2 + 8);
+    message.extend_from_slice(ATTESTATION_DOMAIN);
+    message.extend_from_slice(&(match_id.len() as u32).to_le_bytes());
+    message.extend_from_slice(match_id.as_bytes());
+    message.push(score.home_goals);
+    message.push(score.away_goals);
+    message.extend_from_slice(&timestamp.to_le_bytes());
+    message
+}
+
+/// Data for an ed25519 program instruction verifying one signature, with the
+/// key, signature and message all inline
+pub fn ed25519_instruction_data(signer: &Pubkey, signature: &[u8; ED25519_SIGNATURE_LEN], message: &[u8]) -> Vec<u8> {
+    let public_key_offset = ED25519_HEADER_LEN;
+    let signature_offset = public_key_offset + ED25519_PUBKEY_LEN;
+    let message_offset = signature_offset + ED25519_SIGNATURE_LEN;
+
+    let mut data = Vec::with_capacity(message_offset + message.len());
+    data.extend_from_slice(&[1, 0]);
+    for field in [
+        signature_offset as u16,
+        CURRENT_INSTRUCTION,
+        public_key_offset as u16,
+        CURRENT_INSTRUCTION,
+        message_offset as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+    data
+}
+
+/// Signer and message of an ed25519 program instruction checking exactly one
+/// signature over its own data
+///
+/// Returns `None` for any other layout, including offsets pointing into other
+/// instructions, which could otherwise vouch for a key or message the
+/// caller never sees.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    if data.len() < ED25519_HEADER_LEN || data[0] != 1 {
+        return None;
+    }
+
+    let field = |index: usize| {
+        let start = 2 + index * 2;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    let (signature_index, public_key_offset, public_key_index) = (field(1), field(2) as usize, field(3));
+    let (message_offset, message_len, message_index) = (field(4) as usize, field(5) as usize, field(6));
+    if [signature_index, public_key_index, message_index] != [CURRENT_INSTRUCTION; 3] {
+        return None;
+    }
+
+    let signer = data.get(public_key_offset..public_key_offset.checked_add(ED25519_PUBKEY_LEN)?)?;
+    let message = data.get(message_offset..message_offset.checked_add(message_len)?)?;
+    Some((Pubkey::try_from(signer).ok()?, message))
+}
@@ -0,0 +1,41 @@
+//! Hidden predictions for commit–reveal markets.
+//!
+//! Participants commit `prediction_commitment` before kickoff and reveal the
+//! prediction and salt after it. The market and user are hashed in so nobody
+//! can reuse another participant's commitment, in that market or any other.
+//!
+//! Joins can likewise carry `note_hash` of a note on the prediction, e.g. a
+//! written rationale, which anyone can check once the user publishes it.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::MatchOutcome;
+
+/// Prefix keeping commitments from colliding with any other hashed message
+pub const COMMITMENT_DOMAIN: &[u8] = b"cryptoscore-commit-v1";
+/// Salt length; a random salt keeps the three outcomes from being brute-forced
+pub const SALT_LEN: usize = 32;
+/// Prefix keeping join note hashes from colliding with commitments
+pub const NOTE_DOMAIN: &[u8] = b"cryptoscore-note-v1";
+
+/// Hash `user` commits to before revealing `prediction` in `market`
+pub fn prediction_commitment(
+    market: &Pubkey,
+    user: &Pubkey,
+    prediction: &MatchOutcome,
+    salt: &[u8; SALT_LEN],
+) -> [u8; 32] {
+    let prediction = match prediction {
+        MatchOutcome::Home => 0u8,
+        MatchOutcome::Draw => 1,
+        MatchOutcome::Away => 2,
+    };
+
+    hashv(&[COMMITMENT_DOMAIN, market.as_ref(), user.as_ref(), &[prediction], salt]).to_bytes()
+}
+
+/// Hash `user` attaches to their join of `market` for `note`
+pub fn note_hash(market: &Pubkey, user: &Pubkey, note: &[u8]) -> [u8; 32] {
+    hashv(&[NOTE_DOMAIN, market.as_ref(), user.as_ref(), note]).to_bytes()
+}
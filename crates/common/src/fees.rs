@@ -0,0 +1,212 @@
+//! Fee and payout math applied when a market settles.
+
+/// Creator fee taken from the pool at resolution (2%)
+pub const CREATOR_FEE_BPS: u64 = 200;
+/// Platform fee taken from the pool at resolution (3%)
+pub const PLATFORM_FEE_BPS: u64 = 300;
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Share of platform fees set aside for the insurance fund (10%)
+pub const INSURANCE_SHARE_BPS: u64 = 1_000;
+
+/// Fees owed from a market pool
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MarketFees {
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+}
+
+impl MarketFees {
+    pub fn total(&self) -> Option<u64> {
+        self.creator_fee.checked_add(self.platform_fee)
+    }
+}
+
+/// Largest pool whose fee products fit in a u64 (platform is the larger rate)
+pub const MAX_POOL: u64 = u64::MAX / PLATFORM_FEE_BPS;
+
+/// Why a pool could not be split
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SplitError {
+    /// Pool above `MAX_POOL`, so the fee math would overflow
+    Overflow,
+    /// Nobody picked the winning outcome
+    NoWinners,
+}
+
+/// A pool divided into fees and the prize left for winners
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PoolSplit {
+    pub fees: MarketFees,
+    /// Creator and platform fees combined
+    pub total_fees: u64,
+    pub prize_pool: u64,
+}
+
+impl PoolSplit {
+    /// Equal share of the prize pool for each of `winner_count` winners
+    pub fn reward_per_winner(&self, winner_count: u32) -> Result<u64, SplitError> {
+        self.prize_pool
+            .checked_div(winner_count as u64)
+            .ok_or(SplitError::NoWinners)
+    }
+}
+
+/// Split `total_pool` into creator fee, platform fee and prize pool
+///
+/// All settlement math goes through here so every step is checked.
+pub fn split_pool(total_pool: u64) -> Result<PoolSplit, SplitError> {
+    let fee = |bps: u64| {
+        total_pool
+            .checked_mul(bps)
+            .map(|product| product / BPS_DENOMINATOR)
+            .ok_or(SplitError::Overflow)
+    };
+    let fees = MarketFees {
+        creator_fee: fee(CREATOR_FEE_BPS)?,
+        platform_fee: fee(PLATFORM_FEE_BPS)?,
+    };
+    let total_fees = fees.total().ok_or(SplitError::Overflow)?;
+    let prize_pool = total_pool.checked_sub(total_fees).ok_or(SplitError::Overflow)?;
+
+    Ok(PoolSplit {
+        fees,
+        total_fees,
+        prize_pool,
+    })
+}
+
+/// Split a rake-free market's `total_pool`, which takes no fees and leaves
+/// all of it to the winners
+pub fn split_rake_free_pool(total_pool: u64) -> PoolSplit {
+    PoolSplit {
+        fees: MarketFees::default(),
+        total_fees: 0,
+        prize_pool: total_pool,
+    }
+}
+
+/// Largest share of a charity market's prize pool its creator can pledge (50%)
+pub const MAX_CHARITY_POOL_SHARE_BPS: u16 = 5_000;
+
+/// A charity market's pool divided into its donation and the prize left for
+/// winners
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CharitySplit {
+    /// Creator and platform fees, all given to the charity
+    pub fees: u64,
+    /// Pledged share of the prize pool given to the charity
+    pub pool_share: u64,
+    /// Fees and pool share combined
+    pub donation: u64,
+    pub prize_pool: u64,
+}
+
+impl CharitySplit {
+    /// Equal share of the prize pool for each of `winner_count` winners
+    pub fn reward_per_winner(&self, winner_count: u32) -> Result<u64, SplitError> {
+        self.prize_pool
+            .checked_div(winner_count as u64)
+            .ok_or(SplitError::NoWinners)
+    }
+}
+
+/// Split a charity market's `total_pool` into the fees it waives in the
+/// charity's favour, `pool_share_bps` of the prize pool on top, and the prize
+/// left for winners
+pub fn split_charity_pool(total_pool: u64, pool_share_bps: u16) -> Result<CharitySplit, SplitError> {
+    share_with_charity(split_pool(total_pool)?, pool_share_bps)
+}
+
+/// Give the fees of an already split pool and `pool_share_bps` of its prize
+/// pool to charity, e.g. for a rake-free charity market, which donates only
+/// the pool share
+pub fn share_with_charity(split: PoolSplit, pool_share_bps: u16) -> Result<CharitySplit, SplitError> {
+    let pool_share = u64::try_from(split.prize_pool as u128 * pool_share_bps as u128 / BPS_DENOMINATOR as u128)
+        .map_err(|_| SplitError::Overflow)?;
+    let prize_pool = split.prize_pool.checked_sub(pool_share).ok_or(SplitError::Overflow)?;
+
+    Ok(CharitySplit {
+        fees: split.total_fees,
+        pool_share,
+        donation: split.total_fees.checked_add(pool_share).ok_or(SplitError::Overflow)?,
+        prize_pool,
+    })
+}
+
+/// Share of `creator_fee` paid to a market's earliest correct entry for a
+/// bonus of `bonus_bps`, never more than the fee itself
+pub fn first_correct_bonus(creator_fee: u64, bonus_bps: u16) -> u64 {
+    let bonus = creator_fee as u128 * bonus_bps as u128 / BPS_DENOMINATOR as u128;
+    bonus.min(creator_fee as u128) as u64
+}
+
+/// Split the creator and platform fees out of `total_pool`
+pub fn market_fees(total_pool: u64) -> Option<MarketFees> {
+    split_pool(total_pool).ok().map(|split| split.fees)
+}
+
+/// Pool left for winners once fees are taken
+pub fn prize_pool_after_fees(total_pool: u64) -> Option<u64> {
+    split_pool(total_pool).ok().map(|split| split.prize_pool)
+}
+
+/// Equal share of the prize pool for each of `winner_count` winners
+pub fn reward_per_winner(total_pool: u64, winner_count: u32) -> Option<u64> {
+    split_pool(total_pool)
+        .and_then(|split| split.reward_per_winner(winner_count))
+        .ok()
+}
+
+/// Reward each of `winner_count` winners can claim from a resolved market
+/// that paid `fees_paid` out of `total_pool`, fees or a charity donation
+pub fn settled_reward_per_winner(total_pool: u64, fees_paid: u64, winner_count: u32) -> Option<u64> {
+    total_pool.checked_sub(fees_paid)?.checked_div(winner_count as u64)
+}
+
+/// Payout, in basis points of `entry_fee`, that one more participant joining
+/// a side already picked by `side_count` of a `total_pool` market would win
+///
+/// 20_000 is a 2.0x payout. `None` if the grown pool is too large to split.
+pub fn implied_payout_bps(total_pool: u64, side_count: u32, entry_fee: u64) -> Option<u64> {
+    if entry_fee == 0 {
+        return None;
+    }
+    let reward = reward_per_winner(total_pool.checked_add(entry_fee)?, side_count.checked_add(1)?)?;
+    u64::try_from(reward as u128 * BPS_DENOMINATOR as u128 / entry_fee as u128).ok()
+}
+
+/// Insurance fund's share of `total_collected` platform fees
+pub fn insurance_share(total_collected: u64) -> u64 {
+    // At most a tenth of `total_collected`, so the result always fits
+    (total_collected as u128 * INSURANCE_SHARE_BPS as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/// Share of `interest` earned on a voided pool owed to a participant who joined
+/// at `joined_at`, weighted by how long their entry fee sat in the pool
+///
+/// Entry fees are equal, so weighting by time alone is weighting by stake-seconds.
+/// The total weight is `participant_count * voided_at - joined_at_sum`, where
+/// `joined_at_sum` adds up every participant's join time. Shares round down, so
+/// their sum never exceeds `interest`.
+pub fn interest_share(
+    interest: u64,
+    joined_at: i64,
+    voided_at: i64,
+    participant_count: u32,
+    joined_at_sum: i128,
+) -> Option<u64> {
+    if participant_count == 0 {
+        return None;
+    }
+    let weight = (voided_at as i128 - joined_at as i128).max(0);
+    let total_weight = (participant_count as i128 * voided_at as i128).checked_sub(joined_at_sum)?;
+    if total_weight <= 0 {
+        // Everyone joined as the market was voided, so nobody held the pool longer
+        return Some(interest / participant_count as u64);
+    }
+    if weight > total_weight {
+        return None;
+    }
+    u64::try_from(interest as i128 * weight / total_weight).ok()
+}
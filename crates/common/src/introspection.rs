@@ -0,0 +1,28 @@
+//! Checks, through the instructions sysvar, that an instruction meant to be
+//! called only by another CryptoScore program wasn't sent directly in a
+//! transaction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+/// Whether the executing instruction was invoked through CPI by one of `callers`
+///
+/// The instructions sysvar only lists a transaction's top-level
+/// instructions, so the caller is known exactly when the instruction runs
+/// one CPI deep. Deeper down a CPI chain, e.g. a third-party program calling
+/// the market program, only the direct call is ruled out and the caller is
+/// left to the PDA signing for it.
+pub fn invoked_by(instructions: &AccountInfo, callers: &[Pubkey]) -> bool {
+    let stack_height = get_stack_height();
+    if stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT {
+        return false;
+    }
+    if stack_height > TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+        return true;
+    }
+
+    load_current_index_checked(instructions)
+        .and_then(|current| load_instruction_at_checked(usize::from(current), instructions))
+        .is_ok_and(|top_level| callers.contains(&top_level.program_id))
+}
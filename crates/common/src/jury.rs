@@ -0,0 +1,81 @@
+//! Jury draws for disputed market resolutions.
+//!
+//! Each dispute commits to a slot a few slots after it opens, and its jury
+//! is drawn with the hash of the first slot from there on, which nobody
+//! knows when the dispute opens. The hash is mixed with the dispute address
+//! so disputes committed to the same slot draw independently. Clients run the
+//! same draw to know which arbiter accounts to pass.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::slot_hashes::MAX_ENTRIES;
+
+/// Slots between a dispute opening and the slot its jury is drawn from
+pub const JURY_DRAW_DELAY_SLOTS: u64 = 32;
+
+/// Bytes of one `(slot, hash)` entry in the slot hashes sysvar
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+/// Seed a dispute's jury is drawn with
+pub fn jury_seed(randomness: &[u8; 32], dispute: &Pubkey) -> [u8; 32] {
+    hashv(&[randomness, dispute.as_ref()]).to_bytes()
+}
+
+/// Randomness for a jury draw committed to a slot
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotHashLookup {
+    /// Hash of the first slot at or after the committed one
+    Hash([u8; 32]),
+    /// No slot from the committed one on has been hashed yet
+    Pending,
+    /// The slot hashes sysvar no longer reaches back to the committed slot
+    Expired,
+}
+
+/// Look up the hash of the first slot at or after `slot` in the serialized
+/// slot hashes sysvar `data`, newest entry first
+///
+/// Skipped slots have no hash, so a leader skipping the committed slot only
+/// hands the draw to the next one. Unreadable data reads as pending.
+pub fn committed_slot_hash(data: &[u8], slot: u64) -> SlotHashLookup {
+    let Some(count) = data.get(..8).map(|len| u64::from_le_bytes(len.try_into().unwrap()) as usize) else {
+        return SlotHashLookup::Pending;
+    };
+    let mut found: Option<(u64, [u8; 32])> = None;
+    for entry in data[8..].chunks_exact(SLOT_HASH_ENTRY_LEN).take(count) {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if entry_slot < slot {
+            return found.map_or(SlotHashLookup::Pending, |(_, hash)| SlotHashLookup::Hash(hash));
+        }
+        found = Some((entry_slot, entry[8..].try_into().unwrap()));
+    }
+
+    // Every entry is at or after `slot`; unless the sysvar is full, none before it were dropped
+    match found {
+        Some((oldest, hash)) if oldest == slot || count < MAX_ENTRIES => SlotHashLookup::Hash(hash),
+        Some(_) => SlotHashLookup::Expired,
+        None => SlotHashLookup::Pending,
+    }
+}
+
+/// Draw `jury_size` distinct arbiters other than `disputer` by shuffling the
+/// pool one pick at a time with hashes of `seed`
+///
+/// Returns `None` if the pool is too small.
+pub fn draw_jurors(arbiters: &[Pubkey], disputer: &Pubkey, jury_size: usize, seed: &[u8; 32]) -> Option<Vec<Pubkey>> {
+    let mut pool: Vec<Pubkey> = arbiters.iter().filter(|arbiter| *arbiter != disputer).copied().collect();
+    if pool.len() < jury_size {
+        return None;
+    }
+
+    for pick in 0..jury_size {
+        let draw = hashv(&[seed, &(pick as u64).to_le_bytes()]).to_bytes();
+        let mut offset = [0u8; 8];
+        offset.copy_from_slice(&draw[..8]);
+        let index = pick + (u64::from_le_bytes(offset) % (pool.len() - pick) as u64) as usize;
+        pool.swap(pick, index);
+    }
+    pool.truncate(jury_size);
+
+    Some(pool)
+}
@@ -0,0 +1,334 @@
+//! Types, limits, PDA derivation, fee math, result attestations, bridged
+//! Wormhole results, prediction commitments, winner merkle proofs, jury draws,
+//! CPI caller checks, stake pool layouts, Jupiter payout swaps, compressed
+//! claim receipts, token gates, soulbound reputation tokens, discovery tags,
+//! match ID charsets and rate limits shared by the CryptoScore programs and
+//! off-chain clients.
+
+use anchor_lang::prelude::*;
+
+pub mod attestation;
+pub mod commitment;
+pub mod fees;
+pub mod introspection;
+pub mod jury;
+pub mod match_id;
+pub mod merkle;
+pub mod pda;
+pub mod rate_limit;
+pub mod receipt;
+pub mod reputation;
+pub mod stake_pool;
+pub mod swap;
+pub mod tags;
+pub mod token_gate;
+pub mod wormhole;
+
+pub const FACTORY_PROGRAM_ID: Pubkey = pubkey!("5zADKCecxATSEsCuH5MJa1JdfXGeBLNwEYnkCbqdaYmZ");
+pub const MARKET_PROGRAM_ID: Pubkey = pubkey!("BJmMs142koLJvkutSzWchPGn2CJNGqTtGQV5g3Xt87PU");
+pub const DASHBOARD_PROGRAM_ID: Pubkey = pubkey!("DHJASkp8vNuyR5xPSyj1G66xExRjnPBUuUN4QKiTnadZ");
+pub const GOVERNANCE_PROGRAM_ID: Pubkey = pubkey!("CryTSDmAGaocxE6SdUMiQmfnawgZPmHq9cDGUTeCsa5b");
+
+/// Time after a market ends during which winners can claim before funds may be swept
+pub const CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Longest a market's creator can keep resolution to themselves after the
+/// market ends, unless the factory's resolution grace policy allows less
+pub const MAX_RESOLUTION_GRACE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// How long after a market ends its factory's results signer can correct
+/// the result it resolved to, e.g. after a forfeit or an appeal
+pub const RESULT_CORRECTION_WINDOW_SECONDS: i64 = 3 * 24 * 60 * 60;
+
+/// Longest platform ID an operator can register, the most a PDA seed can hold
+pub const MAX_PLATFORM_ID_LEN: usize = 32;
+
+/// How long after registering a market its registry entry waits for the
+/// market to be initialized before anyone can close it
+pub const ORPHANED_REGISTRY_GRACE_SECONDS: i64 = 60 * 60;
+
+/// How long before kickoff queued joins are executed or refunded
+pub const QUEUED_JOIN_LEAD_SECONDS: i64 = 5 * 60;
+
+/// Co-admins a market creator can appoint to administer the market alongside them
+pub const MAX_CO_ADMINS: usize = 3;
+
+/// Discount tiers a factory can offer reputation and badge holders
+pub const MAX_DISCOUNT_TIERS: usize = 4;
+
+/// Seconds in the unix day a market's kickoff bucket covers
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Tokens a factory can let winners swap their payouts into
+pub const MAX_PAYOUT_SWAP_MINTS: usize = 8;
+
+/// Match results a results signer can publish in one batch
+pub const MAX_BATCH_RESULTS: usize = 16;
+
+/// Samples kept in a market's odds history before the oldest is overwritten
+pub const ODDS_HISTORY_SAMPLES: usize = 48;
+/// Minimum time between odds samples of one market
+pub const ODDS_SAMPLE_INTERVAL_SECONDS: i64 = 30 * 60;
+
+/// Participants listed on each page of a market's participant roster
+pub const ROSTER_PAGE_SIZE: usize = 32;
+
+/// Latest minute of play an in-play market can keep taking joins until
+pub const MAX_IN_PLAY_LOCK_MINUTE: u16 = 120;
+
+/// Players a player prop market can list as outcomes
+pub const MAX_PROP_PLAYERS: usize = 32;
+
+/// Most goals either side scores in a correct-score grid's scorelines
+pub const SCORE_GRID_MAX_GOALS: u8 = 3;
+/// Pick counts a correct-score market keeps: one per grid scoreline, then
+/// one shared by every other score
+pub const SCORE_GRID_BUCKETS: usize = 4 * 4 + 1;
+
+/// Largest handicap line either side can be given, in half goals
+pub const MAX_HANDICAP_LINE: i8 = 20;
+
+// Space Constants
+
+/// Anchor account discriminator
+pub const DISCRIMINATOR_LEN: usize = 8;
+/// Maximum match identifier length in bytes
+pub const MAX_MATCH_ID_LEN: usize = 64;
+/// Serialized match_id (String with length prefix)
+pub const MATCH_ID_SPACE: usize = 4 + MAX_MATCH_ID_LEN;
+/// Serialized `MatchOutcome`
+pub const MATCH_OUTCOME_SPACE: usize = 1;
+/// Serialized `MarketStatus`
+pub const MARKET_STATUS_SPACE: usize = 1;
+/// Serialized `OddsSample`
+pub const ODDS_SAMPLE_SPACE: usize = 8 + 4 + 4 + 4 + 8;
+/// Serialized `FinalScore`
+pub const FINAL_SCORE_SPACE: usize = 1 + 1;
+/// Serialized `MatchResult`
+pub const MATCH_RESULT_SPACE: usize = MATCH_ID_SPACE + FINAL_SCORE_SPACE;
+/// Serialized `PropKind`
+pub const PROP_KIND_SPACE: usize = 1;
+/// Serialized `Scoreline`
+pub const SCORELINE_SPACE: usize = 1 + 1 + 1;
+/// Serialized `HandicapStyle`
+pub const HANDICAP_STYLE_SPACE: usize = 1;
+/// Serialized `OutcomeSet`
+pub const OUTCOME_SET_SPACE: usize = 1;
+
+// Enums
+
+/// Lifecycle stage of a market
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MarketStatus {
+    /// Accepting predictions until kickoff
+    Open,
+    /// Match in progress
+    Live,
+    /// Outcome set; winners can withdraw
+    Resolved,
+    /// Market called off; participants can reclaim their entry fee
+    Cancelled,
+    /// At least one winner has withdrawn
+    Claiming,
+    /// Claim window closed and unclaimed funds swept; terminal
+    Settled,
+    /// At least one participant has been refunded
+    Refunding,
+    /// Every entry fee refunded; terminal
+    Closed,
+}
+
+impl MarketStatus {
+    /// Whether settlement may move a market from this status to `next`
+    ///
+    /// Resolved markets settle through `Resolved -> Claiming -> Settled`
+    /// (straight to `Settled` if nobody claimed) and cancelled ones through
+    /// `Cancelled -> Refunding -> Closed`. A resolved market nobody has
+    /// claimed from can still be voided into the refund path.
+    pub fn can_transition_to(&self, next: &MarketStatus) -> bool {
+        use MarketStatus::*;
+
+        matches!(
+            (self, next),
+            (Open | Live, Resolved)
+                | (Open | Live | Resolved, Cancelled)
+                | (Resolved, Claiming)
+                | (Resolved | Claiming, Settled)
+                | (Cancelled, Refunding)
+                | (Cancelled | Refunding, Closed)
+        )
+    }
+
+    /// Resolved, whether or not claims have started or closed
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, MarketStatus::Resolved | MarketStatus::Claiming | MarketStatus::Settled)
+    }
+
+    /// Cancelled, whether or not refunds have started or finished
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, MarketStatus::Cancelled | MarketStatus::Refunding | MarketStatus::Closed)
+    }
+}
+
+/// Result of a match, from the home team's perspective
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MatchOutcome {
+    Home,
+    Draw,
+    Away,
+}
+
+impl MatchOutcome {
+    /// Outcome of a match that finished `home_goals` to `away_goals`
+    pub fn from_score(home_goals: u8, away_goals: u8) -> Self {
+        match home_goals.cmp(&away_goals) {
+            std::cmp::Ordering::Greater => MatchOutcome::Home,
+            std::cmp::Ordering::Equal => MatchOutcome::Draw,
+            std::cmp::Ordering::Less => MatchOutcome::Away,
+        }
+    }
+}
+
+/// Outcomes a market takes predictions on and resolves to, fixed by its
+/// sport at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutcomeSet {
+    /// Home, draw or away, as in football
+    WinDrawWin,
+    /// Home or away only, for sports without draws such as tennis,
+    /// basketball or best-of-three e-sports series
+    WinLose,
+}
+
+impl OutcomeSet {
+    /// Whether markets with this set offer `outcome`
+    pub fn offers(&self, outcome: &MatchOutcome) -> bool {
+        *self == OutcomeSet::WinDrawWin || *outcome != MatchOutcome::Draw
+    }
+}
+
+/// Event a prop market is settled on, by the ID of the player, or for
+/// outrights the team, the oracle reports
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PropKind {
+    /// The next player to score
+    NextGoalscorer,
+    /// The first player to be booked
+    Booked,
+    /// The season's outright winner, e.g. a league's champion
+    Outright,
+}
+
+/// A correct-score market's pick: a scoreline on its grid or any score off it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Scoreline {
+    /// Final score on the grid, at most `SCORE_GRID_MAX_GOALS` a side
+    Exact { home: u8, away: u8 },
+    /// Any final score off the grid
+    AnyOther,
+}
+
+impl Scoreline {
+    /// Scoreline a match that finished `home_goals` to `away_goals` settles
+    /// on, `AnyOther` if either side scored more than the grid holds
+    pub fn from_score(home_goals: u8, away_goals: u8) -> Self {
+        if home_goals <= SCORE_GRID_MAX_GOALS && away_goals <= SCORE_GRID_MAX_GOALS {
+            Scoreline::Exact {
+                home: home_goals,
+                away: away_goals,
+            }
+        } else {
+            Scoreline::AnyOther
+        }
+    }
+
+    /// Index of the pick count this scoreline is tallied in, or `None` for
+    /// an exact score off the grid
+    pub fn bucket(&self) -> Option<usize> {
+        match *self {
+            Scoreline::Exact { home, away } if home <= SCORE_GRID_MAX_GOALS && away <= SCORE_GRID_MAX_GOALS => {
+                let side = SCORE_GRID_MAX_GOALS as usize + 1;
+                Some(home as usize * side + away as usize)
+            }
+            Scoreline::Exact { .. } => None,
+            Scoreline::AnyOther => Some(SCORE_GRID_BUCKETS - 1),
+        }
+    }
+}
+
+/// How a handicap market settles a result that is level once the line is
+/// applied, which only whole-goal lines can produce
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HandicapStyle {
+    /// Two-way: a level result is a push and every entry is refunded
+    Asian,
+    /// Three-way on whole-goal lines only: a level result wins for draw picks
+    European,
+}
+
+impl HandicapStyle {
+    /// Winning side of a match that finished `home_goals` to `away_goals`
+    /// with `home_line` half goals added to the home side, or `None` for an
+    /// Asian push
+    pub fn settle(&self, home_goals: u8, away_goals: u8, home_line: i8) -> Option<MatchOutcome> {
+        let margin = 2 * home_goals as i16 + home_line as i16 - 2 * away_goals as i16;
+        match margin.cmp(&0) {
+            std::cmp::Ordering::Greater => Some(MatchOutcome::Home),
+            std::cmp::Ordering::Less => Some(MatchOutcome::Away),
+            std::cmp::Ordering::Equal => match self {
+                HandicapStyle::Asian => None,
+                HandicapStyle::European => Some(MatchOutcome::Draw),
+            },
+        }
+    }
+
+    /// Whether the style can be quoted at `home_line` half goals
+    pub fn allows_line(&self, home_line: i8) -> bool {
+        let in_range = (-MAX_HANDICAP_LINE..=MAX_HANDICAP_LINE).contains(&home_line);
+        match self {
+            HandicapStyle::Asian => in_range,
+            HandicapStyle::European => in_range && home_line % 2 == 0,
+        }
+    }
+}
+
+// Structs
+
+/// A market's prediction distribution at one point in time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct OddsSample {
+    /// When the sample was taken
+    pub timestamp: i64,
+    pub home_count: u32,
+    pub draw_count: u32,
+    pub away_count: u32,
+    /// Pool size in lamports
+    pub total_pool: u64,
+}
+
+/// Goals each side scored by the final whistle, which markets resolve from
+/// rather than being told the outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FinalScore {
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+impl FinalScore {
+    pub fn new(home_goals: u8, away_goals: u8) -> Self {
+        FinalScore { home_goals, away_goals }
+    }
+
+    /// Outcome the score settles a match market on
+    pub fn outcome(&self) -> MatchOutcome {
+        MatchOutcome::from_score(self.home_goals, self.away_goals)
+    }
+}
+
+/// Final result of one match in a published results batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MatchResult {
+    /// Match identifier, as registered with the factory
+    pub match_id: String,
+    pub score: FinalScore,
+}
@@ -0,0 +1,50 @@
+//! Characters match IDs may contain, so look-alike fixture IDs can't pass
+//! for one another and clients derive market PDAs from exactly the IDs they
+//! read.
+//!
+//! A charset is a bitmap over ASCII, bit `n` allowing byte `n`. Factories
+//! accept `DEFAULT_MATCH_ID_CHARSET` unless they configure their own, which
+//! may only allow printable characters other than space.
+
+/// Bitmap of the ASCII bytes a match ID may contain
+pub type Charset = u128;
+
+/// Uppercase letters, digits and `-`
+pub const DEFAULT_MATCH_ID_CHARSET: Charset = charset_of(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-");
+
+/// Bytes `!` through `~`, the most a charset can allow
+const GRAPHIC_ASCII: Charset = ((1 << 127) - 1) & !((1 << 33) - 1);
+
+const fn charset_of(chars: &[u8]) -> Charset {
+    let mut charset = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        charset |= 1 << chars[i];
+        i += 1;
+    }
+    charset
+}
+
+/// Encode the characters of `chars` as a charset, or `None` if it's empty or
+/// has anything but printable ASCII other than space
+pub fn encode_charset(chars: &str) -> Option<Charset> {
+    if chars.is_empty() || !chars.bytes().all(|byte| byte.is_ascii_graphic()) {
+        return None;
+    }
+    Some(charset_of(chars.as_bytes()))
+}
+
+/// The characters `charset` allows, in ASCII order
+pub fn decode_charset(charset: Charset) -> String {
+    (0..128u8).filter(|&byte| charset & (1 << byte) != 0).map(char::from).collect()
+}
+
+/// Whether `charset` allows at least one character and only printable ASCII other than space
+pub fn is_valid_charset(charset: Charset) -> bool {
+    charset != 0 && charset & !GRAPHIC_ASCII == 0
+}
+
+/// Whether every byte of `match_id` is allowed by `charset`
+pub fn uses_charset(match_id: &str, charset: Charset) -> bool {
+    match_id.bytes().all(|byte| byte < 128 && charset & (1 << byte) != 0)
+}
@@ -0,0 +1,116 @@
+//! Merkle proofs of a resolved market's winners.
+//!
+//! The market program appends one `winner_leaf` per winner, in roster order,
+//! to an append-only tree of depth `WINNERS_TREE_DEPTH` whose unused leaves
+//! are zero. It keeps only the frontier, the last left node at each level, so
+//! a crank can build the tree a roster page at a time. Anyone holding the
+//! published root can then check a winner's reward with `verify_winner`.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Levels below the root; the tree holds up to 2^20 winners
+pub const WINNERS_TREE_DEPTH: usize = 20;
+
+/// Prefixes keeping leaves and inner nodes from being passed off as each other
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf proving `user` won `reward` lamports
+pub fn winner_leaf(user: &Pubkey, reward: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, user.as_ref(), &reward.to_le_bytes()]).to_bytes()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Root of an all-zero subtree at each level, from the leaves up
+fn zero_hashes() -> [[u8; 32]; WINNERS_TREE_DEPTH] {
+    let mut zeros = [[0; 32]; WINNERS_TREE_DEPTH];
+    for level in 1..WINNERS_TREE_DEPTH {
+        zeros[level] = hash_pair(&zeros[level - 1], &zeros[level - 1]);
+    }
+    zeros
+}
+
+/// Add `leaf` as leaf number `count` to the tree whose frontier is `frontier`
+///
+/// Returns `false`, leaving the frontier untouched, if the tree is full.
+pub fn append_leaf(frontier: &mut [[u8; 32]; WINNERS_TREE_DEPTH], count: u32, leaf: [u8; 32]) -> bool {
+    if count as u64 >= 1 << WINNERS_TREE_DEPTH {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut size = count;
+    for sibling in frontier.iter_mut() {
+        if size & 1 == 0 {
+            *sibling = node;
+            return true;
+        }
+        node = hash_pair(sibling, &node);
+        size >>= 1;
+    }
+    unreachable!("a tree with room for the leaf has a free frontier slot")
+}
+
+/// Root of the tree whose frontier is `frontier` after `count` leaves
+pub fn frontier_root(frontier: &[[u8; 32]; WINNERS_TREE_DEPTH], count: u32) -> [u8; 32] {
+    let zeros = zero_hashes();
+    let mut node = [0; 32];
+    let mut size = count;
+    for (level, sibling) in frontier.iter().enumerate() {
+        node = if size & 1 == 1 {
+            hash_pair(sibling, &node)
+        } else {
+            hash_pair(&node, &zeros[level])
+        };
+        size >>= 1;
+    }
+    node
+}
+
+/// Root over `leaves`, padded with zero leaves; matches the frontier root
+/// after appending them all
+pub fn winners_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut frontier = [[0; 32]; WINNERS_TREE_DEPTH];
+    for (count, leaf) in leaves.iter().enumerate() {
+        assert!(append_leaf(&mut frontier, count as u32, *leaf), "too many winners for the tree");
+    }
+    frontier_root(&frontier, leaves.len() as u32)
+}
+
+/// Sibling of leaf `index` at every level, from the leaves up
+pub fn winner_proof(leaves: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+    let zeros = zero_hashes();
+    let mut level_nodes = leaves.to_vec();
+    let mut index = index;
+    let mut proof = Vec::with_capacity(WINNERS_TREE_DEPTH);
+    for zero in zeros {
+        proof.push(level_nodes.get(index ^ 1).copied().unwrap_or(zero));
+        level_nodes = level_nodes
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&zero)))
+            .collect();
+        index >>= 1;
+    }
+    proof
+}
+
+/// Whether `proof` shows `user` won `reward` as winner number `index` under `root`
+pub fn verify_winner(root: &[u8; 32], user: &Pubkey, reward: u64, index: u32, proof: &[[u8; 32]]) -> bool {
+    if proof.len() != WINNERS_TREE_DEPTH || index as u64 >= 1 << WINNERS_TREE_DEPTH {
+        return false;
+    }
+
+    let mut node = winner_leaf(user, reward);
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (index >> level) & 1 == 1 {
+            hash_pair(sibling, &node)
+        } else {
+            hash_pair(&node, sibling)
+        };
+    }
+    node == *root
+}
@@ -0,0 +1,542 @@
+//! PDA seeds and address derivation for every CryptoScore account.
+
+use std::borrow::Cow;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::pubkey::MAX_SEED_LEN;
+
+use crate::tags::{Tag, TagCategory};
+use crate::{DASHBOARD_PROGRAM_ID, FACTORY_PROGRAM_ID, GOVERNANCE_PROGRAM_ID, MARKET_PROGRAM_ID, SECONDS_PER_DAY};
+
+pub const FACTORY_SEED: &[u8] = b"factory";
+pub const PLATFORM_SEED: &[u8] = b"platform";
+pub const MARKET_REGISTRY_SEED: &[u8] = b"market_registry";
+pub const RESULTS_SIGNER_SEED: &[u8] = b"results_signer";
+pub const WORMHOLE_EMITTER_SEED: &[u8] = b"wormhole_emitter";
+pub const RESOLUTION_POLICY_SEED: &[u8] = b"resolution_policy";
+pub const TAG_SEED: &[u8] = b"tag";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const FEE_RECEIPT_SEED: &[u8] = b"fee_receipt";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+pub const MISRESOLUTION_RULING_SEED: &[u8] = b"misresolution_ruling";
+pub const INSURANCE_CLAIM_SEED: &[u8] = b"insurance_claim";
+pub const RESULTS_BATCH_SEED: &[u8] = b"results_batch";
+pub const LIVE_SCORE_SEED: &[u8] = b"live_score";
+pub const REBATE_POLICY_SEED: &[u8] = b"rebate_policy";
+pub const DISCOUNT_POLICY_SEED: &[u8] = b"discount_policy";
+pub const KEEPER_TIP_POLICY_SEED: &[u8] = b"keeper_tip_policy";
+pub const KEEPER_TIPS_SEED: &[u8] = b"keeper_tips";
+pub const PAYOUT_SWAP_POLICY_SEED: &[u8] = b"payout_swap_policy";
+pub const RECEIPT_POLICY_SEED: &[u8] = b"receipt_policy";
+pub const RESOLUTION_GRACE_POLICY_SEED: &[u8] = b"resolution_grace_policy";
+pub const MATCH_ID_POLICY_SEED: &[u8] = b"match_id_policy";
+pub const RAKE_FREE_MARKET_SEED: &[u8] = b"rake_free_market";
+pub const KICKOFF_BUCKET_SEED: &[u8] = b"kickoff_bucket";
+pub const VOUCHER_CAMPAIGN_SEED: &[u8] = b"voucher_campaign";
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+pub const CREATION_RATE_POLICY_SEED: &[u8] = b"creation_rate_policy";
+pub const CREATOR_RATE_SEED: &[u8] = b"creator_rate";
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+pub const MARKET_SEED: &[u8] = b"market";
+pub const COMMIT_REVEAL_SEED: &[u8] = b"commit_reveal";
+pub const COMMITMENT_SEED: &[u8] = b"commitment";
+pub const TOKEN_GATE_SEED: &[u8] = b"token_gate";
+pub const CHARITY_PLEDGE_SEED: &[u8] = b"charity_pledge";
+pub const FIRST_CORRECT_BONUS_SEED: &[u8] = b"first_correct_bonus";
+pub const IN_PLAY_LOCK_SEED: &[u8] = b"in_play_lock";
+pub const PROP_REGISTRY_SEED: &[u8] = b"prop_registry";
+pub const PROP_ENTRY_SEED: &[u8] = b"prop_entry";
+pub const SCORE_GRID_SEED: &[u8] = b"score_grid";
+pub const SCORE_ENTRY_SEED: &[u8] = b"score_entry";
+pub const HANDICAP_SEED: &[u8] = b"handicap";
+pub const MARKET_CHAIN_SEED: &[u8] = b"market_chain";
+pub const ROLLED_ENTRY_SEED: &[u8] = b"rolled_entry";
+pub const OUTRIGHT_STANDINGS_SEED: &[u8] = b"outright_standings";
+pub const FOLLOW_SEED: &[u8] = b"follow";
+pub const ODDS_HISTORY_SEED: &[u8] = b"odds_history";
+pub const PARTICIPANT_SEED: &[u8] = b"participant";
+pub const PARTICIPANT_ROSTER_SEED: &[u8] = b"participant_roster";
+pub const ROSTER_PAGE_SEED: &[u8] = b"roster_page";
+pub const WINNERS_ROOT_SEED: &[u8] = b"winners_root";
+pub const QUEUED_JOIN_SEED: &[u8] = b"queued_join";
+pub const RESOLUTION_VOTES_SEED: &[u8] = b"resolution_votes";
+pub const YIELD_POSITION_SEED: &[u8] = b"yield_position";
+pub const YIELD_ESCROW_SEED: &[u8] = b"yield_escrow";
+pub const YIELD_TOKENS_SEED: &[u8] = b"yield_tokens";
+pub const CLAIM_RECEIPT_SEED: &[u8] = b"claim_receipt";
+pub const RECEIPT_AUTHORITY_SEED: &[u8] = b"receipt_authority";
+pub const SETTLEMENT_REPORT_SEED: &[u8] = b"settlement_report";
+pub const USER_STATS_SEED: &[u8] = b"user_stats";
+pub const STATS_ENTRY_SEED: &[u8] = b"stats_entry";
+pub const STATS_SNAPSHOT_SEED: &[u8] = b"stats_snapshot";
+pub const STREAK_LEADERBOARD_SEED: &[u8] = b"streak_leaderboard";
+pub const USER_BREAKDOWN_SEED: &[u8] = b"user_breakdown";
+pub const BREAKDOWN_ENTRY_SEED: &[u8] = b"breakdown_entry";
+pub const HEAD_TO_HEAD_SEED: &[u8] = b"head_to_head";
+pub const HEAD_TO_HEAD_MARKET_SEED: &[u8] = b"head_to_head_market";
+pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
+pub const USERNAME_SEED: &[u8] = b"username";
+pub const PLATFORM_STATS_SEED: &[u8] = b"platform_stats";
+pub const CREATOR_STATS_SEED: &[u8] = b"creator_stats";
+pub const CREATOR_EARNINGS_SEED: &[u8] = b"creator_earnings";
+pub const WATCHLIST_SEED: &[u8] = b"watchlist";
+pub const PENDING_CLAIMS_SEED: &[u8] = b"pending_claims";
+pub const REBATE_TRACKER_SEED: &[u8] = b"rebate_tracker";
+pub const POINTS_LEDGER_SEED: &[u8] = b"points_ledger";
+pub const POINTS_TOTALS_SEED: &[u8] = b"points_totals";
+pub const POINTS_SNAPSHOT_SEED: &[u8] = b"points_snapshot";
+pub const POINTS_TOTAL_SNAPSHOT_SEED: &[u8] = b"points_total_snapshot";
+pub const REPUTATION_MINT_SEED: &[u8] = b"reputation_mint";
+pub const PLATFORM_ACTIVITY_SEED: &[u8] = b"platform_activity";
+pub const DAILY_ROLLUP_SEED: &[u8] = b"daily_rollup";
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+pub const STAKE_POSITION_SEED: &[u8] = b"stake_position";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+pub const COURT_SEED: &[u8] = b"court";
+pub const ARBITER_SEED: &[u8] = b"arbiter";
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+
+/// Seed bytes for a match ID
+///
+/// IDs that fit in a single seed are used as-is; longer ones (up to
+/// `MAX_MATCH_ID_LEN`) are replaced by their SHA-256 hash.
+pub fn match_id_seed(match_id: &str) -> Cow<'_, [u8]> {
+    if match_id.len() <= MAX_SEED_LEN {
+        Cow::Borrowed(match_id.as_bytes())
+    } else {
+        Cow::Owned(hash(match_id.as_bytes()).to_bytes().to_vec())
+    }
+}
+
+// Factory
+
+pub fn find_factory_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FACTORY_SEED], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_platform_address(platform_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_SEED, platform_id.as_bytes()], &FACTORY_PROGRAM_ID)
+}
+
+/// Factory of an operator's platform; its markets, treasury and policies are
+/// all seeded by this address, keeping them apart from every other platform's
+pub fn find_platform_factory_address(platform: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FACTORY_SEED, platform.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_market_registry_address(factory: &Pubkey, match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_REGISTRY_SEED, factory.as_ref(), &match_id_seed(match_id)],
+        &FACTORY_PROGRAM_ID,
+    )
+}
+
+pub fn find_results_signer_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESULTS_SIGNER_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+/// Source-chain oracle whose Wormhole messages can resolve markets on `factory`
+pub fn find_wormhole_emitter_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WORMHOLE_EMITTER_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+/// Match results the results signer published under `batch_id`, e.g. one per matchweek
+pub fn find_results_batch_address(factory: &Pubkey, batch_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RESULTS_BATCH_SEED, factory.as_ref(), &batch_id.to_le_bytes()],
+        &FACTORY_PROGRAM_ID,
+    )
+}
+
+/// Score of `match_id` as the results signer reports it during the match
+pub fn find_live_score_address(factory: &Pubkey, match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LIVE_SCORE_SEED, factory.as_ref(), &match_id_seed(match_id)],
+        &FACTORY_PROGRAM_ID,
+    )
+}
+
+pub fn find_resolution_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESOLUTION_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_rebate_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBATE_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_discount_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISCOUNT_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_keeper_tip_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KEEPER_TIP_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_keeper_tips_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KEEPER_TIPS_SEED, market.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_payout_swap_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PAYOUT_SWAP_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_receipt_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_resolution_grace_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESOLUTION_GRACE_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_match_id_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MATCH_ID_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_rake_free_market_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RAKE_FREE_MARKET_SEED, market.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+/// Unix day, counted from the epoch, that a market kicking off at `kickoff_time` is listed under
+pub fn kickoff_day(kickoff_time: i64) -> u32 {
+    (kickoff_time.max(0) / SECONDS_PER_DAY) as u32
+}
+
+/// The factory's listing of markets kicking off on unix `day`
+pub fn find_kickoff_bucket_address(factory: &Pubkey, day: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KICKOFF_BUCKET_SEED, factory.as_ref(), &day.to_le_bytes()], &FACTORY_PROGRAM_ID)
+}
+
+/// The factory's promotional voucher campaign `campaign_id`
+pub fn find_voucher_campaign_address(factory: &Pubkey, campaign_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOUCHER_CAMPAIGN_SEED, factory.as_ref(), &campaign_id.to_le_bytes()],
+        &FACTORY_PROGRAM_ID,
+    )
+}
+
+/// `holder`'s single-use voucher from `campaign`
+pub fn find_voucher_address(campaign: &Pubkey, holder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOUCHER_SEED, campaign.as_ref(), holder.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_creation_rate_policy_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATION_RATE_POLICY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+/// Markets `creator` has recently created on the factory, counted against its creation rate policy
+pub fn find_creator_rate_address(factory: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_RATE_SEED, factory.as_ref(), creator.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_platform_config_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_CONFIG_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_treasury_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_fee_receipt_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_RECEIPT_SEED, market.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_insurance_fund_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[INSURANCE_FUND_SEED, factory.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_misresolution_ruling_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MISRESOLUTION_RULING_SEED, market.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+pub fn find_insurance_claim_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[INSURANCE_CLAIM_SEED, market.as_ref(), user.as_ref()], &FACTORY_PROGRAM_ID)
+}
+
+/// A tag the factory allows in `category`'s slot
+pub fn find_tag_address(factory: &Pubkey, category: TagCategory, tag: &Tag) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TAG_SEED, factory.as_ref(), &[category as u8], tag],
+        &FACTORY_PROGRAM_ID,
+    )
+}
+
+// Market
+
+pub fn find_market_address(factory: &Pubkey, match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SEED, factory.as_ref(), &match_id_seed(match_id)],
+        &MARKET_PROGRAM_ID,
+    )
+}
+
+/// Signer seeds for a market PDA, as used by the market program's own CPIs
+pub fn market_signer_seeds<'a>(factory: &'a Pubkey, match_id_seed: &'a [u8], bump: &'a [u8; 1]) -> [&'a [u8]; 4] {
+    [MARKET_SEED, factory.as_ref(), match_id_seed, bump]
+}
+
+pub fn find_participant_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PARTICIPANT_SEED, market.as_ref(), user.as_ref()],
+        &MARKET_PROGRAM_ID,
+    )
+}
+
+/// A market's participant roster, if its creator has enabled one
+pub fn find_participant_roster_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PARTICIPANT_ROSTER_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Page `page` of a market's participant roster, listing its participants
+/// from `page * ROSTER_PAGE_SIZE` in joining order
+pub fn find_roster_page_address(market: &Pubkey, page: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROSTER_PAGE_SEED, market.as_ref(), &page.to_le_bytes()], &MARKET_PROGRAM_ID)
+}
+
+/// Merkle root of a resolved market's winners, once a crank starts building it
+pub fn find_winners_root_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WINNERS_ROOT_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_commit_reveal_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMMIT_REVEAL_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Holdings a market's joiners must prove, if its creator has gated it
+pub fn find_token_gate_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOKEN_GATE_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Charity a market gives its fees to, if its creator has pledged them
+pub fn find_charity_pledge_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CHARITY_PLEDGE_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Bonus for a market's earliest correct entry, if its creator has offered one
+pub fn find_first_correct_bonus_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FIRST_CORRECT_BONUS_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_in_play_lock_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[IN_PLAY_LOCK_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_prop_registry_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROP_REGISTRY_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_prop_entry_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROP_ENTRY_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_score_grid_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SCORE_GRID_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_score_entry_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SCORE_ENTRY_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_handicap_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HANDICAP_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_market_chain_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MARKET_CHAIN_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_rolled_entry_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLLED_ENTRY_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_outright_standings_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OUTRIGHT_STANDINGS_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_commitment_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMMITMENT_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_follow_address(follower: &Pubkey, leader: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FOLLOW_SEED, follower.as_ref(), leader.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_odds_history_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ODDS_HISTORY_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_queued_join_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[QUEUED_JOIN_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_resolution_votes_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESOLUTION_VOTES_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_yield_position_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[YIELD_POSITION_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// System account the pool passes through on its way into a stake pool
+pub fn find_yield_escrow_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[YIELD_ESCROW_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Token account holding a staked pool's liquid staking tokens
+pub fn find_yield_tokens_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[YIELD_TOKENS_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Marks a user's claim on a market as having had its receipt minted
+pub fn find_claim_receipt_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_RECEIPT_SEED, market.as_ref(), user.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+/// Delegate of every receipt tree and verified creator of every receipt
+pub fn find_receipt_authority_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT_AUTHORITY_SEED], &MARKET_PROGRAM_ID)
+}
+
+pub fn find_settlement_report_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SETTLEMENT_REPORT_SEED, market.as_ref()], &MARKET_PROGRAM_ID)
+}
+
+// Dashboard
+
+pub fn find_user_stats_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_STATS_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+/// Marker that `user`'s entry in `market` has been counted in their stats
+pub fn find_stats_entry_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATS_ENTRY_SEED, market.as_ref(), user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_stats_snapshot_address(user: &Pubkey, week: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[STATS_SNAPSHOT_SEED, user.as_ref(), &week.to_le_bytes()],
+        &DASHBOARD_PROGRAM_ID,
+    )
+}
+
+pub fn find_streak_leaderboard_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STREAK_LEADERBOARD_SEED, factory.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_user_breakdown_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_BREAKDOWN_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_breakdown_entry_address(market: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BREAKDOWN_ENTRY_SEED, market.as_ref(), user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+/// Head-to-head records are keyed by the pair in ascending pubkey order
+pub fn find_head_to_head_address(user_a: &Pubkey, user_b: &Pubkey) -> (Pubkey, u8) {
+    let (first, second) = if user_a <= user_b { (user_a, user_b) } else { (user_b, user_a) };
+    Pubkey::find_program_address(
+        &[HEAD_TO_HEAD_SEED, first.as_ref(), second.as_ref()],
+        &DASHBOARD_PROGRAM_ID,
+    )
+}
+
+pub fn find_head_to_head_market_address(head_to_head: &Pubkey, market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[HEAD_TO_HEAD_MARKET_SEED, head_to_head.as_ref(), market.as_ref()],
+        &DASHBOARD_PROGRAM_ID,
+    )
+}
+
+pub fn find_user_profile_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_PROFILE_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_username_address(username: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USERNAME_SEED, username.as_bytes()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_platform_stats_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_STATS_SEED], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_creator_stats_address(creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_STATS_SEED, creator.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_creator_earnings_address(creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_EARNINGS_SEED, creator.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_watchlist_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WATCHLIST_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_pending_claims_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CLAIMS_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_rebate_tracker_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBATE_TRACKER_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_points_ledger_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POINTS_LEDGER_SEED, user.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_points_totals_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POINTS_TOTALS_SEED], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_points_snapshot_address(user: &Pubkey, epoch: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POINTS_SNAPSHOT_SEED, user.as_ref(), &epoch.to_le_bytes()],
+        &DASHBOARD_PROGRAM_ID,
+    )
+}
+
+pub fn find_points_total_snapshot_address(epoch: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POINTS_TOTAL_SNAPSHOT_SEED, &epoch.to_le_bytes()], &DASHBOARD_PROGRAM_ID)
+}
+
+/// The soulbound reputation mint, which is also its own mint authority and
+/// permanent delegate
+pub fn find_reputation_mint_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REPUTATION_MINT_SEED], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_platform_activity_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_ACTIVITY_SEED, factory.as_ref()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_daily_rollup_address(factory: &Pubkey, day: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DAILY_ROLLUP_SEED, factory.as_ref(), &day.to_le_bytes()], &DASHBOARD_PROGRAM_ID)
+}
+
+pub fn find_governance_address(factory: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE_SEED, factory.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_stake_position_address(governance: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_POSITION_SEED, governance.as_ref(), user.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_proposal_address(governance: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROPOSAL_SEED, governance.as_ref(), &id.to_le_bytes()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_vote_record_address(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_RECORD_SEED, proposal.as_ref(), voter.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_court_address(governance: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COURT_SEED, governance.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_arbiter_address(court: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ARBITER_SEED, court.as_ref(), user.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn find_dispute_address(court: &Pubkey, market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISPUTE_SEED, court.as_ref(), market.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
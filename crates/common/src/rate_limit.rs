@@ -0,0 +1,63 @@
+//! Sliding-window counters limiting how often a wallet can act, e.g. create
+//! markets.
+//!
+//! A counter only keeps the current fixed window's count and the previous
+//! one's. The previous count is weighted by how much of it the sliding window
+//! ending now still overlaps, approximating a true sliding window in constant
+//! space without letting a burst straddling two fixed windows through.
+
+use anchor_lang::prelude::*;
+
+/// Events counted in the current and previous fixed windows of some length
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SlidingWindow {
+    /// Length of the fixed windows counted in, in seconds; zero until the first count
+    pub window: i64,
+    /// Unix timestamp the current fixed window started at, a multiple of its length
+    pub window_start: i64,
+    /// Events in the current fixed window
+    pub current: u32,
+    /// Events in the fixed window before it
+    pub previous: u32,
+}
+
+impl SlidingWindow {
+    pub const LEN: usize = 8 + 8 + 4 + 4;
+
+    /// Estimated events in the `window` seconds up to `now`, rounding the
+    /// previous window's share up
+    pub fn count(&self, now: i64, window: i64) -> u32 {
+        let rolled = self.rolled(now, window);
+        let overlap = (window - (now - rolled.window_start)) as u64;
+        let previous = (rolled.previous as u64 * overlap).div_ceil(window as u64) as u32;
+        rolled.current.saturating_add(previous)
+    }
+
+    /// Whether `events` more at `now` stay within `limit` per `window` seconds
+    pub fn allows(&self, now: i64, window: i64, limit: u32, events: u32) -> bool {
+        self.count(now, window).saturating_add(events) <= limit
+    }
+
+    /// Count `events` at `now`
+    ///
+    /// Counting with a different `window` length than before starts afresh.
+    pub fn record(&mut self, now: i64, window: i64, events: u32) {
+        *self = self.rolled(now, window);
+        self.current = self.current.saturating_add(events);
+    }
+
+    /// The counter moved on to the fixed window containing `now`, or reset
+    /// if it counted in windows of another length
+    fn rolled(&self, now: i64, window: i64) -> Self {
+        let window_start = now - now.rem_euclid(window);
+        if window != self.window {
+            Self { window, window_start, current: 0, previous: 0 }
+        } else if window_start == self.window_start {
+            *self
+        } else if window_start - self.window_start == window {
+            Self { window, window_start, current: 0, previous: self.current }
+        } else {
+            Self { window, window_start, current: 0, previous: 0 }
+        }
+    }
+}
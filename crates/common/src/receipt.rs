@@ -0,0 +1,127 @@
+//! Compressed NFT claim receipts minted through Metaplex Bubblegum.
+//!
+//! Receipts go into a factory's Bubblegum tree whose delegate is the market
+//! program's receipt authority PDA, which also signs as their verified
+//! creator so genuine receipts can be told from look-alikes. `MintV1` and its
+//! metadata are laid out here without depending on the Bubblegum crates.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+
+use crate::MatchOutcome;
+
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Longest URI base a factory can set, leaving room for the claim's query
+/// within Bubblegum's 200-byte URIs
+pub const MAX_RECEIPT_URI_BASE_LEN: usize = 64;
+pub const RECEIPT_SYMBOL: &str = "CSR";
+
+/// Bubblegum's longest name
+const MAX_NAME_LEN: usize = 32;
+/// Anchor discriminator of Bubblegum's `mint_v1`
+const MINT_V1: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+/// `TokenStandard::NonFungible`
+const NON_FUNGIBLE: u8 = 0;
+/// `TokenProgramVersion::Original`
+const ORIGINAL: u8 = 0;
+
+/// Bubblegum's `MetadataArgs`; receipts never set a collection or uses, so
+/// only their `None` tag is laid out
+#[derive(AnchorSerialize)]
+struct MetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    token_standard: Option<u8>,
+    collection: Option<()>,
+    uses: Option<()>,
+    token_program_version: u8,
+    creators: Vec<Creator>,
+}
+
+#[derive(AnchorSerialize)]
+struct Creator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+/// Bubblegum's config PDA of `merkle_tree`, holding its creator and delegate
+pub fn find_tree_config_address(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_PROGRAM_ID)
+}
+
+/// Receipt name for a market's match, cut to Bubblegum's 32 bytes
+pub fn receipt_name(match_id: &str) -> String {
+    let mut name = format!("CryptoScore {}", match_id);
+    while name.len() > MAX_NAME_LEN {
+        name.pop();
+    }
+    name
+}
+
+/// Receipt URI under `uri_base` encoding the claim, e.g.
+/// `https://example.com/receipt?market=…&prediction=Home&stake=…&payout=…`
+pub fn receipt_uri(uri_base: &str, market: &Pubkey, prediction: &MatchOutcome, stake: u64, payout: u64) -> String {
+    format!(
+        "{}?market={}&prediction={:?}&stake={}&payout={}",
+        uri_base, market, prediction, stake, payout
+    )
+}
+
+/// `MintV1` of a receipt named `name` at `uri` into `merkle_tree` for
+/// `owner`, paid by `payer` and signed by `authority` as the tree's delegate
+/// and the receipt's verified creator
+pub fn mint_receipt(
+    merkle_tree: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    name: String,
+    uri: String,
+) -> Instruction {
+    let metadata = MetadataArgs {
+        name,
+        symbol: RECEIPT_SYMBOL.to_string(),
+        uri,
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: Some(NON_FUNGIBLE),
+        collection: None,
+        uses: None,
+        token_program_version: ORIGINAL,
+        creators: vec![Creator {
+            address: *authority,
+            verified: true,
+            share: 100,
+        }],
+    };
+    let mut data = MINT_V1.to_vec();
+    metadata.serialize(&mut data).expect("writing to a vec can't fail");
+
+    Instruction {
+        program_id: BUBBLEGUM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(find_tree_config_address(merkle_tree).0, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new(*merkle_tree, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
@@ -0,0 +1,167 @@
+//! Soulbound reputation tokens other protocols can read as a sybil-resistance
+//! signal.
+//!
+//! The dashboard program owns a Token-2022 mint with the `NonTransferable`
+//! extension, so balances can't be sold or pooled, and the `PermanentDelegate`
+//! extension, so the dashboard can burn a balance back down when a user's
+//! record gets worse. Instructions are laid out by hand like `stake_pool`'s.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+
+use crate::stake_pool::TOKEN_ACCOUNT_LEN;
+use crate::token_gate::ASSOCIATED_TOKEN_PROGRAM_ID;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Reputation is a whole-number balance
+pub const REPUTATION_DECIMALS: u8 = 0;
+/// Settled markets before accuracy counts, so a lucky first pick isn't worth
+/// a perfect record
+pub const MIN_ACCURACY_MARKETS: u64 = 5;
+
+/// Extension type and length heading each Token-2022 extension
+const EXTENSION_HEADER_LEN: usize = 2 + 2;
+/// Size of the reputation mint: the base mint padded to a token account's
+/// length, the account type, then the `NonTransferable` (empty) and
+/// `PermanentDelegate` extensions
+pub const REPUTATION_MINT_LEN: usize = TOKEN_ACCOUNT_LEN + 1 + EXTENSION_HEADER_LEN + EXTENSION_HEADER_LEN + 32;
+
+/// `TokenInstruction` variants
+const MINT_TO: u8 = 7;
+const BURN: u8 = 8;
+const INITIALIZE_MINT_2: u8 = 20;
+const INITIALIZE_NON_TRANSFERABLE_MINT: u8 = 32;
+const INITIALIZE_PERMANENT_DELEGATE: u8 = 35;
+
+/// `AssociatedTokenAccountInstruction::CreateIdempotent`
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// Offsets of the fields of a token account, the same for Token-2022
+const TOKEN_MINT_OFFSET: usize = 0;
+const TOKEN_OWNER_OFFSET: usize = 32;
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
+/// Reputation of a user with `wins` and `losses` on settled markets: one token
+/// per settled market plus their accuracy in whole percent once
+/// `MIN_ACCURACY_MARKETS` have settled, so a worsening record burns tokens
+///
+/// Voided markets say nothing about skill and don't count.
+pub fn reputation_balance(wins: u32, losses: u32) -> u64 {
+    let settled = wins as u64 + losses as u64;
+    if settled < MIN_ACCURACY_MARKETS {
+        return settled;
+    }
+    settled + wins as u64 * 100 / settled
+}
+
+/// `user`'s reputation token account, the Token-2022 associated token account
+/// for `mint`
+pub fn find_reputation_account_address(user: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[user.as_ref(), TOKEN_2022_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// `InitializeNonTransferableMint` for the uninitialized `mint`
+pub fn initialize_non_transferable_mint(mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data: vec![INITIALIZE_NON_TRANSFERABLE_MINT],
+    }
+}
+
+/// `InitializePermanentDelegate` letting `delegate` burn from any of the
+/// uninitialized `mint`'s token accounts
+pub fn initialize_permanent_delegate(mint: &Pubkey, delegate: &Pubkey) -> Instruction {
+    let mut data = vec![INITIALIZE_PERMANENT_DELEGATE];
+    data.extend_from_slice(delegate.as_ref());
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+/// `InitializeMint2` for a reputation mint with `authority` as mint authority
+/// and no freeze authority, after its extensions are initialized
+pub fn initialize_mint(mint: &Pubkey, authority: &Pubkey) -> Instruction {
+    let mut data = vec![INITIALIZE_MINT_2, REPUTATION_DECIMALS];
+    data.extend_from_slice(authority.as_ref());
+    // COption::None freeze authority
+    data.push(0);
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+/// `CreateIdempotent` for `user`'s reputation token account, paid by `payer`
+pub fn create_reputation_account(payer: &Pubkey, user: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(find_reputation_account_address(user, mint).0, false),
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT],
+    }
+}
+
+/// `MintTo` of `amount` reputation into `account`, signed by the mint `authority`
+pub fn mint_reputation(mint: &Pubkey, account: &Pubkey, authority: &Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![MINT_TO];
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+/// `Burn` of `amount` reputation from `account`, signed by the mint's
+/// permanent `delegate`
+pub fn burn_reputation(mint: &Pubkey, account: &Pubkey, delegate: &Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![BURN];
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(*delegate, true),
+        ],
+        data,
+    }
+}
+
+/// Mint, owner and balance of a Token-2022 token account, which may carry
+/// extensions after the base layout
+pub fn parse_token_2022_account(data: &[u8]) -> Option<(Pubkey, Pubkey, u64)> {
+    if data.len() < TOKEN_ACCOUNT_LEN {
+        return None;
+    }
+    let amount = data[TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8].try_into().ok()?;
+    Some((
+        Pubkey::new_from_array(data[TOKEN_MINT_OFFSET..TOKEN_MINT_OFFSET + 32].try_into().ok()?),
+        Pubkey::new_from_array(data[TOKEN_OWNER_OFFSET..TOKEN_OWNER_OFFSET + 32].try_into().ok()?),
+        u64::from_le_bytes(amount),
+    ))
+}
+
+/// Balance of a Token-2022 token account
+pub fn token_2022_amount(data: &[u8]) -> Option<u64> {
+    parse_token_2022_account(data).map(|(_, _, amount)| amount)
+}
@@ -0,0 +1,153 @@
+//! SPL stake pool accounts and instructions used to park an idle market pool
+//! in a liquid staking token such as jitoSOL.
+//!
+//! Only the few fields and instructions the market program needs are laid out
+//! here, read straight from account data the way the stake pool and token
+//! programs serialize them.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{stake, system_program, sysvar};
+
+pub const STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Seed of the stake pool's withdraw authority, after the pool address
+pub const WITHDRAW_AUTHORITY_SEED: &[u8] = b"withdraw";
+/// Size of an SPL token account
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// `AccountType::StakePool` tag leading a stake pool account
+const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+/// Offsets of the fields the market program needs in a `StakePool` account
+const RESERVE_STAKE_OFFSET: usize = 130;
+const POOL_MINT_OFFSET: usize = 162;
+const MANAGER_FEE_ACCOUNT_OFFSET: usize = 194;
+
+/// `StakePoolInstruction` variants
+const DEPOSIT_SOL: u8 = 14;
+const WITHDRAW_SOL: u8 = 16;
+
+/// `TokenInstruction` variants
+const INITIALIZE_ACCOUNT_3: u8 = 18;
+
+/// Offsets of the fields of an SPL token account
+const TOKEN_MINT_OFFSET: usize = 0;
+const TOKEN_OWNER_OFFSET: usize = 32;
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
+/// Accounts a stake pool's SOL deposits and withdrawals go through
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StakePoolAccounts {
+    pub stake_pool: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub pool_mint: Pubkey,
+}
+
+impl StakePoolAccounts {
+    /// Read the accounts from the data of the `StakePool` account at `stake_pool`
+    pub fn parse(stake_pool: Pubkey, data: &[u8]) -> Option<Self> {
+        if *data.first()? != STAKE_POOL_ACCOUNT_TYPE {
+            return None;
+        }
+        Some(Self {
+            stake_pool,
+            withdraw_authority: find_withdraw_authority(&stake_pool).0,
+            reserve_stake: read_pubkey(data, RESERVE_STAKE_OFFSET)?,
+            manager_fee_account: read_pubkey(data, MANAGER_FEE_ACCOUNT_OFFSET)?,
+            pool_mint: read_pubkey(data, POOL_MINT_OFFSET)?,
+        })
+    }
+}
+
+/// PDA allowed to mint and burn the pool's tokens
+pub fn find_withdraw_authority(stake_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[stake_pool.as_ref(), WITHDRAW_AUTHORITY_SEED], &STAKE_POOL_PROGRAM_ID)
+}
+
+/// `DepositSol` staking `lamports` from the system account `from` for pool
+/// tokens minted to `destination`
+pub fn deposit_sol(pool: &StakePoolAccounts, from: &Pubkey, destination: &Pubkey, lamports: u64) -> Instruction {
+    let mut data = vec![DEPOSIT_SOL];
+    data.extend_from_slice(&lamports.to_le_bytes());
+    Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(pool.stake_pool, false),
+            AccountMeta::new_readonly(pool.withdraw_authority, false),
+            AccountMeta::new(pool.reserve_stake, false),
+            AccountMeta::new(*from, true),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new(pool.manager_fee_account, false),
+            // The manager fee account stands in as the referrer
+            AccountMeta::new(pool.manager_fee_account, false),
+            AccountMeta::new(pool.pool_mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// `WithdrawSol` burning `pool_tokens` from `source`, approved by its owner
+/// `authority`, for SOL from the reserve paid to `destination`
+pub fn withdraw_sol(
+    pool: &StakePoolAccounts,
+    authority: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    pool_tokens: u64,
+) -> Instruction {
+    let mut data = vec![WITHDRAW_SOL];
+    data.extend_from_slice(&pool_tokens.to_le_bytes());
+    Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(pool.stake_pool, false),
+            AccountMeta::new_readonly(pool.withdraw_authority, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*source, false),
+            AccountMeta::new(pool.reserve_stake, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new(pool.manager_fee_account, false),
+            AccountMeta::new(pool.pool_mint, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::stake_history::ID, false),
+            AccountMeta::new_readonly(stake::program::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// `InitializeAccount3` making `owner` the owner of the new token account
+/// `account` for `mint`
+pub fn initialize_token_account(account: &Pubkey, mint: &Pubkey, owner: &Pubkey) -> Instruction {
+    let mut data = vec![INITIALIZE_ACCOUNT_3];
+    data.extend_from_slice(owner.as_ref());
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*account, false), AccountMeta::new_readonly(*mint, false)],
+        data,
+    }
+}
+
+/// Mint, owner and balance of an SPL token account
+pub fn parse_token_account(data: &[u8]) -> Option<(Pubkey, Pubkey, u64)> {
+    if data.len() != TOKEN_ACCOUNT_LEN {
+        return None;
+    }
+    let amount = data[TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8].try_into().ok()?;
+    Some((
+        read_pubkey(data, TOKEN_MINT_OFFSET)?,
+        read_pubkey(data, TOKEN_OWNER_OFFSET)?,
+        u64::from_le_bytes(amount),
+    ))
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(bytes))
+}
@@ -0,0 +1,48 @@
+//! Jupiter swaps paying a winner's SOL reward out in another token.
+//!
+//! The route is quoted off-chain through Jupiter's API and reaches the market
+//! program as opaque instruction data with its accounts; only the token
+//! instructions around it are laid out here.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+
+use crate::stake_pool::TOKEN_PROGRAM_ID;
+use crate::token_gate::{find_associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID};
+
+pub const JUPITER_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+/// Wrapped SOL, whose token accounts count their spare lamports as balance
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// `TokenInstruction::SyncNative`
+const SYNC_NATIVE: u8 = 17;
+/// `AssociatedTokenAccountInstruction::CreateIdempotent`
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// `SyncNative` adding lamports sent to the wrapped SOL account `account` to
+/// its token balance
+pub fn sync_native(account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*account, false)],
+        data: vec![SYNC_NATIVE],
+    }
+}
+
+/// `CreateIdempotent` opening `owner`'s associated token account for `mint`,
+/// paid by `payer`, unless it already exists
+pub fn create_associated_token_account(payer: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(find_associated_token_address(owner, mint).0, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT],
+    }
+}
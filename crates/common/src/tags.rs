@@ -0,0 +1,72 @@
+//! Discovery tags stored at fixed offsets on factory market registry entries.
+//!
+//! Each registry entry has one slot per `TagCategory`. A slot holds a short
+//! lowercase tag such as `epl`, `england` or `final`, zero-padded to
+//! `TAG_LEN` bytes, or all zeroes when unset. Only tags the factory has
+//! registered may be used.
+
+use anchor_lang::prelude::*;
+
+/// Bytes per tag slot
+pub const TAG_LEN: usize = 16;
+/// Tag slots on each registry entry, one per `TagCategory`
+pub const TAG_SLOTS: usize = 4;
+/// Serialized `Tags`
+pub const TAGS_SPACE: usize = TAG_LEN * TAG_SLOTS;
+
+/// One zero-padded tag
+pub type Tag = [u8; TAG_LEN];
+/// A registry entry's tags, indexed by `TagCategory::slot`
+pub type Tags = [Tag; TAG_SLOTS];
+
+/// An unset tag slot
+pub const NO_TAG: Tag = [0; TAG_LEN];
+
+/// What a tag slot describes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TagCategory {
+    League,
+    Country,
+    Stage,
+    Sport,
+}
+
+impl TagCategory {
+    pub const ALL: [TagCategory; TAG_SLOTS] =
+        [TagCategory::League, TagCategory::Country, TagCategory::Stage, TagCategory::Sport];
+
+    /// Index of this category's slot in `Tags`
+    pub fn slot(self) -> usize {
+        self as usize
+    }
+}
+
+/// Encode `value` as a tag: 1 to `TAG_LEN` lowercase letters, digits or `-`
+pub fn encode_tag(value: &str) -> Option<Tag> {
+    if value.is_empty() || value.len() > TAG_LEN || !value.bytes().all(is_tag_byte) {
+        return None;
+    }
+
+    let mut tag = NO_TAG;
+    tag[..value.len()].copy_from_slice(value.as_bytes());
+    Some(tag)
+}
+
+/// Whether `tag` is a non-empty encoded tag with nothing after its padding starts
+pub fn is_valid_tag(tag: &Tag) -> bool {
+    let len = tag.iter().position(|&byte| byte == 0).unwrap_or(TAG_LEN);
+    len > 0 && tag[..len].iter().copied().all(is_tag_byte) && tag[len..].iter().all(|&byte| byte == 0)
+}
+
+/// The tag's text, or `None` for an unset or malformed slot
+pub fn decode_tag(tag: &Tag) -> Option<&str> {
+    if !is_valid_tag(tag) {
+        return None;
+    }
+    let len = tag.iter().position(|&byte| byte == 0).unwrap_or(TAG_LEN);
+    std::str::from_utf8(&tag[..len]).ok()
+}
+
+fn is_tag_byte(byte: u8) -> bool {
+    byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-'
+}
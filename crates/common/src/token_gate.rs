@@ -0,0 +1,158 @@
+//! Holder-only markets gated on an SPL token or a Metaplex NFT collection.
+//!
+//! Joiners prove their holdings with their token account and, for
+//! collections, the NFT's metadata account, which `parse_metadata_collection`
+//! reads without depending on the Metaplex crates.
+
+use anchor_lang::prelude::*;
+
+use crate::stake_pool::{parse_token_account, TOKEN_ACCOUNT_LEN, TOKEN_PROGRAM_ID};
+
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Seed of a mint's metadata PDA, before the metadata program and mint
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+/// `Key::MetadataV1` tag leading a metadata account
+const METADATA_V1_KEY: u8 = 4;
+/// Key and update authority, ahead of the mint
+const METADATA_MINT_OFFSET: usize = 1 + 32;
+/// Creator address, verified flag and share
+const CREATOR_LEN: usize = 32 + 1 + 1;
+
+/// What a market's joiners must hold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum TokenGateRule {
+    /// At least `min_amount` base units of `mint`, e.g. a club's fan token
+    Token { mint: Pubkey, min_amount: u64 },
+    /// Any NFT of a verified Metaplex collection
+    Collection { collection: Pubkey },
+}
+
+impl TokenGateRule {
+    /// Serialized size of the largest variant
+    pub const SPACE: usize = 1 + 32 + 8;
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            TokenGateRule::Token { mint, min_amount } => *mint != Pubkey::default() && *min_amount > 0,
+            TokenGateRule::Collection { collection } => *collection != Pubkey::default(),
+        }
+    }
+
+    /// Whether `owner`'s token account data, and for collections the owner
+    /// of `metadata` and its data, satisfy the rule
+    ///
+    /// The caller checks the token account is owned by the token program.
+    pub fn is_met_by(&self, owner: &Pubkey, token_account: &[u8], metadata: Option<(&Pubkey, &[u8])>) -> bool {
+        let Some((mint, holder, amount)) = parse_token_account(token_account) else {
+            return false;
+        };
+        if holder != *owner {
+            return false;
+        }
+
+        match self {
+            TokenGateRule::Token { mint: gated, min_amount } => mint == *gated && amount >= *min_amount,
+            TokenGateRule::Collection { collection } => {
+                let Some((metadata_owner, metadata)) = metadata else {
+                    return false;
+                };
+                amount > 0
+                    && *metadata_owner == METADATA_PROGRAM_ID
+                    && parse_metadata_collection(metadata) == Some((mint, Some(*collection)))
+            }
+        }
+    }
+}
+
+/// `owner`'s associated token account for `mint`, where wallets keep their tokens
+pub fn find_associated_token_address(owner: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+pub fn find_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[METADATA_SEED, METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &METADATA_PROGRAM_ID,
+    )
+}
+
+/// Mint of a Metaplex metadata account and its collection, if verified
+///
+/// Only the metadata program writes metadata, and only at the mint's PDA,
+/// so a metadata account it owns naming `mint` is that mint's metadata.
+pub fn parse_metadata_collection(data: &[u8]) -> Option<(Pubkey, Option<Pubkey>)> {
+    if data.first() != Some(&METADATA_V1_KEY) {
+        return None;
+    }
+    let mint = Pubkey::try_from(data.get(METADATA_MINT_OFFSET..METADATA_MINT_OFFSET + 32)?).ok()?;
+
+    let mut rest = &data[METADATA_MINT_OFFSET + 32..];
+    // Name, symbol and URI
+    for _ in 0..3 {
+        let len = u32::from_le_bytes(rest.get(..4)?.try_into().ok()?) as usize;
+        rest = rest.get(4usize.checked_add(len)?..)?;
+    }
+    // Seller fee basis points
+    rest = rest.get(2..)?;
+    if *rest.first()? == 1 {
+        let count = u32::from_le_bytes(rest.get(1..5)?.try_into().ok()?) as usize;
+        rest = rest.get(5usize.checked_add(count.checked_mul(CREATOR_LEN)?)?..)?;
+    } else {
+        rest = rest.get(1..)?;
+    }
+    // Primary sale happened and is mutable
+    rest = rest.get(2..)?;
+    // Edition nonce and token standard
+    for _ in 0..2 {
+        rest = rest.get(if *rest.first()? == 1 { 2 } else { 1 }..)?;
+    }
+
+    let collection = match rest.first() {
+        Some(1) => {
+            let verified = *rest.get(1)? == 1;
+            let key = Pubkey::try_from(rest.get(2..34)?).ok()?;
+            verified.then_some(key)
+        }
+        _ => None,
+    };
+    Some((mint, collection))
+}
+
+/// SPL token account data holding `amount` of `mint` for `owner`, with the
+/// fields the gate ignores zeroed apart from the initialized state; for
+/// tests and local validators
+pub fn token_account_data(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0; TOKEN_ACCOUNT_LEN];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // `AccountState::Initialized`, after the delegate option
+    data[108] = 1;
+    data
+}
+
+/// Metaplex metadata account data for `mint` with empty name, symbol and
+/// URI, no creators and `collection` as `(key, verified)`; for tests and
+/// local validators
+pub fn metadata_data(mint: &Pubkey, collection: Option<(Pubkey, bool)>) -> Vec<u8> {
+    let mut data = vec![METADATA_V1_KEY];
+    data.extend_from_slice(&[0; 32]);
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(&[0; 3 * 4 + 2]);
+    // No creators, primary sale, mutable, no edition nonce, no token standard
+    data.extend_from_slice(&[0, 0, 1, 0, 0]);
+    match collection {
+        Some((key, verified)) => {
+            data.extend_from_slice(&[1, verified as u8]);
+            data.extend_from_slice(key.as_ref());
+        }
+        None => data.push(0),
+    }
+    data
+}
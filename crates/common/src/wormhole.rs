@@ -0,0 +1,96 @@
+//! Match results bridged from another chain through Wormhole.
+//!
+//! An oracle contract on the source chain publishes `result_payload` as a
+//! Wormhole message. Once guardians sign it, the core bridge verifies the VAA
+//! and stores it in a posted VAA account, which `parse_posted_vaa` reads. The
+//! payload is big-endian so EVM contracts can build it with `abi.encodePacked`.
+
+use crate::attestation::ATTESTATION_DOMAIN;
+use crate::FinalScore;
+
+/// Leading bytes of a posted VAA account written by the core bridge
+pub const POSTED_VAA_MAGIC: &[u8] = b"vaa";
+
+/// Magic, version, consistency level, VAA time, signature set, submission
+/// time, nonce, sequence, emitter chain and emitter address
+const POSTED_VAA_HEADER_LEN: usize = 3 + 1 + 1 + 4 + 32 + 4 + 4 + 8 + 2 + 32;
+
+/// The parts of a posted VAA a result consumer checks
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PostedVaa<'a> {
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub payload: &'a [u8],
+}
+
+/// Result of one match as published by the source-chain oracle
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BridgedResult<'a> {
+    pub match_id: &'a str,
+    pub score: FinalScore,
+    /// When the oracle observed the result, in Unix seconds
+    pub timestamp: i64,
+}
+
+/// Emitter and payload of a core bridge posted VAA account
+///
+/// Returns `None` unless the data starts with the posted VAA magic and holds
+/// the whole payload. Only the core bridge can write such accounts, so
+/// callers must check the account's owner before trusting the result.
+pub fn parse_posted_vaa(data: &[u8]) -> Option<PostedVaa<'_>> {
+    if data.len() < POSTED_VAA_HEADER_LEN + 4 || !data.starts_with(POSTED_VAA_MAGIC) {
+        return None;
+    }
+
+    let sequence = u64::from_le_bytes(data[49..57].try_into().ok()?);
+    let emitter_chain = u16::from_le_bytes(data[57..59].try_into().ok()?);
+    let emitter_address = data[59..91].try_into().ok()?;
+    let payload_len = u32::from_le_bytes(data[91..95].try_into().ok()?) as usize;
+    let payload = data.get(95..95usize.checked_add(payload_len)?)?;
+    Some(PostedVaa { emitter_chain, emitter_address, sequence, payload })
+}
+
+/// Payload the source-chain oracle publishes to report that `match_id`
+/// finished at `score`
+pub fn result_payload(match_id: &str, score: &FinalScore, timestamp: i64) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(ATTESTATION_DOMAIN.len() + 2 + match_id.len() + 2 + 8);
+    payload.extend_from_slice(ATTESTATION_DOMAIN);
+    payload.extend_from_slice(&(match_id.len() as u16).to_be_bytes());
+    payload.extend_from_slice(match_id.as_bytes());
+    payload.push(score.home_goals);
+    payload.push(score.away_goals);
+    payload.extend_from_slice(&timestamp.to_be_bytes());
+    payload
+}
+
+/// Inverse of `result_payload`; `None` for anything else, including
+/// trailing bytes
+pub fn parse_result_payload(payload: &[u8]) -> Option<BridgedResult<'_>> {
+    let rest = payload.strip_prefix(ATTESTATION_DOMAIN)?;
+    let len = u16::from_be_bytes(rest.get(..2)?.try_into().ok()?) as usize;
+    let rest = &rest[2..];
+    let match_id = std::str::from_utf8(rest.get(..len)?).ok()?;
+    let rest = &rest[len..];
+    let (home_goals, away_goals) = (*rest.first()?, *rest.get(1)?);
+    let timestamp: [u8; 8] = rest[2..].try_into().ok()?;
+    Some(BridgedResult {
+        match_id,
+        score: FinalScore::new(home_goals, away_goals),
+        timestamp: i64::from_be_bytes(timestamp),
+    })
+}
+
+/// Posted VAA account data as the core bridge lays it out, with the fields
+/// result consumers ignore zeroed; for tests and local validators
+pub fn posted_vaa_data(emitter_chain: u16, emitter_address: &[u8; 32], sequence: u64, payload: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(POSTED_VAA_HEADER_LEN + 4 + payload.len());
+    data.extend_from_slice(POSTED_VAA_MAGIC);
+    data.extend_from_slice(&[0; 1 + 1 + 4 + 32 + 4 + 4]);
+    data.extend_from_slice(&sequence.to_le_bytes());
+    data.extend_from_slice(&emitter_chain.to_le_bytes());
+    data.extend_from_slice(emitter_address);
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(payload);
+    data
+}
@@ -0,0 +1,56 @@
+//! Result attestation messages and the ed25519 instruction layout.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::attestation::{attestation_message, ed25519_instruction_data, parse_ed25519_instruction};
+use cryptoscore_common::{FinalScore, MatchOutcome};
+
+#[test]
+fn messages_bind_match_score_and_time() {
+    let message = attestation_message("EPL-ARS-CHE", &FinalScore::new(2, 1), 1_700_000_000);
+
+    assert_ne!(message, attestation_message("EPL-ARS-CHF", &FinalScore::new(2, 1), 1_700_000_000));
+    assert_ne!(message, attestation_message("EPL-ARS-CHE", &FinalScore::new(1, 2), 1_700_000_000));
+    assert_ne!(message, attestation_message("EPL-ARS-CHE", &FinalScore::new(3, 1), 1_700_000_000));
+    assert_ne!(message, attestation_message("EPL-ARS-CHE", &FinalScore::new(2, 1), 1_700_000_001));
+    // The length prefix keeps the ID from absorbing the goal counts
+    assert_ne!(
+        attestation_message("A", &FinalScore::new(1, 0), 0),
+        attestation_message("A\u{1}", &FinalScore::new(0, 0), 0),
+    );
+}
+
+#[test]
+fn final_scores_derive_the_outcome() {
+    assert_eq!(FinalScore::new(3, 1).outcome(), MatchOutcome::Home);
+    assert_eq!(FinalScore::new(2, 2).outcome(), MatchOutcome::Draw);
+    assert_eq!(FinalScore::new(0, 1).outcome(), MatchOutcome::Away);
+}
+
+#[test]
+fn inline_instructions_round_trip() {
+    let signer = Pubkey::new_unique();
+    let message = attestation_message("EPL-ARS-CHE", &FinalScore::new(1, 1), 42);
+    let data = ed25519_instruction_data(&signer, &[7; 64], &message);
+
+    assert_eq!(parse_ed25519_instruction(&data), Some((signer, message.as_slice())));
+}
+
+#[test]
+fn offsets_into_other_instructions_are_rejected() {
+    let signer = Pubkey::new_unique();
+    let message = attestation_message("EPL-ARS-CHE", &FinalScore::new(1, 1), 42);
+    let inline = ed25519_instruction_data(&signer, &[7; 64], &message);
+
+    // Signature, public key and message instruction indices in turn
+    for field in [1, 3, 6] {
+        let mut data = inline.clone();
+        let start = 2 + field * 2;
+        data[start..start + 2].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(parse_ed25519_instruction(&data), None);
+    }
+
+    let mut two_signatures = inline.clone();
+    two_signatures[0] = 2;
+    assert_eq!(parse_ed25519_instruction(&two_signatures), None);
+    assert_eq!(parse_ed25519_instruction(&inline[..inline.len() - 1]), None);
+}
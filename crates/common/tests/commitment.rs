@@ -0,0 +1,20 @@
+//! Prediction commitments for commit–reveal markets.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::commitment::prediction_commitment;
+use cryptoscore_common::MatchOutcome;
+
+#[test]
+fn commitments_bind_every_input() {
+    let (market, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let salt = [7u8; 32];
+    let commitment = prediction_commitment(&market, &user, &MatchOutcome::Draw, &salt);
+
+    assert_eq!(prediction_commitment(&market, &user, &MatchOutcome::Draw, &salt), commitment);
+    assert_ne!(prediction_commitment(&market, &user, &MatchOutcome::Home, &salt), commitment);
+    assert_ne!(prediction_commitment(&market, &user, &MatchOutcome::Draw, &[8u8; 32]), commitment);
+
+    // Another user or market can't replay a revealed commitment
+    assert_ne!(prediction_commitment(&market, &Pubkey::new_unique(), &MatchOutcome::Draw, &salt), commitment);
+    assert_ne!(prediction_commitment(&Pubkey::new_unique(), &user, &MatchOutcome::Draw, &salt), commitment);
+}
@@ -0,0 +1,50 @@
+use cryptoscore_common::HandicapStyle::{self, *};
+use cryptoscore_common::{MatchOutcome, MAX_HANDICAP_LINE};
+
+const STYLES: [HandicapStyle; 2] = [Asian, European];
+
+#[test]
+fn half_goal_lines_always_pick_a_side() {
+    // Home -1.5 needs a two-goal win
+    for style in STYLES {
+        assert_eq!(style.settle(2, 0, -3), Some(MatchOutcome::Home));
+        assert_eq!(style.settle(2, 1, -3), Some(MatchOutcome::Away));
+        assert_eq!(style.settle(0, 0, -3), Some(MatchOutcome::Away));
+    }
+
+    // Home +0.5 wins on a draw
+    assert_eq!(Asian.settle(1, 1, 1), Some(MatchOutcome::Home));
+    assert_eq!(Asian.settle(1, 2, 1), Some(MatchOutcome::Away));
+}
+
+#[test]
+fn level_whole_goal_lines_push_on_asian_and_draw_on_european() {
+    // Home -1 winning by exactly one
+    assert_eq!(Asian.settle(2, 1, -2), None);
+    assert_eq!(European.settle(2, 1, -2), Some(MatchOutcome::Draw));
+    assert_eq!(Asian.settle(3, 1, -2), Some(MatchOutcome::Home));
+
+    // A level line is the plain result, with draws pushed on Asian lines
+    assert_eq!(Asian.settle(0, 0, 0), None);
+    assert_eq!(European.settle(0, 0, 0), Some(MatchOutcome::Draw));
+    assert_eq!(Asian.settle(0, 1, 0), Some(MatchOutcome::Away));
+}
+
+#[test]
+fn european_lines_are_whole_goals_within_the_limit() {
+    assert!(Asian.allows_line(-3));
+    assert!(!European.allows_line(-3));
+    assert!(European.allows_line(-2));
+    for style in STYLES {
+        assert!(style.allows_line(MAX_HANDICAP_LINE));
+        assert!(style.allows_line(-MAX_HANDICAP_LINE));
+        assert!(!style.allows_line(MAX_HANDICAP_LINE + 2));
+        assert!(!style.allows_line(i8::MIN));
+    }
+}
+
+#[test]
+fn extreme_scores_do_not_overflow() {
+    assert_eq!(Asian.settle(u8::MAX, 0, -MAX_HANDICAP_LINE), Some(MatchOutcome::Home));
+    assert_eq!(European.settle(0, u8::MAX, MAX_HANDICAP_LINE), Some(MatchOutcome::Away));
+}
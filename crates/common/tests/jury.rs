@@ -0,0 +1,60 @@
+//! Jury draws for disputed market resolutions.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::slot_hashes::MAX_ENTRIES;
+use cryptoscore_common::jury::{committed_slot_hash, draw_jurors, jury_seed, SlotHashLookup};
+
+#[test]
+fn draws_are_distinct_deterministic_and_skip_the_disputer() {
+    let arbiters: Vec<Pubkey> = (0..8).map(|_| Pubkey::new_unique()).collect();
+    let disputer = arbiters[3];
+    let seed = jury_seed(&[9u8; 32], &Pubkey::new_unique());
+
+    let jury = draw_jurors(&arbiters, &disputer, 5, &seed).unwrap();
+    assert_eq!(jury.len(), 5);
+    assert!(!jury.contains(&disputer));
+    assert!(jury.iter().all(|juror| arbiters.contains(juror)));
+    assert!(jury.iter().enumerate().all(|(i, juror)| !jury[..i].contains(juror)));
+    assert_eq!(draw_jurors(&arbiters, &disputer, 5, &seed), Some(jury));
+
+    // Seven arbiters besides the disputer can't seat eight
+    assert_eq!(draw_jurors(&arbiters, &disputer, 8, &seed), None);
+    assert_eq!(draw_jurors(&arbiters, &disputer, 7, &seed).map(|jury| jury.len()), Some(7));
+}
+
+#[test]
+fn seeds_bind_the_dispute() {
+    let randomness = [1u8; 32];
+    let dispute = Pubkey::new_unique();
+
+    assert_eq!(jury_seed(&randomness, &dispute), jury_seed(&randomness, &dispute));
+    assert_ne!(jury_seed(&randomness, &Pubkey::new_unique()), jury_seed(&randomness, &dispute));
+    assert_ne!(jury_seed(&[2u8; 32], &dispute), jury_seed(&randomness, &dispute));
+}
+
+/// Serialized slot hashes sysvar holding `slots`, newest first, each hashed to its own number
+fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+    let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+    for slot in slots {
+        data.extend_from_slice(&slot.to_le_bytes());
+        data.extend_from_slice(&[*slot as u8; 32]);
+    }
+    data
+}
+
+#[test]
+fn draws_use_the_first_slot_hash_from_the_committed_slot() {
+    let data = slot_hashes(&[104, 103, 101, 100]);
+    assert_eq!(committed_slot_hash(&data, 101), SlotHashLookup::Hash([101; 32]));
+
+    // A skipped slot hands the draw to the next one hashed
+    assert_eq!(committed_slot_hash(&data, 102), SlotHashLookup::Hash([103; 32]));
+    assert_eq!(committed_slot_hash(&data, 105), SlotHashLookup::Pending);
+    assert_eq!(committed_slot_hash(&[], 100), SlotHashLookup::Pending);
+
+    // Until the sysvar fills up it reaches back to the first slot
+    assert_eq!(committed_slot_hash(&data, 90), SlotHashLookup::Hash([100; 32]));
+    let full: Vec<u64> = (0..MAX_ENTRIES as u64).rev().map(|slot| slot + 1_000).collect();
+    assert_eq!(committed_slot_hash(&slot_hashes(&full), 1_000), SlotHashLookup::Hash([1_000u64 as u8; 32]));
+    assert_eq!(committed_slot_hash(&slot_hashes(&full), 999), SlotHashLookup::Expired);
+}
@@ -0,0 +1,36 @@
+//! Match ID charsets shared by the factory and market programs and their clients.
+
+use cryptoscore_common::match_id::{
+    decode_charset, encode_charset, is_valid_charset, uses_charset, DEFAULT_MATCH_ID_CHARSET,
+};
+
+#[test]
+fn default_charset_allows_uppercase_fixture_ids() {
+    for match_id in ["EPL-ARS-CHE", "EPL-2024-123", "UCL-FINAL"] {
+        assert!(uses_charset(match_id, DEFAULT_MATCH_ID_CHARSET), "{:?}", match_id);
+    }
+
+    // Lowercase, spaces and look-alike letters from other scripts are rejected
+    for match_id in ["epl-ars-che", "EPL ARS-CHE", "EPL_ARS_CHE", "EPL-\u{0410}RS-CHE"] {
+        assert!(!uses_charset(match_id, DEFAULT_MATCH_ID_CHARSET), "{:?}", match_id);
+    }
+
+    assert_eq!(decode_charset(DEFAULT_MATCH_ID_CHARSET), "-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    assert!(is_valid_charset(DEFAULT_MATCH_ID_CHARSET));
+}
+
+#[test]
+fn charsets_round_trip_printable_ascii() {
+    let charset = encode_charset("abc_123").unwrap();
+    assert_eq!(decode_charset(charset), "123_abc");
+    assert!(is_valid_charset(charset));
+    assert!(uses_charset("a_1", charset));
+    assert!(!uses_charset("A_1", charset));
+
+    for chars in ["", "a b", "tab\t", "\u{00e9}"] {
+        assert_eq!(encode_charset(chars), None, "{:?}", chars);
+    }
+    assert!(!is_valid_charset(0));
+    assert!(!is_valid_charset(1 << b' '));
+    assert!(!is_valid_charset(1 << 127));
+}
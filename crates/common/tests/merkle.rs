@@ -0,0 +1,38 @@
+//! Merkle proofs of a market's winners.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::merkle::{
+    append_leaf, frontier_root, verify_winner, winner_leaf, winner_proof, winners_root, WINNERS_TREE_DEPTH,
+};
+
+#[test]
+fn every_winner_proves_their_reward() {
+    let winners: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let leaves: Vec<[u8; 32]> = winners.iter().map(|user| winner_leaf(user, 1_000)).collect();
+    let root = winners_root(&leaves);
+
+    for (index, user) in winners.iter().enumerate() {
+        let proof = winner_proof(&leaves, index);
+        assert_eq!(proof.len(), WINNERS_TREE_DEPTH);
+        assert!(verify_winner(&root, user, 1_000, index as u32, &proof));
+
+        // The reward, position and proof are all bound to the root
+        assert!(!verify_winner(&root, user, 1_001, index as u32, &proof));
+        assert!(!verify_winner(&root, user, 1_000, index as u32 ^ 1, &proof));
+        assert!(!verify_winner(&root, user, 1_000, index as u32, &proof[1..]));
+    }
+    assert!(!verify_winner(&root, &Pubkey::new_unique(), 1_000, 0, &winner_proof(&leaves, 0)));
+}
+
+#[test]
+fn frontier_roots_track_every_append() {
+    let leaves: Vec<[u8; 32]> = (0..9u64).map(|reward| winner_leaf(&Pubkey::new_unique(), reward)).collect();
+    let mut frontier = [[0; 32]; WINNERS_TREE_DEPTH];
+    assert_eq!(frontier_root(&frontier, 0), winners_root(&[]));
+
+    for (count, leaf) in leaves.iter().enumerate() {
+        assert!(append_leaf(&mut frontier, count as u32, *leaf));
+        assert_eq!(frontier_root(&frontier, count as u32 + 1), winners_root(&leaves[..=count]));
+    }
+    assert!(!append_leaf(&mut frontier, 1 << WINNERS_TREE_DEPTH, leaves[0]));
+}
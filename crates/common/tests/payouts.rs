@@ -0,0 +1,228 @@
+//! Property tests for settlement fee and payout math.
+//!
+//! Settlement is replayed against a simulated market vault the way the market
+//! program applies it: fees leave at resolution, then each winner may withdraw
+//! once, in any order, with every debit checked.
+
+use cryptoscore_common::fees::{
+    first_correct_bonus, implied_payout_bps, insurance_share, interest_share, market_fees, prize_pool_after_fees,
+    reward_per_winner, share_with_charity, split_charity_pool, split_pool, split_rake_free_pool, MarketFees, SplitError,
+    BPS_DENOMINATOR, CREATOR_FEE_BPS, INSURANCE_SHARE_BPS, MAX_CHARITY_POOL_SHARE_BPS, MAX_POOL, PLATFORM_FEE_BPS,
+};
+use proptest::prelude::*;
+
+/// Rent-exempt minimum of a market account, which stays in the vault
+const RENT: u64 = 2_500_000;
+
+#[derive(Debug)]
+struct Scenario {
+    entry_fee: u64,
+    /// Number of participants on the winning outcome, then the losers
+    winners: u32,
+    losers: u32,
+    /// Indices of winners attempting to withdraw, with repeats
+    claims: Vec<usize>,
+}
+
+fn scenario() -> impl Strategy<Value = Scenario> {
+    (1..=100_000_000_000u64, 0..500u32, 0..500u32).prop_flat_map(|(entry_fee, winners, losers)| {
+        let claim = if winners == 0 { Just(0usize).boxed() } else { (0..winners as usize).boxed() };
+        prop::collection::vec(claim, 0..(2 * winners as usize + 1)).prop_map(move |claims| Scenario {
+            entry_fee,
+            winners,
+            losers,
+            claims,
+        })
+    })
+}
+
+#[test]
+fn splits_pools_at_u64_boundaries() {
+    let empty = split_pool(0).unwrap();
+    assert_eq!(empty.prize_pool, 0);
+    assert_eq!(empty.reward_per_winner(1), Ok(0));
+
+    let largest = split_pool(MAX_POOL).unwrap();
+    assert_eq!(largest.fees.platform_fee, MAX_POOL * PLATFORM_FEE_BPS / BPS_DENOMINATOR);
+    assert_eq!(largest.prize_pool + largest.total_fees, MAX_POOL);
+    assert_eq!(largest.reward_per_winner(u32::MAX), Ok(largest.prize_pool / u32::MAX as u64));
+
+    assert_eq!(split_pool(MAX_POOL + 1), Err(SplitError::Overflow));
+    assert_eq!(split_pool(u64::MAX), Err(SplitError::Overflow));
+}
+
+#[test]
+fn splitting_without_winners_is_an_error() {
+    assert_eq!(split_pool(1_000).unwrap().reward_per_winner(0), Err(SplitError::NoWinners));
+    assert_eq!(split_pool(0).unwrap().reward_per_winner(0), Err(SplitError::NoWinners));
+}
+
+#[test]
+fn interest_follows_time_in_the_pool() {
+    // Joined at 0, 600 and 900, voided at 1_000: weights 1_000, 400 and 100
+    let (joins, voided_at) = ([0, 600, 900], 1_000);
+    let sum: i128 = joins.iter().map(|&t| t as i128).sum();
+    let shares: Vec<u64> = joins
+        .iter()
+        .map(|&joined_at| interest_share(1_500, joined_at, voided_at, 3, sum).unwrap())
+        .collect();
+    assert_eq!(shares, [1_000, 400, 100]);
+
+    // Nobody held the pool, so any interest is split evenly
+    assert_eq!(interest_share(300, 1_000, 1_000, 3, 3_000), Some(100));
+    assert_eq!(interest_share(300, 0, 1_000, 0, 0), None);
+}
+
+proptest! {
+    #[test]
+    fn fees_never_exceed_pool(pool in 0..=MAX_POOL) {
+        let fees = market_fees(pool).unwrap();
+        let total = fees.total().unwrap();
+
+        prop_assert!(total <= pool);
+        prop_assert_eq!(fees.creator_fee, pool * CREATOR_FEE_BPS / BPS_DENOMINATOR);
+        prop_assert_eq!(fees.platform_fee, pool * PLATFORM_FEE_BPS / BPS_DENOMINATOR);
+        prop_assert_eq!(prize_pool_after_fees(pool).unwrap() + total, pool);
+    }
+
+    #[test]
+    fn charity_donations_and_prizes_add_up_to_the_pool(pool in 0..=MAX_POOL, share in 0..=MAX_CHARITY_POOL_SHARE_BPS) {
+        let split = split_charity_pool(pool, share).unwrap();
+
+        prop_assert_eq!(split.fees, market_fees(pool).unwrap().total().unwrap());
+        prop_assert_eq!(split.donation, split.fees + split.pool_share);
+        prop_assert_eq!(split.donation + split.prize_pool, pool);
+        prop_assert!(split.prize_pool <= prize_pool_after_fees(pool).unwrap());
+    }
+
+    #[test]
+    fn rake_free_pools_go_entirely_to_winners(pool in any::<u64>(), winners in 1..=u32::MAX) {
+        let split = split_rake_free_pool(pool);
+
+        prop_assert_eq!(split.fees, MarketFees::default());
+        prop_assert_eq!((split.total_fees, split.prize_pool), (0, pool));
+        let reward = split.reward_per_winner(winners).unwrap();
+        prop_assert_eq!(reward, pool / winners as u64);
+        let claimed = reward as u128 * winners as u128;
+        prop_assert!(claimed <= pool as u128);
+        prop_assert!(pool as u128 - claimed < u128::from(winners));
+    }
+
+    #[test]
+    fn rake_free_charity_markets_donate_only_the_pool_share(
+        pool in any::<u64>(),
+        share in 0..=MAX_CHARITY_POOL_SHARE_BPS,
+    ) {
+        let split = share_with_charity(split_rake_free_pool(pool), share).unwrap();
+
+        prop_assert_eq!(split.fees, 0);
+        prop_assert_eq!(split.donation, split.pool_share);
+        prop_assert_eq!(split.donation + split.prize_pool, pool);
+    }
+
+    #[test]
+    fn insurance_takes_a_fixed_share_of_platform_fees(collected in any::<u64>()) {
+        let share = insurance_share(collected);
+
+        prop_assert!(share <= collected);
+        prop_assert_eq!(share as u128, collected as u128 * INSURANCE_SHARE_BPS as u128 / BPS_DENOMINATOR as u128);
+    }
+
+    #[test]
+    fn first_correct_bonuses_come_out_of_the_creator_fee(pool in 0..=MAX_POOL, bonus_bps in 0..=u16::MAX) {
+        let creator_fee = market_fees(pool).unwrap().creator_fee;
+        let bonus = first_correct_bonus(creator_fee, bonus_bps);
+
+        prop_assert!(bonus <= creator_fee);
+        if bonus_bps as u64 >= BPS_DENOMINATOR {
+            prop_assert_eq!(bonus, creator_fee);
+        }
+    }
+
+    #[test]
+    fn oversized_pools_are_rejected(pool in (MAX_POOL + 1)..=u64::MAX) {
+        prop_assert_eq!(market_fees(pool), None);
+        prop_assert_eq!(reward_per_winner(pool, 1), None);
+    }
+
+    #[test]
+    fn no_winners_means_no_reward(pool in 0..=MAX_POOL) {
+        prop_assert_eq!(reward_per_winner(pool, 0), None);
+    }
+
+    #[test]
+    fn dust_is_bounded_by_winner_count(pool in 0..=MAX_POOL, winners in 1..=u32::MAX) {
+        let prize = prize_pool_after_fees(pool).unwrap();
+        let reward = reward_per_winner(pool, winners).unwrap();
+        let paid = reward as u128 * winners as u128;
+
+        prop_assert!(paid <= prize as u128);
+        prop_assert!(prize as u128 - paid < winners as u128);
+    }
+
+    #[test]
+    fn settlement_never_overdraws_the_vault(s in scenario()) {
+        let participants = (s.winners + s.losers) as u64;
+        let Some(pool) = s.entry_fee.checked_mul(participants).filter(|pool| *pool <= MAX_POOL) else {
+            return Ok(());
+        };
+
+        // Resolution: fees leave the vault
+        let mut vault = RENT + pool;
+        let fees = market_fees(pool).unwrap();
+        vault = vault.checked_sub(fees.total().unwrap()).expect("fees overdraw the vault");
+
+        // Withdrawals: each winner is paid at most once
+        let mut withdrawn = vec![false; s.winners as usize];
+        let mut paid = 0u64;
+        for &winner in &s.claims {
+            if s.winners == 0 || withdrawn[winner] {
+                continue;
+            }
+            let reward = reward_per_winner(pool, s.winners).unwrap();
+            vault = vault.checked_sub(reward).expect("withdrawal overdraws the vault");
+            withdrawn[winner] = true;
+            paid += reward;
+        }
+
+        let prize = prize_pool_after_fees(pool).unwrap();
+        prop_assert!(paid <= prize);
+        prop_assert!(vault >= RENT);
+
+        // Once everyone has claimed, only rent and rounding dust remain
+        if withdrawn.iter().all(|claimed| *claimed) && s.winners > 0 {
+            prop_assert!(vault - RENT < s.winners as u64);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn interest_shares_never_exceed_the_interest(
+        interest in 0..=u64::MAX,
+        joins in prop::collection::vec(0i64..=1_000_000, 1..50),
+        held in 0i64..=1_000_000,
+    ) {
+        let voided_at = joins.iter().max().unwrap() + held;
+        let sum: i128 = joins.iter().map(|&t| t as i128).sum();
+        let mut paid: u128 = 0;
+        for &joined_at in &joins {
+            paid += interest_share(interest, joined_at, voided_at, joins.len() as u32, sum).unwrap() as u128;
+        }
+        prop_assert!(paid <= interest as u128);
+    }
+}
+
+proptest! {
+    #[test]
+    fn more_entries_on_a_side_never_raise_its_payout(
+        entry_fee in 1..=1_000_000_000_000u64,
+        entries in 0..10_000u32,
+        others in 0..10_000u32,
+    ) {
+        let pool = entry_fee * (entries + others) as u64;
+        let now = implied_payout_bps(pool, entries, entry_fee).unwrap();
+        let after = implied_payout_bps(pool + entry_fee, entries + 1, entry_fee).unwrap();
+        prop_assert!(after <= now);
+    }
+}
@@ -0,0 +1,28 @@
+//! Match ID seeding: short IDs keep their original addresses, long ones hash.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::pda::{find_market_address, match_id_seed, MARKET_SEED};
+use cryptoscore_common::{MARKET_PROGRAM_ID, MAX_MATCH_ID_LEN};
+
+#[test]
+fn short_match_ids_are_used_raw() {
+    let factory = Pubkey::new_unique();
+    let match_id = "EPL-2024-ARS-CHE-0001";
+
+    assert_eq!(match_id_seed(match_id).as_ref(), match_id.as_bytes());
+    assert_eq!(
+        find_market_address(&factory, match_id),
+        Pubkey::find_program_address(&[MARKET_SEED, factory.as_ref(), match_id.as_bytes()], &MARKET_PROGRAM_ID),
+    );
+}
+
+#[test]
+fn long_match_ids_are_hashed() {
+    let factory = Pubkey::new_unique();
+    let long = "X".repeat(MAX_MATCH_ID_LEN);
+    let longer = format!("{}Y", &long[..MAX_MATCH_ID_LEN - 1]);
+
+    assert_eq!(match_id_seed(&long).len(), 32);
+    assert_ne!(match_id_seed(&long), match_id_seed(&longer));
+    assert_ne!(find_market_address(&factory, &long), find_market_address(&factory, &longer));
+}
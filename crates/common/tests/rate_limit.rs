@@ -0,0 +1,49 @@
+//! Sliding-window rate limits.
+
+use cryptoscore_common::rate_limit::SlidingWindow;
+
+const DAY: i64 = 24 * 60 * 60;
+
+#[test]
+fn counts_within_a_fixed_window_add_up() {
+    let mut counter = SlidingWindow::default();
+    let start = 100 * DAY;
+    counter.record(start + 10, DAY, 3);
+    counter.record(start + DAY / 2, DAY, 2);
+
+    assert_eq!(counter.count(start + DAY / 2, DAY), 5);
+    assert!(counter.allows(start + DAY / 2, DAY, 10, 5));
+    assert!(!counter.allows(start + DAY / 2, DAY, 10, 6));
+}
+
+#[test]
+fn the_previous_window_fades_out_as_the_sliding_window_moves_on() {
+    let mut counter = SlidingWindow::default();
+    let start = 100 * DAY;
+    counter.record(start + DAY - 1, DAY, 10);
+
+    // A burst just before the boundary still counts in full right after it
+    assert_eq!(counter.count(start + DAY, DAY), 10);
+    assert_eq!(counter.count(start + DAY + DAY / 4, DAY), 8);
+    assert_eq!(counter.count(start + 2 * DAY - 1, DAY), 1);
+    assert_eq!(counter.count(start + 2 * DAY, DAY), 0);
+
+    counter.record(start + DAY + DAY / 2, DAY, 1);
+    assert_eq!(counter, SlidingWindow { window: DAY, window_start: start + DAY, current: 1, previous: 10 });
+    assert_eq!(counter.count(start + DAY + DAY / 2, DAY), 6);
+}
+
+#[test]
+fn idle_windows_and_new_window_lengths_start_afresh() {
+    let mut counter = SlidingWindow::default();
+    let start = 100 * DAY;
+    counter.record(start, DAY, 4);
+    counter.record(start + 3 * DAY, DAY, 1);
+    assert_eq!(counter, SlidingWindow { window: DAY, window_start: start + 3 * DAY, current: 1, previous: 0 });
+
+    // Even when the new length's window starts where the old one's did
+    counter.record(start + 4 * DAY, DAY, 3);
+    counter.record(start + 4 * DAY, 2 * DAY, 2);
+    assert_eq!(counter.count(start + 4 * DAY, 2 * DAY), 2);
+    assert_eq!(counter.count(start + 4 * DAY, DAY), 0);
+}
@@ -0,0 +1,50 @@
+//! Bubblegum mints of compressed claim receipts.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::pda::find_receipt_authority_address;
+use cryptoscore_common::receipt::{
+    find_tree_config_address, mint_receipt, receipt_name, receipt_uri, BUBBLEGUM_PROGRAM_ID, RECEIPT_SYMBOL,
+};
+use cryptoscore_common::MatchOutcome;
+
+#[test]
+fn receipts_are_minted_to_the_claimer_signed_by_the_receipt_authority() {
+    let (tree, owner, payer) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let authority = find_receipt_authority_address().0;
+
+    let ix = mint_receipt(&tree, &owner, &payer, &authority, "name".into(), "uri".into());
+    assert_eq!(ix.program_id, BUBBLEGUM_PROGRAM_ID);
+    assert_eq!(ix.accounts[0].pubkey, find_tree_config_address(&tree).0);
+    assert_eq!(ix.accounts[1].pubkey, owner);
+    assert_eq!(ix.accounts[2].pubkey, owner);
+    assert!(ix.accounts[3].is_writable && ix.accounts[3].pubkey == tree);
+    assert!(ix.accounts[4].is_signer && ix.accounts[4].pubkey == payer);
+    assert!(ix.accounts[5].is_signer && !ix.accounts[5].is_writable);
+    assert!(ix.accounts.iter().filter(|meta| meta.is_signer).count() == 2);
+
+    // name, symbol and uri follow the discriminator, then the authority is the only, verified, creator
+    let mut expected = vec![4, 0, 0, 0];
+    expected.extend_from_slice(b"name");
+    expected.extend_from_slice(&[3, 0, 0, 0]);
+    expected.extend_from_slice(RECEIPT_SYMBOL.as_bytes());
+    expected.extend_from_slice(&[3, 0, 0, 0]);
+    expected.extend_from_slice(b"uri");
+    assert_eq!(&ix.data[8..8 + expected.len()], &expected[..]);
+    let creators = &ix.data[ix.data.len() - 38..];
+    assert_eq!(creators[..4], [1, 0, 0, 0]);
+    assert_eq!(creators[4..36], authority.to_bytes());
+    assert_eq!(creators[36..], [1, 100]);
+}
+
+#[test]
+fn receipt_metadata_encodes_the_claim() {
+    assert_eq!(receipt_name("EPL-2024-ARS-CHE"), "CryptoScore EPL-2024-ARS-CHE");
+    assert_eq!(receipt_name(&"x".repeat(40)).len(), 32);
+
+    let market = Pubkey::new_unique();
+    let uri = receipt_uri("https://example.com/receipt", &market, &MatchOutcome::Draw, 100, 250);
+    assert_eq!(
+        uri,
+        format!("https://example.com/receipt?market={}&prediction=Draw&stake=100&payout=250", market)
+    );
+}
@@ -0,0 +1,61 @@
+//! Soulbound reputation balances and the Token-2022 instructions behind them.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::reputation::{
+    burn_reputation, initialize_mint, mint_reputation, parse_token_2022_account, reputation_balance, token_2022_amount,
+    REPUTATION_DECIMALS, REPUTATION_MINT_LEN, TOKEN_2022_PROGRAM_ID,
+};
+
+#[test]
+fn reputation_weighs_settled_markets_by_accuracy() {
+    assert_eq!(reputation_balance(0, 0), 0);
+    // Accuracy only counts once enough markets have settled
+    assert_eq!(reputation_balance(4, 0), 4);
+    assert_eq!(reputation_balance(5, 0), 105);
+    assert_eq!(reputation_balance(5, 1), 6 + 83);
+    assert_eq!(reputation_balance(0, 5), 5);
+    // The same record length is worth more with better picks
+    assert!(reputation_balance(8, 2) > reputation_balance(5, 5));
+    assert_eq!(reputation_balance(u32::MAX, u32::MAX), 2 * u32::MAX as u64 + 50);
+}
+
+#[test]
+fn reputation_mints_fit_both_extensions() {
+    // Base mint padded to 165 bytes, account type, NonTransferable, PermanentDelegate
+    assert_eq!(REPUTATION_MINT_LEN, 165 + 1 + 4 + 4 + 32);
+
+    let (mint, authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ix = initialize_mint(&mint, &authority);
+    assert_eq!(ix.program_id, TOKEN_2022_PROGRAM_ID);
+    assert_eq!(ix.data[..2], [20, REPUTATION_DECIMALS]);
+    assert_eq!(ix.data[2..34], authority.to_bytes());
+    assert_eq!(ix.data[34..], [0]);
+}
+
+#[test]
+fn reputation_is_minted_and_burned_by_the_authority() {
+    let (mint, account, authority) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+    let minted = mint_reputation(&mint, &account, &authority, 5);
+    assert_eq!(minted.data, [&[7][..], &5u64.to_le_bytes()].concat());
+    assert_eq!(minted.accounts[0].pubkey, mint);
+    assert!(minted.accounts[2].is_signer);
+
+    let burned = burn_reputation(&mint, &account, &authority, 2);
+    assert_eq!(burned.data, [&[8][..], &2u64.to_le_bytes()].concat());
+    assert_eq!(burned.accounts[0].pubkey, account);
+    assert!(burned.accounts[2].is_signer);
+}
+
+#[test]
+fn token_2022_balances_read_past_extensions() {
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![0; 165 + 1 + 8];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&42u64.to_le_bytes());
+    assert_eq!(parse_token_2022_account(&data), Some((mint, owner, 42)));
+    assert_eq!(token_2022_amount(&data), Some(42));
+    assert_eq!(token_2022_amount(&data[..165]), Some(42));
+    assert_eq!(token_2022_amount(&data[..100]), None);
+}
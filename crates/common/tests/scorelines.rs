@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use cryptoscore_common::{Scoreline, SCORE_GRID_BUCKETS, SCORE_GRID_MAX_GOALS};
+
+#[test]
+fn grid_scorelines_fill_every_bucket_but_the_last() {
+    let mut buckets = HashSet::new();
+    for home in 0..=SCORE_GRID_MAX_GOALS {
+        for away in 0..=SCORE_GRID_MAX_GOALS {
+            let scoreline = Scoreline::from_score(home, away);
+            assert_eq!(scoreline, Scoreline::Exact { home, away });
+            let bucket = scoreline.bucket().unwrap();
+            assert!(bucket < SCORE_GRID_BUCKETS - 1, "{}-{}", home, away);
+            assert!(buckets.insert(bucket), "{}-{} shares a bucket", home, away);
+        }
+    }
+    assert_eq!(buckets.len(), SCORE_GRID_BUCKETS - 1);
+}
+
+#[test]
+fn scores_off_the_grid_share_the_any_other_bucket() {
+    let other = Some(SCORE_GRID_BUCKETS - 1);
+    assert_eq!(Scoreline::AnyOther.bucket(), other);
+    for (home, away) in [(SCORE_GRID_MAX_GOALS + 1, 0), (0, SCORE_GRID_MAX_GOALS + 1), (7, 5), (u8::MAX, u8::MAX)] {
+        let scoreline = Scoreline::from_score(home, away);
+        assert_eq!(scoreline, Scoreline::AnyOther);
+        assert_eq!(scoreline.bucket(), other);
+    }
+
+    // Off-grid scores can only be picked through the shared bucket
+    assert_eq!(Scoreline::Exact { home: SCORE_GRID_MAX_GOALS + 1, away: 0 }.bucket(), None);
+}
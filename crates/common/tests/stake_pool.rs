@@ -0,0 +1,69 @@
+//! SPL stake pool and token account layouts read by the market program.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::stake_pool::{
+    deposit_sol, find_withdraw_authority, parse_token_account, withdraw_sol, StakePoolAccounts, STAKE_POOL_PROGRAM_ID,
+    TOKEN_ACCOUNT_LEN,
+};
+
+/// A `StakePool` account with the given reserve, mint and manager fee account
+fn stake_pool_data(reserve_stake: &Pubkey, pool_mint: &Pubkey, manager_fee_account: &Pubkey) -> Vec<u8> {
+    let mut data = vec![0; 611];
+    data[0] = 1;
+    data[130..162].copy_from_slice(reserve_stake.as_ref());
+    data[162..194].copy_from_slice(pool_mint.as_ref());
+    data[194..226].copy_from_slice(manager_fee_account.as_ref());
+    data
+}
+
+#[test]
+fn stake_pools_parse_their_deposit_accounts() {
+    let (stake_pool, reserve_stake, pool_mint, manager_fee_account) =
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let data = stake_pool_data(&reserve_stake, &pool_mint, &manager_fee_account);
+
+    let pool = StakePoolAccounts::parse(stake_pool, &data).unwrap();
+    assert_eq!(pool.withdraw_authority, find_withdraw_authority(&stake_pool).0);
+    assert_eq!(pool.reserve_stake, reserve_stake);
+    assert_eq!(pool.pool_mint, pool_mint);
+    assert_eq!(pool.manager_fee_account, manager_fee_account);
+
+    // Validator lists and truncated accounts are rejected
+    let mut validator_list = data.clone();
+    validator_list[0] = 2;
+    assert_eq!(StakePoolAccounts::parse(stake_pool, &validator_list), None);
+    assert_eq!(StakePoolAccounts::parse(stake_pool, &data[..200]), None);
+    assert_eq!(StakePoolAccounts::parse(stake_pool, &[]), None);
+}
+
+#[test]
+fn token_accounts_parse_mint_owner_and_balance() {
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![0; TOKEN_ACCOUNT_LEN];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&42u64.to_le_bytes());
+
+    assert_eq!(parse_token_account(&data), Some((mint, owner, 42)));
+    // Mints and token-2022 accounts with extensions differ in size
+    assert_eq!(parse_token_account(&data[..82]), None);
+}
+
+#[test]
+fn sol_deposits_and_withdrawals_sign_with_the_right_accounts() {
+    let stake_pool = Pubkey::new_unique();
+    let data = stake_pool_data(&Pubkey::new_unique(), &Pubkey::new_unique(), &Pubkey::new_unique());
+    let pool = StakePoolAccounts::parse(stake_pool, &data).unwrap();
+    let (escrow, tokens, market) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+    let deposit = deposit_sol(&pool, &escrow, &tokens, 5);
+    assert_eq!(deposit.program_id, STAKE_POOL_PROGRAM_ID);
+    assert_eq!(deposit.data, [&[14][..], &5u64.to_le_bytes()].concat());
+    let signers: Vec<_> = deposit.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+    assert_eq!(signers, [escrow]);
+
+    let withdraw = withdraw_sol(&pool, &market, &tokens, &market, 7);
+    assert_eq!(withdraw.data, [&[16][..], &7u64.to_le_bytes()].concat());
+    let signers: Vec<_> = withdraw.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+    assert_eq!(signers, [market]);
+}
@@ -0,0 +1,44 @@
+use cryptoscore_common::MarketStatus::{self, *};
+
+const ALL: [MarketStatus; 8] = [Open, Live, Resolved, Cancelled, Claiming, Settled, Refunding, Closed];
+
+#[test]
+fn settlement_follows_its_two_paths() {
+    let allowed = [
+        (Open, Resolved),
+        (Live, Resolved),
+        (Open, Cancelled),
+        (Live, Cancelled),
+        (Resolved, Cancelled),
+        (Resolved, Claiming),
+        (Resolved, Settled),
+        (Claiming, Settled),
+        (Cancelled, Refunding),
+        (Cancelled, Closed),
+        (Refunding, Closed),
+    ];
+
+    for from in &ALL {
+        for to in &ALL {
+            let expected = allowed.contains(&(from.clone(), to.clone()));
+            assert_eq!(from.can_transition_to(to), expected, "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn terminal_statuses_never_move() {
+    for status in &ALL {
+        assert!(!Settled.can_transition_to(status));
+        assert!(!Closed.can_transition_to(status));
+    }
+}
+
+#[test]
+fn resolved_and_cancelled_paths_are_disjoint() {
+    for status in &ALL {
+        assert!(!(status.is_resolved() && status.is_cancelled()), "{:?}", status);
+    }
+    assert!(!Open.is_resolved() && !Open.is_cancelled());
+    assert!(Claiming.is_resolved() && Refunding.is_cancelled());
+}
@@ -0,0 +1,27 @@
+//! Token instructions wrapping a winner's reward for a Jupiter swap.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::stake_pool::TOKEN_PROGRAM_ID;
+use cryptoscore_common::swap::{create_associated_token_account, sync_native, NATIVE_MINT};
+use cryptoscore_common::token_gate::{find_associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID};
+
+#[test]
+fn wrapped_sol_accounts_are_opened_and_synced_for_the_owner() {
+    let (payer, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let wrapped = find_associated_token_address(&owner, &NATIVE_MINT).0;
+
+    let create = create_associated_token_account(&payer, &owner, &NATIVE_MINT);
+    assert_eq!(create.program_id, ASSOCIATED_TOKEN_PROGRAM_ID);
+    assert_eq!(create.data, vec![1]);
+    assert!(create.accounts[0].is_signer && create.accounts[0].is_writable);
+    assert_eq!(create.accounts[1].pubkey, wrapped);
+    assert_eq!(create.accounts[2].pubkey, owner);
+    assert_eq!(create.accounts[3].pubkey, NATIVE_MINT);
+    assert!(!create.accounts[2].is_signer);
+
+    let sync = sync_native(&wrapped);
+    assert_eq!(sync.program_id, TOKEN_PROGRAM_ID);
+    assert_eq!(sync.data, vec![17]);
+    assert_eq!(sync.accounts.len(), 1);
+    assert!(sync.accounts[0].is_writable && !sync.accounts[0].is_signer);
+}
@@ -0,0 +1,33 @@
+//! Discovery tag encoding shared by the factory program and its clients.
+
+use cryptoscore_common::tags::{decode_tag, encode_tag, is_valid_tag, TagCategory, NO_TAG, TAG_LEN};
+
+#[test]
+fn tags_round_trip() {
+    for value in ["epl", "england", "quarter-final", "a", "0123456789abcdef"] {
+        let tag = encode_tag(value).unwrap();
+        assert!(is_valid_tag(&tag));
+        assert_eq!(decode_tag(&tag), Some(value));
+    }
+}
+
+#[test]
+fn malformed_tags_are_rejected() {
+    for value in ["", "EPL", "premier league", "é", "0123456789abcdefg"] {
+        assert_eq!(encode_tag(value), None, "{:?}", value);
+    }
+
+    assert!(!is_valid_tag(&NO_TAG));
+    assert_eq!(decode_tag(&NO_TAG), None);
+
+    // Bytes after the padding starts would make equal tags compare unequal
+    let mut gap = encode_tag("epl").unwrap();
+    gap[TAG_LEN - 1] = b'x';
+    assert!(!is_valid_tag(&gap));
+}
+
+#[test]
+fn categories_map_to_distinct_slots() {
+    let slots: Vec<usize> = TagCategory::ALL.iter().map(|category| category.slot()).collect();
+    assert_eq!(slots, [0, 1, 2, 3]);
+}
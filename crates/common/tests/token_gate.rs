@@ -0,0 +1,70 @@
+//! Token gate rules and the Metaplex metadata layout.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::token_gate::{
+    metadata_data, parse_metadata_collection, token_account_data, TokenGateRule, METADATA_PROGRAM_ID,
+};
+
+#[test]
+fn token_gates_need_the_mint_and_balance() {
+    let (mint, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let rule = TokenGateRule::Token { mint, min_amount: 100 };
+
+    assert!(rule.is_met_by(&holder, &token_account_data(&mint, &holder, 100), None));
+    assert!(!rule.is_met_by(&holder, &token_account_data(&mint, &holder, 99), None));
+    assert!(!rule.is_met_by(&holder, &token_account_data(&Pubkey::new_unique(), &holder, 100), None));
+    // Someone else's tokens don't count
+    assert!(!rule.is_met_by(&holder, &token_account_data(&mint, &Pubkey::new_unique(), 100), None));
+}
+
+#[test]
+fn collection_gates_need_a_verified_nft() {
+    let (nft, collection, holder) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let rule = TokenGateRule::Collection { collection };
+    let token_account = token_account_data(&nft, &holder, 1);
+    let verified = metadata_data(&nft, Some((collection, true)));
+
+    assert!(rule.is_met_by(&holder, &token_account, Some((&METADATA_PROGRAM_ID, &verified))));
+    assert!(!rule.is_met_by(&holder, &token_account, None));
+    assert!(!rule.is_met_by(&holder, &token_account_data(&nft, &holder, 0), Some((&METADATA_PROGRAM_ID, &verified))));
+    // Metadata written by any other program proves nothing
+    assert!(!rule.is_met_by(&holder, &token_account, Some((&Pubkey::new_unique(), &verified))));
+
+    let unverified = metadata_data(&nft, Some((collection, false)));
+    assert!(!rule.is_met_by(&holder, &token_account, Some((&METADATA_PROGRAM_ID, &unverified))));
+    let other_nft = metadata_data(&Pubkey::new_unique(), Some((collection, true)));
+    assert!(!rule.is_met_by(&holder, &token_account, Some((&METADATA_PROGRAM_ID, &other_nft))));
+}
+
+#[test]
+fn metadata_parsing_skips_variable_fields() {
+    let (mint, collection) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![4];
+    data.extend_from_slice(&[0; 32]);
+    data.extend_from_slice(mint.as_ref());
+    for field in ["Fan Pass #7", "FAN", "https://example.com/7.json"] {
+        data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        data.extend_from_slice(field.as_bytes());
+    }
+    data.extend_from_slice(&500u16.to_le_bytes());
+    // Two creators
+    data.extend_from_slice(&[1, 2, 0, 0, 0]);
+    data.extend_from_slice(&[0; 2 * 34]);
+    // Primary sale, mutable, an edition nonce and a token standard
+    data.extend_from_slice(&[1, 1, 1, 255, 1, 4]);
+    data.extend_from_slice(&[1, 1]);
+    data.extend_from_slice(collection.as_ref());
+
+    assert_eq!(parse_metadata_collection(&data), Some((mint, Some(collection))));
+    assert_eq!(parse_metadata_collection(&data[..data.len() - 1]), None);
+    assert_eq!(parse_metadata_collection(&metadata_data(&mint, None)), Some((mint, None)));
+    assert_eq!(parse_metadata_collection(&[0; 100]), None);
+}
+
+#[test]
+fn rules_need_a_mint_and_a_positive_balance() {
+    assert!(TokenGateRule::Token { mint: Pubkey::new_unique(), min_amount: 1 }.is_valid());
+    assert!(!TokenGateRule::Token { mint: Pubkey::new_unique(), min_amount: 0 }.is_valid());
+    assert!(!TokenGateRule::Token { mint: Pubkey::default(), min_amount: 1 }.is_valid());
+    assert!(!TokenGateRule::Collection { collection: Pubkey::default() }.is_valid());
+}
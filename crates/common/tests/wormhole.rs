@@ -0,0 +1,51 @@
+//! Posted VAA accounts and the bridged result payload.
+
+use cryptoscore_common::attestation::ATTESTATION_DOMAIN;
+use cryptoscore_common::wormhole::{parse_posted_vaa, parse_result_payload, posted_vaa_data, result_payload};
+use cryptoscore_common::FinalScore;
+
+#[test]
+fn posted_vaas_round_trip() {
+    let payload = result_payload("EPL-ARS-CHE", &FinalScore::new(0, 2), 1_700_000_000);
+    let data = posted_vaa_data(2, &[9; 32], 17, &payload);
+    let vaa = parse_posted_vaa(&data).unwrap();
+
+    assert_eq!(vaa.emitter_chain, 2);
+    assert_eq!(vaa.emitter_address, [9; 32]);
+    assert_eq!(vaa.sequence, 17);
+    assert_eq!(vaa.payload, payload.as_slice());
+}
+
+#[test]
+fn truncated_or_foreign_accounts_are_rejected() {
+    let data = posted_vaa_data(2, &[9; 32], 17, b"result");
+
+    assert!(parse_posted_vaa(&data[..data.len() - 1]).is_none());
+    let mut foreign = data.clone();
+    foreign[..3].copy_from_slice(b"msg");
+    assert!(parse_posted_vaa(&foreign).is_none());
+}
+
+#[test]
+fn payloads_are_big_endian_and_round_trip() {
+    let payload = result_payload("EPL-ARS-CHE", &FinalScore::new(1, 1), 1_700_000_000);
+    let result = parse_result_payload(&payload).unwrap();
+
+    assert_eq!(result.match_id, "EPL-ARS-CHE");
+    assert_eq!(result.score, FinalScore::new(1, 1));
+    assert_eq!(result.timestamp, 1_700_000_000);
+    assert!(payload.ends_with(&1_700_000_000i64.to_be_bytes()));
+}
+
+#[test]
+fn malformed_payloads_are_rejected() {
+    let payload = result_payload("EPL-ARS-CHE", &FinalScore::new(2, 0), 42);
+
+    assert!(parse_result_payload(&payload[..payload.len() - 1]).is_none());
+    assert!(parse_result_payload(&[payload.as_slice(), &[0]].concat()).is_none());
+    // Results from before payloads carried the score don't parse
+    let mut v1 = payload.clone();
+    v1[ATTESTATION_DOMAIN.len() - 1] = b'1';
+    assert!(parse_result_payload(&v1).is_none());
+    assert!(parse_result_payload(&payload[1..]).is_none());
+}
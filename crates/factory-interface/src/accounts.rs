@@ -0,0 +1,1096 @@
+//! Account lists for each factory instruction, in program order.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+use cryptoscore_common::pda::{
+    find_creation_rate_policy_address, find_creator_rate_address, find_creator_stats_address,
+    find_discount_policy_address, find_factory_address, find_insurance_claim_address, find_insurance_fund_address,
+    find_keeper_tip_policy_address, find_kickoff_bucket_address, find_live_score_address, find_market_address,
+    find_market_registry_address, find_match_id_policy_address, find_misresolution_ruling_address,
+    find_participant_address, find_payout_swap_policy_address, find_platform_activity_address, find_platform_address,
+    find_platform_config_address, find_platform_factory_address, find_platform_stats_address,
+    find_points_ledger_address, find_points_totals_address, find_rake_free_market_address, find_rebate_policy_address,
+    find_receipt_policy_address, find_resolution_grace_policy_address, find_resolution_policy_address,
+    find_results_batch_address, find_results_signer_address, find_tag_address, find_treasury_address,
+    find_voucher_address, find_voucher_campaign_address, find_wormhole_emitter_address, kickoff_day,
+};
+use cryptoscore_common::tags::{Tag, TagCategory, Tags, NO_TAG};
+use cryptoscore_common::{DASHBOARD_PROGRAM_ID, MARKET_PROGRAM_ID};
+
+pub struct InitializeFactory {
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl InitializeFactory {
+    pub fn new(authority: Pubkey) -> Self {
+        Self {
+            factory: find_factory_address().0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for InitializeFactory {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CreatePlatform {
+    pub platform: Pubkey,
+    pub factory: Pubkey,
+    pub operator: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CreatePlatform {
+    /// Derive the platform PDA for `platform_id` and its factory
+    pub fn new(operator: Pubkey, platform_id: &str) -> Self {
+        let platform = find_platform_address(platform_id).0;
+        Self {
+            platform,
+            factory: find_platform_factory_address(&platform).0,
+            operator,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CreatePlatform {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.platform, false),
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.operator, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct UpdateFactoryConfig {
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl UpdateFactoryConfig {
+    pub fn new(authority: Pubkey) -> Self {
+        Self {
+            factory: find_factory_address().0,
+            authority,
+        }
+    }
+}
+
+impl ToAccountMetas for UpdateFactoryConfig {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+pub struct SetResultsSigner {
+    pub factory: Pubkey,
+    pub results_signer: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetResultsSigner {
+    pub fn new(authority: Pubkey) -> Self {
+        Self::on_factory(find_factory_address().0, authority)
+    }
+
+    /// Designate the signer for another factory, e.g. an operator's platform factory
+    pub fn on_factory(factory: Pubkey, authority: Pubkey) -> Self {
+        Self {
+            factory,
+            results_signer: find_results_signer_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetResultsSigner {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.results_signer, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetWormholeEmitter {
+    pub factory: Pubkey,
+    pub wormhole_emitter: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetWormholeEmitter {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            wormhole_emitter: find_wormhole_emitter_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetWormholeEmitter {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.wormhole_emitter, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct PublishResults {
+    pub factory: Pubkey,
+    pub results_signer: Pubkey,
+    pub results_batch: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl PublishResults {
+    pub fn new(signer: Pubkey, batch_id: u64) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            results_signer: find_results_signer_address(&factory).0,
+            results_batch: find_results_batch_address(&factory, batch_id).0,
+            signer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for PublishResults {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new(self.results_batch, false),
+            AccountMeta::new(self.signer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct UpdateLiveScore {
+    pub factory: Pubkey,
+    pub results_signer: Pubkey,
+    pub live_score: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl UpdateLiveScore {
+    pub fn new(signer: Pubkey, match_id: &str) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            results_signer: find_results_signer_address(&factory).0,
+            live_score: find_live_score_address(&factory, match_id).0,
+            signer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for UpdateLiveScore {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new(self.live_score, false),
+            AccountMeta::new(self.signer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetResolutionPolicy {
+    pub factory: Pubkey,
+    pub resolution_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetResolutionPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            resolution_policy: find_resolution_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetResolutionPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.resolution_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetRebatePolicy {
+    pub factory: Pubkey,
+    pub rebate_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetRebatePolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            rebate_policy: find_rebate_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetRebatePolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.rebate_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetDiscountPolicy {
+    pub factory: Pubkey,
+    pub discount_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetDiscountPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            discount_policy: find_discount_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetDiscountPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.discount_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetKeeperTipPolicy {
+    pub factory: Pubkey,
+    pub keeper_tip_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetKeeperTipPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            keeper_tip_policy: find_keeper_tip_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetKeeperTipPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.keeper_tip_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetPayoutSwapPolicy {
+    pub factory: Pubkey,
+    pub payout_swap_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetPayoutSwapPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            payout_swap_policy: find_payout_swap_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetPayoutSwapPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.payout_swap_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetReceiptPolicy {
+    pub factory: Pubkey,
+    pub receipt_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetReceiptPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            receipt_policy: find_receipt_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetReceiptPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.receipt_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetResolutionGracePolicy {
+    pub factory: Pubkey,
+    pub resolution_grace_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetResolutionGracePolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            resolution_grace_policy: find_resolution_grace_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetResolutionGracePolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.resolution_grace_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetMatchIdPolicy {
+    pub factory: Pubkey,
+    pub match_id_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetMatchIdPolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            match_id_policy: find_match_id_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetMatchIdPolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.match_id_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetCreationRatePolicy {
+    pub factory: Pubkey,
+    pub creation_rate_policy: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetCreationRatePolicy {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            creation_rate_policy: find_creation_rate_policy_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetCreationRatePolicy {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.creation_rate_policy, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetRakeFree {
+    pub factory: Pubkey,
+    pub market: Pubkey,
+    pub rake_free_market: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetRakeFree {
+    pub fn new(authority: Pubkey, market: Pubkey) -> Self {
+        Self {
+            factory: find_factory_address().0,
+            market,
+            rake_free_market: find_rake_free_market_address(&market).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetRakeFree {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.rake_free_market, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetGovernor {
+    pub factory: Pubkey,
+    pub platform_config: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetGovernor {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            platform_config: find_platform_config_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetGovernor {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.platform_config, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetPlatformParam {
+    pub factory: Pubkey,
+    pub platform_config: Pubkey,
+    /// Factory authority or the governor
+    pub caller: Pubkey,
+}
+
+impl SetPlatformParam {
+    pub fn new(caller: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            platform_config: find_platform_config_address(&factory).0,
+            caller,
+        }
+    }
+}
+
+impl ToAccountMetas for SetPlatformParam {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.platform_config, false),
+            AccountMeta::new_readonly(self.caller, true),
+        ]
+    }
+}
+
+pub struct CreateMarket {
+    pub factory: Pubkey,
+    pub market_registry: Pubkey,
+    pub market_account: Pubkey,
+    pub kickoff_bucket: Pubkey,
+    pub match_id_policy: Pubkey,
+    pub creation_rate_policy: Pubkey,
+    pub creator_rate: Pubkey,
+    pub creator: Pubkey,
+    pub system_program: Pubkey,
+    /// `TagDefinition`s of the set tag slots, in slot order, passed as remaining accounts
+    pub tags: Vec<Pubkey>,
+}
+
+impl CreateMarket {
+    /// Derive the registry and market PDAs for `match_id` on the factory,
+    /// and the kickoff bucket for the day of `kickoff_time`
+    pub fn new(creator: Pubkey, match_id: &str, kickoff_time: i64) -> Self {
+        Self::on_factory(find_factory_address().0, creator, match_id, kickoff_time)
+    }
+
+    /// Derive them on another factory, e.g. an operator's platform factory
+    pub fn on_factory(factory: Pubkey, creator: Pubkey, match_id: &str, kickoff_time: i64) -> Self {
+        Self {
+            factory,
+            market_registry: find_market_registry_address(&factory, match_id).0,
+            market_account: find_market_address(&factory, match_id).0,
+            kickoff_bucket: find_kickoff_bucket_address(&factory, kickoff_day(kickoff_time)).0,
+            match_id_policy: find_match_id_policy_address(&factory).0,
+            creation_rate_policy: find_creation_rate_policy_address(&factory).0,
+            creator_rate: find_creator_rate_address(&factory, &creator).0,
+            creator,
+            system_program: system_program::ID,
+            tags: vec![],
+        }
+    }
+
+    /// Derive the `TagDefinition`s for the set slots of `tags`
+    pub fn tagged(mut self, tags: &Tags) -> Self {
+        self.tags = tag_definitions(&self.factory, tags);
+        self
+    }
+}
+
+impl ToAccountMetas for CreateMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.market_registry, false),
+            AccountMeta::new_readonly(self.market_account, false),
+            AccountMeta::new(self.kickoff_bucket, false),
+            AccountMeta::new_readonly(self.match_id_policy, false),
+            AccountMeta::new_readonly(self.creation_rate_policy, false),
+            AccountMeta::new(self.creator_rate, false),
+            AccountMeta::new(self.creator, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.tags.iter().map(|tag| AccountMeta::new_readonly(*tag, false)));
+        metas
+    }
+}
+
+pub struct CreateMarketSeries {
+    pub factory: Pubkey,
+    pub creator: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub creator_stats: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub resolution_grace_policy: Pubkey,
+    pub match_id_policy: Pubkey,
+    pub creation_rate_policy: Pubkey,
+    pub creator_rate: Pubkey,
+    pub market_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    /// `TagDefinition`s of the set tag slots, in slot order, passed as remaining accounts
+    pub tags: Vec<Pubkey>,
+    /// Registry entry, market PDA and kickoff bucket of each fixture, passed as remaining accounts after the tags
+    pub fixtures: Vec<(Pubkey, Pubkey, Pubkey)>,
+}
+
+impl CreateMarketSeries {
+    /// Derive the registry and market PDAs and kickoff bucket for each
+    /// `(match_id, kickoff_time)`, in fixture order
+    pub fn new<'a>(creator: Pubkey, fixtures: impl IntoIterator<Item = (&'a str, i64)>) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            creator,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            creator_stats: find_creator_stats_address(&creator).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&creator).0,
+            points_totals: find_points_totals_address().0,
+            resolution_grace_policy: find_resolution_grace_policy_address(&factory).0,
+            match_id_policy: find_match_id_policy_address(&factory).0,
+            creation_rate_policy: find_creation_rate_policy_address(&factory).0,
+            creator_rate: find_creator_rate_address(&factory, &creator).0,
+            market_program: MARKET_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            tags: vec![],
+            fixtures: fixtures
+                .into_iter()
+                .map(|(match_id, kickoff_time)| {
+                    (
+                        find_market_registry_address(&factory, match_id).0,
+                        find_market_address(&factory, match_id).0,
+                        find_kickoff_bucket_address(&factory, kickoff_day(kickoff_time)).0,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Derive the `TagDefinition`s for the set slots of `tags`
+    pub fn tagged(mut self, tags: &Tags) -> Self {
+        self.tags = tag_definitions(&self.factory, tags);
+        self
+    }
+}
+
+impl ToAccountMetas for CreateMarketSeries {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.creator, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new(self.creator_stats, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.resolution_grace_policy, false),
+            AccountMeta::new_readonly(self.match_id_policy, false),
+            AccountMeta::new_readonly(self.creation_rate_policy, false),
+            AccountMeta::new(self.creator_rate, false),
+            AccountMeta::new_readonly(self.market_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.tags.iter().map(|tag| AccountMeta::new_readonly(*tag, false)));
+        for (registry, market, kickoff_bucket) in &self.fixtures {
+            metas.push(AccountMeta::new(*registry, false));
+            metas.push(AccountMeta::new(*market, false));
+            metas.push(AccountMeta::new(*kickoff_bucket, false));
+        }
+        metas
+    }
+}
+
+pub struct AddTag {
+    pub factory: Pubkey,
+    pub tag_definition: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl AddTag {
+    pub fn new(authority: Pubkey, category: TagCategory, tag: &Tag) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            tag_definition: find_tag_address(&factory, category, tag).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for AddTag {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.tag_definition, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct RemoveTag {
+    pub factory: Pubkey,
+    pub tag_definition: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl RemoveTag {
+    pub fn new(authority: Pubkey, category: TagCategory, tag: &Tag) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            tag_definition: find_tag_address(&factory, category, tag).0,
+            authority,
+        }
+    }
+}
+
+impl ToAccountMetas for RemoveTag {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.tag_definition, false),
+            AccountMeta::new(self.authority, true),
+        ]
+    }
+}
+
+pub struct MigrateMarketRegistry {
+    pub market_registry: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl MigrateMarketRegistry {
+    pub fn new(market_registry: Pubkey, payer: Pubkey) -> Self {
+        Self {
+            market_registry,
+            payer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for MigrateMarketRegistry {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market_registry, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct InvalidateRegistry {
+    pub factory: Pubkey,
+    pub market_registry: Pubkey,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+}
+
+impl InvalidateRegistry {
+    /// Accounts for closing `creator`'s orphaned registry entry for `match_id` on `factory`
+    pub fn new(factory: Pubkey, creator: Pubkey, match_id: &str) -> Self {
+        Self {
+            factory,
+            market_registry: find_market_registry_address(&factory, match_id).0,
+            market: find_market_address(&factory, match_id).0,
+            creator,
+        }
+    }
+}
+
+impl ToAccountMetas for InvalidateRegistry {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.market_registry, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.creator, false),
+        ]
+    }
+}
+
+pub struct WithdrawTreasury {
+    pub factory: Pubkey,
+    pub treasury: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub platform_config: Pubkey,
+    pub recipient: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl WithdrawTreasury {
+    pub fn new(authority: Pubkey, recipient: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            treasury: find_treasury_address(&factory).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            platform_config: find_platform_config_address(&factory).0,
+            recipient,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for WithdrawTreasury {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.treasury, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.platform_config, false),
+            AccountMeta::new(self.recipient, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CreateVoucherCampaign {
+    pub factory: Pubkey,
+    pub voucher_campaign: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CreateVoucherCampaign {
+    pub fn new(authority: Pubkey, campaign_id: u64) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            voucher_campaign: find_voucher_campaign_address(&factory, campaign_id).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CreateVoucherCampaign {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.voucher_campaign, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct IssueVoucher {
+    pub factory: Pubkey,
+    pub voucher_campaign: Pubkey,
+    pub voucher: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl IssueVoucher {
+    pub fn new(authority: Pubkey, campaign_id: u64, holder: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        let voucher_campaign = find_voucher_campaign_address(&factory, campaign_id).0;
+        Self {
+            factory,
+            voucher_campaign,
+            voucher: find_voucher_address(&voucher_campaign, &holder).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for IssueVoucher {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.voucher_campaign, false),
+            AccountMeta::new(self.voucher, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct RuleMisresolution {
+    pub factory: Pubkey,
+    pub market: Pubkey,
+    pub ruling: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub platform_config: Pubkey,
+    pub ruler: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl RuleMisresolution {
+    pub fn new(ruler: Pubkey, payer: Pubkey, market: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            market,
+            ruling: find_misresolution_ruling_address(&market).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            platform_config: find_platform_config_address(&factory).0,
+            ruler,
+            payer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for RuleMisresolution {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.ruling, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.platform_config, false),
+            AccountMeta::new_readonly(self.ruler, true),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct RecordResultCorrection {
+    pub factory: Pubkey,
+    pub market: Pubkey,
+    pub ruling: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub instructions: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl RecordResultCorrection {
+    /// `market` signs as its own PDA, so these are only ever sent by the market program's `correct_result`
+    pub fn new(payer: Pubkey, market: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            market,
+            ruling: find_misresolution_ruling_address(&market).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            instructions: instructions_sysvar::ID,
+            payer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for RecordResultCorrection {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.market, true),
+            AccountMeta::new(self.ruling, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct ClaimInsurance {
+    pub factory: Pubkey,
+    pub treasury: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub market: Pubkey,
+    pub ruling: Pubkey,
+    pub participant: Pubkey,
+    pub claim: Pubkey,
+    pub claimant: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ClaimInsurance {
+    pub fn new(claimant: Pubkey, market: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            treasury: find_treasury_address(&factory).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            market,
+            ruling: find_misresolution_ruling_address(&market).0,
+            participant: find_participant_address(&market, &claimant).0,
+            claim: find_insurance_claim_address(&market, &claimant).0,
+            claimant,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for ClaimInsurance {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.treasury, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.ruling, false),
+            AccountMeta::new_readonly(self.participant, false),
+            AccountMeta::new(self.claim, false),
+            AccountMeta::new(self.claimant, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+/// `TagDefinition` addresses for the set slots of `tags`, in slot order
+fn tag_definitions(factory: &Pubkey, tags: &Tags) -> Vec<Pubkey> {
+    TagCategory::ALL
+        .iter()
+        .filter(|category| tags[category.slot()] != NO_TAG)
+        .map(|&category| find_tag_address(factory, category, &tags[category.slot()]).0)
+        .collect()
+}
@@ -0,0 +1,1276 @@
+//! CPI client for calling the factory program from another program.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::InstructionData;
+use cryptoscore_common::tags::{Tag, TagCategory};
+use cryptoscore_common::{MatchOutcome, MatchResult};
+
+use crate::instruction;
+use crate::state::DiscountTier;
+
+pub mod accounts {
+    use anchor_lang::prelude::*;
+
+    pub struct InitializeFactory<'info> {
+        pub factory: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for InitializeFactory<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::InitializeFactory {
+                factory: self.factory.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for InitializeFactory<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CreatePlatform<'info> {
+        pub platform: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub operator: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CreatePlatform<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CreatePlatform {
+                platform: self.platform.key(),
+                factory: self.factory.key(),
+                operator: self.operator.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CreatePlatform<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.platform.clone(),
+                self.factory.clone(),
+                self.operator.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct UpdateFactoryConfig<'info> {
+        pub factory: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for UpdateFactoryConfig<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::UpdateFactoryConfig {
+                factory: self.factory.key(),
+                authority: self.authority.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for UpdateFactoryConfig<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.factory.clone(), self.authority.clone()]
+        }
+    }
+
+    pub struct SetResultsSigner<'info> {
+        pub factory: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetResultsSigner<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetResultsSigner {
+                factory: self.factory.key(),
+                results_signer: self.results_signer.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetResultsSigner<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.results_signer.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetWormholeEmitter<'info> {
+        pub factory: AccountInfo<'info>,
+        pub wormhole_emitter: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetWormholeEmitter<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetWormholeEmitter {
+                factory: self.factory.key(),
+                wormhole_emitter: self.wormhole_emitter.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetWormholeEmitter<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.wormhole_emitter.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct PublishResults<'info> {
+        pub factory: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub results_batch: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for PublishResults<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::PublishResults {
+                factory: self.factory.key(),
+                results_signer: self.results_signer.key(),
+                results_batch: self.results_batch.key(),
+                signer: self.signer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for PublishResults<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.results_signer.clone(),
+                self.results_batch.clone(),
+                self.signer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct UpdateLiveScore<'info> {
+        pub factory: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for UpdateLiveScore<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::UpdateLiveScore {
+                factory: self.factory.key(),
+                results_signer: self.results_signer.key(),
+                live_score: self.live_score.key(),
+                signer: self.signer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for UpdateLiveScore<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.results_signer.clone(),
+                self.live_score.clone(),
+                self.signer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetResolutionPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub resolution_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetResolutionPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetResolutionPolicy {
+                factory: self.factory.key(),
+                resolution_policy: self.resolution_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetResolutionPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.resolution_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetRebatePolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub rebate_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetRebatePolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetRebatePolicy {
+                factory: self.factory.key(),
+                rebate_policy: self.rebate_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetRebatePolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.rebate_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetDiscountPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub discount_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetDiscountPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetDiscountPolicy {
+                factory: self.factory.key(),
+                discount_policy: self.discount_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetDiscountPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.discount_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetKeeperTipPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub keeper_tip_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetKeeperTipPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetKeeperTipPolicy {
+                factory: self.factory.key(),
+                keeper_tip_policy: self.keeper_tip_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetKeeperTipPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.keeper_tip_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetPayoutSwapPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub payout_swap_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetPayoutSwapPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetPayoutSwapPolicy {
+                factory: self.factory.key(),
+                payout_swap_policy: self.payout_swap_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetPayoutSwapPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.payout_swap_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetReceiptPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub receipt_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetReceiptPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetReceiptPolicy {
+                factory: self.factory.key(),
+                receipt_policy: self.receipt_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetReceiptPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.receipt_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetResolutionGracePolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub resolution_grace_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetResolutionGracePolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetResolutionGracePolicy {
+                factory: self.factory.key(),
+                resolution_grace_policy: self.resolution_grace_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetResolutionGracePolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.resolution_grace_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetMatchIdPolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub match_id_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetMatchIdPolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetMatchIdPolicy {
+                factory: self.factory.key(),
+                match_id_policy: self.match_id_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetMatchIdPolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.match_id_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetCreationRatePolicy<'info> {
+        pub factory: AccountInfo<'info>,
+        pub creation_rate_policy: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetCreationRatePolicy<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetCreationRatePolicy {
+                factory: self.factory.key(),
+                creation_rate_policy: self.creation_rate_policy.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetCreationRatePolicy<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.creation_rate_policy.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetRakeFree<'info> {
+        pub factory: AccountInfo<'info>,
+        pub market: AccountInfo<'info>,
+        pub rake_free_market: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetRakeFree<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetRakeFree {
+                factory: self.factory.key(),
+                market: self.market.key(),
+                rake_free_market: self.rake_free_market.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetRakeFree<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.market.clone(),
+                self.rake_free_market.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetGovernor<'info> {
+        pub factory: AccountInfo<'info>,
+        pub platform_config: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetGovernor<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetGovernor {
+                factory: self.factory.key(),
+                platform_config: self.platform_config.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetGovernor<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.platform_config.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetPlatformParam<'info> {
+        pub factory: AccountInfo<'info>,
+        pub platform_config: AccountInfo<'info>,
+        pub caller: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetPlatformParam<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetPlatformParam {
+                factory: self.factory.key(),
+                platform_config: self.platform_config.key(),
+                caller: self.caller.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetPlatformParam<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.platform_config.clone(),
+                self.caller.clone(),
+            ]
+        }
+    }
+
+    pub struct AddTag<'info> {
+        pub factory: AccountInfo<'info>,
+        pub tag_definition: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for AddTag<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::AddTag {
+                factory: self.factory.key(),
+                tag_definition: self.tag_definition.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for AddTag<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.tag_definition.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct RemoveTag<'info> {
+        pub factory: AccountInfo<'info>,
+        pub tag_definition: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RemoveTag<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RemoveTag {
+                factory: self.factory.key(),
+                tag_definition: self.tag_definition.key(),
+                authority: self.authority.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RemoveTag<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.factory.clone(), self.tag_definition.clone(), self.authority.clone()]
+        }
+    }
+
+    pub struct MigrateMarketRegistry<'info> {
+        pub market_registry: AccountInfo<'info>,
+        pub payer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for MigrateMarketRegistry<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::MigrateMarketRegistry {
+                market_registry: self.market_registry.key(),
+                payer: self.payer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for MigrateMarketRegistry<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.market_registry.clone(), self.payer.clone(), self.system_program.clone()]
+        }
+    }
+
+    pub struct InvalidateRegistry<'info> {
+        pub factory: AccountInfo<'info>,
+        pub market_registry: AccountInfo<'info>,
+        pub market: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for InvalidateRegistry<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::InvalidateRegistry {
+                factory: self.factory.key(),
+                market_registry: self.market_registry.key(),
+                market: self.market.key(),
+                creator: self.creator.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for InvalidateRegistry<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.factory.clone(), self.market_registry.clone(), self.market.clone(), self.creator.clone()]
+        }
+    }
+
+    pub struct CreateMarket<'info> {
+        pub factory: AccountInfo<'info>,
+        pub market_registry: AccountInfo<'info>,
+        pub market_account: AccountInfo<'info>,
+        pub kickoff_bucket: AccountInfo<'info>,
+        pub match_id_policy: AccountInfo<'info>,
+        pub creation_rate_policy: AccountInfo<'info>,
+        pub creator_rate: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CreateMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CreateMarket {
+                factory: self.factory.key(),
+                market_registry: self.market_registry.key(),
+                market_account: self.market_account.key(),
+                kickoff_bucket: self.kickoff_bucket.key(),
+                match_id_policy: self.match_id_policy.key(),
+                creation_rate_policy: self.creation_rate_policy.key(),
+                creator_rate: self.creator_rate.key(),
+                creator: self.creator.key(),
+                system_program: self.system_program.key(),
+                // Tag definitions travel as the context's remaining accounts
+                tags: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CreateMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.market_registry.clone(),
+                self.market_account.clone(),
+                self.kickoff_bucket.clone(),
+                self.match_id_policy.clone(),
+                self.creation_rate_policy.clone(),
+                self.creator_rate.clone(),
+                self.creator.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct WithdrawTreasury<'info> {
+        pub factory: AccountInfo<'info>,
+        pub treasury: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub platform_config: AccountInfo<'info>,
+        pub recipient: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for WithdrawTreasury<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::WithdrawTreasury {
+                factory: self.factory.key(),
+                treasury: self.treasury.key(),
+                insurance_fund: self.insurance_fund.key(),
+                platform_config: self.platform_config.key(),
+                recipient: self.recipient.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for WithdrawTreasury<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.treasury.clone(),
+                self.insurance_fund.clone(),
+                self.platform_config.clone(),
+                self.recipient.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CreateVoucherCampaign<'info> {
+        pub factory: AccountInfo<'info>,
+        pub voucher_campaign: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CreateVoucherCampaign<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CreateVoucherCampaign {
+                factory: self.factory.key(),
+                voucher_campaign: self.voucher_campaign.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CreateVoucherCampaign<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.voucher_campaign.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct IssueVoucher<'info> {
+        pub factory: AccountInfo<'info>,
+        pub voucher_campaign: AccountInfo<'info>,
+        pub voucher: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for IssueVoucher<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::IssueVoucher {
+                factory: self.factory.key(),
+                voucher_campaign: self.voucher_campaign.key(),
+                voucher: self.voucher.key(),
+                authority: self.authority.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for IssueVoucher<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.voucher_campaign.clone(),
+                self.voucher.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct RuleMisresolution<'info> {
+        pub factory: AccountInfo<'info>,
+        pub market: AccountInfo<'info>,
+        pub ruling: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub platform_config: AccountInfo<'info>,
+        pub ruler: AccountInfo<'info>,
+        pub payer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RuleMisresolution<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RuleMisresolution {
+                factory: self.factory.key(),
+                market: self.market.key(),
+                ruling: self.ruling.key(),
+                insurance_fund: self.insurance_fund.key(),
+                platform_config: self.platform_config.key(),
+                ruler: self.ruler.key(),
+                payer: self.payer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RuleMisresolution<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.market.clone(),
+                self.ruling.clone(),
+                self.insurance_fund.clone(),
+                self.platform_config.clone(),
+                self.ruler.clone(),
+                self.payer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct RecordResultCorrection<'info> {
+        pub factory: AccountInfo<'info>,
+        pub market: AccountInfo<'info>,
+        pub ruling: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub payer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RecordResultCorrection<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RecordResultCorrection {
+                factory: self.factory.key(),
+                market: self.market.key(),
+                ruling: self.ruling.key(),
+                insurance_fund: self.insurance_fund.key(),
+                instructions: self.instructions.key(),
+                payer: self.payer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RecordResultCorrection<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.market.clone(),
+                self.ruling.clone(),
+                self.insurance_fund.clone(),
+                self.instructions.clone(),
+                self.payer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct ClaimInsurance<'info> {
+        pub factory: AccountInfo<'info>,
+        pub treasury: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub market: AccountInfo<'info>,
+        pub ruling: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub claim: AccountInfo<'info>,
+        pub claimant: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ClaimInsurance<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ClaimInsurance {
+                factory: self.factory.key(),
+                treasury: self.treasury.key(),
+                insurance_fund: self.insurance_fund.key(),
+                market: self.market.key(),
+                ruling: self.ruling.key(),
+                participant: self.participant.key(),
+                claim: self.claim.key(),
+                claimant: self.claimant.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ClaimInsurance<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.treasury.clone(),
+                self.insurance_fund.clone(),
+                self.market.clone(),
+                self.ruling.clone(),
+                self.participant.clone(),
+                self.claim.clone(),
+                self.claimant.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CreateMarketSeries<'info> {
+        pub factory: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub creator_stats: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub resolution_grace_policy: AccountInfo<'info>,
+        pub match_id_policy: AccountInfo<'info>,
+        pub creation_rate_policy: AccountInfo<'info>,
+        pub creator_rate: AccountInfo<'info>,
+        pub market_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CreateMarketSeries<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CreateMarketSeries {
+                factory: self.factory.key(),
+                creator: self.creator.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                creator_stats: self.creator_stats.key(),
+                dashboard_program: self.dashboard_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                resolution_grace_policy: self.resolution_grace_policy.key(),
+                match_id_policy: self.match_id_policy.key(),
+                creation_rate_policy: self.creation_rate_policy.key(),
+                creator_rate: self.creator_rate.key(),
+                market_program: self.market_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                // Tag definitions and fixture accounts travel as the context's remaining accounts
+                tags: vec![],
+                fixtures: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CreateMarketSeries<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.creator.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.creator_stats.clone(),
+                self.dashboard_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.resolution_grace_policy.clone(),
+                self.match_id_policy.clone(),
+                self.creation_rate_policy.clone(),
+                self.creator_rate.clone(),
+                self.market_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+}
+
+pub fn initialize_factory<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::InitializeFactory<'info>>,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    invoke(ctx, instruction::InitializeFactory { platform_fee_bps }.data())
+}
+
+pub fn create_platform<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CreatePlatform<'info>>,
+    platform_id: String,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    invoke(
+        ctx,
+        instruction::CreatePlatform {
+            platform_id,
+            platform_fee_bps,
+        }
+        .data(),
+    )
+}
+
+pub fn update_leaderboard_qualification<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::UpdateFactoryConfig<'info>>,
+    min_leaderboard_markets: u32,
+    min_leaderboard_pool: u64,
+) -> Result<()> {
+    invoke(
+        ctx,
+        instruction::UpdateLeaderboardQualification {
+            min_leaderboard_markets,
+            min_leaderboard_pool,
+        }
+        .data(),
+    )
+}
+
+pub fn set_results_signer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetResultsSigner<'info>>,
+    signer: Pubkey,
+) -> Result<()> {
+    invoke(ctx, instruction::SetResultsSigner { signer }.data())
+}
+
+pub fn set_wormhole_emitter<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetWormholeEmitter<'info>>,
+    core_bridge: Pubkey,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+) -> Result<()> {
+    invoke(ctx, instruction::SetWormholeEmitter { core_bridge, emitter_chain, emitter_address }.data())
+}
+
+pub fn publish_results<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::PublishResults<'info>>,
+    batch_id: u64,
+    results: Vec<MatchResult>,
+) -> Result<()> {
+    invoke(ctx, instruction::PublishResults { batch_id, results }.data())
+}
+
+pub fn update_live_score<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::UpdateLiveScore<'info>>,
+    match_id: String,
+    home_goals: u8,
+    away_goals: u8,
+    minute: u16,
+    is_final: bool,
+) -> Result<()> {
+    invoke(ctx, instruction::UpdateLiveScore { match_id, home_goals, away_goals, minute, is_final }.data())
+}
+
+pub fn set_resolution_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetResolutionPolicy<'info>>,
+    high_stakes_pool: u64,
+    arbiter: Pubkey,
+) -> Result<()> {
+    invoke(ctx, instruction::SetResolutionPolicy { high_stakes_pool, arbiter }.data())
+}
+
+pub fn set_rebate_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetRebatePolicy<'info>>,
+    loss_streak: u16,
+    rebate_bps: u16,
+    epoch_cap: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::SetRebatePolicy { loss_streak, rebate_bps, epoch_cap }.data())
+}
+
+pub fn set_discount_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetDiscountPolicy<'info>>,
+    tiers: Vec<DiscountTier>,
+    epoch_cap: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::SetDiscountPolicy { tiers, epoch_cap }.data())
+}
+
+pub fn set_keeper_tip_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetKeeperTipPolicy<'info>>,
+    tip: u64,
+    market_budget: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::SetKeeperTipPolicy { tip, market_budget }.data())
+}
+
+pub fn set_payout_swap_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetPayoutSwapPolicy<'info>>,
+    mints: Vec<Pubkey>,
+) -> Result<()> {
+    invoke(ctx, instruction::SetPayoutSwapPolicy { mints }.data())
+}
+
+pub fn set_receipt_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetReceiptPolicy<'info>>,
+    merkle_tree: Pubkey,
+    uri_base: String,
+) -> Result<()> {
+    invoke(ctx, instruction::SetReceiptPolicy { merkle_tree, uri_base }.data())
+}
+
+pub fn set_resolution_grace_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetResolutionGracePolicy<'info>>,
+    min_grace_seconds: i64,
+    max_grace_seconds: i64,
+) -> Result<()> {
+    invoke(ctx, instruction::SetResolutionGracePolicy { min_grace_seconds, max_grace_seconds }.data())
+}
+
+pub fn set_match_id_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetMatchIdPolicy<'info>>,
+    allowed_characters: String,
+) -> Result<()> {
+    invoke(ctx, instruction::SetMatchIdPolicy { allowed_characters }.data())
+}
+
+pub fn set_creation_rate_policy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCreationRatePolicy<'info>>,
+    max_markets: u32,
+    window_seconds: i64,
+) -> Result<()> {
+    invoke(ctx, instruction::SetCreationRatePolicy { max_markets, window_seconds }.data())
+}
+
+pub fn set_rake_free<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetRakeFree<'info>>,
+    rake_free: bool,
+) -> Result<()> {
+    invoke(ctx, instruction::SetRakeFree { rake_free }.data())
+}
+
+pub fn set_governor<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetGovernor<'info>>,
+    governor: Pubkey,
+) -> Result<()> {
+    invoke(ctx, instruction::SetGovernor { governor }.data())
+}
+
+pub fn set_platform_param<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetPlatformParam<'info>>,
+    param: instruction::PlatformParam,
+) -> Result<()> {
+    invoke(ctx, instruction::SetPlatformParam { param }.data())
+}
+
+pub fn add_tag<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::AddTag<'info>>,
+    category: TagCategory,
+    tag: Tag,
+) -> Result<()> {
+    invoke(ctx, instruction::AddTag { category, tag }.data())
+}
+
+pub fn remove_tag<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::RemoveTag<'info>>) -> Result<()> {
+    invoke(ctx, instruction::RemoveTag.data())
+}
+
+pub fn migrate_market_registry<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::MigrateMarketRegistry<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::MigrateMarketRegistry.data())
+}
+
+pub fn invalidate_registry<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::InvalidateRegistry<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::InvalidateRegistry.data())
+}
+
+/// Pass each set tag slot's `TagDefinition` with `CpiContext::with_remaining_accounts`
+pub fn create_market<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateMarket<'info>>,
+    args: instruction::CreateMarket,
+) -> Result<()> {
+    invoke(ctx, args.data())
+}
+
+pub fn create_market_series<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateMarketSeries<'info>>,
+    args: instruction::CreateMarketSeries,
+) -> Result<()> {
+    invoke(ctx, args.data())
+}
+
+pub fn withdraw_treasury<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::WithdrawTreasury<'info>>,
+    amount: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::WithdrawTreasury { amount }.data())
+}
+
+pub fn create_voucher_campaign<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateVoucherCampaign<'info>>,
+    args: instruction::CreateVoucherCampaign,
+) -> Result<()> {
+    invoke(ctx, args.data())
+}
+
+pub fn issue_voucher<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::IssueVoucher<'info>>,
+    holder: Pubkey,
+) -> Result<()> {
+    invoke(ctx, instruction::IssueVoucher { holder }.data())
+}
+
+pub fn rule_misresolution<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RuleMisresolution<'info>>,
+    args: instruction::RuleMisresolution,
+) -> Result<()> {
+    invoke(ctx, args.data())
+}
+
+pub fn record_result_correction<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RecordResultCorrection<'info>>,
+    correct_outcome: MatchOutcome,
+) -> Result<()> {
+    invoke(ctx, instruction::RecordResultCorrection { correct_outcome }.data())
+}
+
+pub fn insurance_claim<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::ClaimInsurance<'info>>) -> Result<()> {
+    invoke(ctx, instruction::InsuranceClaim.data())
+}
+
+fn invoke<'info, T: ToAccountMetas + ToAccountInfos<'info>>(
+    ctx: CpiContext<'_, '_, '_, 'info, T>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts: ctx.to_account_metas(None),
+        data,
+    };
+
+    invoke_signed(&ix, &ctx.to_account_infos(), ctx.signer_seeds).map_err(Into::into)
+}
@@ -0,0 +1,131 @@
+//! Events emitted by the factory program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::MatchOutcome;
+
+use crate::instruction::PlatformParam;
+
+#[event]
+pub struct MarketCreated {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub creator: Pubkey,
+    pub match_id: String,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub is_public: bool,
+}
+
+#[event]
+pub struct PlatformFeeCollected {
+    #[index]
+    pub market: Pubkey,
+    pub amount: u64,
+    pub total_collected: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    #[index]
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct StreakRebatePaid {
+    #[index]
+    pub user: Pubkey,
+    pub market: Pubkey,
+    pub amount: u64,
+    pub epoch_paid: u64,
+}
+
+#[event]
+pub struct FeeDiscountPaid {
+    #[index]
+    pub user: Pubkey,
+    pub market: Pubkey,
+    pub discount_bps: u16,
+    pub amount: u64,
+    pub epoch_paid: u64,
+}
+
+#[event]
+pub struct KeeperTipPaid {
+    #[index]
+    pub keeper: Pubkey,
+    pub market: Pubkey,
+    pub amount: u64,
+    pub market_paid: u64,
+}
+
+#[event]
+pub struct VoucherRedeemed {
+    #[index]
+    pub holder: Pubkey,
+    pub market: Pubkey,
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub campaign_spent: u64,
+}
+
+#[event]
+pub struct ResultsPublished {
+    #[index]
+    pub results_batch: Pubkey,
+    pub batch_id: u64,
+    pub result_count: u8,
+}
+
+#[event]
+pub struct LiveScoreUpdated {
+    #[index]
+    pub live_score: Pubkey,
+    pub match_id: String,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub minute: u16,
+    pub is_final: bool,
+}
+
+#[event]
+pub struct InsuranceFunded {
+    pub amount: u64,
+    pub total_funded: u64,
+}
+
+#[event]
+pub struct MisresolutionRuled {
+    #[index]
+    pub market: Pubkey,
+    pub resolved_outcome: MatchOutcome,
+    pub correct_outcome: MatchOutcome,
+    pub claim_cap: u64,
+    pub payout_cap: u64,
+}
+
+#[event]
+pub struct InsuranceClaimPaid {
+    #[index]
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub fund_balance: u64,
+}
+
+#[event]
+pub struct MarketRegistryInvalidated {
+    #[index]
+    pub market_registry: Pubkey,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub match_id: String,
+}
+
+#[event]
+pub struct PlatformParamSet {
+    pub param: PlatformParam,
+    pub caller: Pubkey,
+}
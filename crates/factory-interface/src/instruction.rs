@@ -0,0 +1,699 @@
+//! Factory instruction data and builders.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use cryptoscore_common::tags::{Tag, TagCategory, Tags};
+use cryptoscore_common::{MatchOutcome, MatchResult, OutcomeSet};
+
+use crate::accounts;
+use crate::state::DiscountTier;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeFactory {
+    pub platform_fee_bps: u16,
+}
+
+impl Discriminator for InitializeFactory {
+    const DISCRIMINATOR: [u8; 8] = [179, 64, 75, 250, 39, 254, 240, 178];
+}
+
+impl InstructionData for InitializeFactory {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatePlatform {
+    pub platform_id: String,
+    pub platform_fee_bps: u16,
+}
+
+impl Discriminator for CreatePlatform {
+    const DISCRIMINATOR: [u8; 8] = [159, 106, 44, 241, 53, 188, 123, 238];
+}
+
+impl InstructionData for CreatePlatform {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateLeaderboardQualification {
+    pub min_leaderboard_markets: u32,
+    pub min_leaderboard_pool: u64,
+}
+
+impl Discriminator for UpdateLeaderboardQualification {
+    const DISCRIMINATOR: [u8; 8] = [199, 28, 42, 86, 124, 195, 221, 12];
+}
+
+impl InstructionData for UpdateLeaderboardQualification {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetResultsSigner {
+    pub signer: Pubkey,
+}
+
+impl Discriminator for SetResultsSigner {
+    const DISCRIMINATOR: [u8; 8] = [136, 130, 231, 214, 66, 196, 52, 97];
+}
+
+impl InstructionData for SetResultsSigner {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetWormholeEmitter {
+    pub core_bridge: Pubkey,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+}
+
+impl Discriminator for SetWormholeEmitter {
+    const DISCRIMINATOR: [u8; 8] = [22, 243, 74, 128, 84, 239, 245, 55];
+}
+
+impl InstructionData for SetWormholeEmitter {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PublishResults {
+    pub batch_id: u64,
+    pub results: Vec<MatchResult>,
+}
+
+impl Discriminator for PublishResults {
+    const DISCRIMINATOR: [u8; 8] = [198, 64, 157, 180, 215, 21, 224, 119];
+}
+
+impl InstructionData for PublishResults {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateLiveScore {
+    pub match_id: String,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub minute: u16,
+    pub is_final: bool,
+}
+
+impl Discriminator for UpdateLiveScore {
+    const DISCRIMINATOR: [u8; 8] = [4, 75, 15, 247, 84, 87, 100, 217];
+}
+
+impl InstructionData for UpdateLiveScore {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetResolutionPolicy {
+    pub high_stakes_pool: u64,
+    pub arbiter: Pubkey,
+}
+
+impl Discriminator for SetResolutionPolicy {
+    const DISCRIMINATOR: [u8; 8] = [117, 159, 40, 109, 42, 19, 88, 181];
+}
+
+impl InstructionData for SetResolutionPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRebatePolicy {
+    pub loss_streak: u16,
+    pub rebate_bps: u16,
+    pub epoch_cap: u64,
+}
+
+impl Discriminator for SetRebatePolicy {
+    const DISCRIMINATOR: [u8; 8] = [126, 173, 243, 3, 29, 113, 50, 69];
+}
+
+impl InstructionData for SetRebatePolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetDiscountPolicy {
+    pub tiers: Vec<DiscountTier>,
+    pub epoch_cap: u64,
+}
+
+impl Discriminator for SetDiscountPolicy {
+    const DISCRIMINATOR: [u8; 8] = [148, 211, 152, 81, 237, 67, 43, 255];
+}
+
+impl InstructionData for SetDiscountPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetKeeperTipPolicy {
+    pub tip: u64,
+    pub market_budget: u64,
+}
+
+impl Discriminator for SetKeeperTipPolicy {
+    const DISCRIMINATOR: [u8; 8] = [37, 169, 96, 170, 143, 163, 14, 177];
+}
+
+impl InstructionData for SetKeeperTipPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPayoutSwapPolicy {
+    pub mints: Vec<Pubkey>,
+}
+
+impl Discriminator for SetPayoutSwapPolicy {
+    const DISCRIMINATOR: [u8; 8] = [123, 83, 72, 250, 149, 2, 167, 92];
+}
+
+impl InstructionData for SetPayoutSwapPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetReceiptPolicy {
+    pub merkle_tree: Pubkey,
+    pub uri_base: String,
+}
+
+impl Discriminator for SetReceiptPolicy {
+    const DISCRIMINATOR: [u8; 8] = [253, 142, 110, 1, 31, 122, 103, 80];
+}
+
+impl InstructionData for SetReceiptPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetResolutionGracePolicy {
+    pub min_grace_seconds: i64,
+    pub max_grace_seconds: i64,
+}
+
+impl Discriminator for SetResolutionGracePolicy {
+    const DISCRIMINATOR: [u8; 8] = [104, 85, 4, 143, 214, 100, 187, 128];
+}
+
+impl InstructionData for SetResolutionGracePolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMatchIdPolicy {
+    pub allowed_characters: String,
+}
+
+impl Discriminator for SetMatchIdPolicy {
+    const DISCRIMINATOR: [u8; 8] = [202, 78, 83, 9, 213, 244, 83, 169];
+}
+
+impl InstructionData for SetMatchIdPolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCreationRatePolicy {
+    pub max_markets: u32,
+    pub window_seconds: i64,
+}
+
+impl Discriminator for SetCreationRatePolicy {
+    const DISCRIMINATOR: [u8; 8] = [39, 181, 32, 171, 90, 98, 52, 163];
+}
+
+impl InstructionData for SetCreationRatePolicy {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRakeFree {
+    pub rake_free: bool,
+}
+
+impl Discriminator for SetRakeFree {
+    const DISCRIMINATOR: [u8; 8] = [234, 60, 225, 244, 240, 255, 123, 108];
+}
+
+impl InstructionData for SetRakeFree {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetGovernor {
+    pub governor: Pubkey,
+}
+
+impl Discriminator for SetGovernor {
+    const DISCRIMINATOR: [u8; 8] = [47, 75, 9, 199, 215, 187, 161, 161];
+}
+
+impl InstructionData for SetGovernor {}
+
+/// Platform parameter changed by `SetPlatformParam`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlatformParam {
+    /// At most 1000 bps
+    PlatformFeeBps(u16),
+    /// Seconds, not negative; zero for no limit
+    DisputeWindow(i64),
+    /// Default for any recipient
+    TreasuryRecipient(Pubkey),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPlatformParam {
+    pub param: PlatformParam,
+}
+
+impl Discriminator for SetPlatformParam {
+    const DISCRIMINATOR: [u8; 8] = [44, 71, 1, 137, 52, 153, 122, 102];
+}
+
+impl InstructionData for SetPlatformParam {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMarket {
+    pub match_id: String,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    pub is_public: bool,
+    /// Indexed by `TagCategory::slot`; each set slot needs its `TagDefinition`
+    /// in `accounts::CreateMarket::tags`
+    pub tags: Tags,
+}
+
+impl Discriminator for CreateMarket {
+    const DISCRIMINATOR: [u8; 8] = [103, 226, 97, 235, 200, 188, 251, 254];
+}
+
+impl InstructionData for CreateMarket {}
+
+/// One match in a `CreateMarketSeries`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SeriesFixture {
+    pub match_id: String,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMarketSeries {
+    /// In the same order as `accounts::CreateMarketSeries::fixtures`
+    pub fixtures: Vec<SeriesFixture>,
+    pub entry_fee: u64,
+    pub is_public: bool,
+    pub outcome_set: OutcomeSet,
+    pub resolution_grace_seconds: i64,
+    /// Shared by every market in the series
+    pub tags: Tags,
+}
+
+impl Discriminator for CreateMarketSeries {
+    const DISCRIMINATOR: [u8; 8] = [123, 229, 193, 148, 9, 68, 122, 29];
+}
+
+impl InstructionData for CreateMarketSeries {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddTag {
+    pub category: TagCategory,
+    pub tag: Tag,
+}
+
+impl Discriminator for AddTag {
+    const DISCRIMINATOR: [u8; 8] = [104, 103, 92, 4, 25, 211, 151, 90];
+}
+
+impl InstructionData for AddTag {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveTag;
+
+impl Discriminator for RemoveTag {
+    const DISCRIMINATOR: [u8; 8] = [61, 253, 89, 114, 205, 115, 244, 102];
+}
+
+impl InstructionData for RemoveTag {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateMarketRegistry;
+
+impl Discriminator for MigrateMarketRegistry {
+    const DISCRIMINATOR: [u8; 8] = [117, 4, 252, 98, 158, 238, 204, 227];
+}
+
+impl InstructionData for MigrateMarketRegistry {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InvalidateRegistry;
+
+impl Discriminator for InvalidateRegistry {
+    const DISCRIMINATOR: [u8; 8] = [223, 57, 15, 182, 66, 112, 220, 233];
+}
+
+impl InstructionData for InvalidateRegistry {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawTreasury {
+    pub amount: u64,
+}
+
+impl Discriminator for WithdrawTreasury {
+    const DISCRIMINATOR: [u8; 8] = [40, 63, 122, 158, 144, 216, 83, 96];
+}
+
+impl InstructionData for WithdrawTreasury {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateVoucherCampaign {
+    pub campaign_id: u64,
+    pub budget: u64,
+    pub max_entry_fee: u64,
+    pub expires_at: i64,
+}
+
+impl Discriminator for CreateVoucherCampaign {
+    const DISCRIMINATOR: [u8; 8] = [11, 144, 181, 65, 173, 169, 67, 104];
+}
+
+impl InstructionData for CreateVoucherCampaign {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IssueVoucher {
+    pub holder: Pubkey,
+}
+
+impl Discriminator for IssueVoucher {
+    const DISCRIMINATOR: [u8; 8] = [231, 243, 245, 223, 76, 249, 243, 35];
+}
+
+impl InstructionData for IssueVoucher {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RuleMisresolution {
+    pub correct_outcome: MatchOutcome,
+    pub claim_cap: u64,
+    pub payout_cap: u64,
+}
+
+impl Discriminator for RuleMisresolution {
+    const DISCRIMINATOR: [u8; 8] = [252, 131, 195, 6, 104, 199, 213, 75];
+}
+
+impl InstructionData for RuleMisresolution {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecordResultCorrection {
+    pub correct_outcome: MatchOutcome,
+}
+
+impl Discriminator for RecordResultCorrection {
+    const DISCRIMINATOR: [u8; 8] = [171, 128, 156, 83, 85, 184, 67, 231];
+}
+
+impl InstructionData for RecordResultCorrection {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InsuranceClaim;
+
+impl Discriminator for InsuranceClaim {
+    const DISCRIMINATOR: [u8; 8] = [191, 176, 0, 41, 189, 68, 66, 142];
+}
+
+impl InstructionData for InsuranceClaim {}
+
+// Builders
+
+pub fn initialize_factory(accounts: accounts::InitializeFactory, platform_fee_bps: u16) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: InitializeFactory { platform_fee_bps }.data(),
+    }
+}
+
+pub fn create_platform(accounts: accounts::CreatePlatform, platform_id: String, platform_fee_bps: u16) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CreatePlatform {
+            platform_id,
+            platform_fee_bps,
+        }
+        .data(),
+    }
+}
+
+pub fn update_leaderboard_qualification(
+    accounts: accounts::UpdateFactoryConfig,
+    min_leaderboard_markets: u32,
+    min_leaderboard_pool: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: UpdateLeaderboardQualification {
+            min_leaderboard_markets,
+            min_leaderboard_pool,
+        }
+        .data(),
+    }
+}
+
+pub fn set_results_signer(accounts: accounts::SetResultsSigner, signer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetResultsSigner { signer }.data(),
+    }
+}
+
+pub fn set_wormhole_emitter(
+    accounts: accounts::SetWormholeEmitter,
+    core_bridge: Pubkey,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetWormholeEmitter { core_bridge, emitter_chain, emitter_address }.data(),
+    }
+}
+
+pub fn publish_results(accounts: accounts::PublishResults, batch_id: u64, results: Vec<MatchResult>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: PublishResults { batch_id, results }.data(),
+    }
+}
+
+pub fn update_live_score(
+    accounts: accounts::UpdateLiveScore,
+    match_id: String,
+    home_goals: u8,
+    away_goals: u8,
+    minute: u16,
+    is_final: bool,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: UpdateLiveScore { match_id, home_goals, away_goals, minute, is_final }.data(),
+    }
+}
+
+pub fn set_resolution_policy(
+    accounts: accounts::SetResolutionPolicy,
+    high_stakes_pool: u64,
+    arbiter: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetResolutionPolicy { high_stakes_pool, arbiter }.data(),
+    }
+}
+
+pub fn set_rebate_policy(
+    accounts: accounts::SetRebatePolicy,
+    loss_streak: u16,
+    rebate_bps: u16,
+    epoch_cap: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetRebatePolicy { loss_streak, rebate_bps, epoch_cap }.data(),
+    }
+}
+
+pub fn set_discount_policy(
+    accounts: accounts::SetDiscountPolicy,
+    tiers: Vec<DiscountTier>,
+    epoch_cap: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetDiscountPolicy { tiers, epoch_cap }.data(),
+    }
+}
+
+pub fn set_keeper_tip_policy(accounts: accounts::SetKeeperTipPolicy, tip: u64, market_budget: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetKeeperTipPolicy { tip, market_budget }.data(),
+    }
+}
+
+pub fn set_payout_swap_policy(accounts: accounts::SetPayoutSwapPolicy, mints: Vec<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetPayoutSwapPolicy { mints }.data(),
+    }
+}
+
+pub fn set_receipt_policy(accounts: accounts::SetReceiptPolicy, merkle_tree: Pubkey, uri_base: String) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetReceiptPolicy { merkle_tree, uri_base }.data(),
+    }
+}
+
+pub fn set_resolution_grace_policy(
+    accounts: accounts::SetResolutionGracePolicy,
+    min_grace_seconds: i64,
+    max_grace_seconds: i64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetResolutionGracePolicy { min_grace_seconds, max_grace_seconds }.data(),
+    }
+}
+
+/// `allowed_characters` lists the characters match IDs may contain, printable ASCII other than space
+pub fn set_match_id_policy(accounts: accounts::SetMatchIdPolicy, allowed_characters: String) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetMatchIdPolicy { allowed_characters }.data(),
+    }
+}
+
+/// Zero `max_markets` turns the limit off
+pub fn set_creation_rate_policy(
+    accounts: accounts::SetCreationRatePolicy,
+    max_markets: u32,
+    window_seconds: i64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetCreationRatePolicy { max_markets, window_seconds }.data(),
+    }
+}
+
+pub fn set_rake_free(accounts: accounts::SetRakeFree, rake_free: bool) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetRakeFree { rake_free }.data(),
+    }
+}
+
+pub fn set_governor(accounts: accounts::SetGovernor, governor: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetGovernor { governor }.data(),
+    }
+}
+
+pub fn set_platform_param(accounts: accounts::SetPlatformParam, param: PlatformParam) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetPlatformParam { param }.data(),
+    }
+}
+
+pub fn create_market(accounts: accounts::CreateMarket, args: CreateMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn create_market_series(accounts: accounts::CreateMarketSeries, args: CreateMarketSeries) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn add_tag(accounts: accounts::AddTag, category: TagCategory, tag: Tag) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: AddTag { category, tag }.data(),
+    }
+}
+
+pub fn remove_tag(accounts: accounts::RemoveTag) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RemoveTag.data(),
+    }
+}
+
+pub fn migrate_market_registry(accounts: accounts::MigrateMarketRegistry) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: MigrateMarketRegistry.data(),
+    }
+}
+
+pub fn invalidate_registry(accounts: accounts::InvalidateRegistry) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: InvalidateRegistry.data(),
+    }
+}
+
+pub fn withdraw_treasury(accounts: accounts::WithdrawTreasury, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: WithdrawTreasury { amount }.data(),
+    }
+}
+
+pub fn create_voucher_campaign(accounts: accounts::CreateVoucherCampaign, args: CreateVoucherCampaign) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn issue_voucher(accounts: accounts::IssueVoucher, holder: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: IssueVoucher { holder }.data(),
+    }
+}
+
+pub fn rule_misresolution(accounts: accounts::RuleMisresolution, args: RuleMisresolution) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn record_result_correction(
+    accounts: accounts::RecordResultCorrection,
+    correct_outcome: MatchOutcome,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RecordResultCorrection { correct_outcome }.data(),
+    }
+}
+
+pub fn insurance_claim(accounts: accounts::ClaimInsurance) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: InsuranceClaim.data(),
+    }
+}
@@ -0,0 +1,23 @@
+//! Thin interface to the CryptoScore factory program.
+//!
+//! Exposes the account layouts, events, instruction data, instruction builders and
+//! (behind the `cpi` feature) a CPI client, so other programs and clients can
+//! integrate without depending on the full program crate.
+
+use anchor_lang::prelude::*;
+
+pub mod accounts;
+#[cfg(feature = "cpi")]
+pub mod cpi;
+pub mod events;
+pub mod instruction;
+pub mod state;
+
+pub use state::{
+    CreationRatePolicy, CreatorRate, DiscountPolicy, DiscountTier, Factory, FeeReceipt, InsuranceClaim, InsuranceFund,
+    KeeperTipPolicy, KeeperTips, KickoffBucket, LiveScore, MarketRegistry, MatchIdPolicy, MisresolutionRuling,
+    PayoutSwapPolicy, Platform, PlatformConfig, RakeFreeMarket, RebatePolicy, ReceiptPolicy, ResolutionGracePolicy,
+    ResolutionPolicy, ResultsBatch, ResultsSigner, TagDefinition, Treasury, Voucher, VoucherCampaign, WormholeEmitter,
+};
+
+declare_id!("5zADKCecxATSEsCuH5MJa1JdfXGeBLNwEYnkCbqdaYmZ");
@@ -0,0 +1,454 @@
+//! Factory program account layouts, byte-for-byte with the program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::rate_limit::SlidingWindow;
+use cryptoscore_common::tags::{Tag, TagCategory, Tags};
+use cryptoscore_common::{MatchOutcome, MatchResult};
+
+#[account]
+pub struct Factory {
+    /// Authority that can update factory settings
+    pub authority: Pubkey,
+    /// Total number of markets created
+    pub market_count: u64,
+    /// Platform fee in basis points (100 = 1%)
+    pub platform_fee_bps: u16,
+    /// Minimum qualifying markets settled before a user can enter leaderboards
+    pub min_leaderboard_markets: u32,
+    /// Minimum market pool in lamports for a settlement to count towards leaderboards
+    pub min_leaderboard_pool: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Platform {
+    /// Operator, also the authority of the platform's factory
+    pub operator: Pubkey,
+    /// The platform's factory, at the `factory` PDA of this account
+    pub factory: Pubkey,
+    /// Platform identifier (e.g., "acme-sports")
+    pub platform_id: String,
+    /// Timestamp when the platform was created
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ResultsSigner {
+    /// Factory this signer resolves markets for
+    pub factory: Pubkey,
+    /// Ed25519 key signing `attestation_message`s
+    pub signer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct WormholeEmitter {
+    /// Factory this emitter resolves markets for
+    pub factory: Pubkey,
+    /// Wormhole core bridge program that owns posted VAA accounts
+    pub core_bridge: Pubkey,
+    /// Wormhole chain ID of the oracle's chain
+    pub emitter_chain: u16,
+    /// Oracle contract address, left-padded to 32 bytes
+    pub emitter_address: [u8; 32],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ResultsBatch {
+    /// Factory whose results signer published the batch
+    pub factory: Pubkey,
+    /// Caller-chosen identifier, e.g. a matchweek number
+    pub batch_id: u64,
+    /// Results signer at the time of publishing
+    pub signer: Pubkey,
+    /// Publishing timestamp
+    pub published_at: i64,
+    /// One result per match, with distinct match IDs
+    pub results: Vec<MatchResult>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct LiveScore {
+    /// Factory whose results signer reports the score
+    pub factory: Pubkey,
+    /// Match identifier, as registered with the factory
+    pub match_id: String,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Minute of play at the last update
+    pub minute: u16,
+    /// Whether the last update was the final score
+    pub is_final: bool,
+    /// Results signer at the time of the last update
+    pub signer: Pubkey,
+    /// Timestamp of the last update
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ResolutionPolicy {
+    /// Factory whose markets the policy applies to
+    pub factory: Pubkey,
+    /// Smallest pool in lamports needing co-signed resolution; zero for none
+    pub high_stakes_pool: u64,
+    /// Independent key co-signing alongside the creator and results signer
+    pub arbiter: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct RebatePolicy {
+    /// Factory whose treasury funds the rebates
+    pub factory: Pubkey,
+    /// Consecutive settled losses earning a rebate on the next entry; zero for none
+    pub loss_streak: u16,
+    /// Share of the entry fee rebated, in basis points
+    pub rebate_bps: u16,
+    /// Most lamports rebated per epoch
+    pub epoch_cap: u64,
+    /// Epoch `epoch_paid` counts towards
+    pub epoch: u64,
+    /// Lamports rebated during `epoch`
+    pub epoch_paid: u64,
+    /// Total lamports rebated
+    pub total_paid: u64,
+    /// Number of rebates paid
+    pub rebate_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct DiscountPolicy {
+    /// Factory whose treasury funds the discounts
+    pub factory: Pubkey,
+    /// Dashboard's soulbound reputation mint, whose balances tiers are measured in
+    pub reputation_mint: Pubkey,
+    /// Discount tiers; users get the largest discount they qualify for
+    pub tiers: Vec<DiscountTier>,
+    /// Most lamports discounted per epoch
+    pub epoch_cap: u64,
+    /// Epoch `epoch_paid` counts towards
+    pub epoch: u64,
+    /// Lamports discounted during `epoch`
+    pub epoch_paid: u64,
+    /// Total lamports discounted
+    pub total_paid: u64,
+    /// Number of discounts paid
+    pub discount_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// One discount tier, reached by earning enough reputation or holding enough
+/// creator badges
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DiscountTier {
+    /// Reputation, as earned by a verified record, qualifying for the tier; zero to ignore reputation
+    pub min_reputation: u64,
+    /// Creator badges qualifying for the tier; zero to ignore badges
+    pub min_badges: u8,
+    /// Share of the entry fee discounted, in basis points
+    pub discount_bps: u16,
+}
+
+#[account]
+pub struct KeeperTipPolicy {
+    /// Factory whose treasury pays the tips
+    pub factory: Pubkey,
+    /// Lamports tipped per crank; zero when tips are off
+    pub tip: u64,
+    /// Most lamports tipped per market
+    pub market_budget: u64,
+    /// Total lamports tipped
+    pub total_paid: u64,
+    /// Number of tips paid
+    pub tip_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct PayoutSwapPolicy {
+    /// Factory whose markets pay out in these tokens
+    pub factory: Pubkey,
+    /// Mints payouts can be swapped into; empty when swaps are off
+    pub mints: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ReceiptPolicy {
+    /// Factory whose markets' claims get receipts
+    pub factory: Pubkey,
+    /// Bubblegum tree receipts are minted into; default when receipts are off
+    pub merkle_tree: Pubkey,
+    /// Start of every receipt's URI
+    pub uri_base: String,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ResolutionGracePolicy {
+    /// Factory whose markets are bound
+    pub factory: Pubkey,
+    /// Shortest grace period, in seconds after a market ends
+    pub min_grace_seconds: i64,
+    /// Longest grace period, in seconds after a market ends
+    pub max_grace_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct MatchIdPolicy {
+    /// Factory whose match IDs are checked
+    pub factory: Pubkey,
+    /// Bitmap of the allowed ASCII bytes, as in `cryptoscore_common::match_id`
+    pub charset: u128,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct CreationRatePolicy {
+    /// Factory whose creators are limited
+    pub factory: Pubkey,
+    /// Markets a wallet can create per window; zero when the limit is off
+    pub max_markets: u32,
+    /// Length of the sliding window in seconds
+    pub window_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct CreatorRate {
+    /// Factory the markets were created on
+    pub factory: Pubkey,
+    /// Wallet creating the markets
+    pub creator: Pubkey,
+    /// Markets created recently, in windows of the creation rate policy's length
+    pub recent: SlidingWindow,
+    /// Markets created in total
+    pub total_created: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct RakeFreeMarket {
+    /// Factory the market belongs to
+    pub factory: Pubkey,
+    /// Market the flag applies to
+    pub market: Pubkey,
+    /// Whether the market's resolution takes no fees
+    pub rake_free: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct KickoffBucket {
+    /// Factory the markets belong to
+    pub factory: Pubkey,
+    /// Unix day, counted from the epoch, the markets kick off on
+    pub day: u32,
+    /// Markets listed at creation, including any never initialized or since cancelled
+    pub markets: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct KeeperTips {
+    /// Market whose cranks were tipped
+    pub market: Pubkey,
+    /// Lamports tipped so far
+    pub paid: u64,
+    /// Number of tips paid
+    pub tip_count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct VoucherCampaign {
+    /// Factory whose treasury funds the vouchers
+    pub factory: Pubkey,
+    /// Campaign ID chosen by the factory authority
+    pub campaign_id: u64,
+    /// Most lamports the campaign's vouchers can cover in total
+    pub budget: u64,
+    /// Largest entry fee a voucher covers
+    pub max_entry_fee: u64,
+    /// Unix timestamp from which vouchers can't be issued or redeemed
+    pub expires_at: i64,
+    /// Lamports covered so far
+    pub spent: u64,
+    /// Number of vouchers issued
+    pub issued: u32,
+    /// Number of vouchers redeemed
+    pub redeemed: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Voucher {
+    /// Campaign funding the voucher
+    pub campaign: Pubkey,
+    /// User who can redeem the voucher
+    pub holder: Pubkey,
+    /// Market the voucher was redeemed in; the default pubkey until then
+    pub redeemed_market: Pubkey,
+    /// Unix timestamp of the redemption, zero until then
+    pub redeemed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct MarketRegistry {
+    /// Factory that created this market
+    pub factory: Pubkey,
+    /// Market account address
+    pub market_address: Pubkey,
+    /// Creator of the market
+    pub creator: Pubkey,
+    /// Discovery tags indexed by `TagCategory::slot`, all zeroes when unset
+    pub tags: Tags,
+    /// Match identifier (e.g., "EPL-2024-123")
+    pub match_id: String,
+    /// Timestamp when market was created
+    pub created_at: i64,
+    /// Whether market is public or private
+    pub is_public: bool,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    /// Match kickoff time
+    pub kickoff_time: i64,
+    /// Match end time
+    pub end_time: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct TagDefinition {
+    /// Factory the tag is registered with
+    pub factory: Pubkey,
+    /// Slot the tag may be used in
+    pub category: TagCategory,
+    /// Zero-padded tag bytes
+    pub tag: Tag,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct PlatformConfig {
+    /// Factory the parameters apply to
+    pub factory: Pubkey,
+    /// Key that can change platform parameters besides the factory authority; default for none
+    pub governor: Pubkey,
+    /// Seconds after a market ends during which it can be ruled mis-resolved; zero for no limit
+    pub dispute_window: i64,
+    /// Only account treasury withdrawals can go to; default for any
+    pub treasury_recipient: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Treasury {
+    /// Factory collecting the fees
+    pub factory: Pubkey,
+    /// Total platform fees recorded in lamports
+    pub total_collected: u64,
+    /// Total withdrawn by the factory authority in lamports
+    pub total_withdrawn: u64,
+    /// Number of fee receipts issued
+    pub receipt_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct FeeReceipt {
+    /// Factory whose treasury received the fee
+    pub factory: Pubkey,
+    /// Market that paid the fee
+    pub market: Pubkey,
+    /// Fee in lamports
+    pub amount: u64,
+    /// Resolution timestamp
+    pub collected_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct InsuranceFund {
+    /// Factory whose fees fund it
+    pub factory: Pubkey,
+    /// Total moved in from the treasury in lamports
+    pub total_funded: u64,
+    /// Total paid out on claims in lamports
+    pub total_paid: u64,
+    /// Number of claims paid
+    pub claim_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct MisresolutionRuling {
+    /// Factory the market belongs to
+    pub factory: Pubkey,
+    /// Mis-resolved market
+    pub market: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome it should have resolved to
+    pub correct_outcome: MatchOutcome,
+    /// Most one participant can claim in lamports
+    pub claim_cap: u64,
+    /// Most all claims on the market can pay in lamports
+    pub payout_cap: u64,
+    /// Paid out on claims so far in lamports
+    pub total_paid: u64,
+    /// Number of claims paid
+    pub claim_count: u32,
+    /// Ruling timestamp
+    pub ruled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct InsuranceClaim {
+    /// Mis-resolved market
+    pub market: Pubkey,
+    /// Participant compensated
+    pub user: Pubkey,
+    /// Lamports paid
+    pub amount: u64,
+    /// Claim timestamp
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
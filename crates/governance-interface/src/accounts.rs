@@ -0,0 +1,458 @@
+//! Account lists for each governance instruction, in program order.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::system_program;
+use cryptoscore_common::pda::{
+    find_arbiter_address, find_court_address, find_dispute_address, find_factory_address, find_governance_address,
+    find_insurance_fund_address, find_misresolution_ruling_address, find_platform_config_address,
+    find_proposal_address, find_stake_position_address, find_vote_record_address,
+};
+use cryptoscore_common::FACTORY_PROGRAM_ID;
+
+pub struct InitializeGovernance {
+    pub factory: Pubkey,
+    pub governance: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl InitializeGovernance {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        Self {
+            factory,
+            governance: find_governance_address(&factory).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for InitializeGovernance {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.governance, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct Stake {
+    pub governance: Pubkey,
+    pub stake_position: Pubkey,
+    pub user: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl Stake {
+    pub fn new(user: Pubkey) -> Self {
+        let governance = find_governance_address(&find_factory_address().0).0;
+        Self {
+            governance,
+            stake_position: find_stake_position_address(&governance, &user).0,
+            user,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for Stake {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.governance, false),
+            AccountMeta::new(self.stake_position, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct Unstake {
+    pub governance: Pubkey,
+    pub stake_position: Pubkey,
+    pub user: Pubkey,
+}
+
+impl Unstake {
+    pub fn new(user: Pubkey) -> Self {
+        let governance = find_governance_address(&find_factory_address().0).0;
+        Self {
+            governance,
+            stake_position: find_stake_position_address(&governance, &user).0,
+            user,
+        }
+    }
+}
+
+impl ToAccountMetas for Unstake {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.governance, false),
+            AccountMeta::new(self.stake_position, false),
+            AccountMeta::new(self.user, true),
+        ]
+    }
+}
+
+pub struct CreateProposal {
+    pub governance: Pubkey,
+    pub stake_position: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CreateProposal {
+    /// `id` is the governance's current `proposal_count`
+    pub fn new(proposer: Pubkey, id: u64) -> Self {
+        let governance = find_governance_address(&find_factory_address().0).0;
+        Self {
+            governance,
+            stake_position: find_stake_position_address(&governance, &proposer).0,
+            proposal: find_proposal_address(&governance, id).0,
+            proposer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CreateProposal {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.governance, false),
+            AccountMeta::new_readonly(self.stake_position, false),
+            AccountMeta::new(self.proposal, false),
+            AccountMeta::new(self.proposer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CastVote {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub stake_position: Pubkey,
+    pub vote_record: Pubkey,
+    pub voter: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CastVote {
+    pub fn new(voter: Pubkey, id: u64) -> Self {
+        let governance = find_governance_address(&find_factory_address().0).0;
+        let proposal = find_proposal_address(&governance, id).0;
+        Self {
+            governance,
+            proposal,
+            stake_position: find_stake_position_address(&governance, &voter).0,
+            vote_record: find_vote_record_address(&proposal, &voter).0,
+            voter,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CastVote {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.governance, false),
+            AccountMeta::new(self.proposal, false),
+            AccountMeta::new(self.stake_position, false),
+            AccountMeta::new(self.vote_record, false),
+            AccountMeta::new(self.voter, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct ExecuteProposal {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub factory: Pubkey,
+    pub platform_config: Pubkey,
+    pub factory_program: Pubkey,
+}
+
+impl ExecuteProposal {
+    pub fn new(id: u64) -> Self {
+        let factory = find_factory_address().0;
+        let governance = find_governance_address(&factory).0;
+        Self {
+            governance,
+            proposal: find_proposal_address(&governance, id).0,
+            factory,
+            platform_config: find_platform_config_address(&factory).0,
+            factory_program: FACTORY_PROGRAM_ID,
+        }
+    }
+}
+
+impl ToAccountMetas for ExecuteProposal {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.governance, false),
+            AccountMeta::new(self.proposal, false),
+            AccountMeta::new(self.factory, false),
+            AccountMeta::new(self.platform_config, false),
+            AccountMeta::new_readonly(self.factory_program, false),
+        ]
+    }
+}
+
+pub struct InitializeCourt {
+    pub factory: Pubkey,
+    pub governance: Pubkey,
+    pub court: Pubkey,
+    pub authority: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl InitializeCourt {
+    pub fn new(authority: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        let governance = find_governance_address(&factory).0;
+        Self {
+            factory,
+            governance,
+            court: find_court_address(&governance).0,
+            authority,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for InitializeCourt {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.governance, false),
+            AccountMeta::new(self.court, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct JoinCourt {
+    pub court: Pubkey,
+    pub arbiter: Pubkey,
+    pub user: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl JoinCourt {
+    pub fn new(user: Pubkey) -> Self {
+        let court = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        Self {
+            court,
+            arbiter: find_arbiter_address(&court, &user).0,
+            user,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for JoinCourt {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.court, false),
+            AccountMeta::new(self.arbiter, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct LeaveCourt {
+    pub court: Pubkey,
+    pub arbiter: Pubkey,
+    pub user: Pubkey,
+}
+
+impl LeaveCourt {
+    pub fn new(user: Pubkey) -> Self {
+        let court = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        Self {
+            court,
+            arbiter: find_arbiter_address(&court, &user).0,
+            user,
+        }
+    }
+}
+
+impl ToAccountMetas for LeaveCourt {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.court, false),
+            AccountMeta::new(self.arbiter, false),
+            AccountMeta::new(self.user, true),
+        ]
+    }
+}
+
+pub struct OpenDispute {
+    pub governance: Pubkey,
+    pub court: Pubkey,
+    pub market: Pubkey,
+    pub platform_config: Pubkey,
+    pub dispute: Pubkey,
+    pub disputer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl OpenDispute {
+    pub fn new(disputer: Pubkey, market: Pubkey) -> Self {
+        let factory = find_factory_address().0;
+        let governance = find_governance_address(&factory).0;
+        let court = find_court_address(&governance).0;
+        Self {
+            governance,
+            court,
+            market,
+            platform_config: find_platform_config_address(&factory).0,
+            dispute: find_dispute_address(&court, &market).0,
+            disputer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for OpenDispute {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.governance, false),
+            AccountMeta::new_readonly(self.court, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.platform_config, false),
+            AccountMeta::new(self.dispute, false),
+            AccountMeta::new(self.disputer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct DrawJury {
+    pub court: Pubkey,
+    pub dispute: Pubkey,
+    pub slot_hashes: Pubkey,
+    /// Arbiter accounts of the jurors the committed slot hash draws, in jury
+    /// order, passed as remaining accounts
+    pub arbiters: Vec<Pubkey>,
+}
+
+impl DrawJury {
+    /// Accounts for drawing `jurors`, worked out off-chain from the same slot
+    /// hash, on `market`'s dispute; no jurors only commits to a new slot
+    pub fn new(market: Pubkey, jurors: &[Pubkey]) -> Self {
+        let court = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        Self {
+            court,
+            dispute: find_dispute_address(&court, &market).0,
+            slot_hashes: slot_hashes::ID,
+            arbiters: jurors.iter().map(|juror| find_arbiter_address(&court, juror).0).collect(),
+        }
+    }
+}
+
+impl ToAccountMetas for DrawJury {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.court, false),
+            AccountMeta::new(self.dispute, false),
+            AccountMeta::new_readonly(self.slot_hashes, false),
+        ];
+        metas.extend(self.arbiters.iter().map(|arbiter| AccountMeta::new(*arbiter, false)));
+        metas
+    }
+}
+
+pub struct CastJurorVote {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+}
+
+impl CastJurorVote {
+    pub fn new(juror: Pubkey, market: Pubkey) -> Self {
+        let court = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        Self {
+            dispute: find_dispute_address(&court, &market).0,
+            juror,
+        }
+    }
+}
+
+impl ToAccountMetas for CastJurorVote {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.dispute, false),
+            AccountMeta::new_readonly(self.juror, true),
+        ]
+    }
+}
+
+pub struct SettleDispute {
+    pub governance: Pubkey,
+    pub court: Pubkey,
+    pub dispute: Pubkey,
+    pub disputer: Pubkey,
+    pub factory: Pubkey,
+    pub market: Pubkey,
+    pub ruling: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub platform_config: Pubkey,
+    pub payer: Pubkey,
+    pub factory_program: Pubkey,
+    pub system_program: Pubkey,
+    /// Arbiter accounts of the dispute's jurors, in jury order, passed as
+    /// remaining accounts
+    pub arbiters: Vec<Pubkey>,
+}
+
+impl SettleDispute {
+    /// Accounts for settling `market`'s dispute, opened by `disputer` and
+    /// heard by `jurors`
+    pub fn new(payer: Pubkey, market: Pubkey, disputer: Pubkey, jurors: &[Pubkey]) -> Self {
+        let factory = find_factory_address().0;
+        let governance = find_governance_address(&factory).0;
+        let court = find_court_address(&governance).0;
+        Self {
+            governance,
+            court,
+            dispute: find_dispute_address(&court, &market).0,
+            disputer,
+            factory,
+            market,
+            ruling: find_misresolution_ruling_address(&market).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            platform_config: find_platform_config_address(&factory).0,
+            payer,
+            factory_program: FACTORY_PROGRAM_ID,
+            system_program: system_program::ID,
+            arbiters: jurors.iter().map(|juror| find_arbiter_address(&court, juror).0).collect(),
+        }
+    }
+}
+
+impl ToAccountMetas for SettleDispute {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.governance, false),
+            AccountMeta::new(self.court, false),
+            AccountMeta::new(self.dispute, false),
+            AccountMeta::new(self.disputer, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.ruling, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.platform_config, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.factory_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.arbiters.iter().map(|arbiter| AccountMeta::new(*arbiter, false)));
+        metas
+    }
+}
@@ -0,0 +1,73 @@
+//! Events emitted by the governance program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::MatchOutcome;
+use cryptoscore_factory_interface::instruction::PlatformParam;
+
+#[event]
+pub struct ProposalCreated {
+    #[index]
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub param: PlatformParam,
+    pub voting_ends_at: i64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    #[index]
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    #[index]
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub param: PlatformParam,
+}
+
+#[event]
+pub struct DisputeOpened {
+    #[index]
+    pub dispute: Pubkey,
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub resolved_outcome: MatchOutcome,
+    pub claimed_outcome: MatchOutcome,
+}
+
+#[event]
+pub struct JuryDrawn {
+    #[index]
+    pub dispute: Pubkey,
+    pub market: Pubkey,
+    pub jurors: Vec<Pubkey>,
+    pub voting_ends_at: i64,
+}
+
+#[event]
+pub struct JurorVoted {
+    #[index]
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub outcome: MatchOutcome,
+}
+
+#[event]
+pub struct DisputeSettled {
+    #[index]
+    pub dispute: Pubkey,
+    pub market: Pubkey,
+    pub verdict: MatchOutcome,
+    pub overturned: bool,
+    pub slashed: u64,
+    pub juror_reward: u64,
+}
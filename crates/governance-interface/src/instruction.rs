@@ -0,0 +1,270 @@
+//! Governance instruction data and builders.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use cryptoscore_common::MatchOutcome;
+use cryptoscore_factory_interface::instruction::PlatformParam;
+
+use crate::accounts;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeGovernance {
+    pub voting_period: i64,
+    pub timelock: i64,
+    pub quorum_bps: u16,
+    pub min_proposal_stake: u64,
+}
+
+impl Discriminator for InitializeGovernance {
+    const DISCRIMINATOR: [u8; 8] = [171, 87, 101, 237, 27, 107, 201, 57];
+}
+
+impl InstructionData for InitializeGovernance {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct Stake {
+    pub amount: u64,
+}
+
+impl Discriminator for Stake {
+    const DISCRIMINATOR: [u8; 8] = [206, 176, 202, 18, 200, 209, 179, 108];
+}
+
+impl InstructionData for Stake {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct Unstake {
+    pub amount: u64,
+}
+
+impl Discriminator for Unstake {
+    const DISCRIMINATOR: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
+}
+
+impl InstructionData for Unstake {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateProposal {
+    pub param: PlatformParam,
+}
+
+impl Discriminator for CreateProposal {
+    const DISCRIMINATOR: [u8; 8] = [132, 116, 68, 174, 216, 160, 198, 22];
+}
+
+impl InstructionData for CreateProposal {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CastVote {
+    pub approve: bool,
+}
+
+impl Discriminator for CastVote {
+    const DISCRIMINATOR: [u8; 8] = [20, 212, 15, 189, 69, 180, 69, 151];
+}
+
+impl InstructionData for CastVote {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteProposal;
+
+impl Discriminator for ExecuteProposal {
+    const DISCRIMINATOR: [u8; 8] = [186, 60, 116, 133, 108, 128, 111, 28];
+}
+
+impl InstructionData for ExecuteProposal {}
+
+/// Jury, stake and ruling settings of a court
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CourtTerms {
+    /// Jurors per dispute, odd and at most 9
+    pub jury_size: u8,
+    /// Seconds jurors have to vote once drawn
+    pub voting_period: i64,
+    /// Lamports an arbiter must stake to be drawn
+    pub min_arbiter_stake: u64,
+    /// Lamports a disputer pays, split between jurors voting for the verdict
+    pub dispute_fee: u64,
+    /// Share of stake jurors not voting for the verdict lose, in basis points
+    pub slash_bps: u16,
+    /// Most one participant can claim on an overturned market in lamports
+    pub claim_cap: u64,
+    /// Most all claims on an overturned market can pay in lamports
+    pub payout_cap: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeCourt {
+    pub terms: CourtTerms,
+}
+
+impl Discriminator for InitializeCourt {
+    const DISCRIMINATOR: [u8; 8] = [180, 6, 145, 250, 31, 191, 214, 46];
+}
+
+impl InstructionData for InitializeCourt {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JoinCourt {
+    pub amount: u64,
+}
+
+impl Discriminator for JoinCourt {
+    const DISCRIMINATOR: [u8; 8] = [157, 87, 40, 223, 227, 214, 200, 73];
+}
+
+impl InstructionData for JoinCourt {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LeaveCourt;
+
+impl Discriminator for LeaveCourt {
+    const DISCRIMINATOR: [u8; 8] = [11, 254, 28, 16, 18, 240, 176, 174];
+}
+
+impl InstructionData for LeaveCourt {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OpenDispute {
+    pub claimed_outcome: MatchOutcome,
+}
+
+impl Discriminator for OpenDispute {
+    const DISCRIMINATOR: [u8; 8] = [137, 25, 99, 119, 23, 223, 161, 42];
+}
+
+impl InstructionData for OpenDispute {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DrawJury;
+
+impl Discriminator for DrawJury {
+    const DISCRIMINATOR: [u8; 8] = [192, 82, 81, 195, 206, 241, 173, 90];
+}
+
+impl InstructionData for DrawJury {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CastJurorVote {
+    pub outcome: MatchOutcome,
+}
+
+impl Discriminator for CastJurorVote {
+    const DISCRIMINATOR: [u8; 8] = [188, 185, 249, 80, 46, 77, 192, 91];
+}
+
+impl InstructionData for CastJurorVote {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SettleDispute;
+
+impl Discriminator for SettleDispute {
+    const DISCRIMINATOR: [u8; 8] = [155, 147, 5, 44, 20, 204, 146, 43];
+}
+
+impl InstructionData for SettleDispute {}
+
+pub fn initialize_governance(accounts: accounts::InitializeGovernance, args: InitializeGovernance) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn stake(accounts: accounts::Stake, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: Stake { amount }.data(),
+    }
+}
+
+pub fn unstake(accounts: accounts::Unstake, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: Unstake { amount }.data(),
+    }
+}
+
+pub fn create_proposal(accounts: accounts::CreateProposal, param: PlatformParam) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CreateProposal { param }.data(),
+    }
+}
+
+pub fn cast_vote(accounts: accounts::CastVote, approve: bool) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CastVote { approve }.data(),
+    }
+}
+
+pub fn execute_proposal(accounts: accounts::ExecuteProposal) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ExecuteProposal.data(),
+    }
+}
+
+pub fn initialize_court(accounts: accounts::InitializeCourt, terms: CourtTerms) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: InitializeCourt { terms }.data(),
+    }
+}
+
+pub fn join_court(accounts: accounts::JoinCourt, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: JoinCourt { amount }.data(),
+    }
+}
+
+pub fn leave_court(accounts: accounts::LeaveCourt) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: LeaveCourt.data(),
+    }
+}
+
+pub fn open_dispute(accounts: accounts::OpenDispute, claimed_outcome: MatchOutcome) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: OpenDispute { claimed_outcome }.data(),
+    }
+}
+
+pub fn draw_jury(accounts: accounts::DrawJury) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: DrawJury.data(),
+    }
+}
+
+pub fn cast_juror_vote(accounts: accounts::CastJurorVote, outcome: MatchOutcome) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CastJurorVote { outcome }.data(),
+    }
+}
+
+pub fn settle_dispute(accounts: accounts::SettleDispute) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SettleDispute.data(),
+    }
+}
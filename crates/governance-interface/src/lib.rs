@@ -0,0 +1,18 @@
+//! Thin interface to the CryptoScore governance program.
+//!
+//! Exposes the account layouts, events, instruction data and instruction
+//! builders, so clients can stake, propose, vote and take part in the dispute
+//! court without depending on the full program crate.
+
+use anchor_lang::prelude::*;
+
+pub mod accounts;
+pub mod events;
+pub mod instruction;
+pub mod state;
+
+pub use cryptoscore_factory_interface::instruction::PlatformParam;
+pub use instruction::CourtTerms;
+pub use state::{Arbiter, Court, Dispute, DisputeStatus, Governance, Proposal, StakePosition, VoteRecord};
+
+declare_id!("CryTSDmAGaocxE6SdUMiQmfnawgZPmHq9cDGUTeCsa5b");
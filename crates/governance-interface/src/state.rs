@@ -0,0 +1,165 @@
+//! Governance program account layouts, byte-for-byte with the program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::MatchOutcome;
+use cryptoscore_factory_interface::instruction::PlatformParam;
+
+use crate::instruction::CourtTerms;
+
+#[account]
+pub struct Governance {
+    /// Factory whose parameters proposals change
+    pub factory: Pubkey,
+    /// Seconds a proposal is open for voting
+    pub voting_period: i64,
+    /// Seconds between the end of voting and when a passed proposal can execute
+    pub timelock: i64,
+    /// Share of the stake at a proposal's creation that has to vote on it, in basis points
+    pub quorum_bps: u16,
+    /// Lamports a user must have staked to create a proposal
+    pub min_proposal_stake: u64,
+    /// Lamports staked across all stake positions
+    pub total_staked: u64,
+    /// Number of proposals created, and the next proposal's ID
+    pub proposal_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct StakePosition {
+    /// Governance the stake votes in
+    pub governance: Pubkey,
+    /// Staker
+    pub user: Pubkey,
+    /// Lamports staked, also the voting power
+    pub amount: u64,
+    /// Time until which the stake can't be withdrawn
+    pub locked_until: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Proposal {
+    /// Governance the proposal was made in
+    pub governance: Pubkey,
+    /// Sequential proposal ID
+    pub id: u64,
+    /// User who made the proposal
+    pub proposer: Pubkey,
+    /// Parameter change executed if the proposal passes
+    pub param: PlatformParam,
+    /// Stake that has to vote for the proposal to pass
+    pub quorum_votes: u64,
+    /// Stake voting for the change
+    pub votes_for: u64,
+    /// Stake voting against the change
+    pub votes_against: u64,
+    /// Timestamp when the proposal was created
+    pub created_at: i64,
+    /// Timestamp when voting ends
+    pub voting_ends_at: i64,
+    /// Timestamp from which the proposal can execute if passed
+    pub executable_at: i64,
+    /// Whether the change has been made
+    pub executed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Whether more stake voted for than against and the votes reached quorum
+    pub fn passed(&self) -> bool {
+        self.votes_for > self.votes_against && self.votes_for.saturating_add(self.votes_against) >= self.quorum_votes
+    }
+}
+
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Voter
+    pub voter: Pubkey,
+    /// Whether the vote was for the change
+    pub approve: bool,
+    /// Lamports staked when the vote was cast
+    pub weight: u64,
+    /// Timestamp of the vote
+    pub voted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Court {
+    /// Governance whose PDA rules on overturned resolutions
+    pub governance: Pubkey,
+    /// Jury, stake and ruling settings
+    pub terms: CourtTerms,
+    /// Arbiters staking at least the minimum, whom juries are drawn from
+    pub arbiters: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Arbiter {
+    /// Court the arbiter sits in
+    pub court: Pubkey,
+    /// Arbiter
+    pub user: Pubkey,
+    /// Lamports staked, slashed for voting against verdicts
+    pub stake: u64,
+    /// Juries the arbiter was drawn for whose disputes aren't settled yet
+    pub active_disputes: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Dispute {
+    /// Court hearing the dispute
+    pub court: Pubkey,
+    /// Disputed market
+    pub market: Pubkey,
+    /// User who opened the dispute
+    pub disputer: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome the disputer claims is correct
+    pub claimed_outcome: MatchOutcome,
+    /// Dispute fee paid in lamports
+    pub fee: u64,
+    /// Timestamp when the dispute was opened
+    pub opened_at: i64,
+    /// Stage of the dispute
+    pub status: DisputeStatus,
+    /// Slot whose hash, or the next one's if it was skipped, draws the jury
+    pub draw_slot: u64,
+    /// Slot hash the jury was drawn with
+    pub randomness: [u8; 32],
+    /// Drawn jurors
+    pub jurors: Vec<Pubkey>,
+    /// Each juror's vote, by jury position
+    pub votes: Vec<Option<MatchOutcome>>,
+    /// Timestamp when jury voting ends
+    pub voting_ends_at: i64,
+    /// Outcome the jury settled on
+    pub verdict: Option<MatchOutcome>,
+    /// Lamports each juror voting for the verdict earned
+    pub juror_reward: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Stage of a dispute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum DisputeStatus {
+    /// Waiting for the committed slot to draw the jury
+    AwaitingJury,
+    /// Jurors are voting
+    Voting,
+    /// Verdict reached and stakes settled
+    Settled,
+}
@@ -0,0 +1,99 @@
+//! Decoding of tracked program accounts and the startup snapshot.
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use cryptoscore_common::DASHBOARD_PROGRAM_ID;
+use cryptoscore_dashboard::UserStats;
+use cryptoscore_market_interface::{Market, Participant};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::client_error::Result as ClientResult;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+
+pub enum TrackedAccount {
+    Market(Market),
+    Participant(Participant),
+    UserStats(UserStats),
+}
+
+impl TrackedAccount {
+    /// Decode an account owned by `owner`, or `None` if it isn't a tracked type
+    pub fn decode(owner: &Pubkey, data: &[u8]) -> Option<Self> {
+        let discriminator = data.get(..8)?;
+
+        if *owner == cryptoscore_market_interface::ID {
+            if discriminator == Market::DISCRIMINATOR {
+                return deserialize(data).map(Self::Market);
+            }
+            if discriminator == Participant::DISCRIMINATOR {
+                return deserialize(data).map(Self::Participant);
+            }
+        } else if *owner == DASHBOARD_PROGRAM_ID && discriminator == UserStats::DISCRIMINATOR {
+            return deserialize(data).map(Self::UserStats);
+        }
+
+        None
+    }
+}
+
+/// A tracked account's state as of `slot`; `None` once the account is closed
+pub struct AccountUpdate {
+    pub address: Pubkey,
+    pub slot: u64,
+    pub account: Option<TrackedAccount>,
+}
+
+impl AccountUpdate {
+    /// Build an update from raw account fields, skipping untracked accounts
+    pub fn new(address: Pubkey, slot: u64, owner: &Pubkey, lamports: u64, data: &[u8]) -> Option<Self> {
+        // Closed accounts are reassigned to the system program with no data
+        if lamports == 0 {
+            return Some(Self { address, slot, account: None });
+        }
+        TrackedAccount::decode(owner, data).map(|account| Self {
+            address,
+            slot,
+            account: Some(account),
+        })
+    }
+}
+
+/// Every tracked account as of the current slot, to seed the views before streaming
+pub fn snapshot(rpc: &RpcClient) -> ClientResult<Vec<AccountUpdate>> {
+    let slot = rpc.get_slot()?;
+    let mut updates = Vec::new();
+
+    let programs = [
+        (cryptoscore_market_interface::ID, vec![]),
+        (
+            DASHBOARD_PROGRAM_ID,
+            vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, UserStats::DISCRIMINATOR.to_vec()))],
+        ),
+    ];
+    for (program_id, filters) in programs {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(rpc.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        updates.extend(
+            rpc.get_program_accounts_with_config(&program_id, config)?
+                .into_iter()
+                .filter_map(|(address, account)| {
+                    AccountUpdate::new(address, slot, &account.owner, account.lamports, &account.data)
+                }),
+        );
+    }
+
+    Ok(updates)
+}
+
+fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Option<T> {
+    T::try_deserialize(&mut &data[..]).ok()
+}
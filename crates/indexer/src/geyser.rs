@@ -0,0 +1,92 @@
+//! Yellowstone gRPC consumer for real-time account updates.
+
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::thread::{self, JoinHandle};
+
+use futures::{SinkExt, StreamExt};
+use solana_sdk::pubkey::Pubkey;
+use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_proto::prelude::{
+    subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts,
+    SubscribeRequestPing,
+};
+
+use crate::accounts::AccountUpdate;
+use crate::Update;
+
+/// Programs whose account writes are streamed
+const OWNERS: [Pubkey; 2] = [cryptoscore_market_interface::ID, cryptoscore_common::DASHBOARD_PROGRAM_ID];
+
+/// Stream tracked account updates from a Yellowstone endpoint into `sender`
+///
+/// Runs on its own thread with a single-threaded runtime and logs why it
+/// stopped, which happens when the stream ends or `sender` is dropped.
+pub fn spawn(endpoint: String, x_token: Option<String>, sender: Sender<Update>) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let result = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(Into::into)
+            .and_then(|runtime| runtime.block_on(consume(endpoint, x_token, sender)));
+        if let Err(err) = result {
+            eprintln!("Geyser consumer stopped: {}", err);
+        }
+    })
+}
+
+async fn consume(
+    endpoint: String,
+    x_token: Option<String>,
+    sender: Sender<Update>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut client = GeyserGrpcClient::build_from_shared(endpoint)?
+        .x_token(x_token)?
+        .connect()
+        .await?;
+
+    let request = SubscribeRequest {
+        accounts: HashMap::from([(
+            "cryptoscore".to_string(),
+            SubscribeRequestFilterAccounts {
+                owner: OWNERS.iter().map(Pubkey::to_string).collect(),
+                ..SubscribeRequestFilterAccounts::default()
+            },
+        )]),
+        commitment: Some(CommitmentLevel::Confirmed as i32),
+        ..SubscribeRequest::default()
+    };
+    let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
+
+    while let Some(message) = stream.next().await {
+        match message?.update_oneof {
+            Some(UpdateOneof::Account(update)) => {
+                let Some(account) = update.account else {
+                    continue;
+                };
+                let (Ok(address), Ok(owner)) = (
+                    Pubkey::try_from(account.pubkey.as_slice()),
+                    Pubkey::try_from(account.owner.as_slice()),
+                ) else {
+                    continue;
+                };
+
+                if let Some(update) = AccountUpdate::new(address, update.slot, &owner, account.lamports, &account.data) {
+                    sender.send(Update::Account(update))?;
+                }
+            }
+            // Answer server pings so load balancers keep the stream open
+            Some(UpdateOneof::Ping(_)) => {
+                subscribe_tx
+                    .send(SubscribeRequest {
+                        ping: Some(SubscribeRequestPing { id: 1 }),
+                        ..SubscribeRequest::default()
+                    })
+                    .await?;
+            }
+            _ => {}
+        }
+    }
+
+    Err("Geyser stream closed".into())
+}
@@ -0,0 +1,126 @@
+//! Transaction sources: historical replay over RPC and live log subscriptions.
+
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use cryptoscore_client::events::parse_logs;
+use cryptoscore_client::CryptoscoreEvent;
+use solana_client::client_error::Result as ClientResult;
+use solana_client::pubsub_client::{PubsubClient, PubsubClientError, PubsubLogsClientSubscription};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::{RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+
+use crate::Update;
+
+/// Programs whose transactions are indexed
+pub const PROGRAMS: [Pubkey; 2] = [cryptoscore_factory_interface::ID, cryptoscore_market_interface::ID];
+
+/// Largest page `getSignaturesForAddress` returns
+const SIGNATURE_PAGE_LIMIT: usize = 1000;
+
+/// A successful transaction and the CryptoScore events it emitted
+pub struct IndexedTransaction {
+    pub signature: Signature,
+    pub slot: u64,
+    pub events: Vec<CryptoscoreEvent>,
+}
+
+/// Signatures of every successful program transaction at or after `from_slot`, oldest first
+pub fn signatures_since(rpc: &RpcClient, from_slot: u64) -> ClientResult<Vec<(Signature, u64)>> {
+    let mut signatures = Vec::new();
+
+    for program in PROGRAMS {
+        let mut before = None;
+        loop {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: None,
+                limit: Some(SIGNATURE_PAGE_LIMIT),
+                commitment: Some(rpc.commitment()),
+            };
+            let page = rpc.get_signatures_for_address_with_config(&program, config)?;
+
+            // Pages run newest to oldest, so stop once one reaches past `from_slot`
+            let done = page.len() < SIGNATURE_PAGE_LIMIT || page.last().is_none_or(|s| s.slot < from_slot);
+            before = page.last().and_then(|s| s.signature.parse().ok());
+
+            signatures.extend(
+                page.into_iter()
+                    .filter(|status| status.slot >= from_slot && status.err.is_none())
+                    .filter_map(|status| Some((status.signature.parse().ok()?, status.slot))),
+            );
+
+            if done {
+                break;
+            }
+        }
+    }
+
+    // A factory `create_market` CPI shows up under both programs
+    signatures.sort_by_key(|(signature, slot)| (*slot, *signature));
+    signatures.dedup();
+    Ok(signatures)
+}
+
+/// Fetch a confirmed transaction and decode its events
+pub fn fetch_transaction(rpc: &RpcClient, signature: &Signature, slot: u64) -> ClientResult<IndexedTransaction> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Json),
+        commitment: Some(rpc.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let transaction = rpc.get_transaction_with_config(signature, config)?;
+    let logs = transaction
+        .transaction
+        .meta
+        .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+        .unwrap_or_default();
+
+    Ok(IndexedTransaction {
+        signature: *signature,
+        slot,
+        events: parse_logs(&logs),
+    })
+}
+
+/// Forward decoded successful transactions for every indexed program into `sender`
+///
+/// The subscriptions stay open for as long as the returned handles are held.
+pub fn subscribe(
+    ws_url: &str,
+    config: RpcTransactionLogsConfig,
+    sender: Sender<Update>,
+) -> Result<Vec<PubsubLogsClientSubscription>, PubsubClientError> {
+    let mut subscriptions = Vec::with_capacity(PROGRAMS.len());
+
+    for program in PROGRAMS {
+        let filter = RpcTransactionLogsFilter::Mentions(vec![program.to_string()]);
+        let (subscription, logs) = PubsubClient::logs_subscribe(ws_url, filter, config.clone())?;
+        subscriptions.push(subscription);
+
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for response in logs {
+                if response.value.err.is_some() {
+                    continue;
+                }
+                let Ok(signature) = response.value.signature.parse() else {
+                    continue;
+                };
+                let transaction = IndexedTransaction {
+                    signature,
+                    slot: response.context.slot,
+                    events: parse_logs(&response.value.logs),
+                };
+                if sender.send(Update::Transaction(transaction)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    Ok(subscriptions)
+}
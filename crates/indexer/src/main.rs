@@ -0,0 +1,164 @@
+//! Event indexer for the CryptoScore programs.
+//!
+//! Replays program transactions from a slot (or from where the last run
+//! stopped), then follows new ones over a websocket log subscription, writing
+//! decoded events into SQLite for frontends to query. With `--geyser`, Market,
+//! Participant and UserStats accounts are also streamed from a Yellowstone
+//! endpoint into the `open_markets` and `pending_claims` views. With
+//! `--notify`, webhooks fire as markets resolve, claims open up and created
+//! markets reach participant thresholds.
+
+#![allow(clippy::result_large_err)]
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+use clap::Parser;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionLogsConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+mod accounts;
+mod geyser;
+mod logs;
+mod notify;
+mod store;
+
+use accounts::AccountUpdate;
+use logs::IndexedTransaction;
+use notify::Dispatcher;
+use store::Store;
+
+#[derive(Parser)]
+#[command(name = "cryptoscore-indexer", about = "Index CryptoScore events into SQLite")]
+struct Args {
+    /// JSON RPC URL
+    #[arg(long, short = 'u', default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Websocket URL (derived from --url when omitted)
+    #[arg(long)]
+    ws_url: Option<String>,
+
+    /// SQLite database file
+    #[arg(long, default_value = "cryptoscore.db")]
+    db: PathBuf,
+
+    /// Replay from this slot instead of resuming from the stored cursor
+    #[arg(long)]
+    from_slot: Option<u64>,
+
+    /// Exit after replaying history instead of following new transactions
+    #[arg(long)]
+    no_follow: bool,
+
+    /// Yellowstone gRPC endpoint for real-time account updates
+    #[arg(long, conflicts_with = "no_follow")]
+    geyser: Option<String>,
+
+    /// Access token for the Yellowstone endpoint
+    #[arg(long, requires = "geyser")]
+    x_token: Option<String>,
+
+    /// JSON webhook configuration; enables notifications for new transactions
+    #[arg(long)]
+    notify: Option<PathBuf>,
+}
+
+/// Anything the live sources feed into the store
+pub enum Update {
+    Transaction(IndexedTransaction),
+    Account(AccountUpdate),
+}
+
+fn main() {
+    if let Err(err) = run(Args::parse()) {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let commitment = CommitmentConfig::confirmed();
+    let rpc = RpcClient::new_with_commitment(args.url.clone(), commitment);
+    let mut store = Store::open(&args.db)?;
+
+    // Only notify for transactions past where the last run stopped (or, on a
+    // fresh database, past now) so replaying history doesn't flood webhooks
+    let dispatcher = match &args.notify {
+        Some(path) => {
+            let after_slot = match store.last_slot()? {
+                Some(slot) => slot,
+                None => rpc.get_slot()?,
+            };
+            Some(Dispatcher::load(path, after_slot)?)
+        }
+        None => None,
+    };
+
+    // Subscribe before replaying so nothing landing mid-replay is missed;
+    // anything seen twice is deduplicated by the store
+    let (sender, receiver) = mpsc::channel();
+    let _subscriptions = if args.no_follow {
+        Vec::new()
+    } else {
+        let ws_url = args.ws_url.clone().unwrap_or_else(|| websocket_url(&args.url));
+        logs::subscribe(&ws_url, RpcTransactionLogsConfig { commitment: Some(commitment) }, sender.clone())?
+    };
+    if let Some(endpoint) = args.geyser {
+        geyser::spawn(endpoint, args.x_token, sender.clone());
+
+        // Seed the views once; the stream only carries accounts as they change
+        let snapshot = accounts::snapshot(&rpc)?;
+        println!("Loaded {} accounts", snapshot.len());
+        for update in &snapshot {
+            store.apply(update)?;
+        }
+    }
+    drop(sender);
+
+    let from_slot = match args.from_slot {
+        Some(slot) => slot,
+        None => store.last_slot()?.unwrap_or(0),
+    };
+    let signatures = logs::signatures_since(&rpc, from_slot)?;
+    println!("Replaying {} transactions from slot {}", signatures.len(), from_slot);
+    for (signature, slot) in signatures {
+        index(&mut store, dispatcher.as_ref(), &logs::fetch_transaction(&rpc, &signature, slot)?)?;
+    }
+
+    if !args.no_follow {
+        println!("Following new transactions");
+    }
+    for update in receiver {
+        match update {
+            Update::Transaction(transaction) => index(&mut store, dispatcher.as_ref(), &transaction)?,
+            Update::Account(update) => store.apply(&update)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn index(
+    store: &mut Store,
+    dispatcher: Option<&Dispatcher>,
+    transaction: &IndexedTransaction,
+) -> Result<(), Box<dyn Error>> {
+    let inserted = store.record(transaction)?;
+    if inserted > 0 {
+        println!("slot {} {}: {} events", transaction.slot, transaction.signature, inserted);
+    }
+    if let Some(dispatcher) = dispatcher {
+        dispatcher.handle(store, transaction)?;
+    }
+    Ok(())
+}
+
+/// The validator's pubsub endpoint for an RPC URL
+fn websocket_url(url: &str) -> String {
+    let url = url.replacen("https://", "wss://", 1).replacen("http://", "ws://", 1);
+    // Local validators serve pubsub on the port after RPC
+    url.replace(":8899", ":8900")
+}
@@ -0,0 +1,184 @@
+//! Webhook notifications keyed off indexed events.
+//!
+//! Each notification is delivered at most once: its key is recorded after
+//! every matching webhook accepts it, so replays and restarts don't re-fire it.
+
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+
+use cryptoscore_client::CryptoscoreEvent;
+use cryptoscore_common::fees::reward_per_winner;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::logs::IndexedTransaction;
+use crate::store::Store;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topic {
+    /// A market the recipient joined was resolved
+    MarketResolved,
+    /// The recipient won and can withdraw their reward
+    ClaimAvailable,
+    /// A market the recipient created reached a participant threshold
+    ParticipantThreshold,
+}
+
+#[derive(Deserialize)]
+pub struct Webhook {
+    pub url: String,
+    pub topics: Vec<Topic>,
+    /// Only deliver notifications for these wallets (all when empty)
+    #[serde(default)]
+    pub recipients: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct NotifyConfig {
+    pub webhooks: Vec<Webhook>,
+    /// Participant counts that trigger `participant_threshold` for the creator
+    #[serde(default)]
+    pub participant_thresholds: Vec<u32>,
+}
+
+/// The JSON body posted to webhooks
+#[derive(Serialize)]
+pub struct Notification {
+    pub topic: Topic,
+    pub recipient: String,
+    pub market: String,
+    pub signature: String,
+    pub slot: u64,
+    pub data: Value,
+}
+
+impl Notification {
+    fn key(&self) -> String {
+        match self.topic {
+            Topic::ParticipantThreshold => format!("{:?}:{}:{}", self.topic, self.market, self.data["threshold"]),
+            _ => format!("{:?}:{}:{}", self.topic, self.market, self.recipient),
+        }
+    }
+}
+
+pub struct Dispatcher {
+    config: NotifyConfig,
+    agent: ureq::Agent,
+    /// Transactions at or before this slot were handled by an earlier run
+    after_slot: u64,
+}
+
+impl Dispatcher {
+    pub fn load(path: &Path, after_slot: u64) -> Result<Self, Box<dyn Error>> {
+        let config = serde_json::from_reader(File::open(path)?)?;
+        let agent = ureq::AgentBuilder::new().timeout(Duration::from_secs(10)).build();
+        Ok(Self { config, agent, after_slot })
+    }
+
+    /// Fire the notifications for a transaction already written to `store`
+    pub fn handle(&self, store: &Store, transaction: &IndexedTransaction) -> Result<(), Box<dyn Error>> {
+        if transaction.slot <= self.after_slot {
+            return Ok(());
+        }
+
+        for notification in self.notifications(store, transaction)? {
+            let key = notification.key();
+            if store.is_notified(&key)? {
+                continue;
+            }
+            // Leave the key unrecorded on failure so a replay retries it
+            match self.deliver(&notification) {
+                Ok(()) => store.mark_notified(&key)?,
+                Err(err) => eprintln!("Failed to deliver {}: {}", key, err),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn notifications(&self, store: &Store, transaction: &IndexedTransaction) -> rusqlite::Result<Vec<Notification>> {
+        let mut notifications = Vec::new();
+        let notification = |topic: Topic, recipient: String, market: String, data: Value| Notification {
+            topic,
+            recipient,
+            market,
+            signature: transaction.signature.to_string(),
+            slot: transaction.slot,
+            data,
+        };
+
+        for event in &transaction.events {
+            match event {
+                CryptoscoreEvent::MarketResolved(e) => {
+                    let market = e.market.to_string();
+                    let outcome = format!("{:?}", e.outcome);
+                    // A charity market's donation comes out of the pool in the same transaction
+                    let donated_prize_pool = transaction.events.iter().find_map(|event| match event {
+                        CryptoscoreEvent::CharityDonated(donation) if donation.market == e.market => {
+                            Some(donation.prize_pool)
+                        }
+                        _ => None,
+                    });
+                    let reward = match donated_prize_pool {
+                        Some(prize_pool) => prize_pool.checked_div(e.winner_count as u64),
+                        None => reward_per_winner(e.total_pool, e.winner_count),
+                    };
+
+                    for (user, prediction) in store.market_predictions(&market)? {
+                        let won = prediction == outcome;
+                        notifications.push(notification(
+                            Topic::MarketResolved,
+                            user.clone(),
+                            market.clone(),
+                            json!({ "outcome": outcome, "prediction": prediction, "won": won }),
+                        ));
+                        if let (true, Some(amount)) = (won, reward) {
+                            notifications.push(notification(
+                                Topic::ClaimAvailable,
+                                user,
+                                market.clone(),
+                                json!({ "amount": amount }),
+                            ));
+                        }
+                    }
+                }
+                CryptoscoreEvent::PredictionMade(e) => {
+                    let market = e.market.to_string();
+                    let Some(creator) = store.market_creator(&market)? else {
+                        continue;
+                    };
+                    let count = store.prediction_count(&market)?;
+
+                    for &threshold in &self.config.participant_thresholds {
+                        if count >= threshold {
+                            notifications.push(notification(
+                                Topic::ParticipantThreshold,
+                                creator.clone(),
+                                market.clone(),
+                                json!({ "threshold": threshold, "participant_count": count }),
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(notifications)
+    }
+
+    fn deliver(&self, notification: &Notification) -> Result<(), Box<ureq::Error>> {
+        let webhooks = self.config.webhooks.iter().filter(|webhook| {
+            webhook.topics.contains(&notification.topic)
+                && (webhook.recipients.is_empty() || webhook.recipients.contains(&notification.recipient))
+        });
+
+        for webhook in webhooks {
+            self.agent.post(&webhook.url).send_json(notification)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,432 @@
+//! SQLite storage for decoded events, account views, sent notifications and the replay cursor.
+
+use std::path::Path;
+
+use cryptoscore_client::CryptoscoreEvent;
+use cryptoscore_common::fees::settled_reward_per_winner;
+use cryptoscore_common::{MatchOutcome, CLAIM_WINDOW_SECONDS};
+use rusqlite::{params, Connection, OptionalExtension, Result};
+
+use crate::accounts::{AccountUpdate, TrackedAccount};
+use crate::logs::IndexedTransaction;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS markets_created (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    market TEXT NOT NULL,
+    creator TEXT NOT NULL,
+    match_id TEXT NOT NULL,
+    entry_fee INTEGER NOT NULL,
+    kickoff_time INTEGER NOT NULL,
+    is_public INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS markets_created_creator ON markets_created (creator);
+
+CREATE TABLE IF NOT EXISTS predictions (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    market TEXT NOT NULL,
+    user TEXT NOT NULL,
+    prediction TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS predictions_market ON predictions (market);
+CREATE INDEX IF NOT EXISTS predictions_user ON predictions (user);
+
+CREATE TABLE IF NOT EXISTS resolutions (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    market TEXT NOT NULL,
+    outcome TEXT NOT NULL,
+    winner_count INTEGER NOT NULL,
+    total_pool INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS resolutions_market ON resolutions (market);
+
+CREATE TABLE IF NOT EXISTS claims (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    market TEXT NOT NULL,
+    user TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS claims_user ON claims (user);
+
+CREATE TABLE IF NOT EXISTS market_accounts (
+    address TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    creator TEXT NOT NULL,
+    match_id TEXT NOT NULL,
+    entry_fee INTEGER NOT NULL,
+    kickoff_time INTEGER NOT NULL,
+    end_time INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    outcome TEXT,
+    total_pool INTEGER NOT NULL,
+    participant_count INTEGER NOT NULL,
+    is_public INTEGER NOT NULL,
+    reward_per_winner INTEGER,
+    claim_deadline INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS participant_accounts (
+    address TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    market TEXT NOT NULL,
+    user TEXT NOT NULL,
+    prediction TEXT NOT NULL,
+    joined_at INTEGER NOT NULL,
+    has_withdrawn INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS participant_accounts_user ON participant_accounts (user);
+CREATE INDEX IF NOT EXISTS participant_accounts_market ON participant_accounts (market);
+
+CREATE TABLE IF NOT EXISTS user_stats_accounts (
+    address TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    total_markets INTEGER NOT NULL,
+    wins INTEGER NOT NULL,
+    losses INTEGER NOT NULL,
+    total_wagered INTEGER NOT NULL,
+    total_won INTEGER NOT NULL,
+    net_profit INTEGER NOT NULL,
+    current_streak INTEGER NOT NULL,
+    best_streak INTEGER NOT NULL,
+    xp INTEGER NOT NULL,
+    level INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS user_stats_accounts_user ON user_stats_accounts (user);
+
+CREATE VIEW IF NOT EXISTS open_markets AS
+    SELECT * FROM market_accounts WHERE status = 'Open';
+
+CREATE VIEW IF NOT EXISTS pending_claims AS
+    SELECT p.user, p.market, p.address AS participant, m.reward_per_winner AS amount, m.claim_deadline
+    FROM participant_accounts p
+    JOIN market_accounts m ON m.address = p.market
+    WHERE m.status = 'Resolved' AND p.prediction = m.outcome AND p.has_withdrawn = 0;
+
+CREATE TABLE IF NOT EXISTS notifications_sent (
+    key TEXT PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS cursor (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    slot INTEGER NOT NULL
+);
+";
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Highest slot with an indexed transaction, used to resume replay
+    pub fn last_slot(&self) -> Result<Option<u64>> {
+        self.conn
+            .query_row("SELECT slot FROM cursor WHERE id = 0", [], |row| row.get(0))
+            .optional()
+    }
+
+    /// Write a transaction's events and advance the cursor atomically
+    ///
+    /// Rows are keyed by signature and event index, so replaying an already
+    /// indexed range is a no-op. Returns the number of new rows.
+    pub fn record(&mut self, transaction: &IndexedTransaction) -> Result<usize> {
+        let db = self.conn.transaction()?;
+        let signature = transaction.signature.to_string();
+        let slot = transaction.slot;
+        let mut inserted = 0;
+
+        for (index, event) in transaction.events.iter().enumerate() {
+            inserted += match event {
+                CryptoscoreEvent::MarketCreated(e) => db.execute(
+                    "INSERT OR IGNORE INTO markets_created
+                     (signature, event_index, slot, market, creator, match_id, entry_fee, kickoff_time, is_public)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    params![
+                        signature,
+                        index,
+                        slot,
+                        e.market.to_string(),
+                        e.creator.to_string(),
+                        e.match_id,
+                        e.entry_fee,
+                        e.kickoff_time,
+                        e.is_public
+                    ],
+                )?,
+                CryptoscoreEvent::PredictionMade(e) => db.execute(
+                    "INSERT OR IGNORE INTO predictions
+                     (signature, event_index, slot, market, user, prediction, timestamp)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        signature,
+                        index,
+                        slot,
+                        e.market.to_string(),
+                        e.user.to_string(),
+                        format!("{:?}", e.prediction),
+                        e.timestamp
+                    ],
+                )?,
+                CryptoscoreEvent::MarketResolved(e) => db.execute(
+                    "INSERT OR IGNORE INTO resolutions
+                     (signature, event_index, slot, market, outcome, winner_count, total_pool)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        signature,
+                        index,
+                        slot,
+                        e.market.to_string(),
+                        format!("{:?}", e.outcome),
+                        e.winner_count,
+                        e.total_pool
+                    ],
+                )?,
+                CryptoscoreEvent::RewardClaimed(e) => db.execute(
+                    "INSERT OR IGNORE INTO claims
+                     (signature, event_index, slot, market, user, amount)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        signature,
+                        index,
+                        slot,
+                        e.market.to_string(),
+                        e.user.to_string(),
+                        e.amount
+                    ],
+                )?,
+                CryptoscoreEvent::ResultAttested(_)
+                | CryptoscoreEvent::ResultBridged(_)
+                | CryptoscoreEvent::ResultCorrected(_)
+                | CryptoscoreEvent::RewardRolled(_)
+                | CryptoscoreEvent::RewardSwapped(_)
+                | CryptoscoreEvent::ClaimReceiptMinted(_)
+                | CryptoscoreEvent::FeesDistributed(_)
+                | CryptoscoreEvent::UnclaimedSwept(_)
+                | CryptoscoreEvent::MarketCancelled(_)
+                | CryptoscoreEvent::RefundClaimed(_)
+                | CryptoscoreEvent::MarketClosed(_)
+                | CryptoscoreEvent::SettlementReportCompiled(_)
+                | CryptoscoreEvent::SolvencyViolation(_)
+                | CryptoscoreEvent::PlatformFeeCollected(_)
+                | CryptoscoreEvent::TreasuryWithdrawn(_)
+                | CryptoscoreEvent::PoolStaked(_)
+                | CryptoscoreEvent::PoolUnwound(_)
+                | CryptoscoreEvent::ResolutionSigned(_)
+                | CryptoscoreEvent::ResolutionCoSigned(_)
+                | CryptoscoreEvent::InsuranceFunded(_)
+                | CryptoscoreEvent::MisresolutionRuled(_)
+                | CryptoscoreEvent::InsuranceClaimPaid(_)
+                | CryptoscoreEvent::JoinQueued(_)
+                | CryptoscoreEvent::QueuedJoinRefunded(_)
+                | CryptoscoreEvent::CopyJoined(_)
+                | CryptoscoreEvent::PredictionCommitted(_)
+                | CryptoscoreEvent::CoAdminsUpdated(_)
+                | CryptoscoreEvent::WinnersRootPublished(_)
+                | CryptoscoreEvent::CharityPledged(_)
+                | CryptoscoreEvent::CharityDonated(_)
+                | CryptoscoreEvent::InPlayEnabled(_)
+                | CryptoscoreEvent::PropPlayersListed(_)
+                | CryptoscoreEvent::PropPicked(_)
+                | CryptoscoreEvent::PropResolved(_)
+                | CryptoscoreEvent::OutrightCheckpointed(_)
+                | CryptoscoreEvent::CorrectScoreEnabled(_)
+                | CryptoscoreEvent::ScorePicked(_)
+                | CryptoscoreEvent::CorrectScoreResolved(_)
+                | CryptoscoreEvent::HandicapSet(_)
+                | CryptoscoreEvent::HandicapResolved(_)
+                | CryptoscoreEvent::ResultsPublished(_)
+                | CryptoscoreEvent::LiveScoreUpdated(_)
+                | CryptoscoreEvent::StreakRebatePaid(_)
+                | CryptoscoreEvent::FeeDiscountPaid(_)
+                | CryptoscoreEvent::KeeperTipPaid(_)
+                | CryptoscoreEvent::VoucherRedeemed(_)
+                | CryptoscoreEvent::PlatformParamSet(_)
+                | CryptoscoreEvent::MarketRegistryInvalidated(_)
+                | CryptoscoreEvent::ProposalCreated(_)
+                | CryptoscoreEvent::VoteCast(_)
+                | CryptoscoreEvent::ProposalExecuted(_)
+                | CryptoscoreEvent::DisputeOpened(_)
+                | CryptoscoreEvent::JuryDrawn(_)
+                | CryptoscoreEvent::JurorVoted(_)
+                | CryptoscoreEvent::DisputeSettled(_) => 0,
+            };
+        }
+
+        db.execute(
+            "INSERT INTO cursor (id, slot) VALUES (0, ?1)
+             ON CONFLICT (id) DO UPDATE SET slot = MAX(slot, excluded.slot)",
+            params![slot],
+        )?;
+        db.commit()?;
+
+        Ok(inserted)
+    }
+
+    /// Upsert an account into its view table, ignoring updates older than the stored row
+    pub fn apply(&mut self, update: &AccountUpdate) -> Result<()> {
+        let address = update.address.to_string();
+        let slot = update.slot;
+
+        match &update.account {
+            Some(TrackedAccount::Market(market)) => {
+                let reward = market.outcome.as_ref().and_then(|outcome| {
+                    let winners = match outcome {
+                        MatchOutcome::Home => market.home_count,
+                        MatchOutcome::Draw => market.draw_count,
+                        MatchOutcome::Away => market.away_count,
+                    };
+                    settled_reward_per_winner(market.total_pool, market.fees_paid, winners)
+                });
+                self.conn.execute(
+                    "INSERT INTO market_accounts
+                     (address, slot, creator, match_id, entry_fee, kickoff_time, end_time, status, outcome,
+                      total_pool, participant_count, is_public, reward_per_winner, claim_deadline)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+                     ON CONFLICT (address) DO UPDATE SET
+                        slot = excluded.slot, status = excluded.status, outcome = excluded.outcome,
+                        total_pool = excluded.total_pool, participant_count = excluded.participant_count,
+                        reward_per_winner = excluded.reward_per_winner
+                     WHERE excluded.slot >= market_accounts.slot",
+                    params![
+                        address,
+                        slot,
+                        market.creator.to_string(),
+                        market.match_id,
+                        market.entry_fee,
+                        market.kickoff_time,
+                        market.end_time,
+                        format!("{:?}", market.status),
+                        market.outcome.as_ref().map(|outcome| format!("{:?}", outcome)),
+                        market.total_pool,
+                        market.participant_count,
+                        market.is_public,
+                        reward,
+                        market.end_time.saturating_add(CLAIM_WINDOW_SECONDS)
+                    ],
+                )?;
+            }
+            Some(TrackedAccount::Participant(participant)) => {
+                self.conn.execute(
+                    "INSERT INTO participant_accounts
+                     (address, slot, market, user, prediction, joined_at, has_withdrawn)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                     ON CONFLICT (address) DO UPDATE SET
+                        slot = excluded.slot, has_withdrawn = excluded.has_withdrawn
+                     WHERE excluded.slot >= participant_accounts.slot",
+                    params![
+                        address,
+                        slot,
+                        participant.market.to_string(),
+                        participant.user.to_string(),
+                        format!("{:?}", participant.prediction),
+                        participant.joined_at,
+                        participant.has_withdrawn
+                    ],
+                )?;
+            }
+            Some(TrackedAccount::UserStats(stats)) => {
+                self.conn.execute(
+                    "INSERT INTO user_stats_accounts
+                     (address, slot, user, total_markets, wins, losses, total_wagered, total_won, net_profit,
+                      current_streak, best_streak, xp, level)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+                     ON CONFLICT (address) DO UPDATE SET
+                        slot = excluded.slot, total_markets = excluded.total_markets, wins = excluded.wins,
+                        losses = excluded.losses, total_wagered = excluded.total_wagered,
+                        total_won = excluded.total_won, net_profit = excluded.net_profit,
+                        current_streak = excluded.current_streak, best_streak = excluded.best_streak,
+                        xp = excluded.xp, level = excluded.level
+                     WHERE excluded.slot >= user_stats_accounts.slot",
+                    params![
+                        address,
+                        slot,
+                        stats.user.to_string(),
+                        stats.total_markets,
+                        stats.wins,
+                        stats.losses,
+                        stats.total_wagered,
+                        stats.total_won,
+                        stats.net_profit,
+                        stats.current_streak,
+                        stats.best_streak,
+                        stats.xp,
+                        stats.level
+                    ],
+                )?;
+            }
+            None => {
+                for table in ["market_accounts", "participant_accounts", "user_stats_accounts"] {
+                    self.conn.execute(
+                        &format!("DELETE FROM {} WHERE address = ?1 AND slot <= ?2", table),
+                        params![address, slot],
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Every indexed `(user, prediction)` in `market`
+    pub fn market_predictions(&self, market: &str) -> Result<Vec<(String, String)>> {
+        let mut statement = self
+            .conn
+            .prepare_cached("SELECT DISTINCT user, prediction FROM predictions WHERE market = ?1")?;
+        let rows = statement.query_map(params![market], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+
+    /// Number of distinct users with an indexed prediction in `market`
+    pub fn prediction_count(&self, market: &str) -> Result<u32> {
+        self.conn.query_row(
+            "SELECT COUNT(DISTINCT user) FROM predictions WHERE market = ?1",
+            params![market],
+            |row| row.get(0),
+        )
+    }
+
+    /// Creator of `market` from its creation event, or its account if created before indexing
+    pub fn market_creator(&self, market: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT creator FROM markets_created WHERE market = ?1
+                 UNION ALL SELECT creator FROM market_accounts WHERE address = ?1
+                 LIMIT 1",
+                params![market],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    pub fn is_notified(&self, key: &str) -> Result<bool> {
+        self.conn
+            .query_row("SELECT 1 FROM notifications_sent WHERE key = ?1", params![key], |_| Ok(()))
+            .optional()
+            .map(|row| row.is_some())
+    }
+
+    pub fn mark_notified(&self, key: &str) -> Result<()> {
+        self.conn
+            .execute("INSERT OR IGNORE INTO notifications_sent (key) VALUES (?1)", params![key])
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,2615 @@
+//! Account lists for each market instruction, in program order.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::solana_program::{stake, sysvar};
+use anchor_lang::system_program;
+use cryptoscore_common::pda::{
+    find_charity_pledge_address, find_claim_receipt_address, find_commit_reveal_address, find_commitment_address,
+    find_creator_earnings_address, find_creator_stats_address, find_discount_policy_address, find_factory_address,
+    find_fee_receipt_address, find_first_correct_bonus_address, find_follow_address, find_handicap_address,
+    find_in_play_lock_address, find_insurance_fund_address, find_keeper_tip_policy_address, find_keeper_tips_address,
+    find_live_score_address, find_market_address, find_market_chain_address, find_match_id_policy_address,
+    find_misresolution_ruling_address, find_odds_history_address, find_outright_standings_address,
+    find_participant_address, find_participant_roster_address, find_payout_swap_policy_address,
+    find_pending_claims_address, find_platform_activity_address, find_platform_stats_address,
+    find_points_ledger_address, find_points_totals_address, find_prop_entry_address, find_prop_registry_address,
+    find_queued_join_address, find_rake_free_market_address, find_rebate_policy_address, find_rebate_tracker_address,
+    find_receipt_authority_address, find_receipt_policy_address, find_resolution_grace_policy_address,
+    find_resolution_policy_address, find_resolution_votes_address, find_results_batch_address,
+    find_results_signer_address, find_rolled_entry_address, find_roster_page_address, find_score_entry_address,
+    find_score_grid_address, find_settlement_report_address, find_token_gate_address, find_treasury_address,
+    find_user_stats_address, find_voucher_address, find_winners_root_address, find_wormhole_emitter_address,
+    find_yield_escrow_address, find_yield_position_address, find_yield_tokens_address,
+};
+use cryptoscore_common::receipt::{
+    find_tree_config_address, BUBBLEGUM_PROGRAM_ID, COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID,
+};
+use cryptoscore_common::stake_pool::{StakePoolAccounts, STAKE_POOL_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use cryptoscore_common::swap::{JUPITER_PROGRAM_ID, NATIVE_MINT};
+use cryptoscore_common::token_gate::find_associated_token_address;
+use cryptoscore_common::{DASHBOARD_PROGRAM_ID, FACTORY_PROGRAM_ID, ROSTER_PAGE_SIZE};
+
+pub struct InitializeMarket {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub creator: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub creator_stats: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub resolution_grace_policy: Pubkey,
+    pub match_id_policy: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl InitializeMarket {
+    /// Derive every PDA for a new market on `factory`
+    pub fn new(factory: Pubkey, creator: Pubkey, match_id: &str) -> Self {
+        Self {
+            market: find_market_address(&factory, match_id).0,
+            factory,
+            creator,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            creator_stats: find_creator_stats_address(&creator).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&creator).0,
+            points_totals: find_points_totals_address().0,
+            resolution_grace_policy: find_resolution_grace_policy_address(&factory).0,
+            match_id_policy: find_match_id_policy_address(&factory).0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for InitializeMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.creator, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new(self.creator_stats, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.resolution_grace_policy, false),
+            AccountMeta::new_readonly(self.match_id_policy, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct JoinMarket {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub token_gate: Pubkey,
+    pub in_play_lock: Pubkey,
+    /// The match's live score on in-play markets, checked on joins after
+    /// kickoff; any account, by default the program ID, otherwise
+    pub live_score: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub rebate_tracker: Pubkey,
+    pub factory: Pubkey,
+    pub rebate_policy: Pubkey,
+    pub treasury: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub discount_policy: Pubkey,
+    pub user_stats: Pubkey,
+    pub creator_stats: Pubkey,
+    pub factory_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    /// Campaign of a voucher covering the entry fee; `None` when the user pays it
+    pub voucher_campaign: Option<Pubkey>,
+    /// The user's voucher from `voucher_campaign`; `None` when the user pays the entry fee
+    pub voucher: Option<Pubkey>,
+    pub roster: RosterSlot,
+    /// On token-gated markets, the user's token account followed for
+    /// collection gates by the NFT's metadata, passed as remaining accounts
+    pub gate_proof: Vec<Pubkey>,
+}
+
+impl JoinMarket {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self::on_factory(find_factory_address().0, market, user)
+    }
+
+    /// Join a market created under another factory, e.g. an operator's platform factory
+    pub fn on_factory(factory: Pubkey, market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            token_gate: find_token_gate_address(&market).0,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            live_score: crate::ID,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            rebate_tracker: find_rebate_tracker_address(&user).0,
+            factory,
+            rebate_policy: find_rebate_policy_address(&factory).0,
+            treasury: find_treasury_address(&factory).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            discount_policy: find_discount_policy_address(&factory).0,
+            user_stats: find_user_stats_address(&user).0,
+            creator_stats: find_creator_stats_address(&user).0,
+            factory_program: FACTORY_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            voucher_campaign: None,
+            voucher: None,
+            roster: RosterSlot::new(market),
+            gate_proof: vec![],
+        }
+    }
+
+    /// Have the user's voucher from `voucher_campaign` cover the entry fee
+    pub fn with_voucher(self, voucher_campaign: Pubkey) -> Self {
+        Self {
+            voucher_campaign: Some(voucher_campaign),
+            voucher: Some(find_voucher_address(&voucher_campaign, &self.user).0),
+            ..self
+        }
+    }
+}
+
+impl ToAccountMetas for JoinMarket {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.in_play_lock, false),
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.rebate_tracker, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.rebate_policy, false),
+            AccountMeta::new(self.treasury, false),
+            AccountMeta::new_readonly(self.insurance_fund, false),
+            AccountMeta::new(self.discount_policy, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.creator_stats, false),
+            AccountMeta::new_readonly(self.factory_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ];
+        // Anchor reads the program ID in an optional slot as `None`
+        metas.extend([self.voucher_campaign, self.voucher].map(|account| match account {
+            Some(account) => AccountMeta::new(account, false),
+            None => AccountMeta::new_readonly(crate::ID, false),
+        }));
+        metas.extend(self.roster.to_account_metas(is_signer));
+        metas.extend(self.gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+        metas
+    }
+}
+
+pub struct ValidateJoin {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub token_gate: Pubkey,
+    pub in_play_lock: Pubkey,
+    /// The match's live score on in-play markets, as for `JoinMarket`
+    pub live_score: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    /// On token-gated markets, the user's holdings proof as for `JoinMarket`,
+    /// passed as remaining accounts
+    pub gate_proof: Vec<Pubkey>,
+}
+
+impl ValidateJoin {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            token_gate: find_token_gate_address(&market).0,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            live_score: crate::ID,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            gate_proof: vec![],
+        }
+    }
+}
+
+impl ToAccountMetas for ValidateJoin {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.participant, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.in_play_lock, false),
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+        ];
+        metas.extend(self.gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+        metas
+    }
+}
+
+pub struct QueueJoin {
+    pub market: Pubkey,
+    pub queued_join: Pubkey,
+    pub user: Pubkey,
+    pub token_gate: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl QueueJoin {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            queued_join: find_queued_join_address(&market, &user).0,
+            user,
+            token_gate: find_token_gate_address(&market).0,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for QueueJoin {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.queued_join, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct ExecuteQueuedJoin {
+    pub market: Pubkey,
+    pub queued_join: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub cranker: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub token_gate: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub roster: RosterSlot,
+    pub tip: KeeperTip,
+}
+
+impl ExecuteQueuedJoin {
+    /// Accounts for `cranker` executing `user`'s queued join
+    pub fn new(market: Pubkey, user: Pubkey, cranker: Pubkey) -> Self {
+        Self {
+            market,
+            queued_join: find_queued_join_address(&market, &user).0,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            cranker,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            token_gate: find_token_gate_address(&market).0,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            roster: RosterSlot::new(market),
+            tip: KeeperTip::new(market),
+        }
+    }
+}
+
+impl ToAccountMetas for ExecuteQueuedJoin {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.queued_join, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.cranker, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ];
+        metas.extend(self.roster.to_account_metas(is_signer));
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct RefundQueuedJoin {
+    pub market: Pubkey,
+    pub queued_join: Pubkey,
+    pub user: Pubkey,
+    pub signer: Pubkey,
+}
+
+impl RefundQueuedJoin {
+    /// Accounts for `signer` refunding `user`'s queued join
+    pub fn new(market: Pubkey, user: Pubkey, signer: Pubkey) -> Self {
+        Self {
+            market,
+            queued_join: find_queued_join_address(&market, &user).0,
+            user,
+            signer,
+        }
+    }
+}
+
+impl ToAccountMetas for RefundQueuedJoin {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.queued_join, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.signer, true),
+        ]
+    }
+}
+
+pub struct FollowLeader {
+    pub follow: Pubkey,
+    pub leader: Pubkey,
+    pub follower: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl FollowLeader {
+    pub fn new(follower: Pubkey, leader: Pubkey) -> Self {
+        Self {
+            follow: find_follow_address(&follower, &leader).0,
+            leader,
+            follower,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for FollowLeader {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.follow, false),
+            AccountMeta::new_readonly(self.leader, false),
+            AccountMeta::new(self.follower, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct UnfollowLeader {
+    pub follow: Pubkey,
+    pub follower: Pubkey,
+}
+
+impl UnfollowLeader {
+    pub fn new(follower: Pubkey, leader: Pubkey) -> Self {
+        Self {
+            follow: find_follow_address(&follower, &leader).0,
+            follower,
+        }
+    }
+}
+
+impl ToAccountMetas for UnfollowLeader {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.follow, false),
+            AccountMeta::new(self.follower, true),
+        ]
+    }
+}
+
+pub struct CopyJoin {
+    pub market: Pubkey,
+    pub follow: Pubkey,
+    pub leader_participant: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub token_gate: Pubkey,
+    pub in_play_lock: Pubkey,
+    /// The match's live score on in-play markets, as for `JoinMarket`
+    pub live_score: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub roster: RosterSlot,
+    /// On token-gated markets, the user's token account followed for
+    /// collection gates by the NFT's metadata, passed as remaining accounts
+    pub gate_proof: Vec<Pubkey>,
+}
+
+impl CopyJoin {
+    /// Accounts for `user` copying `leader`'s prediction in `market`
+    pub fn new(market: Pubkey, user: Pubkey, leader: Pubkey) -> Self {
+        Self {
+            market,
+            follow: find_follow_address(&user, &leader).0,
+            leader_participant: find_participant_address(&market, &leader).0,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            token_gate: find_token_gate_address(&market).0,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            live_score: crate::ID,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            roster: RosterSlot::new(market),
+            gate_proof: vec![],
+        }
+    }
+}
+
+impl ToAccountMetas for CopyJoin {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.follow, false),
+            AccountMeta::new_readonly(self.leader_participant, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.in_play_lock, false),
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ];
+        metas.extend(self.roster.to_account_metas(is_signer));
+        metas.extend(self.gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+        metas
+    }
+}
+
+pub struct EnableCommitReveal {
+    pub market: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub admin: Pubkey,
+    pub token_gate: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl EnableCommitReveal {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            admin,
+            token_gate: find_token_gate_address(&market).0,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for EnableCommitReveal {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.commit_reveal, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct EnableParticipantRoster {
+    pub market: Pubkey,
+    pub participant_roster: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl EnableParticipantRoster {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            participant_roster: find_participant_roster_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for EnableParticipantRoster {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.participant_roster, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct PledgeToCharity {
+    pub market: Pubkey,
+    pub charity_pledge: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl PledgeToCharity {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            charity_pledge: find_charity_pledge_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for PledgeToCharity {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.charity_pledge, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct OfferFirstCorrectBonus {
+    pub market: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl OfferFirstCorrectBonus {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for OfferFirstCorrectBonus {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetFollowUpMarket {
+    pub market: Pubkey,
+    pub follow_up: Pubkey,
+    pub market_chain: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetFollowUpMarket {
+    pub fn new(market: Pubkey, follow_up: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            follow_up,
+            market_chain: find_market_chain_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetFollowUpMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.follow_up, false),
+            AccountMeta::new(self.market_chain, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct EnableInPlay {
+    pub market: Pubkey,
+    pub in_play_lock: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl EnableInPlay {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for EnableInPlay {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.in_play_lock, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct ListPropPlayers {
+    pub market: Pubkey,
+    pub prop_registry: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub score_grid: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ListPropPlayers {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            prop_registry: find_prop_registry_address(&market).0,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for ListPropPlayers {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.prop_registry, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct JoinProp {
+    pub market: Pubkey,
+    pub prop_registry: Pubkey,
+    pub prop_entry: Pubkey,
+    pub user: Pubkey,
+    pub token_gate: Pubkey,
+    pub in_play_lock: Pubkey,
+    /// The match's live score on in-play markets, as for `JoinMarket`
+    pub live_score: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    /// On token-gated markets, the user's holdings proof as for `JoinMarket`,
+    /// passed as remaining accounts
+    pub gate_proof: Vec<Pubkey>,
+}
+
+impl JoinProp {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            prop_registry: find_prop_registry_address(&market).0,
+            prop_entry: find_prop_entry_address(&market, &user).0,
+            user,
+            token_gate: find_token_gate_address(&market).0,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            live_score: crate::ID,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            gate_proof: vec![],
+        }
+    }
+}
+
+impl ToAccountMetas for JoinProp {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.prop_registry, false),
+            AccountMeta::new(self.prop_entry, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.in_play_lock, false),
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+        metas
+    }
+}
+
+pub struct WithdrawPropRewards {
+    pub market: Pubkey,
+    pub prop_registry: Pubkey,
+    pub prop_entry: Pubkey,
+    pub user: Pubkey,
+}
+
+impl WithdrawPropRewards {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            prop_registry: find_prop_registry_address(&market).0,
+            prop_entry: find_prop_entry_address(&market, &user).0,
+            user,
+        }
+    }
+}
+
+impl ToAccountMetas for WithdrawPropRewards {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new(self.prop_entry, false),
+            AccountMeta::new(self.user, true),
+        ]
+    }
+}
+
+pub struct EnableCorrectScore {
+    pub market: Pubkey,
+    pub score_grid: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub prop_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl EnableCorrectScore {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            score_grid: find_score_grid_address(&market).0,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            prop_registry: find_prop_registry_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for EnableCorrectScore {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.score_grid, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SetHandicap {
+    pub market: Pubkey,
+    pub handicap: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetHandicap {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            handicap: find_handicap_address(&market).0,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetHandicap {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.handicap, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct JoinCorrectScore {
+    pub market: Pubkey,
+    pub score_grid: Pubkey,
+    pub score_entry: Pubkey,
+    pub user: Pubkey,
+    pub token_gate: Pubkey,
+    pub in_play_lock: Pubkey,
+    /// The match's live score on in-play markets, as for `JoinMarket`
+    pub live_score: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    /// On token-gated markets, the user's holdings proof as for `JoinMarket`,
+    /// passed as remaining accounts
+    pub gate_proof: Vec<Pubkey>,
+}
+
+impl JoinCorrectScore {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            score_grid: find_score_grid_address(&market).0,
+            score_entry: find_score_entry_address(&market, &user).0,
+            user,
+            token_gate: find_token_gate_address(&market).0,
+            in_play_lock: find_in_play_lock_address(&market).0,
+            live_score: crate::ID,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            gate_proof: vec![],
+        }
+    }
+}
+
+impl ToAccountMetas for JoinCorrectScore {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.score_grid, false),
+            AccountMeta::new(self.score_entry, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.token_gate, false),
+            AccountMeta::new_readonly(self.in_play_lock, false),
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+        metas
+    }
+}
+
+pub struct WithdrawScoreRewards {
+    pub market: Pubkey,
+    pub score_grid: Pubkey,
+    pub score_entry: Pubkey,
+    pub user: Pubkey,
+}
+
+impl WithdrawScoreRewards {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            score_grid: find_score_grid_address(&market).0,
+            score_entry: find_score_entry_address(&market, &user).0,
+            user,
+        }
+    }
+}
+
+impl ToAccountMetas for WithdrawScoreRewards {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.score_entry, false),
+            AccountMeta::new(self.user, true),
+        ]
+    }
+}
+
+pub struct SetTokenGate {
+    pub market: Pubkey,
+    pub token_gate: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SetTokenGate {
+    pub fn new(market: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            market,
+            token_gate: find_token_gate_address(&market).0,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            admin,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SetTokenGate {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.token_gate, false),
+            AccountMeta::new_readonly(self.commit_reveal, false),
+            AccountMeta::new(self.admin, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CommitPrediction {
+    pub market: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub commitment: Pubkey,
+    pub user: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CommitPrediction {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            commitment: find_commitment_address(&market, &user).0,
+            user,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CommitPrediction {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.commit_reveal, false),
+            AccountMeta::new(self.commitment, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new(self.points_ledger, false),
+            AccountMeta::new(self.points_totals, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct RevealPrediction {
+    pub market: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub commitment: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub roster: RosterSlot,
+}
+
+impl RevealPrediction {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            commit_reveal: find_commit_reveal_address(&market).0,
+            commitment: find_commitment_address(&market, &user).0,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            roster: RosterSlot::new(market),
+        }
+    }
+}
+
+impl ToAccountMetas for RevealPrediction {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.commit_reveal, false),
+            AccountMeta::new(self.commitment, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ];
+        metas.extend(self.roster.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveMarket {
+    pub market: Pubkey,
+    pub resolver: Pubkey,
+    pub creator: Pubkey,
+    pub factory: Pubkey,
+    pub treasury: Pubkey,
+    /// Resolver's participant account; `None` when the creator resolves
+    pub participant: Option<Pubkey>,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub creator_stats: Pubkey,
+    pub creator_earnings: Pubkey,
+    pub fee_receipt: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub resolution_policy: Pubkey,
+    pub factory_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub charity_pledge: Pubkey,
+    /// Charity recorded in the market's charity pledge; `None` when it has none
+    pub charity: Option<Pubkey>,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub handicap: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub rake_free_market: Pubkey,
+}
+
+impl ResolveMarket {
+    /// Accounts for a resolution by the creator of a market on `factory`
+    pub fn by_creator(market: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            market,
+            resolver: creator,
+            creator,
+            factory,
+            treasury: find_treasury_address(&factory).0,
+            participant: None,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            creator_stats: find_creator_stats_address(&creator).0,
+            creator_earnings: find_creator_earnings_address(&creator).0,
+            fee_receipt: find_fee_receipt_address(&market).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            resolution_policy: find_resolution_policy_address(&factory).0,
+            factory_program: FACTORY_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            charity_pledge: find_charity_pledge_address(&market).0,
+            charity: None,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            handicap: find_handicap_address(&market).0,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            rake_free_market: find_rake_free_market_address(&market).0,
+        }
+    }
+
+    /// Accounts for a resolution by one of the market's participants
+    pub fn by_participant(market: Pubkey, resolver: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            resolver,
+            participant: Some(find_participant_address(&market, &resolver).0),
+            ..Self::by_creator(market, creator, factory)
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.resolver, true),
+            AccountMeta::new_readonly(self.creator, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.treasury, false),
+            // Anchor reads the program ID in an optional slot as `None`
+            AccountMeta::new_readonly(self.participant.unwrap_or(crate::ID), false),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new(self.creator_stats, false),
+            AccountMeta::new(self.creator_earnings, false),
+            AccountMeta::new(self.fee_receipt, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.resolution_policy, false),
+            AccountMeta::new_readonly(self.factory_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.charity_pledge, false),
+            match self.charity {
+                Some(charity) => AccountMeta::new(charity, false),
+                None => AccountMeta::new_readonly(crate::ID, false),
+            },
+            AccountMeta::new(self.prop_registry, false),
+            AccountMeta::new(self.score_grid, false),
+            AccountMeta::new_readonly(self.handicap, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+            AccountMeta::new_readonly(self.rake_free_market, false),
+        ]
+    }
+}
+
+pub struct ResolveWithAttestation {
+    /// `resolver` is whoever relays the attestation; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub results_signer: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveWithAttestation {
+    /// Accounts for relaying an attested result for a market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            results_signer: find_results_signer_address(&factory).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveWithAttestation {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.push(AccountMeta::new_readonly(self.results_signer, false));
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveProp {
+    /// `resolver` must be the factory's results signer; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub results_signer: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveProp {
+    /// Accounts for the results signer `signer` resolving a prop market on `factory`
+    pub fn new(market: Pubkey, signer: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: signer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            results_signer: find_results_signer_address(&factory).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveProp {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.push(AccountMeta::new_readonly(self.results_signer, false));
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct RecordOutrightCheckpoint {
+    pub market: Pubkey,
+    pub prop_registry: Pubkey,
+    pub outright_standings: Pubkey,
+    pub results_signer: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl RecordOutrightCheckpoint {
+    /// Accounts for the results signer `signer` checkpointing an outright market on `factory`
+    pub fn new(market: Pubkey, signer: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            market,
+            prop_registry: find_prop_registry_address(&market).0,
+            outright_standings: find_outright_standings_address(&market).0,
+            results_signer: find_results_signer_address(&factory).0,
+            signer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for RecordOutrightCheckpoint {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new(self.outright_standings, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new(self.signer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SettleOutrightEarly {
+    /// `resolver` is whoever relays the settlement; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub outright_standings: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl SettleOutrightEarly {
+    /// Accounts for relaying the settlement of a decided outright market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            outright_standings: find_outright_standings_address(&market).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for SettleOutrightEarly {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.push(AccountMeta::new_readonly(self.outright_standings, false));
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveWithVaa {
+    /// `resolver` is whoever relays the VAA; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub wormhole_emitter: Pubkey,
+    pub posted_vaa: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveWithVaa {
+    /// Accounts for relaying the result in `posted_vaa` to a market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey, posted_vaa: Pubkey) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            wormhole_emitter: find_wormhole_emitter_address(&factory).0,
+            posted_vaa,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveWithVaa {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.extend([
+            AccountMeta::new_readonly(self.wormhole_emitter, false),
+            AccountMeta::new_readonly(self.posted_vaa, false),
+        ]);
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveWithLiveScore {
+    /// `resolver` is whoever relays the score; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub live_score: Pubkey,
+    pub results_signer: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveWithLiveScore {
+    /// Accounts for relaying the final live score of `match_id` to its market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey, match_id: &str) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            live_score: find_live_score_address(&factory, match_id).0,
+            results_signer: find_results_signer_address(&factory).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveWithLiveScore {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.extend([
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+        ]);
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveCorrectScore {
+    /// `resolver` is whoever relays the score; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub live_score: Pubkey,
+    pub results_signer: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveCorrectScore {
+    /// Accounts for relaying the final live score of `match_id` to its correct-score market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey, match_id: &str) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            live_score: find_live_score_address(&factory, match_id).0,
+            results_signer: find_results_signer_address(&factory).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveCorrectScore {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.extend([
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+        ]);
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveHandicap {
+    /// `resolver` is whoever relays the score; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub live_score: Pubkey,
+    pub results_signer: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl ResolveHandicap {
+    /// Accounts for relaying the final live score of `match_id` to its handicap market on `factory`
+    pub fn new(market: Pubkey, relayer: Pubkey, creator: Pubkey, factory: Pubkey, match_id: &str) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: relayer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            live_score: find_live_score_address(&factory, match_id).0,
+            results_signer: find_results_signer_address(&factory).0,
+            tip: KeeperTip::on_factory(factory, market),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveHandicap {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.extend([
+            AccountMeta::new_readonly(self.live_score, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+        ]);
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct ResolveMarketsBatch {
+    pub results_batch: Pubkey,
+    pub results_signer: Pubkey,
+    /// Resolution accounts of each market, passed as remaining accounts with
+    /// the relayer as `resolver` and no `participant`
+    pub markets: Vec<ResolveMarket>,
+}
+
+impl ResolveMarketsBatch {
+    /// Accounts for relaying batch `batch_id` on `factory` to each
+    /// `(market, creator)` pair
+    pub fn new(
+        factory: Pubkey,
+        batch_id: u64,
+        relayer: Pubkey,
+        markets: impl IntoIterator<Item = (Pubkey, Pubkey)>,
+    ) -> Self {
+        Self {
+            results_batch: find_results_batch_address(&factory, batch_id).0,
+            results_signer: find_results_signer_address(&factory).0,
+            markets: markets
+                .into_iter()
+                .map(|(market, creator)| ResolveMarket {
+                    resolver: relayer,
+                    ..ResolveMarket::by_creator(market, creator, factory)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ToAccountMetas for ResolveMarketsBatch {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.results_batch, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+        ];
+        for market in &self.markets {
+            metas.extend(market.to_account_metas(is_signer));
+        }
+        metas
+    }
+}
+
+pub struct SignResolution {
+    pub market: Pubkey,
+    pub results_signer: Pubkey,
+    pub resolution_policy: Pubkey,
+    pub resolution_votes: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl SignResolution {
+    /// Accounts for `signer` signing off on a market on `factory`
+    pub fn new(market: Pubkey, signer: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            market,
+            results_signer: find_results_signer_address(&factory).0,
+            resolution_policy: find_resolution_policy_address(&factory).0,
+            resolution_votes: find_resolution_votes_address(&market).0,
+            signer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for SignResolution {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new_readonly(self.resolution_policy, false),
+            AccountMeta::new(self.resolution_votes, false),
+            AccountMeta::new(self.signer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CoSignResolution {
+    /// `resolver` is the co-signer; `participant` must be `None`
+    pub resolution: ResolveMarket,
+    pub results_signer: Pubkey,
+    pub resolution_votes: Pubkey,
+}
+
+impl CoSignResolution {
+    /// Accounts for `co_signer` settling a market on `factory`
+    pub fn new(market: Pubkey, co_signer: Pubkey, creator: Pubkey, factory: Pubkey) -> Self {
+        Self {
+            resolution: ResolveMarket {
+                resolver: co_signer,
+                ..ResolveMarket::by_creator(market, creator, factory)
+            },
+            results_signer: find_results_signer_address(&factory).0,
+            resolution_votes: find_resolution_votes_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for CoSignResolution {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = self.resolution.to_account_metas(is_signer);
+        metas.extend([
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new_readonly(self.resolution_votes, false),
+        ]);
+        metas
+    }
+}
+
+pub struct CorrectResult {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub results_signer: Pubkey,
+    pub signer: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub handicap: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub winners_root: Pubkey,
+    pub misresolution_ruling: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub factory_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CorrectResult {
+    /// Accounts for the results signer `signer` correcting the result of a resolved market on `factory`
+    pub fn new(market: Pubkey, factory: Pubkey, signer: Pubkey) -> Self {
+        Self {
+            market,
+            factory,
+            results_signer: find_results_signer_address(&factory).0,
+            signer,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            handicap: find_handicap_address(&market).0,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            winners_root: find_winners_root_address(&market).0,
+            misresolution_ruling: find_misresolution_ruling_address(&market).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            factory_program: FACTORY_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CorrectResult {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.results_signer, false),
+            AccountMeta::new(self.signer, true),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new_readonly(self.handicap, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+            AccountMeta::new(self.winners_root, false),
+            AccountMeta::new(self.misresolution_ruling, false),
+            AccountMeta::new(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.factory_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SettleRebateEntry {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub rebate_tracker: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+    pub keeper: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl SettleRebateEntry {
+    /// Accounts for `keeper` settling `user`'s entry in `market`
+    pub fn new(market: Pubkey, user: Pubkey, keeper: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            rebate_tracker: find_rebate_tracker_address(&user).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            keeper,
+            tip: KeeperTip::new(market),
+        }
+    }
+}
+
+impl ToAccountMetas for SettleRebateEntry {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.participant, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new(self.rebate_tracker, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new(self.keeper, true),
+        ];
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct RecordPendingClaim {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub pending_claims: Pubkey,
+    pub cranker: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub tip: KeeperTip,
+}
+
+impl RecordPendingClaim {
+    pub fn new(market: Pubkey, user: Pubkey, cranker: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            pending_claims: find_pending_claims_address(&user).0,
+            cranker,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            tip: KeeperTip::new(market),
+        }
+    }
+}
+
+impl ToAccountMetas for RecordPendingClaim {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.participant, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new(self.pending_claims, false),
+            AccountMeta::new(self.cranker, true),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct BuildWinnersRoot {
+    pub market: Pubkey,
+    pub participant_roster: Pubkey,
+    pub roster_page: Pubkey,
+    pub winners_root: Pubkey,
+    pub cranker: Pubkey,
+    pub system_program: Pubkey,
+    pub tip: KeeperTip,
+    /// Participant accounts of the roster entries being folded in, passed as
+    /// remaining accounts
+    pub participants: Vec<Pubkey>,
+}
+
+impl BuildWinnersRoot {
+    /// Accounts folding in `users`, the roster entries from `cursor` on, all
+    /// on one roster page
+    pub fn new(market: Pubkey, cranker: Pubkey, cursor: u32, users: &[Pubkey]) -> Self {
+        Self {
+            market,
+            participant_roster: find_participant_roster_address(&market).0,
+            roster_page: find_roster_page_address(&market, cursor / ROSTER_PAGE_SIZE as u32).0,
+            winners_root: find_winners_root_address(&market).0,
+            cranker,
+            system_program: system_program::ID,
+            tip: KeeperTip::new(market),
+            participants: users.iter().map(|user| find_participant_address(&market, user).0).collect(),
+        }
+    }
+}
+
+impl ToAccountMetas for BuildWinnersRoot {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.participant_roster, false),
+            AccountMeta::new_readonly(self.roster_page, false),
+            AccountMeta::new(self.winners_root, false),
+            AccountMeta::new(self.cranker, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.tip.to_account_metas(is_signer));
+        metas.extend(self.participants.iter().map(|participant| AccountMeta::new_readonly(*participant, false)));
+        metas
+    }
+}
+
+/// Participant roster accounts following each join's account list
+pub struct RosterSlot {
+    pub participant_roster: Pubkey,
+    pub roster_page: Pubkey,
+}
+
+impl RosterSlot {
+    /// The roster's first page; joins past it need `at_page` with the page
+    /// the roster's count falls on
+    pub fn new(market: Pubkey) -> Self {
+        Self::at_page(market, 0)
+    }
+
+    pub fn at_page(market: Pubkey, page: u32) -> Self {
+        Self {
+            participant_roster: find_participant_roster_address(&market).0,
+            roster_page: find_roster_page_address(&market, page).0,
+        }
+    }
+}
+
+impl ToAccountMetas for RosterSlot {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.participant_roster, false),
+            AccountMeta::new(self.roster_page, false),
+        ]
+    }
+}
+
+/// Keeper tip accounts closing each permissionless crank's account list
+pub struct KeeperTip {
+    pub factory: Pubkey,
+    pub keeper_tip_policy: Pubkey,
+    pub keeper_tips: Pubkey,
+    pub treasury: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub factory_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl KeeperTip {
+    pub fn new(market: Pubkey) -> Self {
+        Self::on_factory(find_factory_address().0, market)
+    }
+
+    /// Tip from the treasury of the factory `market` was created under
+    pub fn on_factory(factory: Pubkey, market: Pubkey) -> Self {
+        Self {
+            factory,
+            keeper_tip_policy: find_keeper_tip_policy_address(&factory).0,
+            keeper_tips: find_keeper_tips_address(&market).0,
+            treasury: find_treasury_address(&factory).0,
+            insurance_fund: find_insurance_fund_address(&factory).0,
+            factory_program: FACTORY_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for KeeperTip {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.keeper_tip_policy, false),
+            AccountMeta::new(self.keeper_tips, false),
+            AccountMeta::new(self.treasury, false),
+            AccountMeta::new_readonly(self.insurance_fund, false),
+            AccountMeta::new_readonly(self.factory_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct WithdrawRewards {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub pending_claims: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+}
+
+impl WithdrawRewards {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            pending_claims: find_pending_claims_address(&user).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for WithdrawRewards {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new(self.pending_claims, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ]
+    }
+}
+
+pub struct RollRewards {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub pending_claims: Pubkey,
+    pub market_chain: Pubkey,
+    pub previous_rolled_entry: Pubkey,
+    pub follow_up: Pubkey,
+    pub entry: Pubkey,
+    pub rolled_entry: Pubkey,
+    pub commit_reveal: Pubkey,
+    pub token_gate: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub follow_up_first_correct_bonus: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub points_ledger: Pubkey,
+    pub points_totals: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub roster: RosterSlot,
+}
+
+impl RollRewards {
+    /// Accounts for `user` rolling their reward from `market` into its follow-up market `follow_up`
+    pub fn new(market: Pubkey, follow_up: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            pending_claims: find_pending_claims_address(&user).0,
+            market_chain: find_market_chain_address(&market).0,
+            previous_rolled_entry: find_rolled_entry_address(&market, &user).0,
+            follow_up,
+            entry: find_participant_address(&follow_up, &user).0,
+            rolled_entry: find_rolled_entry_address(&follow_up, &user).0,
+            commit_reveal: find_commit_reveal_address(&follow_up).0,
+            token_gate: find_token_gate_address(&follow_up).0,
+            prop_registry: find_prop_registry_address(&follow_up).0,
+            score_grid: find_score_grid_address(&follow_up).0,
+            follow_up_first_correct_bonus: find_first_correct_bonus_address(&follow_up).0,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&find_factory_address().0).0,
+            points_ledger: find_points_ledger_address(&user).0,
+            points_totals: find_points_totals_address().0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            roster: RosterSlot::new(follow_up),
+        }
+    }
+}
+
+impl ToAccountMetas for RollRewards {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+                AccountMeta::new(self.market, false),
+                AccountMeta::new(self.participant, false),
+                AccountMeta::new(self.user, true),
+                AccountMeta::new(self.first_correct_bonus, false),
+                AccountMeta::new(self.pending_claims, false),
+                AccountMeta::new(self.market_chain, false),
+                AccountMeta::new_readonly(self.previous_rolled_entry, false),
+                AccountMeta::new(self.follow_up, false),
+                AccountMeta::new(self.entry, false),
+                AccountMeta::new(self.rolled_entry, false),
+                AccountMeta::new_readonly(self.commit_reveal, false),
+                AccountMeta::new_readonly(self.token_gate, false),
+                AccountMeta::new_readonly(self.prop_registry, false),
+                AccountMeta::new_readonly(self.score_grid, false),
+                AccountMeta::new(self.follow_up_first_correct_bonus, false),
+                AccountMeta::new(self.platform_stats, false),
+                AccountMeta::new(self.platform_activity, false),
+                AccountMeta::new(self.points_ledger, false),
+                AccountMeta::new(self.points_totals, false),
+                AccountMeta::new_readonly(self.dashboard_program, false),
+                AccountMeta::new_readonly(self.instructions, false),
+                AccountMeta::new_readonly(self.system_program, false),
+        ];
+        metas.extend(self.roster.to_account_metas(is_signer));
+        metas
+    }
+}
+
+pub struct WithdrawRewardsSwapped {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub payout_swap_policy: Pubkey,
+    pub wrapped_sol: Pubkey,
+    pub output_tokens: Pubkey,
+    pub pending_claims: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub jupiter_program: Pubkey,
+    pub token_program: Pubkey,
+    pub instructions: Pubkey,
+    pub system_program: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    /// Accounts of the Jupiter route, passed as remaining accounts
+    pub route: Vec<AccountMeta>,
+}
+
+impl WithdrawRewardsSwapped {
+    /// Accounts for swapping `user`'s reward on `market`, created by
+    /// `factory`, into `output_mint` through their associated token accounts
+    pub fn new(market: Pubkey, factory: Pubkey, user: Pubkey, output_mint: &Pubkey, route: Vec<AccountMeta>) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            payout_swap_policy: find_payout_swap_policy_address(&factory).0,
+            wrapped_sol: find_associated_token_address(&user, &NATIVE_MINT).0,
+            output_tokens: find_associated_token_address(&user, output_mint).0,
+            pending_claims: find_pending_claims_address(&user).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            jupiter_program: JUPITER_PROGRAM_ID,
+            token_program: TOKEN_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            system_program: system_program::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            route,
+        }
+    }
+}
+
+impl ToAccountMetas for WithdrawRewardsSwapped {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.payout_swap_policy, false),
+            AccountMeta::new(self.wrapped_sol, false),
+            AccountMeta::new(self.output_tokens, false),
+            AccountMeta::new(self.pending_claims, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.jupiter_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ];
+        metas.extend(self.route.iter().cloned());
+        metas
+    }
+}
+
+pub struct MintClaimReceipt {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub user: Pubkey,
+    pub receipt_policy: Pubkey,
+    pub claim_receipt: Pubkey,
+    pub receipt_authority: Pubkey,
+    pub tree_config: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub bubblegum_program: Pubkey,
+    pub log_wrapper: Pubkey,
+    pub compression_program: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl MintClaimReceipt {
+    /// Accounts for minting `user`'s receipt of their claim on `market`,
+    /// created by `factory`, into the factory's `merkle_tree`
+    pub fn new(market: Pubkey, factory: Pubkey, user: Pubkey, merkle_tree: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            user,
+            receipt_policy: find_receipt_policy_address(&factory).0,
+            claim_receipt: find_claim_receipt_address(&market, &user).0,
+            receipt_authority: find_receipt_authority_address().0,
+            tree_config: find_tree_config_address(&merkle_tree).0,
+            merkle_tree,
+            bubblegum_program: BUBBLEGUM_PROGRAM_ID,
+            log_wrapper: NOOP_PROGRAM_ID,
+            compression_program: COMPRESSION_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for MintClaimReceipt {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.participant, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.receipt_policy, false),
+            AccountMeta::new(self.claim_receipt, false),
+            AccountMeta::new_readonly(self.receipt_authority, false),
+            AccountMeta::new(self.tree_config, false),
+            AccountMeta::new(self.merkle_tree, false),
+            AccountMeta::new_readonly(self.bubblegum_program, false),
+            AccountMeta::new_readonly(self.log_wrapper, false),
+            AccountMeta::new_readonly(self.compression_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct SweepUnclaimed {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+    pub platform: Pubkey,
+    pub first_correct_bonus: Pubkey,
+}
+
+impl SweepUnclaimed {
+    /// Sweep `market` created under `factory` to `platform`
+    pub fn new(market: Pubkey, factory: Pubkey, authority: Pubkey, platform: Pubkey) -> Self {
+        Self {
+            market,
+            factory,
+            authority,
+            platform,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for SweepUnclaimed {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.platform, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ]
+    }
+}
+
+pub struct SetCoAdmins {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+}
+
+impl ToAccountMetas for SetCoAdmins {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.creator, true),
+        ]
+    }
+}
+
+pub struct CancelMarket {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub canceller: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+}
+
+impl CancelMarket {
+    /// Cancel `market` created under `factory`
+    pub fn new(market: Pubkey, factory: Pubkey, canceller: Pubkey) -> Self {
+        Self {
+            market,
+            factory,
+            canceller,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CancelMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.canceller, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+        ]
+    }
+}
+
+pub struct ClaimRefund {
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub yield_position: Pubkey,
+    pub user: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ClaimRefund {
+    pub fn new(market: Pubkey, user: Pubkey) -> Self {
+        Self {
+            market,
+            participant: find_participant_address(&market, &user).0,
+            yield_position: find_yield_position_address(&market).0,
+            user,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for ClaimRefund {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new_readonly(self.yield_position, false),
+            AccountMeta::new(self.user, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct CloseMarket {
+    pub market: Pubkey,
+    pub yield_position: Pubkey,
+}
+
+impl CloseMarket {
+    pub fn new(market: Pubkey) -> Self {
+        Self {
+            market,
+            yield_position: find_yield_position_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for CloseMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.yield_position, false),
+        ]
+    }
+}
+
+pub struct CompileSettlementReport {
+    pub market: Pubkey,
+    pub prop_registry: Pubkey,
+    pub score_grid: Pubkey,
+    pub settlement_report: Pubkey,
+    pub cranker: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl CompileSettlementReport {
+    pub fn new(market: Pubkey, cranker: Pubkey) -> Self {
+        Self {
+            market,
+            prop_registry: find_prop_registry_address(&market).0,
+            score_grid: find_score_grid_address(&market).0,
+            settlement_report: find_settlement_report_address(&market).0,
+            cranker,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for CompileSettlementReport {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.prop_registry, false),
+            AccountMeta::new_readonly(self.score_grid, false),
+            AccountMeta::new(self.settlement_report, false),
+            AccountMeta::new(self.cranker, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct EmergencyVoid {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+    pub platform_stats: Pubkey,
+    pub platform_activity: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub instructions: Pubkey,
+    pub first_correct_bonus: Pubkey,
+}
+
+impl EmergencyVoid {
+    /// Void `market` created under `factory`
+    pub fn new(market: Pubkey, factory: Pubkey, authority: Pubkey) -> Self {
+        Self {
+            market,
+            factory,
+            authority,
+            platform_stats: find_platform_stats_address().0,
+            platform_activity: find_platform_activity_address(&factory).0,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            instructions: instructions_sysvar::ID,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for EmergencyVoid {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.platform_stats, false),
+            AccountMeta::new(self.platform_activity, false),
+            AccountMeta::new_readonly(self.dashboard_program, false),
+            AccountMeta::new_readonly(self.instructions, false),
+            AccountMeta::new(self.first_correct_bonus, false),
+        ]
+    }
+}
+
+pub struct AssertSolvency {
+    pub market: Pubkey,
+}
+
+impl ToAccountMetas for AssertSolvency {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![AccountMeta::new_readonly(self.market, false)]
+    }
+}
+
+pub struct PreviewSettlement {
+    pub market: Pubkey,
+    pub charity_pledge: Pubkey,
+    pub first_correct_bonus: Pubkey,
+    pub rake_free_market: Pubkey,
+}
+
+impl PreviewSettlement {
+    pub fn new(market: Pubkey) -> Self {
+        Self {
+            market,
+            charity_pledge: find_charity_pledge_address(&market).0,
+            first_correct_bonus: find_first_correct_bonus_address(&market).0,
+            rake_free_market: find_rake_free_market_address(&market).0,
+        }
+    }
+}
+
+impl ToAccountMetas for PreviewSettlement {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new_readonly(self.charity_pledge, false),
+            AccountMeta::new_readonly(self.first_correct_bonus, false),
+            AccountMeta::new_readonly(self.rake_free_market, false),
+        ]
+    }
+}
+
+pub struct MigrateMarket {
+    pub market: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl MigrateMarket {
+    pub fn new(market: Pubkey, payer: Pubkey) -> Self {
+        Self {
+            market,
+            payer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for MigrateMarket {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct MigrateParticipant {
+    pub participant: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl MigrateParticipant {
+    pub fn new(participant: Pubkey, payer: Pubkey) -> Self {
+        Self {
+            participant,
+            payer,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for MigrateParticipant {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.participant, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct StakeIdlePool {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub yield_position: Pubkey,
+    pub yield_escrow: Pubkey,
+    pub pool_tokens: Pubkey,
+    pub stake_pool: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub pool_mint: Pubkey,
+    pub authority: Pubkey,
+    pub stake_pool_program: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl StakeIdlePool {
+    /// Accounts for staking `market`'s pool in `pool`, read with `StakePoolAccounts::parse`
+    pub fn new(market: Pubkey, factory: Pubkey, authority: Pubkey, pool: &StakePoolAccounts) -> Self {
+        Self {
+            market,
+            factory,
+            yield_position: find_yield_position_address(&market).0,
+            yield_escrow: find_yield_escrow_address(&market).0,
+            pool_tokens: find_yield_tokens_address(&market).0,
+            stake_pool: pool.stake_pool,
+            withdraw_authority: pool.withdraw_authority,
+            reserve_stake: pool.reserve_stake,
+            manager_fee_account: pool.manager_fee_account,
+            pool_mint: pool.pool_mint,
+            authority,
+            stake_pool_program: STAKE_POOL_PROGRAM_ID,
+            token_program: TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for StakeIdlePool {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.yield_position, false),
+            AccountMeta::new(self.yield_escrow, false),
+            AccountMeta::new(self.pool_tokens, false),
+            AccountMeta::new(self.stake_pool, false),
+            AccountMeta::new_readonly(self.withdraw_authority, false),
+            AccountMeta::new(self.reserve_stake, false),
+            AccountMeta::new(self.manager_fee_account, false),
+            AccountMeta::new(self.pool_mint, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new_readonly(self.stake_pool_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+pub struct UnwindIdlePool {
+    pub market: Pubkey,
+    pub factory: Pubkey,
+    pub yield_position: Pubkey,
+    pub pool_tokens: Pubkey,
+    pub stake_pool: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub pool_mint: Pubkey,
+    pub caller: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
+    pub stake_pool_program: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl UnwindIdlePool {
+    /// Accounts for unwinding `market`'s pool from the stake pool it was staked in
+    pub fn new(market: Pubkey, factory: Pubkey, caller: Pubkey, pool: &StakePoolAccounts) -> Self {
+        Self {
+            market,
+            factory,
+            yield_position: find_yield_position_address(&market).0,
+            pool_tokens: find_yield_tokens_address(&market).0,
+            stake_pool: pool.stake_pool,
+            withdraw_authority: pool.withdraw_authority,
+            reserve_stake: pool.reserve_stake,
+            manager_fee_account: pool.manager_fee_account,
+            pool_mint: pool.pool_mint,
+            caller,
+            clock: sysvar::clock::ID,
+            stake_history: sysvar::stake_history::ID,
+            stake_program: stake::program::ID,
+            stake_pool_program: STAKE_POOL_PROGRAM_ID,
+            token_program: TOKEN_PROGRAM_ID,
+        }
+    }
+}
+
+impl ToAccountMetas for UnwindIdlePool {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.market, false),
+            AccountMeta::new_readonly(self.factory, false),
+            AccountMeta::new(self.yield_position, false),
+            AccountMeta::new(self.pool_tokens, false),
+            AccountMeta::new(self.stake_pool, false),
+            AccountMeta::new_readonly(self.withdraw_authority, false),
+            AccountMeta::new(self.reserve_stake, false),
+            AccountMeta::new(self.manager_fee_account, false),
+            AccountMeta::new(self.pool_mint, false),
+            AccountMeta::new_readonly(self.caller, true),
+            AccountMeta::new_readonly(self.clock, false),
+            AccountMeta::new_readonly(self.stake_history, false),
+            AccountMeta::new_readonly(self.stake_program, false),
+            AccountMeta::new_readonly(self.stake_pool_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+pub struct RecordOddsSample {
+    pub market: Pubkey,
+    pub odds_history: Pubkey,
+    pub cranker: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl RecordOddsSample {
+    pub fn new(market: Pubkey, cranker: Pubkey) -> Self {
+        Self {
+            market,
+            odds_history: find_odds_history_address(&market).0,
+            cranker,
+            system_program: system_program::ID,
+        }
+    }
+}
+
+impl ToAccountMetas for RecordOddsSample {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.market, false),
+            AccountMeta::new(self.odds_history, false),
+            AccountMeta::new(self.cranker, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
@@ -0,0 +1,2898 @@
+//! CPI client for calling the market program from another program.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::{get_return_data, invoke_signed};
+use anchor_lang::InstructionData;
+use cryptoscore_common::commitment::SALT_LEN;
+use cryptoscore_common::token_gate::TokenGateRule;
+use cryptoscore_common::{HandicapStyle, MatchOutcome, PropKind, Scoreline, MAX_CO_ADMINS};
+
+use crate::{instruction, SettlementPreview};
+
+pub mod accounts {
+    use anchor_lang::prelude::*;
+
+    pub struct InitializeMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub creator_stats: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub resolution_grace_policy: AccountInfo<'info>,
+        pub match_id_policy: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for InitializeMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::InitializeMarket {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                creator: self.creator.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                creator_stats: self.creator_stats.key(),
+                dashboard_program: self.dashboard_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                resolution_grace_policy: self.resolution_grace_policy.key(),
+                match_id_policy: self.match_id_policy.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for InitializeMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.creator.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.creator_stats.clone(),
+                self.dashboard_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.resolution_grace_policy.clone(),
+                self.match_id_policy.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct JoinMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub rebate_tracker: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub rebate_policy: AccountInfo<'info>,
+        pub treasury: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub discount_policy: AccountInfo<'info>,
+        pub user_stats: AccountInfo<'info>,
+        pub creator_stats: AccountInfo<'info>,
+        pub factory_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        /// Campaign of a voucher covering the entry fee; `None` when the user pays it
+        pub voucher_campaign: Option<AccountInfo<'info>>,
+        /// The user's voucher from `voucher_campaign`; `None` when the user pays the entry fee
+        pub voucher: Option<AccountInfo<'info>>,
+        pub roster: RosterSlot<'info>,
+    }
+
+    impl ToAccountMetas for JoinMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::JoinMarket {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                commit_reveal: self.commit_reveal.key(),
+                token_gate: self.token_gate.key(),
+                in_play_lock: self.in_play_lock.key(),
+                live_score: self.live_score.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                rebate_tracker: self.rebate_tracker.key(),
+                factory: self.factory.key(),
+                rebate_policy: self.rebate_policy.key(),
+                treasury: self.treasury.key(),
+                insurance_fund: self.insurance_fund.key(),
+                discount_policy: self.discount_policy.key(),
+                user_stats: self.user_stats.key(),
+                creator_stats: self.creator_stats.key(),
+                factory_program: self.factory_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                voucher_campaign: self.voucher_campaign.as_ref().map(|voucher_campaign| voucher_campaign.key()),
+                voucher: self.voucher.as_ref().map(|voucher| voucher.key()),
+                roster: self.roster.keys(),
+                // Holdings proofs travel as the context's remaining accounts
+                gate_proof: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for JoinMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.commit_reveal.clone(),
+                self.token_gate.clone(),
+                self.in_play_lock.clone(),
+                self.live_score.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.rebate_tracker.clone(),
+                self.factory.clone(),
+                self.rebate_policy.clone(),
+                self.treasury.clone(),
+                self.insurance_fund.clone(),
+                self.discount_policy.clone(),
+                self.user_stats.clone(),
+                self.creator_stats.clone(),
+                self.factory_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ];
+            infos.extend(self.voucher_campaign.clone());
+            infos.extend(self.voucher.clone());
+            infos.extend(self.roster.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ValidateJoin<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ValidateJoin<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ValidateJoin {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                commit_reveal: self.commit_reveal.key(),
+                token_gate: self.token_gate.key(),
+                in_play_lock: self.in_play_lock.key(),
+                live_score: self.live_score.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                // Holdings proofs travel as the context's remaining accounts
+                gate_proof: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ValidateJoin<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.commit_reveal.clone(),
+                self.token_gate.clone(),
+                self.in_play_lock.clone(),
+                self.live_score.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+            ]
+        }
+    }
+
+    pub struct QueueJoin<'info> {
+        pub market: AccountInfo<'info>,
+        pub queued_join: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for QueueJoin<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::QueueJoin {
+                market: self.market.key(),
+                queued_join: self.queued_join.key(),
+                user: self.user.key(),
+                token_gate: self.token_gate.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for QueueJoin<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.queued_join.clone(),
+                self.user.clone(),
+                self.token_gate.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct ExecuteQueuedJoin<'info> {
+        pub market: AccountInfo<'info>,
+        pub queued_join: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub cranker: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub roster: RosterSlot<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ExecuteQueuedJoin<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ExecuteQueuedJoin {
+                market: self.market.key(),
+                queued_join: self.queued_join.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                cranker: self.cranker.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                commit_reveal: self.commit_reveal.key(),
+                token_gate: self.token_gate.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                roster: self.roster.keys(),
+                tip: self.tip.keys(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ExecuteQueuedJoin<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.queued_join.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.cranker.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.commit_reveal.clone(),
+                self.token_gate.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ];
+            infos.extend(self.roster.to_account_infos());
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct RefundQueuedJoin<'info> {
+        pub market: AccountInfo<'info>,
+        pub queued_join: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RefundQueuedJoin<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RefundQueuedJoin {
+                market: self.market.key(),
+                queued_join: self.queued_join.key(),
+                user: self.user.key(),
+                signer: self.signer.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RefundQueuedJoin<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.queued_join.clone(),
+                self.user.clone(),
+                self.signer.clone(),
+            ]
+        }
+    }
+
+    pub struct FollowLeader<'info> {
+        pub follow: AccountInfo<'info>,
+        pub leader: AccountInfo<'info>,
+        pub follower: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for FollowLeader<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::FollowLeader {
+                follow: self.follow.key(),
+                leader: self.leader.key(),
+                follower: self.follower.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for FollowLeader<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.follow.clone(),
+                self.leader.clone(),
+                self.follower.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct UnfollowLeader<'info> {
+        pub follow: AccountInfo<'info>,
+        pub follower: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for UnfollowLeader<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::UnfollowLeader {
+                follow: self.follow.key(),
+                follower: self.follower.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for UnfollowLeader<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.follow.clone(),
+                self.follower.clone(),
+            ]
+        }
+    }
+
+    pub struct CopyJoin<'info> {
+        pub market: AccountInfo<'info>,
+        pub follow: AccountInfo<'info>,
+        pub leader_participant: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub roster: RosterSlot<'info>,
+    }
+
+    impl ToAccountMetas for CopyJoin<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CopyJoin {
+                market: self.market.key(),
+                follow: self.follow.key(),
+                leader_participant: self.leader_participant.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                commit_reveal: self.commit_reveal.key(),
+                token_gate: self.token_gate.key(),
+                in_play_lock: self.in_play_lock.key(),
+                live_score: self.live_score.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                roster: self.roster.keys(),
+                // Holdings proofs travel as the context's remaining accounts
+                gate_proof: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CopyJoin<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.follow.clone(),
+                self.leader_participant.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.commit_reveal.clone(),
+                self.token_gate.clone(),
+                self.in_play_lock.clone(),
+                self.live_score.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ];
+            infos.extend(self.roster.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct EnableCommitReveal<'info> {
+        pub market: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for EnableCommitReveal<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::EnableCommitReveal {
+                market: self.market.key(),
+                commit_reveal: self.commit_reveal.key(),
+                admin: self.admin.key(),
+                token_gate: self.token_gate.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for EnableCommitReveal<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.commit_reveal.clone(),
+                self.admin.clone(),
+                self.token_gate.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct EnableParticipantRoster<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant_roster: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for EnableParticipantRoster<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::EnableParticipantRoster {
+                market: self.market.key(),
+                participant_roster: self.participant_roster.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for EnableParticipantRoster<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant_roster.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct PledgeToCharity<'info> {
+        pub market: AccountInfo<'info>,
+        pub charity_pledge: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for PledgeToCharity<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::PledgeToCharity {
+                market: self.market.key(),
+                charity_pledge: self.charity_pledge.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for PledgeToCharity<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.charity_pledge.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct OfferFirstCorrectBonus<'info> {
+        pub market: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for OfferFirstCorrectBonus<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::OfferFirstCorrectBonus {
+                market: self.market.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for OfferFirstCorrectBonus<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.first_correct_bonus.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetFollowUpMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub follow_up: AccountInfo<'info>,
+        pub market_chain: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetFollowUpMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetFollowUpMarket {
+                market: self.market.key(),
+                follow_up: self.follow_up.key(),
+                market_chain: self.market_chain.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetFollowUpMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.follow_up.clone(),
+                self.market_chain.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct EnableInPlay<'info> {
+        pub market: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for EnableInPlay<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::EnableInPlay {
+                market: self.market.key(),
+                in_play_lock: self.in_play_lock.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for EnableInPlay<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.in_play_lock.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct ListPropPlayers<'info> {
+        pub market: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ListPropPlayers<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ListPropPlayers {
+                market: self.market.key(),
+                prop_registry: self.prop_registry.key(),
+                commit_reveal: self.commit_reveal.key(),
+                score_grid: self.score_grid.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ListPropPlayers<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.prop_registry.clone(),
+                self.commit_reveal.clone(),
+                self.score_grid.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct JoinProp<'info> {
+        pub market: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub prop_entry: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for JoinProp<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::JoinProp {
+                market: self.market.key(),
+                prop_registry: self.prop_registry.key(),
+                prop_entry: self.prop_entry.key(),
+                user: self.user.key(),
+                token_gate: self.token_gate.key(),
+                in_play_lock: self.in_play_lock.key(),
+                live_score: self.live_score.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                // Holdings proofs travel as the context's remaining accounts
+                gate_proof: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for JoinProp<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.prop_registry.clone(),
+                self.prop_entry.clone(),
+                self.user.clone(),
+                self.token_gate.clone(),
+                self.in_play_lock.clone(),
+                self.live_score.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct WithdrawPropRewards<'info> {
+        pub market: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub prop_entry: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for WithdrawPropRewards<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::WithdrawPropRewards {
+                market: self.market.key(),
+                prop_registry: self.prop_registry.key(),
+                prop_entry: self.prop_entry.key(),
+                user: self.user.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for WithdrawPropRewards<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.prop_registry.clone(),
+                self.prop_entry.clone(),
+                self.user.clone(),
+            ]
+        }
+    }
+
+    pub struct EnableCorrectScore<'info> {
+        pub market: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for EnableCorrectScore<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::EnableCorrectScore {
+                market: self.market.key(),
+                score_grid: self.score_grid.key(),
+                commit_reveal: self.commit_reveal.key(),
+                prop_registry: self.prop_registry.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for EnableCorrectScore<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.score_grid.clone(),
+                self.commit_reveal.clone(),
+                self.prop_registry.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SetHandicap<'info> {
+        pub market: AccountInfo<'info>,
+        pub handicap: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetHandicap<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetHandicap {
+                market: self.market.key(),
+                handicap: self.handicap.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetHandicap<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.handicap.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct JoinCorrectScore<'info> {
+        pub market: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub score_entry: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub in_play_lock: AccountInfo<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for JoinCorrectScore<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::JoinCorrectScore {
+                market: self.market.key(),
+                score_grid: self.score_grid.key(),
+                score_entry: self.score_entry.key(),
+                user: self.user.key(),
+                token_gate: self.token_gate.key(),
+                in_play_lock: self.in_play_lock.key(),
+                live_score: self.live_score.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                // Holdings proofs travel as the context's remaining accounts
+                gate_proof: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for JoinCorrectScore<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.score_grid.clone(),
+                self.score_entry.clone(),
+                self.user.clone(),
+                self.token_gate.clone(),
+                self.in_play_lock.clone(),
+                self.live_score.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct WithdrawScoreRewards<'info> {
+        pub market: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub score_entry: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for WithdrawScoreRewards<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::WithdrawScoreRewards {
+                market: self.market.key(),
+                score_grid: self.score_grid.key(),
+                score_entry: self.score_entry.key(),
+                user: self.user.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for WithdrawScoreRewards<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.score_grid.clone(),
+                self.score_entry.clone(),
+                self.user.clone(),
+            ]
+        }
+    }
+
+    pub struct SetTokenGate<'info> {
+        pub market: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub admin: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetTokenGate<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetTokenGate {
+                market: self.market.key(),
+                token_gate: self.token_gate.key(),
+                commit_reveal: self.commit_reveal.key(),
+                admin: self.admin.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetTokenGate<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.token_gate.clone(),
+                self.commit_reveal.clone(),
+                self.admin.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CommitPrediction<'info> {
+        pub market: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub commitment: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CommitPrediction<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CommitPrediction {
+                market: self.market.key(),
+                commit_reveal: self.commit_reveal.key(),
+                commitment: self.commitment.key(),
+                user: self.user.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CommitPrediction<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.commit_reveal.clone(),
+                self.commitment.clone(),
+                self.user.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct RevealPrediction<'info> {
+        pub market: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub commitment: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub roster: RosterSlot<'info>,
+    }
+
+    impl ToAccountMetas for RevealPrediction<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RevealPrediction {
+                market: self.market.key(),
+                commit_reveal: self.commit_reveal.key(),
+                commitment: self.commitment.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                roster: self.roster.keys(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RevealPrediction<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.commit_reveal.clone(),
+                self.commitment.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ];
+            infos.extend(self.roster.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub resolver: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub treasury: AccountInfo<'info>,
+        /// Resolver's participant account; `None` when the creator resolves
+        pub participant: Option<AccountInfo<'info>>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub creator_stats: AccountInfo<'info>,
+        pub creator_earnings: AccountInfo<'info>,
+        pub fee_receipt: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub resolution_policy: AccountInfo<'info>,
+        pub factory_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub charity_pledge: AccountInfo<'info>,
+        /// Charity recorded in the market's charity pledge; `None` when it has none
+        pub charity: Option<AccountInfo<'info>>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub handicap: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub rake_free_market: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ResolveMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ResolveMarket {
+                market: self.market.key(),
+                resolver: self.resolver.key(),
+                creator: self.creator.key(),
+                factory: self.factory.key(),
+                treasury: self.treasury.key(),
+                participant: self.participant.as_ref().map(|participant| participant.key()),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                creator_stats: self.creator_stats.key(),
+                creator_earnings: self.creator_earnings.key(),
+                fee_receipt: self.fee_receipt.key(),
+                dashboard_program: self.dashboard_program.key(),
+                resolution_policy: self.resolution_policy.key(),
+                factory_program: self.factory_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                charity_pledge: self.charity_pledge.key(),
+                charity: self.charity.as_ref().map(|charity| charity.key()),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                handicap: self.handicap.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                rake_free_market: self.rake_free_market.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.resolver.clone(),
+                self.creator.clone(),
+                self.factory.clone(),
+                self.treasury.clone(),
+            ];
+            infos.extend(self.participant.clone());
+            infos.extend([
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.creator_stats.clone(),
+                self.creator_earnings.clone(),
+                self.fee_receipt.clone(),
+                self.dashboard_program.clone(),
+                self.resolution_policy.clone(),
+                self.factory_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.charity_pledge.clone(),
+            ]);
+            infos.extend(self.charity.clone());
+            infos.extend([
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.handicap.clone(),
+                self.first_correct_bonus.clone(),
+                self.rake_free_market.clone(),
+            ]);
+            infos
+        }
+    }
+
+    pub struct ResolveWithAttestation<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveWithAttestation<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.push(AccountMeta::new_readonly(self.results_signer.key(), false));
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveWithAttestation<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.push(self.results_signer.clone());
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveProp<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveProp<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.push(AccountMeta::new_readonly(self.results_signer.key(), false));
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveProp<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.push(self.results_signer.clone());
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct RecordOutrightCheckpoint<'info> {
+        pub market: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub outright_standings: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RecordOutrightCheckpoint<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RecordOutrightCheckpoint {
+                market: self.market.key(),
+                prop_registry: self.prop_registry.key(),
+                outright_standings: self.outright_standings.key(),
+                results_signer: self.results_signer.key(),
+                signer: self.signer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RecordOutrightCheckpoint<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.prop_registry.clone(),
+                self.outright_standings.clone(),
+                self.results_signer.clone(),
+                self.signer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SettleOutrightEarly<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub outright_standings: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for SettleOutrightEarly<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.push(AccountMeta::new_readonly(self.outright_standings.key(), false));
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SettleOutrightEarly<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.push(self.outright_standings.clone());
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveWithVaa<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub wormhole_emitter: AccountInfo<'info>,
+        pub posted_vaa: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveWithVaa<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.extend([
+                AccountMeta::new_readonly(self.wormhole_emitter.key(), false),
+                AccountMeta::new_readonly(self.posted_vaa.key(), false),
+            ]);
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveWithVaa<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.extend([self.wormhole_emitter.clone(), self.posted_vaa.clone()]);
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveWithLiveScore<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveWithLiveScore<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.extend([
+                AccountMeta::new_readonly(self.live_score.key(), false),
+                AccountMeta::new_readonly(self.results_signer.key(), false),
+            ]);
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveWithLiveScore<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.extend([self.live_score.clone(), self.results_signer.clone()]);
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveCorrectScore<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveCorrectScore<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.extend([
+                AccountMeta::new_readonly(self.live_score.key(), false),
+                AccountMeta::new_readonly(self.results_signer.key(), false),
+            ]);
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveCorrectScore<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.extend([self.live_score.clone(), self.results_signer.clone()]);
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveHandicap<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub live_score: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for ResolveHandicap<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.extend([
+                AccountMeta::new_readonly(self.live_score.key(), false),
+                AccountMeta::new_readonly(self.results_signer.key(), false),
+            ]);
+            metas.extend(self.tip.to_account_metas(is_signer));
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveHandicap<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.extend([self.live_score.clone(), self.results_signer.clone()]);
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct ResolveMarketsBatch<'info> {
+        pub results_batch: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ResolveMarketsBatch<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ResolveMarketsBatch {
+                results_batch: self.results_batch.key(),
+                results_signer: self.results_signer.key(),
+                // Each market's resolution accounts travel as the context's remaining accounts
+                markets: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ResolveMarketsBatch<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.results_batch.clone(), self.results_signer.clone()]
+        }
+    }
+
+    pub struct SignResolution<'info> {
+        pub market: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub resolution_policy: AccountInfo<'info>,
+        pub resolution_votes: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SignResolution<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SignResolution {
+                market: self.market.key(),
+                results_signer: self.results_signer.key(),
+                resolution_policy: self.resolution_policy.key(),
+                resolution_votes: self.resolution_votes.key(),
+                signer: self.signer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SignResolution<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.results_signer.clone(),
+                self.resolution_policy.clone(),
+                self.resolution_votes.clone(),
+                self.signer.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CoSignResolution<'info> {
+        pub resolution: ResolveMarket<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub resolution_votes: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CoSignResolution<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            let mut metas = self.resolution.to_account_metas(is_signer);
+            metas.extend([
+                AccountMeta::new_readonly(self.results_signer.key(), false),
+                AccountMeta::new_readonly(self.resolution_votes.key(), false),
+            ]);
+            metas
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CoSignResolution<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = self.resolution.to_account_infos();
+            infos.extend([self.results_signer.clone(), self.resolution_votes.clone()]);
+            infos
+        }
+    }
+
+    pub struct CorrectResult<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub results_signer: AccountInfo<'info>,
+        pub signer: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub handicap: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub winners_root: AccountInfo<'info>,
+        pub misresolution_ruling: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub factory_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CorrectResult<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CorrectResult {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                results_signer: self.results_signer.key(),
+                signer: self.signer.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                handicap: self.handicap.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                winners_root: self.winners_root.key(),
+                misresolution_ruling: self.misresolution_ruling.key(),
+                insurance_fund: self.insurance_fund.key(),
+                factory_program: self.factory_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CorrectResult<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.results_signer.clone(),
+                self.signer.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.handicap.clone(),
+                self.first_correct_bonus.clone(),
+                self.winners_root.clone(),
+                self.misresolution_ruling.clone(),
+                self.insurance_fund.clone(),
+                self.factory_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SettleRebateEntry<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub rebate_tracker: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub keeper: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for SettleRebateEntry<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SettleRebateEntry {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                rebate_tracker: self.rebate_tracker.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+                keeper: self.keeper.key(),
+                tip: self.tip.keys(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SettleRebateEntry<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.rebate_tracker.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+                self.keeper.clone(),
+            ];
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct RecordPendingClaim<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub pending_claims: AccountInfo<'info>,
+        pub cranker: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for RecordPendingClaim<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RecordPendingClaim {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                pending_claims: self.pending_claims.key(),
+                cranker: self.cranker.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                tip: self.tip.keys(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RecordPendingClaim<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.pending_claims.clone(),
+                self.cranker.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ];
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct BuildWinnersRoot<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant_roster: AccountInfo<'info>,
+        pub roster_page: AccountInfo<'info>,
+        pub winners_root: AccountInfo<'info>,
+        pub cranker: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub tip: KeeperTip<'info>,
+    }
+
+    impl ToAccountMetas for BuildWinnersRoot<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::BuildWinnersRoot {
+                market: self.market.key(),
+                participant_roster: self.participant_roster.key(),
+                roster_page: self.roster_page.key(),
+                winners_root: self.winners_root.key(),
+                cranker: self.cranker.key(),
+                system_program: self.system_program.key(),
+                tip: self.tip.keys(),
+                // Participant accounts travel as the context's remaining accounts
+                participants: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for BuildWinnersRoot<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.participant_roster.clone(),
+                self.roster_page.clone(),
+                self.winners_root.clone(),
+                self.cranker.clone(),
+                self.system_program.clone(),
+            ];
+            infos.extend(self.tip.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct RosterSlot<'info> {
+        pub participant_roster: AccountInfo<'info>,
+        pub roster_page: AccountInfo<'info>,
+    }
+
+    impl RosterSlot<'_> {
+        pub(crate) fn keys(&self) -> crate::accounts::RosterSlot {
+            crate::accounts::RosterSlot {
+                participant_roster: self.participant_roster.key(),
+                roster_page: self.roster_page.key(),
+            }
+        }
+    }
+
+    impl ToAccountMetas for RosterSlot<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.keys().to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RosterSlot<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.participant_roster.clone(), self.roster_page.clone()]
+        }
+    }
+
+    pub struct KeeperTip<'info> {
+        pub factory: AccountInfo<'info>,
+        pub keeper_tip_policy: AccountInfo<'info>,
+        pub keeper_tips: AccountInfo<'info>,
+        pub treasury: AccountInfo<'info>,
+        pub insurance_fund: AccountInfo<'info>,
+        pub factory_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl KeeperTip<'_> {
+        pub(crate) fn keys(&self) -> crate::accounts::KeeperTip {
+            crate::accounts::KeeperTip {
+                factory: self.factory.key(),
+                keeper_tip_policy: self.keeper_tip_policy.key(),
+                keeper_tips: self.keeper_tips.key(),
+                treasury: self.treasury.key(),
+                insurance_fund: self.insurance_fund.key(),
+                factory_program: self.factory_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+            }
+        }
+    }
+
+    impl ToAccountMetas for KeeperTip<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.keys().to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for KeeperTip<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.factory.clone(),
+                self.keeper_tip_policy.clone(),
+                self.keeper_tips.clone(),
+                self.treasury.clone(),
+                self.insurance_fund.clone(),
+                self.factory_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct WithdrawRewards<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub pending_claims: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for WithdrawRewards<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::WithdrawRewards {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                pending_claims: self.pending_claims.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for WithdrawRewards<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.pending_claims.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ]
+        }
+    }
+
+    pub struct RollRewards<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub pending_claims: AccountInfo<'info>,
+        pub market_chain: AccountInfo<'info>,
+        pub previous_rolled_entry: AccountInfo<'info>,
+        pub follow_up: AccountInfo<'info>,
+        pub entry: AccountInfo<'info>,
+        pub rolled_entry: AccountInfo<'info>,
+        pub commit_reveal: AccountInfo<'info>,
+        pub token_gate: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub follow_up_first_correct_bonus: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub points_ledger: AccountInfo<'info>,
+        pub points_totals: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub roster: RosterSlot<'info>,
+    }
+
+    impl ToAccountMetas for RollRewards<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RollRewards {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                pending_claims: self.pending_claims.key(),
+                market_chain: self.market_chain.key(),
+                previous_rolled_entry: self.previous_rolled_entry.key(),
+                follow_up: self.follow_up.key(),
+                entry: self.entry.key(),
+                rolled_entry: self.rolled_entry.key(),
+                commit_reveal: self.commit_reveal.key(),
+                token_gate: self.token_gate.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                follow_up_first_correct_bonus: self.follow_up_first_correct_bonus.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                points_ledger: self.points_ledger.key(),
+                points_totals: self.points_totals.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                roster: self.roster.keys(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RollRewards<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            let mut infos = vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.first_correct_bonus.clone(),
+                self.pending_claims.clone(),
+                self.market_chain.clone(),
+                self.previous_rolled_entry.clone(),
+                self.follow_up.clone(),
+                self.entry.clone(),
+                self.rolled_entry.clone(),
+                self.commit_reveal.clone(),
+                self.token_gate.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.follow_up_first_correct_bonus.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.points_ledger.clone(),
+                self.points_totals.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+            ];
+            infos.extend(self.roster.to_account_infos());
+            infos
+        }
+    }
+
+    pub struct WithdrawRewardsSwapped<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub payout_swap_policy: AccountInfo<'info>,
+        pub wrapped_sol: AccountInfo<'info>,
+        pub output_tokens: AccountInfo<'info>,
+        pub pending_claims: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub jupiter_program: AccountInfo<'info>,
+        pub token_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for WithdrawRewardsSwapped<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::WithdrawRewardsSwapped {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                payout_swap_policy: self.payout_swap_policy.key(),
+                wrapped_sol: self.wrapped_sol.key(),
+                output_tokens: self.output_tokens.key(),
+                pending_claims: self.pending_claims.key(),
+                dashboard_program: self.dashboard_program.key(),
+                jupiter_program: self.jupiter_program.key(),
+                token_program: self.token_program.key(),
+                instructions: self.instructions.key(),
+                system_program: self.system_program.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                // The route travels as the context's remaining accounts
+                route: vec![],
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for WithdrawRewardsSwapped<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.payout_swap_policy.clone(),
+                self.wrapped_sol.clone(),
+                self.output_tokens.clone(),
+                self.pending_claims.clone(),
+                self.dashboard_program.clone(),
+                self.jupiter_program.clone(),
+                self.token_program.clone(),
+                self.instructions.clone(),
+                self.system_program.clone(),
+                self.first_correct_bonus.clone(),
+            ]
+        }
+    }
+
+    pub struct MintClaimReceipt<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub receipt_policy: AccountInfo<'info>,
+        pub claim_receipt: AccountInfo<'info>,
+        pub receipt_authority: AccountInfo<'info>,
+        pub tree_config: AccountInfo<'info>,
+        pub merkle_tree: AccountInfo<'info>,
+        pub bubblegum_program: AccountInfo<'info>,
+        pub log_wrapper: AccountInfo<'info>,
+        pub compression_program: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for MintClaimReceipt<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::MintClaimReceipt {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                user: self.user.key(),
+                receipt_policy: self.receipt_policy.key(),
+                claim_receipt: self.claim_receipt.key(),
+                receipt_authority: self.receipt_authority.key(),
+                tree_config: self.tree_config.key(),
+                merkle_tree: self.merkle_tree.key(),
+                bubblegum_program: self.bubblegum_program.key(),
+                log_wrapper: self.log_wrapper.key(),
+                compression_program: self.compression_program.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for MintClaimReceipt<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.user.clone(),
+                self.receipt_policy.clone(),
+                self.claim_receipt.clone(),
+                self.receipt_authority.clone(),
+                self.tree_config.clone(),
+                self.merkle_tree.clone(),
+                self.bubblegum_program.clone(),
+                self.log_wrapper.clone(),
+                self.compression_program.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct SweepUnclaimed<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub platform: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SweepUnclaimed<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SweepUnclaimed {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                authority: self.authority.key(),
+                platform: self.platform.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SweepUnclaimed<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.authority.clone(),
+                self.platform.clone(),
+                self.first_correct_bonus.clone(),
+            ]
+        }
+    }
+
+    pub struct SetCoAdmins<'info> {
+        pub market: AccountInfo<'info>,
+        pub creator: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for SetCoAdmins<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::SetCoAdmins {
+                market: self.market.key(),
+                creator: self.creator.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for SetCoAdmins<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.market.clone(), self.creator.clone()]
+        }
+    }
+
+    pub struct CancelMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub canceller: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CancelMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CancelMarket {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                canceller: self.canceller.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CancelMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.canceller.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+            ]
+        }
+    }
+
+    pub struct ClaimRefund<'info> {
+        pub market: AccountInfo<'info>,
+        pub participant: AccountInfo<'info>,
+        pub yield_position: AccountInfo<'info>,
+        pub user: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ClaimRefund<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::ClaimRefund {
+                market: self.market.key(),
+                participant: self.participant.key(),
+                yield_position: self.yield_position.key(),
+                user: self.user.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ClaimRefund<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.participant.clone(),
+                self.yield_position.clone(),
+                self.user.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct CloseMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub yield_position: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CloseMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CloseMarket {
+                market: self.market.key(),
+                yield_position: self.yield_position.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CloseMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.market.clone(), self.yield_position.clone()]
+        }
+    }
+
+    pub struct CompileSettlementReport<'info> {
+        pub market: AccountInfo<'info>,
+        pub prop_registry: AccountInfo<'info>,
+        pub score_grid: AccountInfo<'info>,
+        pub settlement_report: AccountInfo<'info>,
+        pub cranker: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for CompileSettlementReport<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::CompileSettlementReport {
+                market: self.market.key(),
+                prop_registry: self.prop_registry.key(),
+                score_grid: self.score_grid.key(),
+                settlement_report: self.settlement_report.key(),
+                cranker: self.cranker.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for CompileSettlementReport<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.prop_registry.clone(),
+                self.score_grid.clone(),
+                self.settlement_report.clone(),
+                self.cranker.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct EmergencyVoid<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub platform_stats: AccountInfo<'info>,
+        pub platform_activity: AccountInfo<'info>,
+        pub dashboard_program: AccountInfo<'info>,
+        pub instructions: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for EmergencyVoid<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::EmergencyVoid {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                authority: self.authority.key(),
+                platform_stats: self.platform_stats.key(),
+                platform_activity: self.platform_activity.key(),
+                dashboard_program: self.dashboard_program.key(),
+                instructions: self.instructions.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for EmergencyVoid<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.authority.clone(),
+                self.platform_stats.clone(),
+                self.platform_activity.clone(),
+                self.dashboard_program.clone(),
+                self.instructions.clone(),
+                self.first_correct_bonus.clone(),
+            ]
+        }
+    }
+
+    pub struct AssertSolvency<'info> {
+        pub market: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for AssertSolvency<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::AssertSolvency {
+                market: self.market.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for AssertSolvency<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.market.clone()]
+        }
+    }
+
+    pub struct PreviewSettlement<'info> {
+        pub market: AccountInfo<'info>,
+        pub charity_pledge: AccountInfo<'info>,
+        pub first_correct_bonus: AccountInfo<'info>,
+        pub rake_free_market: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for PreviewSettlement<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::PreviewSettlement {
+                market: self.market.key(),
+                charity_pledge: self.charity_pledge.key(),
+                first_correct_bonus: self.first_correct_bonus.key(),
+                rake_free_market: self.rake_free_market.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for PreviewSettlement<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.charity_pledge.clone(),
+                self.first_correct_bonus.clone(),
+                self.rake_free_market.clone(),
+            ]
+        }
+    }
+
+    pub struct MigrateMarket<'info> {
+        pub market: AccountInfo<'info>,
+        pub payer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for MigrateMarket<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::MigrateMarket {
+                market: self.market.key(),
+                payer: self.payer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for MigrateMarket<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.market.clone(), self.payer.clone(), self.system_program.clone()]
+        }
+    }
+
+    pub struct MigrateParticipant<'info> {
+        pub participant: AccountInfo<'info>,
+        pub payer: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for MigrateParticipant<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::MigrateParticipant {
+                participant: self.participant.key(),
+                payer: self.payer.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for MigrateParticipant<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.participant.clone(), self.payer.clone(), self.system_program.clone()]
+        }
+    }
+
+    pub struct StakeIdlePool<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub yield_position: AccountInfo<'info>,
+        pub yield_escrow: AccountInfo<'info>,
+        pub pool_tokens: AccountInfo<'info>,
+        pub stake_pool: AccountInfo<'info>,
+        pub withdraw_authority: AccountInfo<'info>,
+        pub reserve_stake: AccountInfo<'info>,
+        pub manager_fee_account: AccountInfo<'info>,
+        pub pool_mint: AccountInfo<'info>,
+        pub authority: AccountInfo<'info>,
+        pub stake_pool_program: AccountInfo<'info>,
+        pub token_program: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for StakeIdlePool<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::StakeIdlePool {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                yield_position: self.yield_position.key(),
+                yield_escrow: self.yield_escrow.key(),
+                pool_tokens: self.pool_tokens.key(),
+                stake_pool: self.stake_pool.key(),
+                withdraw_authority: self.withdraw_authority.key(),
+                reserve_stake: self.reserve_stake.key(),
+                manager_fee_account: self.manager_fee_account.key(),
+                pool_mint: self.pool_mint.key(),
+                authority: self.authority.key(),
+                stake_pool_program: self.stake_pool_program.key(),
+                token_program: self.token_program.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for StakeIdlePool<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.yield_position.clone(),
+                self.yield_escrow.clone(),
+                self.pool_tokens.clone(),
+                self.stake_pool.clone(),
+                self.withdraw_authority.clone(),
+                self.reserve_stake.clone(),
+                self.manager_fee_account.clone(),
+                self.pool_mint.clone(),
+                self.authority.clone(),
+                self.stake_pool_program.clone(),
+                self.token_program.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+
+    pub struct UnwindIdlePool<'info> {
+        pub market: AccountInfo<'info>,
+        pub factory: AccountInfo<'info>,
+        pub yield_position: AccountInfo<'info>,
+        pub pool_tokens: AccountInfo<'info>,
+        pub stake_pool: AccountInfo<'info>,
+        pub withdraw_authority: AccountInfo<'info>,
+        pub reserve_stake: AccountInfo<'info>,
+        pub manager_fee_account: AccountInfo<'info>,
+        pub pool_mint: AccountInfo<'info>,
+        pub caller: AccountInfo<'info>,
+        pub clock: AccountInfo<'info>,
+        pub stake_history: AccountInfo<'info>,
+        pub stake_program: AccountInfo<'info>,
+        pub stake_pool_program: AccountInfo<'info>,
+        pub token_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for UnwindIdlePool<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::UnwindIdlePool {
+                market: self.market.key(),
+                factory: self.factory.key(),
+                yield_position: self.yield_position.key(),
+                pool_tokens: self.pool_tokens.key(),
+                stake_pool: self.stake_pool.key(),
+                withdraw_authority: self.withdraw_authority.key(),
+                reserve_stake: self.reserve_stake.key(),
+                manager_fee_account: self.manager_fee_account.key(),
+                pool_mint: self.pool_mint.key(),
+                caller: self.caller.key(),
+                clock: self.clock.key(),
+                stake_history: self.stake_history.key(),
+                stake_program: self.stake_program.key(),
+                stake_pool_program: self.stake_pool_program.key(),
+                token_program: self.token_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for UnwindIdlePool<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.factory.clone(),
+                self.yield_position.clone(),
+                self.pool_tokens.clone(),
+                self.stake_pool.clone(),
+                self.withdraw_authority.clone(),
+                self.reserve_stake.clone(),
+                self.manager_fee_account.clone(),
+                self.pool_mint.clone(),
+                self.caller.clone(),
+                self.clock.clone(),
+                self.stake_history.clone(),
+                self.stake_program.clone(),
+                self.stake_pool_program.clone(),
+                self.token_program.clone(),
+            ]
+        }
+    }
+
+    pub struct RecordOddsSample<'info> {
+        pub market: AccountInfo<'info>,
+        pub odds_history: AccountInfo<'info>,
+        pub cranker: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for RecordOddsSample<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            crate::accounts::RecordOddsSample {
+                market: self.market.key(),
+                odds_history: self.odds_history.key(),
+                cranker: self.cranker.key(),
+                system_program: self.system_program.key(),
+            }
+            .to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for RecordOddsSample<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![
+                self.market.clone(),
+                self.odds_history.clone(),
+                self.cranker.clone(),
+                self.system_program.clone(),
+            ]
+        }
+    }
+}
+
+pub fn initialize_market<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::InitializeMarket<'info>>,
+    args: instruction::InitializeMarket,
+) -> Result<()> {
+    invoke(ctx, args.data())
+}
+
+pub fn join_market<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::JoinMarket<'info>>,
+    prediction: MatchOutcome,
+    note_hash: [u8; 32],
+) -> Result<()> {
+    invoke(ctx, instruction::JoinMarket { prediction, note_hash }.data())
+}
+
+pub fn validate_join<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ValidateJoin<'info>>,
+    prediction: MatchOutcome,
+) -> Result<()> {
+    invoke(ctx, instruction::ValidateJoin { prediction }.data())
+}
+
+pub fn queue_join<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::QueueJoin<'info>>,
+    prediction: MatchOutcome,
+    min_payout_bps: u64,
+) -> Result<()> {
+    invoke(
+        ctx,
+        instruction::QueueJoin {
+            prediction,
+            min_payout_bps,
+        }
+        .data(),
+    )
+}
+
+pub fn execute_queued_join<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ExecuteQueuedJoin<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::ExecuteQueuedJoin.data())
+}
+
+pub fn refund_queued_join<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::RefundQueuedJoin<'info>>) -> Result<()> {
+    invoke(ctx, instruction::RefundQueuedJoin.data())
+}
+
+pub fn follow<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::FollowLeader<'info>>) -> Result<()> {
+    invoke(ctx, instruction::Follow.data())
+}
+
+pub fn unfollow<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::UnfollowLeader<'info>>) -> Result<()> {
+    invoke(ctx, instruction::Unfollow.data())
+}
+
+pub fn copy_join<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::CopyJoin<'info>>) -> Result<()> {
+    invoke(ctx, instruction::CopyJoin.data())
+}
+
+pub fn enable_commit_reveal<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::EnableCommitReveal<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::EnableCommitReveal.data())
+}
+
+pub fn enable_participant_roster<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::EnableParticipantRoster<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::EnableParticipantRoster.data())
+}
+
+pub fn pledge_to_charity<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::PledgeToCharity<'info>>,
+    charity: Pubkey,
+    pool_share_bps: u16,
+) -> Result<()> {
+    invoke(ctx, instruction::PledgeToCharity { charity, pool_share_bps }.data())
+}
+
+pub fn offer_first_correct_bonus<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::OfferFirstCorrectBonus<'info>>,
+    bonus_bps: u16,
+) -> Result<()> {
+    invoke(ctx, instruction::OfferFirstCorrectBonus { bonus_bps }.data())
+}
+
+pub fn set_follow_up_market<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetFollowUpMarket<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::SetFollowUpMarket.data())
+}
+
+pub fn enable_in_play<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::EnableInPlay<'info>>,
+    lock_minute: u16,
+    lock_on_goal: bool,
+) -> Result<()> {
+    invoke(ctx, instruction::EnableInPlay { lock_minute, lock_on_goal }.data())
+}
+
+pub fn list_prop_players<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ListPropPlayers<'info>>,
+    kind: PropKind,
+    player_ids: Vec<u32>,
+) -> Result<()> {
+    invoke(ctx, instruction::ListPropPlayers { kind, player_ids }.data())
+}
+
+pub fn join_prop<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::JoinProp<'info>>, player_id: u32) -> Result<()> {
+    invoke(ctx, instruction::JoinProp { player_id }.data())
+}
+
+pub fn withdraw_prop_rewards<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::WithdrawPropRewards<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::WithdrawPropRewards.data())
+}
+
+pub fn enable_correct_score<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::EnableCorrectScore<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::EnableCorrectScore.data())
+}
+
+pub fn set_handicap<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetHandicap<'info>>,
+    style: HandicapStyle,
+    home_line: i8,
+    away_line: i8,
+) -> Result<()> {
+    invoke(
+        ctx,
+        instruction::SetHandicap {
+            style,
+            home_line,
+            away_line,
+        }
+        .data(),
+    )
+}
+
+pub fn join_correct_score<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::JoinCorrectScore<'info>>,
+    scoreline: Scoreline,
+) -> Result<()> {
+    invoke(ctx, instruction::JoinCorrectScore { scoreline }.data())
+}
+
+pub fn withdraw_score_rewards<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::WithdrawScoreRewards<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::WithdrawScoreRewards.data())
+}
+
+pub fn set_token_gate<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetTokenGate<'info>>,
+    rule: TokenGateRule,
+) -> Result<()> {
+    invoke(ctx, instruction::SetTokenGate { rule }.data())
+}
+
+pub fn commit_prediction<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CommitPrediction<'info>>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    invoke(ctx, instruction::CommitPrediction { commitment }.data())
+}
+
+pub fn reveal_prediction<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RevealPrediction<'info>>,
+    prediction: MatchOutcome,
+    salt: [u8; SALT_LEN],
+) -> Result<()> {
+    invoke(ctx, instruction::RevealPrediction { prediction, salt }.data())
+}
+
+pub fn resolve_market<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveMarket<'info>>,
+    home_goals: u8,
+    away_goals: u8,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveMarket { home_goals, away_goals }.data())
+}
+
+pub fn resolve_with_live_score<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveWithLiveScore<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveWithLiveScore.data())
+}
+
+pub fn resolve_correct_score<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveCorrectScore<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveCorrectScore.data())
+}
+
+pub fn resolve_handicap<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveHandicap<'info>>) -> Result<()> {
+    invoke(ctx, instruction::ResolveHandicap.data())
+}
+
+pub fn resolve_markets_batch<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveMarketsBatch<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveMarketsBatch.data())
+}
+
+pub fn resolve_with_attestation<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveWithAttestation<'info>>,
+    home_goals: u8,
+    away_goals: u8,
+    attested_at: i64,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveWithAttestation { home_goals, away_goals, attested_at }.data())
+}
+
+pub fn resolve_prop<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveProp<'info>>,
+    player_id: Option<u32>,
+) -> Result<()> {
+    invoke(ctx, instruction::ResolveProp { player_id }.data())
+}
+
+pub fn record_outright_checkpoint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RecordOutrightCheckpoint<'info>>,
+    eliminated: Vec<u32>,
+) -> Result<()> {
+    invoke(ctx, instruction::RecordOutrightCheckpoint { eliminated }.data())
+}
+
+pub fn settle_outright_early<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SettleOutrightEarly<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::SettleOutrightEarly.data())
+}
+
+pub fn resolve_with_vaa<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::ResolveWithVaa<'info>>) -> Result<()> {
+    invoke(ctx, instruction::ResolveWithVaa.data())
+}
+
+pub fn sign_resolution<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SignResolution<'info>>,
+    home_goals: u8,
+    away_goals: u8,
+) -> Result<()> {
+    invoke(ctx, instruction::SignResolution { home_goals, away_goals }.data())
+}
+
+pub fn co_sign_resolution<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CoSignResolution<'info>>,
+    home_goals: u8,
+    away_goals: u8,
+) -> Result<()> {
+    invoke(ctx, instruction::CoSignResolution { home_goals, away_goals }.data())
+}
+
+pub fn correct_result<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CorrectResult<'info>>,
+    home_goals: u8,
+    away_goals: u8,
+) -> Result<()> {
+    invoke(ctx, instruction::CorrectResult { home_goals, away_goals }.data())
+}
+
+pub fn settle_rebate_entry<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SettleRebateEntry<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::SettleRebateEntry.data())
+}
+
+pub fn record_pending_claim<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RecordPendingClaim<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::RecordPendingClaim.data())
+}
+
+pub fn build_winners_root<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::BuildWinnersRoot<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::BuildWinnersRoot.data())
+}
+
+pub fn withdraw_rewards<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::WithdrawRewards<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::WithdrawRewards.data())
+}
+
+pub fn roll_rewards<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::RollRewards<'info>>,
+    prediction: MatchOutcome,
+) -> Result<()> {
+    invoke(ctx, instruction::RollRewards { prediction }.data())
+}
+
+pub fn withdraw_rewards_swapped<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::WithdrawRewardsSwapped<'info>>,
+    route_data: Vec<u8>,
+    min_amount_out: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::WithdrawRewardsSwapped { route_data, min_amount_out }.data())
+}
+
+pub fn mint_claim_receipt<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::MintClaimReceipt<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::MintClaimReceipt.data())
+}
+
+pub fn sweep_unclaimed<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SweepUnclaimed<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::SweepUnclaimed.data())
+}
+
+pub fn set_co_admins<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCoAdmins<'info>>,
+    co_admins: [Pubkey; MAX_CO_ADMINS],
+) -> Result<()> {
+    invoke(ctx, instruction::SetCoAdmins { co_admins }.data())
+}
+
+pub fn cancel_market<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::CancelMarket<'info>>) -> Result<()> {
+    invoke(ctx, instruction::CancelMarket.data())
+}
+
+pub fn claim_refund<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::ClaimRefund<'info>>) -> Result<()> {
+    invoke(ctx, instruction::ClaimRefund.data())
+}
+
+pub fn close_market<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::CloseMarket<'info>>) -> Result<()> {
+    invoke(ctx, instruction::CloseMarket.data())
+}
+
+pub fn compile_settlement_report<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::CompileSettlementReport<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::CompileSettlementReport.data())
+}
+
+pub fn emergency_void<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::EmergencyVoid<'info>>) -> Result<()> {
+    invoke(ctx, instruction::EmergencyVoid.data())
+}
+
+pub fn assert_solvency<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::AssertSolvency<'info>>) -> Result<()> {
+    invoke(ctx, instruction::AssertSolvency.data())
+}
+
+/// Returns the preview the market program set as its return data
+pub fn preview_settlement<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::PreviewSettlement<'info>>,
+    outcome: MatchOutcome,
+) -> Result<SettlementPreview> {
+    invoke(ctx, instruction::PreviewSettlement { outcome }.data())?;
+    match get_return_data() {
+        Some((program_id, data)) if program_id == crate::ID => Ok(SettlementPreview::try_from_slice(&data)?),
+        _ => Err(ErrorCode::InstructionDidNotSerialize.into()),
+    }
+}
+
+pub fn migrate_market<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::MigrateMarket<'info>>) -> Result<()> {
+    invoke(ctx, instruction::MigrateMarket.data())
+}
+
+pub fn migrate_participant<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::MigrateParticipant<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::MigrateParticipant.data())
+}
+
+pub fn stake_idle_pool<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::StakeIdlePool<'info>>,
+    min_pool_tokens: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::StakeIdlePool { min_pool_tokens }.data())
+}
+
+pub fn unwind_idle_pool<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::UnwindIdlePool<'info>>,
+    min_lamports: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::UnwindIdlePool { min_lamports }.data())
+}
+
+pub fn record_odds_sample<'info>(ctx: CpiContext<'_, '_, '_, 'info, accounts::RecordOddsSample<'info>>) -> Result<()> {
+    invoke(ctx, instruction::RecordOddsSample.data())
+}
+
+fn invoke<'info, T: ToAccountMetas + ToAccountInfos<'info>>(
+    ctx: CpiContext<'_, '_, '_, 'info, T>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts: ctx.to_account_metas(None),
+        data,
+    };
+
+    invoke_signed(&ix, &ctx.to_account_infos(), ctx.signer_seeds).map_err(Into::into)
+}
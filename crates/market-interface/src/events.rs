@@ -0,0 +1,356 @@
+//! Events emitted by the market program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::{HandicapStyle, MatchOutcome, PropKind, Scoreline, MAX_CO_ADMINS};
+
+#[event]
+pub struct PredictionMade {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub prediction: MatchOutcome,
+    pub timestamp: i64,
+    pub entry_index: u32,
+}
+
+#[event]
+pub struct MarketResolved {
+    #[index]
+    pub market: Pubkey,
+    pub outcome: MatchOutcome,
+    pub winner_count: u32,
+    pub total_pool: u64,
+}
+
+#[event]
+pub struct ResultAttested {
+    #[index]
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub outcome: MatchOutcome,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct ResultCorrected {
+    #[index]
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub resolved_outcome: MatchOutcome,
+    pub outcome: MatchOutcome,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub re_resolved: bool,
+}
+
+#[event]
+pub struct ResultBridged {
+    #[index]
+    pub market: Pubkey,
+    pub emitter_chain: u16,
+    pub sequence: u64,
+    pub outcome: MatchOutcome,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardRolled {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub follow_up: Pubkey,
+    pub user: Pubkey,
+    pub reward: u64,
+    pub entry_fee: u64,
+    pub streak: u32,
+}
+
+#[event]
+pub struct RewardSwapped {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub lamports: u64,
+    pub output_mint: Pubkey,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct ClaimReceiptMinted {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub prediction: MatchOutcome,
+    pub stake: u64,
+    pub payout: u64,
+}
+
+#[event]
+pub struct FeesDistributed {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub creator: Pubkey,
+    pub creator_fee: u64,
+    #[index]
+    pub platform: Pubkey,
+    pub platform_fee: u64,
+    pub total_fees: u64,
+}
+
+#[event]
+pub struct UnclaimedSwept {
+    #[index]
+    pub market: Pubkey,
+    pub platform: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MarketCancelled {
+    #[index]
+    pub market: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub total_pool: u64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SettlementReportCompiled {
+    #[index]
+    pub market: Pubkey,
+    pub total_in: u64,
+    pub fees_out: u64,
+    pub rewards_out: u64,
+    pub refunds_out: u64,
+    pub dust: u64,
+}
+
+#[event]
+pub struct MarketClosed {
+    #[index]
+    pub market: Pubkey,
+}
+
+#[event]
+pub struct SolvencyViolation {
+    #[index]
+    pub market: Pubkey,
+    pub available: u64,
+    pub liability: u64,
+}
+
+#[event]
+pub struct JoinQueued {
+    #[index]
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub prediction: MatchOutcome,
+    pub min_payout_bps: u64,
+}
+
+#[event]
+pub struct QueuedJoinRefunded {
+    #[index]
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PredictionCommitted {
+    #[index]
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoAdminsUpdated {
+    #[index]
+    pub market: Pubkey,
+    pub co_admins: [Pubkey; MAX_CO_ADMINS],
+}
+
+#[event]
+pub struct CopyJoined {
+    #[index]
+    pub market: Pubkey,
+    pub follower: Pubkey,
+    pub leader: Pubkey,
+    pub prediction: MatchOutcome,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ResolutionSigned {
+    #[index]
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub outcome: MatchOutcome,
+}
+
+#[event]
+pub struct ResolutionCoSigned {
+    #[index]
+    pub market: Pubkey,
+    pub outcome: MatchOutcome,
+    pub signers: [Pubkey; 2],
+}
+
+#[event]
+pub struct PoolStaked {
+    #[index]
+    pub market: Pubkey,
+    pub stake_pool: Pubkey,
+    pub lamports: u64,
+    pub pool_tokens: u64,
+}
+
+#[event]
+pub struct PoolUnwound {
+    #[index]
+    pub market: Pubkey,
+    pub staked: u64,
+    pub returned: u64,
+}
+
+#[event]
+pub struct WinnersRootPublished {
+    #[index]
+    pub market: Pubkey,
+    pub root: [u8; 32],
+    pub winners: u32,
+    pub reward: u64,
+}
+
+#[event]
+pub struct CharityPledged {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub charity: Pubkey,
+    pub pool_share_bps: u16,
+}
+
+#[event]
+pub struct CharityDonated {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub charity: Pubkey,
+    pub fees: u64,
+    pub pool_share: u64,
+    pub prize_pool: u64,
+}
+
+#[event]
+pub struct InPlayEnabled {
+    #[index]
+    pub market: Pubkey,
+    pub live_score: Pubkey,
+    pub lock_minute: u16,
+    pub lock_on_goal: bool,
+}
+
+#[event]
+pub struct PropPlayersListed {
+    #[index]
+    pub market: Pubkey,
+    pub kind: PropKind,
+    pub player_ids: Vec<u32>,
+}
+
+#[event]
+pub struct PropPicked {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub player_id: u32,
+}
+
+#[event]
+pub struct PropResolved {
+    #[index]
+    pub market: Pubkey,
+    pub kind: PropKind,
+    pub player_id: Option<u32>,
+    pub winner_count: u32,
+    pub total_pool: u64,
+}
+
+#[event]
+pub struct OutrightCheckpointed {
+    #[index]
+    pub market: Pubkey,
+    pub checkpoint: u32,
+    pub eliminated: Vec<u32>,
+    pub remaining: u32,
+}
+
+#[event]
+pub struct CorrectScoreEnabled {
+    #[index]
+    pub market: Pubkey,
+}
+
+#[event]
+pub struct ScorePicked {
+    #[index]
+    pub market: Pubkey,
+    #[index]
+    pub user: Pubkey,
+    pub scoreline: Scoreline,
+}
+
+#[event]
+pub struct CorrectScoreResolved {
+    #[index]
+    pub market: Pubkey,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub scoreline: Scoreline,
+    pub winner_count: u32,
+    pub total_pool: u64,
+}
+
+#[event]
+pub struct HandicapSet {
+    #[index]
+    pub market: Pubkey,
+    pub style: HandicapStyle,
+    pub home_line: i8,
+    pub away_line: i8,
+}
+
+#[event]
+pub struct HandicapResolved {
+    #[index]
+    pub market: Pubkey,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub home_line: i8,
+    pub outcome: Option<MatchOutcome>,
+}
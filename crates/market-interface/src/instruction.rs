@@ -0,0 +1,1139 @@
+//! Market instruction data and builders.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use cryptoscore_common::commitment::SALT_LEN;
+use cryptoscore_common::token_gate::TokenGateRule;
+use cryptoscore_common::{HandicapStyle, MatchOutcome, OutcomeSet, PropKind, Scoreline, MAX_CO_ADMINS};
+
+use crate::accounts;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeMarket {
+    pub match_id: String,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    pub is_public: bool,
+    pub outcome_set: OutcomeSet,
+    pub resolution_grace_seconds: i64,
+}
+
+impl Discriminator for InitializeMarket {
+    const DISCRIMINATOR: [u8; 8] = [35, 35, 189, 193, 155, 48, 170, 203];
+}
+
+impl InstructionData for InitializeMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JoinMarket {
+    pub prediction: MatchOutcome,
+    /// All zeros for no note
+    pub note_hash: [u8; 32],
+}
+
+impl Discriminator for JoinMarket {
+    const DISCRIMINATOR: [u8; 8] = [141, 113, 87, 152, 182, 213, 41, 202];
+}
+
+impl InstructionData for JoinMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ValidateJoin {
+    pub prediction: MatchOutcome,
+}
+
+impl Discriminator for ValidateJoin {
+    const DISCRIMINATOR: [u8; 8] = [174, 113, 187, 146, 18, 132, 143, 25];
+}
+
+impl InstructionData for ValidateJoin {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueJoin {
+    pub prediction: MatchOutcome,
+    pub min_payout_bps: u64,
+}
+
+impl Discriminator for QueueJoin {
+    const DISCRIMINATOR: [u8; 8] = [92, 200, 67, 181, 226, 164, 189, 56];
+}
+
+impl InstructionData for QueueJoin {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteQueuedJoin;
+
+impl Discriminator for ExecuteQueuedJoin {
+    const DISCRIMINATOR: [u8; 8] = [92, 232, 73, 33, 182, 4, 54, 219];
+}
+
+impl InstructionData for ExecuteQueuedJoin {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RefundQueuedJoin;
+
+impl Discriminator for RefundQueuedJoin {
+    const DISCRIMINATOR: [u8; 8] = [42, 229, 136, 10, 204, 113, 226, 126];
+}
+
+impl InstructionData for RefundQueuedJoin {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct Follow;
+
+impl Discriminator for Follow {
+    const DISCRIMINATOR: [u8; 8] = [161, 61, 150, 122, 164, 153, 0, 18];
+}
+
+impl InstructionData for Follow {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct Unfollow;
+
+impl Discriminator for Unfollow {
+    const DISCRIMINATOR: [u8; 8] = [122, 47, 24, 161, 12, 85, 224, 68];
+}
+
+impl InstructionData for Unfollow {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CopyJoin;
+
+impl Discriminator for CopyJoin {
+    const DISCRIMINATOR: [u8; 8] = [204, 185, 51, 53, 10, 81, 247, 40];
+}
+
+impl InstructionData for CopyJoin {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnableCommitReveal;
+
+impl Discriminator for EnableCommitReveal {
+    const DISCRIMINATOR: [u8; 8] = [64, 114, 138, 119, 161, 198, 150, 211];
+}
+
+impl InstructionData for EnableCommitReveal {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnableParticipantRoster;
+
+impl Discriminator for EnableParticipantRoster {
+    const DISCRIMINATOR: [u8; 8] = [90, 96, 126, 249, 145, 115, 61, 3];
+}
+
+impl InstructionData for EnableParticipantRoster {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PledgeToCharity {
+    pub charity: Pubkey,
+    pub pool_share_bps: u16,
+}
+
+impl Discriminator for PledgeToCharity {
+    const DISCRIMINATOR: [u8; 8] = [54, 251, 64, 152, 75, 60, 38, 42];
+}
+
+impl InstructionData for PledgeToCharity {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OfferFirstCorrectBonus {
+    pub bonus_bps: u16,
+}
+
+impl Discriminator for OfferFirstCorrectBonus {
+    const DISCRIMINATOR: [u8; 8] = [11, 18, 2, 192, 130, 182, 86, 111];
+}
+
+impl InstructionData for OfferFirstCorrectBonus {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetFollowUpMarket;
+
+impl Discriminator for SetFollowUpMarket {
+    const DISCRIMINATOR: [u8; 8] = [99, 236, 187, 244, 44, 19, 106, 58];
+}
+
+impl InstructionData for SetFollowUpMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnableInPlay {
+    pub lock_minute: u16,
+    pub lock_on_goal: bool,
+}
+
+impl Discriminator for EnableInPlay {
+    const DISCRIMINATOR: [u8; 8] = [5, 176, 19, 233, 238, 87, 196, 1];
+}
+
+impl InstructionData for EnableInPlay {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ListPropPlayers {
+    pub kind: PropKind,
+    pub player_ids: Vec<u32>,
+}
+
+impl Discriminator for ListPropPlayers {
+    const DISCRIMINATOR: [u8; 8] = [132, 239, 212, 19, 209, 203, 73, 0];
+}
+
+impl InstructionData for ListPropPlayers {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JoinProp {
+    pub player_id: u32,
+}
+
+impl Discriminator for JoinProp {
+    const DISCRIMINATOR: [u8; 8] = [67, 155, 234, 42, 171, 218, 176, 104];
+}
+
+impl InstructionData for JoinProp {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawPropRewards;
+
+impl Discriminator for WithdrawPropRewards {
+    const DISCRIMINATOR: [u8; 8] = [64, 51, 6, 61, 174, 32, 28, 90];
+}
+
+impl InstructionData for WithdrawPropRewards {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnableCorrectScore;
+
+impl Discriminator for EnableCorrectScore {
+    const DISCRIMINATOR: [u8; 8] = [31, 79, 204, 253, 157, 193, 166, 123];
+}
+
+impl InstructionData for EnableCorrectScore {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetHandicap {
+    pub style: HandicapStyle,
+    pub home_line: i8,
+    pub away_line: i8,
+}
+
+impl Discriminator for SetHandicap {
+    const DISCRIMINATOR: [u8; 8] = [138, 99, 35, 213, 192, 7, 59, 220];
+}
+
+impl InstructionData for SetHandicap {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JoinCorrectScore {
+    pub scoreline: Scoreline,
+}
+
+impl Discriminator for JoinCorrectScore {
+    const DISCRIMINATOR: [u8; 8] = [104, 26, 149, 185, 73, 164, 98, 67];
+}
+
+impl InstructionData for JoinCorrectScore {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawScoreRewards;
+
+impl Discriminator for WithdrawScoreRewards {
+    const DISCRIMINATOR: [u8; 8] = [159, 217, 239, 58, 166, 224, 250, 11];
+}
+
+impl InstructionData for WithdrawScoreRewards {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTokenGate {
+    pub rule: TokenGateRule,
+}
+
+impl Discriminator for SetTokenGate {
+    const DISCRIMINATOR: [u8; 8] = [181, 246, 120, 133, 255, 105, 150, 113];
+}
+
+impl InstructionData for SetTokenGate {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CommitPrediction {
+    pub commitment: [u8; 32],
+}
+
+impl Discriminator for CommitPrediction {
+    const DISCRIMINATOR: [u8; 8] = [92, 250, 182, 231, 10, 22, 234, 71];
+}
+
+impl InstructionData for CommitPrediction {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevealPrediction {
+    pub prediction: MatchOutcome,
+    pub salt: [u8; SALT_LEN],
+}
+
+impl Discriminator for RevealPrediction {
+    const DISCRIMINATOR: [u8; 8] = [76, 137, 127, 4, 163, 5, 110, 64];
+}
+
+impl InstructionData for RevealPrediction {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveMarket {
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+impl Discriminator for ResolveMarket {
+    const DISCRIMINATOR: [u8; 8] = [155, 23, 80, 173, 46, 74, 23, 239];
+}
+
+impl InstructionData for ResolveMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveWithAttestation {
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub attested_at: i64,
+}
+
+impl Discriminator for ResolveWithAttestation {
+    const DISCRIMINATOR: [u8; 8] = [46, 12, 117, 47, 238, 194, 34, 208];
+}
+
+impl InstructionData for ResolveWithAttestation {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveProp {
+    pub player_id: Option<u32>,
+}
+
+impl Discriminator for ResolveProp {
+    const DISCRIMINATOR: [u8; 8] = [62, 82, 43, 210, 71, 58, 83, 235];
+}
+
+impl InstructionData for ResolveProp {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecordOutrightCheckpoint {
+    pub eliminated: Vec<u32>,
+}
+
+impl Discriminator for RecordOutrightCheckpoint {
+    const DISCRIMINATOR: [u8; 8] = [19, 141, 219, 87, 216, 11, 58, 71];
+}
+
+impl InstructionData for RecordOutrightCheckpoint {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SettleOutrightEarly;
+
+impl Discriminator for SettleOutrightEarly {
+    const DISCRIMINATOR: [u8; 8] = [49, 170, 239, 217, 67, 168, 214, 79];
+}
+
+impl InstructionData for SettleOutrightEarly {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveWithVaa;
+
+impl Discriminator for ResolveWithVaa {
+    const DISCRIMINATOR: [u8; 8] = [213, 75, 178, 181, 187, 34, 192, 231];
+}
+
+impl InstructionData for ResolveWithVaa {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveWithLiveScore;
+
+impl Discriminator for ResolveWithLiveScore {
+    const DISCRIMINATOR: [u8; 8] = [141, 107, 134, 225, 198, 15, 4, 70];
+}
+
+impl InstructionData for ResolveWithLiveScore {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveCorrectScore;
+
+impl Discriminator for ResolveCorrectScore {
+    const DISCRIMINATOR: [u8; 8] = [238, 62, 220, 253, 237, 170, 154, 114];
+}
+
+impl InstructionData for ResolveCorrectScore {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveHandicap;
+
+impl Discriminator for ResolveHandicap {
+    const DISCRIMINATOR: [u8; 8] = [94, 189, 213, 199, 76, 139, 154, 60];
+}
+
+impl InstructionData for ResolveHandicap {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveMarketsBatch;
+
+impl Discriminator for ResolveMarketsBatch {
+    const DISCRIMINATOR: [u8; 8] = [198, 208, 163, 154, 195, 15, 198, 45];
+}
+
+impl InstructionData for ResolveMarketsBatch {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SignResolution {
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+impl Discriminator for SignResolution {
+    const DISCRIMINATOR: [u8; 8] = [87, 70, 168, 111, 103, 59, 11, 137];
+}
+
+impl InstructionData for SignResolution {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CoSignResolution {
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+impl Discriminator for CoSignResolution {
+    const DISCRIMINATOR: [u8; 8] = [123, 63, 93, 74, 240, 85, 93, 109];
+}
+
+impl InstructionData for CoSignResolution {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CorrectResult {
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+impl Discriminator for CorrectResult {
+    const DISCRIMINATOR: [u8; 8] = [64, 86, 97, 65, 175, 186, 85, 196];
+}
+
+impl InstructionData for CorrectResult {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SettleRebateEntry;
+
+impl Discriminator for SettleRebateEntry {
+    const DISCRIMINATOR: [u8; 8] = [184, 21, 74, 4, 240, 227, 157, 35];
+}
+
+impl InstructionData for SettleRebateEntry {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecordPendingClaim;
+
+impl Discriminator for RecordPendingClaim {
+    const DISCRIMINATOR: [u8; 8] = [57, 45, 187, 225, 26, 62, 21, 17];
+}
+
+impl InstructionData for RecordPendingClaim {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuildWinnersRoot;
+
+impl Discriminator for BuildWinnersRoot {
+    const DISCRIMINATOR: [u8; 8] = [181, 160, 232, 177, 221, 181, 56, 250];
+}
+
+impl InstructionData for BuildWinnersRoot {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawRewards;
+
+impl Discriminator for WithdrawRewards {
+    const DISCRIMINATOR: [u8; 8] = [10, 214, 219, 139, 205, 22, 251, 21];
+}
+
+impl InstructionData for WithdrawRewards {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RollRewards {
+    pub prediction: MatchOutcome,
+}
+
+impl Discriminator for RollRewards {
+    const DISCRIMINATOR: [u8; 8] = [244, 4, 21, 1, 95, 65, 152, 213];
+}
+
+impl InstructionData for RollRewards {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawRewardsSwapped {
+    pub route_data: Vec<u8>,
+    pub min_amount_out: u64,
+}
+
+impl Discriminator for WithdrawRewardsSwapped {
+    const DISCRIMINATOR: [u8; 8] = [106, 101, 2, 216, 167, 159, 52, 110];
+}
+
+impl InstructionData for WithdrawRewardsSwapped {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintClaimReceipt;
+
+impl Discriminator for MintClaimReceipt {
+    const DISCRIMINATOR: [u8; 8] = [16, 50, 176, 32, 168, 114, 13, 146];
+}
+
+impl InstructionData for MintClaimReceipt {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SweepUnclaimed;
+
+impl Discriminator for SweepUnclaimed {
+    const DISCRIMINATOR: [u8; 8] = [64, 168, 221, 224, 42, 216, 138, 144];
+}
+
+impl InstructionData for SweepUnclaimed {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCoAdmins {
+    pub co_admins: [Pubkey; MAX_CO_ADMINS],
+}
+
+impl Discriminator for SetCoAdmins {
+    const DISCRIMINATOR: [u8; 8] = [36, 76, 116, 215, 50, 231, 187, 35];
+}
+
+impl InstructionData for SetCoAdmins {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelMarket;
+
+impl Discriminator for CancelMarket {
+    const DISCRIMINATOR: [u8; 8] = [205, 121, 84, 210, 222, 71, 150, 11];
+}
+
+impl InstructionData for CancelMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimRefund;
+
+impl Discriminator for ClaimRefund {
+    const DISCRIMINATOR: [u8; 8] = [15, 16, 30, 161, 255, 228, 97, 60];
+}
+
+impl InstructionData for ClaimRefund {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseMarket;
+
+impl Discriminator for CloseMarket {
+    const DISCRIMINATOR: [u8; 8] = [88, 154, 248, 186, 48, 14, 123, 244];
+}
+
+impl InstructionData for CloseMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CompileSettlementReport;
+
+impl Discriminator for CompileSettlementReport {
+    const DISCRIMINATOR: [u8; 8] = [152, 191, 98, 151, 192, 65, 157, 2];
+}
+
+impl InstructionData for CompileSettlementReport {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EmergencyVoid;
+
+impl Discriminator for EmergencyVoid {
+    const DISCRIMINATOR: [u8; 8] = [108, 30, 145, 27, 141, 33, 106, 177];
+}
+
+impl InstructionData for EmergencyVoid {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AssertSolvency;
+
+impl Discriminator for AssertSolvency {
+    const DISCRIMINATOR: [u8; 8] = [62, 222, 126, 110, 240, 124, 81, 10];
+}
+
+impl InstructionData for AssertSolvency {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PreviewSettlement {
+    pub outcome: MatchOutcome,
+}
+
+impl Discriminator for PreviewSettlement {
+    const DISCRIMINATOR: [u8; 8] = [51, 200, 158, 79, 54, 226, 154, 248];
+}
+
+impl InstructionData for PreviewSettlement {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateMarket;
+
+impl Discriminator for MigrateMarket {
+    const DISCRIMINATOR: [u8; 8] = [201, 113, 181, 120, 217, 60, 109, 203];
+}
+
+impl InstructionData for MigrateMarket {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateParticipant;
+
+impl Discriminator for MigrateParticipant {
+    const DISCRIMINATOR: [u8; 8] = [184, 145, 178, 112, 190, 233, 158, 0];
+}
+
+impl InstructionData for MigrateParticipant {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StakeIdlePool {
+    pub min_pool_tokens: u64,
+}
+
+impl Discriminator for StakeIdlePool {
+    const DISCRIMINATOR: [u8; 8] = [67, 113, 174, 110, 233, 170, 9, 220];
+}
+
+impl InstructionData for StakeIdlePool {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnwindIdlePool {
+    pub min_lamports: u64,
+}
+
+impl Discriminator for UnwindIdlePool {
+    const DISCRIMINATOR: [u8; 8] = [223, 131, 212, 192, 125, 45, 6, 201];
+}
+
+impl InstructionData for UnwindIdlePool {}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecordOddsSample;
+
+impl Discriminator for RecordOddsSample {
+    const DISCRIMINATOR: [u8; 8] = [245, 98, 54, 53, 251, 233, 203, 234];
+}
+
+impl InstructionData for RecordOddsSample {}
+
+// Builders
+
+pub fn initialize_market(accounts: accounts::InitializeMarket, args: InitializeMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn join_market(accounts: accounts::JoinMarket, prediction: MatchOutcome, note_hash: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: JoinMarket { prediction, note_hash }.data(),
+    }
+}
+
+/// Simulate to learn why `join_market` would fail; the user need not sign
+pub fn validate_join(accounts: accounts::ValidateJoin, prediction: MatchOutcome) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ValidateJoin { prediction }.data(),
+    }
+}
+
+/// `min_payout_bps` is the smallest payout, in basis points of the entry fee,
+/// at which the join executes; 20_000 is 2.0x
+pub fn queue_join(accounts: accounts::QueueJoin, prediction: MatchOutcome, min_payout_bps: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: QueueJoin {
+            prediction,
+            min_payout_bps,
+        }
+        .data(),
+    }
+}
+
+pub fn execute_queued_join(accounts: accounts::ExecuteQueuedJoin) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ExecuteQueuedJoin.data(),
+    }
+}
+
+pub fn refund_queued_join(accounts: accounts::RefundQueuedJoin) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RefundQueuedJoin.data(),
+    }
+}
+
+pub fn follow(accounts: accounts::FollowLeader) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: Follow.data(),
+    }
+}
+
+pub fn unfollow(accounts: accounts::UnfollowLeader) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: Unfollow.data(),
+    }
+}
+
+pub fn copy_join(accounts: accounts::CopyJoin) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CopyJoin.data(),
+    }
+}
+
+pub fn enable_commit_reveal(accounts: accounts::EnableCommitReveal) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: EnableCommitReveal.data(),
+    }
+}
+
+pub fn enable_participant_roster(accounts: accounts::EnableParticipantRoster) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: EnableParticipantRoster.data(),
+    }
+}
+
+/// `pool_share_bps` is at most `cryptoscore_common::fees::MAX_CHARITY_POOL_SHARE_BPS`
+pub fn pledge_to_charity(accounts: accounts::PledgeToCharity, charity: Pubkey, pool_share_bps: u16) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: PledgeToCharity { charity, pool_share_bps }.data(),
+    }
+}
+
+/// `bonus_bps` is a share of the creator fee, from 1 to 10000
+pub fn offer_first_correct_bonus(accounts: accounts::OfferFirstCorrectBonus, bonus_bps: u16) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: OfferFirstCorrectBonus { bonus_bps }.data(),
+    }
+}
+
+pub fn set_follow_up_market(accounts: accounts::SetFollowUpMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetFollowUpMarket.data(),
+    }
+}
+
+pub fn enable_in_play(accounts: accounts::EnableInPlay, lock_minute: u16, lock_on_goal: bool) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: EnableInPlay { lock_minute, lock_on_goal }.data(),
+    }
+}
+
+/// `player_ids` are the oracle IDs of the players entries can pick, each
+/// listed once
+pub fn list_prop_players(accounts: accounts::ListPropPlayers, kind: PropKind, player_ids: Vec<u32>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ListPropPlayers { kind, player_ids }.data(),
+    }
+}
+
+pub fn join_prop(accounts: accounts::JoinProp, player_id: u32) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: JoinProp { player_id }.data(),
+    }
+}
+
+pub fn withdraw_prop_rewards(accounts: accounts::WithdrawPropRewards) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: WithdrawPropRewards.data(),
+    }
+}
+
+pub fn enable_correct_score(accounts: accounts::EnableCorrectScore) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: EnableCorrectScore.data(),
+    }
+}
+
+/// Lines are in half goals and must mirror each other, e.g. -3 and 3 for
+/// home -1.5; European lines are whole goals
+pub fn set_handicap(
+    accounts: accounts::SetHandicap,
+    style: HandicapStyle,
+    home_line: i8,
+    away_line: i8,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetHandicap {
+            style,
+            home_line,
+            away_line,
+        }
+        .data(),
+    }
+}
+
+/// Exact scorelines must be on the grid; pick `Scoreline::AnyOther` for the rest
+pub fn join_correct_score(accounts: accounts::JoinCorrectScore, scoreline: Scoreline) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: JoinCorrectScore { scoreline }.data(),
+    }
+}
+
+pub fn withdraw_score_rewards(accounts: accounts::WithdrawScoreRewards) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: WithdrawScoreRewards.data(),
+    }
+}
+
+pub fn set_token_gate(accounts: accounts::SetTokenGate, rule: TokenGateRule) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetTokenGate { rule }.data(),
+    }
+}
+
+/// `commitment` is `cryptoscore_common::commitment::prediction_commitment`
+/// of the prediction and a random salt kept for the reveal
+pub fn commit_prediction(accounts: accounts::CommitPrediction, commitment: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CommitPrediction { commitment }.data(),
+    }
+}
+
+pub fn reveal_prediction(
+    accounts: accounts::RevealPrediction,
+    prediction: MatchOutcome,
+    salt: [u8; SALT_LEN],
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RevealPrediction { prediction, salt }.data(),
+    }
+}
+
+/// The outcome is derived on-chain from the final score
+pub fn resolve_market(accounts: accounts::ResolveMarket, home_goals: u8, away_goals: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveMarket { home_goals, away_goals }.data(),
+    }
+}
+
+/// Must directly follow an ed25519 program instruction verifying the results
+/// signer's signature over `attestation_message(match_id, score, attested_at)`
+pub fn resolve_with_attestation(
+    accounts: accounts::ResolveWithAttestation,
+    home_goals: u8,
+    away_goals: u8,
+    attested_at: i64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveWithAttestation { home_goals, away_goals, attested_at }.data(),
+    }
+}
+
+/// `player_id` is `None` when no listed player did it, e.g. no goal was scored
+pub fn resolve_prop(accounts: accounts::ResolveProp, player_id: Option<u32>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveProp { player_id }.data(),
+    }
+}
+
+/// `eliminated` lists the team IDs that can no longer win as of this checkpoint
+pub fn record_outright_checkpoint(accounts: accounts::RecordOutrightCheckpoint, eliminated: Vec<u32>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RecordOutrightCheckpoint { eliminated }.data(),
+    }
+}
+
+pub fn settle_outright_early(accounts: accounts::SettleOutrightEarly) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SettleOutrightEarly.data(),
+    }
+}
+
+/// `posted_vaa` must already have been verified and posted by the factory's
+/// registered Wormhole core bridge
+pub fn resolve_with_vaa(accounts: accounts::ResolveWithVaa) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveWithVaa.data(),
+    }
+}
+
+/// The factory's results signer must have marked the live score final
+pub fn resolve_with_live_score(accounts: accounts::ResolveWithLiveScore) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveWithLiveScore.data(),
+    }
+}
+
+pub fn resolve_correct_score(accounts: accounts::ResolveCorrectScore) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveCorrectScore.data(),
+    }
+}
+
+pub fn resolve_handicap(accounts: accounts::ResolveHandicap) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveHandicap.data(),
+    }
+}
+
+pub fn resolve_markets_batch(accounts: accounts::ResolveMarketsBatch) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ResolveMarketsBatch.data(),
+    }
+}
+
+pub fn sign_resolution(accounts: accounts::SignResolution, home_goals: u8, away_goals: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SignResolution { home_goals, away_goals }.data(),
+    }
+}
+
+pub fn co_sign_resolution(accounts: accounts::CoSignResolution, home_goals: u8, away_goals: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CoSignResolution { home_goals, away_goals }.data(),
+    }
+}
+
+pub fn correct_result(accounts: accounts::CorrectResult, home_goals: u8, away_goals: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CorrectResult { home_goals, away_goals }.data(),
+    }
+}
+
+pub fn settle_rebate_entry(accounts: accounts::SettleRebateEntry) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SettleRebateEntry.data(),
+    }
+}
+
+pub fn record_pending_claim(accounts: accounts::RecordPendingClaim) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RecordPendingClaim.data(),
+    }
+}
+
+pub fn build_winners_root(accounts: accounts::BuildWinnersRoot) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: BuildWinnersRoot.data(),
+    }
+}
+
+pub fn withdraw_rewards(accounts: accounts::WithdrawRewards) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: WithdrawRewards.data(),
+    }
+}
+
+pub fn roll_rewards(accounts: accounts::RollRewards, prediction: MatchOutcome) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RollRewards { prediction }.data(),
+    }
+}
+
+pub fn withdraw_rewards_swapped(
+    accounts: accounts::WithdrawRewardsSwapped,
+    route_data: Vec<u8>,
+    min_amount_out: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: WithdrawRewardsSwapped { route_data, min_amount_out }.data(),
+    }
+}
+
+pub fn mint_claim_receipt(accounts: accounts::MintClaimReceipt) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: MintClaimReceipt.data(),
+    }
+}
+
+pub fn sweep_unclaimed(accounts: accounts::SweepUnclaimed) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SweepUnclaimed.data(),
+    }
+}
+
+pub fn set_co_admins(accounts: accounts::SetCoAdmins, co_admins: [Pubkey; MAX_CO_ADMINS]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: SetCoAdmins { co_admins }.data(),
+    }
+}
+
+pub fn cancel_market(accounts: accounts::CancelMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CancelMarket.data(),
+    }
+}
+
+pub fn claim_refund(accounts: accounts::ClaimRefund) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: ClaimRefund.data(),
+    }
+}
+
+pub fn close_market(accounts: accounts::CloseMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CloseMarket.data(),
+    }
+}
+
+pub fn compile_settlement_report(accounts: accounts::CompileSettlementReport) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: CompileSettlementReport.data(),
+    }
+}
+
+pub fn emergency_void(accounts: accounts::EmergencyVoid) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: EmergencyVoid.data(),
+    }
+}
+
+pub fn assert_solvency(accounts: accounts::AssertSolvency) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: AssertSolvency.data(),
+    }
+}
+
+/// Simulate it and read a `SettlementPreview` from the return data
+pub fn preview_settlement(accounts: accounts::PreviewSettlement, outcome: MatchOutcome) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: PreviewSettlement { outcome }.data(),
+    }
+}
+
+pub fn migrate_market(accounts: accounts::MigrateMarket) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: MigrateMarket.data(),
+    }
+}
+
+pub fn migrate_participant(accounts: accounts::MigrateParticipant) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: MigrateParticipant.data(),
+    }
+}
+
+pub fn stake_idle_pool(accounts: accounts::StakeIdlePool, min_pool_tokens: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: StakeIdlePool { min_pool_tokens }.data(),
+    }
+}
+
+pub fn unwind_idle_pool(accounts: accounts::UnwindIdlePool, min_lamports: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: UnwindIdlePool { min_lamports }.data(),
+    }
+}
+
+pub fn record_odds_sample(accounts: accounts::RecordOddsSample) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: RecordOddsSample.data(),
+    }
+}
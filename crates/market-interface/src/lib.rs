@@ -0,0 +1,28 @@
+//! Thin interface to the CryptoScore market program.
+//!
+//! Exposes the account layouts, events, instruction data, instruction builders,
+//! participant verification and (behind the `cpi` feature) a CPI client, so
+//! other programs and clients can integrate without depending on the full
+//! program crate.
+
+use anchor_lang::prelude::*;
+
+pub mod accounts;
+#[cfg(feature = "cpi")]
+pub mod cpi;
+pub mod events;
+pub mod instruction;
+pub mod state;
+pub mod verify;
+
+pub use cryptoscore_common::token_gate::TokenGateRule;
+pub use cryptoscore_common::{FinalScore, HandicapStyle, MarketStatus, MatchOutcome, OutcomeSet, PropKind, Scoreline};
+pub use state::{
+    CharityPledge, ClaimReceipt, CommitReveal, Commitment, EarliestEntry, FirstCorrectBonus, Follow, Handicap,
+    InPlayLock, Market, MarketChain, OddsHistory, OutrightStandings, Participant, ParticipantRoster, PropEntry,
+    PropRegistry, QueuedJoin, ResolutionVotes, RolledEntry, RosterPage, ScoreEntry, ScoreGrid, SettlementPreview,
+    SettlementReport, TokenGate, WinnersRoot, YieldPosition,
+};
+pub use verify::{verify_participant, VerifiedEntry};
+
+declare_id!("BJmMs142koLJvkutSzWchPGn2CJNGqTtGQV5g3Xt87PU");
@@ -0,0 +1,490 @@
+//! Market program account layouts, byte-for-byte with the program.
+
+use anchor_lang::prelude::*;
+use cryptoscore_common::merkle::WINNERS_TREE_DEPTH;
+use cryptoscore_common::token_gate::TokenGateRule;
+use cryptoscore_common::{
+    FinalScore, HandicapStyle, MarketStatus, MatchOutcome, OddsSample, OutcomeSet, PropKind, Scoreline, MAX_CO_ADMINS,
+    ODDS_HISTORY_SAMPLES, ROSTER_PAGE_SIZE, SCORE_GRID_BUCKETS,
+};
+
+#[account]
+pub struct Market {
+    /// Factory that created this market
+    pub factory: Pubkey,
+    /// Creator of the market
+    pub creator: Pubkey,
+    /// Match identifier
+    pub match_id: String,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    /// Match kickoff timestamp
+    pub kickoff_time: i64,
+    /// Match end timestamp
+    pub end_time: i64,
+    /// Current market status
+    pub status: MarketStatus,
+    /// Match outcome (if resolved)
+    pub outcome: Option<MatchOutcome>,
+    /// Total pool in lamports
+    pub total_pool: u64,
+    /// Total number of participants
+    pub participant_count: u32,
+    /// Number of HOME predictions
+    pub home_count: u32,
+    /// Number of DRAW predictions
+    pub draw_count: u32,
+    /// Number of AWAY predictions
+    pub away_count: u32,
+    /// Whether market is public
+    pub is_public: bool,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Creator and platform fees paid at resolution
+    pub fees_paid: u64,
+    /// Rewards, refunds and swept funds paid out of the pool
+    pub total_claimed: u64,
+    /// Users who can administer the market alongside the creator; unused
+    /// slots hold the default pubkey
+    pub co_admins: [Pubkey; MAX_CO_ADMINS],
+    /// Outcomes the market takes predictions on and resolves to
+    pub outcome_set: OutcomeSet,
+    /// Seconds after `end_time` during which only the creator can resolve
+    pub resolution_grace_seconds: i64,
+    /// Final score the market resolved from; unset for player prop markets
+    /// and markets resolved before scores were recorded
+    pub final_score: Option<FinalScore>,
+    /// Sum of every entry's join time, weighting refunds' share of staking
+    /// yield; zero for markets migrated from before it was tracked
+    pub joined_at_sum: i128,
+    /// Queued joins escrowed and not yet executed or refunded; zero for
+    /// markets migrated from before it was tracked
+    pub queued_join_count: u32,
+}
+
+#[account]
+pub struct Participant {
+    /// Market this participant joined
+    pub market: Pubkey,
+    /// User's wallet address
+    pub user: Pubkey,
+    /// User's prediction
+    pub prediction: MatchOutcome,
+    /// Timestamp when joined
+    pub joined_at: i64,
+    /// Whether rewards have been withdrawn
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Hash of the user's note on their prediction; all zeros when they left none
+    pub note_hash: [u8; 32],
+    /// Position of the entry among the market's entries, from 0; `u32::MAX`
+    /// for participants that joined before entries were indexed
+    pub entry_index: u32,
+}
+
+#[account]
+pub struct QueuedJoin {
+    /// Market to join
+    pub market: Pubkey,
+    /// User joining
+    pub user: Pubkey,
+    /// Outcome to join on
+    pub prediction: MatchOutcome,
+    /// Smallest acceptable payout in basis points of the entry fee
+    pub min_payout_bps: u64,
+    /// Entry fee escrowed in lamports
+    pub entry_fee: u64,
+    /// Participant account rent escrowed for whoever executes the join
+    pub participant_rent: u64,
+    /// Queueing timestamp
+    pub queued_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct CommitReveal {
+    /// Market taking committed predictions
+    pub market: Pubkey,
+    /// Predictions committed
+    pub commitments: u32,
+    /// Commitments revealed so far
+    pub reveals: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct TokenGate {
+    /// Market being gated
+    pub market: Pubkey,
+    /// What joiners must hold
+    pub rule: TokenGateRule,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ParticipantRoster {
+    /// Market whose participants are listed
+    pub market: Pubkey,
+    /// Participants listed across every page
+    pub count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct CharityPledge {
+    /// Market making the donation
+    pub market: Pubkey,
+    /// Charity receiving it at resolution
+    pub charity: Pubkey,
+    /// Share of the prize pool given on top of the fees, in basis points
+    pub pool_share_bps: u16,
+    /// Lamports given at resolution
+    pub donated: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Earliest entry on one outcome of a market offering a first-correct bonus
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EarliestEntry {
+    pub user: Pubkey,
+    pub entry_index: u32,
+}
+
+#[account]
+pub struct FirstCorrectBonus {
+    /// Market paying the bonus
+    pub market: Pubkey,
+    /// Share of the creator fee paid as the bonus, in basis points
+    pub bonus_bps: u16,
+    /// Earliest entry on home, draw and away so far
+    pub earliest: [Option<EarliestEntry>; 3],
+    /// Earliest entry on the winning outcome, set at resolution
+    pub winner: Option<Pubkey>,
+    /// Lamports set aside for the winner at resolution
+    pub bonus: u64,
+    /// Whether the winner has been paid the bonus
+    pub claimed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// How a market would settle on an outcome, returned by `preview_settlement`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SettlementPreview {
+    /// Outcome the preview assumes
+    pub outcome: MatchOutcome,
+    /// Participants who predicted it
+    pub winner_count: u32,
+    /// What each winner could withdraw
+    pub reward_per_winner: u64,
+    /// Creator fee, less any first-correct bonus
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+    /// Charity market's donation, in place of both fees
+    pub charity_donation: u64,
+    /// First-correct bonus taken out of the creator fee
+    pub first_correct_bonus: u64,
+    /// Prize pool left in the vault once every winner withdraws
+    pub dust: u64,
+}
+
+#[account]
+pub struct MarketChain {
+    /// Market whose winners roll over
+    pub market: Pubkey,
+    /// Market the rewards roll into
+    pub follow_up: Pubkey,
+    /// Rewards rolled into the follow-up so far
+    pub rolled: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct RolledEntry {
+    /// Market entered
+    pub market: Pubkey,
+    /// User who rolled their reward
+    pub user: Pubkey,
+    /// Market whose reward funded the entry
+    pub source_market: Pubkey,
+    /// Reward rolled in lamports, before the entry fee was staked from it
+    pub reward: u64,
+    /// Consecutive markets the user has rolled into, counting this one
+    pub streak: u32,
+    /// Timestamp of the roll
+    pub rolled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct InPlayLock {
+    /// Market taking joins in play
+    pub market: Pubkey,
+    /// Factory live score of the market's match
+    pub live_score: Pubkey,
+    /// Minute of play from which joins are refused
+    pub lock_minute: u16,
+    /// Whether the first goal also stops joins
+    pub lock_on_goal: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct PropRegistry {
+    /// Player prop market
+    pub market: Pubkey,
+    /// Player-level event the market settles on
+    pub kind: PropKind,
+    /// Oracle IDs of the players entries can pick
+    pub player_ids: Vec<u32>,
+    /// Entries on each listed player, in `player_ids` order
+    pub pick_counts: Vec<u32>,
+    /// Player the results signer reported at resolution, if any listed one
+    pub winning_player: Option<u32>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct OutrightStandings {
+    /// Outright market
+    pub market: Pubkey,
+    /// Whether each listed team can no longer win, in `player_ids` order
+    pub eliminated: Vec<bool>,
+    /// Checkpoints the results signer has recorded
+    pub checkpoint_count: u32,
+    /// When the latest checkpoint was recorded
+    pub last_checkpoint_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct PropEntry {
+    /// Prop market entered
+    pub market: Pubkey,
+    /// User who entered
+    pub user: Pubkey,
+    /// Oracle ID of the picked player
+    pub player_id: u32,
+    /// When the user entered
+    pub joined_at: i64,
+    /// Whether the user has withdrawn their reward or refund
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ScoreGrid {
+    /// Correct-score market
+    pub market: Pubkey,
+    /// Entries on each scoreline, indexed by `Scoreline::bucket`
+    pub pick_counts: [u32; SCORE_GRID_BUCKETS],
+    /// Scoreline the final score settled on, once resolved
+    pub result: Option<Scoreline>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Handicap {
+    /// Handicap market
+    pub market: Pubkey,
+    /// How a level result after the line settles
+    pub style: HandicapStyle,
+    /// Half goals added to the home side's score
+    pub home_line: i8,
+    /// Half goals added to the away side's score, mirroring `home_line`
+    pub away_line: i8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ScoreEntry {
+    /// Correct-score market entered
+    pub market: Pubkey,
+    /// User who entered
+    pub user: Pubkey,
+    /// Picked final score
+    pub scoreline: Scoreline,
+    /// When the user entered
+    pub joined_at: i64,
+    /// Whether the user has withdrawn their reward or refund
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct RosterPage {
+    /// Market whose participants are listed
+    pub market: Pubkey,
+    /// Page number, from 0
+    pub page: u32,
+    /// Participants in joining order; the first `len` slots are filled
+    pub users: [Pubkey; ROSTER_PAGE_SIZE],
+    /// Participants listed on this page
+    pub len: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Verify wins against `root` with `cryptoscore_common::merkle::verify_winner`,
+/// once `published`
+#[account]
+pub struct WinnersRoot {
+    /// Market whose winners are proven
+    pub market: Pubkey,
+    /// Reward every winner is owed, in lamports
+    pub reward: u64,
+    /// Roster entries folded in so far
+    pub cursor: u32,
+    /// Winners appended to the tree so far
+    pub winners: u32,
+    /// Last left node at each level of the tree
+    pub frontier: [[u8; 32]; WINNERS_TREE_DEPTH],
+    /// Root over every winner, set once published
+    pub root: [u8; 32],
+    /// Whether the whole roster has been folded in
+    pub published: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct Commitment {
+    /// Market entered
+    pub market: Pubkey,
+    /// User who committed
+    pub user: Pubkey,
+    /// `prediction_commitment` of the hidden prediction
+    pub commitment: [u8; 32],
+    /// Commitment timestamp
+    pub committed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Position of the entry among the market's entries, kept on reveal
+    pub entry_index: u32,
+}
+
+#[account]
+pub struct Follow {
+    /// User copying predictions
+    pub follower: Pubkey,
+    /// User whose predictions are copied
+    pub leader: Pubkey,
+    /// Following timestamp
+    pub followed_at: i64,
+    /// Markets joined by copying the leader
+    pub copies: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ResolutionVotes {
+    /// Market being resolved
+    pub market: Pubkey,
+    /// Score each of the creator, results signer and arbiter signed, if any
+    pub scores: [Option<FinalScore>; 3],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct YieldPosition {
+    /// Market whose pool was staked
+    pub market: Pubkey,
+    /// Stake pool holding it
+    pub stake_pool: Pubkey,
+    /// Liquid staking token received
+    pub pool_mint: Pubkey,
+    /// Lamports taken from the vault
+    pub staked: u64,
+    /// Pool tokens minted for them
+    pub pool_tokens: u64,
+    /// Staking timestamp
+    pub staked_at: i64,
+    /// Lamports returned to the vault on unwinding
+    pub returned: u64,
+    /// Unwinding timestamp, unset while the pool is staked
+    pub unwound_at: Option<i64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct OddsHistory {
+    /// Market sampled
+    pub market: Pubkey,
+    /// Samples in slot order; `head` is the oldest once the buffer is full
+    pub samples: [OddsSample; ODDS_HISTORY_SAMPLES],
+    /// Slot the next sample is written to
+    pub head: u8,
+    /// Samples recorded, up to `ODDS_HISTORY_SAMPLES`
+    pub len: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+pub struct ClaimReceipt {
+    /// Market the claim was made on
+    pub market: Pubkey,
+    /// Claiming user
+    pub user: Pubkey,
+    /// User's prediction
+    pub prediction: MatchOutcome,
+    /// Entry fee staked, in lamports
+    pub stake: u64,
+    /// Reward or refund withdrawn, in lamports
+    pub payout: u64,
+    /// Minting timestamp
+    pub minted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// `total_in` always equals `fees_out + rewards_out + refunds_out + dust`
+#[account]
+pub struct SettlementReport {
+    /// Market reported on
+    pub market: Pubkey,
+    /// Factory that created it
+    pub factory: Pubkey,
+    /// `Settled` once swept, or `Closed` once refunded
+    pub status: MarketStatus,
+    /// Participants who entered
+    pub participants: u32,
+    /// Winners the pool was split between
+    pub winners: u32,
+    /// Entry fees pooled, in lamports
+    pub total_in: u64,
+    /// Creator and platform fees, or the charity donation, paid at resolution
+    pub fees_out: u64,
+    /// Winners' equal shares, whether claimed or swept unclaimed
+    pub rewards_out: u64,
+    /// Entry fees refunded
+    pub refunds_out: u64,
+    /// Remainder of the equal split, swept with unclaimed rewards or left in the vault
+    pub dust: u64,
+    /// Rewards, refunds and sweeps paid out of the vault
+    pub paid_out: u64,
+    /// Lamports above rent still in the vault
+    pub vault_remaining: u64,
+    /// Compilation timestamp
+    pub compiled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -0,0 +1,69 @@
+//! Checks for other programs, e.g. tournaments, referrals or badges, that
+//! take a market and one of its participants as accounts and need to know
+//! the user really made their prediction and how the market resolved.
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use cryptoscore_common::{MarketStatus, MatchOutcome};
+
+use crate::{Market, Participant};
+
+/// A user's entry in a market, read from accounts the market program owns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedEntry {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub prediction: MatchOutcome,
+    pub joined_at: i64,
+    /// Position of the entry among the market's entries, for tiebreakers
+    pub entry_index: u32,
+    pub status: MarketStatus,
+    /// Outcome the market resolved to; player prop and correct-score markets leave it unset
+    pub outcome: Option<MatchOutcome>,
+}
+
+impl VerifiedEntry {
+    pub fn is_resolved(&self) -> bool {
+        self.status.is_resolved()
+    }
+
+    /// Whether the market resolved to the user's prediction
+    pub fn won(&self) -> bool {
+        self.is_resolved() && self.outcome.as_ref() == Some(&self.prediction)
+    }
+}
+
+/// Verify that `user` joined `market` through `participant`
+///
+/// Both accounts must be owned by the market program and carry its account
+/// discriminators, which only the program can write, so their addresses
+/// need not be derived; the participant must name the market and the user.
+pub fn verify_participant(market: &AccountInfo, participant: &AccountInfo, user: &Pubkey) -> Result<VerifiedEntry> {
+    let market_state: Market = read_owned(market)?;
+    let entry: Participant = read_owned(participant)?;
+
+    if entry.market != market.key() {
+        return Err(error!(ErrorCode::ConstraintHasOne).with_pubkeys((entry.market, market.key())));
+    }
+    if entry.user != *user {
+        return Err(error!(ErrorCode::ConstraintHasOne).with_pubkeys((entry.user, *user)));
+    }
+
+    Ok(VerifiedEntry {
+        market: market.key(),
+        user: entry.user,
+        prediction: entry.prediction,
+        joined_at: entry.joined_at,
+        entry_index: entry.entry_index,
+        status: market_state.status,
+        outcome: market_state.outcome,
+    })
+}
+
+/// Deserialize `account`, checking the market program owns it
+fn read_owned<T: AccountDeserialize>(account: &AccountInfo) -> Result<T> {
+    if *account.owner != crate::ID {
+        return Err(error!(ErrorCode::AccountOwnedByWrongProgram).with_pubkeys((*account.owner, crate::ID)));
+    }
+    T::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
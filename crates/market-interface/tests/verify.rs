@@ -0,0 +1,151 @@
+//! Participant verification for programs building on resolved markets.
+
+use anchor_lang::error::{Error, ErrorCode};
+use anchor_lang::prelude::*;
+use cryptoscore_common::{MarketStatus, MatchOutcome, OutcomeSet, MAX_CO_ADMINS};
+use cryptoscore_market_interface::{verify_participant, Market, Participant};
+
+fn market(status: MarketStatus, outcome: Option<MatchOutcome>) -> Vec<u8> {
+    let market = Market {
+        factory: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        match_id: "EPL-VERIFY".to_string(),
+        entry_fee: 100_000_000,
+        kickoff_time: 1_700_000_000,
+        end_time: 1_700_007_200,
+        status,
+        outcome,
+        total_pool: 200_000_000,
+        participant_count: 2,
+        home_count: 1,
+        draw_count: 0,
+        away_count: 1,
+        is_public: true,
+        bump: 255,
+        fees_paid: 0,
+        total_claimed: 0,
+        co_admins: [Pubkey::default(); MAX_CO_ADMINS],
+        outcome_set: OutcomeSet::WinDrawWin,
+        resolution_grace_seconds: 0,
+        final_score: None,
+        joined_at_sum: 0,
+        queued_join_count: 0,
+    };
+    let mut data = Vec::new();
+    market.try_serialize(&mut data).unwrap();
+    data
+}
+
+fn participant(market: Pubkey, user: Pubkey, prediction: MatchOutcome) -> Vec<u8> {
+    let participant = Participant {
+        market,
+        user,
+        prediction,
+        joined_at: 1_699_990_000,
+        has_withdrawn: false,
+        bump: 254,
+        note_hash: [0; 32],
+        entry_index: 3,
+    };
+    let mut data = Vec::new();
+    participant.try_serialize(&mut data).unwrap();
+    data
+}
+
+fn code(result: Result<impl std::fmt::Debug>) -> u32 {
+    match result.unwrap_err() {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => panic!("unexpected program error {:?}", error),
+    }
+}
+
+#[test]
+fn entries_report_the_prediction_and_how_the_market_resolved() {
+    let (market_key, participant_key, user) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let owner = cryptoscore_market_interface::ID;
+    let (mut market_lamports, mut participant_lamports) = (0, 0);
+    let mut market_data = market(MarketStatus::Resolved, Some(MatchOutcome::Home));
+    let mut participant_data = participant(market_key, user, MatchOutcome::Home);
+    let market_info =
+        AccountInfo::new(&market_key, false, false, &mut market_lamports, &mut market_data, &owner, false, 0);
+    let participant_info = AccountInfo::new(
+        &participant_key,
+        false,
+        false,
+        &mut participant_lamports,
+        &mut participant_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let entry = verify_participant(&market_info, &participant_info, &user).unwrap();
+    assert_eq!(entry.market, market_key);
+    assert_eq!(entry.prediction, MatchOutcome::Home);
+    assert_eq!(entry.entry_index, 3);
+    assert_eq!(entry.outcome, Some(MatchOutcome::Home));
+    assert!(entry.won());
+
+    // Someone else can't pass off the user's entry as their own
+    let result = verify_participant(&market_info, &participant_info, &Pubkey::new_unique());
+    assert_eq!(code(result), u32::from(ErrorCode::ConstraintHasOne));
+}
+
+#[test]
+fn open_and_cancelled_markets_have_no_winners() {
+    let (market_key, participant_key, user) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let owner = cryptoscore_market_interface::ID;
+    for status in [MarketStatus::Open, MarketStatus::Cancelled] {
+        let (mut market_lamports, mut participant_lamports) = (0, 0);
+        let mut market_data = market(status, Some(MatchOutcome::Away));
+        let mut participant_data = participant(market_key, user, MatchOutcome::Away);
+        let market_info =
+            AccountInfo::new(&market_key, false, false, &mut market_lamports, &mut market_data, &owner, false, 0);
+        let participant_info = AccountInfo::new(
+            &participant_key,
+            false,
+            false,
+            &mut participant_lamports,
+            &mut participant_data,
+            &owner,
+            false,
+            0,
+        );
+
+        let entry = verify_participant(&market_info, &participant_info, &user).unwrap();
+        assert!(!entry.is_resolved());
+        assert!(!entry.won());
+    }
+}
+
+#[test]
+fn accounts_must_be_the_market_programs_own() {
+    let (market_key, participant_key, user) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let owner = cryptoscore_market_interface::ID;
+    let forger = Pubkey::new_unique();
+    let (mut market_lamports, mut participant_lamports) = (0, 0);
+    let mut market_data = market(MarketStatus::Resolved, Some(MatchOutcome::Draw));
+    let mut participant_data = participant(market_key, user, MatchOutcome::Draw);
+    let forged_market =
+        AccountInfo::new(&market_key, false, false, &mut market_lamports, &mut market_data, &forger, false, 0);
+    let participant_info = AccountInfo::new(
+        &participant_key,
+        false,
+        false,
+        &mut participant_lamports,
+        &mut participant_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let result = verify_participant(&forged_market, &participant_info, &user);
+    assert_eq!(code(result), u32::from(ErrorCode::AccountOwnedByWrongProgram));
+
+    // A market passed where the participant belongs fails its discriminator
+    let (mut other_lamports, mut other_data) = (0, market(MarketStatus::Resolved, Some(MatchOutcome::Draw)));
+    let market_info =
+        AccountInfo::new(&market_key, false, false, &mut other_lamports, &mut other_data, &owner, false, 0);
+    let result = verify_participant(&market_info, &market_info, &user);
+    assert_eq!(code(result), u32::from(ErrorCode::AccountDiscriminatorMismatch));
+}
@@ -0,0 +1,1590 @@
+//! LiteSVM harness for cross-program lifecycle tests.
+//!
+//! Loads the factory, market, dashboard and governance programs built by `anchor build`
+//! from `target/deploy`, so run that before `cargo test -p cryptoscore-program-tests`.
+
+#![allow(clippy::result_large_err)]
+
+use std::path::PathBuf;
+
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, InstructionData, ToAccountMetas};
+use cryptoscore_common::attestation::{attestation_message, ed25519_instruction_data};
+use cryptoscore_common::commitment::{prediction_commitment, SALT_LEN};
+use cryptoscore_common::jury::{committed_slot_hash, draw_jurors, jury_seed, SlotHashLookup};
+use cryptoscore_common::pda::{
+    find_breakdown_entry_address, find_charity_pledge_address, find_court_address, find_creator_earnings_address,
+    find_daily_rollup_address, find_dispute_address, find_factory_address, find_governance_address,
+    find_head_to_head_address, find_head_to_head_market_address, find_in_play_lock_address, find_participant_address,
+    find_participant_roster_address, find_pending_claims_address, find_platform_activity_address,
+    find_platform_stats_address, find_points_ledger_address, find_points_snapshot_address,
+    find_points_total_snapshot_address, find_points_totals_address, find_rebate_tracker_address,
+    find_reputation_mint_address, find_stats_entry_address, find_user_breakdown_address, find_user_stats_address,
+    find_voucher_campaign_address, find_watchlist_address, find_winners_root_address,
+};
+use cryptoscore_common::reputation::{find_reputation_account_address, token_2022_amount, TOKEN_2022_PROGRAM_ID};
+use cryptoscore_common::tags::{encode_tag, TagCategory, Tags, NO_TAG, TAG_SLOTS};
+use cryptoscore_common::stake_pool::TOKEN_PROGRAM_ID;
+use cryptoscore_common::token_gate::{
+    find_metadata_address, metadata_data, token_account_data, TokenGateRule, ASSOCIATED_TOKEN_PROGRAM_ID,
+    METADATA_PROGRAM_ID,
+};
+use cryptoscore_common::wormhole::posted_vaa_data;
+use cryptoscore_common::{
+    FinalScore, HandicapStyle, MatchOutcome, MatchResult, OutcomeSet, PropKind, Scoreline, DASHBOARD_PROGRAM_ID,
+    MAX_CO_ADMINS, ROSTER_PAGE_SIZE,
+};
+use cryptoscore_factory_interface as factory;
+use cryptoscore_factory_interface::instruction::PlatformParam;
+use cryptoscore_governance_interface as governance;
+use cryptoscore_market_interface as market;
+use litesvm::types::{FailedTransactionMetadata, TransactionMetadata};
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::ed25519_program;
+use solana_sdk::hash::hashv;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::slot_hashes::{SlotHashes, MAX_ENTRIES};
+use solana_sdk::system_program;
+use solana_sdk::sysvar::slot_hashes;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub type TransactionResult = Result<TransactionMetadata, FailedTransactionMetadata>;
+
+/// Platform fee the factory is initialized with
+pub const PLATFORM_FEE_BPS: u16 = 300;
+
+/// A fresh validator with the factory and platform stats initialized
+pub struct TestEnv {
+    pub svm: LiteSVM,
+    /// Factory authority and default platform fee account
+    pub authority: Keypair,
+}
+
+impl TestEnv {
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        for (program_id, name) in [
+            (factory::ID, "cryptoscore_factory"),
+            (market::ID, "cryptoscore_market"),
+            (DASHBOARD_PROGRAM_ID, "cryptoscore_dashboard"),
+            (governance::ID, "cryptoscore_governance"),
+        ] {
+            let path = deploy_dir().join(format!("{}.so", name));
+            svm.add_program_from_file(program_id, &path)
+                .unwrap_or_else(|err| panic!("{} ({}); run `anchor build` first", path.display(), err));
+        }
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 100 * LAMPORTS_PER_SOL).unwrap();
+
+        let mut env = Self { svm, authority };
+        let authority = env.authority.pubkey();
+        let platform_stats = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::InitializePlatformStats {
+                platform_stats: find_platform_stats_address().0,
+                payer: authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::InitializePlatformStats {}.data(),
+        };
+        let factory = factory::instruction::initialize_factory(
+            factory::accounts::InitializeFactory::new(authority),
+            PLATFORM_FEE_BPS,
+        );
+        env.send_as_authority(&[factory, platform_stats]).unwrap();
+        env
+    }
+
+    /// A new wallet holding `sol` SOL
+    pub fn user(&mut self, sol: u64) -> Keypair {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), sol * LAMPORTS_PER_SOL).unwrap();
+        user
+    }
+
+    /// Sign and send `instructions` with `signer` as fee payer
+    pub fn send(&mut self, instructions: &[Instruction], signer: &Keypair) -> TransactionResult {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(transaction);
+        // Let an identical transaction be sent again later in the test
+        self.svm.expire_blockhash();
+        result
+    }
+
+    pub fn send_as_authority(&mut self, instructions: &[Instruction]) -> TransactionResult {
+        let authority = self.authority.insecure_clone();
+        self.send(instructions, &authority)
+    }
+
+    pub fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    /// Move the cluster clock to `unix_timestamp`
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// Move the cluster clock to `slot`, hashing the slots before it into the
+    /// slot hashes sysvar
+    pub fn warp_to_slot(&mut self, slot: u64) {
+        self.svm.warp_to_slot(slot);
+        let hashes: Vec<_> = (slot.saturating_sub(MAX_ENTRIES as u64)..slot)
+            .map(|past| (past, hashv(&[&past.to_le_bytes()])))
+            .collect();
+        self.svm.set_sysvar(&SlotHashes::new(&hashes));
+    }
+
+    pub fn lamports(&self, address: &Pubkey) -> u64 {
+        self.svm.get_account(address).map_or(0, |account| account.lamports)
+    }
+
+    /// Fetch and deserialize a program account, panicking if it's missing
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self
+            .svm
+            .get_account(address)
+            .unwrap_or_else(|| panic!("account {} not found", address));
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    /// Roster accounts listing `market`'s next participant: the page its
+    /// roster's count falls on, or the first page if it has no roster
+    pub fn roster_slot(&self, market: Pubkey) -> market::accounts::RosterSlot {
+        match self.svm.get_account(&find_participant_roster_address(&market).0) {
+            Some(account) => {
+                let roster = market::ParticipantRoster::try_deserialize(&mut &account.data[..]).unwrap();
+                market::accounts::RosterSlot::at_page(market, roster.count / ROSTER_PAGE_SIZE as u32)
+            }
+            None => market::accounts::RosterSlot::new(market),
+        }
+    }
+
+    /// Charity `market`'s resolution has to pay, if its creator pledged one
+    pub fn pledged_charity(&self, market: Pubkey) -> Option<Pubkey> {
+        self.svm.get_account(&find_charity_pledge_address(&market).0).map(|account| {
+            market::CharityPledge::try_deserialize(&mut &account.data[..]).unwrap().charity
+        })
+    }
+
+    /// Live score `market`'s in-play joins are checked against, if its creator enabled them
+    pub fn followed_live_score(&self, market: Pubkey) -> Option<Pubkey> {
+        self.svm.get_account(&find_in_play_lock_address(&market).0).map(|account| {
+            market::InPlayLock::try_deserialize(&mut &account.data[..]).unwrap().live_score
+        })
+    }
+
+    /// Register a market with the factory and initialize it, returning its address
+    pub fn create_market(
+        &mut self,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.create_tagged_market(creator, match_id, entry_fee, kickoff_time, end_time, [NO_TAG; TAG_SLOTS])
+    }
+
+    /// `create_market` for a market hidden from public listings
+    pub fn create_private_market(
+        &mut self,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.register_market(
+            find_factory_address().0,
+            creator,
+            match_id,
+            entry_fee,
+            kickoff_time,
+            end_time,
+            false,
+            OutcomeSet::WinDrawWin,
+            0,
+            [NO_TAG; TAG_SLOTS],
+        )
+    }
+
+    /// `create_market` for a sport without draws
+    pub fn create_win_lose_market(
+        &mut self,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.register_market(
+            find_factory_address().0,
+            creator,
+            match_id,
+            entry_fee,
+            kickoff_time,
+            end_time,
+            true,
+            OutcomeSet::WinLose,
+            0,
+            [NO_TAG; TAG_SLOTS],
+        )
+    }
+
+    /// `create_market` keeping resolution to the creator for `grace_seconds` after `end_time`
+    pub fn create_market_with_grace(
+        &mut self,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+        grace_seconds: i64,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.register_market(
+            find_factory_address().0,
+            creator,
+            match_id,
+            entry_fee,
+            kickoff_time,
+            end_time,
+            true,
+            OutcomeSet::WinDrawWin,
+            grace_seconds,
+            [NO_TAG; TAG_SLOTS],
+        )
+    }
+
+    /// `create_market` on an operator's platform factory instead of the default one
+    pub fn create_platform_market(
+        &mut self,
+        creator: &Keypair,
+        factory: Pubkey,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.register_market(
+            factory,
+            creator,
+            match_id,
+            entry_fee,
+            kickoff_time,
+            end_time,
+            true,
+            OutcomeSet::WinDrawWin,
+            0,
+            [NO_TAG; TAG_SLOTS],
+        )
+    }
+
+    /// `create_market` with discovery tags, passing each set slot's definition
+    pub fn create_tagged_market(
+        &mut self,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+        tags: Tags,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        self.register_market(
+            find_factory_address().0,
+            creator,
+            match_id,
+            entry_fee,
+            kickoff_time,
+            end_time,
+            true,
+            OutcomeSet::WinDrawWin,
+            0,
+            tags,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn register_market(
+        &mut self,
+        factory: Pubkey,
+        creator: &Keypair,
+        match_id: &str,
+        entry_fee: u64,
+        kickoff_time: i64,
+        end_time: i64,
+        is_public: bool,
+        outcome_set: OutcomeSet,
+        resolution_grace_seconds: i64,
+        tags: Tags,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        let accounts = market::accounts::InitializeMarket::new(factory, creator.pubkey(), match_id);
+        let address = accounts.market;
+
+        let register = factory::instruction::create_market(
+            factory::accounts::CreateMarket::on_factory(factory, creator.pubkey(), match_id, kickoff_time)
+                .tagged(&tags),
+            factory::instruction::CreateMarket {
+                match_id: match_id.to_string(),
+                entry_fee,
+                kickoff_time,
+                end_time,
+                is_public,
+                tags,
+            },
+        );
+        let initialize = market::instruction::initialize_market(
+            accounts,
+            market::instruction::InitializeMarket {
+                match_id: match_id.to_string(),
+                entry_fee,
+                kickoff_time,
+                end_time,
+                is_public,
+                outcome_set,
+                resolution_grace_seconds,
+            },
+        );
+        self.send(&[register, initialize], creator)?;
+        Ok(address)
+    }
+
+    /// List every `(match_id, kickoff_time, end_time)` fixture in one
+    /// `create_market_series` call, returning the market addresses in order
+    pub fn create_market_series(
+        &mut self,
+        creator: &Keypair,
+        fixtures: &[(&str, i64, i64)],
+        entry_fee: u64,
+    ) -> Result<Vec<Pubkey>, FailedTransactionMetadata> {
+        let accounts = factory::accounts::CreateMarketSeries::new(
+            creator.pubkey(),
+            fixtures.iter().map(|&(match_id, kickoff_time, _)| (match_id, kickoff_time)),
+        );
+        let markets = accounts.fixtures.iter().map(|(_, market, _)| *market).collect();
+
+        let ix = factory::instruction::create_market_series(
+            accounts,
+            factory::instruction::CreateMarketSeries {
+                fixtures: fixtures
+                    .iter()
+                    .map(|&(match_id, kickoff_time, end_time)| factory::instruction::SeriesFixture {
+                        match_id: match_id.to_string(),
+                        kickoff_time,
+                        end_time,
+                    })
+                    .collect(),
+                entry_fee,
+                is_public: true,
+                outcome_set: OutcomeSet::WinDrawWin,
+                resolution_grace_seconds: 0,
+                tags: [NO_TAG; TAG_SLOTS],
+            },
+        );
+        // Each market costs a registry, a market CPI and two stats CPIs
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        self.send(&[budget, ix], creator)?;
+        Ok(markets)
+    }
+
+    pub fn join(&mut self, user: &Keypair, market: Pubkey, prediction: MatchOutcome) -> TransactionResult {
+        self.join_with_note(user, market, prediction, [0; 32])
+    }
+
+    /// `join` attaching `note_hash` to the participant
+    pub fn join_with_note(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        note_hash: [u8; 32],
+    ) -> TransactionResult {
+        let factory = self.account::<market::Market>(&market).factory;
+        let defaults = market::accounts::JoinMarket::on_factory(factory, market, user.pubkey());
+        let accounts = market::accounts::JoinMarket {
+            live_score: self.followed_live_score(market).unwrap_or(defaults.live_score),
+            roster: self.roster_slot(market),
+            ..defaults
+        };
+        self.send(&[market::instruction::join_market(accounts, prediction, note_hash)], user)
+    }
+
+    pub fn migrate_participant(&mut self, payer: &Keypair, participant: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::MigrateParticipant::new(participant, payer.pubkey());
+        self.send(&[market::instruction::migrate_participant(accounts)], payer)
+    }
+
+    /// Join a token-gated market, passing `proof` as the holdings proof
+    pub fn gated_join(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        proof: Vec<Pubkey>,
+    ) -> TransactionResult {
+        let accounts = market::accounts::JoinMarket {
+            roster: self.roster_slot(market),
+            gate_proof: proof,
+            ..market::accounts::JoinMarket::new(market, user.pubkey())
+        };
+        self.send(&[market::instruction::join_market(accounts, prediction, [0; 32])], user)
+    }
+
+    /// Join a market with `user`'s voucher from campaign `campaign_id` paying the entry fee
+    pub fn voucher_join(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        campaign_id: u64,
+    ) -> TransactionResult {
+        let factory = self.account::<market::Market>(&market).factory;
+        let defaults = market::accounts::JoinMarket::on_factory(factory, market, user.pubkey())
+            .with_voucher(find_voucher_campaign_address(&factory, campaign_id).0);
+        let accounts = market::accounts::JoinMarket {
+            roster: self.roster_slot(market),
+            ..defaults
+        };
+        self.send(&[market::instruction::join_market(accounts, prediction, [0; 32])], user)
+    }
+
+    /// Check `user`'s join of `market` on `prediction` without joining
+    pub fn validate_join(&mut self, user: &Keypair, market: Pubkey, prediction: MatchOutcome) -> TransactionResult {
+        let defaults = market::accounts::ValidateJoin::new(market, user.pubkey());
+        let accounts = market::accounts::ValidateJoin {
+            live_score: self.followed_live_score(market).unwrap_or(defaults.live_score),
+            ..defaults
+        };
+        self.send(&[market::instruction::validate_join(accounts, prediction)], user)
+    }
+
+    /// How `market` would settle on `outcome`, read from `preview_settlement`'s return data
+    pub fn preview_settlement(
+        &mut self,
+        payer: &Keypair,
+        market: Pubkey,
+        outcome: MatchOutcome,
+    ) -> Result<market::SettlementPreview, FailedTransactionMetadata> {
+        let accounts = market::accounts::PreviewSettlement::new(market);
+        let meta = self.send(&[market::instruction::preview_settlement(accounts, outcome)], payer)?;
+        Ok(market::SettlementPreview::try_from_slice(&meta.return_data.data).unwrap())
+    }
+
+    pub fn queue_join(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        min_payout_bps: u64,
+    ) -> TransactionResult {
+        let accounts = market::accounts::QueueJoin::new(market, user.pubkey());
+        self.send(&[market::instruction::queue_join(accounts, prediction, min_payout_bps)], user)
+    }
+
+    pub fn follow(&mut self, follower: &Keypair, leader: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::FollowLeader::new(follower.pubkey(), leader);
+        self.send(&[market::instruction::follow(accounts)], follower)
+    }
+
+    pub fn unfollow(&mut self, follower: &Keypair, leader: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::UnfollowLeader::new(follower.pubkey(), leader);
+        self.send(&[market::instruction::unfollow(accounts)], follower)
+    }
+
+    pub fn copy_join(&mut self, user: &Keypair, market: Pubkey, leader: Pubkey) -> TransactionResult {
+        let defaults = market::accounts::CopyJoin::new(market, user.pubkey(), leader);
+        let accounts = market::accounts::CopyJoin {
+            live_score: self.followed_live_score(market).unwrap_or(defaults.live_score),
+            roster: self.roster_slot(market),
+            ..defaults
+        };
+        self.send(&[market::instruction::copy_join(accounts)], user)
+    }
+
+    pub fn enable_commit_reveal(&mut self, admin: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::EnableCommitReveal::new(market, admin.pubkey());
+        self.send(&[market::instruction::enable_commit_reveal(accounts)], admin)
+    }
+
+    pub fn enable_participant_roster(&mut self, admin: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::EnableParticipantRoster::new(market, admin.pubkey());
+        self.send(&[market::instruction::enable_participant_roster(accounts)], admin)
+    }
+
+    pub fn enable_in_play(
+        &mut self,
+        admin: &Keypair,
+        market: Pubkey,
+        lock_minute: u16,
+        lock_on_goal: bool,
+    ) -> TransactionResult {
+        let accounts = market::accounts::EnableInPlay::new(market, admin.pubkey());
+        self.send(&[market::instruction::enable_in_play(accounts, lock_minute, lock_on_goal)], admin)
+    }
+
+    pub fn pledge_to_charity(
+        &mut self,
+        admin: &Keypair,
+        market: Pubkey,
+        charity: Pubkey,
+        pool_share_bps: u16,
+    ) -> TransactionResult {
+        let accounts = market::accounts::PledgeToCharity::new(market, admin.pubkey());
+        self.send(&[market::instruction::pledge_to_charity(accounts, charity, pool_share_bps)], admin)
+    }
+
+    pub fn offer_first_correct_bonus(&mut self, admin: &Keypair, market: Pubkey, bonus_bps: u16) -> TransactionResult {
+        let accounts = market::accounts::OfferFirstCorrectBonus::new(market, admin.pubkey());
+        self.send(&[market::instruction::offer_first_correct_bonus(accounts, bonus_bps)], admin)
+    }
+
+    pub fn set_follow_up_market(&mut self, admin: &Keypair, market: Pubkey, follow_up: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::SetFollowUpMarket::new(market, follow_up, admin.pubkey());
+        self.send(&[market::instruction::set_follow_up_market(accounts)], admin)
+    }
+
+    pub fn set_token_gate(&mut self, admin: &Keypair, market: Pubkey, rule: TokenGateRule) -> TransactionResult {
+        let accounts = market::accounts::SetTokenGate::new(market, admin.pubkey());
+        self.send(&[market::instruction::set_token_gate(accounts, rule)], admin)
+    }
+
+    pub fn list_prop_players(
+        &mut self,
+        admin: &Keypair,
+        market: Pubkey,
+        kind: PropKind,
+        player_ids: Vec<u32>,
+    ) -> TransactionResult {
+        let accounts = market::accounts::ListPropPlayers::new(market, admin.pubkey());
+        self.send(&[market::instruction::list_prop_players(accounts, kind, player_ids)], admin)
+    }
+
+    pub fn join_prop(&mut self, user: &Keypair, market: Pubkey, player_id: u32) -> TransactionResult {
+        let defaults = market::accounts::JoinProp::new(market, user.pubkey());
+        let accounts = market::accounts::JoinProp {
+            live_score: self.followed_live_score(market).unwrap_or(defaults.live_score),
+            ..defaults
+        };
+        self.send(&[market::instruction::join_prop(accounts, player_id)], user)
+    }
+
+    pub fn enable_correct_score(&mut self, admin: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::EnableCorrectScore::new(market, admin.pubkey());
+        self.send(&[market::instruction::enable_correct_score(accounts)], admin)
+    }
+
+    pub fn join_correct_score(&mut self, user: &Keypair, market: Pubkey, scoreline: Scoreline) -> TransactionResult {
+        let defaults = market::accounts::JoinCorrectScore::new(market, user.pubkey());
+        let accounts = market::accounts::JoinCorrectScore {
+            live_score: self.followed_live_score(market).unwrap_or(defaults.live_score),
+            ..defaults
+        };
+        self.send(&[market::instruction::join_correct_score(accounts, scoreline)], user)
+    }
+
+    pub fn set_handicap(
+        &mut self,
+        admin: &Keypair,
+        market: Pubkey,
+        style: HandicapStyle,
+        home_line: i8,
+        away_line: i8,
+    ) -> TransactionResult {
+        let accounts = market::accounts::SetHandicap::new(market, admin.pubkey());
+        self.send(&[market::instruction::set_handicap(accounts, style, home_line, away_line)], admin)
+    }
+
+    /// A token account holding `amount` of `mint` for `owner`
+    pub fn token_account(&mut self, owner: Pubkey, mint: Pubkey, amount: u64) -> Pubkey {
+        let address = Pubkey::new_unique();
+        self.set_program_account(address, TOKEN_PROGRAM_ID, token_account_data(&mint, &owner, amount));
+        address
+    }
+
+    /// An NFT held by `owner` in `collection`, as its token account and metadata
+    pub fn nft(&mut self, owner: Pubkey, collection: Pubkey, verified: bool) -> (Pubkey, Pubkey) {
+        let mint = Pubkey::new_unique();
+        let token_account = self.token_account(owner, mint, 1);
+        let metadata = find_metadata_address(&mint).0;
+        self.set_program_account(metadata, METADATA_PROGRAM_ID, metadata_data(&mint, Some((collection, verified))));
+        (token_account, metadata)
+    }
+
+    pub fn commit_prediction(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: &[u8; SALT_LEN],
+    ) -> TransactionResult {
+        let accounts = market::accounts::CommitPrediction::new(market, user.pubkey());
+        let commitment = prediction_commitment(&market, &user.pubkey(), &prediction, salt);
+        self.send(&[market::instruction::commit_prediction(accounts, commitment)], user)
+    }
+
+    pub fn reveal_prediction(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> TransactionResult {
+        let accounts = market::accounts::RevealPrediction {
+            roster: self.roster_slot(market),
+            ..market::accounts::RevealPrediction::new(market, user.pubkey())
+        };
+        self.send(&[market::instruction::reveal_prediction(accounts, prediction, salt)], user)
+    }
+
+    pub fn record_odds_sample(&mut self, cranker: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::RecordOddsSample::new(market, cranker.pubkey());
+        self.send(&[market::instruction::record_odds_sample(accounts)], cranker)
+    }
+
+    /// Execute `user`'s queued join with `cranker` paying for the participant account
+    pub fn execute_queued_join(&mut self, cranker: &Keypair, market: Pubkey, user: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::ExecuteQueuedJoin {
+            roster: self.roster_slot(market),
+            ..market::accounts::ExecuteQueuedJoin::new(market, user, cranker.pubkey())
+        };
+        self.send(&[market::instruction::execute_queued_join(accounts)], cranker)
+    }
+
+    pub fn refund_queued_join(&mut self, signer: &Keypair, market: Pubkey, user: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::RefundQueuedJoin::new(market, user, signer.pubkey());
+        self.send(&[market::instruction::refund_queued_join(accounts)], signer)
+    }
+
+    /// Resolve from the final score as the creator or a participant, paying the platform fee to the
+    /// factory treasury
+    pub fn resolve(
+        &mut self,
+        resolver: &Keypair,
+        address: Pubkey,
+        (home_goals, away_goals): (u8, u8),
+    ) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let (creator, factory) = (market.creator, market.factory);
+        let mut accounts = if resolver.pubkey() == creator {
+            market::accounts::ResolveMarket::by_creator(address, creator, factory)
+        } else {
+            market::accounts::ResolveMarket::by_participant(address, resolver.pubkey(), creator, factory)
+        };
+        accounts.charity = self.pledged_charity(address);
+        self.send(&[market::instruction::resolve_market(accounts, home_goals, away_goals)], resolver)
+    }
+
+    /// Register `tag` for `category` with the factory
+    pub fn add_tag(&mut self, category: TagCategory, tag: &str) -> TransactionResult {
+        let tag = encode_tag(tag).expect("valid tag");
+        let ix = factory::instruction::add_tag(
+            factory::accounts::AddTag::new(self.authority.pubkey(), category, &tag),
+            category,
+            tag,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Register `platform_id` with `operator` as its operator, returning the platform's factory
+    pub fn create_platform(
+        &mut self,
+        operator: &Keypair,
+        platform_id: &str,
+        platform_fee_bps: u16,
+    ) -> Result<Pubkey, FailedTransactionMetadata> {
+        let accounts = factory::accounts::CreatePlatform::new(operator.pubkey(), platform_id);
+        let address = accounts.factory;
+        let ix = factory::instruction::create_platform(accounts, platform_id.to_string(), platform_fee_bps);
+        self.send(&[ix], operator)?;
+        Ok(address)
+    }
+
+    /// Make `signer` the factory's results signer
+    pub fn set_results_signer(&mut self, signer: Pubkey) -> TransactionResult {
+        let ix = factory::instruction::set_results_signer(
+            factory::accounts::SetResultsSigner::new(self.authority.pubkey()),
+            signer,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Accept results from the oracle at `emitter_address` on `emitter_chain`, as posted by `core_bridge`
+    pub fn set_wormhole_emitter(
+        &mut self,
+        core_bridge: Pubkey,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+    ) -> TransactionResult {
+        let ix = factory::instruction::set_wormhole_emitter(
+            factory::accounts::SetWormholeEmitter::new(self.authority.pubkey()),
+            core_bridge,
+            emitter_chain,
+            emitter_address,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Write a posted VAA account owned by `core_bridge` as if the bridge had verified the VAA
+    pub fn post_vaa(
+        &mut self,
+        core_bridge: Pubkey,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        payload: &[u8],
+    ) -> Pubkey {
+        let address = Pubkey::new_unique();
+        self.set_program_account(address, core_bridge, posted_vaa_data(emitter_chain, &emitter_address, 0, payload));
+        address
+    }
+
+    /// Write a rent-exempt account holding `data` owned by `owner`
+    fn set_program_account(&mut self, address: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(address, account).unwrap();
+    }
+
+    /// Require pools of at least `high_stakes_pool` lamports to be co-signed, with `arbiter` as the third key
+    pub fn set_resolution_policy(&mut self, high_stakes_pool: u64, arbiter: Pubkey) -> TransactionResult {
+        let ix = factory::instruction::set_resolution_policy(
+            factory::accounts::SetResolutionPolicy::new(self.authority.pubkey()),
+            high_stakes_pool,
+            arbiter,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Rebate `rebate_bps` of the next entry fee after `loss_streak` tracked losses, up to `epoch_cap` per epoch
+    pub fn set_rebate_policy(&mut self, loss_streak: u16, rebate_bps: u16, epoch_cap: u64) -> TransactionResult {
+        let ix = factory::instruction::set_rebate_policy(
+            factory::accounts::SetRebatePolicy::new(self.authority.pubkey()),
+            loss_streak,
+            rebate_bps,
+            epoch_cap,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Discount entry fees for users meeting one of `tiers`
+    pub fn set_discount_policy(&mut self, tiers: Vec<factory::DiscountTier>, epoch_cap: u64) -> TransactionResult {
+        let ix = factory::instruction::set_discount_policy(
+            factory::accounts::SetDiscountPolicy::new(self.authority.pubkey()),
+            tiers,
+            epoch_cap,
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    /// Tip `tip` lamports per permissionless crank, up to `market_budget` per market
+    pub fn set_keeper_tip_policy(&mut self, tip: u64, market_budget: u64) -> TransactionResult {
+        let accounts = factory::accounts::SetKeeperTipPolicy::new(self.authority.pubkey());
+        let ix = factory::instruction::set_keeper_tip_policy(accounts, tip, market_budget);
+        self.send_as_authority(&[ix])
+    }
+
+    /// Open voucher campaign `campaign_id`, covering entry fees of up to
+    /// `max_entry_fee` until `expires_at` from a budget of `budget` lamports
+    pub fn create_voucher_campaign(
+        &mut self,
+        campaign_id: u64,
+        budget: u64,
+        max_entry_fee: u64,
+        expires_at: i64,
+    ) -> TransactionResult {
+        let ix = factory::instruction::create_voucher_campaign(
+            factory::accounts::CreateVoucherCampaign::new(self.authority.pubkey(), campaign_id),
+            factory::instruction::CreateVoucherCampaign { campaign_id, budget, max_entry_fee, expires_at },
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn issue_voucher(&mut self, campaign_id: u64, holder: Pubkey) -> TransactionResult {
+        let accounts = factory::accounts::IssueVoucher::new(self.authority.pubkey(), campaign_id, holder);
+        self.send_as_authority(&[factory::instruction::issue_voucher(accounts, holder)])
+    }
+
+    /// Let winners swap their payouts into `mints`
+    pub fn set_payout_swap_policy(&mut self, mints: Vec<Pubkey>) -> TransactionResult {
+        let accounts = factory::accounts::SetPayoutSwapPolicy::new(self.authority.pubkey());
+        self.send_as_authority(&[factory::instruction::set_payout_swap_policy(accounts, mints)])
+    }
+
+    pub fn set_receipt_policy(&mut self, merkle_tree: Pubkey, uri_base: &str) -> TransactionResult {
+        let accounts = factory::accounts::SetReceiptPolicy::new(self.authority.pubkey());
+        let ix = factory::instruction::set_receipt_policy(accounts, merkle_tree, uri_base.to_string());
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn set_resolution_grace_policy(&mut self, min_grace_seconds: i64, max_grace_seconds: i64) -> TransactionResult {
+        let accounts = factory::accounts::SetResolutionGracePolicy::new(self.authority.pubkey());
+        let ix = factory::instruction::set_resolution_grace_policy(accounts, min_grace_seconds, max_grace_seconds);
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn set_match_id_policy(&mut self, allowed_characters: &str) -> TransactionResult {
+        let accounts = factory::accounts::SetMatchIdPolicy::new(self.authority.pubkey());
+        let ix = factory::instruction::set_match_id_policy(accounts, allowed_characters.to_string());
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn set_creation_rate_policy(&mut self, max_markets: u32, window_seconds: i64) -> TransactionResult {
+        let accounts = factory::accounts::SetCreationRatePolicy::new(self.authority.pubkey());
+        let ix = factory::instruction::set_creation_rate_policy(accounts, max_markets, window_seconds);
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn set_rake_free(&mut self, caller: &Keypair, market: Pubkey, rake_free: bool) -> TransactionResult {
+        let accounts = factory::accounts::SetRakeFree::new(caller.pubkey(), market);
+        self.send(&[factory::instruction::set_rake_free(accounts, rake_free)], caller)
+    }
+
+    /// Let `governor` change platform parameters alongside the factory authority
+    pub fn set_governor(&mut self, governor: Pubkey) -> TransactionResult {
+        let accounts = factory::accounts::SetGovernor::new(self.authority.pubkey());
+        let ix = factory::instruction::set_governor(accounts, governor);
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn set_platform_param(&mut self, caller: &Keypair, param: PlatformParam) -> TransactionResult {
+        let accounts = factory::accounts::SetPlatformParam::new(caller.pubkey());
+        self.send(&[factory::instruction::set_platform_param(accounts, param)], caller)
+    }
+
+    /// Put the factory under governance as its authority
+    pub fn initialize_governance(
+        &mut self,
+        voting_period: i64,
+        timelock: i64,
+        quorum_bps: u16,
+        min_proposal_stake: u64,
+    ) -> TransactionResult {
+        let ix = governance::instruction::initialize_governance(
+            governance::accounts::InitializeGovernance::new(self.authority.pubkey()),
+            governance::instruction::InitializeGovernance {
+                voting_period,
+                timelock,
+                quorum_bps,
+                min_proposal_stake,
+            },
+        );
+        self.send_as_authority(&[ix])
+    }
+
+    pub fn stake(&mut self, user: &Keypair, amount: u64) -> TransactionResult {
+        let ix = governance::instruction::stake(governance::accounts::Stake::new(user.pubkey()), amount);
+        self.send(&[ix], user)
+    }
+
+    pub fn unstake(&mut self, user: &Keypair, amount: u64) -> TransactionResult {
+        let ix = governance::instruction::unstake(governance::accounts::Unstake::new(user.pubkey()), amount);
+        self.send(&[ix], user)
+    }
+
+    /// Propose `param` as `proposer`, returning the proposal ID
+    pub fn create_proposal(
+        &mut self,
+        proposer: &Keypair,
+        param: PlatformParam,
+    ) -> Result<u64, FailedTransactionMetadata> {
+        let address = find_governance_address(&find_factory_address().0).0;
+        let id = self.account::<governance::Governance>(&address).proposal_count;
+        let accounts = governance::accounts::CreateProposal::new(proposer.pubkey(), id);
+        self.send(&[governance::instruction::create_proposal(accounts, param)], proposer)?;
+        Ok(id)
+    }
+
+    pub fn cast_vote(&mut self, voter: &Keypair, id: u64, approve: bool) -> TransactionResult {
+        let ix = governance::instruction::cast_vote(governance::accounts::CastVote::new(voter.pubkey(), id), approve);
+        self.send(&[ix], voter)
+    }
+
+    pub fn execute_proposal(&mut self, cranker: &Keypair, id: u64) -> TransactionResult {
+        let ix = governance::instruction::execute_proposal(governance::accounts::ExecuteProposal::new(id));
+        self.send(&[ix], cranker)
+    }
+
+    /// Set up the dispute court as the factory authority
+    pub fn initialize_court(&mut self, terms: governance::CourtTerms) -> TransactionResult {
+        let accounts = governance::accounts::InitializeCourt::new(self.authority.pubkey());
+        self.send_as_authority(&[governance::instruction::initialize_court(accounts, terms)])
+    }
+
+    pub fn join_court(&mut self, user: &Keypair, amount: u64) -> TransactionResult {
+        let ix = governance::instruction::join_court(governance::accounts::JoinCourt::new(user.pubkey()), amount);
+        self.send(&[ix], user)
+    }
+
+    pub fn leave_court(&mut self, user: &Keypair) -> TransactionResult {
+        let ix = governance::instruction::leave_court(governance::accounts::LeaveCourt::new(user.pubkey()));
+        self.send(&[ix], user)
+    }
+
+    pub fn open_dispute(&mut self, disputer: &Keypair, market: Pubkey, claimed: MatchOutcome) -> TransactionResult {
+        let accounts = governance::accounts::OpenDispute::new(disputer.pubkey(), market);
+        self.send(&[governance::instruction::open_dispute(accounts, claimed)], disputer)
+    }
+
+    /// Draw `market`'s jury from the slot hash its dispute committed to,
+    /// passing the arbiter accounts the same draw picks off-chain and
+    /// returning the jurors
+    pub fn draw_jury(&mut self, cranker: &Keypair, market: Pubkey) -> Result<Vec<Pubkey>, FailedTransactionMetadata> {
+        let court_address = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        let court = self.account::<governance::Court>(&court_address);
+        let dispute_address = find_dispute_address(&court_address, &market).0;
+        let dispute = self.account::<governance::Dispute>(&dispute_address);
+        let slot_hashes = self.svm.get_account(&slot_hashes::ID).map(|account| account.data).unwrap_or_default();
+        let jurors = match committed_slot_hash(&slot_hashes, dispute.draw_slot) {
+            SlotHashLookup::Hash(hash) => {
+                let seed = jury_seed(&hash, &dispute_address);
+                draw_jurors(&court.arbiters, &dispute.disputer, court.terms.jury_size as usize, &seed)
+                    .unwrap_or_default()
+            }
+            SlotHashLookup::Pending | SlotHashLookup::Expired => Vec::new(),
+        };
+        let accounts = governance::accounts::DrawJury::new(market, &jurors);
+        self.send(&[governance::instruction::draw_jury(accounts)], cranker)?;
+        Ok(jurors)
+    }
+
+    pub fn cast_juror_vote(&mut self, juror: &Keypair, market: Pubkey, outcome: MatchOutcome) -> TransactionResult {
+        let accounts = governance::accounts::CastJurorVote::new(juror.pubkey(), market);
+        self.send(&[governance::instruction::cast_juror_vote(accounts, outcome)], juror)
+    }
+
+    pub fn settle_dispute(&mut self, cranker: &Keypair, market: Pubkey) -> TransactionResult {
+        let court_address = find_court_address(&find_governance_address(&find_factory_address().0).0).0;
+        let dispute = self.account::<governance::Dispute>(&find_dispute_address(&court_address, &market).0);
+        let accounts =
+            governance::accounts::SettleDispute::new(cranker.pubkey(), market, dispute.disputer, &dispute.jurors);
+        self.send(&[governance::instruction::settle_dispute(accounts)], cranker)
+    }
+
+    pub fn publish_results(&mut self, signer: &Keypair, batch_id: u64, results: Vec<MatchResult>) -> TransactionResult {
+        let accounts = factory::accounts::PublishResults::new(signer.pubkey(), batch_id);
+        self.send(&[factory::instruction::publish_results(accounts, batch_id, results)], signer)
+    }
+
+    /// Resolve `markets` from results batch `batch_id`, with `relayer` paying for the ledgers
+    pub fn resolve_markets_batch(&mut self, relayer: &Keypair, batch_id: u64, markets: &[Pubkey]) -> TransactionResult {
+        let markets: Vec<_> = markets
+            .iter()
+            .map(|address| (*address, self.account::<market::Market>(address).creator))
+            .collect();
+        let accounts =
+            market::accounts::ResolveMarketsBatch::new(find_factory_address().0, batch_id, relayer.pubkey(), markets);
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        self.send(&[budget, market::instruction::resolve_markets_batch(accounts)], relayer)
+    }
+
+    pub fn sign_resolution(
+        &mut self,
+        signer: &Keypair,
+        address: Pubkey,
+        (home_goals, away_goals): (u8, u8),
+    ) -> TransactionResult {
+        let factory = self.account::<market::Market>(&address).factory;
+        let accounts = market::accounts::SignResolution::new(address, signer.pubkey(), factory);
+        self.send(&[market::instruction::sign_resolution(accounts, home_goals, away_goals)], signer)
+    }
+
+    pub fn co_sign_resolution(
+        &mut self,
+        co_signer: &Keypair,
+        address: Pubkey,
+        (home_goals, away_goals): (u8, u8),
+    ) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts =
+            market::accounts::CoSignResolution::new(address, co_signer.pubkey(), market.creator, market.factory);
+        self.send(&[market::instruction::co_sign_resolution(accounts, home_goals, away_goals)], co_signer)
+    }
+
+    /// Correct the final score of a resolved market as the results signer `signer`
+    pub fn correct_result(
+        &mut self,
+        signer: &Keypair,
+        address: Pubkey,
+        (home_goals, away_goals): (u8, u8),
+    ) -> TransactionResult {
+        let factory = self.account::<market::Market>(&address).factory;
+        let accounts = market::accounts::CorrectResult::new(address, factory, signer.pubkey());
+        self.send(&[market::instruction::correct_result(accounts, home_goals, away_goals)], signer)
+    }
+
+    pub fn withdraw_treasury(&mut self, authority: &Keypair, recipient: Pubkey, amount: u64) -> TransactionResult {
+        let ix = factory::instruction::withdraw_treasury(
+            factory::accounts::WithdrawTreasury::new(authority.pubkey(), recipient),
+            amount,
+        );
+        self.send(&[ix], authority)
+    }
+
+    pub fn rule_misresolution(
+        &mut self,
+        authority: &Keypair,
+        market: Pubkey,
+        correct_outcome: MatchOutcome,
+        claim_cap: u64,
+        payout_cap: u64,
+    ) -> TransactionResult {
+        let ix = factory::instruction::rule_misresolution(
+            factory::accounts::RuleMisresolution::new(authority.pubkey(), authority.pubkey(), market),
+            factory::instruction::RuleMisresolution {
+                correct_outcome,
+                claim_cap,
+                payout_cap,
+            },
+        );
+        self.send(&[ix], authority)
+    }
+
+    pub fn claim_insurance(&mut self, claimant: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = factory::accounts::ClaimInsurance::new(claimant.pubkey(), market);
+        let ix = factory::instruction::insurance_claim(accounts);
+        self.send(&[ix], claimant)
+    }
+
+    /// The ed25519 check of `results_signer`'s attestation and the resolution
+    /// it authorizes, relayed by `relayer`
+    pub fn attestation(
+        &self,
+        relayer: &Keypair,
+        results_signer: &Keypair,
+        address: Pubkey,
+        (home_goals, away_goals): (u8, u8),
+        attested_at: i64,
+    ) -> [Instruction; 2] {
+        let market = self.account::<market::Market>(&address);
+        let message = attestation_message(&market.match_id, &FinalScore::new(home_goals, away_goals), attested_at);
+        let signature = results_signer.sign_message(&message).into();
+        let verify = Instruction {
+            program_id: ed25519_program::id(),
+            accounts: vec![],
+            data: ed25519_instruction_data(&results_signer.pubkey(), &signature, &message),
+        };
+        let accounts = market::accounts::ResolveWithAttestation::new(
+            address,
+            relayer.pubkey(),
+            market.creator,
+            market.factory,
+        );
+        [verify, market::instruction::resolve_with_attestation(accounts, home_goals, away_goals, attested_at)]
+    }
+
+    pub fn resolve_with_vaa(&mut self, relayer: &Keypair, address: Pubkey, posted_vaa: Pubkey) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::ResolveWithVaa::new(
+            address,
+            relayer.pubkey(),
+            market.creator,
+            market.factory,
+            posted_vaa,
+        );
+        self.send(&[market::instruction::resolve_with_vaa(accounts)], relayer)
+    }
+
+    /// Report `match_id`'s score at `minute`, signing as the factory's results signer
+    pub fn update_live_score(
+        &mut self,
+        signer: &Keypair,
+        match_id: &str,
+        (home_goals, away_goals): (u8, u8),
+        minute: u16,
+        is_final: bool,
+    ) -> TransactionResult {
+        let accounts = factory::accounts::UpdateLiveScore::new(signer.pubkey(), match_id);
+        let ix = factory::instruction::update_live_score(
+            accounts,
+            match_id.to_string(),
+            home_goals,
+            away_goals,
+            minute,
+            is_final,
+        );
+        self.send(&[ix], signer)
+    }
+
+    pub fn resolve_with_live_score(&mut self, relayer: &Keypair, address: Pubkey) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::ResolveWithLiveScore::new(
+            address,
+            relayer.pubkey(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        self.send(&[market::instruction::resolve_with_live_score(accounts)], relayer)
+    }
+
+    pub fn resolve_prop(&mut self, signer: &Keypair, address: Pubkey, player_id: Option<u32>) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::ResolveProp::new(address, signer.pubkey(), market.creator, market.factory);
+        self.send(&[market::instruction::resolve_prop(accounts, player_id)], signer)
+    }
+
+    pub fn record_outright_checkpoint(
+        &mut self,
+        signer: &Keypair,
+        address: Pubkey,
+        eliminated: Vec<u32>,
+    ) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::RecordOutrightCheckpoint::new(address, signer.pubkey(), market.factory);
+        self.send(&[market::instruction::record_outright_checkpoint(accounts, eliminated)], signer)
+    }
+
+    pub fn settle_outright_early(&mut self, relayer: &Keypair, address: Pubkey) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts =
+            market::accounts::SettleOutrightEarly::new(address, relayer.pubkey(), market.creator, market.factory);
+        self.send(&[market::instruction::settle_outright_early(accounts)], relayer)
+    }
+
+    pub fn resolve_correct_score(&mut self, relayer: &Keypair, address: Pubkey) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::ResolveCorrectScore::new(
+            address,
+            relayer.pubkey(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        self.send(&[market::instruction::resolve_correct_score(accounts)], relayer)
+    }
+
+    pub fn resolve_handicap(&mut self, relayer: &Keypair, address: Pubkey) -> TransactionResult {
+        let market = self.account::<market::Market>(&address);
+        let accounts = market::accounts::ResolveHandicap::new(
+            address,
+            relayer.pubkey(),
+            market.creator,
+            market.factory,
+            &market.match_id,
+        );
+        self.send(&[market::instruction::resolve_handicap(accounts)], relayer)
+    }
+
+    /// Fold `users`, the next entries of `market`'s roster, into its winners root
+    pub fn build_winners_root(&mut self, cranker: &Keypair, market: Pubkey, users: &[Pubkey]) -> TransactionResult {
+        let cursor = self.svm.get_account(&find_winners_root_address(&market).0).map_or(0, |account| {
+            market::WinnersRoot::try_deserialize(&mut &account.data[..]).unwrap().cursor
+        });
+        let accounts = market::accounts::BuildWinnersRoot::new(market, cranker.pubkey(), cursor, users);
+        self.send(&[market::instruction::build_winners_root(accounts)], cranker)
+    }
+
+    pub fn withdraw(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = market::instruction::withdraw_rewards(market::accounts::WithdrawRewards::new(market, user.pubkey()));
+        self.send(&[ix], user)
+    }
+
+    /// Roll `user`'s reward on `market` into `follow_up` as an entry on `prediction`
+    pub fn roll_rewards(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        follow_up: Pubkey,
+        prediction: MatchOutcome,
+    ) -> TransactionResult {
+        let accounts = market::accounts::RollRewards {
+            roster: self.roster_slot(follow_up),
+            ..market::accounts::RollRewards::new(market, follow_up, user.pubkey())
+        };
+        self.send(&[market::instruction::roll_rewards(accounts, prediction)], user)
+    }
+
+    /// `withdraw_rewards_swapped` from `wrapped_sol` into `output_tokens` with
+    /// an empty route, so it fails at the swap once every check has passed
+    pub fn withdraw_swapped(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        wrapped_sol: Pubkey,
+        output_tokens: Pubkey,
+    ) -> TransactionResult {
+        let factory = find_factory_address().0;
+        let accounts = market::accounts::WithdrawRewardsSwapped {
+            wrapped_sol,
+            output_tokens,
+            ..market::accounts::WithdrawRewardsSwapped::new(market, factory, user.pubkey(), &Pubkey::default(), vec![])
+        };
+        self.send(&[market::instruction::withdraw_rewards_swapped(accounts, vec![], 1)], user)
+    }
+
+    /// `mint_claim_receipt` into `merkle_tree`; no Bubblegum program is
+    /// loaded, so it fails at the mint once every check has passed
+    pub fn mint_claim_receipt(&mut self, user: &Keypair, market: Pubkey, merkle_tree: Pubkey) -> TransactionResult {
+        let factory = find_factory_address().0;
+        let accounts = market::accounts::MintClaimReceipt::new(market, factory, user.pubkey(), merkle_tree);
+        self.send(&[market::instruction::mint_claim_receipt(accounts)], user)
+    }
+
+    pub fn withdraw_prop(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::WithdrawPropRewards::new(market, user.pubkey());
+        self.send(&[market::instruction::withdraw_prop_rewards(accounts)], user)
+    }
+
+    pub fn withdraw_score(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::WithdrawScoreRewards::new(market, user.pubkey());
+        self.send(&[market::instruction::withdraw_score_rewards(accounts)], user)
+    }
+
+    pub fn settle_rebate_entry(&mut self, cranker: &Keypair, market: Pubkey, user: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::SettleRebateEntry::new(market, user, cranker.pubkey());
+        self.send(&[market::instruction::settle_rebate_entry(accounts)], cranker)
+    }
+
+    pub fn record_pending_claim(&mut self, cranker: &Keypair, market: Pubkey, user: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::RecordPendingClaim::new(market, user, cranker.pubkey());
+        self.send(&[market::instruction::record_pending_claim(accounts)], cranker)
+    }
+
+    pub fn sweep(&mut self, signer: &Keypair, market: Pubkey) -> TransactionResult {
+        let (factory, platform) = (find_factory_address().0, self.authority.pubkey());
+        let accounts = market::accounts::SweepUnclaimed::new(market, factory, signer.pubkey(), platform);
+        self.send(&[market::instruction::sweep_unclaimed(accounts)], signer)
+    }
+
+    pub fn set_co_admins(
+        &mut self,
+        creator: &Keypair,
+        market: Pubkey,
+        co_admins: [Pubkey; MAX_CO_ADMINS],
+    ) -> TransactionResult {
+        let accounts = market::accounts::SetCoAdmins {
+            market,
+            creator: creator.pubkey(),
+        };
+        self.send(&[market::instruction::set_co_admins(accounts, co_admins)], creator)
+    }
+
+    pub fn cancel(&mut self, signer: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::CancelMarket::new(market, find_factory_address().0, signer.pubkey());
+        self.send(&[market::instruction::cancel_market(accounts)], signer)
+    }
+
+    pub fn void(&mut self, signer: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::EmergencyVoid::new(market, find_factory_address().0, signer.pubkey());
+        self.send(&[market::instruction::emergency_void(accounts)], signer)
+    }
+
+    pub fn refund(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = market::instruction::claim_refund(market::accounts::ClaimRefund::new(market, user.pubkey()));
+        self.send(&[ix], user)
+    }
+
+    pub fn close(&mut self, signer: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = market::instruction::close_market(market::accounts::CloseMarket::new(market));
+        self.send(&[ix], signer)
+    }
+
+    pub fn compile_settlement_report(&mut self, cranker: &Keypair, market: Pubkey) -> TransactionResult {
+        let accounts = market::accounts::CompileSettlementReport::new(market, cranker.pubkey());
+        self.send(&[market::instruction::compile_settlement_report(accounts)], cranker)
+    }
+
+    /// Record `user`'s entry in a settled `market` in their dashboard stats
+    pub fn record_result(&mut self, cranker: &Keypair, user: Pubkey, market: Pubkey) -> TransactionResult {
+        let factory = self.account::<market::Market>(&market).factory;
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::UpdateUserStats {
+                user_stats: find_user_stats_address(&user).0,
+                stats_entry: find_stats_entry_address(&market, &user).0,
+                factory,
+                market,
+                participant: find_participant_address(&market, &user).0,
+                user,
+                payer: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::UpdateUserStats {}.data(),
+        };
+        self.send(&[ix], cranker)
+    }
+
+    /// Record `user`'s entry in a resolved `market` in their accuracy breakdown, cranked by `cranker`
+    pub fn record_breakdown(&mut self, cranker: &Keypair, user: Pubkey, market: Pubkey) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::UpdateUserBreakdown {
+                user_breakdown: find_user_breakdown_address(&user).0,
+                breakdown_entry: find_breakdown_entry_address(&market, &user).0,
+                market,
+                participant: find_participant_address(&market, &user).0,
+                user,
+                payer: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::UpdateUserBreakdown {}.data(),
+        };
+        self.send(&[ix], cranker)
+    }
+
+    /// Record a resolved `market` both users entered in their head-to-head record, cranked by `cranker`
+    pub fn record_head_to_head(
+        &mut self,
+        cranker: &Keypair,
+        user_a: Pubkey,
+        user_b: Pubkey,
+        market: Pubkey,
+    ) -> TransactionResult {
+        let head_to_head = find_head_to_head_address(&user_a, &user_b).0;
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::UpdateHeadToHead {
+                head_to_head,
+                head_to_head_market: find_head_to_head_market_address(&head_to_head, &market).0,
+                market,
+                participant_a: find_participant_address(&market, &user_a).0,
+                participant_b: find_participant_address(&market, &user_b).0,
+                user_a,
+                user_b,
+                payer: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::UpdateHeadToHead {}.data(),
+        };
+        self.send(&[ix], cranker)
+    }
+
+    /// Pay a creator their accrued market fees
+    pub fn claim_creator_earnings(&mut self, creator: &Keypair) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::ClaimCreatorEarnings {
+                creator_earnings: find_creator_earnings_address(&creator.pubkey()).0,
+                creator: creator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::ClaimCreatorEarnings {}.data(),
+        };
+        self.send(&[ix], creator)
+    }
+
+    /// Add a market to the user's watchlist
+    pub fn watch(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::AddToWatchlist {
+                watchlist: find_watchlist_address(&user.pubkey()).0,
+                market,
+                user: user.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::AddToWatchlist {}.data(),
+        };
+        self.send(&[ix], user)
+    }
+
+    /// Remove a market from the user's watchlist
+    pub fn unwatch(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::RemoveFromWatchlist {
+                watchlist: find_watchlist_address(&user.pubkey()).0,
+                user: user.pubkey(),
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::RemoveFromWatchlist { market }.data(),
+        };
+        self.send(&[ix], user)
+    }
+
+    /// Drop a market from the user's pending claims
+    pub fn dismiss_pending_claim(&mut self, user: &Keypair, market: Pubkey) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::DismissPendingClaim {
+                pending_claims: find_pending_claims_address(&user.pubkey()).0,
+                user: user.pubkey(),
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::DismissPendingClaim { market }.data(),
+        };
+        self.send(&[ix], user)
+    }
+
+    /// Opt the user in to losing streak rebates
+    pub fn create_rebate_tracker(&mut self, user: &Keypair) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::CreateRebateTracker {
+                rebate_tracker: find_rebate_tracker_address(&user.pubkey()).0,
+                user: user.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::CreateRebateTracker {}.data(),
+        };
+        self.send(&[ix], user)
+    }
+
+    /// Drop the user's open entries and losing streak
+    pub fn reset_rebate_tracker(&mut self, user: &Keypair) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::ResetRebateTracker {
+                rebate_tracker: find_rebate_tracker_address(&user.pubkey()).0,
+                user: user.pubkey(),
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::ResetRebateTracker {}.data(),
+        };
+        self.send(&[ix], user)
+    }
+
+    /// Freeze `user`'s points for a finished epoch, paid for by `payer`
+    pub fn snapshot_points(&mut self, payer: &Keypair, user: Pubkey, epoch: u32) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::SnapshotPoints {
+                points_ledger: find_points_ledger_address(&user).0,
+                points_snapshot: find_points_snapshot_address(&user, epoch).0,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::SnapshotPoints { epoch }.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// Freeze a finished epoch's points across all users, paid for by `payer`
+    pub fn snapshot_points_total(&mut self, payer: &Keypair, epoch: u32) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::SnapshotPointsTotal {
+                points_totals: find_points_totals_address().0,
+                points_total_snapshot: find_points_total_snapshot_address(epoch).0,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::SnapshotPointsTotal { epoch }.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// Start counting `factory`'s daily activity, paid for by `payer`
+    pub fn initialize_platform_activity(&mut self, payer: &Keypair, factory: Pubkey) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::InitializePlatformActivity {
+                factory,
+                platform_activity: find_platform_activity_address(&factory).0,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::InitializePlatformActivity {}.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// Roll up `factory`'s closed `day` of activity, paid for by `payer`
+    pub fn emit_daily_rollup(&mut self, payer: &Keypair, factory: Pubkey, day: u32) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::EmitDailyRollup {
+                platform_activity: find_platform_activity_address(&factory).0,
+                daily_rollup: find_daily_rollup_address(&factory, day).0,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::EmitDailyRollup { day }.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// Create the soulbound reputation mint, paid for by `payer`
+    pub fn initialize_reputation_mint(&mut self, payer: &Keypair) -> TransactionResult {
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::InitializeReputationMint {
+                reputation_mint: find_reputation_mint_address().0,
+                payer: payer.pubkey(),
+                token_program: TOKEN_2022_PROGRAM_ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::InitializeReputationMint {}.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// Mint or burn `user`'s reputation to match their stats, cranked by `payer`
+    pub fn sync_reputation(&mut self, payer: &Keypair, user: Pubkey) -> TransactionResult {
+        let reputation_mint = find_reputation_mint_address().0;
+        let ix = Instruction {
+            program_id: DASHBOARD_PROGRAM_ID,
+            accounts: cryptoscore_dashboard::accounts::SyncReputation {
+                user_stats: find_user_stats_address(&user).0,
+                reputation_mint,
+                reputation_account: find_reputation_account_address(&user, &reputation_mint).0,
+                user,
+                payer: payer.pubkey(),
+                token_program: TOKEN_2022_PROGRAM_ID,
+                associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: cryptoscore_dashboard::instruction::SyncReputation {}.data(),
+        };
+        self.send(&[ix], payer)
+    }
+
+    /// `user`'s reputation token balance, zero before their first sync
+    pub fn reputation(&self, user: &Pubkey) -> u64 {
+        let account = find_reputation_account_address(user, &find_reputation_mint_address().0).0;
+        self.svm
+            .get_account(&account)
+            .and_then(|account| token_2022_amount(&account.data))
+            .unwrap_or(0)
+    }
+}
+
+impl Default for TestEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The custom program error a failed transaction returned, if any
+pub fn error_code<T>(result: &Result<T, FailedTransactionMetadata>) -> Option<u32> {
+    match result {
+        Err(FailedTransactionMetadata {
+            err: TransactionError::InstructionError(_, InstructionError::Custom(code)),
+            ..
+        }) => Some(*code),
+        _ => None,
+    }
+}
+
+fn deploy_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy")
+}
@@ -0,0 +1,62 @@
+//! Compute-unit ceilings for the instructions users batch together.
+//!
+//! Join, resolve and withdraw must stay under these ceilings so several of
+//! them (e.g. batch claims) still fit in one transaction. Run with
+//! `--nocapture` to print the measured usage of every instruction.
+
+use cryptoscore_common::{MatchOutcome, CLAIM_WINDOW_SECONDS};
+use cryptoscore_program_tests::TestEnv;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+
+const JOIN_BUDGET: u64 = 60_000;
+const RESOLVE_BUDGET: u64 = 80_000;
+const WITHDRAW_BUDGET: u64 = 20_000;
+
+const ENTRY_FEE: u64 = LAMPORTS_PER_SOL / 10;
+
+#[test]
+fn instructions_stay_within_compute_budgets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let players: Vec<_> = (0..4).map(|_| env.user(10)).collect();
+
+    let kickoff = env.now() + 60 * 60;
+    let end = kickoff + 2 * 60 * 60;
+    let mut usage = Vec::new();
+
+    let authority = env.authority.insecure_clone();
+    let market = env.create_market(&creator, "CU-BENCH", ENTRY_FEE, kickoff, end).unwrap();
+
+    // Later joins touch fuller platform stats, so keep the most expensive
+    let mut join = 0;
+    for (i, player) in players.iter().enumerate() {
+        let prediction = if i % 2 == 0 { MatchOutcome::Home } else { MatchOutcome::Away };
+        join = join.max(env.join(player, market, prediction).unwrap().compute_units_consumed);
+    }
+    usage.push(("join_market", join, Some(JOIN_BUDGET)));
+
+    env.warp_to(end);
+    let resolve = env.resolve(&players[0], market, (2, 1)).unwrap();
+    usage.push(("resolve_market", resolve.compute_units_consumed, Some(RESOLVE_BUDGET)));
+
+    let withdraw = env.withdraw(&players[0], market).unwrap();
+    usage.push(("withdraw_rewards", withdraw.compute_units_consumed, Some(WITHDRAW_BUDGET)));
+
+    env.warp_to(end + CLAIM_WINDOW_SECONDS);
+    let sweep = env.sweep(&authority, market).unwrap();
+    usage.push(("sweep_unclaimed", sweep.compute_units_consumed, None));
+
+    let mut over_budget = Vec::new();
+    for (name, used, budget) in usage {
+        match budget {
+            Some(budget) => {
+                println!("{:<20} {:>7} CU (budget {})", name, used, budget);
+                if used > budget {
+                    over_budget.push(format!("{} used {} CU, budget is {}", name, used, budget));
+                }
+            }
+            None => println!("{:<20} {:>7} CU", name, used),
+        }
+    }
+    assert!(over_budget.is_empty(), "over compute budget: {}", over_budget.join("; "));
+}
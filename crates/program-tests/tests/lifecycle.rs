@@ -0,0 +1,3473 @@
+//! Full market lifecycle across the factory, market, dashboard and governance programs.
+
+use anchor_lang::error::ErrorCode;
+use cryptoscore_common::commitment::{note_hash, SALT_LEN};
+use cryptoscore_common::fees::{
+    first_correct_bonus, insurance_share, market_fees, reward_per_winner, split_charity_pool,
+};
+use cryptoscore_common::match_id::{decode_charset, DEFAULT_MATCH_ID_CHARSET};
+use cryptoscore_common::merkle::{verify_winner, winner_leaf, winner_proof, winners_root};
+use cryptoscore_common::pda::{
+    find_arbiter_address, find_charity_pledge_address, find_claim_receipt_address, find_commitment_address,
+    find_court_address, find_creator_earnings_address, find_creator_rate_address, find_daily_rollup_address,
+    find_discount_policy_address, find_dispute_address, find_factory_address, find_fee_receipt_address,
+    find_first_correct_bonus_address, find_follow_address, find_governance_address, find_head_to_head_address,
+    find_in_play_lock_address, find_insurance_claim_address, find_insurance_fund_address,
+    find_keeper_tip_policy_address, find_keeper_tips_address, find_kickoff_bucket_address, find_live_score_address,
+    find_market_address, find_market_chain_address, find_market_registry_address, find_match_id_policy_address,
+    find_misresolution_ruling_address, find_odds_history_address, find_outright_standings_address,
+    find_participant_address, find_participant_roster_address, find_pending_claims_address, find_platform_address,
+    find_platform_config_address, find_platform_stats_address, find_points_ledger_address, find_points_snapshot_address,
+    find_points_total_snapshot_address, find_points_totals_address, find_prop_entry_address, find_prop_registry_address,
+    find_proposal_address, find_queued_join_address, find_rake_free_market_address, find_rebate_policy_address,
+    find_rebate_tracker_address, find_results_batch_address, find_rolled_entry_address, find_roster_page_address,
+    find_score_entry_address, find_score_grid_address, find_settlement_report_address, find_stake_position_address,
+    find_treasury_address, find_user_breakdown_address, find_user_stats_address, find_voucher_address,
+    find_voucher_campaign_address, find_watchlist_address, find_winners_root_address, kickoff_day,
+};
+use cryptoscore_common::receipt::MAX_RECEIPT_URI_BASE_LEN;
+use cryptoscore_common::reputation::reputation_balance;
+use cryptoscore_common::swap::NATIVE_MINT;
+use cryptoscore_common::tags::{encode_tag, TagCategory, NO_TAG, TAG_SLOTS};
+use cryptoscore_common::token_gate::TokenGateRule;
+use cryptoscore_common::wormhole::result_payload;
+use cryptoscore_common::{
+    FinalScore, HandicapStyle, MarketStatus, MatchOutcome, MatchResult, OutcomeSet, PropKind, Scoreline,
+    CLAIM_WINDOW_SECONDS, MAX_DISCOUNT_TIERS, MAX_IN_PLAY_LOCK_MINUTE, MAX_PAYOUT_SWAP_MINTS,
+    MAX_RESOLUTION_GRACE_SECONDS, ODDS_HISTORY_SAMPLES, ODDS_SAMPLE_INTERVAL_SECONDS, ORPHANED_REGISTRY_GRACE_SECONDS,
+    QUEUED_JOIN_LEAD_SECONDS, RESULT_CORRECTION_WINDOW_SECONDS, ROSTER_PAGE_SIZE, SCORE_GRID_BUCKETS,
+};
+use cryptoscore_dashboard::{
+    day_of, week_of, xp_for_result, CreatorEarnings, DailyRollupSnapshot, DashboardError, HeadToHead, MarketResult,
+    PendingClaims, PlatformStats, PointsLedger, PointsSnapshot, PointsTotalSnapshot, PointsTotals, RebateTracker,
+    UserBreakdown, UserStats, Watchlist, POINTS_PER_MARKET_CREATED, RECENT_POINTS_EPOCHS, SECONDS_PER_DAY,
+    SECONDS_PER_WEEK,
+};
+use cryptoscore_factory_interface::{
+    CreatorRate, DiscountPolicy, DiscountTier, Factory, FeeReceipt, InsuranceClaim, InsuranceFund, KeeperTipPolicy,
+    KeeperTips, KickoffBucket, LiveScore, MarketRegistry, MatchIdPolicy, MisresolutionRuling, Platform, PlatformConfig,
+    RakeFreeMarket, RebatePolicy, ResultsBatch, Treasury, Voucher, VoucherCampaign,
+};
+use cryptoscore_factory::FactoryError;
+use cryptoscore_factory_interface::instruction::PlatformParam;
+use cryptoscore_governance::GovernanceError;
+use cryptoscore_governance_interface::{Arbiter, Court, CourtTerms, Dispute, DisputeStatus, Proposal, StakePosition};
+use cryptoscore_market::MarketError;
+use cryptoscore_market_interface::{
+    CharityPledge, FirstCorrectBonus, Follow, InPlayLock, Market, MarketChain, OutrightStandings, Participant,
+    ParticipantRoster, PropEntry, PropRegistry, RolledEntry, RosterPage, ScoreEntry, ScoreGrid, SettlementReport,
+    WinnersRoot,
+};
+use cryptoscore_program_tests::{error_code, TestEnv, PLATFORM_FEE_BPS};
+use solana_sdk::hash::hashv;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const ENTRY_FEE: u64 = LAMPORTS_PER_SOL / 10;
+const HOUR: i64 = 60 * 60;
+
+/// Kickoff and end times for a match starting in an hour
+fn schedule(env: &TestEnv) -> (i64, i64) {
+    let kickoff = env.now() + HOUR;
+    (kickoff, kickoff + 2 * HOUR)
+}
+
+/// Create a market only `user` joins, on `prediction`, then resolve it from
+/// `score` and wait out its correction window, or cancel it without one
+fn settled_entry(
+    env: &mut TestEnv,
+    creator: &Keypair,
+    user: &Keypair,
+    match_id: &str,
+    prediction: MatchOutcome,
+    score: Option<(u8, u8)>,
+) -> Pubkey {
+    let (kickoff, end) = schedule(env);
+    let market = env.create_market(creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+    env.join(user, market, prediction).unwrap();
+    match score {
+        Some(score) => {
+            env.warp_to(end);
+            env.resolve(creator, market, score).unwrap();
+            env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+        }
+        None => env.cancel(creator, market).unwrap(),
+    }
+    market
+}
+
+#[test]
+fn create_join_resolve_and_claim() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-ARS-CHE", ENTRY_FEE, kickoff, end).unwrap();
+
+    let factory = find_factory_address().0;
+    let registry: MarketRegistry = env.account(&find_market_registry_address(&factory, "EPL-ARS-CHE").0);
+    assert_eq!(registry.market_address, market);
+    assert_eq!(env.account::<Factory>(&factory).market_count, 1);
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Away).unwrap();
+
+    let state: Market = env.account(&market);
+    assert_eq!(state.participant_count, 3);
+    assert_eq!((state.home_count, state.draw_count, state.away_count), (2, 0, 1));
+    assert_eq!(state.total_pool, 3 * ENTRY_FEE);
+
+    // Entries only count towards stats once the market has settled
+    let unsettled = env.record_result(&carol, alice.pubkey(), market);
+    assert_eq!(error_code(&unsettled), Some(DashboardError::MarketNotSettled.into()));
+
+    // Resolving before the match ends is rejected
+    let early = env.resolve(&alice, market, (2, 1));
+    assert_eq!(error_code(&early), Some(MarketError::MarketNotEnded.into()));
+
+    env.warp_to(end);
+    let creator_before = env.lamports(&creator.pubkey());
+    let platform_before = env.lamports(&env.authority.pubkey());
+    env.resolve(&alice, market, (2, 1)).unwrap();
+
+    // Both fees wait in ledgers instead of being pushed to the creator and platform
+    let fees = market_fees(3 * ENTRY_FEE).unwrap();
+    assert_eq!(env.lamports(&creator.pubkey()), creator_before);
+    assert_eq!(env.lamports(&env.authority.pubkey()), platform_before);
+    let treasury: Treasury = env.account(&find_treasury_address(&factory).0);
+    assert_eq!(treasury.total_collected, fees.platform_fee);
+    let receipt: FeeReceipt = env.account(&find_fee_receipt_address(&market).0);
+    assert_eq!((receipt.market, receipt.amount), (market, fees.platform_fee));
+    let earnings: CreatorEarnings = env.account(&find_creator_earnings_address(&creator.pubkey()).0);
+    assert_eq!(earnings.unclaimed(), fees.creator_fee);
+
+    let state: Market = env.account(&market);
+    assert_eq!(state.status, MarketStatus::Resolved);
+    assert_eq!(state.outcome, Some(MatchOutcome::Home));
+    assert_eq!(state.fees_paid, fees.total().unwrap());
+
+    let platform_stats: PlatformStats = env.account(&find_platform_stats_address().0);
+    assert_eq!(platform_stats.resolved_markets, 1);
+
+    // Each winner takes an equal share of the pool after fees
+    let reward = reward_per_winner(3 * ENTRY_FEE, 2).unwrap();
+    for winner in [&alice, &bob] {
+        let before = env.lamports(&winner.pubkey());
+        let market_before = env.lamports(&market);
+        env.withdraw(winner, market).unwrap();
+
+        // The winner also pays the transaction fee, so check the market side exactly
+        assert_eq!(market_before - env.lamports(&market), reward);
+        assert!(env.lamports(&winner.pubkey()) > before);
+
+        let participant: Participant = env.account(&find_participant_address(&market, &winner.pubkey()).0);
+        assert!(participant.has_withdrawn);
+    }
+
+    // The first claim opened the claiming phase
+    let state: Market = env.account(&market);
+    assert_eq!(state.status, MarketStatus::Claiming);
+    assert_eq!(state.total_claimed, 2 * reward);
+
+    let twice = env.withdraw(&alice, market);
+    assert_eq!(error_code(&twice), Some(MarketError::AlreadyWithdrawn.into()));
+
+    let loser = env.withdraw(&carol, market);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+
+    // The market never pays out more than it holds
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_market::Market::LEN);
+    assert!(env.lamports(&market) >= rent);
+
+    // Settled results flow into dashboard stats, read from the market and each
+    // entry, once the results signer can no longer correct them
+    let correctable = env.record_result(&carol, alice.pubkey(), market);
+    assert_eq!(error_code(&correctable), Some(DashboardError::CorrectionWindowOpen.into()));
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    env.record_result(&carol, alice.pubkey(), market).unwrap();
+    env.record_result(&carol, carol.pubkey(), market).unwrap();
+
+    // Each entry counts once, and only for users who made it
+    let twice = env.record_result(&alice, alice.pubkey(), market);
+    assert!(twice.is_err());
+    let stranger = env.record_result(&carol, creator.pubkey(), market);
+    assert!(stranger.is_err());
+
+    let alice_stats: UserStats = env.account(&find_user_stats_address(&alice.pubkey()).0);
+    assert_eq!((alice_stats.wins, alice_stats.verified_wins, alice_stats.current_streak), (1, 1, 1));
+    assert_eq!(alice_stats.net_profit, reward as i64 - ENTRY_FEE as i64);
+    // Two of the three participants picked the home win
+    assert_eq!(alice_stats.xp, xp_for_result(&MarketResult::Win, ENTRY_FEE, 6_666));
+
+    let carol_stats: UserStats = env.account(&find_user_stats_address(&carol.pubkey()).0);
+    assert_eq!((carol_stats.losses, carol_stats.verified_losses, carol_stats.current_streak), (1, 1, -1));
+}
+
+#[test]
+fn joining_closes_at_kickoff() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let late = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "LIGA-RMA-BAR", ENTRY_FEE, kickoff, end).unwrap();
+
+    env.warp_to(kickoff);
+    let result = env.join(&late, market, MatchOutcome::Draw);
+    assert_eq!(error_code(&result), Some(MarketError::MarketAlreadyStarted.into()));
+}
+
+#[test]
+fn retried_joins_are_no_ops() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "SERIEA-JUV-INT", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+
+    // A retry of the same join neither charges nor counts the user again
+    let before = env.lamports(&market);
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    assert_eq!(env.lamports(&market), before);
+    let state: Market = env.account(&market);
+    assert_eq!((state.participant_count, state.away_count, state.total_pool), (1, 1, ENTRY_FEE));
+
+    let switched = env.join(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&switched), Some(MarketError::AlreadyJoined.into()));
+
+    // Retries still succeed once the join window has closed
+    env.warp_to(kickoff);
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+}
+
+#[test]
+fn validated_joins_report_why_they_would_fail() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let poor = env.user(1);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-TOT-NEW", 2 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+
+    // Validating moves no funds and leaves the market untouched
+    let before = env.lamports(&market);
+    env.validate_join(&alice, market, MatchOutcome::Home).unwrap();
+    assert_eq!(env.lamports(&market), before);
+    assert_eq!(env.account::<Market>(&market).participant_count, 0);
+
+    let broke = env.validate_join(&poor, market, MatchOutcome::Home);
+    assert_eq!(error_code(&broke), Some(MarketError::InsufficientBalance.into()));
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.validate_join(&alice, market, MatchOutcome::Home).unwrap();
+    let switched = env.validate_join(&alice, market, MatchOutcome::Draw);
+    assert_eq!(error_code(&switched), Some(MarketError::AlreadyJoined.into()));
+
+    env.warp_to(kickoff);
+    let late = env.validate_join(&creator, market, MatchOutcome::Away);
+    assert_eq!(error_code(&late), Some(MarketError::MarketAlreadyStarted.into()));
+}
+
+#[test]
+fn charity_markets_donate_fees_and_pool_share() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let charity = env.user(1).pubkey();
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-BHA-FUL", ENTRY_FEE, kickoff, end).unwrap();
+
+    // The creator can't name themselves, nor pledge more than half the pool
+    let own = env.pledge_to_charity(&creator, market, creator.pubkey(), 1_000);
+    assert_eq!(error_code(&own), Some(MarketError::InvalidCharity.into()));
+    let greedy = env.pledge_to_charity(&creator, market, charity, 5_001);
+    assert_eq!(error_code(&greedy), Some(MarketError::InvalidCharity.into()));
+    env.pledge_to_charity(&creator, market, charity, 2_000).unwrap();
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Away).unwrap();
+
+    env.warp_to(end);
+
+    // Resolution has to pay the recorded charity
+    let accounts = cryptoscore_market_interface::accounts::ResolveMarket::by_participant(
+        market,
+        alice.pubkey(),
+        creator.pubkey(),
+        find_factory_address().0,
+    );
+    let ix = cryptoscore_market_interface::instruction::resolve_market(accounts, 2, 1);
+    let unpaid = env.send(&[ix], &alice);
+    assert_eq!(error_code(&unpaid), Some(MarketError::CharityMismatch.into()));
+
+    let charity_before = env.lamports(&charity);
+    env.resolve(&alice, market, (2, 1)).unwrap();
+
+    // Fees and the pledged pool share go to the charity, not the creator or platform
+    let split = split_charity_pool(3 * ENTRY_FEE, 2_000).unwrap();
+    assert_eq!(split.donation, split.fees + split.pool_share);
+    assert_eq!(env.lamports(&charity) - charity_before, split.donation);
+    assert_eq!(env.lamports(&find_creator_earnings_address(&creator.pubkey()).0), 0);
+    assert_eq!(env.lamports(&find_fee_receipt_address(&market).0), 0);
+    let pledge: CharityPledge = env.account(&find_charity_pledge_address(&market).0);
+    assert_eq!((pledge.charity, pledge.donated), (charity, split.donation));
+    assert_eq!(env.account::<Market>(&market).fees_paid, split.donation);
+
+    // Winners share what's left
+    let market_before = env.lamports(&market);
+    env.withdraw(&alice, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), split.prize_pool / 2);
+}
+
+#[test]
+fn the_earliest_correct_entry_earns_a_bonus_from_the_creator_fee() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let outsider = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let dave = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-BRE-WOL", ENTRY_FEE, kickoff, end).unwrap();
+
+    // Only the admin can offer a bonus, and only a share of the creator fee
+    for bonus_bps in [0, 10_001] {
+        let invalid = env.offer_first_correct_bonus(&creator, market, bonus_bps);
+        assert_eq!(error_code(&invalid), Some(MarketError::InvalidFirstCorrectBonus.into()));
+    }
+    let foreign = env.offer_first_correct_bonus(&outsider, market, 5_000);
+    assert_eq!(error_code(&foreign), Some(MarketError::NotMarketAdmin.into()));
+    env.offer_first_correct_bonus(&creator, market, 5_000).unwrap();
+
+    // Alice is first in but wrong; Bob is the first to back the result
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Home).unwrap();
+    env.join(&dave, market, MatchOutcome::Home).unwrap();
+
+    env.warp_to(end);
+    env.resolve(&carol, market, (2, 1)).unwrap();
+
+    let bonus_address = find_first_correct_bonus_address(&market).0;
+    let expected = first_correct_bonus(market_fees(4 * ENTRY_FEE).unwrap().creator_fee, 5_000);
+    let bonus: FirstCorrectBonus = env.account(&bonus_address);
+    assert_eq!((bonus.winner, bonus.bonus), (Some(bob.pubkey()), expected));
+    assert!(expected > 0);
+
+    // Other winners get just their reward; Bob's bonus comes with his
+    let (market_before, bonus_before) = (env.lamports(&market), env.lamports(&bonus_address));
+    env.withdraw(&carol, market).unwrap();
+    let reward = market_before - env.lamports(&market);
+    assert_eq!(env.lamports(&bonus_address), bonus_before);
+
+    let (market_before, bob_before) = (env.lamports(&market), env.lamports(&bob.pubkey()));
+    env.withdraw(&bob, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), reward);
+    assert_eq!(bonus_before - env.lamports(&bonus_address), expected);
+    assert!(env.lamports(&bob.pubkey()) > bob_before + reward);
+    assert!(env.account::<FirstCorrectBonus>(&bonus_address).claimed);
+}
+
+#[test]
+fn unowed_first_correct_bonuses_return_to_the_vault() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let results_signer = env.user(10);
+    let authority = env.authority.insecure_clone();
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let fees = market_fees(3 * ENTRY_FEE).unwrap();
+    let expected = first_correct_bonus(fees.creator_fee, 5_000);
+    let (kickoff, end) = schedule(&env);
+    let mut markets = vec![];
+    for match_id in ["POR-POR-BEN", "POR-SCP-BRA", "POR-GUI-VIT"] {
+        let market = env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+        env.offer_first_correct_bonus(&creator, market, 5_000).unwrap();
+        env.join(&alice, market, MatchOutcome::Home).unwrap();
+        env.join(&bob, market, MatchOutcome::Home).unwrap();
+        env.join(&carol, market, MatchOutcome::Away).unwrap();
+        markets.push(market);
+    }
+    env.warp_to(end);
+    for market in &markets {
+        env.resolve(&creator, *market, (2, 1)).unwrap();
+    }
+    let bonus_rent = env.svm.minimum_balance_for_rent_exemption(FirstCorrectBonus::LEN);
+
+    // A voided market refunds the bonus along with the rest of the pool
+    let voided = markets[0];
+    let bonus_address = find_first_correct_bonus_address(&voided).0;
+    env.void(&authority, voided).unwrap();
+    let bonus: FirstCorrectBonus = env.account(&bonus_address);
+    assert_eq!((bonus.winner, bonus.bonus), (None, 0));
+    assert_eq!(env.lamports(&bonus_address), bonus_rent);
+    let state: Market = env.account(&voided);
+    assert_eq!(state.fees_paid, fees.total().unwrap() - expected);
+    assert_eq!(state.refund_per_participant(), (3 * ENTRY_FEE - fees.total().unwrap() + expected) / 3);
+    let claim = env.withdraw(&alice, voided);
+    assert_eq!(error_code(&claim), Some(MarketError::MarketNotResolved.into()));
+
+    // A ruled mis-resolution leaves the bonus to be swept rather than paid
+    let ruled = markets[1];
+    let bonus_address = find_first_correct_bonus_address(&ruled).0;
+    env.withdraw(&bob, ruled).unwrap();
+    let market_before = env.lamports(&ruled);
+    env.correct_result(&results_signer, ruled, (0, 1)).unwrap();
+    assert_eq!(env.lamports(&ruled) - market_before, expected);
+    assert_eq!(env.lamports(&bonus_address), bonus_rent);
+    let alice_before = env.lamports(&alice.pubkey());
+    env.withdraw(&alice, ruled).unwrap();
+    let reward = market_before - env.lamports(&ruled) + expected;
+    assert!(env.lamports(&alice.pubkey()) <= alice_before + reward);
+    assert!(!env.account::<FirstCorrectBonus>(&bonus_address).claimed);
+
+    // An unclaimed bonus is swept with the unclaimed rewards
+    let swept = markets[2];
+    let bonus_address = find_first_correct_bonus_address(&swept).0;
+    env.warp_to(end + CLAIM_WINDOW_SECONDS);
+    let platform_before = env.lamports(&authority.pubkey());
+    env.sweep(&authority, swept).unwrap();
+    assert_eq!(env.lamports(&bonus_address), bonus_rent);
+    assert_eq!(env.account::<FirstCorrectBonus>(&bonus_address).bonus, 0);
+    assert!(env.lamports(&authority.pubkey()) - platform_before > 3 * ENTRY_FEE - fees.total().unwrap());
+    let late = env.withdraw(&alice, swept);
+    assert!(late.is_err());
+}
+
+#[test]
+fn settlement_previews_match_what_resolution_pays() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let dave = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-CRY-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    env.offer_first_correct_bonus(&creator, market, 2_500).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Home).unwrap();
+    env.join(&dave, market, MatchOutcome::Home).unwrap();
+
+    let fees = market_fees(4 * ENTRY_FEE).unwrap();
+    let prize_pool = 4 * ENTRY_FEE - fees.total().unwrap();
+    let bonus = first_correct_bonus(fees.creator_fee, 2_500);
+    let home = env.preview_settlement(&alice, market, MatchOutcome::Home).unwrap();
+    assert_eq!(home.winner_count, 3);
+    assert_eq!((home.creator_fee, home.platform_fee), (fees.creator_fee - bonus, fees.platform_fee));
+    assert_eq!((home.first_correct_bonus, home.charity_donation), (bonus, 0));
+    assert_eq!(home.reward_per_winner, prize_pool / 3);
+    assert_eq!(home.dust, prize_pool % 3);
+
+    // Nobody picked a draw, so the whole prize pool would be left over
+    let draw = env.preview_settlement(&alice, market, MatchOutcome::Draw).unwrap();
+    assert_eq!((draw.winner_count, draw.reward_per_winner), (0, 0));
+    assert_eq!((draw.first_correct_bonus, draw.dust), (0, prize_pool));
+
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!(state.fees_paid, home.creator_fee + home.first_correct_bonus + home.platform_fee);
+    let market_before = env.lamports(&market);
+    env.withdraw(&carol, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), home.reward_per_winner);
+
+    let resolved = env.preview_settlement(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&resolved), Some(MarketError::MarketAlreadyResolved.into()));
+}
+
+#[test]
+fn rake_free_markets_pay_winners_the_whole_pool() {
+    let mut env = TestEnv::new();
+    let authority = env.authority.insecure_clone();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let dave = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-NEW-TOT", ENTRY_FEE, kickoff, end).unwrap();
+
+    // Only the factory authority flags markets, and can change its mind before anyone joins
+    let stranger = env.set_rake_free(&creator, market, true);
+    assert_eq!(error_code(&stranger), Some(FactoryError::Unauthorized.into()));
+    env.set_rake_free(&authority, market, true).unwrap();
+    env.set_rake_free(&authority, market, false).unwrap();
+    env.set_rake_free(&authority, market, true).unwrap();
+    let flag: RakeFreeMarket = env.account(&find_rake_free_market_address(&market).0);
+    assert_eq!((flag.market, flag.rake_free), (market, true));
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Home).unwrap();
+    env.join(&dave, market, MatchOutcome::Away).unwrap();
+
+    // Entrants joined expecting the whole pool
+    let locked = env.set_rake_free(&authority, market, false);
+    assert_eq!(error_code(&locked), Some(FactoryError::RakeFreeLocked.into()));
+
+    let treasury = find_treasury_address(&find_factory_address().0).0;
+    let treasury_before = env.lamports(&treasury);
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    // No fee leaves the pool
+    assert_eq!(env.account::<Market>(&market).fees_paid, 0);
+    assert_eq!(env.lamports(&treasury), treasury_before);
+    assert_eq!(env.lamports(&find_creator_earnings_address(&creator.pubkey()).0), 0);
+    assert_eq!(env.lamports(&find_fee_receipt_address(&market).0), 0);
+
+    // Winners split all of it, leaving only the rounding dust
+    let reward = 4 * ENTRY_FEE / 3;
+    for winner in [&alice, &bob, &carol] {
+        let market_before = env.lamports(&market);
+        env.withdraw(winner, market).unwrap();
+        assert_eq!(market_before - env.lamports(&market), reward);
+    }
+    let state: Market = env.account(&market);
+    assert_eq!(state.total_claimed, 3 * reward);
+    assert_eq!(state.total_pool - state.total_claimed, 4 * ENTRY_FEE % 3);
+
+    let resolved = env.set_rake_free(&authority, market, false);
+    assert_eq!(error_code(&resolved), Some(FactoryError::MarketAlreadyResolved.into()));
+}
+
+#[test]
+fn queued_joins_execute_only_at_their_payout() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let dave = env.user(10);
+    let erin = env.user(10);
+    let cranker = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-LIV-MCI", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+
+    // Carol wants at least 2x on the draw, Dave 3x on a crowded home side
+    env.queue_join(&carol, market, MatchOutcome::Draw, 20_000).unwrap();
+    env.queue_join(&dave, market, MatchOutcome::Home, 30_000).unwrap();
+    env.queue_join(&erin, market, MatchOutcome::Away, 10_000).unwrap();
+    let carol_queue = find_queued_join_address(&market, &carol.pubkey()).0;
+    let dave_queue = find_queued_join_address(&market, &dave.pubkey()).0;
+    let erin_queue = find_queued_join_address(&market, &erin.pubkey()).0;
+    assert_eq!(env.account::<Market>(&market).queued_join_count, 3);
+
+    // Only the user can withdraw before the deadline
+    let early = env.execute_queued_join(&cranker, market, carol.pubkey());
+    assert_eq!(error_code(&early), Some(MarketError::QueuedJoinNotDue.into()));
+    let pending = env.refund_queued_join(&cranker, market, dave.pubkey());
+    assert_eq!(error_code(&pending), Some(MarketError::QueuedJoinPending.into()));
+
+    let erin_before = env.lamports(&erin.pubkey());
+    let escrow = env.lamports(&erin_queue);
+    env.refund_queued_join(&erin, market, erin.pubkey()).unwrap();
+    assert_eq!(env.lamports(&erin_queue), 0);
+    assert!(env.lamports(&erin.pubkey()) > erin_before + escrow - LAMPORTS_PER_SOL / 100);
+
+    let deadline = kickoff - QUEUED_JOIN_LEAD_SECONDS;
+    env.warp_to(deadline);
+    let closed = env.queue_join(&erin, market, MatchOutcome::Away, 10_000);
+    assert_eq!(error_code(&closed), Some(MarketError::JoinQueueClosed.into()));
+
+    let pool_before = env.lamports(&market);
+    env.execute_queued_join(&cranker, market, carol.pubkey()).unwrap();
+    assert_eq!(env.lamports(&market) - pool_before, ENTRY_FEE);
+    assert_eq!(env.lamports(&carol_queue), 0);
+    let participant: Participant = env.account(&find_participant_address(&market, &carol.pubkey()).0);
+    assert_eq!(participant.prediction, MatchOutcome::Draw);
+    let state: Market = env.account(&market);
+    assert_eq!((state.home_count, state.draw_count, state.total_pool), (2, 1, 3 * ENTRY_FEE));
+
+    // A third home winner would take well under 3x, so Dave's join is refunded
+    let unmet = env.execute_queued_join(&cranker, market, dave.pubkey());
+    assert_eq!(error_code(&unmet), Some(MarketError::PayoutConditionNotMet.into()));
+    env.refund_queued_join(&cranker, market, dave.pubkey()).unwrap();
+    assert_eq!(env.lamports(&dave_queue), 0);
+    let state: Market = env.account(&market);
+    assert_eq!((state.participant_count, state.queued_join_count), (3, 0));
+}
+
+#[test]
+fn queued_joins_keep_markets_on_match_predictions() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "EPL-ARS-CHE", ENTRY_FEE, kickoff, end).unwrap();
+    env.queue_join(&alice, market, MatchOutcome::Home, 15_000).unwrap();
+
+    // A pending queued join predicts the match, so the market can't switch to props or scores
+    let props = env.list_prop_players(&creator, market, PropKind::NextGoalscorer, vec![9, 10]);
+    assert_eq!(error_code(&props), Some(MarketError::MarketHasQueuedJoins.into()));
+    let scores = env.enable_correct_score(&creator, market);
+    assert_eq!(error_code(&scores), Some(MarketError::MarketHasQueuedJoins.into()));
+
+    env.refund_queued_join(&alice, market, alice.pubkey()).unwrap();
+    env.list_prop_players(&creator, market, PropKind::NextGoalscorer, vec![9, 10]).unwrap();
+}
+
+#[test]
+fn odds_history_keeps_the_latest_samples_until_kickoff() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+
+    // Leave room for more samples than the history holds
+    let kickoff = env.now() + (ODDS_HISTORY_SAMPLES as i64 + 4) * ODDS_SAMPLE_INTERVAL_SECONDS;
+    let market = env.create_market(&creator, "EPL-TOT-NEW", ENTRY_FEE, kickoff, kickoff + 2 * HOUR).unwrap();
+    let history = find_odds_history_address(&market).0;
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.record_odds_sample(&cranker, market).unwrap();
+    let too_soon = env.record_odds_sample(&cranker, market);
+    assert_eq!(error_code(&too_soon), Some(MarketError::OddsSampleTooSoon.into()));
+
+    env.warp_to(env.now() + ODDS_SAMPLE_INTERVAL_SECONDS);
+    env.join(&bob, market, MatchOutcome::Draw).unwrap();
+    env.record_odds_sample(&cranker, market).unwrap();
+
+    let samples = env.account::<cryptoscore_market::OddsHistory>(&history).chronological();
+    assert_eq!(samples.len(), 2);
+    assert_eq!((samples[0].home_count, samples[0].draw_count, samples[0].total_pool), (1, 0, ENTRY_FEE));
+    assert_eq!((samples[1].home_count, samples[1].draw_count, samples[1].total_pool), (1, 1, 2 * ENTRY_FEE));
+
+    // Once full, each sample replaces the oldest
+    let first = samples[0].timestamp;
+    for _ in 0..ODDS_HISTORY_SAMPLES {
+        env.warp_to(env.now() + ODDS_SAMPLE_INTERVAL_SECONDS);
+        env.record_odds_sample(&cranker, market).unwrap();
+    }
+    let samples = env.account::<cryptoscore_market::OddsHistory>(&history).chronological();
+    assert_eq!(samples.len(), ODDS_HISTORY_SAMPLES);
+    assert_eq!(samples[0].timestamp, first + 2 * ODDS_SAMPLE_INTERVAL_SECONDS);
+    assert!(samples.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+
+    env.warp_to(kickoff);
+    let started = env.record_odds_sample(&cranker, market);
+    assert_eq!(error_code(&started), Some(MarketError::MarketAlreadyStarted.into()));
+}
+
+#[test]
+fn followers_copy_a_leaders_prediction() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let leader = env.user(10);
+    let follower = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "BUND-BVB-RBL", ENTRY_FEE, kickoff, end).unwrap();
+
+    let own = env.follow(&leader, leader.pubkey());
+    assert_eq!(error_code(&own), Some(MarketError::CannotFollowSelf.into()));
+    env.follow(&follower, leader.pubkey()).unwrap();
+
+    // Nothing to copy until the leader has joined
+    assert!(env.copy_join(&follower, market, leader.pubkey()).is_err());
+
+    env.join(&leader, market, MatchOutcome::Away).unwrap();
+    let market_before = env.lamports(&market);
+    env.copy_join(&follower, market, leader.pubkey()).unwrap();
+    assert_eq!(env.lamports(&market) - market_before, ENTRY_FEE);
+
+    let participant: Participant = env.account(&find_participant_address(&market, &follower.pubkey()).0);
+    assert_eq!(participant.prediction, MatchOutcome::Away);
+    let state: Market = env.account(&market);
+    assert_eq!((state.away_count, state.participant_count), (2, 2));
+
+    let follow_address = find_follow_address(&follower.pubkey(), &leader.pubkey()).0;
+    assert_eq!(env.account::<Follow>(&follow_address).copies, 1);
+
+    env.unfollow(&follower, leader.pubkey()).unwrap();
+    assert_eq!(env.lamports(&follow_address), 0);
+}
+
+#[test]
+fn winners_roll_rewards_into_follow_up_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let rival = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "SERA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    let follow_up = env.create_market(&creator, "SERA-MIL-JUV", ENTRY_FEE, end + HOUR, end + 3 * HOUR).unwrap();
+    let last = env.create_market(&creator, "SERA-JUV-INT", ENTRY_FEE, end + 4 * HOUR, end + 6 * HOUR).unwrap();
+    let foreign = env.create_market(&rival, "SERA-NAP-ROM", ENTRY_FEE, end + HOUR, end + 3 * HOUR).unwrap();
+
+    // Only the creator's own later markets can follow on
+    for other in [foreign, market] {
+        let invalid = env.set_follow_up_market(&creator, market, other);
+        assert_eq!(error_code(&invalid), Some(MarketError::InvalidFollowUpMarket.into()));
+    }
+    let stranger = env.set_follow_up_market(&alice, market, follow_up);
+    assert_eq!(error_code(&stranger), Some(MarketError::NotMarketAdmin.into()));
+    env.set_follow_up_market(&creator, market, follow_up).unwrap();
+    env.set_follow_up_market(&creator, follow_up, last).unwrap();
+
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (1, 0)).unwrap();
+
+    let loser = env.roll_rewards(&bob, market, follow_up, MatchOutcome::Draw);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+    let elsewhere = env.roll_rewards(&alice, market, foreign, MatchOutcome::Draw);
+    assert_eq!(error_code(&elsewhere), Some(MarketError::NotFollowUpMarket.into()));
+
+    // The reward pays the follow-up's entry fee and the rest is paid out
+    let reward = reward_per_winner(2 * ENTRY_FEE, 1).unwrap();
+    let before = env.lamports(&follow_up);
+    env.roll_rewards(&alice, market, follow_up, MatchOutcome::Draw).unwrap();
+    assert_eq!(env.lamports(&follow_up), before + ENTRY_FEE);
+    assert!(env.account::<Participant>(&find_participant_address(&market, &alice.pubkey()).0).has_withdrawn);
+    let entry: Participant = env.account(&find_participant_address(&follow_up, &alice.pubkey()).0);
+    assert_eq!(entry.prediction, MatchOutcome::Draw);
+
+    let rolled: RolledEntry = env.account(&find_rolled_entry_address(&follow_up, &alice.pubkey()).0);
+    assert_eq!((rolled.source_market, rolled.reward, rolled.streak), (market, reward, 1));
+    let chain: MarketChain = env.account(&find_market_chain_address(&market).0);
+    assert_eq!((chain.follow_up, chain.rolled), (follow_up, 1));
+    assert!(env.roll_rewards(&alice, market, follow_up, MatchOutcome::Draw).is_err());
+
+    // Rolling the follow-up's reward on extends the streak
+    env.join(&bob, follow_up, MatchOutcome::Away).unwrap();
+    env.warp_to(end + 3 * HOUR);
+    env.resolve(&creator, follow_up, (2, 2)).unwrap();
+    env.roll_rewards(&alice, follow_up, last, MatchOutcome::Away).unwrap();
+    let rolled: RolledEntry = env.account(&find_rolled_entry_address(&last, &alice.pubkey()).0);
+    assert_eq!((rolled.source_market, rolled.streak), (follow_up, 2));
+}
+
+#[test]
+fn co_admins_administer_a_market_for_its_creator() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let co_admin = env.user(10);
+    let outsider = env.user(10);
+    let none = Pubkey::default();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_private_market(&creator, "LIGA-BET-SEV", ENTRY_FEE, kickoff, end).unwrap();
+
+    let itself = env.set_co_admins(&creator, market, [creator.pubkey(), none, none]);
+    assert_eq!(error_code(&itself), Some(MarketError::InvalidCoAdmin.into()));
+    let twice = env.set_co_admins(&creator, market, [co_admin.pubkey(), none, co_admin.pubkey()]);
+    assert_eq!(error_code(&twice), Some(MarketError::InvalidCoAdmin.into()));
+    assert!(env.set_co_admins(&outsider, market, [outsider.pubkey(), none, none]).is_err());
+
+    env.set_co_admins(&creator, market, [none, co_admin.pubkey(), none]).unwrap();
+    assert_eq!(env.account::<Market>(&market).co_admins, [none, co_admin.pubkey(), none]);
+
+    let configured = env.enable_commit_reveal(&outsider, market);
+    assert_eq!(error_code(&configured), Some(MarketError::NotMarketAdmin.into()));
+    env.enable_commit_reveal(&co_admin, market).unwrap();
+
+    let cancelled = env.cancel(&outsider, market);
+    assert_eq!(error_code(&cancelled), Some(MarketError::UnauthorizedCanceller.into()));
+    env.cancel(&co_admin, market).unwrap();
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Cancelled);
+}
+
+#[test]
+fn committed_predictions_stay_hidden_until_kickoff() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let (alice_salt, bob_salt) = ([1u8; SALT_LEN], [2u8; SALT_LEN]);
+
+    let (kickoff, end) = schedule(&env);
+    let public = env.create_market(&creator, "EPL-TOT-WHU", ENTRY_FEE, kickoff, end).unwrap();
+    let listed = env.enable_commit_reveal(&creator, public);
+    assert_eq!(error_code(&listed), Some(MarketError::CommitRevealPrivateOnly.into()));
+
+    let market = env.create_private_market(&creator, "EPL-NEW-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    env.enable_commit_reveal(&creator, market).unwrap();
+    let plain = env.join(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&plain), Some(MarketError::CommitRevealRequired.into()));
+
+    // Commitments fund the pool without moving the visible odds
+    env.commit_prediction(&alice, market, MatchOutcome::Home, &alice_salt).unwrap();
+    env.commit_prediction(&bob, market, MatchOutcome::Away, &bob_salt).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!((state.participant_count, state.total_pool), (2, 2 * ENTRY_FEE));
+    assert_eq!((state.home_count, state.draw_count, state.away_count), (0, 0, 0));
+
+    let early = env.reveal_prediction(&alice, market, MatchOutcome::Home, alice_salt);
+    assert_eq!(error_code(&early), Some(MarketError::RevealWindowClosed.into()));
+
+    env.warp_to(kickoff);
+    let wrong = env.reveal_prediction(&alice, market, MatchOutcome::Away, alice_salt);
+    assert_eq!(error_code(&wrong), Some(MarketError::InvalidReveal.into()));
+    env.reveal_prediction(&alice, market, MatchOutcome::Home, alice_salt).unwrap();
+
+    let participant: Participant = env.account(&find_participant_address(&market, &alice.pubkey()).0);
+    assert_eq!((participant.prediction, participant.entry_index), (MatchOutcome::Home, 0));
+    assert_eq!(env.account::<Market>(&market).home_count, 1);
+    assert_eq!(env.lamports(&find_commitment_address(&market, &alice.pubkey()).0), 0);
+
+    // Bob never reveals, so his entry stays in the pool for the winner
+    env.warp_to(end);
+    let late = env.reveal_prediction(&bob, market, MatchOutcome::Away, bob_salt);
+    assert_eq!(error_code(&late), Some(MarketError::RevealWindowClosed.into()));
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    let market_before = env.lamports(&market);
+    env.withdraw(&alice, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), reward_per_winner(2 * ENTRY_FEE, 1).unwrap());
+}
+
+#[test]
+fn token_gated_markets_only_take_holders() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let holder = env.user(10);
+    let outsider = env.user(10);
+    let fan_token = Pubkey::new_unique();
+    let rule = TokenGateRule::Token { mint: fan_token, min_amount: 1_000 };
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "LALIGA-RMA-FCB", ENTRY_FEE, kickoff, end).unwrap();
+    let empty = TokenGateRule::Token { mint: fan_token, min_amount: 0 };
+    let invalid = env.set_token_gate(&creator, market, empty);
+    assert_eq!(error_code(&invalid), Some(MarketError::InvalidTokenGate.into()));
+    let configured = env.set_token_gate(&outsider, market, rule.clone());
+    assert_eq!(error_code(&configured), Some(MarketError::NotMarketAdmin.into()));
+    env.set_token_gate(&creator, market, rule).unwrap();
+
+    // Joins need a token account holding enough of the fan token, owned by the joiner
+    let plain = env.join(&holder, market, MatchOutcome::Home);
+    assert_eq!(error_code(&plain), Some(MarketError::TokenGateNotMet.into()));
+    let short = env.token_account(holder.pubkey(), fan_token, 999);
+    let short = env.gated_join(&holder, market, MatchOutcome::Home, vec![short]);
+    assert_eq!(error_code(&short), Some(MarketError::TokenGateNotMet.into()));
+    let holdings = env.token_account(holder.pubkey(), fan_token, 1_000);
+    let borrowed = env.gated_join(&outsider, market, MatchOutcome::Home, vec![holdings]);
+    assert_eq!(error_code(&borrowed), Some(MarketError::TokenGateNotMet.into()));
+
+    env.gated_join(&holder, market, MatchOutcome::Home, vec![holdings]).unwrap();
+    assert_eq!(env.account::<Market>(&market).participant_count, 1);
+
+    // Queued joins can't prove holdings when they execute
+    let queued = env.queue_join(&outsider, market, MatchOutcome::Away, 15_000);
+    assert_eq!(error_code(&queued), Some(MarketError::TokenGated.into()));
+
+    // Collection gates need a verified NFT of the collection and its metadata
+    let collection = Pubkey::new_unique();
+    let private = env.create_private_market(&creator, "LALIGA-ATM-SEV", ENTRY_FEE, kickoff, end).unwrap();
+    env.set_token_gate(&creator, private, TokenGateRule::Collection { collection }).unwrap();
+    let committed = env.enable_commit_reveal(&creator, private);
+    assert_eq!(error_code(&committed), Some(MarketError::TokenGated.into()));
+
+    let (unverified, metadata) = env.nft(holder.pubkey(), collection, false);
+    let unverified = env.gated_join(&holder, private, MatchOutcome::Draw, vec![unverified, metadata]);
+    assert_eq!(error_code(&unverified), Some(MarketError::TokenGateNotMet.into()));
+    let (nft, metadata) = env.nft(holder.pubkey(), collection, true);
+    let missing = env.gated_join(&holder, private, MatchOutcome::Draw, vec![nft]);
+    assert_eq!(error_code(&missing), Some(MarketError::TokenGateNotMet.into()));
+    env.gated_join(&holder, private, MatchOutcome::Draw, vec![nft, metadata]).unwrap();
+
+    let late = env.set_token_gate(&creator, private, TokenGateRule::Collection { collection });
+    assert!(late.is_err());
+}
+
+#[test]
+fn rosters_list_participants_page_by_page() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let outsider = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "SERIEA-JUV-INT", ENTRY_FEE, kickoff, end).unwrap();
+    let enabled = env.enable_participant_roster(&outsider, market);
+    assert_eq!(error_code(&enabled), Some(MarketError::NotMarketAdmin.into()));
+    env.enable_participant_roster(&creator, market).unwrap();
+
+    // The entry after a full page opens the next one
+    let users: Vec<Keypair> = (0..=ROSTER_PAGE_SIZE).map(|_| env.user(1)).collect();
+    for user in &users[..ROSTER_PAGE_SIZE] {
+        env.join(user, market, MatchOutcome::Home).unwrap();
+    }
+    let last = &users[ROSTER_PAGE_SIZE];
+    let stale = cryptoscore_market_interface::instruction::join_market(
+        cryptoscore_market_interface::accounts::JoinMarket {
+            roster: cryptoscore_market_interface::accounts::RosterSlot::new(market),
+            ..cryptoscore_market_interface::accounts::JoinMarket::new(market, last.pubkey())
+        },
+        MatchOutcome::Away,
+        [0; 32],
+    );
+    let stale = env.send(&[stale], last);
+    assert_eq!(error_code(&stale), Some(MarketError::InvalidRosterAccount.into()));
+    env.join(last, market, MatchOutcome::Away).unwrap();
+
+    let roster: ParticipantRoster = env.account(&find_participant_roster_address(&market).0);
+    assert_eq!(roster.count as usize, ROSTER_PAGE_SIZE + 1);
+    let first: RosterPage = env.account(&find_roster_page_address(&market, 0).0);
+    assert_eq!(first.len as usize, ROSTER_PAGE_SIZE);
+    assert_eq!(first.users[0], users[0].pubkey());
+    let second: RosterPage = env.account(&find_roster_page_address(&market, 1).0);
+    assert_eq!(&second.users[..second.len as usize], &[last.pubkey()]);
+
+    // Rosters must list every entry, so they can't start late
+    let listed = env.create_market(&creator, "SERIEA-MIL-ROM", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&outsider, listed, MatchOutcome::Draw).unwrap();
+    let late = env.enable_participant_roster(&creator, listed);
+    assert_eq!(error_code(&late), Some(MarketError::MarketHasEntries.into()));
+}
+
+#[test]
+fn winners_roots_prove_rewards_in_roster_order() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let cranker = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "BUNDES-BAY-BVB", ENTRY_FEE, kickoff, end).unwrap();
+    env.enable_participant_roster(&creator, market).unwrap();
+    let users: Vec<Keypair> = (0..4).map(|_| env.user(1)).collect();
+    let predictions = [MatchOutcome::Home, MatchOutcome::Away, MatchOutcome::Home, MatchOutcome::Draw];
+    for (user, prediction) in users.iter().zip(predictions) {
+        env.join(user, market, prediction).unwrap();
+    }
+    let roster: Vec<Pubkey> = users.iter().map(|user| user.pubkey()).collect();
+
+    let early = env.build_winners_root(&cranker, market, &roster);
+    assert_eq!(error_code(&early), Some(MarketError::MarketNotResolved.into()));
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    // Participants are folded in exactly as the roster lists them
+    let skipped = env.build_winners_root(&cranker, market, &roster[1..2]);
+    assert_eq!(error_code(&skipped), Some(MarketError::RosterParticipantMismatch.into()));
+    env.build_winners_root(&cranker, market, &roster[..2]).unwrap();
+    let address = find_winners_root_address(&market).0;
+    let partial: WinnersRoot = env.account(&address);
+    assert_eq!((partial.cursor, partial.winners, partial.published), (2, 1, false));
+    env.build_winners_root(&cranker, market, &roster[2..]).unwrap();
+
+    let published: WinnersRoot = env.account(&address);
+    let reward = reward_per_winner(4 * ENTRY_FEE, 2).unwrap();
+    let leaves = [winner_leaf(&roster[0], reward), winner_leaf(&roster[2], reward)];
+    assert!(published.published);
+    assert_eq!((published.winners, published.reward), (2, reward));
+    assert_eq!(published.root, winners_root(&leaves));
+    assert!(verify_winner(&published.root, &roster[2], reward, 1, &winner_proof(&leaves, 1)));
+    assert!(!verify_winner(&published.root, &roster[1], reward, 1, &winner_proof(&leaves, 1)));
+
+    let again = env.build_winners_root(&cranker, market, &[]);
+    assert_eq!(error_code(&again), Some(MarketError::WinnersRootPublished.into()));
+}
+
+#[test]
+fn only_creator_or_participants_resolve() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let player = env.user(10);
+    let outsider = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "SERIEA-JUV-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&player, market, MatchOutcome::Away).unwrap();
+
+    env.warp_to(end);
+    assert!(env.resolve(&outsider, market, (0, 2)).is_err());
+
+    env.resolve(&creator, market, (0, 2)).unwrap();
+    let again = env.resolve(&player, market, (2, 1));
+    assert_eq!(error_code(&again), Some(MarketError::MarketAlreadyResolved.into()));
+}
+
+#[test]
+fn unclaimed_rewards_sweep_after_claim_window() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let winner = env.user(10);
+    let loser = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "BUN-BAY-BVB", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&winner, market, MatchOutcome::Home).unwrap();
+    env.join(&loser, market, MatchOutcome::Away).unwrap();
+
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    let authority = env.authority.insecure_clone();
+    let early = env.sweep(&authority, market);
+    assert_eq!(error_code(&early), Some(MarketError::ClaimWindowOpen.into()));
+
+    env.warp_to(end + CLAIM_WINDOW_SECONDS);
+    let unauthorized = env.sweep(&loser, market);
+    assert_eq!(error_code(&unauthorized), Some(MarketError::UnauthorizedSweeper.into()));
+
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_market::Market::LEN);
+    let unclaimed = env.lamports(&market) - rent;
+    let platform_before = env.lamports(&authority.pubkey());
+    env.sweep(&authority, market).unwrap();
+
+    // The authority paid the transaction fee out of the swept amount
+    assert_eq!(env.lamports(&market), rent);
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Settled);
+    assert!(env.lamports(&authority.pubkey()) > platform_before);
+    assert!(env.lamports(&authority.pubkey()) - platform_before <= unclaimed);
+
+    // Claims close once the market is settled
+    let late = env.withdraw(&winner, market);
+    assert_eq!(error_code(&late), Some(MarketError::ClaimsClosed.into()));
+}
+
+#[test]
+fn sweep_rejects_recipients_aliasing_market_accounts() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let loser = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "FRA-PSG-MAR", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&loser, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+    env.warp_to(end + CLAIM_WINDOW_SECONDS);
+
+    let participant = find_participant_address(&market, &loser.pubkey()).0;
+    for platform in [market, participant] {
+        let sweep = cryptoscore_market_interface::instruction::sweep_unclaimed(
+            cryptoscore_market_interface::accounts::SweepUnclaimed::new(
+                market,
+                find_factory_address().0,
+                env.authority.pubkey(),
+                platform,
+            ),
+        );
+        let aliased = env.send_as_authority(&[sweep]);
+        assert_eq!(error_code(&aliased), Some(MarketError::DuplicateAccount.into()));
+    }
+}
+
+#[test]
+fn cancelled_markets_refund_then_close() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ITA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+
+    // Only the creator or factory authority may cancel
+    let stranger = env.cancel(&alice, market);
+    assert_eq!(error_code(&stranger), Some(MarketError::UnauthorizedCanceller.into()));
+
+    // Refunds and closing wait for cancellation
+    let early = env.refund(&alice, market);
+    assert_eq!(error_code(&early), Some(MarketError::MarketNotCancelled.into()));
+
+    let active = |env: &TestEnv| env.account::<PlatformStats>(&find_platform_stats_address().0).active_markets;
+    assert_eq!(active(&env), 1);
+    env.cancel(&creator, market).unwrap();
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Cancelled);
+    assert_eq!(active(&env), 0);
+
+    // A cancelled market can neither be joined nor resolved
+    let carol = env.user(10);
+    let join = env.join(&carol, market, MatchOutcome::Draw);
+    assert_eq!(error_code(&join), Some(MarketError::MarketNotOpen.into()));
+    env.warp_to(end);
+    let resolve = env.resolve(&creator, market, (2, 1));
+    assert_eq!(error_code(&resolve), Some(MarketError::InvalidStatusTransition.into()));
+
+    let market_before = env.lamports(&market);
+    env.refund(&alice, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), ENTRY_FEE);
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Refunding);
+
+    let twice = env.refund(&alice, market);
+    assert_eq!(error_code(&twice), Some(MarketError::AlreadyWithdrawn.into()));
+
+    let outstanding = env.close(&alice, market);
+    assert_eq!(error_code(&outstanding), Some(MarketError::RefundsOutstanding.into()));
+
+    env.refund(&bob, market).unwrap();
+    env.close(&alice, market).unwrap();
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Closed);
+
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_market::Market::LEN);
+    assert_eq!(env.lamports(&market), rent);
+}
+
+#[test]
+fn resolution_pays_platform_fees_only_into_the_treasury() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "POR-BEN-POR", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+
+    // The platform fee can't be redirected to the creator or the market itself
+    for treasury in [creator.pubkey(), market] {
+        let mut accounts = cryptoscore_market_interface::accounts::ResolveMarket::by_participant(
+            market,
+            alice.pubkey(),
+            creator.pubkey(),
+            find_factory_address().0,
+        );
+        accounts.treasury = treasury;
+        let ix = cryptoscore_market_interface::instruction::resolve_market(accounts, 2, 1);
+        let redirected = env.send(&[ix], &alice);
+        assert_eq!(error_code(&redirected), Some(ErrorCode::ConstraintSeeds.into()));
+    }
+
+    env.resolve(&alice, market, (2, 1)).unwrap();
+}
+
+#[test]
+fn treasury_withdrawals_are_limited_to_collected_fees() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+
+    let factory = find_factory_address().0;
+    let mut collected = 0;
+    for match_id in ["NED-AJA-PSV", "NED-FEY-AZA"] {
+        let (kickoff, end) = schedule(&env);
+        let market = env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+        env.join(&alice, market, MatchOutcome::Home).unwrap();
+        env.join(&bob, market, MatchOutcome::Away).unwrap();
+        env.warp_to(end);
+        env.resolve(&creator, market, (2, 1)).unwrap();
+        collected += market_fees(2 * ENTRY_FEE).unwrap().platform_fee;
+
+        let receipt: FeeReceipt = env.account(&find_fee_receipt_address(&market).0);
+        assert_eq!(receipt.factory, factory);
+    }
+
+    let treasury_address = find_treasury_address(&factory).0;
+    let treasury: Treasury = env.account(&treasury_address);
+    assert_eq!((treasury.total_collected, treasury.receipt_count), (collected, 2));
+
+    // Only the factory authority may withdraw
+    let stranger = env.withdraw_treasury(&alice, alice.pubkey(), collected);
+    assert_eq!(error_code(&stranger), Some(ErrorCode::ConstraintHasOne.into()));
+
+    // Withdrawals can't dip into the insurance fund's share or the treasury's rent
+    let authority = env.authority.insecure_clone();
+    let recipient = Pubkey::new_unique();
+    let withdrawable = collected - insurance_share(collected);
+    let overdraw = env.withdraw_treasury(&authority, recipient, withdrawable + 1);
+    assert_eq!(error_code(&overdraw), Some(FactoryError::InsufficientTreasuryFunds.into()));
+
+    env.withdraw_treasury(&authority, recipient, withdrawable).unwrap();
+    assert_eq!(env.lamports(&recipient), withdrawable);
+    let treasury: Treasury = env.account(&treasury_address);
+    assert_eq!(treasury.total_withdrawn, withdrawable);
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_factory::Treasury::LEN);
+    assert_eq!(env.lamports(&treasury_address), rent);
+
+    let fund_address = find_insurance_fund_address(&factory).0;
+    let fund: InsuranceFund = env.account(&fund_address);
+    assert_eq!(fund.total_funded, insurance_share(collected));
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_factory::InsuranceFund::LEN);
+    assert_eq!(env.lamports(&fund_address), rent + insurance_share(collected));
+}
+
+#[test]
+fn insurance_compensates_winners_of_misresolved_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+
+    // A well-funded market seeds the insurance fund with its platform fee
+    let (kickoff, end) = schedule(&env);
+    let funding = env.create_market(&creator, "BRA-FLA-PAL", 5 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+    env.join(&alice, funding, MatchOutcome::Home).unwrap();
+    env.join(&bob, funding, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, funding, (2, 1)).unwrap();
+
+    // Alice and Bob predicted Away, but the market paid out Carol's Home
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "BRA-COR-SAO", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.join(&carol, market, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+    env.withdraw(&carol, market).unwrap();
+
+    // Only a ruling for a different outcome, by the factory authority, opens claims
+    let stranger = env.rule_misresolution(&alice, market, MatchOutcome::Away, ENTRY_FEE, ENTRY_FEE);
+    assert_eq!(error_code(&stranger), Some(FactoryError::Unauthorized.into()));
+    let authority = env.authority.insecure_clone();
+    let same = env.rule_misresolution(&authority, market, MatchOutcome::Home, ENTRY_FEE, ENTRY_FEE);
+    assert_eq!(error_code(&same), Some(FactoryError::InvalidRuling.into()));
+
+    let early = env.claim_insurance(&alice, market);
+    assert_eq!(error_code(&early), Some(ErrorCode::AccountNotInitialized.into()));
+
+    // Each claim is capped below the full reward, and the market's payout below both claims
+    let claim_cap = ENTRY_FEE / 10;
+    let payout_cap = claim_cap + claim_cap / 2;
+    assert!(claim_cap < reward_per_winner(3 * ENTRY_FEE, 2).unwrap());
+    env.rule_misresolution(&authority, market, MatchOutcome::Away, claim_cap, payout_cap).unwrap();
+
+    let unaffected = env.claim_insurance(&carol, market);
+    assert_eq!(error_code(&unaffected), Some(FactoryError::NotAffectedByRuling.into()));
+
+    let before = env.lamports(&alice.pubkey());
+    env.claim_insurance(&alice, market).unwrap();
+    let claim: InsuranceClaim = env.account(&find_insurance_claim_address(&market, &alice.pubkey()).0);
+    assert_eq!(claim.amount, claim_cap);
+    assert!(env.lamports(&alice.pubkey()) > before);
+    assert!(env.claim_insurance(&alice, market).is_err());
+
+    env.claim_insurance(&bob, market).unwrap();
+    let claim: InsuranceClaim = env.account(&find_insurance_claim_address(&market, &bob.pubkey()).0);
+    assert_eq!(claim.amount, payout_cap - claim_cap);
+
+    let ruling: MisresolutionRuling = env.account(&find_misresolution_ruling_address(&market).0);
+    assert_eq!((ruling.total_paid, ruling.claim_count), (payout_cap, 2));
+    let fund: InsuranceFund = env.account(&find_insurance_fund_address(&find_factory_address().0).0);
+    assert_eq!((fund.total_paid, fund.claim_count), (payout_cap, 2));
+}
+
+#[test]
+fn results_signer_corrects_results_within_the_window() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ARG-BOC-RIV", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    // Only the results signer corrects, and only to a different score
+    let stranger = env.correct_result(&alice, market, (0, 1));
+    assert_eq!(error_code(&stranger), Some(MarketError::NotResultsSigner.into()));
+    let unchanged = env.correct_result(&results_signer, market, (2, 1));
+    assert_eq!(error_code(&unchanged), Some(MarketError::ResultUnchanged.into()));
+
+    // Before anyone claims, a changed outcome re-resolves the market
+    env.correct_result(&results_signer, market, (0, 1)).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!(state.outcome, Some(MatchOutcome::Away));
+    assert_eq!(state.final_score, Some(FinalScore::new(0, 1)));
+
+    let loser = env.withdraw(&alice, market);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+    env.withdraw(&bob, market).unwrap();
+
+    // Once a winner has claimed, a changed outcome is ruled a mis-resolution instead
+    env.correct_result(&results_signer, market, (3, 0)).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!(state.outcome, Some(MatchOutcome::Away));
+    let ruling: MisresolutionRuling = env.account(&find_misresolution_ruling_address(&market).0);
+    assert_eq!(ruling.resolved_outcome, MatchOutcome::Away);
+    assert_eq!(ruling.correct_outcome, MatchOutcome::Home);
+
+    // The window closes a fixed time after the match ends
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    let late = env.correct_result(&results_signer, market, (0, 2));
+    assert_eq!(error_code(&late), Some(MarketError::CorrectionWindowClosed.into()));
+}
+
+#[test]
+fn stats_only_record_results_past_the_correction_window() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "BRA-FLA-PAL", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    // While the result can change, no crank records it
+    let stats = env.record_result(&cranker, alice.pubkey(), market);
+    assert_eq!(error_code(&stats), Some(DashboardError::CorrectionWindowOpen.into()));
+    let breakdown = env.record_breakdown(&cranker, alice.pubkey(), market);
+    assert_eq!(error_code(&breakdown), Some(DashboardError::CorrectionWindowOpen.into()));
+    let (first, second) = if alice.pubkey() < bob.pubkey() { (&alice, &bob) } else { (&bob, &alice) };
+    let head_to_head = env.record_head_to_head(&cranker, first.pubkey(), second.pubkey(), market);
+    assert_eq!(error_code(&head_to_head), Some(DashboardError::CorrectionWindowOpen.into()));
+
+    env.correct_result(&results_signer, market, (0, 1)).unwrap();
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    env.record_result(&cranker, alice.pubkey(), market).unwrap();
+    env.record_result(&cranker, bob.pubkey(), market).unwrap();
+
+    // Stats reflect the corrected outcome rather than the first one
+    let alice_stats: UserStats = env.account(&find_user_stats_address(&alice.pubkey()).0);
+    assert_eq!((alice_stats.wins, alice_stats.losses, alice_stats.current_streak), (0, 1, -1));
+    let bob_stats: UserStats = env.account(&find_user_stats_address(&bob.pubkey()).0);
+    assert_eq!((bob_stats.wins, bob_stats.losses, bob_stats.current_streak), (1, 0, 1));
+}
+
+#[test]
+fn governance_changes_platform_params_after_a_timelock() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let authority = env.authority.insecure_clone();
+    let factory = find_factory_address().0;
+    let governance = find_governance_address(&factory).0;
+    let day = 24 * HOUR;
+
+    // A resolved market leaves fees in the treasury and something to dispute
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ITA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    env.initialize_governance(day, 2 * day, 2_000, LAMPORTS_PER_SOL).unwrap();
+    env.set_governor(governance).unwrap();
+    let config: PlatformConfig = env.account(&find_platform_config_address(&factory).0);
+    assert_eq!((config.governor, config.dispute_window), (governance, 0));
+
+    env.stake(&alice, 2 * LAMPORTS_PER_SOL).unwrap();
+    env.stake(&bob, LAMPORTS_PER_SOL).unwrap();
+    env.stake(&carol, LAMPORTS_PER_SOL / 2).unwrap();
+
+    // Proposing takes the minimum stake and a change the factory would accept
+    let small = env.create_proposal(&carol, PlatformParam::DisputeWindow(7 * day));
+    assert_eq!(error_code(&small), Some(GovernanceError::InsufficientStake.into()));
+    let invalid = env.create_proposal(&alice, PlatformParam::PlatformFeeBps(5_000));
+    assert_eq!(error_code(&invalid), Some(GovernanceError::InvalidProposal.into()));
+    let id = env.create_proposal(&alice, PlatformParam::DisputeWindow(7 * day)).unwrap();
+    let proposal_address = find_proposal_address(&governance, id).0;
+    let proposal: Proposal = env.account(&proposal_address);
+    assert_eq!(proposal.quorum_votes, 7 * LAMPORTS_PER_SOL / 10);
+
+    env.cast_vote(&alice, id, true).unwrap();
+    env.cast_vote(&bob, id, false).unwrap();
+    assert!(env.cast_vote(&bob, id, true).is_err());
+    let proposal: Proposal = env.account(&proposal_address);
+    assert_eq!((proposal.votes_for, proposal.votes_against), (2 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL));
+
+    // Voting locks the stake until voting ends
+    let locked = env.unstake(&alice, LAMPORTS_PER_SOL);
+    assert_eq!(error_code(&locked), Some(GovernanceError::StakeLocked.into()));
+    let position: StakePosition = env.account(&find_stake_position_address(&governance, &alice.pubkey()).0);
+    assert_eq!(position.locked_until, proposal.voting_ends_at);
+
+    // Passed proposals wait out the timelock after voting closes
+    let early = env.execute_proposal(&carol, id);
+    assert_eq!(error_code(&early), Some(GovernanceError::TimelockActive.into()));
+    env.warp_to(proposal.voting_ends_at);
+    let late = env.cast_vote(&carol, id, false);
+    assert_eq!(error_code(&late), Some(GovernanceError::VotingClosed.into()));
+    let early = env.execute_proposal(&carol, id);
+    assert_eq!(error_code(&early), Some(GovernanceError::TimelockActive.into()));
+
+    env.warp_to(proposal.executable_at);
+    env.execute_proposal(&carol, id).unwrap();
+    let config: PlatformConfig = env.account(&find_platform_config_address(&factory).0);
+    assert_eq!(config.dispute_window, 7 * day);
+    let again = env.execute_proposal(&carol, id);
+    assert_eq!(error_code(&again), Some(GovernanceError::ProposalAlreadyExecuted.into()));
+
+    let before = env.lamports(&alice.pubkey());
+    env.unstake(&alice, 2 * LAMPORTS_PER_SOL).unwrap();
+    assert!(env.lamports(&alice.pubkey()) > before);
+
+    // Without quorum a proposal can't execute
+    let id = env.create_proposal(&bob, PlatformParam::PlatformFeeBps(100)).unwrap();
+    let proposal: Proposal = env.account(&find_proposal_address(&governance, id).0);
+    env.warp_to(proposal.executable_at);
+    let failed = env.execute_proposal(&bob, id);
+    assert_eq!(error_code(&failed), Some(GovernanceError::ProposalNotPassed.into()));
+    let factory_account: Factory = env.account(&factory);
+    assert_eq!(factory_account.platform_fee_bps, PLATFORM_FEE_BPS);
+
+    // The factory authority keeps a direct path, and nobody else has one
+    let recipient = Pubkey::new_unique();
+    let stranger = env.set_platform_param(&alice, PlatformParam::TreasuryRecipient(alice.pubkey()));
+    assert_eq!(error_code(&stranger), Some(FactoryError::Unauthorized.into()));
+    env.set_platform_param(&authority, PlatformParam::TreasuryRecipient(recipient)).unwrap();
+    let elsewhere = env.withdraw_treasury(&authority, authority.pubkey(), 1);
+    assert_eq!(error_code(&elsewhere), Some(FactoryError::InvalidTreasuryRecipient.into()));
+    env.withdraw_treasury(&authority, recipient, 1).unwrap();
+
+    // The dispute window the proposal set has closed on the market
+    env.warp_to(end + 7 * day + 1);
+    let closed = env.rule_misresolution(&authority, market, MatchOutcome::Away, ENTRY_FEE, ENTRY_FEE);
+    assert_eq!(error_code(&closed), Some(FactoryError::DisputeWindowClosed.into()));
+}
+
+#[test]
+fn court_juries_overturn_resolutions_and_slash_the_minority() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let jurors = [env.user(10), env.user(10), env.user(10)];
+    let governance = find_governance_address(&find_factory_address().0).0;
+    let court = find_court_address(&governance).0;
+
+    // The creator resolved Home, but Alice's Away was the real result
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ESP-RMA-BAR", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    env.initialize_governance(24 * HOUR, 0, 0, 0).unwrap();
+    env.set_governor(governance).unwrap();
+    // A fee that doesn't split evenly leaves a lamport of dust
+    let terms = CourtTerms {
+        jury_size: 3,
+        voting_period: HOUR,
+        min_arbiter_stake: LAMPORTS_PER_SOL,
+        dispute_fee: ENTRY_FEE + 1,
+        slash_bps: 5_000,
+        claim_cap: ENTRY_FEE,
+        payout_cap: 2 * ENTRY_FEE,
+    };
+    let even = env.initialize_court(CourtTerms { jury_size: 4, ..terms.clone() });
+    assert_eq!(error_code(&even), Some(GovernanceError::InvalidCourtConfig.into()));
+    env.initialize_court(terms).unwrap();
+
+    // Arbiters need the minimum stake; Alice joins too but can't judge her own dispute
+    let small = env.join_court(&bob, LAMPORTS_PER_SOL / 2);
+    assert_eq!(error_code(&small), Some(GovernanceError::InsufficientStake.into()));
+    env.join_court(&alice, LAMPORTS_PER_SOL).unwrap();
+    for juror in &jurors {
+        env.join_court(juror, LAMPORTS_PER_SOL).unwrap();
+    }
+
+    let same = env.open_dispute(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&same), Some(GovernanceError::InvalidDispute.into()));
+    env.open_dispute(&alice, market, MatchOutcome::Away).unwrap();
+    let dispute_address = find_dispute_address(&court, &market).0;
+
+    // Anyone draws once the committed slot is hashed, and the jury is everyone but the disputer
+    let dispute: Dispute = env.account(&dispute_address);
+    let early = env.draw_jury(&bob, market);
+    assert_eq!(error_code(&early), Some(GovernanceError::JuryDrawPending.into()));
+    env.warp_to_slot(dispute.draw_slot + 1);
+    let jury = env.draw_jury(&bob, market).unwrap();
+    let mut drawn = jury.clone();
+    let mut expected: Vec<Pubkey> = jurors.iter().map(|juror| juror.pubkey()).collect();
+    drawn.sort();
+    expected.sort();
+    assert_eq!(drawn, expected);
+    let dispute: Dispute = env.account(&dispute_address);
+    assert_eq!(dispute.randomness, hashv(&[&dispute.draw_slot.to_le_bytes()]).to_bytes());
+    let again = env.draw_jury(&bob, market);
+    assert_eq!(error_code(&again), Some(GovernanceError::JuryAlreadyDrawn.into()));
+
+    // Drawn stake is locked until the dispute settles
+    let locked = env.leave_court(&jurors[0]);
+    assert_eq!(error_code(&locked), Some(GovernanceError::ArbiterOnJury.into()));
+
+    let outsider = env.cast_juror_vote(&alice, market, MatchOutcome::Away);
+    assert_eq!(error_code(&outsider), Some(GovernanceError::NotJuror.into()));
+    env.cast_juror_vote(&jurors[0], market, MatchOutcome::Away).unwrap();
+    env.cast_juror_vote(&jurors[1], market, MatchOutcome::Away).unwrap();
+    env.cast_juror_vote(&jurors[2], market, MatchOutcome::Home).unwrap();
+    let twice = env.cast_juror_vote(&jurors[0], market, MatchOutcome::Home);
+    assert_eq!(error_code(&twice), Some(GovernanceError::AlreadyVoted.into()));
+
+    let early = env.settle_dispute(&bob, market);
+    assert_eq!(error_code(&early), Some(GovernanceError::VotingOpen.into()));
+    let dispute: Dispute = env.account(&dispute_address);
+    env.warp_to(dispute.voting_ends_at);
+    env.settle_dispute(&bob, market).unwrap();
+
+    // The minority juror lost half their stake to the majority, along with the
+    // fee, and the last majority juror in jury order takes the dust
+    let slashed = LAMPORTS_PER_SOL / 2;
+    let reward = (ENTRY_FEE + 1 + slashed) / 2;
+    let dispute: Dispute = env.account(&dispute_address);
+    assert_eq!(dispute.status, DisputeStatus::Settled);
+    assert_eq!((dispute.verdict, dispute.juror_reward), (Some(MatchOutcome::Away), reward));
+    let last_majority = *jury.iter().rev().find(|juror| **juror != jurors[2].pubkey()).unwrap();
+    for juror in &jurors[..2] {
+        let winner: Arbiter = env.account(&find_arbiter_address(&court, &juror.pubkey()).0);
+        let dust = u64::from(juror.pubkey() == last_majority);
+        assert_eq!((winner.stake, winner.active_disputes), (LAMPORTS_PER_SOL + reward + dust, 0));
+    }
+    let loser: Arbiter = env.account(&find_arbiter_address(&court, &jurors[2].pubkey()).0);
+    assert_eq!(loser.stake, LAMPORTS_PER_SOL - slashed);
+    let pool: Court = env.account(&court);
+    assert!(!pool.arbiters.contains(&jurors[2].pubkey()));
+
+    // The overturned resolution is ruled through the governor, so Alice can claim
+    let ruling: MisresolutionRuling = env.account(&find_misresolution_ruling_address(&market).0);
+    assert_eq!((ruling.correct_outcome, ruling.claim_cap), (MatchOutcome::Away, ENTRY_FEE));
+    let settled = env.settle_dispute(&bob, market);
+    assert_eq!(error_code(&settled), Some(GovernanceError::DisputeNotVoting.into()));
+
+    let before = env.lamports(&jurors[0].pubkey());
+    env.leave_court(&jurors[0]).unwrap();
+    assert!(env.lamports(&jurors[0].pubkey()) > before + LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn solvency_checks_catch_a_drained_vault() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ENG-ARS-CHE", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+
+    let assert_solvency = cryptoscore_market_interface::instruction::assert_solvency(
+        cryptoscore_market_interface::accounts::AssertSolvency { market },
+    );
+    env.send(std::slice::from_ref(&assert_solvency), &alice).unwrap();
+
+    // Drain the vault down to rent behind the program's back
+    let rent = env.svm.minimum_balance_for_rent_exemption(cryptoscore_market::Market::LEN);
+    let mut account = env.svm.get_account(&market).unwrap();
+    account.lamports = rent;
+    env.svm.set_account(market, account).unwrap();
+
+    let insolvent = env.send(&[assert_solvency], &alice);
+    assert_eq!(error_code(&insolvent), Some(MarketError::InsolventMarket.into()));
+    let logs = &insolvent.unwrap_err().meta.logs;
+    assert!(logs.iter().any(|log| log.starts_with("Program data: ")));
+
+    // State changes refuse to build on an insolvent market
+    env.warp_to(end);
+    let resolve = env.resolve(&alice, market, (2, 1));
+    assert!(resolve.is_err());
+}
+
+#[test]
+fn emergency_void_only_unlocks_refunds() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ESP-RMA-BAR", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&alice, market, (2, 1)).unwrap();
+
+    // Only the factory authority holds the switch, and the creator can't
+    // cancel a resolved market the ordinary way
+    let creator_void = env.void(&creator, market);
+    assert_eq!(error_code(&creator_void), Some(MarketError::UnauthorizedVoider.into()));
+    let creator_cancel = env.cancel(&creator, market);
+    assert_eq!(error_code(&creator_cancel), Some(MarketError::MarketAlreadyResolved.into()));
+
+    let authority = env.authority.insecure_clone();
+    let market_before = env.lamports(&market);
+    env.void(&authority, market).unwrap();
+
+    // The market already stopped counting as active when it resolved
+    let platform_stats: PlatformStats = env.account(&find_platform_stats_address().0);
+    assert_eq!(platform_stats.active_markets, 0);
+
+    // No funds moved and the outcome stands
+    assert_eq!(env.lamports(&market), market_before);
+    let state: Market = env.account(&market);
+    assert_eq!(state.status, MarketStatus::Cancelled);
+    assert_eq!(state.outcome, Some(MatchOutcome::Home));
+
+    // Winners can no longer claim; everyone shares what's left after fees
+    let claim = env.withdraw(&alice, market);
+    assert_eq!(error_code(&claim), Some(MarketError::MarketNotResolved.into()));
+
+    let refund = (2 * ENTRY_FEE - market_fees(2 * ENTRY_FEE).unwrap().total().unwrap()) / 2;
+    for user in [&alice, &bob] {
+        let before = env.lamports(&market);
+        env.refund(user, market).unwrap();
+        assert_eq!(before - env.lamports(&market), refund);
+    }
+    env.close(&alice, market).unwrap();
+
+    // Once claims start, the switch no longer applies
+    let (kickoff, end) = schedule(&env);
+    let late = env.create_market(&creator, "ESP-SEV-BET", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, late, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+    env.resolve(&alice, late, (2, 1)).unwrap();
+    env.withdraw(&alice, late).unwrap();
+    let claiming = env.void(&authority, late);
+    assert_eq!(error_code(&claiming), Some(MarketError::InvalidStatusTransition.into()));
+}
+
+#[test]
+fn attested_results_resolve_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let relayer = env.user(10);
+    let results_signer = Keypair::new();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "MLS-LAG-SEA", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+    env.warp_to(end + HOUR);
+    let attested_at = end + 30 * 60;
+
+    // Without the ed25519 check the result carries no proof
+    let [_, resolve] = env.attestation(&relayer, &results_signer, market, (0, 2), attested_at);
+    let missing = env.send(&[resolve], &relayer);
+    assert_eq!(error_code(&missing), Some(MarketError::MissingAttestation.into()));
+
+    // A valid signature from anyone else, or over a different result, doesn't count
+    let impostor = Keypair::new();
+    let forged = env.attestation(&relayer, &impostor, market, (0, 2), attested_at);
+    let forged = env.send(&forged, &relayer);
+    assert_eq!(error_code(&forged), Some(MarketError::InvalidAttestation.into()));
+
+    let [verify, _] = env.attestation(&relayer, &results_signer, market, (0, 2), attested_at);
+    let [_, swapped] = env.attestation(&relayer, &results_signer, market, (2, 1), attested_at);
+    let swapped = env.send(&[verify, swapped], &relayer);
+    assert_eq!(error_code(&swapped), Some(MarketError::InvalidAttestation.into()));
+
+    // The signature covers the score, not just who won
+    let [verify, _] = env.attestation(&relayer, &results_signer, market, (0, 2), attested_at);
+    let [_, rescored] = env.attestation(&relayer, &results_signer, market, (0, 3), attested_at);
+    let rescored = env.send(&[verify, rescored], &relayer);
+    assert_eq!(error_code(&rescored), Some(MarketError::InvalidAttestation.into()));
+
+    // Results are attested after the match and never ahead of the clock
+    for attested_at in [end - 1, env.now() + 1] {
+        let early = env.attestation(&relayer, &results_signer, market, (0, 2), attested_at);
+        let early = env.send(&early, &relayer);
+        assert_eq!(error_code(&early), Some(MarketError::InvalidAttestation.into()));
+    }
+
+    let attested = env.attestation(&relayer, &results_signer, market, (0, 2), attested_at);
+    env.send(&attested, &relayer).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!(state.status, MarketStatus::Resolved);
+    assert_eq!(state.outcome, Some(MatchOutcome::Away));
+    env.withdraw(&alice, market).unwrap();
+
+    // The creator can't attest its own market
+    env.set_results_signer(creator.pubkey()).unwrap();
+    let (kickoff, end) = schedule(&env);
+    let own = env.create_market(&creator, "MLS-POR-VAN", ENTRY_FEE, kickoff, end).unwrap();
+    env.warp_to(end);
+    let attested = env.attestation(&relayer, &creator, own, (2, 1), end);
+    let attested = env.send(&attested, &relayer);
+    assert_eq!(error_code(&attested), Some(MarketError::CreatorIsResultsSigner.into()));
+}
+
+#[test]
+fn wormhole_results_resolve_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let relayer = env.user(10);
+    let core_bridge = Pubkey::new_unique();
+    let oracle = [7; 32];
+
+    let invalid = env.set_wormhole_emitter(core_bridge, 0, oracle);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidWormholeEmitter.into()));
+    env.set_wormhole_emitter(core_bridge, 2, oracle).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "NFL-KC-BUF", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.warp_to(end + HOUR);
+    let attested_at = end + 30 * 60;
+    let payload = result_payload("NFL-KC-BUF", &FinalScore::new(2, 1), attested_at);
+
+    // Accounts the core bridge didn't write carry no guardian signatures
+    let forged = env.post_vaa(Pubkey::new_unique(), 2, oracle, &payload);
+    let forged = env.resolve_with_vaa(&relayer, market, forged);
+    assert_eq!(error_code(&forged), Some(MarketError::InvalidVaa.into()));
+
+    // Only the registered oracle on the registered chain counts
+    for (chain, emitter) in [(5, oracle), (2, [8; 32])] {
+        let foreign = env.post_vaa(core_bridge, chain, emitter, &payload);
+        let foreign = env.resolve_with_vaa(&relayer, market, foreign);
+        assert_eq!(error_code(&foreign), Some(MarketError::UnknownVaaEmitter.into()));
+    }
+
+    let other = result_payload("NFL-DAL-PHI", &FinalScore::new(2, 1), attested_at);
+    let other = env.post_vaa(core_bridge, 2, oracle, &other);
+    let other = env.resolve_with_vaa(&relayer, market, other);
+    assert_eq!(error_code(&other), Some(MarketError::VaaMatchMismatch.into()));
+
+    // Results are observed after the match and never ahead of the clock
+    for observed_at in [end - 1, env.now() + 1] {
+        let early = result_payload("NFL-KC-BUF", &FinalScore::new(2, 1), observed_at);
+        let early = env.post_vaa(core_bridge, 2, oracle, &early);
+        let early = env.resolve_with_vaa(&relayer, market, early);
+        assert_eq!(error_code(&early), Some(MarketError::InvalidVaa.into()));
+    }
+
+    let posted = env.post_vaa(core_bridge, 2, oracle, &payload);
+    env.resolve_with_vaa(&relayer, market, posted).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!(state.status, MarketStatus::Resolved);
+    assert_eq!(state.outcome, Some(MatchOutcome::Home));
+    env.withdraw(&alice, market).unwrap();
+
+    // The same VAA can't resolve the market again
+    let replayed = env.resolve_with_vaa(&relayer, market, posted);
+    assert!(replayed.is_err());
+}
+
+#[test]
+fn published_results_resolve_a_matchweek() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let relayer = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let fixtures = ["EPL-LIV-MCI", "EPL-AVL-BHA", "EPL-FUL-BRE"];
+    let markets: Vec<Pubkey> = fixtures
+        .iter()
+        .map(|match_id| env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap())
+        .collect();
+    env.join(&alice, markets[0], MatchOutcome::Home).unwrap();
+    env.join(&bob, markets[1], MatchOutcome::Draw).unwrap();
+    let result = |match_id: &str, (home_goals, away_goals): (u8, u8)| MatchResult {
+        match_id: match_id.to_string(),
+        score: FinalScore::new(home_goals, away_goals),
+    };
+
+    let impostor = env.publish_results(&relayer, 1, vec![result(fixtures[0], (2, 1))]);
+    assert_eq!(error_code(&impostor), Some(FactoryError::NotResultsSigner.into()));
+    let repeated = vec![result(fixtures[0], (2, 1)), result(fixtures[0], (0, 2))];
+    let repeated = env.publish_results(&results_signer, 1, repeated);
+    assert_eq!(error_code(&repeated), Some(FactoryError::InvalidResultsBatch.into()));
+
+    // Results published before the final whistle never resolve a market
+    env.publish_results(&results_signer, 0, vec![result(fixtures[0], (0, 2))]).unwrap();
+    env.warp_to(end);
+    let early = env.resolve_markets_batch(&relayer, 0, &markets[..1]);
+    assert_eq!(error_code(&early), Some(MarketError::InvalidAttestation.into()));
+
+    let matchweek = vec![result(fixtures[0], (2, 1)), result(fixtures[1], (1, 1))];
+    env.publish_results(&results_signer, 1, matchweek).unwrap();
+    let batch: ResultsBatch = env.account(&find_results_batch_address(&find_factory_address().0, 1).0);
+    assert_eq!((batch.signer, batch.results.len()), (results_signer.pubkey(), 2));
+
+    let missing = env.resolve_markets_batch(&relayer, 1, &markets);
+    assert_eq!(error_code(&missing), Some(MarketError::ResultNotInBatch.into()));
+    env.resolve_markets_batch(&relayer, 1, &markets[..2]).unwrap();
+    for (market, outcome) in markets.iter().zip([MatchOutcome::Home, MatchOutcome::Draw]) {
+        let state: Market = env.account(market);
+        assert_eq!((state.status, state.outcome), (MarketStatus::Resolved, Some(outcome)));
+    }
+    let state: Market = env.account(&markets[1]);
+    assert_eq!(state.final_score, Some(FinalScore::new(1, 1)));
+    env.withdraw(&alice, markets[0]).unwrap();
+    env.withdraw(&bob, markets[1]).unwrap();
+
+    // Replacing the results signer retires its batches
+    env.publish_results(&results_signer, 2, vec![result(fixtures[2], (0, 2))]).unwrap();
+    env.set_results_signer(relayer.pubkey()).unwrap();
+    let stale = env.resolve_markets_batch(&relayer, 2, &markets[2..]);
+    assert_eq!(error_code(&stale), Some(MarketError::StaleResultsBatch.into()));
+}
+
+#[test]
+fn final_live_score_resolves_the_match() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let relayer = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let match_id = "ESP-RMA-BAR";
+    let market = env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+
+    let impostor = env.update_live_score(&relayer, match_id, (0, 0), 1, false);
+    assert_eq!(error_code(&impostor), Some(FactoryError::NotResultsSigner.into()));
+    env.warp_to(kickoff);
+    env.update_live_score(&results_signer, match_id, (1, 0), 23, false).unwrap();
+    env.update_live_score(&results_signer, match_id, (1, 2), 71, false).unwrap();
+    let score: LiveScore = env.account(&find_live_score_address(&find_factory_address().0, match_id).0);
+    assert_eq!((score.home_goals, score.away_goals, score.minute), (1, 2, 71));
+
+    // A score still in play never resolves the market
+    env.warp_to(end);
+    let in_play = env.resolve_with_live_score(&relayer, market);
+    assert_eq!(error_code(&in_play), Some(MarketError::LiveScoreNotFinal.into()));
+
+    env.update_live_score(&results_signer, match_id, (1, 2), 94, true).unwrap();
+    let corrected = env.update_live_score(&results_signer, match_id, (2, 2), 95, true);
+    assert_eq!(error_code(&corrected), Some(FactoryError::LiveScoreFinal.into()));
+    env.resolve_with_live_score(&relayer, market).unwrap();
+    let state: Market = env.account(&market);
+    assert_eq!((state.status, state.outcome), (MarketStatus::Resolved, Some(MatchOutcome::Away)));
+    assert_eq!(state.final_score, Some(FinalScore::new(1, 2)));
+    env.withdraw(&alice, market).unwrap();
+}
+
+#[test]
+fn in_play_markets_lock_joins_on_the_live_score() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let outsider = env.user(10);
+    let results_signer = env.user(10);
+    let players: Vec<Keypair> = (0..6).map(|_| env.user(10)).collect();
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let by_minute = env.create_market(&creator, "GER-BAY-BVB", ENTRY_FEE, kickoff, end).unwrap();
+    let by_goal = env.create_market(&creator, "GER-RBL-SGE", ENTRY_FEE, kickoff, end).unwrap();
+    let pre_match = env.create_market(&creator, "GER-WOB-SCF", ENTRY_FEE, kickoff, end).unwrap();
+    let unreported = env.create_market(&creator, "GER-HOF-M05", ENTRY_FEE, kickoff, end).unwrap();
+
+    let impostor = env.enable_in_play(&outsider, by_minute, 60, false);
+    assert_eq!(error_code(&impostor), Some(MarketError::NotMarketAdmin.into()));
+    let unbounded = env.enable_in_play(&creator, by_minute, MAX_IN_PLAY_LOCK_MINUTE + 1, false);
+    assert_eq!(error_code(&unbounded), Some(MarketError::InvalidInPlayLock.into()));
+    env.enable_in_play(&creator, by_minute, 60, false).unwrap();
+    env.enable_in_play(&creator, by_goal, 90, true).unwrap();
+    env.enable_in_play(&creator, unreported, 90, false).unwrap();
+    let lock: InPlayLock = env.account(&find_in_play_lock_address(&by_minute).0);
+    assert_eq!(lock.live_score, find_live_score_address(&find_factory_address().0, "GER-BAY-BVB").0);
+
+    // Kickoff still closes markets that aren't in play
+    env.warp_to(kickoff + 60);
+    let late = env.join(&players[0], pre_match, MatchOutcome::Home);
+    assert_eq!(error_code(&late), Some(MarketError::MarketAlreadyStarted.into()));
+
+    // In-play joins need the live score the lock follows, even before its first update
+    let unscored = cryptoscore_market_interface::instruction::join_market(
+        cryptoscore_market_interface::accounts::JoinMarket {
+            roster: env.roster_slot(by_minute),
+            ..cryptoscore_market_interface::accounts::JoinMarket::new(by_minute, players[0].pubkey())
+        },
+        MatchOutcome::Home,
+        [0; 32],
+    );
+    let unscored = env.send(&[unscored], &players[0]);
+    assert_eq!(error_code(&unscored), Some(MarketError::InvalidLiveScore.into()));
+    env.join(&players[0], by_minute, MatchOutcome::Home).unwrap();
+
+    env.update_live_score(&results_signer, "GER-BAY-BVB", (1, 1), 59, false).unwrap();
+    env.join(&players[1], by_minute, MatchOutcome::Draw).unwrap();
+    env.follow(&players[5], players[0].pubkey()).unwrap();
+    env.copy_join(&players[5], by_minute, players[0].pubkey()).unwrap();
+    env.update_live_score(&results_signer, "GER-BAY-BVB", (1, 1), 60, false).unwrap();
+    env.validate_join(&players[2], by_minute, MatchOutcome::Away).unwrap_err();
+    let locked = env.join(&players[2], by_minute, MatchOutcome::Away);
+    assert_eq!(error_code(&locked), Some(MarketError::InPlayLocked.into()));
+    assert_eq!(env.account::<Market>(&by_minute).participant_count, 3);
+
+    // The first goal locks goal-locked markets well before their lock minute
+    env.update_live_score(&results_signer, "GER-RBL-SGE", (0, 0), 12, false).unwrap();
+    env.join(&players[3], by_goal, MatchOutcome::Away).unwrap();
+    env.update_live_score(&results_signer, "GER-RBL-SGE", (0, 1), 13, false).unwrap();
+    let after_goal = env.join(&players[4], by_goal, MatchOutcome::Away);
+    assert_eq!(error_code(&after_goal), Some(MarketError::InPlayLocked.into()));
+
+    // Copying a leader's pick is locked out the same way
+    env.follow(&players[4], players[3].pubkey()).unwrap();
+    let copied = env.copy_join(&players[4], by_goal, players[3].pubkey());
+    assert_eq!(error_code(&copied), Some(MarketError::InPlayLocked.into()));
+
+    // Without score updates, the market's end still closes it
+    env.join(&players[5], unreported, MatchOutcome::Home).unwrap();
+    env.warp_to(end);
+    let after_end = env.join(&players[4], unreported, MatchOutcome::Home);
+    assert_eq!(error_code(&after_end), Some(MarketError::InPlayLocked.into()));
+}
+
+#[test]
+fn player_props_resolve_by_player_id() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ITA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    let impostor = env.list_prop_players(&alice, market, PropKind::NextGoalscorer, vec![9, 10, 11]);
+    assert_eq!(error_code(&impostor), Some(MarketError::NotMarketAdmin.into()));
+    let repeated = env.list_prop_players(&creator, market, PropKind::NextGoalscorer, vec![9, 10, 9]);
+    assert_eq!(error_code(&repeated), Some(MarketError::InvalidPropPlayers.into()));
+    env.list_prop_players(&creator, market, PropKind::NextGoalscorer, vec![9, 10, 11]).unwrap();
+
+    // Prop markets only take picks on their listed players
+    let predicted = env.join(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&predicted), Some(MarketError::PropMarket.into()));
+    let unlisted = env.join_prop(&alice, market, 7);
+    assert_eq!(error_code(&unlisted), Some(MarketError::PropPlayerNotListed.into()));
+    env.join_prop(&alice, market, 9).unwrap();
+    env.join_prop(&bob, market, 9).unwrap();
+    env.join_prop(&carol, market, 10).unwrap();
+    let registry: PropRegistry = env.account(&find_prop_registry_address(&market).0);
+    assert_eq!(registry.pick_counts, vec![2, 1, 0]);
+    assert_eq!(env.account::<Market>(&market).participant_count, 3);
+
+    // Only the results signer resolves, and only by player ID
+    env.warp_to(end);
+    let by_outcome = env.resolve(&creator, market, (2, 1));
+    assert_eq!(error_code(&by_outcome), Some(MarketError::PropMarket.into()));
+    let by_creator = env.resolve_prop(&creator, market, Some(10));
+    assert_eq!(error_code(&by_creator), Some(MarketError::NotResultsSigner.into()));
+    env.resolve_prop(&results_signer, market, Some(9)).unwrap();
+    let registry: PropRegistry = env.account(&find_prop_registry_address(&market).0);
+    assert_eq!(registry.winning_player, Some(9));
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Resolved);
+
+    let market_before = env.lamports(&market);
+    env.withdraw_prop(&alice, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), reward_per_winner(3 * ENTRY_FEE, 2).unwrap());
+    let entry: PropEntry = env.account(&find_prop_entry_address(&market, &alice.pubkey()).0);
+    assert!(entry.has_withdrawn);
+    let again = env.withdraw_prop(&alice, market);
+    assert_eq!(error_code(&again), Some(MarketError::AlreadyWithdrawn.into()));
+    let loser = env.withdraw_prop(&carol, market);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+    env.withdraw_prop(&bob, market).unwrap();
+}
+
+#[test]
+fn outrights_settle_early_once_one_team_is_left() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let results_signer = env.user(10);
+    let relayer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    // Entries stay open for a month of a nine-month season
+    let kickoff = env.now() + 30 * 24 * HOUR;
+    let end = kickoff + 240 * 24 * HOUR;
+    let market = env.create_market(&creator, "ENG-PL-2026-WINNER", ENTRY_FEE, kickoff, end).unwrap();
+    env.list_prop_players(&creator, market, PropKind::Outright, vec![1, 2, 3, 4]).unwrap();
+    env.join_prop(&alice, market, 1).unwrap();
+    env.join_prop(&bob, market, 2).unwrap();
+    env.warp_to(kickoff - HOUR);
+    env.join_prop(&carol, market, 1).unwrap();
+
+    // Only the results signer checkpoints, and eliminations carry over
+    env.warp_to(kickoff + 100 * 24 * HOUR);
+    let by_creator = env.record_outright_checkpoint(&creator, market, vec![4]);
+    assert_eq!(error_code(&by_creator), Some(MarketError::NotResultsSigner.into()));
+    let unlisted = env.record_outright_checkpoint(&results_signer, market, vec![7]);
+    assert_eq!(error_code(&unlisted), Some(MarketError::PropPlayerNotListed.into()));
+    env.record_outright_checkpoint(&results_signer, market, vec![4]).unwrap();
+    env.record_outright_checkpoint(&results_signer, market, vec![3]).unwrap();
+    let standings: OutrightStandings = env.account(&find_outright_standings_address(&market).0);
+    assert_eq!(standings.eliminated, vec![false, false, true, true]);
+    assert_eq!(standings.checkpoint_count, 2);
+    let undecided = env.settle_outright_early(&relayer, market);
+    assert_eq!(error_code(&undecided), Some(MarketError::OutrightUndecided.into()));
+    let nobody_left = env.record_outright_checkpoint(&results_signer, market, vec![1, 2]);
+    assert_eq!(error_code(&nobody_left), Some(MarketError::InvalidOutrightCheckpoint.into()));
+
+    // Mathematically decided with two months to go
+    env.warp_to(end - 60 * 24 * HOUR);
+    env.record_outright_checkpoint(&results_signer, market, vec![2]).unwrap();
+    env.settle_outright_early(&relayer, market).unwrap();
+    let settled = env.account::<Market>(&market);
+    assert_eq!(settled.status, MarketStatus::Resolved);
+    assert_eq!(settled.end_time, end - 60 * 24 * HOUR);
+    let registry: PropRegistry = env.account(&find_prop_registry_address(&market).0);
+    assert_eq!(registry.winning_player, Some(1));
+
+    let market_before = env.lamports(&market);
+    env.withdraw_prop(&alice, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), reward_per_winner(3 * ENTRY_FEE, 2).unwrap());
+    let loser = env.withdraw_prop(&bob, market);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+}
+
+#[test]
+fn decided_outrights_close_entries_before_their_deadline() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let results_signer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let kickoff = env.now() + 30 * 24 * HOUR;
+    let market = env.create_market(&creator, "ESP-LL-2026-WINNER", ENTRY_FEE, kickoff, kickoff + HOUR).unwrap();
+    env.list_prop_players(&creator, market, PropKind::Outright, vec![1, 2]).unwrap();
+    env.join_prop(&alice, market, 2).unwrap();
+
+    env.record_outright_checkpoint(&results_signer, market, vec![2]).unwrap();
+    let late = env.join_prop(&bob, market, 1);
+    assert_eq!(error_code(&late), Some(MarketError::MarketAlreadyStarted.into()));
+    assert_eq!(env.account::<Market>(&market).kickoff_time, env.now());
+}
+
+#[test]
+fn correct_score_markets_pay_the_any_other_bucket_on_exotic_results() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let results_signer = env.user(10);
+    let relayer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let match_id = "GER-BAY-DOR";
+    let market = env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+    let impostor = env.enable_correct_score(&alice, market);
+    assert_eq!(error_code(&impostor), Some(MarketError::NotMarketAdmin.into()));
+    env.enable_correct_score(&creator, market).unwrap();
+    let props = env.list_prop_players(&creator, market, PropKind::NextGoalscorer, vec![9]);
+    assert_eq!(error_code(&props), Some(MarketError::CorrectScoreMarket.into()));
+
+    // Exact picks stay on the grid; everything else goes in the shared bucket
+    let predicted = env.join(&alice, market, MatchOutcome::Home);
+    assert_eq!(error_code(&predicted), Some(MarketError::CorrectScoreMarket.into()));
+    let off_grid = env.join_correct_score(&alice, market, Scoreline::Exact { home: 5, away: 2 });
+    assert_eq!(error_code(&off_grid), Some(MarketError::InvalidScoreline.into()));
+    env.join_correct_score(&alice, market, Scoreline::Exact { home: 2, away: 1 }).unwrap();
+    env.join_correct_score(&bob, market, Scoreline::AnyOther).unwrap();
+    env.join_correct_score(&carol, market, Scoreline::AnyOther).unwrap();
+    let grid: ScoreGrid = env.account(&find_score_grid_address(&market).0);
+    assert_eq!(grid.pick_counts[Scoreline::Exact { home: 2, away: 1 }.bucket().unwrap()], 1);
+    assert_eq!(grid.pick_counts[SCORE_GRID_BUCKETS - 1], 2);
+    assert_eq!(grid.pick_counts.iter().sum::<u32>(), 3);
+
+    // Only a final score resolves, and never by outcome
+    env.warp_to(end);
+    env.update_live_score(&results_signer, match_id, (5, 2), 90, false).unwrap();
+    let unfinished = env.resolve_correct_score(&relayer, market);
+    assert_eq!(error_code(&unfinished), Some(MarketError::LiveScoreNotFinal.into()));
+    let by_outcome = env.resolve(&creator, market, (2, 1));
+    assert_eq!(error_code(&by_outcome), Some(MarketError::CorrectScoreMarket.into()));
+    env.update_live_score(&results_signer, match_id, (5, 2), 94, true).unwrap();
+    env.resolve_correct_score(&relayer, market).unwrap();
+    let grid: ScoreGrid = env.account(&find_score_grid_address(&market).0);
+    assert_eq!(grid.result, Some(Scoreline::AnyOther));
+    assert_eq!(env.account::<Market>(&market).status, MarketStatus::Resolved);
+
+    let market_before = env.lamports(&market);
+    env.withdraw_score(&bob, market).unwrap();
+    assert_eq!(market_before - env.lamports(&market), reward_per_winner(3 * ENTRY_FEE, 2).unwrap());
+    let entry: ScoreEntry = env.account(&find_score_entry_address(&market, &bob.pubkey()).0);
+    assert!(entry.has_withdrawn);
+    let again = env.withdraw_score(&bob, market);
+    assert_eq!(error_code(&again), Some(MarketError::AlreadyWithdrawn.into()));
+    let loser = env.withdraw_score(&alice, market);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+    env.withdraw_score(&carol, market).unwrap();
+}
+
+#[test]
+fn handicap_markets_settle_on_the_line_and_push_level_asian_lines() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let results_signer = env.user(10);
+    let relayer = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let spread = env.create_market(&creator, "NED-AJX-PSV", ENTRY_FEE, kickoff, end).unwrap();
+    let level = env.create_market(&creator, "NED-FEY-AZ", ENTRY_FEE, kickoff, end).unwrap();
+
+    // Lines must mirror each other, and European lines are whole goals
+    let lopsided = env.set_handicap(&creator, spread, HandicapStyle::Asian, -3, 2);
+    assert_eq!(error_code(&lopsided), Some(MarketError::InvalidHandicapLine.into()));
+    let half_goal = env.set_handicap(&creator, spread, HandicapStyle::European, -3, 3);
+    assert_eq!(error_code(&half_goal), Some(MarketError::InvalidHandicapLine.into()));
+    env.set_handicap(&creator, spread, HandicapStyle::Asian, -3, 3).unwrap();
+    env.set_handicap(&creator, level, HandicapStyle::Asian, -4, 4).unwrap();
+
+    for market in [spread, level] {
+        env.join(&alice, market, MatchOutcome::Home).unwrap();
+        env.join(&bob, market, MatchOutcome::Away).unwrap();
+    }
+    let late = env.set_handicap(&creator, spread, HandicapStyle::Asian, -1, 1);
+    assert_eq!(error_code(&late), Some(MarketError::MarketHasEntries.into()));
+
+    // Winning by one doesn't cover home -1.5
+    env.warp_to(end);
+    env.update_live_score(&results_signer, "NED-AJX-PSV", (2, 1), 93, true).unwrap();
+    let by_outcome = env.resolve(&creator, spread, (2, 1));
+    assert_eq!(error_code(&by_outcome), Some(MarketError::HandicapMarket.into()));
+    env.resolve_handicap(&relayer, spread).unwrap();
+    let settled = env.account::<Market>(&spread);
+    assert_eq!(settled.status, MarketStatus::Resolved);
+    assert_eq!(settled.outcome, Some(MatchOutcome::Away));
+    let loser = env.withdraw(&alice, spread);
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+    env.withdraw(&bob, spread).unwrap();
+
+    // Winning by exactly two on home -2 pushes, so everyone gets their entry back
+    env.update_live_score(&results_signer, "NED-FEY-AZ", (3, 1), 95, true).unwrap();
+    env.resolve_handicap(&relayer, level).unwrap();
+    assert_eq!(env.account::<Market>(&level).status, MarketStatus::Cancelled);
+    let market_before = env.lamports(&level);
+    env.refund(&alice, level).unwrap();
+    assert_eq!(market_before - env.lamports(&level), ENTRY_FEE);
+}
+
+#[test]
+fn high_stakes_markets_need_two_resolvers() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let results_signer = env.user(10);
+    let arbiter = env.user(10);
+    env.set_results_signer(results_signer.pubkey()).unwrap();
+    env.set_resolution_policy(2 * ENTRY_FEE, arbiter.pubkey()).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let small = env.create_market(&creator, "ITA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    let large = env.create_market(&creator, "ITA-JUV-ROM", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, small, MatchOutcome::Home).unwrap();
+    for player in [&alice, &bob] {
+        env.join(player, large, MatchOutcome::Home).unwrap();
+    }
+    env.warp_to(end);
+
+    // Pools below the threshold still resolve with one signature
+    let unneeded = env.sign_resolution(&creator, small, (2, 1));
+    assert_eq!(error_code(&unneeded), Some(MarketError::CoSignersNotRequired.into()));
+    env.resolve(&creator, small, (2, 1)).unwrap();
+
+    let single = env.resolve(&creator, large, (2, 1));
+    assert_eq!(error_code(&single), Some(MarketError::CoSignersRequired.into()));
+
+    // Only the creator, results signer and arbiter can sign
+    let outsider = env.sign_resolution(&alice, large, (2, 1));
+    assert_eq!(error_code(&outsider), Some(MarketError::UnauthorizedResolver.into()));
+
+    // The first signature can't settle on its own, nor count towards a different outcome
+    env.sign_resolution(&creator, large, (2, 1)).unwrap();
+    let alone = env.co_sign_resolution(&creator, large, (2, 1));
+    assert_eq!(error_code(&alone), Some(MarketError::MissingCoSignature.into()));
+    let disagree = env.co_sign_resolution(&arbiter, large, (0, 2));
+    assert_eq!(error_code(&disagree), Some(MarketError::MissingCoSignature.into()));
+
+    env.co_sign_resolution(&arbiter, large, (2, 1)).unwrap();
+    let state: Market = env.account(&large);
+    assert_eq!(state.status, MarketStatus::Resolved);
+    assert_eq!(state.outcome, Some(MatchOutcome::Home));
+    env.withdraw(&bob, large).unwrap();
+}
+
+#[test]
+fn markets_carry_registered_discovery_tags() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    env.add_tag(TagCategory::League, "epl").unwrap();
+    env.add_tag(TagCategory::Country, "england").unwrap();
+
+    let epl = encode_tag("epl").unwrap();
+    let england = encode_tag("england").unwrap();
+    let (kickoff, end) = schedule(&env);
+
+    // Tags must be registered, and for the slot they're used in
+    let mut tags = [NO_TAG; TAG_SLOTS];
+    tags[TagCategory::League.slot()] = encode_tag("laliga").unwrap();
+    let unregistered = env.create_tagged_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end, tags);
+    assert_eq!(error_code(&unregistered), Some(FactoryError::UnregisteredTag.into()));
+
+    let mut tags = [NO_TAG; TAG_SLOTS];
+    tags[TagCategory::Country.slot()] = epl;
+    let misplaced = env.create_tagged_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end, tags);
+    assert_eq!(error_code(&misplaced), Some(FactoryError::UnregisteredTag.into()));
+
+    let mut tags = [NO_TAG; TAG_SLOTS];
+    tags[TagCategory::League.slot()] = epl;
+    tags[TagCategory::Country.slot()] = england;
+    env.create_tagged_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end, tags).unwrap();
+
+    // Tags sit at a fixed offset ahead of the match ID, ready for memcmp filters
+    let factory = find_factory_address().0;
+    let registry_address = find_market_registry_address(&factory, "ENG-LIV-EVE").0;
+    let registry: MarketRegistry = env.account(&registry_address);
+    assert_eq!(registry.tags, tags);
+    let data = env.svm.get_account(&registry_address).unwrap().data;
+    let offset = 8 + 3 * 32 + TagCategory::Country.slot() * england.len();
+    assert_eq!(data[offset..offset + england.len()], england);
+
+    // Untagged markets need no tag accounts at all
+    env.create_market(&creator, "ENG-MUN-MCI", ENTRY_FEE, kickoff, end).unwrap();
+
+    let stranger = env.user(1);
+    let ix = cryptoscore_factory_interface::instruction::add_tag(
+        cryptoscore_factory_interface::accounts::AddTag::new(stranger.pubkey(), TagCategory::Sport, &epl),
+        TagCategory::Sport,
+        epl,
+    );
+    let unauthorized = env.send(&[ix], &stranger);
+    assert_eq!(error_code(&unauthorized), Some(FactoryError::Unauthorized.into()));
+}
+
+#[test]
+fn pending_claims_track_unclaimed_wins() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+    let (kickoff, end) = schedule(&env);
+    let first = env.create_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    let second = env.create_market(&creator, "ENG-MUN-MCI", ENTRY_FEE, kickoff, end).unwrap();
+    for market in [first, second] {
+        env.join(&alice, market, MatchOutcome::Home).unwrap();
+        env.join(&bob, market, MatchOutcome::Away).unwrap();
+    }
+
+    let unresolved = env.record_pending_claim(&cranker, first, alice.pubkey());
+    assert_eq!(error_code(&unresolved), Some(MarketError::MarketNotResolved.into()));
+
+    env.warp_to(end);
+    env.resolve(&creator, first, (2, 1)).unwrap();
+    env.resolve(&creator, second, (2, 1)).unwrap();
+    let loser = env.record_pending_claim(&cranker, first, bob.pubkey());
+    assert_eq!(error_code(&loser), Some(MarketError::NotAWinner.into()));
+
+    env.record_pending_claim(&cranker, first, alice.pubkey()).unwrap();
+    env.record_pending_claim(&cranker, second, alice.pubkey()).unwrap();
+    let repeated = env.record_pending_claim(&cranker, first, alice.pubkey());
+    assert_eq!(error_code(&repeated), Some(DashboardError::ClaimAlreadyPending.into()));
+    let address = find_pending_claims_address(&alice.pubkey()).0;
+    let pending: PendingClaims = env.account(&address);
+    assert_eq!((pending.user, pending.markets), (alice.pubkey(), vec![first, second]));
+
+    // Withdrawing prunes the market, and it can't be listed again
+    env.withdraw(&alice, first).unwrap();
+    assert_eq!(env.account::<PendingClaims>(&address).markets, vec![second]);
+    let claimed = env.record_pending_claim(&cranker, first, alice.pubkey());
+    assert_eq!(error_code(&claimed), Some(MarketError::AlreadyWithdrawn.into()));
+
+    env.dismiss_pending_claim(&alice, second).unwrap();
+    assert!(env.account::<PendingClaims>(&address).markets.is_empty());
+    let dismissed = env.dismiss_pending_claim(&alice, second);
+    assert_eq!(error_code(&dismissed), Some(DashboardError::ClaimNotPending.into()));
+
+    // Winners nobody recorded still withdraw
+    let carol = env.user(10);
+    let (kickoff, end) = schedule(&env);
+    let third = env.create_market(&creator, "ENG-ARS-TOT", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&carol, third, MatchOutcome::Draw).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, third, (1, 1)).unwrap();
+    env.withdraw(&carol, third).unwrap();
+}
+
+#[test]
+fn losing_streaks_earn_entry_rebates() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let dave = env.user(10);
+    let cranker = env.user(10);
+
+    let invalid = env.set_rebate_policy(2, 10_001, ENTRY_FEE);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidRebatePolicy.into()));
+    env.set_rebate_policy(2, 5_000, ENTRY_FEE * 3 / 4).unwrap();
+    env.create_rebate_tracker(&alice).unwrap();
+    env.create_rebate_tracker(&dave).unwrap();
+
+    // A well-funded market fills the treasury while Alice and Dave lose two tracked markets
+    let (kickoff, end) = schedule(&env);
+    let funding = env.create_market(&creator, "ITA-INT-MIL", 5 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+    env.join(&bob, funding, MatchOutcome::Home).unwrap();
+    env.join(&creator, funding, MatchOutcome::Away).unwrap();
+    let mut losses = Vec::new();
+    for match_id in ["ITA-JUV-ROM", "ITA-NAP-LAZ"] {
+        let market = env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+        env.join(&alice, market, MatchOutcome::Away).unwrap();
+        env.join(&dave, market, MatchOutcome::Draw).unwrap();
+        env.join(&bob, market, MatchOutcome::Home).unwrap();
+        losses.push(market);
+    }
+    let unresolved = env.settle_rebate_entry(&cranker, losses[0], alice.pubkey());
+    assert_eq!(error_code(&unresolved), Some(MarketError::MarketNotResolved.into()));
+
+    env.warp_to(end);
+    for market in [funding, losses[0], losses[1]] {
+        env.resolve(&creator, market, (2, 1)).unwrap();
+    }
+    let correctable = env.settle_rebate_entry(&cranker, losses[0], alice.pubkey());
+    assert_eq!(error_code(&correctable), Some(MarketError::CorrectionWindowOpen.into()));
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+
+    // Each tracked entry settles once its result is final
+    env.settle_rebate_entry(&cranker, losses[0], alice.pubkey()).unwrap();
+    let repeated = env.settle_rebate_entry(&cranker, losses[0], alice.pubkey());
+    assert_eq!(error_code(&repeated), Some(DashboardError::EntryNotTracked.into()));
+    let untracked = env.settle_rebate_entry(&cranker, losses[0], bob.pubkey());
+    assert!(untracked.is_err());
+
+    // With an entry still open the streak isn't due, so the next join pays full price
+    let policy_address = find_rebate_policy_address(&find_factory_address().0).0;
+    let (kickoff, end) = schedule(&env);
+    let next = env.create_market(&creator, "ITA-ATA-FIO", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, next, MatchOutcome::Away).unwrap();
+    assert_eq!(env.account::<RebatePolicy>(&policy_address).rebate_count, 0);
+
+    // Once both losses settle, Dave's next join is rebated half its entry fee
+    for market in &losses {
+        env.settle_rebate_entry(&cranker, *market, dave.pubkey()).unwrap();
+    }
+    let tracker_address = find_rebate_tracker_address(&dave.pubkey()).0;
+    assert_eq!(env.account::<RebateTracker>(&tracker_address).loss_streak, 2);
+    let treasury_address = find_treasury_address(&find_factory_address().0).0;
+    let treasury_before = env.lamports(&treasury_address);
+    env.join(&dave, next, MatchOutcome::Home).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE / 2);
+    let tracker: RebateTracker = env.account(&tracker_address);
+    assert_eq!((tracker.rebated_losses, tracker.open_entries), (2, vec![next]));
+
+    // A win resets Dave's streak; Alice's third loss makes hers due, but the epoch cap leaves a quarter of the fee
+    env.warp_to(end);
+    env.resolve(&creator, next, (2, 1)).unwrap();
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    env.settle_rebate_entry(&cranker, next, dave.pubkey()).unwrap();
+    assert_eq!(env.account::<RebateTracker>(&tracker_address).loss_streak, 0);
+    for market in [losses[1], next] {
+        env.settle_rebate_entry(&cranker, market, alice.pubkey()).unwrap();
+    }
+    let treasury_before = env.lamports(&treasury_address);
+    let (kickoff, end) = schedule(&env);
+    let last = env.create_market(&creator, "ITA-BOL-TOR", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, last, MatchOutcome::Home).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE / 4);
+
+    let policy: RebatePolicy = env.account(&policy_address);
+    assert_eq!((policy.rebate_count, policy.total_paid), (2, ENTRY_FEE * 3 / 4));
+    assert_eq!(policy.epoch_paid, ENTRY_FEE * 3 / 4);
+
+    // Resetting drops the open entry and the streak, leaving it for the crank to skip
+    let alice_tracker = find_rebate_tracker_address(&alice.pubkey()).0;
+    assert_eq!(env.account::<RebateTracker>(&alice_tracker).rebated_losses, 3);
+    env.reset_rebate_tracker(&alice).unwrap();
+    let tracker: RebateTracker = env.account(&alice_tracker);
+    assert!(tracker.open_entries.is_empty());
+    assert_eq!((tracker.loss_streak, tracker.rebated_losses), (0, 0));
+    env.warp_to(end);
+    env.resolve(&creator, last, (0, 2)).unwrap();
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    let dropped = env.settle_rebate_entry(&cranker, last, alice.pubkey());
+    assert_eq!(error_code(&dropped), Some(DashboardError::EntryNotTracked.into()));
+}
+
+#[test]
+fn reputation_and_badge_holders_get_entry_discounts() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+
+    let unthresholded = DiscountTier { min_reputation: 0, min_badges: 0, discount_bps: 1_000 };
+    let invalid = env.set_discount_policy(vec![unthresholded], ENTRY_FEE);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidDiscountPolicy.into()));
+    let tier = DiscountTier { min_reputation: 1, min_badges: 0, discount_bps: 100 };
+    let too_many = env.set_discount_policy(vec![tier; MAX_DISCOUNT_TIERS + 1], ENTRY_FEE);
+    assert_eq!(error_code(&too_many), Some(FactoryError::InvalidDiscountPolicy.into()));
+    let tiers = vec![
+        DiscountTier { min_reputation: 4, min_badges: 0, discount_bps: 2_000 },
+        DiscountTier { min_reputation: 100, min_badges: 0, discount_bps: 5_000 },
+        DiscountTier { min_reputation: 0, min_badges: 1, discount_bps: 1_000 },
+    ];
+    env.set_discount_policy(tiers, ENTRY_FEE * 2 / 5).unwrap();
+
+    // A well-funded market fills the treasury and earns its creator a first badge
+    let (kickoff, end) = schedule(&env);
+    let funding = env.create_market(&creator, "ESP-RMA-BAR", 5 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+    env.join(&bob, funding, MatchOutcome::Home).unwrap();
+    env.join(&creator, funding, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, funding, (2, 1)).unwrap();
+
+    // Alice's wins recorded from their markets earn enough reputation for the first tier
+    for i in 0..4 {
+        let won = settled_entry(&mut env, &creator, &alice, &format!("ESP-WIN-{i}"), MatchOutcome::Home, Some((1, 0)));
+        env.record_result(&cranker, alice.pubkey(), won).unwrap();
+    }
+
+    // Bob has neither reputation nor badges and pays full price
+    let treasury_address = find_treasury_address(&find_factory_address().0).0;
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ESP-ATM-SEV", ENTRY_FEE, kickoff, end).unwrap();
+    let treasury_before = env.lamports(&treasury_address);
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    assert_eq!(env.lamports(&treasury_address), treasury_before);
+
+    // Alice's reputation and the creator's badge each reach a tier
+    env.join(&alice, market, MatchOutcome::Away).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE / 5);
+    env.join(&creator, market, MatchOutcome::Draw).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE * 3 / 10);
+
+    // The epoch cap leaves only part of Alice's next discount
+    let treasury_before = env.lamports(&treasury_address);
+    let next = env.create_market(&creator, "ESP-VAL-BET", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, next, MatchOutcome::Home).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE / 10);
+
+    let policy: DiscountPolicy = env.account(&find_discount_policy_address(&find_factory_address().0).0);
+    assert_eq!((policy.discount_count, policy.total_paid), (3, ENTRY_FEE * 2 / 5));
+    assert_eq!(policy.epoch_paid, policy.epoch_cap);
+}
+
+#[test]
+fn vouchers_cover_entry_fees_from_the_treasury() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+
+    // A well-funded market fills the treasury
+    let (kickoff, end) = schedule(&env);
+    let funding = env.create_market(&creator, "ENG-ARS-TOT", 5 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+    env.join(&bob, funding, MatchOutcome::Home).unwrap();
+    env.join(&creator, funding, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, funding, (2, 1)).unwrap();
+
+    let (kickoff, end) = schedule(&env);
+    let expires_at = end + HOUR;
+    let invalid = env.create_voucher_campaign(1, ENTRY_FEE, 2 * ENTRY_FEE, expires_at);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidVoucherCampaign.into()));
+    env.create_voucher_campaign(1, ENTRY_FEE * 3 / 2, ENTRY_FEE, expires_at).unwrap();
+    env.issue_voucher(1, alice.pubkey()).unwrap();
+    env.issue_voucher(1, bob.pubkey()).unwrap();
+    assert!(env.issue_voucher(1, bob.pubkey()).is_err());
+
+    let market = env.create_market(&creator, "ENG-CHE-LIV", ENTRY_FEE, kickoff, end).unwrap();
+    let pricey = env.create_market(&creator, "ENG-MCI-MUN", 2 * ENTRY_FEE, kickoff, end).unwrap();
+
+    // The treasury pays Alice's entry fee straight into the pool
+    let treasury_address = find_treasury_address(&find_factory_address().0).0;
+    let (treasury_before, market_before) = (env.lamports(&treasury_address), env.lamports(&market));
+    env.voucher_join(&alice, market, MatchOutcome::Home, 1).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), ENTRY_FEE);
+    assert_eq!(env.lamports(&market) - market_before, ENTRY_FEE);
+    assert_eq!(env.account::<Market>(&market).total_pool, ENTRY_FEE);
+
+    let campaign_address = find_voucher_campaign_address(&find_factory_address().0, 1).0;
+    let voucher: Voucher = env.account(&find_voucher_address(&campaign_address, &alice.pubkey()).0);
+    assert_eq!(voucher.redeemed_market, market);
+    let reused = env.voucher_join(&alice, pricey, MatchOutcome::Home, 1);
+    assert_eq!(error_code(&reused), Some(FactoryError::VoucherRedeemed.into()));
+
+    // Bob's voucher covers neither a pricier entry nor more than the budget has left
+    let pricier = env.voucher_join(&bob, pricey, MatchOutcome::Away, 1);
+    assert_eq!(error_code(&pricier), Some(FactoryError::VoucherEntryFeeTooHigh.into()));
+    let exhausted = env.voucher_join(&bob, market, MatchOutcome::Away, 1);
+    assert_eq!(error_code(&exhausted), Some(FactoryError::VoucherBudgetExhausted.into()));
+    assert!(env.voucher_join(&carol, market, MatchOutcome::Away, 1).is_err());
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+
+    let campaign: VoucherCampaign = env.account(&campaign_address);
+    assert_eq!((campaign.spent, campaign.issued, campaign.redeemed), (ENTRY_FEE, 2, 1));
+
+    env.warp_to(expires_at);
+    let expired = env.issue_voucher(1, carol.pubkey());
+    assert_eq!(error_code(&expired), Some(FactoryError::VoucherExpired.into()));
+}
+
+#[test]
+fn keepers_are_tipped_for_permissionless_cranks() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let keeper = env.user(10);
+    let tip = ENTRY_FEE / 100;
+
+    let invalid = env.set_keeper_tip_policy(tip, tip - 1);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidKeeperTipPolicy.into()));
+
+    // A well-funded market fills the treasury before tips are turned on
+    let (kickoff, end) = schedule(&env);
+    let funding = env.create_market(&creator, "POR-BEN-POR", 5 * LAMPORTS_PER_SOL, kickoff, end).unwrap();
+    env.join(&bob, funding, MatchOutcome::Home).unwrap();
+    env.join(&creator, funding, MatchOutcome::Away).unwrap();
+    let market = env.create_market(&creator, "POR-SCP-BRA", ENTRY_FEE, kickoff, end).unwrap();
+    let small = env.create_market(&creator, "POR-GUI-VIT", ENTRY_FEE / 10, kickoff, end).unwrap();
+    for user in [&alice, &bob] {
+        env.create_rebate_tracker(user).unwrap();
+    }
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.join(&alice, small, MatchOutcome::Home).unwrap();
+    env.join(&bob, small, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    for address in [funding, market, small] {
+        env.resolve(&creator, address, (2, 1)).unwrap();
+    }
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+
+    // Without a policy cranks run untipped and leave no tally
+    let treasury_address = find_treasury_address(&find_factory_address().0).0;
+    let treasury_before = env.lamports(&treasury_address);
+    env.record_pending_claim(&keeper, funding, bob.pubkey()).unwrap();
+    assert_eq!(env.lamports(&treasury_address), treasury_before);
+    assert_eq!(env.lamports(&find_keeper_tips_address(&funding).0), 0);
+
+    // Each crank is tipped until the market's budget runs out
+    env.set_keeper_tip_policy(tip, tip * 5 / 2).unwrap();
+    env.record_pending_claim(&keeper, market, alice.pubkey()).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), tip);
+    env.settle_rebate_entry(&keeper, market, alice.pubkey()).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), 2 * tip);
+    env.settle_rebate_entry(&keeper, market, bob.pubkey()).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), tip * 5 / 2);
+    let tips: KeeperTips = env.account(&find_keeper_tips_address(&market).0);
+    assert_eq!((tips.market, tips.paid, tips.tip_count), (market, tip * 5 / 2, 3));
+
+    // A small market can only tip the platform fee its pool owes
+    let treasury_before = env.lamports(&treasury_address);
+    env.record_pending_claim(&keeper, small, alice.pubkey()).unwrap();
+    let platform_fee = market_fees(ENTRY_FEE / 5).unwrap().platform_fee;
+    assert!(platform_fee < tip);
+    assert_eq!(treasury_before - env.lamports(&treasury_address), platform_fee);
+    env.settle_rebate_entry(&keeper, small, alice.pubkey()).unwrap();
+    assert_eq!(treasury_before - env.lamports(&treasury_address), platform_fee);
+
+    let policy: KeeperTipPolicy = env.account(&find_keeper_tip_policy_address(&find_factory_address().0).0);
+    assert_eq!((policy.tip_count, policy.total_paid), (4, tip * 5 / 2 + platform_fee));
+}
+
+#[test]
+fn points_ledger_credits_wagers_and_markets_by_epoch() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+    let epoch = week_of(env.now());
+
+    let (kickoff, end) = schedule(&env);
+    let first = env.create_market(&creator, "FRA-PSG-OLM", ENTRY_FEE, kickoff, end).unwrap();
+    let second = env.create_market(&creator, "FRA-LYO-MON", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, first, MatchOutcome::Home).unwrap();
+    env.join(&alice, second, MatchOutcome::Draw).unwrap();
+    env.join(&bob, first, MatchOutcome::Away).unwrap();
+
+    let ledger: PointsLedger = env.account(&find_points_ledger_address(&alice.pubkey()).0);
+    assert_eq!((ledger.user, ledger.wager_points, ledger.creator_points), (alice.pubkey(), 2 * ENTRY_FEE, 0));
+    assert_eq!(ledger.points_in(epoch), Some(2 * ENTRY_FEE));
+    let ledger: PointsLedger = env.account(&find_points_ledger_address(&creator.pubkey()).0);
+    assert_eq!(ledger.creator_points, 2 * POINTS_PER_MARKET_CREATED);
+    let totals: PointsTotals = env.account(&find_points_totals_address().0);
+    let total = totals.total_in(epoch).unwrap();
+    assert_eq!((total.points, total.users), (2 * POINTS_PER_MARKET_CREATED + 3 * ENTRY_FEE, 3));
+
+    // Only finished epochs can be snapshotted
+    let early = env.snapshot_points(&cranker, alice.pubkey(), epoch);
+    assert_eq!(error_code(&early), Some(DashboardError::PointsEpochNotFinished.into()));
+
+    // Next week's credits land in their own tally
+    env.warp_to(env.now() + SECONDS_PER_WEEK);
+    let (kickoff, end) = schedule(&env);
+    let third = env.create_market(&creator, "FRA-LIL-REN", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, third, MatchOutcome::Away).unwrap();
+    let ledger: PointsLedger = env.account(&find_points_ledger_address(&alice.pubkey()).0);
+    assert_eq!(ledger.wager_points, 3 * ENTRY_FEE);
+    assert_eq!((ledger.points_in(epoch), ledger.points_in(epoch + 1)), (Some(2 * ENTRY_FEE), Some(ENTRY_FEE)));
+
+    env.snapshot_points(&cranker, alice.pubkey(), epoch).unwrap();
+    env.snapshot_points(&cranker, bob.pubkey(), epoch).unwrap();
+    env.snapshot_points_total(&cranker, epoch).unwrap();
+    let snapshot: PointsSnapshot = env.account(&find_points_snapshot_address(&alice.pubkey(), epoch).0);
+    assert_eq!((snapshot.user, snapshot.epoch, snapshot.points), (alice.pubkey(), epoch, 2 * ENTRY_FEE));
+    let snapshot: PointsSnapshot = env.account(&find_points_snapshot_address(&bob.pubkey(), epoch).0);
+    assert_eq!(snapshot.points, ENTRY_FEE);
+    let snapshot: PointsTotalSnapshot = env.account(&find_points_total_snapshot_address(epoch).0);
+    assert_eq!((snapshot.points, snapshot.users), (total.points, 3));
+    let repeated = env.snapshot_points(&cranker, alice.pubkey(), epoch);
+    assert!(repeated.is_err());
+
+    // Once a later epoch reuses the creator's slot, the old epoch can no longer be snapshotted
+    env.warp_to(env.now() + RECENT_POINTS_EPOCHS as i64 * SECONDS_PER_WEEK);
+    let (kickoff, end) = schedule(&env);
+    env.create_market(&creator, "FRA-NIC-LEN", ENTRY_FEE, kickoff, end).unwrap();
+    let expired = env.snapshot_points(&cranker, creator.pubkey(), epoch);
+    assert_eq!(error_code(&expired), Some(DashboardError::PointsEpochUnavailable.into()));
+
+    // Prop and correct-score entries earn wager points like match predictions
+    let (kickoff, end) = schedule(&env);
+    let props = env.create_market(&creator, "FRA-NAN-BRE", ENTRY_FEE, kickoff, end).unwrap();
+    let scores = env.create_market(&creator, "FRA-TFC-STR", ENTRY_FEE, kickoff, end).unwrap();
+    env.list_prop_players(&creator, props, PropKind::NextGoalscorer, vec![9, 10]).unwrap();
+    env.enable_correct_score(&creator, scores).unwrap();
+    env.join_prop(&bob, props, 9).unwrap();
+    env.join_correct_score(&bob, scores, Scoreline::Exact { home: 1, away: 0 }).unwrap();
+    let ledger: PointsLedger = env.account(&find_points_ledger_address(&bob.pubkey()).0);
+    assert_eq!(ledger.wager_points, 3 * ENTRY_FEE);
+}
+
+#[test]
+fn reputation_tokens_track_settled_records() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let cranker = env.user(10);
+
+    env.initialize_reputation_mint(&cranker).unwrap();
+    let repeated = env.initialize_reputation_mint(&cranker);
+    assert!(repeated.is_err());
+
+    // Users without settled markets have no stats to sync
+    let unknown = env.sync_reputation(&cranker, alice.pubkey());
+    assert!(unknown.is_err());
+
+    // Early markets only count towards the track record
+    for i in 0..4 {
+        let won = settled_entry(&mut env, &creator, &alice, &format!("ENG-WIN-{i}"), MatchOutcome::Home, Some((2, 0)));
+        env.record_result(&cranker, alice.pubkey(), won).unwrap();
+    }
+    env.sync_reputation(&cranker, alice.pubkey()).unwrap();
+    assert_eq!(env.reputation(&alice.pubkey()), 4);
+
+    // Voids don't move it, and syncing an unchanged record is a no-op
+    let void = settled_entry(&mut env, &creator, &alice, "ENG-VOID", MatchOutcome::Home, None);
+    env.record_result(&cranker, alice.pubkey(), void).unwrap();
+    env.sync_reputation(&cranker, alice.pubkey()).unwrap();
+    assert_eq!(env.reputation(&alice.pubkey()), 4);
+
+    // Accuracy counts once enough markets have settled, and a loss burns some back
+    let won = settled_entry(&mut env, &creator, &alice, "ENG-WIN-4", MatchOutcome::Away, Some((0, 1)));
+    env.record_result(&cranker, alice.pubkey(), won).unwrap();
+    env.sync_reputation(&cranker, alice.pubkey()).unwrap();
+    assert_eq!(env.reputation(&alice.pubkey()), reputation_balance(5, 0));
+    let lost = settled_entry(&mut env, &creator, &alice, "ENG-LOSS", MatchOutcome::Draw, Some((3, 1)));
+    env.record_result(&cranker, alice.pubkey(), lost).unwrap();
+    env.sync_reputation(&alice, alice.pubkey()).unwrap();
+    assert_eq!(env.reputation(&alice.pubkey()), reputation_balance(5, 1));
+    assert!(reputation_balance(5, 1) < reputation_balance(5, 0));
+}
+
+#[test]
+fn breakdowns_count_resolved_entries_by_pick_and_league() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let cranker = env.user(10);
+
+    // Entries count by what Alice picked and the league their match ID starts with
+    let won = settled_entry(&mut env, &creator, &alice, "EPL-ARS-CHE", MatchOutcome::Away, Some((0, 1)));
+    let lost = settled_entry(&mut env, &creator, &alice, "EPL-LIV-EVE", MatchOutcome::Away, Some((2, 0)));
+    let other = settled_entry(&mut env, &creator, &alice, "LALIGA-RMA-BAR", MatchOutcome::Home, Some((1, 0)));
+    for market in [won, lost, other] {
+        env.record_breakdown(&cranker, alice.pubkey(), market).unwrap();
+    }
+
+    let breakdown: UserBreakdown = env.account(&find_user_breakdown_address(&alice.pubkey()).0);
+    assert_eq!((breakdown.away.correct, breakdown.away.incorrect), (1, 1));
+    assert_eq!((breakdown.home.correct, breakdown.home.incorrect), (1, 0));
+    assert_eq!(breakdown.draw.correct + breakdown.draw.incorrect, 0);
+    assert_eq!(breakdown.leagues.len(), 2);
+    assert_eq!(breakdown.leagues[0].code, UserBreakdown::league_code_bytes("EPL"));
+    assert_eq!((breakdown.leagues[0].accuracy.correct, breakdown.leagues[0].accuracy.incorrect), (1, 1));
+
+    // Each entry counts once, and only entries the user made
+    assert!(env.record_breakdown(&cranker, alice.pubkey(), won).is_err());
+    assert!(env.record_breakdown(&cranker, creator.pubkey(), won).is_err());
+
+    // Cancelled markets have no outcome to be right or wrong about
+    let void = settled_entry(&mut env, &creator, &alice, "EPL-MCI-MUN", MatchOutcome::Home, None);
+    let unresolved = env.record_breakdown(&cranker, alice.pubkey(), void);
+    assert_eq!(error_code(&unresolved), Some(DashboardError::MarketNotResolved.into()));
+
+    let long = settled_entry(&mut env, &creator, &alice, "BUNDESLIGA-FCB-BVB", MatchOutcome::Home, Some((1, 0)));
+    let invalid = env.record_breakdown(&cranker, alice.pubkey(), long);
+    assert_eq!(error_code(&invalid), Some(DashboardError::InvalidLeagueCode.into()));
+}
+
+#[test]
+fn head_to_head_records_count_shared_resolved_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let (alice, bob) = (env.user(10), env.user(10));
+    let (first, second) = if alice.pubkey() < bob.pubkey() { (alice, bob) } else { (bob, alice) };
+    let cranker = env.user(10);
+
+    // Only the first user picks the winner
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "ITA-JUV-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&first, market, MatchOutcome::Home).unwrap();
+    env.join(&second, market, MatchOutcome::Away).unwrap();
+
+    // Nothing is compared before the market resolves
+    let early = env.record_head_to_head(&cranker, first.pubkey(), second.pubkey(), market);
+    assert_eq!(error_code(&early), Some(DashboardError::MarketNotResolved.into()));
+
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 0)).unwrap();
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    let unsorted = env.record_head_to_head(&cranker, second.pubkey(), first.pubkey(), market);
+    assert_eq!(error_code(&unsorted), Some(DashboardError::UnsortedHeadToHeadPair.into()));
+    env.record_head_to_head(&cranker, first.pubkey(), second.pubkey(), market).unwrap();
+
+    let record: HeadToHead = env.account(&find_head_to_head_address(&first.pubkey(), &second.pubkey()).0);
+    assert_eq!((record.shared_markets, record.user_a_wins, record.user_b_wins), (1, 1, 0));
+    assert_eq!(record.last_market, market);
+
+    // A market counts once, and only if both users entered it
+    assert!(env.record_head_to_head(&cranker, first.pubkey(), second.pubkey(), market).is_err());
+    let solo = settled_entry(&mut env, &creator, &first, "ITA-INT-NAP", MatchOutcome::Draw, Some((1, 1)));
+    assert!(env.record_head_to_head(&cranker, first.pubkey(), second.pubkey(), solo).is_err());
+
+    let record: HeadToHead = env.account(&find_head_to_head_address(&first.pubkey(), &second.pubkey()).0);
+    assert_eq!(record.shared_markets, 1);
+}
+
+#[test]
+fn watchlists_follow_markets_in_order() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let fan = env.user(10);
+    let (kickoff, end) = schedule(&env);
+    let first = env.create_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    let second = env.create_market(&creator, "ENG-MUN-MCI", ENTRY_FEE, kickoff, end).unwrap();
+    let third = env.create_market(&creator, "ENG-ARS-TOT", ENTRY_FEE, kickoff, end).unwrap();
+
+    env.watch(&fan, first).unwrap();
+    env.watch(&fan, second).unwrap();
+    env.watch(&fan, third).unwrap();
+    let duplicate = env.watch(&fan, first);
+    assert_eq!(error_code(&duplicate), Some(DashboardError::AlreadyWatching.into()));
+
+    // Only market accounts can be followed
+    let not_a_market = env.watch(&fan, creator.pubkey());
+    assert_eq!(error_code(&not_a_market), Some(DashboardError::InvalidMarketAccount.into()));
+
+    env.unwatch(&fan, second).unwrap();
+    let watchlist: Watchlist = env.account(&find_watchlist_address(&fan.pubkey()).0);
+    assert_eq!(watchlist.user, fan.pubkey());
+    assert_eq!(watchlist.markets, vec![first, third]);
+
+    let missing = env.unwatch(&fan, second);
+    assert_eq!(error_code(&missing), Some(DashboardError::NotWatching.into()));
+
+    // Each wallet has its own list
+    let other = env.user(1);
+    let empty = env.unwatch(&other, first);
+    assert!(empty.is_err());
+}
+
+#[test]
+fn market_series_lists_a_matchweek_at_once() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let (kickoff, end) = schedule(&env);
+    let fixtures = [
+        ("EPL-MW1-LIV-EVE", kickoff, end),
+        ("EPL-MW1-MUN-MCI", kickoff + HOUR, end + HOUR),
+        ("EPL-MW1-ARS-TOT", kickoff + 2 * HOUR, end + 2 * HOUR),
+    ];
+
+    let markets = env.create_market_series(&creator, &fixtures, ENTRY_FEE).unwrap();
+    assert_eq!(markets.len(), fixtures.len());
+
+    let factory_address = find_factory_address().0;
+    for (&(match_id, kickoff_time, end_time), market_address) in fixtures.iter().zip(&markets) {
+        let market: Market = env.account(market_address);
+        assert_eq!(market.match_id, match_id);
+        assert_eq!(market.creator, creator.pubkey());
+        assert_eq!(market.kickoff_time, kickoff_time);
+        assert_eq!(market.entry_fee, ENTRY_FEE);
+
+        let registry: MarketRegistry = env.account(&find_market_registry_address(&factory_address, match_id).0);
+        assert_eq!(registry.market_address, *market_address);
+        assert_eq!(registry.end_time, end_time);
+    }
+    let factory: Factory = env.account(&factory_address);
+    assert_eq!(factory.market_count, 3);
+
+    // Listed markets take joins like any other
+    let player = env.user(1);
+    env.join(&player, markets[1], MatchOutcome::Draw).unwrap();
+
+    // One bad fixture fails the whole series
+    let invalid = [("EPL-MW2-CHE-NEW", kickoff, end), ("EPL-MW2-BHA-WHU", end, kickoff)];
+    let result = env.create_market_series(&creator, &invalid, ENTRY_FEE);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidEndTime.into()));
+    assert_eq!(env.account::<Factory>(&factory_address).market_count, 3);
+
+    let result = env.create_market_series(&creator, &[], ENTRY_FEE);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidSeriesLength.into()));
+}
+
+#[test]
+fn kickoff_buckets_list_each_days_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let factory_address = find_factory_address().0;
+    let (kickoff, end) = schedule(&env);
+    let today = kickoff_day(kickoff);
+    let tomorrow = (today as i64 + 1) * SECONDS_PER_DAY + HOUR;
+
+    let single = env.create_market(&creator, "EPL-KO-LIV-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    let fixtures = [
+        ("EPL-KO-MUN-MCI", kickoff, end),
+        ("EPL-KO-ARS-TOT", tomorrow, tomorrow + 2 * HOUR),
+        ("EPL-KO-CHE-NEW", kickoff, end),
+    ];
+    let series = env.create_market_series(&creator, &fixtures, ENTRY_FEE).unwrap();
+
+    // Fixtures sharing a day share a bucket, in the order they were listed
+    let bucket: KickoffBucket = env.account(&find_kickoff_bucket_address(&factory_address, today).0);
+    assert_eq!(bucket.factory, factory_address);
+    assert_eq!(bucket.day, today);
+    assert_eq!(bucket.markets, vec![single, series[0], series[2]]);
+
+    let bucket: KickoffBucket = env.account(&find_kickoff_bucket_address(&factory_address, today + 1).0);
+    assert_eq!(bucket.day, today + 1);
+    assert_eq!(bucket.markets, vec![series[1]]);
+}
+
+#[test]
+fn creator_fees_accrue_across_markets_until_claimed() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let players: Vec<_> = (0..3).map(|_| env.user(10)).collect();
+    let (kickoff, end) = schedule(&env);
+
+    let nothing = env.claim_creator_earnings(&creator);
+    assert!(nothing.is_err());
+
+    let first = env.create_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    let second = env.create_market(&creator, "ENG-MUN-MCI", ENTRY_FEE, kickoff, end).unwrap();
+    for player in &players {
+        env.join(player, first, MatchOutcome::Home).unwrap();
+    }
+    env.join(&players[0], second, MatchOutcome::Away).unwrap();
+
+    env.warp_to(end);
+    env.resolve(&creator, first, (2, 1)).unwrap();
+    env.resolve(&creator, second, (0, 2)).unwrap();
+
+    let expected = market_fees(3 * ENTRY_FEE).unwrap().creator_fee + market_fees(ENTRY_FEE).unwrap().creator_fee;
+    let address = find_creator_earnings_address(&creator.pubkey()).0;
+    let earnings: CreatorEarnings = env.account(&address);
+    assert_eq!(earnings.creator, creator.pubkey());
+    assert_eq!(earnings.total_accrued, expected);
+    assert_eq!(earnings.markets_accrued, 2);
+
+    // Claiming pays out everything accrued and keeps the ledger rent-exempt
+    let before = env.lamports(&creator.pubkey());
+    env.claim_creator_earnings(&creator).unwrap();
+    assert!(env.lamports(&creator.pubkey()) > before);
+    let earnings: CreatorEarnings = env.account(&address);
+    assert_eq!(earnings.total_claimed, expected);
+    assert_eq!(earnings.unclaimed(), 0);
+    let rent = env.svm.minimum_balance_for_rent_exemption(CreatorEarnings::LEN);
+    assert_eq!(env.lamports(&address), rent);
+
+    let twice = env.claim_creator_earnings(&creator);
+    assert_eq!(error_code(&twice), Some(DashboardError::NothingToClaim.into()));
+}
+
+#[test]
+fn win_lose_markets_refuse_draws() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let home = env.user(10);
+    let away = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    let market = env.create_win_lose_market(&creator, "ATP-SIN-ALC", ENTRY_FEE, kickoff, end).unwrap();
+    assert_eq!(env.account::<Market>(&market).outcome_set, OutcomeSet::WinLose);
+
+    let draw = env.join(&home, market, MatchOutcome::Draw);
+    assert_eq!(error_code(&draw), Some(MarketError::OutcomeNotOffered.into()));
+    env.join(&home, market, MatchOutcome::Home).unwrap();
+    env.join(&away, market, MatchOutcome::Away).unwrap();
+
+    env.warp_to(end);
+    let draw = env.resolve(&creator, market, (1, 1));
+    assert_eq!(error_code(&draw), Some(MarketError::OutcomeNotOffered.into()));
+    env.resolve(&creator, market, (0, 2)).unwrap();
+
+    let market: Market = env.account(&market);
+    assert_eq!(market.status, MarketStatus::Resolved);
+    assert_eq!(market.outcome, Some(MatchOutcome::Away));
+}
+
+#[test]
+fn swapped_payouts_only_go_to_whitelisted_tokens_of_the_winner() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let winner = env.user(10);
+    let loser = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    let market = env.create_market(&creator, "ENG-LIV-EVE", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&winner, market, MatchOutcome::Home).unwrap();
+    env.join(&loser, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    let usdc = Pubkey::new_unique();
+    let too_many = env.set_payout_swap_policy(vec![usdc; MAX_PAYOUT_SWAP_MINTS + 1]);
+    assert_eq!(error_code(&too_many), Some(FactoryError::InvalidPayoutSwapPolicy.into()));
+    env.set_payout_swap_policy(vec![usdc]).unwrap();
+
+    let wrapped_sol = env.token_account(winner.pubkey(), NATIVE_MINT, 0);
+    let usdc_tokens = env.token_account(winner.pubkey(), usdc, 0);
+    let other_tokens = env.token_account(winner.pubkey(), Pubkey::new_unique(), 0);
+    let result = env.withdraw_swapped(&winner, market, wrapped_sol, other_tokens);
+    assert_eq!(error_code(&result), Some(MarketError::PayoutMintNotAllowed.into()));
+
+    // The reward is wrapped in, and swapped into, the winner's own accounts only
+    let loser_tokens = env.token_account(loser.pubkey(), usdc, 0);
+    let result = env.withdraw_swapped(&winner, market, wrapped_sol, loser_tokens);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidSwapAccount.into()));
+    let not_wrapped = env.token_account(winner.pubkey(), usdc, 0);
+    let result = env.withdraw_swapped(&winner, market, not_wrapped, usdc_tokens);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidSwapAccount.into()));
+
+    let loser_wrapped = env.token_account(loser.pubkey(), NATIVE_MINT, 0);
+    let result = env.withdraw_swapped(&loser, market, loser_wrapped, loser_tokens);
+    assert_eq!(error_code(&result), Some(MarketError::NotAWinner.into()));
+
+    // Nothing was paid by the failed swaps
+    env.withdraw(&winner, market).unwrap();
+    let result = env.withdraw_swapped(&winner, market, wrapped_sol, usdc_tokens);
+    assert_eq!(error_code(&result), Some(MarketError::AlreadyWithdrawn.into()));
+}
+
+#[test]
+fn claim_receipts_are_only_minted_for_withdrawn_claims_when_the_factory_enables_them() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let winner = env.user(10);
+    let loser = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    let market = env.create_market(&creator, "ENG-WOL-BOU", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&winner, market, MatchOutcome::Home).unwrap();
+    env.join(&loser, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+    env.withdraw(&winner, market).unwrap();
+
+    let long_uri = "x".repeat(MAX_RECEIPT_URI_BASE_LEN + 1);
+    let result = env.set_receipt_policy(Pubkey::new_unique(), &long_uri);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidReceiptPolicy.into()));
+    env.set_receipt_policy(Pubkey::default(), "https://example.com/receipt").unwrap();
+    let result = env.mint_claim_receipt(&winner, market, Pubkey::default());
+    assert_eq!(error_code(&result), Some(MarketError::ReceiptsDisabled.into()));
+
+    let tree = Pubkey::new_unique();
+    env.set_receipt_policy(tree, "https://example.com/receipt").unwrap();
+    let result = env.mint_claim_receipt(&winner, market, Pubkey::new_unique());
+    assert_eq!(error_code(&result), Some(ErrorCode::ConstraintAddress.into()));
+
+    // Losers never withdraw, so they have no claim to record
+    let result = env.mint_claim_receipt(&loser, market, tree);
+    assert_eq!(error_code(&result), Some(MarketError::ClaimNotWithdrawn.into()));
+
+    // The winner's claim passes every check and only fails at the Bubblegum mint
+    let result = env.mint_claim_receipt(&winner, market, tree);
+    assert!(result.is_err());
+    assert_eq!(error_code(&result), None);
+    assert!(env.svm.get_account(&find_claim_receipt_address(&market, &winner.pubkey()).0).is_none());
+}
+
+#[test]
+fn settlement_reports_account_for_every_lamport_of_swept_and_closed_markets() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let carol = env.user(10);
+    let authority = env.authority.insecure_clone();
+    let (kickoff, end) = schedule(&env);
+
+    let market = env.create_market(&creator, "ESP-SEV-BET", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Home).unwrap();
+    env.join(&carol, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+    env.withdraw(&alice, market).unwrap();
+
+    let unswept = env.compile_settlement_report(&carol, market);
+    assert_eq!(error_code(&unswept), Some(MarketError::MarketNotSettled.into()));
+    env.warp_to(end + CLAIM_WINDOW_SECONDS);
+    env.sweep(&authority, market).unwrap();
+    env.compile_settlement_report(&carol, market).unwrap();
+
+    // Bob's unclaimed share was swept with the dust, but still counts as his reward
+    let settled = env.account::<Market>(&market);
+    let report = env.account::<SettlementReport>(&find_settlement_report_address(&market).0);
+    let reward = (settled.total_pool - settled.fees_paid) / 2;
+    assert_eq!((report.status, report.participants, report.winners), (MarketStatus::Settled, 3, 2));
+    assert_eq!(report.total_in, 3 * ENTRY_FEE);
+    assert_eq!(report.fees_out, settled.fees_paid);
+    assert_eq!(report.rewards_out, 2 * reward);
+    assert_eq!(report.refunds_out, 0);
+    assert_eq!(report.total_in, report.fees_out + report.rewards_out + report.refunds_out + report.dust);
+    assert_eq!(report.paid_out, settled.total_claimed);
+    assert_eq!(report.paid_out, report.rewards_out + report.dust);
+    assert_eq!(report.vault_remaining, 0);
+    assert!(env.compile_settlement_report(&carol, market).is_err());
+
+    let cancelled = env.create_market(&creator, "ESP-VIL-CEL", ENTRY_FEE, kickoff, end + 1).unwrap();
+    env.join(&alice, cancelled, MatchOutcome::Draw).unwrap();
+    env.join(&bob, cancelled, MatchOutcome::Away).unwrap();
+    env.cancel(&authority, cancelled).unwrap();
+    env.refund(&alice, cancelled).unwrap();
+    env.refund(&bob, cancelled).unwrap();
+    env.close(&creator, cancelled).unwrap();
+    env.compile_settlement_report(&carol, cancelled).unwrap();
+
+    let report = env.account::<SettlementReport>(&find_settlement_report_address(&cancelled).0);
+    assert_eq!((report.status, report.winners), (MarketStatus::Closed, 0));
+    assert_eq!((report.fees_out, report.rewards_out), (0, 0));
+    assert_eq!(report.refunds_out, 2 * ENTRY_FEE);
+    assert_eq!(report.dust, 0);
+    assert_eq!(report.paid_out, report.refunds_out);
+}
+
+#[test]
+fn only_the_creator_resolves_during_the_resolution_grace_period() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    let result = env.create_market_with_grace(&creator, "FRA-LYO-MAR", ENTRY_FEE, kickoff, end, -1);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidResolutionGrace.into()));
+    let too_long = MAX_RESOLUTION_GRACE_SECONDS + 1;
+    let result = env.create_market_with_grace(&creator, "FRA-LYO-MAR", ENTRY_FEE, kickoff, end, too_long);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidResolutionGrace.into()));
+
+    // The factory narrows the allowed grace periods
+    let result = env.set_resolution_grace_policy(7200, 3600);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidResolutionGracePolicy.into()));
+    env.set_resolution_grace_policy(3600, 86_400).unwrap();
+    let result = env.create_market_with_grace(&creator, "FRA-LYO-MAR", ENTRY_FEE, kickoff, end, 60);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidResolutionGrace.into()));
+
+    let first = env.create_market_with_grace(&creator, "FRA-LYO-MAR", ENTRY_FEE, kickoff, end, 3600).unwrap();
+    let second = env.create_market_with_grace(&creator, "FRA-NIC-REN", ENTRY_FEE, kickoff, end, 3600).unwrap();
+    assert_eq!(env.account::<Market>(&first).resolution_grace_seconds, 3600);
+    for market in [first, second] {
+        env.join(&alice, market, MatchOutcome::Home).unwrap();
+        env.join(&bob, market, MatchOutcome::Away).unwrap();
+    }
+    env.warp_to(end);
+
+    // Participants wait out the grace period, the creator doesn't
+    let result = env.resolve(&alice, first, (2, 1));
+    assert_eq!(error_code(&result), Some(MarketError::ResolutionGracePeriod.into()));
+    env.resolve(&creator, first, (2, 1)).unwrap();
+    assert_eq!(env.account::<Market>(&first).status, MarketStatus::Resolved);
+
+    env.warp_to(end + 3599);
+    let result = env.resolve(&bob, second, (0, 2));
+    assert_eq!(error_code(&result), Some(MarketError::ResolutionGracePeriod.into()));
+    env.warp_to(end + 3600);
+    env.resolve(&bob, second, (0, 2)).unwrap();
+    assert_eq!(env.account::<Market>(&second).outcome, Some(MatchOutcome::Away));
+}
+
+#[test]
+fn joins_carry_a_note_hash_and_entry_index_and_older_participants_grow_to_hold_them() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    let market = env.create_market(&creator, "POR-BEN-SCP", ENTRY_FEE, kickoff, end).unwrap();
+    let note = note_hash(&market, &alice.pubkey(), b"Benfica have won six straight at home");
+    env.join_with_note(&alice, market, MatchOutcome::Home, note).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+
+    let alice_entry = find_participant_address(&market, &alice.pubkey()).0;
+    let bob_entry = find_participant_address(&market, &bob.pubkey()).0;
+    assert_eq!(env.account::<Participant>(&alice_entry).note_hash, note);
+    assert_eq!(env.account::<Participant>(&bob_entry).note_hash, [0; 32]);
+    assert_eq!(env.account::<Participant>(&alice_entry).entry_index, 0);
+    assert_eq!(env.account::<Participant>(&bob_entry).entry_index, 1);
+
+    // Participants of the current layout have nothing to migrate
+    let result = env.migrate_participant(&bob, bob_entry);
+    assert_eq!(error_code(&result), Some(MarketError::AlreadyMigrated.into()));
+    let result = env.migrate_participant(&bob, market);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidParticipantAccount.into()));
+
+    // Cut Bob's entry back to the layout before notes
+    let mut account = env.svm.get_account(&bob_entry).unwrap();
+    account.data.truncate(cryptoscore_market::Participant::PRE_NOTE_LEN);
+    env.svm.set_account(bob_entry, account).unwrap();
+    env.warp_to(end);
+    env.resolve(&creator, market, (0, 2)).unwrap();
+    assert!(env.withdraw(&bob, market).is_err());
+
+    env.migrate_participant(&bob, bob_entry).unwrap();
+    let migrated: Participant = env.account(&bob_entry);
+    assert_eq!((migrated.note_hash, migrated.entry_index), ([0; 32], cryptoscore_market::Participant::UNINDEXED));
+    env.withdraw(&bob, market).unwrap();
+    assert!(env.account::<Participant>(&bob_entry).has_withdrawn);
+
+    // Entries from before indexing keep their note but are left unindexed too
+    let mut account = env.svm.get_account(&alice_entry).unwrap();
+    account.data.truncate(cryptoscore_market::Participant::PRE_ENTRY_INDEX_LEN);
+    env.svm.set_account(alice_entry, account).unwrap();
+    env.migrate_participant(&alice, alice_entry).unwrap();
+    let migrated: Participant = env.account(&alice_entry);
+    assert_eq!((migrated.note_hash, migrated.entry_index), (note, cryptoscore_market::Participant::UNINDEXED));
+}
+
+#[test]
+fn platforms_run_their_own_markets_treasury_and_results_signer() {
+    let mut env = TestEnv::new();
+    let operator = env.user(10);
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let relayer = env.user(10);
+    let results_signer = Keypair::new();
+
+    let platform_factory = env.create_platform(&operator, "acme-sports", 250).unwrap();
+    let platform: Platform = env.account(&find_platform_address("acme-sports").0);
+    assert_eq!((platform.operator, platform.factory), (operator.pubkey(), platform_factory));
+    let factory: Factory = env.account(&platform_factory);
+    assert_eq!((factory.authority, factory.platform_fee_bps), (operator.pubkey(), 250));
+
+    // Platform IDs are unique and fit in a seed
+    let taken = env.create_platform(&creator, "acme-sports", 250);
+    assert!(taken.is_err());
+    let empty = env.create_platform(&creator, "", 250);
+    assert_eq!(error_code(&empty), Some(FactoryError::InvalidPlatformId.into()));
+
+    // The same match gets a separate market on each platform
+    let (kickoff, end) = schedule(&env);
+    let default = env.create_market(&creator, "NED-AJA-PSV", ENTRY_FEE, kickoff, end).unwrap();
+    let market =
+        env.create_platform_market(&creator, platform_factory, "NED-AJA-PSV", ENTRY_FEE, kickoff, end).unwrap();
+    assert_ne!(market, default);
+    assert_eq!(market, find_market_address(&platform_factory, "NED-AJA-PSV").0);
+    assert_eq!(env.account::<Market>(&market).factory, platform_factory);
+    assert_eq!(env.account::<Factory>(&platform_factory).market_count, 1);
+    assert_eq!(env.account::<Factory>(&find_factory_address().0).market_count, 1);
+
+    // Only the operator configures the platform's oracle
+    let set_signer = |authority: Pubkey| {
+        cryptoscore_factory_interface::instruction::set_results_signer(
+            cryptoscore_factory_interface::accounts::SetResultsSigner::on_factory(platform_factory, authority),
+            results_signer.pubkey(),
+        )
+    };
+    let default_authority = env.authority.pubkey();
+    let result = env.send_as_authority(&[set_signer(default_authority)]);
+    assert_eq!(error_code(&result), Some(FactoryError::Unauthorized.into()));
+    env.send(&[set_signer(operator.pubkey())], &operator).unwrap();
+    env.set_results_signer(Keypair::new().pubkey()).unwrap();
+
+    // The platform's results signer resolves its markets, and its fee goes to the platform's treasury
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&alice, default, MatchOutcome::Home).unwrap();
+    env.warp_to(end + HOUR);
+    let attested = env.attestation(&relayer, &results_signer, default, (2, 1), end);
+    let result = env.send(&attested, &relayer);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidAttestation.into()));
+
+    let attested = env.attestation(&relayer, &results_signer, market, (2, 1), end);
+    env.send(&attested, &relayer).unwrap();
+    assert_eq!(env.account::<Market>(&market).outcome, Some(MatchOutcome::Home));
+    let treasury: Treasury = env.account(&find_treasury_address(&platform_factory).0);
+    assert_eq!(treasury.total_collected, market_fees(ENTRY_FEE).unwrap().platform_fee);
+    assert!(env.svm.get_account(&find_treasury_address(&find_factory_address().0).0).is_none());
+    env.withdraw(&alice, market).unwrap();
+
+    // Stats apply the leaderboard rules of the factory each market was created under
+    let rules = cryptoscore_factory_interface::instruction::update_leaderboard_qualification(
+        cryptoscore_factory_interface::accounts::UpdateFactoryConfig {
+            factory: platform_factory,
+            authority: operator.pubkey(),
+        },
+        1,
+        2 * ENTRY_FEE,
+    );
+    env.send(&[rules], &operator).unwrap();
+    env.warp_to(end + RESULT_CORRECTION_WINDOW_SECONDS + 1);
+    env.record_result(&relayer, alice.pubkey(), market).unwrap();
+    let stats: UserStats = env.account(&find_user_stats_address(&alice.pubkey()).0);
+    assert_eq!((stats.total_markets, stats.qualified_markets), (1, 0));
+}
+
+#[test]
+fn daily_rollups_report_each_platform_day_from_its_counters() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let alice = env.user(10);
+    let bob = env.user(10);
+    let cranker = env.user(10);
+    let factory = find_factory_address().0;
+
+    // Start on a fresh day so the whole market runs within it
+    let day = day_of(env.now()) + 1;
+    env.warp_to(day as i64 * SECONDS_PER_DAY);
+    env.initialize_platform_activity(&cranker, factory).unwrap();
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "POR-BEN-SCP", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Home).unwrap();
+    env.join(&bob, market, MatchOutcome::Away).unwrap();
+    env.warp_to(end + HOUR);
+    env.resolve(&creator, market, (2, 1)).unwrap();
+
+    // The day can only be rolled up once it's over
+    let early = env.emit_daily_rollup(&cranker, factory, day);
+    assert_eq!(error_code(&early), Some(DashboardError::RollupUnavailable.into()));
+
+    env.warp_to((day + 1) as i64 * SECONDS_PER_DAY + HOUR);
+    env.emit_daily_rollup(&cranker, factory, day).unwrap();
+    let platform_stats: PlatformStats = env.account(&find_platform_stats_address().0);
+    let rollup: DailyRollupSnapshot = env.account(&find_daily_rollup_address(&factory, day).0);
+    assert_eq!(rollup.factory, factory);
+    assert_eq!((rollup.totals.day, rollup.totals.markets_created, rollup.totals.markets_resolved), (day, 1, 1));
+    assert_eq!((rollup.totals.joins, rollup.totals.volume), (2, 2 * ENTRY_FEE));
+    assert_eq!(rollup.totals.new_users, platform_stats.estimated_unique_bettors());
+    assert_eq!(rollup.totals.fees, platform_stats.total_fees);
+    let repeated = env.emit_daily_rollup(&cranker, factory, day);
+    assert!(repeated.is_err());
+
+    // Returning bettors aren't new users on later days
+    let (kickoff, end) = schedule(&env);
+    let market = env.create_market(&creator, "POR-BRA-GUI", ENTRY_FEE, kickoff, end).unwrap();
+    env.join(&alice, market, MatchOutcome::Draw).unwrap();
+    env.warp_to((day + 2) as i64 * SECONDS_PER_DAY);
+    env.emit_daily_rollup(&cranker, factory, day + 1).unwrap();
+    let rollup: DailyRollupSnapshot = env.account(&find_daily_rollup_address(&factory, day + 1).0);
+    assert_eq!((rollup.totals.markets_created, rollup.totals.markets_resolved), (1, 0));
+    assert_eq!((rollup.totals.joins, rollup.totals.volume, rollup.totals.new_users), (1, ENTRY_FEE, 0));
+
+    // Quiet days have nothing to roll up
+    env.warp_to((day + 4) as i64 * SECONDS_PER_DAY);
+    let quiet = env.emit_daily_rollup(&cranker, factory, day + 3);
+    assert_eq!(error_code(&quiet), Some(DashboardError::RollupUnavailable.into()));
+}
+
+#[test]
+fn orphaned_registries_can_be_invalidated_after_the_grace_period() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let keeper = env.user(1);
+    let factory = find_factory_address().0;
+    let (kickoff, end) = schedule(&env);
+
+    // Register a market whose initialization never follows
+    let register = cryptoscore_factory_interface::instruction::create_market(
+        cryptoscore_factory_interface::accounts::CreateMarket::new(creator.pubkey(), "ITA-ROM-LAZ", kickoff),
+        cryptoscore_factory_interface::instruction::CreateMarket {
+            match_id: "ITA-ROM-LAZ".to_string(),
+            entry_fee: ENTRY_FEE,
+            kickoff_time: kickoff,
+            end_time: end,
+            is_public: true,
+            tags: [NO_TAG; TAG_SLOTS],
+        },
+    );
+    env.send(&[register], &creator).unwrap();
+    let initialized = env.create_market(&creator, "ITA-INT-MIL", ENTRY_FEE, kickoff, end).unwrap();
+    assert_eq!(env.account::<Factory>(&factory).market_count, 2);
+
+    let invalidate = |match_id: &str| {
+        cryptoscore_factory_interface::instruction::invalidate_registry(
+            cryptoscore_factory_interface::accounts::InvalidateRegistry::new(factory, creator.pubkey(), match_id),
+        )
+    };
+    let early = env.send(&[invalidate("ITA-ROM-LAZ")], &keeper);
+    assert_eq!(error_code(&early), Some(FactoryError::RegistryGracePeriodActive.into()));
+
+    env.warp_to(env.now() + ORPHANED_REGISTRY_GRACE_SECONDS);
+    let live = env.send(&[invalidate("ITA-INT-MIL")], &keeper);
+    assert_eq!(error_code(&live), Some(FactoryError::MarketInitialized.into()));
+    assert!(env.svm.get_account(&initialized).is_some());
+
+    // Anyone can close the orphan, refunding the creator's rent
+    let registry_address = find_market_registry_address(&factory, "ITA-ROM-LAZ").0;
+    let (rent, creator_before) = (env.lamports(&registry_address), env.lamports(&creator.pubkey()));
+    env.send(&[invalidate("ITA-ROM-LAZ")], &keeper).unwrap();
+    assert!(env.svm.get_account(&registry_address).is_none());
+    assert_eq!(env.lamports(&creator.pubkey()), creator_before + rent);
+    assert_eq!(env.account::<Factory>(&factory).market_count, 1);
+
+    // The match ID is free to be listed again
+    let (kickoff, end) = schedule(&env);
+    env.create_market(&creator, "ITA-ROM-LAZ", ENTRY_FEE, kickoff, end).unwrap();
+}
+
+#[test]
+fn match_ids_must_use_the_factorys_charset() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let (kickoff, end) = schedule(&env);
+
+    // By default only upper-case letters, digits and hyphens are allowed
+    let result = env.create_market(&creator, "epl-ars-che", ENTRY_FEE, kickoff, end);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidMatchIdCharacters.into()));
+    let result = env.create_market(&creator, "EPL ARS CHE", ENTRY_FEE, kickoff, end);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidMatchIdCharacters.into()));
+
+    // The market program checks the same charset when initialized without the factory
+    let ix = cryptoscore_market_interface::instruction::initialize_market(
+        cryptoscore_market_interface::accounts::InitializeMarket::new(
+            find_factory_address().0,
+            creator.pubkey(),
+            "EPL_ARS_CHE",
+        ),
+        cryptoscore_market_interface::instruction::InitializeMarket {
+            match_id: "EPL_ARS_CHE".to_string(),
+            entry_fee: ENTRY_FEE,
+            kickoff_time: kickoff,
+            end_time: end,
+            is_public: true,
+            outcome_set: OutcomeSet::WinDrawWin,
+            resolution_grace_seconds: 0,
+        },
+    );
+    let result = env.send(&[ix], &creator);
+    assert_eq!(error_code(&result), Some(MarketError::InvalidMatchIdCharacters.into()));
+
+    for allowed in ["", "AB CD", "ABC\u{e9}"] {
+        let result = env.set_match_id_policy(allowed);
+        assert_eq!(error_code(&result), Some(FactoryError::InvalidMatchIdPolicy.into()));
+    }
+    let stranger = env.user(1);
+    let ix = cryptoscore_factory_interface::instruction::set_match_id_policy(
+        cryptoscore_factory_interface::accounts::SetMatchIdPolicy::new(stranger.pubkey()),
+        "ABC".to_string(),
+    );
+    let unauthorized = env.send(&[ix], &stranger);
+    assert_eq!(error_code(&unauthorized), Some(FactoryError::Unauthorized.into()));
+
+    // A configured charset replaces the default
+    env.set_match_id_policy("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_").unwrap();
+    let policy = env.account::<MatchIdPolicy>(&find_match_id_policy_address(&find_factory_address().0).0);
+    assert_ne!(policy.charset, DEFAULT_MATCH_ID_CHARSET);
+    assert_eq!(decode_charset(policy.charset), "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz");
+
+    env.create_market(&creator, "epl_ARS_che", ENTRY_FEE, kickoff, end).unwrap();
+    let result = env.create_market(&creator, "EPL-ARS-CHE", ENTRY_FEE, kickoff, end);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidMatchIdCharacters.into()));
+    let fixtures = [("EPL_MUN_LIV", kickoff, end), ("EPL-TOT-NEW", kickoff, end)];
+    let result = env.create_market_series(&creator, &fixtures, ENTRY_FEE);
+    assert_eq!(error_code(&result), Some(FactoryError::InvalidMatchIdCharacters.into()));
+}
+
+#[test]
+fn creators_are_rate_limited_over_a_sliding_window() {
+    let mut env = TestEnv::new();
+    let creator = env.user(10);
+    let authority = env.authority.insecure_clone();
+    let (kickoff, end) = schedule(&env);
+
+    let invalid = env.set_creation_rate_policy(3, 0);
+    assert_eq!(error_code(&invalid), Some(FactoryError::InvalidCreationRatePolicy.into()));
+    let stranger = env.user(1);
+    let ix = cryptoscore_factory_interface::instruction::set_creation_rate_policy(
+        cryptoscore_factory_interface::accounts::SetCreationRatePolicy::new(stranger.pubkey()),
+        3,
+        SECONDS_PER_DAY,
+    );
+    let unauthorized = env.send(&[ix], &stranger);
+    assert_eq!(error_code(&unauthorized), Some(FactoryError::Unauthorized.into()));
+
+    // Three markets a day, counting every fixture of a series
+    env.set_creation_rate_policy(3, SECONDS_PER_DAY).unwrap();
+    env.create_market(&creator, "EPL-ARS-CHE", ENTRY_FEE, kickoff, end).unwrap();
+    env.create_market(&creator, "EPL-MUN-LIV", ENTRY_FEE, kickoff, end).unwrap();
+    let fixtures = [("EPL-TOT-NEW", kickoff, end), ("EPL-EVE-FUL", kickoff, end)];
+    let series = env.create_market_series(&creator, &fixtures, ENTRY_FEE);
+    assert_eq!(error_code(&series), Some(FactoryError::CreationRateLimited.into()));
+    env.create_market_series(&creator, &fixtures[..1], ENTRY_FEE).unwrap();
+    let result = env.create_market(&creator, "EPL-EVE-FUL", ENTRY_FEE, kickoff, end);
+    assert_eq!(error_code(&result), Some(FactoryError::CreationRateLimited.into()));
+
+    // The factory authority is never limited
+    for match_id in ["UCL-RMA-BAR", "UCL-BAY-PSG", "UCL-JUV-ATM", "UCL-POR-BEN"] {
+        env.create_market(&authority, match_id, ENTRY_FEE, kickoff, end).unwrap();
+    }
+
+    // Once the window has slid past them, the creator's markets no longer count
+    env.warp_to(env.now() + 2 * SECONDS_PER_DAY);
+    let (kickoff, end) = schedule(&env);
+    env.create_market(&creator, "EPL-EVE-FUL", ENTRY_FEE, kickoff, end).unwrap();
+    let rate: CreatorRate = env.account(&find_creator_rate_address(&find_factory_address().0, &creator.pubkey()).0);
+    assert_eq!(rate.creator, creator.pubkey());
+    assert_eq!(rate.total_created, 4);
+    assert_eq!(rate.recent.count(env.now(), SECONDS_PER_DAY), 1);
+
+    // Lifting the limit lets the creator go on
+    env.set_creation_rate_policy(0, SECONDS_PER_DAY).unwrap();
+    for match_id in ["SER-ROM-LAZ", "SER-INT-MIL", "SER-NAP-JUV"] {
+        env.create_market(&creator, match_id, ENTRY_FEE, kickoff, end).unwrap();
+    }
+}
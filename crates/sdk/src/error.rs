@@ -0,0 +1,47 @@
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use solana_client::client_error::ClientError;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_error::ErrorContext;
+
+/// Errors returned by the SDK fetch helpers
+#[derive(Debug)]
+pub enum SdkError {
+    /// RPC request failed
+    Rpc(ClientError),
+    /// Account data did not deserialize as the expected type
+    Deserialize(Pubkey, anchor_lang::error::Error),
+    /// Requested account does not exist
+    AccountNotFound(Pubkey),
+    /// Simulated transaction failed other than with a CryptoScore program error
+    Simulation(TransactionError),
+    /// Simulated instruction failed with a CryptoScore program error
+    Program(ErrorContext),
+    /// Simulated instruction set no return data
+    NoReturnData,
+}
+
+impl fmt::Display for SdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SdkError::Rpc(err) => write!(f, "RPC error: {}", err),
+            SdkError::Deserialize(address, err) => {
+                write!(f, "Failed to deserialize account {}: {}", address, err)
+            }
+            SdkError::AccountNotFound(address) => write!(f, "Account {} not found", address),
+            SdkError::Simulation(err) => write!(f, "Simulation failed: {}", err),
+            SdkError::Program(context) => write!(f, "Simulation failed: {}", context),
+            SdkError::NoReturnData => write!(f, "Simulation returned no data"),
+        }
+    }
+}
+
+impl std::error::Error for SdkError {}
+
+impl From<ClientError> for SdkError {
+    fn from(err: ClientError) -> Self {
+        SdkError::Rpc(err)
+    }
+}
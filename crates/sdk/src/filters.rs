@@ -0,0 +1,67 @@
+//! `getProgramAccounts` memcmp filters for CryptoScore accounts.
+//!
+//! Only fixed-offset fields can be matched server-side; anything stored after
+//! a variable-length `match_id` is filtered client-side once deserialized.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use cryptoscore_common::tags::{Tag, TagCategory, TAG_LEN};
+use cryptoscore_common::DISCRIMINATOR_LEN;
+use cryptoscore_factory::MarketRegistry;
+use cryptoscore_market::{Market, Participant, QueuedJoin};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+
+fn discriminator<T: Discriminator>() -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, T::DISCRIMINATOR.to_vec()))
+}
+
+/// Start of `MarketRegistry::tags`, after the factory, market and creator keys
+const REGISTRY_TAGS_OFFSET: usize = DISCRIMINATOR_LEN + 3 * 32;
+
+fn pubkey_at(offset: usize, key: &Pubkey) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, key.to_bytes().to_vec()))
+}
+
+/// Matches every `Market` account
+pub fn market_accounts() -> Vec<RpcFilterType> {
+    vec![discriminator::<Market>()]
+}
+
+/// Matches `Market` accounts created by `factory`
+pub fn markets_by_factory(factory: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator::<Market>(), pubkey_at(DISCRIMINATOR_LEN, factory)]
+}
+
+/// Matches `Market` accounts created by `creator`
+pub fn markets_by_creator(creator: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator::<Market>(), pubkey_at(DISCRIMINATOR_LEN + 32, creator)]
+}
+
+/// Matches every `Participant` account in `market`
+pub fn participants_by_market(market: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator::<Participant>(), pubkey_at(DISCRIMINATOR_LEN, market)]
+}
+
+/// Matches every `Participant` account owned by `user`
+pub fn participants_by_user(user: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator::<Participant>(), pubkey_at(DISCRIMINATOR_LEN + 32, user)]
+}
+
+/// Matches every `QueuedJoin` escrow waiting on `market`
+pub fn queued_joins_by_market(market: &Pubkey) -> Vec<RpcFilterType> {
+    vec![discriminator::<QueuedJoin>(), pubkey_at(DISCRIMINATOR_LEN, market)]
+}
+
+/// Matches every factory `MarketRegistry` account
+pub fn market_registries() -> Vec<RpcFilterType> {
+    vec![discriminator::<MarketRegistry>()]
+}
+
+/// Matches `MarketRegistry` entries carrying every one of `tags`, each in its category's slot
+pub fn registries_by_tags(tags: &[(TagCategory, Tag)]) -> Vec<RpcFilterType> {
+    let mut filters = vec![discriminator::<MarketRegistry>()];
+    filters.extend(tags.iter().map(|(category, tag)| {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(REGISTRY_TAGS_OFFSET + category.slot() * TAG_LEN, tag.to_vec()))
+    }));
+    filters
+}
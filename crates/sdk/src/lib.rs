@@ -0,0 +1,30 @@
+//! Client-side helpers for reading CryptoScore program accounts.
+//!
+//! Market listings and derived metrics are computed off-chain from
+//! `getProgramAccounts` results rather than through on-chain view
+//! instructions, which could never return data to the caller.
+
+#![allow(clippy::result_large_err)]
+
+pub mod error;
+pub mod filters;
+pub mod markets;
+pub mod points;
+pub mod preflight;
+pub mod program_error;
+pub mod quote;
+pub mod reputation;
+
+pub use cryptoscore_common::{fees, pda, MarketStatus, MatchOutcome};
+pub use error::SdkError;
+pub use markets::{
+    fetch_all_markets, fetch_handicap, fetch_kickoff_markets, fetch_live_score, fetch_market_details,
+    fetch_odds_history, fetch_outright_standings, fetch_participant_roster, fetch_pending_claims, fetch_prop_registry,
+    fetch_queued_joins, fetch_score_grid, fetch_tracked_rebate_entries, fetch_unclaimed_winners, fetch_user_markets,
+    fetch_winner_proof, MarketDetails, MarketQuery, MarketSummary, SortOption, WinnerProof,
+};
+pub use points::{fetch_points, PointsSummary};
+pub use preflight::{preview_settlement, validate_join};
+pub use program_error::{ErrorContext, ErrorDecoder, ProgramError};
+pub use quote::{quote_join, OutcomeQuote, PayoutQuote};
+pub use reputation::{fetch_reputation, ReputationSummary};
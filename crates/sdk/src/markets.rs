@@ -0,0 +1,581 @@
+//! Market listings and derived metrics computed from on-chain accounts.
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use cryptoscore_common::fees::{prize_pool_after_fees, reward_per_winner, settled_reward_per_winner};
+use cryptoscore_common::merkle::{winner_leaf, winner_proof};
+use cryptoscore_common::pda::{
+    find_factory_address, find_handicap_address, find_kickoff_bucket_address, find_live_score_address,
+    find_odds_history_address, find_outright_standings_address, find_participant_address,
+    find_participant_roster_address, find_pending_claims_address, find_prop_registry_address,
+    find_rebate_tracker_address, find_roster_page_address, find_score_grid_address, find_winners_root_address,
+};
+use cryptoscore_common::tags::{Tag, TagCategory};
+use cryptoscore_common::{FinalScore, MarketStatus, MatchOutcome};
+use cryptoscore_dashboard::{PendingClaims, RebateTracker};
+use cryptoscore_factory::{KickoffBucket, LiveScore, MarketRegistry};
+use cryptoscore_market::{
+    Handicap, Market, OddsHistory, OddsSample, OutrightStandings, Participant, ParticipantRoster, PropRegistry,
+    QueuedJoin, RosterPage, ScoreGrid, WinnersRoot,
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::RpcFilterType;
+
+use crate::error::SdkError;
+use crate::filters;
+
+/// Maximum accounts per `getMultipleAccounts` request
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SortOption {
+    /// Newest markets first, by factory registry creation time
+    #[default]
+    CreationTime,
+    /// Largest pools first
+    PoolSize,
+    /// Most participants first
+    ParticipantCount,
+    /// Earliest end time first
+    EndingSoon,
+}
+
+/// Filtering, sorting and pagination options for market listings
+#[derive(Clone)]
+pub struct MarketQuery {
+    pub status: Option<MarketStatus>,
+    pub is_public: Option<bool>,
+    pub creator: Option<Pubkey>,
+    /// Discovery tags the market's registry entry must all carry, matched server-side
+    pub tags: Vec<(TagCategory, Tag)>,
+    pub sort_by: SortOption,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl Default for MarketQuery {
+    fn default() -> Self {
+        Self {
+            status: None,
+            is_public: None,
+            creator: None,
+            tags: Vec::new(),
+            sort_by: SortOption::default(),
+            page: 0,
+            page_size: 20,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarketSummary {
+    pub market_address: Pubkey,
+    pub creator: Pubkey,
+    pub match_id: String,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    pub status: u8,
+    pub total_pool: u64,
+    pub participant_count: u32,
+    pub home_count: u32,
+    pub draw_count: u32,
+    pub away_count: u32,
+    pub is_public: bool,
+}
+
+impl MarketSummary {
+    pub fn from_market(market_address: Pubkey, market: &Market) -> Self {
+        Self {
+            market_address,
+            creator: market.creator,
+            match_id: market.match_id.clone(),
+            entry_fee: market.entry_fee,
+            kickoff_time: market.kickoff_time,
+            end_time: market.end_time,
+            status: market.status.clone() as u8,
+            total_pool: market.total_pool,
+            participant_count: market.participant_count,
+            home_count: market.home_count,
+            draw_count: market.draw_count,
+            away_count: market.away_count,
+            is_public: market.is_public,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarketDetails {
+    pub market_address: Pubkey,
+    pub creator: Pubkey,
+    pub co_admins: Vec<Pubkey>,
+    pub match_id: String,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    pub status: u8,
+    pub outcome: Option<u8>,
+    /// Score the market resolved from, when it recorded one
+    pub final_score: Option<FinalScore>,
+    pub total_pool: u64,
+    pub participant_count: u32,
+    pub home_count: u32,
+    pub draw_count: u32,
+    pub away_count: u32,
+    pub is_public: bool,
+    // Derived metrics
+    pub home_percentage: u8,
+    pub draw_percentage: u8,
+    pub away_percentage: u8,
+    pub prize_pool_after_fees: u64,
+    pub reward_per_winner: u64,
+}
+
+impl MarketDetails {
+    /// Build details with the same fee and reward math `withdraw_rewards` applies
+    pub fn from_market(market_address: Pubkey, market: &Market) -> Self {
+        let percentage = |count: u32| -> u8 {
+            if market.participant_count == 0 {
+                0
+            } else {
+                (count as u64 * 100 / market.participant_count as u64) as u8
+            }
+        };
+
+        let winner_count = match market.outcome {
+            Some(MatchOutcome::Home) => market.home_count,
+            Some(MatchOutcome::Draw) => market.draw_count,
+            Some(MatchOutcome::Away) => market.away_count,
+            None => 0,
+        };
+
+        Self {
+            market_address,
+            creator: market.creator,
+            co_admins: market
+                .co_admins
+                .iter()
+                .filter(|co_admin| **co_admin != Pubkey::default())
+                .copied()
+                .collect(),
+            match_id: market.match_id.clone(),
+            entry_fee: market.entry_fee,
+            kickoff_time: market.kickoff_time,
+            end_time: market.end_time,
+            status: market.status.clone() as u8,
+            outcome: market.outcome.clone().map(|outcome| outcome as u8),
+            final_score: market.final_score,
+            total_pool: market.total_pool,
+            participant_count: market.participant_count,
+            home_count: market.home_count,
+            draw_count: market.draw_count,
+            away_count: market.away_count,
+            is_public: market.is_public,
+            home_percentage: percentage(market.home_count),
+            draw_percentage: percentage(market.draw_count),
+            away_percentage: percentage(market.away_count),
+            prize_pool_after_fees: prize_pool_after_fees(market.total_pool).unwrap_or(0),
+            // Resolution records what it paid out, which a charity market's
+            // pledged pool share adds to
+            reward_per_winner: match market.status.is_resolved() {
+                true => settled_reward_per_winner(market.total_pool, market.fees_paid, winner_count),
+                false => reward_per_winner(market.total_pool, winner_count),
+            }
+            .unwrap_or(0),
+        }
+    }
+}
+
+/// Fetch all markets matching `query`, sorted and paginated client-side
+pub fn fetch_all_markets(rpc: &RpcClient, query: &MarketQuery) -> Result<Vec<MarketSummary>, SdkError> {
+    let market_filters = match &query.creator {
+        Some(creator) => filters::markets_by_creator(creator),
+        None => filters::market_accounts(),
+    };
+    let markets = fetch_program_accounts::<Market>(rpc, &cryptoscore_market::ID, market_filters)?;
+
+    select_markets(rpc, markets, query)
+}
+
+/// Fetch the markets `user` has joined, using the query's status and paging options
+pub fn fetch_user_markets(
+    rpc: &RpcClient,
+    user: &Pubkey,
+    query: &MarketQuery,
+) -> Result<Vec<MarketSummary>, SdkError> {
+    let participants = fetch_program_accounts::<Participant>(
+        rpc,
+        &cryptoscore_market::ID,
+        filters::participants_by_user(user),
+    )?;
+    let market_keys: Vec<Pubkey> = participants
+        .iter()
+        .map(|(_, participant)| participant.market)
+        .collect();
+
+    let mut markets = Vec::with_capacity(market_keys.len());
+    for chunk in market_keys.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        let accounts = rpc.get_multiple_accounts(chunk)?;
+        for (address, account) in chunk.iter().zip(accounts) {
+            // Closed markets have no account left to report
+            if let Some(account) = account {
+                markets.push((*address, deserialize::<Market>(address, &account.data)?));
+            }
+        }
+    }
+
+    select_markets(rpc, markets, query)
+}
+
+/// Fetch a single market with derived metrics
+pub fn fetch_market_details(rpc: &RpcClient, market: &Pubkey) -> Result<MarketDetails, SdkError> {
+    let account = rpc
+        .get_account_with_commitment(market, rpc.commitment())?
+        .value
+        .ok_or(SdkError::AccountNotFound(*market))?;
+
+    Ok(MarketDetails::from_market(
+        *market,
+        &deserialize::<Market>(market, &account.data)?,
+    ))
+}
+
+/// Fetch the odds samples recorded for `market`, oldest first
+///
+/// Empty if nobody has sampled the market yet.
+pub fn fetch_odds_history(rpc: &RpcClient, market: &Pubkey) -> Result<Vec<OddsSample>, SdkError> {
+    let address = find_odds_history_address(market).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(deserialize::<OddsHistory>(&address, &account.data)?.chronological()),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Fetch the score the results signer last reported for `match_id`
+///
+/// `None` until the first update, e.g. before kickoff.
+pub fn fetch_live_score(rpc: &RpcClient, match_id: &str) -> Result<Option<LiveScore>, SdkError> {
+    let address = find_live_score_address(&find_factory_address().0, match_id).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<LiveScore>(&address, &account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fetch the markets `factory` lists as kicking off on the unix `days`, day
+/// by day in creation order, e.g. a weekend's as `saturday..=saturday + 1`
+///
+/// Days without markets are skipped. Clients still filter by status, since
+/// buckets keep markets that were cancelled or never initialized.
+pub fn fetch_kickoff_markets(
+    rpc: &RpcClient,
+    factory: &Pubkey,
+    days: RangeInclusive<u32>,
+) -> Result<Vec<Pubkey>, SdkError> {
+    let buckets: Vec<Pubkey> = days.map(|day| find_kickoff_bucket_address(factory, day).0).collect();
+    let mut markets = Vec::new();
+    for chunk in buckets.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        for (address, account) in chunk.iter().zip(rpc.get_multiple_accounts(chunk)?) {
+            if let Some(account) = account {
+                markets.extend(deserialize::<KickoffBucket>(address, &account.data)?.markets);
+            }
+        }
+    }
+    Ok(markets)
+}
+
+/// Fetch the players `market` takes picks on, with their pick counts
+///
+/// `None` unless the creator has made it a player prop market.
+pub fn fetch_prop_registry(rpc: &RpcClient, market: &Pubkey) -> Result<Option<PropRegistry>, SdkError> {
+    let address = find_prop_registry_address(market).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<PropRegistry>(&address, &account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fetch the latest standings checkpoint of outright `market`
+///
+/// `None` until the results signer records its first checkpoint.
+pub fn fetch_outright_standings(rpc: &RpcClient, market: &Pubkey) -> Result<Option<OutrightStandings>, SdkError> {
+    let address = find_outright_standings_address(market).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<OutrightStandings>(&address, &account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fetch the handicap line `market` settles on
+///
+/// `None` unless the creator has made it a handicap market.
+pub fn fetch_handicap(rpc: &RpcClient, market: &Pubkey) -> Result<Option<Handicap>, SdkError> {
+    let address = find_handicap_address(market).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<Handicap>(&address, &account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fetch the pick counts of `market`'s correct-score grid, indexed by
+/// `Scoreline::bucket`, with the settled scoreline once resolved
+///
+/// `None` unless the creator has made it a correct-score market.
+pub fn fetch_score_grid(rpc: &RpcClient, market: &Pubkey) -> Result<Option<ScoreGrid>, SdkError> {
+    let address = find_score_grid_address(market).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<ScoreGrid>(&address, &account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fetch the joins queued on `market`, oldest first
+pub fn fetch_queued_joins(rpc: &RpcClient, market: &Pubkey) -> Result<Vec<(Pubkey, QueuedJoin)>, SdkError> {
+    let mut queued = fetch_program_accounts::<QueuedJoin>(
+        rpc,
+        &cryptoscore_market::ID,
+        filters::queued_joins_by_market(market),
+    )?;
+    queued.sort_by_key(|(_, queued)| queued.queued_at);
+
+    Ok(queued)
+}
+
+/// Fetch the participants listed on `market`'s roster, in joining order
+///
+/// Empty if the creator hasn't enabled a participant roster.
+pub fn fetch_participant_roster(rpc: &RpcClient, market: &Pubkey) -> Result<Vec<Pubkey>, SdkError> {
+    let address = find_participant_roster_address(market).0;
+    let Some(account) = rpc.get_account_with_commitment(&address, rpc.commitment())?.value else {
+        return Ok(Vec::new());
+    };
+    let roster = deserialize::<ParticipantRoster>(&address, &account.data)?;
+
+    let pages: Vec<Pubkey> = (0..roster.pages()).map(|page| find_roster_page_address(market, page).0).collect();
+    let mut users = Vec::with_capacity(roster.count as usize);
+    for chunk in pages.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        for (address, account) in chunk.iter().zip(rpc.get_multiple_accounts(chunk)?) {
+            let account = account.ok_or(SdkError::AccountNotFound(*address))?;
+            users.extend_from_slice(deserialize::<RosterPage>(address, &account.data)?.listed());
+        }
+    }
+    Ok(users)
+}
+
+/// A winner's position under their market's winners root and the proof of it
+#[derive(Clone, Debug)]
+pub struct WinnerProof {
+    /// Winner number, in roster order
+    pub index: u32,
+    /// Reward the winner is owed, in lamports
+    pub reward: u64,
+    /// Sibling at each level of the tree, from the leaves up
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Build `user`'s proof of winning `market` against its published winners root
+///
+/// `None` if the root hasn't been published or `user` isn't among the winners.
+pub fn fetch_winner_proof(rpc: &RpcClient, market: &Pubkey, user: &Pubkey) -> Result<Option<WinnerProof>, SdkError> {
+    let address = find_winners_root_address(market).0;
+    let Some(account) = rpc.get_account_with_commitment(&address, rpc.commitment())?.value else {
+        return Ok(None);
+    };
+    let winners_root = deserialize::<WinnersRoot>(&address, &account.data)?;
+    if !winners_root.published {
+        return Ok(None);
+    }
+    let account = rpc
+        .get_account_with_commitment(market, rpc.commitment())?
+        .value
+        .ok_or(SdkError::AccountNotFound(*market))?;
+    let outcome = deserialize::<Market>(market, &account.data)?.outcome;
+
+    // Winners are leaves in the order the roster lists them
+    let roster = fetch_participant_roster(rpc, market)?;
+    let mut winners = Vec::new();
+    for chunk in roster.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        let participants: Vec<Pubkey> = chunk.iter().map(|user| find_participant_address(market, user).0).collect();
+        for (address, account) in participants.iter().zip(rpc.get_multiple_accounts(&participants)?) {
+            let account = account.ok_or(SdkError::AccountNotFound(*address))?;
+            let participant = deserialize::<Participant>(address, &account.data)?;
+            if Some(&participant.prediction) == outcome.as_ref() {
+                winners.push(participant.user);
+            }
+        }
+    }
+
+    let Some(index) = winners.iter().position(|winner| winner == user) else {
+        return Ok(None);
+    };
+    let leaves: Vec<[u8; 32]> = winners.iter().map(|winner| winner_leaf(winner, winners_root.reward)).collect();
+    Ok(Some(WinnerProof {
+        index: index as u32,
+        reward: winners_root.reward,
+        proof: winner_proof(&leaves, index),
+    }))
+}
+
+/// Fetch the users holding an unclaimed win on resolved `market`
+///
+/// Empty until the market resolves.
+pub fn fetch_unclaimed_winners(rpc: &RpcClient, market: &Pubkey) -> Result<Vec<Pubkey>, SdkError> {
+    let account = rpc
+        .get_account_with_commitment(market, rpc.commitment())?
+        .value
+        .ok_or(SdkError::AccountNotFound(*market))?;
+    let Some(outcome) = deserialize::<Market>(market, &account.data)?.outcome else {
+        return Ok(Vec::new());
+    };
+    let participants = fetch_program_accounts::<Participant>(
+        rpc,
+        &cryptoscore_market::ID,
+        filters::participants_by_market(market),
+    )?;
+
+    Ok(participants
+        .into_iter()
+        .filter(|(_, participant)| participant.prediction == outcome && !participant.has_withdrawn)
+        .map(|(_, participant)| participant.user)
+        .collect())
+}
+
+/// Fetch the markets where `user` has an unclaimed win recorded, oldest first
+///
+/// Empty if no win has been recorded for the user yet.
+pub fn fetch_pending_claims(rpc: &RpcClient, user: &Pubkey) -> Result<Vec<Pubkey>, SdkError> {
+    let address = find_pending_claims_address(user).0;
+    match rpc.get_account_with_commitment(&address, rpc.commitment())?.value {
+        Some(account) => Ok(deserialize::<PendingClaims>(&address, &account.data)?.markets),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Fetch the participants of `market` whose rebate tracker still holds it as an open entry
+///
+/// These are the users `settle_rebate_entry` can settle once the market resolves or is cancelled.
+pub fn fetch_tracked_rebate_entries(rpc: &RpcClient, market: &Pubkey) -> Result<Vec<Pubkey>, SdkError> {
+    let users: Vec<Pubkey> = fetch_program_accounts::<Participant>(
+        rpc,
+        &cryptoscore_market::ID,
+        filters::participants_by_market(market),
+    )?
+    .into_iter()
+    .map(|(_, participant)| participant.user)
+    .collect();
+
+    let mut tracked = Vec::new();
+    for chunk in users.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        let trackers: Vec<Pubkey> = chunk.iter().map(|user| find_rebate_tracker_address(user).0).collect();
+        let accounts = rpc.get_multiple_accounts(&trackers)?;
+        for ((user, address), account) in chunk.iter().zip(&trackers).zip(accounts) {
+            // Users who never opted in have no tracker
+            if let Some(account) = account {
+                if deserialize::<RebateTracker>(address, &account.data)?.open_entries.contains(market) {
+                    tracked.push(*user);
+                }
+            }
+        }
+    }
+    Ok(tracked)
+}
+
+/// Apply the query's client-side filters, sort and page to fetched markets
+fn select_markets(
+    rpc: &RpcClient,
+    markets: Vec<(Pubkey, Market)>,
+    query: &MarketQuery,
+) -> Result<Vec<MarketSummary>, SdkError> {
+    let mut markets: Vec<(Pubkey, Market)> = markets
+        .into_iter()
+        .filter(|(_, market)| query.status.as_ref().is_none_or(|status| market.status == *status))
+        .filter(|(_, market)| query.is_public.is_none_or(|is_public| market.is_public == is_public))
+        .filter(|(_, market)| query.creator.is_none_or(|creator| market.creator == creator))
+        .collect();
+
+    if !query.tags.is_empty() {
+        let tagged = fetch_tagged_markets(rpc, &query.tags)?;
+        markets.retain(|(address, _)| tagged.contains(address));
+    }
+
+    match query.sort_by {
+        SortOption::CreationTime => {
+            // Markets are not timestamped on creation; the factory registry is
+            let created_at = fetch_creation_times(rpc)?;
+            markets.sort_by_key(|(address, _)| {
+                Reverse(created_at.get(address).copied().unwrap_or(i64::MIN))
+            });
+        }
+        SortOption::PoolSize => markets.sort_by_key(|(_, market)| Reverse(market.total_pool)),
+        SortOption::ParticipantCount => {
+            markets.sort_by_key(|(_, market)| Reverse(market.participant_count))
+        }
+        SortOption::EndingSoon => markets.sort_by_key(|(_, market)| market.end_time),
+    }
+
+    Ok(markets
+        .iter()
+        .skip(query.page as usize * query.page_size as usize)
+        .take(query.page_size as usize)
+        .map(|(address, market)| MarketSummary::from_market(*address, market))
+        .collect())
+}
+
+/// Markets whose factory registry entries carry every one of `tags`
+fn fetch_tagged_markets(rpc: &RpcClient, tags: &[(TagCategory, Tag)]) -> Result<HashSet<Pubkey>, SdkError> {
+    let registries = fetch_program_accounts::<MarketRegistry>(
+        rpc,
+        &cryptoscore_factory::ID,
+        filters::registries_by_tags(tags),
+    )?;
+
+    Ok(registries
+        .into_iter()
+        .map(|(_, registry)| registry.market_address)
+        .collect())
+}
+
+/// Map market addresses to their factory registry creation time
+fn fetch_creation_times(rpc: &RpcClient) -> Result<HashMap<Pubkey, i64>, SdkError> {
+    let registries = fetch_program_accounts::<MarketRegistry>(
+        rpc,
+        &cryptoscore_factory::ID,
+        filters::market_registries(),
+    )?;
+
+    Ok(registries
+        .into_iter()
+        .map(|(_, registry)| (registry.market_address, registry.created_at))
+        .collect())
+}
+
+fn fetch_program_accounts<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, T)>, SdkError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    rpc.get_program_accounts_with_config(program_id, config)?
+        .into_iter()
+        .map(|(address, account)| Ok((address, deserialize::<T>(&address, &account.data)?)))
+        .collect()
+}
+
+pub(crate) fn deserialize<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T, SdkError> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| SdkError::Deserialize(*address, err))
+}
@@ -0,0 +1,78 @@
+//! Points ledger reads, preferring an epoch's snapshot over the ledger's
+//! recent tallies.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use cryptoscore_common::fees::BPS_DENOMINATOR;
+use cryptoscore_common::pda::{
+    find_points_ledger_address, find_points_snapshot_address, find_points_total_snapshot_address,
+    find_points_totals_address,
+};
+use cryptoscore_dashboard::{week_of, PointsLedger, PointsSnapshot, PointsTotalSnapshot, PointsTotals};
+use solana_client::rpc_client::RpcClient;
+
+use crate::error::SdkError;
+use crate::markets::deserialize;
+
+/// A user's lifetime points and their share of one epoch's points
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PointsSummary {
+    pub user: Pubkey,
+    pub wager_points: u64,
+    pub creator_points: u64,
+    /// Week number since the unix epoch
+    pub epoch: u32,
+    /// Points the user earned in the epoch, if still on chain
+    pub epoch_points: Option<u64>,
+    /// Points credited across all users in the epoch, if still on chain
+    pub epoch_total: Option<u64>,
+}
+
+impl PointsSummary {
+    /// The user's share of the epoch's points in basis points
+    pub fn share_bps(&self) -> Option<u64> {
+        match (self.epoch_points, self.epoch_total) {
+            (Some(_), Some(0)) => Some(0),
+            (Some(points), Some(total)) => Some((points as u128 * BPS_DENOMINATOR as u128 / total as u128) as u64),
+            _ => None,
+        }
+    }
+}
+
+/// Fetch `user`'s points, with their share of `epoch` or of the epoch in
+/// progress by cluster time
+///
+/// Users who have never earned points read as zero.
+pub fn fetch_points(rpc: &RpcClient, user: &Pubkey, epoch: Option<u32>) -> Result<PointsSummary, SdkError> {
+    let epoch = match epoch {
+        Some(epoch) => epoch,
+        None => week_of(rpc.get_block_time(rpc.get_slot()?)?),
+    };
+    let ledger = fetch_optional::<PointsLedger>(rpc, &find_points_ledger_address(user).0)?;
+
+    let epoch_points = match fetch_optional::<PointsSnapshot>(rpc, &find_points_snapshot_address(user, epoch).0)? {
+        Some(snapshot) => Some(snapshot.points),
+        None => ledger.as_ref().map_or(Some(0), |ledger| ledger.points_in(epoch)),
+    };
+    let epoch_total = match fetch_optional::<PointsTotalSnapshot>(rpc, &find_points_total_snapshot_address(epoch).0)? {
+        Some(snapshot) => Some(snapshot.points),
+        None => fetch_optional::<PointsTotals>(rpc, &find_points_totals_address().0)?
+            .map_or(Some(0), |totals| totals.total_in(epoch).map(|total| total.points)),
+    };
+
+    Ok(PointsSummary {
+        user: *user,
+        wager_points: ledger.as_ref().map_or(0, |ledger| ledger.wager_points),
+        creator_points: ledger.as_ref().map_or(0, |ledger| ledger.creator_points),
+        epoch,
+        epoch_points,
+        epoch_total,
+    })
+}
+
+pub(crate) fn fetch_optional<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<Option<T>, SdkError> {
+    match rpc.get_account_with_commitment(address, rpc.commitment())?.value {
+        Some(account) => Ok(Some(deserialize::<T>(address, &account.data)?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,128 @@
+//! Pre-flight checks that simulate the market program's read-only
+//! `validate_join` and `preview_settlement`, so frontends learn why a join
+//! would fail before the user signs or sends funds, and what a market would
+//! pay out before it is resolved.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cryptoscore_common::pda::{
+    find_charity_pledge_address, find_commit_reveal_address, find_first_correct_bonus_address,
+    find_in_play_lock_address, find_participant_address, find_prop_registry_address, find_rake_free_market_address,
+    find_score_grid_address, find_token_gate_address,
+};
+use cryptoscore_common::MatchOutcome;
+use cryptoscore_market::{InPlayLock, SettlementPreview};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::transaction::Transaction;
+
+use crate::error::SdkError;
+use crate::points::fetch_optional;
+use crate::program_error::{ErrorContext, ErrorDecoder};
+
+/// Why `user` can't join `market` on `prediction` right now, or `None` if
+/// the join would go through
+///
+/// On token-gated markets `gate_proof` is the user's token account followed,
+/// for collection gates, by the NFT's metadata. The simulation is fee-paid by
+/// `user` without their signature, so a wallet that can't cover a
+/// transaction fee fails it with `SdkError::Simulation`. Joins after kickoff
+/// on in-play markets are checked against the live score the market follows.
+pub fn validate_join(
+    rpc: &RpcClient,
+    market: &Pubkey,
+    user: &Pubkey,
+    prediction: MatchOutcome,
+    gate_proof: &[Pubkey],
+) -> Result<Option<ErrorContext>, SdkError> {
+    let in_play_lock = find_in_play_lock_address(market).0;
+    let live_score = fetch_optional::<InPlayLock>(rpc, &in_play_lock)?
+        .map_or(cryptoscore_market::ID, |lock| lock.live_score);
+    let mut accounts = cryptoscore_market::accounts::ValidateJoin {
+        market: *market,
+        participant: find_participant_address(market, user).0,
+        user: *user,
+        commit_reveal: find_commit_reveal_address(market).0,
+        token_gate: find_token_gate_address(market).0,
+        in_play_lock,
+        live_score,
+        prop_registry: find_prop_registry_address(market).0,
+        score_grid: find_score_grid_address(market).0,
+    }
+    .to_account_metas(None);
+    accounts.extend(gate_proof.iter().map(|proof| AccountMeta::new_readonly(*proof, false)));
+    let instruction = Instruction {
+        program_id: cryptoscore_market::ID,
+        accounts,
+        data: cryptoscore_market::instruction::ValidateJoin { prediction }.data(),
+    };
+
+    let simulation = simulate(rpc, instruction, user)?;
+
+    let Some(err) = simulation.err else {
+        return Ok(None);
+    };
+    ErrorDecoder::default()
+        .decode_context(&simulation.logs.unwrap_or_default())
+        .map(Some)
+        .ok_or(SdkError::Simulation(err))
+}
+
+/// How `market` would settle if it resolved to `outcome` now, with the exact
+/// fee and reward math of resolution
+///
+/// The simulation is fee-paid by `payer` without their signature. Fails with
+/// `SdkError::Program` if the market can't resolve to `outcome`, e.g. because
+/// it already resolved or doesn't offer it.
+pub fn preview_settlement(
+    rpc: &RpcClient,
+    market: &Pubkey,
+    outcome: MatchOutcome,
+    payer: &Pubkey,
+) -> Result<SettlementPreview, SdkError> {
+    let accounts = cryptoscore_market::accounts::PreviewSettlement {
+        market: *market,
+        charity_pledge: find_charity_pledge_address(market).0,
+        first_correct_bonus: find_first_correct_bonus_address(market).0,
+        rake_free_market: find_rake_free_market_address(market).0,
+    };
+    let instruction = Instruction {
+        program_id: cryptoscore_market::ID,
+        accounts: accounts.to_account_metas(None),
+        data: cryptoscore_market::instruction::PreviewSettlement { outcome }.data(),
+    };
+    let simulation = simulate(rpc, instruction, payer)?;
+
+    if let Some(err) = simulation.err {
+        return Err(ErrorDecoder::default()
+            .decode_context(&simulation.logs.unwrap_or_default())
+            .map_or(SdkError::Simulation(err), SdkError::Program));
+    }
+    let data = simulation
+        .return_data
+        .filter(|return_data| return_data.program_id == cryptoscore_market::ID.to_string())
+        .and_then(|return_data| STANDARD.decode(return_data.data.0).ok())
+        .ok_or(SdkError::NoReturnData)?;
+    SettlementPreview::try_from_slice(&data).map_err(|err| SdkError::Deserialize(*market, err.into()))
+}
+
+/// Simulate `instruction` alone, fee-paid by `payer` without their signature
+fn simulate(
+    rpc: &RpcClient,
+    instruction: Instruction,
+    payer: &Pubkey,
+) -> Result<RpcSimulateTransactionResult, SdkError> {
+    let transaction = Transaction::new_unsigned(Message::new(&[instruction], Some(payer)));
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: true,
+        commitment: Some(rpc.commitment()),
+        ..RpcSimulateTransactionConfig::default()
+    };
+    Ok(rpc.simulate_transaction_with_config(&transaction, config)?.value)
+}
@@ -0,0 +1,493 @@
+//! Maps custom program error codes from failed transactions back to the
+//! `FactoryError`, `MarketError`, `DashboardError` and `GovernanceError` variants.
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::{DASHBOARD_PROGRAM_ID, FACTORY_PROGRAM_ID, GOVERNANCE_PROGRAM_ID, MARKET_PROGRAM_ID};
+use cryptoscore_dashboard::DashboardError;
+use cryptoscore_factory::FactoryError;
+use cryptoscore_governance::GovernanceError;
+use cryptoscore_market::MarketError;
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+
+/// First code of Anchor's `#[error_code]` enums
+const ERROR_CODE_OFFSET: u32 = 6000;
+
+/// Lists an error enum's variants in declaration order, failing to compile
+/// if a variant is added to the program without being added here
+macro_rules! error_table {
+    ($table:ident: $error:ident { $($variant:ident),* $(,)? }) => {
+        const $table: &[$error] = &[$($error::$variant),*];
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn exhaustive(err: $error) {
+                match err {
+                    $($error::$variant)|* => {}
+                }
+            }
+        };
+    };
+}
+
+error_table!(FACTORY_ERRORS: FactoryError {
+    InvalidPlatformFee,
+    InvalidMatchId,
+    MatchIdTooLong,
+    ZeroEntryFee,
+    InvalidKickoffTime,
+    InvalidEndTime,
+    MarketCountOverflow,
+    Unauthorized,
+    InvalidTag,
+    UnregisteredTag,
+    InvalidRegistryAccount,
+    AlreadyMigrated,
+    InvalidSeriesLength,
+    InvalidSeriesAccounts,
+    UnauthorizedCaller,
+    TreasuryOverflow,
+    InsufficientTreasuryFunds,
+    MarketNotResolved,
+    InvalidRuling,
+    InvalidInsuranceCap,
+    NotAffectedByRuling,
+    InsuranceCapReached,
+    InsufficientInsuranceFunds,
+    InvalidResultsBatch,
+    NotResultsSigner,
+    InvalidRebatePolicy,
+    InvalidPlatformParam,
+    InvalidTreasuryRecipient,
+    DisputeWindowClosed,
+    InvalidWormholeEmitter,
+    InvalidDiscountPolicy,
+    InvalidKeeperTipPolicy,
+    LiveScoreFinal,
+    InvalidPayoutSwapPolicy,
+    InvalidReceiptPolicy,
+    InvalidResolutionGracePolicy,
+    InvalidPlatformId,
+    RegistryGracePeriodActive,
+    MarketInitialized,
+    InvalidMatchIdCharacters,
+    InvalidMatchIdPolicy,
+    MarketAlreadyResolved,
+    RakeFreeLocked,
+    KickoffBucketFull,
+    InvalidVoucherCampaign,
+    VoucherExpired,
+    VoucherRedeemed,
+    VoucherEntryFeeTooHigh,
+    VoucherBudgetExhausted,
+    InvalidCreationRatePolicy,
+    CreationRateLimited,
+});
+
+error_table!(MARKET_ERRORS: MarketError {
+    InvalidMatchId,
+    MatchIdTooLong,
+    ZeroEntryFee,
+    InvalidKickoffTime,
+    InvalidEndTime,
+    MarketNotOpen,
+    MarketAlreadyStarted,
+    PoolOverflow,
+    ParticipantOverflow,
+    CountOverflow,
+    UnauthorizedResolver,
+    MarketAlreadyResolved,
+    MarketNotEnded,
+    MarketNotResolved,
+    AlreadyWithdrawn,
+    NoOutcome,
+    NotAWinner,
+    NoWinners,
+    CalculationError,
+    InsufficientFunds,
+    InvalidCreator,
+    ClaimWindowOpen,
+    NothingToSweep,
+    UnauthorizedSweeper,
+    FeeOverflow,
+    RewardCalculationFailed,
+    BalanceOverflow,
+    InvalidClaimDeadline,
+    InvalidVault,
+    DuplicateAccount,
+    BelowRentExemption,
+    InvalidStatusTransition,
+    ClaimsClosed,
+    UnauthorizedCanceller,
+    MarketNotCancelled,
+    RefundsOutstanding,
+    CreatorIsPlatform,
+    InsolventMarket,
+    PayoutOverflow,
+    InvalidMarketAccount,
+    AlreadyMigrated,
+    UnauthorizedVoider,
+    MissingAttestation,
+    InvalidAttestation,
+    CreatorIsResultsSigner,
+    UnauthorizedStaker,
+    StakingWindowClosed,
+    NothingToStake,
+    InvalidStakePool,
+    StakeSlippageExceeded,
+    PoolNotStaked,
+    UnauthorizedUnwind,
+    CoSignersRequired,
+    CoSignersNotRequired,
+    MissingCoSignature,
+    InvalidPayoutCondition,
+    JoinQueueClosed,
+    QueuedJoinNotDue,
+    PayoutConditionNotMet,
+    QueuedJoinPending,
+    OddsSampleTooSoon,
+    CannotFollowSelf,
+    CommitRevealRequired,
+    CommitRevealPrivateOnly,
+    MarketHasEntries,
+    RevealWindowClosed,
+    InvalidReveal,
+    InvalidCoAdmin,
+    NotMarketAdmin,
+    EmptyResolutionBatch,
+    ResultNotInBatch,
+    StaleResultsBatch,
+    MissingRebateAmount,
+    InvalidVaa,
+    UnknownVaaEmitter,
+    VaaMatchMismatch,
+    InvalidTokenGate,
+    TokenGateNotMet,
+    TokenGated,
+    InvalidRosterAccount,
+    WinnersRootPublished,
+    RosterParticipantMismatch,
+    AlreadyJoined,
+    InsufficientBalance,
+    InvalidCharity,
+    CharityMismatch,
+    LiveScoreNotFinal,
+    StaleLiveScore,
+    InvalidInPlayLock,
+    InvalidLiveScore,
+    InPlayLocked,
+    InvalidPropPlayers,
+    PropMarket,
+    NotAPropMarket,
+    PropPlayerNotListed,
+    NotResultsSigner,
+    InvalidScoreline,
+    CorrectScoreMarket,
+    NotACorrectScoreMarket,
+    InvalidHandicapLine,
+    HandicapMarket,
+    NotAHandicapMarket,
+    NotAnOutrightMarket,
+    InvalidOutrightCheckpoint,
+    OutrightUndecided,
+    OutcomeNotOffered,
+    PayoutMintNotAllowed,
+    InvalidSwapAccount,
+    SwapSlippageExceeded,
+    ReceiptsDisabled,
+    ClaimNotWithdrawn,
+    MarketNotSettled,
+    InvalidResolutionGrace,
+    ResolutionGracePeriod,
+    InvalidParticipantAccount,
+    FactoryMismatch,
+    InvalidMatchIdCharacters,
+    InvalidFirstCorrectBonus,
+    CorrectionWindowClosed,
+    ResultUnchanged,
+    InvalidFollowUpMarket,
+    NotFollowUpMarket,
+    RewardBelowEntryFee,
+    IncompleteVoucher,
+    MarketHasQueuedJoins,
+    InvalidBonusAccount,
+    CorrectionWindowOpen,
+});
+
+error_table!(DASHBOARD_ERRORS: DashboardError {
+    StatOverflow,
+    InvalidPageSize,
+    InvalidSortOption,
+    InvalidLeagueCode,
+    LeagueLimitReached,
+    UnsortedHeadToHeadPair,
+    NotHeadToHeadMember,
+    DuplicateHeadToHeadMarket,
+    InvalidUsernameLength,
+    InvalidUsernameCharacters,
+    UsernameNotOwned,
+    InvalidPickShare,
+    NoActiveStreak,
+    StreakFreezeAlreadyActive,
+    InsufficientXp,
+    InvalidSnapshotWeek,
+    UnauthorizedCaller,
+    InvalidStatsAccount,
+    AlreadyMigrated,
+    InvalidDecayWindow,
+    NotQualifiedForLeaderboard,
+    InvalidMarketAccount,
+    AlreadyWatching,
+    WatchlistFull,
+    NotWatching,
+    NothingToClaim,
+    EarningsShortfall,
+    ClaimAlreadyPending,
+    PendingClaimsFull,
+    ClaimNotPending,
+    RebateNotDue,
+    EntryNotTracked,
+    PointsEpochNotFinished,
+    PointsEpochUnavailable,
+    InvalidReputationAccount,
+    RollupUnavailable,
+    MarketNotSettled,
+    MarketNotResolved,
+    CorrectionWindowOpen,
+});
+
+error_table!(GOVERNANCE_ERRORS: GovernanceError {
+    Unauthorized,
+    InvalidGovernanceConfig,
+    InvalidStakeAmount,
+    InsufficientStake,
+    StakeLocked,
+    StakeOverflow,
+    InvalidProposal,
+    ProposalCountOverflow,
+    VotingClosed,
+    NoVotingPower,
+    VoteOverflow,
+    TimelockActive,
+    ProposalNotPassed,
+    ProposalAlreadyExecuted,
+    InvalidCourtConfig,
+    ArbiterPoolFull,
+    ArbiterOnJury,
+    MarketNotResolved,
+    InvalidDispute,
+    DisputeWindowClosed,
+    NotEnoughArbiters,
+    JuryAlreadyDrawn,
+    JurorAccountsMismatch,
+    NotJuror,
+    AlreadyVoted,
+    DisputeNotVoting,
+    VotingOpen,
+    JuryDrawPending,
+});
+
+/// A custom error raised by one of the CryptoScore programs
+#[derive(Clone, Copy, Debug)]
+pub enum ProgramError {
+    Factory(FactoryError),
+    Market(MarketError),
+    Dashboard(DashboardError),
+    Governance(GovernanceError),
+}
+
+impl ProgramError {
+    /// Name of the program that raised the error
+    pub fn program(&self) -> &'static str {
+        match self {
+            ProgramError::Factory(_) => "cryptoscore_factory",
+            ProgramError::Market(_) => "cryptoscore_market",
+            ProgramError::Dashboard(_) => "cryptoscore_dashboard",
+            ProgramError::Governance(_) => "cryptoscore_governance",
+        }
+    }
+
+    /// Variant name, e.g. `MarketNotEnded`
+    pub fn name(&self) -> String {
+        match self {
+            ProgramError::Factory(err) => err.name(),
+            ProgramError::Market(err) => err.name(),
+            ProgramError::Dashboard(err) => err.name(),
+            ProgramError::Governance(err) => err.name(),
+        }
+    }
+
+    /// Custom program error code as reported on-chain
+    pub fn code(&self) -> u32 {
+        match *self {
+            ProgramError::Factory(err) => err.into(),
+            ProgramError::Market(err) => err.into(),
+            ProgramError::Dashboard(err) => err.into(),
+            ProgramError::Governance(err) => err.into(),
+        }
+    }
+
+    /// The error's `#[msg]` text
+    pub fn message(&self) -> String {
+        match self {
+            ProgramError::Factory(err) => err.to_string(),
+            ProgramError::Market(err) => err.to_string(),
+            ProgramError::Dashboard(err) => err.to_string(),
+            ProgramError::Governance(err) => err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} error {}): {}", self.name(), self.program(), self.code(), self.message())
+    }
+}
+
+impl std::error::Error for ProgramError {}
+
+/// A program error together with the context Anchor logged when raising it
+#[derive(Clone, Debug)]
+pub struct ErrorContext {
+    pub error: ProgramError,
+    /// Source file and line that raised the error
+    pub location: Option<String>,
+    /// Account named by a failed account constraint
+    pub account: Option<String>,
+    /// Compared values, e.g. (balance, amount) for `InsufficientFunds` or
+    /// (now, end time) for `MarketNotEnded`
+    pub values: Option<(String, String)>,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(account) = &self.account {
+            write!(f, "\n  account: {}", account)?;
+        }
+        if let Some((left, right)) = &self.values {
+            write!(f, "\n  left: {}\n  right: {}", left, right)?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, "\n  at {}", location)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes custom error codes for a set of deployed program IDs
+///
+/// Error codes overlap between programs, and a dashboard error raised inside
+/// a market CPI fails the top-level market instruction. The program logs
+/// name the program that actually failed, so prefer `decode_logs`.
+#[derive(Clone, Debug)]
+pub struct ErrorDecoder {
+    pub factory_program: Pubkey,
+    pub market_program: Pubkey,
+    pub dashboard_program: Pubkey,
+    pub governance_program: Pubkey,
+}
+
+impl Default for ErrorDecoder {
+    fn default() -> Self {
+        Self {
+            factory_program: FACTORY_PROGRAM_ID,
+            market_program: MARKET_PROGRAM_ID,
+            dashboard_program: DASHBOARD_PROGRAM_ID,
+            governance_program: GOVERNANCE_PROGRAM_ID,
+        }
+    }
+}
+
+impl ErrorDecoder {
+    /// Map a custom error code raised by `program_id`
+    pub fn decode(&self, program_id: &Pubkey, code: u32) -> Option<ProgramError> {
+        let index = code.checked_sub(ERROR_CODE_OFFSET)? as usize;
+
+        if *program_id == self.factory_program {
+            FACTORY_ERRORS.get(index).copied().map(ProgramError::Factory)
+        } else if *program_id == self.market_program {
+            MARKET_ERRORS.get(index).copied().map(ProgramError::Market)
+        } else if *program_id == self.dashboard_program {
+            DASHBOARD_ERRORS.get(index).copied().map(ProgramError::Dashboard)
+        } else if *program_id == self.governance_program {
+            GOVERNANCE_ERRORS.get(index).copied().map(ProgramError::Governance)
+        } else {
+            None
+        }
+    }
+
+    /// Find the innermost CryptoScore program failure in transaction logs
+    pub fn decode_logs(&self, logs: &[String]) -> Option<ProgramError> {
+        self.find_failure(logs).map(|(_, err)| err)
+    }
+
+    /// Like `decode_logs`, also collecting the location, account and
+    /// compared values Anchor logged before the failure
+    pub fn decode_context(&self, logs: &[String]) -> Option<ErrorContext> {
+        let (failed_at, error) = self.find_failure(logs)?;
+        let mut context = ErrorContext {
+            error,
+            location: None,
+            account: None,
+            values: None,
+        };
+
+        let Some(thrown_at) = logs[..failed_at]
+            .iter()
+            .rposition(|log| log.starts_with("Program log: AnchorError"))
+        else {
+            return Some(context);
+        };
+
+        let thrown = &logs[thrown_at]["Program log: AnchorError ".len()..];
+        if let Some(rest) = thrown.strip_prefix("caused by account: ") {
+            context.account = rest.split_once('.').map(|(account, _)| account.to_string());
+        }
+        context.location = thrown
+            .split_once("thrown in ")
+            .and_then(|(_, rest)| rest.split_once(". Error Code"))
+            .map(|(location, _)| location.to_string());
+
+        let mut lines = logs[thrown_at + 1..failed_at]
+            .iter()
+            .filter_map(|log| log.strip_prefix("Program log: "));
+        let mut value = |label: &str| {
+            let line = lines.next()?.strip_prefix(label)?;
+            // Pubkeys are logged on the line after their label
+            if line.is_empty() {
+                lines.next().map(str::to_string)
+            } else {
+                Some(line.trim_start().to_string())
+            }
+        };
+        if let Some(left) = value("Left:") {
+            context.values = value("Right:").map(|right| (left, right));
+        }
+
+        Some(context)
+    }
+
+    fn find_failure(&self, logs: &[String]) -> Option<(usize, ProgramError)> {
+        logs.iter().enumerate().find_map(|(index, log)| {
+            let (program, code) = log
+                .strip_prefix("Program ")?
+                .split_once(" failed: custom program error: 0x")?;
+            let err = self.decode(&program.parse().ok()?, u32::from_str_radix(code, 16).ok()?)?;
+            Some((index, err))
+        })
+    }
+
+    /// Decode the preflight simulation logs carried by a failed send
+    pub fn decode_client_error(&self, err: &ClientError) -> Option<ProgramError> {
+        match err.kind() {
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+                ..
+            }) => self.decode_logs(result.logs.as_deref()?),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Expected payouts for joining a market, from its current on-chain state.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::fees::{market_fees, prize_pool_after_fees, reward_per_winner, MarketFees};
+use cryptoscore_common::{MarketStatus, MatchOutcome};
+use cryptoscore_market::Market;
+use solana_client::rpc_client::RpcClient;
+
+use crate::error::SdkError;
+use crate::markets::deserialize;
+
+/// Payout if a new participant joins predicting `outcome` and it wins
+#[derive(Clone, Debug)]
+pub struct OutcomeQuote {
+    pub outcome: MatchOutcome,
+    /// Winners including the new participant
+    pub winner_count: u32,
+    /// Reward per winner, as `withdraw_rewards` would pay it
+    pub payout: u64,
+    /// Win probability at which the entry fee breaks even (`stake / payout`);
+    /// above 1.0 the payout is less than the stake
+    pub break_even_probability: Option<f64>,
+}
+
+/// Quote for joining a market with a given prediction
+///
+/// Every participant stakes exactly the market's entry fee, so the stake is
+/// fixed by the market. Payouts assume nobody else joins before kickoff.
+#[derive(Clone, Debug)]
+pub struct PayoutQuote {
+    pub market_address: Pubkey,
+    pub prediction: MatchOutcome,
+    /// Entry fee paid to join
+    pub stake: u64,
+    /// Pool including the new stake
+    pub total_pool: u64,
+    /// Fees taken from `total_pool` at resolution
+    pub fees: MarketFees,
+    /// Pool shared by the winners
+    pub prize_pool: u64,
+    /// Payout for `prediction`
+    pub payout: u64,
+    pub break_even_probability: Option<f64>,
+    /// Quotes for joining with each prediction the market offers
+    pub outcomes: Vec<OutcomeQuote>,
+    /// Whether the market still accepts predictions
+    pub is_open: bool,
+}
+
+impl PayoutQuote {
+    /// Quote with the same fee and reward math the market program applies
+    pub fn from_market(market_address: Pubkey, market: &Market, prediction: MatchOutcome, now: i64) -> Self {
+        let stake = market.entry_fee;
+        let total_pool = market.total_pool.saturating_add(stake);
+
+        let outcomes: Vec<OutcomeQuote> = [MatchOutcome::Home, MatchOutcome::Draw, MatchOutcome::Away]
+            .into_iter()
+            .filter(|outcome| market.outcome_set.offers(outcome))
+            .map(|outcome| {
+                let count = match outcome {
+                    MatchOutcome::Home => market.home_count,
+                    MatchOutcome::Draw => market.draw_count,
+                    MatchOutcome::Away => market.away_count,
+                };
+                let winner_count = count.saturating_add(1);
+                let payout = reward_per_winner(total_pool, winner_count).unwrap_or(0);
+
+                OutcomeQuote {
+                    outcome,
+                    winner_count,
+                    payout,
+                    break_even_probability: break_even_probability(stake, payout),
+                }
+            })
+            .collect();
+
+        // A prediction the market doesn't offer can never pay out
+        let chosen = outcomes.iter().find(|quote| quote.outcome == prediction);
+
+        Self {
+            market_address,
+            prediction,
+            stake,
+            total_pool,
+            fees: market_fees(total_pool).unwrap_or_default(),
+            prize_pool: prize_pool_after_fees(total_pool).unwrap_or(0),
+            payout: chosen.map_or(0, |quote| quote.payout),
+            break_even_probability: chosen.and_then(|quote| quote.break_even_probability),
+            is_open: market.status == MarketStatus::Open && now < market.kickoff_time,
+            outcomes,
+        }
+    }
+}
+
+fn break_even_probability(stake: u64, payout: u64) -> Option<f64> {
+    (payout > 0).then(|| stake as f64 / payout as f64)
+}
+
+/// Fetch a market and quote joining it with `prediction`
+pub fn quote_join(rpc: &RpcClient, market: &Pubkey, prediction: MatchOutcome) -> Result<PayoutQuote, SdkError> {
+    let account = rpc
+        .get_account_with_commitment(market, rpc.commitment())?
+        .value
+        .ok_or(SdkError::AccountNotFound(*market))?;
+    let now = rpc.get_block_time(rpc.get_slot()?)?;
+
+    Ok(PayoutQuote::from_market(
+        *market,
+        &deserialize::<Market>(market, &account.data)?,
+        prediction,
+        now,
+    ))
+}
@@ -0,0 +1,46 @@
+//! Soulbound reputation reads, for protocols using the balance as a
+//! sybil-resistance signal.
+
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::pda::{find_reputation_mint_address, find_user_stats_address};
+use cryptoscore_common::reputation::{find_reputation_account_address, reputation_balance, token_2022_amount};
+use cryptoscore_dashboard::UserStats;
+use solana_client::rpc_client::RpcClient;
+
+use crate::error::SdkError;
+use crate::points::fetch_optional;
+
+/// A user's reputation token balance and the balance their record earns
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReputationSummary {
+    pub user: Pubkey,
+    /// The user's reputation token account
+    pub account: Pubkey,
+    /// Tokens held as of the last sync
+    pub balance: u64,
+    /// Tokens the user's settled record is worth now
+    pub earned: u64,
+}
+
+impl ReputationSummary {
+    /// Whether a `sync_reputation` would leave the balance unchanged
+    pub fn is_synced(&self) -> bool {
+        self.balance == self.earned
+    }
+}
+
+/// Fetch `user`'s reputation balance alongside what their verified results earn
+///
+/// Users without stats or a token account read as zero.
+pub fn fetch_reputation(rpc: &RpcClient, user: &Pubkey) -> Result<ReputationSummary, SdkError> {
+    let account = find_reputation_account_address(user, &find_reputation_mint_address().0).0;
+    let balance = rpc
+        .get_account_with_commitment(&account, rpc.commitment())?
+        .value
+        .and_then(|account| token_2022_amount(&account.data))
+        .unwrap_or(0);
+    let earned = fetch_optional::<UserStats>(rpc, &find_user_stats_address(user).0)?
+        .map_or(0, |stats| reputation_balance(stats.verified_wins, stats.verified_losses));
+
+    Ok(ReputationSummary { user: *user, account, balance, earned })
+}
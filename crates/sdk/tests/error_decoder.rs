@@ -0,0 +1,104 @@
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::{DASHBOARD_PROGRAM_ID, FACTORY_PROGRAM_ID, MARKET_PROGRAM_ID};
+use cryptoscore_dashboard::DashboardError;
+use cryptoscore_factory::FactoryError;
+use cryptoscore_market::MarketError;
+use cryptoscore_sdk::{ErrorDecoder, ProgramError};
+
+#[test]
+fn decodes_codes_per_program() {
+    let decoder = ErrorDecoder::default();
+
+    let code = u32::from(MarketError::MarketNotEnded);
+    assert!(matches!(
+        decoder.decode(&MARKET_PROGRAM_ID, code),
+        Some(ProgramError::Market(MarketError::MarketNotEnded))
+    ));
+
+    let code = u32::from(FactoryError::Unauthorized);
+    assert!(matches!(
+        decoder.decode(&FACTORY_PROGRAM_ID, code),
+        Some(ProgramError::Factory(FactoryError::Unauthorized))
+    ));
+
+    let code = u32::from(DashboardError::NotQualifiedForLeaderboard);
+    assert!(matches!(
+        decoder.decode(&DASHBOARD_PROGRAM_ID, code),
+        Some(ProgramError::Dashboard(DashboardError::NotQualifiedForLeaderboard))
+    ));
+}
+
+#[test]
+fn rejects_unknown_codes_and_programs() {
+    let decoder = ErrorDecoder::default();
+
+    assert!(decoder.decode(&MARKET_PROGRAM_ID, 2006).is_none());
+    assert!(decoder.decode(&MARKET_PROGRAM_ID, 6999).is_none());
+    assert!(decoder.decode(&Pubkey::new_unique(), 6000).is_none());
+}
+
+#[test]
+fn decodes_innermost_failure_from_logs() {
+    let decoder = ErrorDecoder::default();
+    let code = u32::from(DashboardError::UnauthorizedCaller);
+    let logs = vec![
+        format!("Program {} invoke [1]", MARKET_PROGRAM_ID),
+        format!("Program {} invoke [2]", DASHBOARD_PROGRAM_ID),
+        format!("Program {} failed: custom program error: {:#x}", DASHBOARD_PROGRAM_ID, code),
+        format!("Program {} failed: custom program error: {:#x}", MARKET_PROGRAM_ID, code),
+    ];
+
+    let err = decoder.decode_logs(&logs).unwrap();
+    assert!(matches!(err, ProgramError::Dashboard(DashboardError::UnauthorizedCaller)));
+    assert_eq!(err.code(), code);
+    assert_eq!(err.name(), "UnauthorizedCaller");
+    assert_eq!(err.message(), "Caller is not authorized to record market activity");
+}
+
+#[test]
+fn decodes_logged_values_with_the_error() {
+    let decoder = ErrorDecoder::default();
+    let code = u32::from(MarketError::InsufficientFunds);
+    let logs = vec![
+        format!("Program {} invoke [1]", MARKET_PROGRAM_ID),
+        "Program log: Instruction: WithdrawRewards".to_string(),
+        format!(
+            "Program log: AnchorError thrown in programs/market/src/lib.rs:385. Error Code: InsufficientFunds. \
+             Error Number: {}. Error Message: Insufficient funds in market.",
+            code
+        ),
+        "Program log: Left: 1500000".to_string(),
+        "Program log: Right: 2000000".to_string(),
+        format!("Program {} failed: custom program error: {:#x}", MARKET_PROGRAM_ID, code),
+    ];
+
+    let context = decoder.decode_context(&logs).unwrap();
+    assert!(matches!(context.error, ProgramError::Market(MarketError::InsufficientFunds)));
+    assert_eq!(context.location.as_deref(), Some("programs/market/src/lib.rs:385"));
+    assert_eq!(context.values, Some(("1500000".to_string(), "2000000".to_string())));
+    assert!(context.account.is_none());
+}
+
+#[test]
+fn decodes_pubkey_values_logged_on_their_own_line() {
+    let decoder = ErrorDecoder::default();
+    let code = u32::from(MarketError::InvalidCreator);
+    let (expected, actual) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let logs = vec![
+        format!(
+            "Program log: AnchorError caused by account: creator. Error Code: InvalidCreator. \
+             Error Number: {}. Error Message: Invalid creator account.",
+            code
+        ),
+        "Program log: Left:".to_string(),
+        format!("Program log: {}", expected),
+        "Program log: Right:".to_string(),
+        format!("Program log: {}", actual),
+        format!("Program {} failed: custom program error: {:#x}", MARKET_PROGRAM_ID, code),
+    ];
+
+    let context = decoder.decode_context(&logs).unwrap();
+    assert_eq!(context.account.as_deref(), Some("creator"));
+    assert_eq!(context.values, Some((expected.to_string(), actual.to_string())));
+    assert!(context.location.is_none());
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::Pubkey;
+use cryptoscore_common::fees::{market_fees, reward_per_winner};
+use cryptoscore_common::{MarketStatus, MatchOutcome, OutcomeSet, MAX_CO_ADMINS};
+use cryptoscore_market::Market;
+use cryptoscore_sdk::PayoutQuote;
+
+const ENTRY_FEE: u64 = 100_000_000;
+const KICKOFF: i64 = 1_700_000_000;
+
+fn market(home: u32, draw: u32, away: u32) -> Market {
+    let participants = home + draw + away;
+    Market {
+        factory: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        match_id: "EPL-QUOTE".to_string(),
+        entry_fee: ENTRY_FEE,
+        kickoff_time: KICKOFF,
+        end_time: KICKOFF + 7_200,
+        status: MarketStatus::Open,
+        outcome: None,
+        total_pool: ENTRY_FEE * participants as u64,
+        participant_count: participants,
+        home_count: home,
+        draw_count: draw,
+        away_count: away,
+        is_public: true,
+        bump: 255,
+        fees_paid: 0,
+        total_claimed: 0,
+        co_admins: [Pubkey::default(); MAX_CO_ADMINS],
+        outcome_set: OutcomeSet::WinDrawWin,
+        resolution_grace_seconds: 0,
+        final_score: None,
+        joined_at_sum: 0,
+        queued_join_count: 0,
+    }
+}
+
+#[test]
+fn quotes_include_the_new_stake() {
+    let quote = PayoutQuote::from_market(Pubkey::new_unique(), &market(3, 1, 0), MatchOutcome::Draw, KICKOFF - 60);
+    let pool = ENTRY_FEE * 5;
+
+    assert!(quote.is_open);
+    assert_eq!(quote.stake, ENTRY_FEE);
+    assert_eq!(quote.total_pool, pool);
+    assert_eq!(quote.fees, market_fees(pool).unwrap());
+    assert_eq!(quote.payout, reward_per_winner(pool, 2).unwrap());
+
+    let winners: Vec<u32> = quote.outcomes.iter().map(|outcome| outcome.winner_count).collect();
+    assert_eq!(winners, vec![4, 2, 1]);
+    // Backing the only unpicked outcome pays the whole prize pool
+    assert_eq!(quote.outcomes[2].payout, quote.prize_pool);
+}
+
+#[test]
+fn break_even_reflects_fees_and_crowding() {
+    let quote = PayoutQuote::from_market(Pubkey::new_unique(), &market(9, 0, 0), MatchOutcome::Home, KICKOFF - 60);
+
+    // Everyone on one side gets back less than they staked
+    assert!(quote.break_even_probability.unwrap() > 1.0);
+    assert!(quote.outcomes[1].break_even_probability.unwrap() < 0.2);
+}
+
+#[test]
+fn quotes_after_kickoff_are_closed() {
+    let quote = PayoutQuote::from_market(Pubkey::new_unique(), &market(1, 1, 1), MatchOutcome::Away, KICKOFF);
+    assert!(!quote.is_open);
+}
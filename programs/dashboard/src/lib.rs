@@ -1,4 +1,22 @@
+use std::cmp::Ordering;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::Discriminator;
+use cryptoscore_common::introspection::invoked_by;
+use cryptoscore_common::pda::{match_id_seed, BREAKDOWN_ENTRY_SEED, HEAD_TO_HEAD_MARKET_SEED, STATS_ENTRY_SEED};
+use cryptoscore_common::reputation::{
+    burn_reputation, create_reputation_account, find_reputation_account_address, initialize_mint,
+    initialize_non_transferable_mint, initialize_permanent_delegate, mint_reputation, reputation_balance,
+    token_2022_amount, REPUTATION_MINT_LEN, TOKEN_2022_PROGRAM_ID,
+};
+use cryptoscore_common::token_gate::ASSOCIATED_TOKEN_PROGRAM_ID;
+use cryptoscore_common::{DISCRIMINATOR_LEN, MARKET_PROGRAM_ID, RESULT_CORRECTION_WINDOW_SECONDS};
+use cryptoscore_factory::Factory;
+use cryptoscore_market_interface::{Market, Participant};
+
+pub use cryptoscore_common::MatchOutcome;
 
 declare_id!("DHJASkp8vNuyR5xPSyj1G66xExRjnPBUuUN4QKiTnadZ");
 
@@ -6,19 +24,33 @@ declare_id!("DHJASkp8vNuyR5xPSyj1G66xExRjnPBUuUN4QKiTnadZ");
 pub mod cryptoscore_dashboard {
     use super::*;
 
-    /// Initialize or update user statistics
-    pub fn update_user_stats(
-        ctx: Context<UpdateUserStats>,
-        market_result: MarketResult,
-        amount_wagered: u64,
-        amount_won: u64,
-    ) -> Result<()> {
+    /// Record a user's entry in a resolved or cancelled market in their
+    /// statistics; anyone can crank it, once per entry and, for resolved
+    /// markets, once the correction window has closed
+    ///
+    /// The result, stake and payout are read from the market and the user's
+    /// participant account, which only the market program writes.
+    pub fn update_user_stats(ctx: Context<UpdateUserStats>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let market_result = settled_result(market, &ctx.accounts.participant.prediction)?;
+        let amount_wagered = market.entry_fee;
+        let amount_won = match market_result {
+            MarketResult::Win => reward_per_winner(market),
+            MarketResult::Loss | MarketResult::Void => 0,
+        };
+        
+        let entry = &mut ctx.accounts.stats_entry;
+        entry.market = market.key();
+        entry.user = ctx.accounts.user.key();
+        entry.bump = ctx.bumps.stats_entry;
+        
         let user_stats = &mut ctx.accounts.user_stats;
         let current_time = Clock::get()?.unix_timestamp;
         
         // Initialize if first time
         if user_stats.total_markets == 0 {
             user_stats.user = ctx.accounts.user.key();
+            user_stats.version = UserStats::CURRENT_VERSION;
             user_stats.bump = ctx.bumps.user_stats;
         }
         
@@ -29,15 +61,35 @@ pub mod cryptoscore_dashboard {
         user_stats.total_wagered = user_stats.total_wagered.checked_add(amount_wagered)
             .ok_or(DashboardError::StatOverflow)?;
         
+        // Only settled markets with a large enough pool count towards leaderboards
+        if market_result != MarketResult::Void
+            && ctx.accounts.market.total_pool >= ctx.accounts.factory.min_leaderboard_pool
+        {
+            user_stats.qualified_markets = user_stats.qualified_markets.checked_add(1)
+                .ok_or(DashboardError::StatOverflow)?;
+        }
+        
         // Update wins/losses and streaks
         match market_result {
             MarketResult::Win => {
                 user_stats.wins = user_stats.wins.checked_add(1)
                     .ok_or(DashboardError::StatOverflow)?;
+                user_stats.verified_wins = user_stats.verified_wins.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
                 
                 user_stats.total_won = user_stats.total_won.checked_add(amount_won)
                     .ok_or(DashboardError::StatOverflow)?;
                 
+                // Profit on a win is the payout minus the stake
+                let profit = (amount_won as i128)
+                    .checked_sub(amount_wagered as i128)
+                    .ok_or(DashboardError::StatOverflow)?;
+                user_stats.net_profit = apply_profit(user_stats.net_profit, profit)?;
+                
+                if amount_won > user_stats.largest_win {
+                    user_stats.largest_win = amount_won;
+                }
+                
                 // Update streak
                 if user_stats.current_streak >= 0 {
                     user_stats.current_streak = user_stats.current_streak.checked_add(1)
@@ -54,235 +106,3506 @@ pub mod cryptoscore_dashboard {
             MarketResult::Loss => {
                 user_stats.losses = user_stats.losses.checked_add(1)
                     .ok_or(DashboardError::StatOverflow)?;
+                user_stats.verified_losses = user_stats.verified_losses.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
                 
-                // Update streak
-                if user_stats.current_streak <= 0 {
+                user_stats.total_lost = user_stats.total_lost.checked_add(amount_wagered)
+                    .ok_or(DashboardError::StatOverflow)?;
+                
+                user_stats.net_profit = apply_profit(user_stats.net_profit, -(amount_wagered as i128))?;
+                
+                // Update streak, consuming a streak freeze to protect a win streak
+                if user_stats.current_streak > 0 && user_stats.streak_freeze_active {
+                    user_stats.streak_freeze_active = false;
+                    
+                    emit!(StreakFreezeConsumed {
+                        user: ctx.accounts.user.key(),
+                        protected_streak: user_stats.current_streak,
+                    });
+                } else if user_stats.current_streak <= 0 {
                     user_stats.current_streak = user_stats.current_streak.checked_sub(1)
                         .ok_or(DashboardError::StatOverflow)?;
                 } else {
                     user_stats.current_streak = -1;
                 }
             },
+            MarketResult::Void => {
+                // Refunded entries count as markets played but leave streaks and P&L untouched
+                user_stats.voids = user_stats.voids.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                
+                user_stats.total_refunded = user_stats.total_refunded.checked_add(amount_wagered)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+        }
+        
+        // Award XP for the settled market
+        let xp_gained = xp_for_result(&market_result, amount_wagered, winning_pick_share_bps(market));
+        let previous_level = user_stats.level;
+        user_stats.xp = user_stats.xp.checked_add(xp_gained)
+            .ok_or(DashboardError::StatOverflow)?;
+        user_stats.level = level_for_xp(user_stats.xp);
+        
+        if user_stats.level > previous_level {
+            emit!(LevelUp {
+                user: ctx.accounts.user.key(),
+                previous_level,
+                new_level: user_stats.level,
+                xp: user_stats.xp,
+            });
         }
         
+        // Refresh derived metrics
+        user_stats.average_stake = user_stats.total_wagered / user_stats.total_markets as u64;
+        user_stats.roi_bps = roi_bps(user_stats.net_profit, user_stats.total_wagered);
+        
         user_stats.last_updated = current_time;
         
         msg!("Updated stats for user {}: {} wins, {} losses, streak: {}", 
             ctx.accounts.user.key(), user_stats.wins, user_stats.losses, user_stats.current_streak);
+        msg!("Net profit: {} lamports, ROI: {} bps", user_stats.net_profit, user_stats.roi_bps);
         
         Ok(())
     }
 
-    /// Get paginated list of all markets with filtering
-    pub fn get_all_markets(
-        _ctx: Context<GetAllMarkets>,
-        filter_status: Option<u8>,
-        filter_visibility: Option<bool>,
-        sort_by: SortOption,
-        page: u32,
-        page_size: u32,
-    ) -> Result<Vec<MarketSummary>> {
-        // This is a view function meant to be called off-chain
-        // Clients should fetch market registry and market accounts directly
-        // and apply filtering/sorting client-side
+    /// Migrate a user stats account from an older layout to the current one
+    pub fn migrate_user_stats(ctx: Context<MigrateUserStats>) -> Result<()> {
+        let account_info = ctx.accounts.user_stats.to_account_info();
+        
+        let migrated = {
+            let data = account_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == UserStats::discriminator(),
+                DashboardError::InvalidStatsAccount
+            );
+            require!(data.len() == LegacyUserStats::LEN, DashboardError::AlreadyMigrated);
+            
+            let legacy = LegacyUserStats::deserialize(&mut &data[8..])?;
+            require!(legacy.user == ctx.accounts.user.key(), DashboardError::InvalidStatsAccount);
+            legacy.into_current()
+        };
+        
+        // Top up rent for the larger account before reallocating
+        let rent = Rent::get()?;
+        let required = rent.minimum_balance(UserStats::LEN);
+        let shortfall = required.saturating_sub(account_info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        
+        account_info.realloc(UserStats::LEN, true)?;
         
-        msg!("Getting all markets - status: {:?}, visibility: {:?}, sort: {:?}, page: {}, size: {}", 
-            filter_status, filter_visibility, sort_by, page, page_size);
+        let mut data = account_info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
         
-        // Return empty vec as this is meant to be called off-chain
-        Ok(vec![])
+        msg!("Migrated user stats for {} to version {}", migrated.user, migrated.version);
+        
+        Ok(())
     }
 
-    /// Get markets for a specific user with filtering
-    pub fn get_user_markets(
-        _ctx: Context<GetUserMarkets>,
-        user: Pubkey,
-        filter_status: Option<u8>,
-        sort_by: SortOption,
-        page: u32,
-        page_size: u32,
-    ) -> Result<Vec<MarketSummary>> {
-        // This is a view function meant to be called off-chain
-        // Clients should fetch participant accounts for the user
-        // then fetch corresponding market data
+    /// Spend XP to protect the current win streak from the next loss
+    pub fn purchase_streak_freeze(ctx: Context<PurchaseStreakFreeze>) -> Result<()> {
+        let user_stats = &mut ctx.accounts.user_stats;
+        
+        require!(user_stats.current_streak > 0, DashboardError::NoActiveStreak);
+        require!(!user_stats.streak_freeze_active, DashboardError::StreakFreezeAlreadyActive);
+        
+        let available_xp = user_stats.xp.saturating_sub(user_stats.xp_spent);
+        require!(available_xp >= STREAK_FREEZE_XP_COST, DashboardError::InsufficientXp);
+        
+        user_stats.xp_spent = user_stats.xp_spent.checked_add(STREAK_FREEZE_XP_COST)
+            .ok_or(DashboardError::StatOverflow)?;
+        user_stats.streak_freeze_active = true;
         
-        msg!("Getting markets for user {} - status: {:?}, sort: {:?}, page: {}, size: {}", 
-            user, filter_status, sort_by, page, page_size);
+        msg!("User {} froze streak of {} for {} XP", 
+            ctx.accounts.user.key(), user_stats.current_streak, STREAK_FREEZE_XP_COST);
         
-        // Return empty vec as this is meant to be called off-chain
-        Ok(vec![])
+        Ok(())
     }
 
-    /// Get comprehensive details for a specific market
-    pub fn get_market_details(
-        _ctx: Context<GetMarketDetails>,
-        market: Pubkey,
-    ) -> Result<MarketDetails> {
-        // This is a view function meant to be called off-chain
-        // Clients should fetch the market account and calculate derived metrics
+    /// Write an immutable snapshot of a user's stats for the current week
+    pub fn snapshot_user_stats(ctx: Context<SnapshotUserStats>, week: u32) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // Snapshots can only be taken for the week in progress
+        require!(week == week_of(current_time), DashboardError::InvalidSnapshotWeek);
         
-        msg!("Getting details for market {}", market);
+        let user_stats = &ctx.accounts.user_stats;
+        let snapshot = &mut ctx.accounts.stats_snapshot;
         
-        // Return default as this is meant to be called off-chain
-        Ok(MarketDetails::default())
+        snapshot.user = user_stats.user;
+        snapshot.week = week;
+        snapshot.total_markets = user_stats.total_markets;
+        snapshot.wins = user_stats.wins;
+        snapshot.losses = user_stats.losses;
+        snapshot.total_wagered = user_stats.total_wagered;
+        snapshot.total_won = user_stats.total_won;
+        snapshot.net_profit = user_stats.net_profit;
+        snapshot.current_streak = user_stats.current_streak;
+        snapshot.xp = user_stats.xp;
+        snapshot.level = user_stats.level;
+        snapshot.taken_at = current_time;
+        snapshot.bump = ctx.bumps.stats_snapshot;
+        
+        msg!("Snapshot of user {} stats for week {}", snapshot.user, week);
+        
+        Ok(())
     }
 
-    /// Get aggregated statistics across all markets
-    pub fn get_market_stats(
-        _ctx: Context<GetMarketStats>,
-    ) -> Result<AggregatedStats> {
-        // This is a view function meant to be called off-chain
-        // Clients should aggregate data from all market accounts
+    /// Initialize the hot-streak leaderboard of a factory or platform factory
+    pub fn initialize_streak_leaderboard(
+        ctx: Context<InitializeStreakLeaderboard>,
+        decay_window: i64,
+    ) -> Result<()> {
+        require!(decay_window > 0, DashboardError::InvalidDecayWindow);
         
-        msg!("Getting aggregated market statistics");
+        let leaderboard = &mut ctx.accounts.streak_leaderboard;
+        leaderboard.factory = ctx.accounts.factory.key();
+        leaderboard.decay_window = decay_window;
+        leaderboard.entries = Vec::new();
+        leaderboard.last_cranked = Clock::get()?.unix_timestamp;
+        leaderboard.bump = ctx.bumps.streak_leaderboard;
         
-        // Return default as this is meant to be called off-chain
-        Ok(AggregatedStats::default())
+        msg!("Streak leaderboard initialized with decay window: {}s", decay_window);
+        
+        Ok(())
     }
-}
 
-// Account Structures
+    /// Submit a user's current win streak to a factory's hot-streak leaderboard,
+    /// if they qualify under its rules (permissionless)
+    pub fn submit_streak(ctx: Context<SubmitStreak>) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.streak_leaderboard;
+        let user_stats = &ctx.accounts.user_stats;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(user_stats.current_streak > 0, DashboardError::NoActiveStreak);
+        require!(
+            user_stats.qualified_markets >= ctx.accounts.factory.min_leaderboard_markets,
+            DashboardError::NotQualifiedForLeaderboard
+        );
+        
+        let entry = StreakEntry {
+            user: user_stats.user,
+            streak: user_stats.current_streak as u32,
+            score: 0,
+            last_settled: user_stats.last_updated,
+        };
+        
+        leaderboard.entries.retain(|existing| existing.user != entry.user);
+        leaderboard.entries.push(entry);
+        leaderboard.apply_decay(current_time);
+        
+        msg!("Streak of {} submitted for user {}", user_stats.current_streak, user_stats.user);
+        
+        Ok(())
+    }
 
-#[account]
-pub struct UserStats {
-    /// User's wallet address
-    pub user: Pubkey,
-    /// Total number of markets participated in
-    pub total_markets: u32,
-    /// Number of wins
-    pub wins: u32,
-    /// Number of losses
-    pub losses: u32,
-    /// Total amount wagered in lamports
-    pub total_wagered: u64,
-    /// Total amount won in lamports
-    pub total_won: u64,
-    /// Current winning/losing streak (positive = wins, negative = losses)
-    pub current_streak: i32,
-    /// Best winning streak
-    pub best_streak: u32,
-    /// Last update timestamp
-    pub last_updated: i64,
-    /// PDA bump seed
-    pub bump: u8,
-}
+    /// Apply inactivity decay to the hot-streak leaderboard (permissionless crank)
+    pub fn decay_streak_leaderboard(ctx: Context<DecayStreakLeaderboard>) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.streak_leaderboard;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        leaderboard.apply_decay(current_time);
+        leaderboard.last_cranked = current_time;
+        
+        msg!("Streak leaderboard decayed, {} active entries", leaderboard.entries.len());
+        
+        Ok(())
+    }
 
-impl UserStats {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // user
-        4 +  // total_markets
-        4 +  // wins
-        4 +  // losses
-        8 +  // total_wagered
-        8 +  // total_won
-        4 +  // current_streak
-        4 +  // best_streak
-        8 +  // last_updated
-        1;   // bump
-}
+    /// Record the accuracy of a user's entry in a resolved market, split by
+    /// outcome and by the league its match ID starts with; anyone can crank
+    /// it, once per entry and once the correction window has closed
+    pub fn update_user_breakdown(ctx: Context<UpdateUserBreakdown>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let prediction = ctx.accounts.participant.prediction.clone();
+        let outcome = resolved_outcome(market)?;
+        let league_code = UserBreakdown::league_code(&market.match_id)
+            .ok_or(DashboardError::InvalidLeagueCode)?;
+        
+        let entry = &mut ctx.accounts.breakdown_entry;
+        entry.market = market.key();
+        entry.user = ctx.accounts.user.key();
+        entry.bump = ctx.bumps.breakdown_entry;
+        
+        let breakdown = &mut ctx.accounts.user_breakdown;
+        
+        // Initialize if first time
+        if breakdown.user == Pubkey::default() {
+            breakdown.user = ctx.accounts.user.key();
+            breakdown.bump = ctx.bumps.user_breakdown;
+        }
+        
+        let correct = prediction == outcome;
+        
+        // Update per-outcome accuracy, keyed by what the user picked
+        let accuracy = match prediction {
+            MatchOutcome::Home => &mut breakdown.home,
+            MatchOutcome::Draw => &mut breakdown.draw,
+            MatchOutcome::Away => &mut breakdown.away,
+        };
+        accuracy.record(correct)?;
+        
+        // Update per-league accuracy
+        let code = UserBreakdown::league_code_bytes(league_code);
+        match breakdown.leagues.iter_mut().find(|league| league.code == code) {
+            Some(league) => league.accuracy.record(correct)?,
+            None => {
+                require!(
+                    breakdown.leagues.len() < UserBreakdown::MAX_LEAGUES,
+                    DashboardError::LeagueLimitReached
+                );
+                let mut league = LeagueAccuracy {
+                    code,
+                    accuracy: Accuracy::default(),
+                };
+                league.accuracy.record(correct)?;
+                breakdown.leagues.push(league);
+            },
+        }
+        
+        msg!("Updated breakdown for user {}: {:?} pick in {} was {}", 
+            ctx.accounts.user.key(), prediction, league_code,
+            if correct { "correct" } else { "incorrect" });
+        
+        Ok(())
+    }
 
-// Enums
+    /// Record a resolved market both users entered in their head-to-head
+    /// record; anyone can crank it, once per market and once the correction
+    /// window has closed
+    ///
+    /// Who picked correctly is read from each user's participant account and
+    /// the market's outcome.
+    pub fn update_head_to_head(ctx: Context<UpdateHeadToHead>) -> Result<()> {
+        let market = ctx.accounts.market.key();
+        let outcome = resolved_outcome(&ctx.accounts.market)?;
+        let user_a_correct = ctx.accounts.participant_a.prediction == outcome;
+        let user_b_correct = ctx.accounts.participant_b.prediction == outcome;
+        
+        let recorded = &mut ctx.accounts.head_to_head_market;
+        recorded.head_to_head = ctx.accounts.head_to_head.key();
+        recorded.market = market;
+        recorded.bump = ctx.bumps.head_to_head_market;
+        
+        let head_to_head = &mut ctx.accounts.head_to_head;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // Initialize if first time
+        if head_to_head.shared_markets == 0 {
+            head_to_head.user_a = ctx.accounts.user_a.key();
+            head_to_head.user_b = ctx.accounts.user_b.key();
+            head_to_head.bump = ctx.bumps.head_to_head;
+        }
+        
+        head_to_head.shared_markets = head_to_head.shared_markets.checked_add(1)
+            .ok_or(DashboardError::StatOverflow)?;
+        
+        let counter = match (user_a_correct, user_b_correct) {
+            (true, false) => &mut head_to_head.user_a_wins,
+            (false, true) => &mut head_to_head.user_b_wins,
+            (true, true) => &mut head_to_head.both_correct,
+            (false, false) => &mut head_to_head.both_incorrect,
+        };
+        *counter = counter.checked_add(1).ok_or(DashboardError::StatOverflow)?;
+        
+        head_to_head.last_market = market;
+        head_to_head.last_updated = current_time;
+        
+        msg!("Head-to-head {} vs {}: {} - {} over {} markets", 
+            head_to_head.user_a, head_to_head.user_b,
+            head_to_head.user_a_wins, head_to_head.user_b_wins, head_to_head.shared_markets);
+        
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum MarketResult {
-    Win,
-    Loss,
-}
+    /// Create a user profile and claim a unique username
+    pub fn create_profile(
+        ctx: Context<CreateProfile>,
+        username: String,
+        avatar_hash: [u8; 32],
+    ) -> Result<()> {
+        validate_username(&username)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let profile = &mut ctx.accounts.user_profile;
+        let username_record = &mut ctx.accounts.username_record;
+        
+        profile.user = ctx.accounts.user.key();
+        profile.username = username.clone();
+        profile.avatar_hash = avatar_hash;
+        profile.created_at = current_time;
+        profile.updated_at = current_time;
+        profile.bump = ctx.bumps.user_profile;
+        
+        username_record.owner = ctx.accounts.user.key();
+        username_record.bump = ctx.bumps.username_record;
+        
+        emit!(ProfileUpdated {
+            user: profile.user,
+            username,
+            avatar_hash,
+        });
+        
+        msg!("Profile created for user {}: {}", profile.user, profile.username);
+        
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
-pub enum SortOption {
-    CreationTime,
-    PoolSize,
-    ParticipantCount,
-    EndingSoon,
-}
+    /// Update the avatar hash on a user profile
+    pub fn update_profile(
+        ctx: Context<UpdateProfile>,
+        avatar_hash: [u8; 32],
+    ) -> Result<()> {
+        let profile = &mut ctx.accounts.user_profile;
+        
+        profile.avatar_hash = avatar_hash;
+        profile.updated_at = Clock::get()?.unix_timestamp;
+        
+        emit!(ProfileUpdated {
+            user: profile.user,
+            username: profile.username.clone(),
+            avatar_hash,
+        });
+        
+        msg!("Profile updated for user {}", profile.user);
+        
+        Ok(())
+    }
 
-// Return Types
+    /// Move a profile to a new username, releasing the old one
+    pub fn change_username(
+        ctx: Context<ChangeUsername>,
+        new_username: String,
+    ) -> Result<()> {
+        validate_username(&new_username)?;
+        
+        let profile = &mut ctx.accounts.user_profile;
+        let new_record = &mut ctx.accounts.new_username_record;
+        
+        new_record.owner = ctx.accounts.user.key();
+        new_record.bump = ctx.bumps.new_username_record;
+        
+        msg!("User {} renamed from {} to {}", profile.user, profile.username, new_username);
+        
+        profile.username = new_username;
+        profile.updated_at = Clock::get()?.unix_timestamp;
+        
+        emit!(ProfileUpdated {
+            user: profile.user,
+            username: profile.username.clone(),
+            avatar_hash: profile.avatar_hash,
+        });
+        
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
-pub struct MarketSummary {
-    pub market_address: Pubkey,
-    pub creator: Pubkey,
-    pub match_id: String,
-    pub entry_fee: u64,
-    pub kickoff_time: i64,
-    pub end_time: i64,
-    pub status: u8,
-    pub total_pool: u64,
-    pub participant_count: u32,
-    pub home_count: u32,
-    pub draw_count: u32,
-    pub away_count: u32,
-    pub is_public: bool,
-}
+    /// Follow a market, creating the user's watchlist on first use
+    pub fn add_to_watchlist(ctx: Context<AddToWatchlist>) -> Result<()> {
+        let watchlist = &mut ctx.accounts.watchlist;
+        let market = ctx.accounts.market.key();
+        
+        if watchlist.user == Pubkey::default() {
+            watchlist.user = ctx.accounts.user.key();
+            watchlist.bump = ctx.bumps.watchlist;
+        }
+        
+        require!(!watchlist.markets.contains(&market), DashboardError::AlreadyWatching);
+        require!(watchlist.markets.len() < Watchlist::MAX_MARKETS, DashboardError::WatchlistFull);
+        
+        watchlist.markets.push(market);
+        
+        msg!("User {} is watching market {}", watchlist.user, market);
+        
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
-pub struct MarketDetails {
-    pub market_address: Pubkey,
-    pub creator: Pubkey,
-    pub match_id: String,
-    pub entry_fee: u64,
-    pub kickoff_time: i64,
-    pub end_time: i64,
-    pub status: u8,
-    pub outcome: Option<u8>,
-    pub total_pool: u64,
-    pub participant_count: u32,
-    pub home_count: u32,
-    pub draw_count: u32,
-    pub away_count: u32,
-    pub is_public: bool,
-    // Derived metrics
-    pub home_percentage: u8,
-    pub draw_percentage: u8,
-    pub away_percentage: u8,
-    pub prize_pool_after_fees: u64,
-    pub reward_per_winner: u64,
-}
+    /// Unfollow a market, keeping the rest of the watchlist in order
+    ///
+    /// Takes the market by address so closed markets can still be removed.
+    pub fn remove_from_watchlist(ctx: Context<RemoveFromWatchlist>, market: Pubkey) -> Result<()> {
+        let watchlist = &mut ctx.accounts.watchlist;
+        
+        let index = watchlist.markets.iter()
+            .position(|watched| watched == &market)
+            .ok_or(DashboardError::NotWatching)?;
+        watchlist.markets.remove(index);
+        
+        msg!("User {} stopped watching market {}", watchlist.user, market);
+        
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
-pub struct AggregatedStats {
-    pub total_markets: u32,
-    pub open_markets: u32,
-    pub live_markets: u32,
-    pub resolved_markets: u32,
-    pub total_participants: u32,
-    pub total_volume: u64,
-}
+    /// List a market where the user holds an unclaimed win (CPI from the market program only)
+    pub fn record_pending_claim(ctx: Context<RecordPendingClaim>) -> Result<()> {
+        let pending_claims = &mut ctx.accounts.pending_claims;
+        let market = ctx.accounts.market.key();
+        
+        // Initialize if first time
+        if pending_claims.user == Pubkey::default() {
+            pending_claims.user = ctx.accounts.user.key();
+            pending_claims.bump = ctx.bumps.pending_claims;
+        }
+        
+        require!(!pending_claims.markets.contains(&market), DashboardError::ClaimAlreadyPending);
+        require!(pending_claims.markets.len() < PendingClaims::MAX_MARKETS, DashboardError::PendingClaimsFull);
+        
+        pending_claims.markets.push(market);
+        
+        msg!("User {} has an unclaimed win in market {}", pending_claims.user, market);
+        
+        Ok(())
+    }
 
-// Context Structures
+    /// Drop a market from the user's pending claims once its reward is paid
+    /// (CPI from the market program only)
+    ///
+    /// Claims that were never recorded are left alone, so every withdrawal
+    /// can prune unconditionally.
+    pub fn clear_pending_claim(ctx: Context<ClearPendingClaim>) -> Result<()> {
+        let market = ctx.accounts.market.key();
+        ctx.accounts.pending_claims.markets.retain(|pending| pending != &market);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct UpdateUserStats<'info> {
-    #[account(
-        init_if_needed,
-        payer = user,
-        space = UserStats::LEN,
-        seeds = [
-            b"user_stats",
-            user.key().as_ref()
-        ],
-        bump
-    )]
-    pub user_stats: Account<'info, UserStats>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Drop a market the user can no longer claim from, such as one swept
+    /// after its claim window
+    ///
+    /// Takes the market by address so closed markets can still be removed.
+    pub fn dismiss_pending_claim(ctx: Context<DismissPendingClaim>, market: Pubkey) -> Result<()> {
+        let pending_claims = &mut ctx.accounts.pending_claims;
+        
+        let index = pending_claims.markets.iter()
+            .position(|pending| pending == &market)
+            .ok_or(DashboardError::ClaimNotPending)?;
+        pending_claims.markets.remove(index);
+        
+        msg!("User {} dismissed pending claim on market {}", pending_claims.user, market);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct GetAllMarkets {}
+    /// Opt in to losing-streak rebates by tracking the user's market entries
+    ///
+    /// Only entries made after this are tracked.
+    pub fn create_rebate_tracker(ctx: Context<CreateRebateTracker>) -> Result<()> {
+        let tracker = &mut ctx.accounts.rebate_tracker;
+        
+        tracker.user = ctx.accounts.user.key();
+        tracker.open_entries = Vec::new();
+        tracker.loss_streak = 0;
+        tracker.rebated_losses = 0;
+        tracker.bump = ctx.bumps.rebate_tracker;
+        
+        msg!("Tracking entries of user {} for rebates", tracker.user);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct GetUserMarkets {}
+    /// Forget every open entry and the current streak
+    ///
+    /// Lets a user recover when a tracked market is closed before its
+    /// result could be settled.
+    pub fn reset_rebate_tracker(ctx: Context<ResetRebateTracker>) -> Result<()> {
+        ctx.accounts.rebate_tracker.reset();
+        
+        msg!("Reset rebate tracking for user {}", ctx.accounts.user.key());
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct GetMarketDetails {}
+    /// Track a new entry, consuming the streak's rebate if `rebated` (CPI from
+    /// the market program only)
+    ///
+    /// A tracker with no room left forfeits its streak and open entries
+    /// rather than failing the join.
+    pub fn track_rebate_entry(ctx: Context<TrackRebateEntry>, rebated: bool) -> Result<()> {
+        let tracker = &mut ctx.accounts.rebate_tracker;
+        
+        if rebated {
+            require!(tracker.open_entries.is_empty(), DashboardError::RebateNotDue);
+            tracker.rebated_losses = tracker.loss_streak;
+        }
+        if tracker.open_entries.len() >= RebateTracker::MAX_OPEN_ENTRIES {
+            tracker.reset();
+        }
+        tracker.open_entries.push(ctx.accounts.market.key());
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct GetMarketStats {}
+    /// Apply a tracked entry's result to the user's losing streak (CPI from
+    /// the market program only)
+    pub fn settle_rebate_entry(ctx: Context<SettleRebateEntry>, market_result: MarketResult) -> Result<()> {
+        let tracker = &mut ctx.accounts.rebate_tracker;
+        let market = ctx.accounts.market.key();
+        
+        let index = tracker.open_entries.iter()
+            .position(|entry| entry == &market)
+            .ok_or(DashboardError::EntryNotTracked)?;
+        tracker.open_entries.remove(index);
+        
+        match market_result {
+            MarketResult::Win => {
+                tracker.loss_streak = 0;
+                tracker.rebated_losses = 0;
+            },
+            MarketResult::Loss => {
+                tracker.loss_streak = tracker.loss_streak.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            MarketResult::Void => {},
+        }
+        
+        msg!("Settled entry in market {} for user {}: losing streak {}", market, tracker.user, tracker.loss_streak);
+        
+        Ok(())
+    }
+
+    /// Initialize the platform-wide stats account
+    pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
+        let platform_stats = &mut ctx.accounts.platform_stats;
+        
+        platform_stats.total_markets = 0;
+        platform_stats.active_markets = 0;
+        platform_stats.resolved_markets = 0;
+        platform_stats.total_participants = 0;
+        platform_stats.total_volume = 0;
+        platform_stats.total_fees = 0;
+        platform_stats.bettor_sketch = [0; PlatformStats::SKETCH_REGISTERS];
+        platform_stats.last_updated = Clock::get()?.unix_timestamp;
+        platform_stats.bump = ctx.bumps.platform_stats;
+        
+        msg!("Platform stats initialized");
+        
+        Ok(())
+    }
+
+    /// Record market activity in platform stats (CPI from the market program only)
+    pub fn record_market_activity(
+        ctx: Context<RecordMarketActivity>,
+        activity: MarketActivity,
+    ) -> Result<()> {
+        let platform_stats = &mut ctx.accounts.platform_stats;
+        
+        match activity {
+            MarketActivity::Created => {
+                platform_stats.total_markets = platform_stats.total_markets.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                platform_stats.active_markets = platform_stats.active_markets.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            MarketActivity::Joined { user, amount } => {
+                platform_stats.total_participants = platform_stats.total_participants.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                platform_stats.total_volume = platform_stats.total_volume.checked_add(amount)
+                    .ok_or(DashboardError::StatOverflow)?;
+                platform_stats.record_bettor(&user);
+            },
+            MarketActivity::Resolved { fees } => {
+                platform_stats.active_markets = platform_stats.active_markets.saturating_sub(1);
+                platform_stats.resolved_markets = platform_stats.resolved_markets.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                platform_stats.total_fees = platform_stats.total_fees.checked_add(fees)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            MarketActivity::Cancelled { was_resolved } => {
+                // A resolved market already stopped counting as active
+                if !was_resolved {
+                    platform_stats.active_markets = platform_stats.active_markets.saturating_sub(1);
+                }
+            },
+        }
+        
+        platform_stats.last_updated = Clock::get()?.unix_timestamp;
+        
+        let activity_info = &ctx.accounts.platform_activity;
+        if activity_info.owner == &crate::ID && !activity_info.data_is_empty() {
+            let mut platform_activity = PlatformActivity::try_deserialize(&mut &activity_info.try_borrow_data()?[..])?;
+            platform_activity.record(&activity, platform_stats.last_updated)?;
+            platform_activity.try_serialize(&mut &mut activity_info.try_borrow_mut_data()?[..])?;
+        }
+        
+        msg!("Recorded {:?} for market {}", activity, ctx.accounts.market.key());
+        
+        Ok(())
+    }
+
+    /// Start counting a factory's activity by day for its daily rollups
+    ///
+    /// Anyone can initialize it, paying for the account; days before it
+    /// exists aren't counted.
+    pub fn initialize_platform_activity(ctx: Context<InitializePlatformActivity>) -> Result<()> {
+        let platform_activity = &mut ctx.accounts.platform_activity;
+        
+        platform_activity.factory = ctx.accounts.factory.key();
+        platform_activity.current = DayTotals {
+            day: day_of(Clock::get()?.unix_timestamp),
+            ..DayTotals::default()
+        };
+        platform_activity.last_closed = DayTotals::default();
+        platform_activity.bettor_sketch = [0; PlatformStats::SKETCH_REGISTERS];
+        platform_activity.day_start_sketch = [0; PlatformStats::SKETCH_REGISTERS];
+        platform_activity.closed_start_sketch = [0; PlatformStats::SKETCH_REGISTERS];
+        platform_activity.bump = ctx.bumps.platform_activity;
+        
+        msg!("Platform activity initialized for factory {}", platform_activity.factory);
+        
+        Ok(())
+    }
+
+    /// Roll a factory's closed `day` of activity up into a snapshot and a
+    /// `DailyRollup` event, so operator dashboards needn't reindex history
+    ///
+    /// Anyone can crank it once the day is over, before the factory's next
+    /// active day closes in turn; each day is rolled up once.
+    pub fn emit_daily_rollup(ctx: Context<EmitDailyRollup>, day: u32) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let platform_activity = &mut ctx.accounts.platform_activity;
+        platform_activity.roll_over(day_of(current_time));
+        
+        let mut totals = platform_activity.last_closed;
+        require!(day > 0 && totals.day == day, DashboardError::RollupUnavailable);
+        totals.new_users = platform_activity.closed_day_new_users();
+        
+        let rollup = &mut ctx.accounts.daily_rollup;
+        rollup.factory = platform_activity.factory;
+        rollup.totals = totals;
+        rollup.rolled_up_at = current_time;
+        rollup.bump = ctx.bumps.daily_rollup;
+        
+        emit!(DailyRollup {
+            factory: rollup.factory,
+            day,
+            volume: totals.volume,
+            joins: totals.joins,
+            new_users: totals.new_users,
+            markets_created: totals.markets_created,
+            markets_resolved: totals.markets_resolved,
+            fees: totals.fees,
+        });
+        
+        msg!("Rolled up day {} for factory {}: {} joins", day, rollup.factory, totals.joins);
+        
+        Ok(())
+    }
+
+    /// Record market creation/resolution in the creator's stats (CPI from the market program only)
+    pub fn record_creator_activity(
+        ctx: Context<RecordCreatorActivity>,
+        activity: CreatorActivity,
+    ) -> Result<()> {
+        let creator_stats = &mut ctx.accounts.creator_stats;
+        
+        // Initialize if first time
+        if creator_stats.creator == Pubkey::default() {
+            creator_stats.creator = ctx.accounts.creator.key();
+            creator_stats.bump = ctx.bumps.creator_stats;
+        }
+        
+        match activity {
+            CreatorActivity::MarketCreated => {
+                creator_stats.markets_created = creator_stats.markets_created.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            CreatorActivity::MarketResolved { volume, creator_fee } => {
+                creator_stats.markets_resolved = creator_stats.markets_resolved.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                creator_stats.total_volume_hosted = creator_stats.total_volume_hosted.checked_add(volume)
+                    .ok_or(DashboardError::StatOverflow)?;
+                creator_stats.total_fees_earned = creator_stats.total_fees_earned.checked_add(creator_fee)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            CreatorActivity::DisputeLost => {
+                creator_stats.disputes_lost = creator_stats.disputes_lost.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+        }
+        
+        creator_stats.last_updated = Clock::get()?.unix_timestamp;
+        
+        // Award or revoke badges based on the updated stats
+        let previous_badges = creator_stats.badges;
+        creator_stats.badges = creator_stats.earned_badges();
+        let new_badges = creator_stats.badges & !previous_badges;
+        if new_badges != 0 {
+            emit!(CreatorBadgesEarned {
+                creator: creator_stats.creator,
+                new_badges,
+                badges: creator_stats.badges,
+            });
+        }
+        
+        msg!("Recorded {:?} for creator {}", activity, creator_stats.creator);
+        
+        Ok(())
+    }
+
+    /// Credit a market's creator fee to the creator's earnings ledger
+    ///
+    /// Called by the market program at resolution, before it moves the fee
+    /// into the earnings account.
+    pub fn accrue_creator_earnings(ctx: Context<AccrueCreatorEarnings>, amount: u64) -> Result<()> {
+        let earnings = &mut ctx.accounts.creator_earnings;
+        
+        // Initialize if first time
+        if earnings.creator == Pubkey::default() {
+            earnings.creator = ctx.accounts.creator.key();
+            earnings.bump = ctx.bumps.creator_earnings;
+        }
+        
+        earnings.total_accrued = earnings.total_accrued.checked_add(amount)
+            .ok_or(DashboardError::StatOverflow)?;
+        earnings.markets_accrued = earnings.markets_accrued.checked_add(1)
+            .ok_or(DashboardError::StatOverflow)?;
+        earnings.last_accrued_at = Clock::get()?.unix_timestamp;
+        
+        emit!(CreatorEarningsAccrued {
+            creator: earnings.creator,
+            market: ctx.accounts.market.key(),
+            amount,
+            total_accrued: earnings.total_accrued,
+        });
+        
+        msg!("Accrued {} lamports for creator {}", amount, earnings.creator);
+        
+        Ok(())
+    }
+
+    /// Pay the creator every accrued fee not yet claimed
+    pub fn claim_creator_earnings(ctx: Context<ClaimCreatorEarnings>) -> Result<()> {
+        let earnings = &mut ctx.accounts.creator_earnings;
+        let amount = earnings.unclaimed();
+        require_gt!(amount, 0, DashboardError::NothingToClaim);
+        
+        // The ledger must be backed by lamports above the rent reserve
+        let earnings_info = earnings.to_account_info();
+        let rent_minimum = Rent::get()?.minimum_balance(earnings_info.data_len());
+        let available = earnings_info.lamports().saturating_sub(rent_minimum);
+        require_gte!(available, amount, DashboardError::EarningsShortfall);
+        
+        **earnings_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += amount;
+        
+        earnings.total_claimed = earnings.total_claimed.checked_add(amount)
+            .ok_or(DashboardError::StatOverflow)?;
+        earnings.last_claimed_at = Clock::get()?.unix_timestamp;
+        
+        emit!(CreatorEarningsClaimed {
+            creator: earnings.creator,
+            amount,
+            total_claimed: earnings.total_claimed,
+        });
+        
+        msg!("Creator {} claimed {} lamports", earnings.creator, amount);
+        
+        Ok(())
+    }
+
+    /// Credit points for wagering or creating a market to the user's ledger
+    /// and the platform totals (CPI from the market program only)
+    pub fn credit_points(ctx: Context<CreditPoints>, activity: PointsActivity) -> Result<()> {
+        let epoch = week_of(Clock::get()?.unix_timestamp);
+        let points = activity.points().ok_or(DashboardError::StatOverflow)?;
+        let user = ctx.accounts.user.key();
+        
+        let ledger = &mut ctx.accounts.points_ledger;
+        if ledger.user == Pubkey::default() {
+            ledger.user = user;
+            ledger.bump = ctx.bumps.points_ledger;
+        }
+        let balance = match activity {
+            PointsActivity::Wagered { .. } => &mut ledger.wager_points,
+            PointsActivity::MarketCreated => &mut ledger.creator_points,
+        };
+        *balance = balance.checked_add(points).ok_or(DashboardError::StatOverflow)?;
+        
+        // A user's first credit in the epoch also counts them in its total
+        let tally = ledger.tally_mut(epoch);
+        let first_credit = tally.points == 0;
+        tally.points = tally.points.checked_add(points).ok_or(DashboardError::StatOverflow)?;
+        let epoch_points = tally.points;
+        
+        let totals = &mut ctx.accounts.points_totals;
+        totals.bump = ctx.bumps.points_totals;
+        let total = totals.total_mut(epoch);
+        total.points = total.points.checked_add(points).ok_or(DashboardError::StatOverflow)?;
+        if first_credit {
+            total.users = total.users.checked_add(1).ok_or(DashboardError::StatOverflow)?;
+        }
+        
+        emit!(PointsCredited {
+            user,
+            epoch,
+            activity: activity.clone(),
+            points,
+            epoch_points,
+        });
+        
+        msg!("Credited {} points to {} for {:?}", points, user, activity);
+        
+        Ok(())
+    }
+
+    /// Freeze a user's points for a finished epoch into a snapshot
+    ///
+    /// Anyone can crank it while the ledger still holds the epoch, i.e. for
+    /// `RECENT_POINTS_EPOCHS` weeks after it.
+    pub fn snapshot_points(ctx: Context<SnapshotPoints>, epoch: u32) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gt!(week_of(current_time), epoch, DashboardError::PointsEpochNotFinished);
+        
+        let ledger = &ctx.accounts.points_ledger;
+        let points = ledger.points_in(epoch).ok_or(DashboardError::PointsEpochUnavailable)?;
+        
+        let snapshot = &mut ctx.accounts.points_snapshot;
+        snapshot.user = ledger.user;
+        snapshot.epoch = epoch;
+        snapshot.points = points;
+        snapshot.taken_at = current_time;
+        snapshot.bump = ctx.bumps.points_snapshot;
+        
+        msg!("Snapshot of user {} points for epoch {}: {}", snapshot.user, epoch, points);
+        
+        Ok(())
+    }
+
+    /// Freeze the points credited across all users in a finished epoch into
+    /// a snapshot, the denominator of each user's share
+    pub fn snapshot_points_total(ctx: Context<SnapshotPointsTotal>, epoch: u32) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gt!(week_of(current_time), epoch, DashboardError::PointsEpochNotFinished);
+        
+        let total = ctx.accounts.points_totals
+            .total_in(epoch)
+            .ok_or(DashboardError::PointsEpochUnavailable)?;
+        
+        let snapshot = &mut ctx.accounts.points_total_snapshot;
+        snapshot.epoch = epoch;
+        snapshot.points = total.points;
+        snapshot.users = total.users;
+        snapshot.taken_at = current_time;
+        snapshot.bump = ctx.bumps.points_total_snapshot;
+        
+        msg!("Snapshot of epoch {} points: {} across {} users", epoch, total.points, total.users);
+        
+        Ok(())
+    }
+
+    /// Create the soulbound reputation mint: non-transferable, with the mint
+    /// PDA as its own mint authority and permanent delegate so balances can
+    /// be burned back down
+    pub fn initialize_reputation_mint(ctx: Context<InitializeReputationMint>) -> Result<()> {
+        let mint = ctx.accounts.reputation_mint.key();
+        let mint_bump = [ctx.bumps.reputation_mint];
+        let mint_seeds: [&[u8]; 2] = [b"reputation_mint", &mint_bump];
+        
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.reputation_mint.to_account_info(),
+                },
+                &[&mint_seeds],
+            ),
+            Rent::get()?.minimum_balance(REPUTATION_MINT_LEN),
+            REPUTATION_MINT_LEN as u64,
+            &TOKEN_2022_PROGRAM_ID,
+        )?;
+        
+        // Extensions must be initialized before the mint itself
+        for ix in [
+            initialize_non_transferable_mint(&mint),
+            initialize_permanent_delegate(&mint, &mint),
+            initialize_mint(&mint, &mint),
+        ] {
+            invoke(
+                &ix,
+                &[ctx.accounts.reputation_mint.to_account_info(), ctx.accounts.token_program.to_account_info()],
+            )?;
+        }
+        
+        msg!("Reputation mint {} initialized", mint);
+        
+        Ok(())
+    }
+
+    /// Mint or burn a user's reputation tokens so their balance matches their
+    /// settled record; anyone can crank it
+    ///
+    /// Only results read from market accounts count, so wins and losses
+    /// recorded before entries were verified earn no reputation.
+    pub fn sync_reputation(ctx: Context<SyncReputation>) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let mint = ctx.accounts.reputation_mint.key();
+        let account = ctx.accounts.reputation_account.key();
+        let user_stats = &ctx.accounts.user_stats;
+        let target = reputation_balance(user_stats.verified_wins, user_stats.verified_losses);
+        
+        // The user's token account is created on their first sync, paid for by the cranker
+        if ctx.accounts.reputation_account.data_is_empty() {
+            invoke(
+                &create_reputation_account(&ctx.accounts.payer.key(), &user, &mint),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.reputation_account.to_account_info(),
+                    ctx.accounts.user.to_account_info(),
+                    ctx.accounts.reputation_mint.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                ],
+            )?;
+        }
+        
+        let balance = token_2022_amount(&ctx.accounts.reputation_account.try_borrow_data()?)
+            .ok_or(DashboardError::InvalidReputationAccount)?;
+        let ix = match target.cmp(&balance) {
+            Ordering::Greater => mint_reputation(&mint, &account, &mint, target - balance),
+            Ordering::Less => burn_reputation(&mint, &account, &mint, balance - target),
+            Ordering::Equal => {
+                msg!("Reputation of {} already at {}", user, balance);
+                return Ok(());
+            },
+        };
+        
+        let mint_bump = [ctx.bumps.reputation_mint];
+        let mint_seeds: [&[u8]; 2] = [b"reputation_mint", &mint_bump];
+        invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.reputation_mint.to_account_info(),
+                ctx.accounts.reputation_account.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            &[&mint_seeds],
+        )?;
+        
+        emit!(ReputationSynced {
+            user,
+            previous_balance: balance,
+            balance: target,
+        });
+        
+        msg!("Reputation of {}: {} -> {}", user, balance, target);
+        
+        Ok(())
+    }
+}
+
+// Account Structures
+
+/// A user's lifetime prediction record, XP and streaks
+#[account]
+pub struct UserStats {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Total number of markets participated in
+    pub total_markets: u32,
+    /// Number of wins
+    pub wins: u32,
+    /// Number of losses
+    pub losses: u32,
+    /// Total amount wagered in lamports
+    pub total_wagered: u64,
+    /// Total amount won in lamports
+    pub total_won: u64,
+    /// Total stake lost on losing predictions in lamports
+    pub total_lost: u64,
+    /// Net profit/loss in lamports (total payouts minus total stakes)
+    pub net_profit: i64,
+    /// Return on investment in basis points (net_profit / total_wagered)
+    pub roi_bps: i64,
+    /// Largest single payout in lamports
+    pub largest_win: u64,
+    /// Average stake per market in lamports
+    pub average_stake: u64,
+    /// Experience points earned from settled markets
+    pub xp: u64,
+    /// Level derived from xp
+    pub level: u16,
+    /// XP spent on perks such as streak freezes (levels are based on lifetime xp)
+    pub xp_spent: u64,
+    /// Whether a streak freeze will absorb the next loss
+    pub streak_freeze_active: bool,
+    /// Current winning/losing streak (positive = wins, negative = losses)
+    pub current_streak: i32,
+    /// Best winning streak
+    pub best_streak: u32,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// Account layout version
+    pub version: u8,
+    /// Markets settled that meet the factory's leaderboard pool threshold
+    pub qualified_markets: u32,
+    /// Number of cancelled markets that were refunded
+    pub voids: u32,
+    /// Total stake refunded from cancelled markets in lamports
+    pub total_refunded: u64,
+    /// Wins read from market accounts, which reputation is minted from
+    pub verified_wins: u32,
+    /// Losses read from market accounts, which reputation is minted from
+    pub verified_losses: u32,
+    /// Reserved space for future stat fields
+    pub reserved: [u8; 40],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserStats {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 40;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 +  // total_markets
+        4 +  // wins
+        4 +  // losses
+        8 +  // total_wagered
+        8 +  // total_won
+        8 +  // total_lost
+        8 +  // net_profit
+        8 +  // roi_bps
+        8 +  // largest_win
+        8 +  // average_stake
+        8 +  // xp
+        2 +  // level
+        8 +  // xp_spent
+        1 +  // streak_freeze_active
+        4 +  // current_streak
+        4 +  // best_streak
+        8 +  // last_updated
+        1 +  // version
+        4 +  // qualified_markets
+        4 +  // voids
+        8 +  // total_refunded
+        4 +  // verified_wins
+        4 +  // verified_losses
+        Self::RESERVED_LEN + // reserved
+        1;   // bump
+}
+
+/// Original (version 0) UserStats layout, kept for migrations
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyUserStats {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Total number of markets participated in
+    pub total_markets: u32,
+    /// Number of markets won
+    pub wins: u32,
+    /// Number of markets lost
+    pub losses: u32,
+    /// Total lamports wagered
+    pub total_wagered: u64,
+    /// Total lamports won
+    pub total_won: u64,
+    /// Current streak, positive for wins and negative for losses
+    pub current_streak: i32,
+    /// Longest win streak
+    pub best_streak: u32,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LegacyUserStats {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 +  // total_markets
+        4 +  // wins
+        4 +  // losses
+        8 +  // total_wagered
+        8 +  // total_won
+        4 +  // current_streak
+        4 +  // best_streak
+        8 +  // last_updated
+        1;   // bump
+    
+    /// Convert to the current layout, deriving what can be derived from the old totals
+    pub fn into_current(self) -> UserStats {
+        let net_profit = (self.total_won as i128 - self.total_wagered as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        let average_stake = if self.total_markets > 0 {
+            self.total_wagered / self.total_markets as u64
+        } else {
+            0
+        };
+        
+        UserStats {
+            user: self.user,
+            total_markets: self.total_markets,
+            wins: self.wins,
+            losses: self.losses,
+            total_wagered: self.total_wagered,
+            total_won: self.total_won,
+            total_lost: 0,
+            net_profit,
+            roi_bps: roi_bps(net_profit, self.total_wagered),
+            largest_win: 0,
+            average_stake,
+            xp: 0,
+            level: 1,
+            xp_spent: 0,
+            streak_freeze_active: false,
+            current_streak: self.current_streak,
+            best_streak: self.best_streak,
+            last_updated: self.last_updated,
+            version: UserStats::CURRENT_VERSION,
+            qualified_markets: 0,
+            voids: 0,
+            total_refunded: 0,
+            verified_wins: 0,
+            verified_losses: 0,
+            reserved: [0; UserStats::RESERVED_LEN],
+            bump: self.bump,
+        }
+    }
+}
+
+/// Platform-wide totals, written by the market program via CPI
+#[account]
+pub struct PlatformStats {
+    /// Total number of markets created
+    pub total_markets: u64,
+    /// Markets created but not yet resolved
+    pub active_markets: u64,
+    /// Number of resolved markets
+    pub resolved_markets: u64,
+    /// Total number of market joins
+    pub total_participants: u64,
+    /// Total entry fees paid in lamports
+    pub total_volume: u64,
+    /// Total creator and platform fees distributed in lamports
+    pub total_fees: u64,
+    /// HyperLogLog registers for estimating unique bettors
+    pub bettor_sketch: [u8; 128],
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformStats {
+    pub const SKETCH_REGISTERS: usize = 128;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        8 +  // total_markets
+        8 +  // active_markets
+        8 +  // resolved_markets
+        8 +  // total_participants
+        8 +  // total_volume
+        8 +  // total_fees
+        Self::SKETCH_REGISTERS + // bettor_sketch
+        8 +  // last_updated
+        1;   // bump
+    
+    /// Add a bettor to the unique bettor sketch
+    pub fn record_bettor(&mut self, user: &Pubkey) {
+        record_in_sketch(&mut self.bettor_sketch, user);
+    }
+    
+    /// Estimate the number of unique bettors from the sketch (for off-chain use)
+    pub fn estimated_unique_bettors(&self) -> u64 {
+        estimate_sketch(&self.bettor_sketch)
+    }
+}
+
+/// Add `user` to a HyperLogLog sketch of unique bettors
+fn record_in_sketch(sketch: &mut [u8; PlatformStats::SKETCH_REGISTERS], user: &Pubkey) {
+    let hash = anchor_lang::solana_program::hash::hash(user.as_ref()).to_bytes();
+    let register = hash[0] as usize % PlatformStats::SKETCH_REGISTERS;
+    let mut rest = [0u8; 8];
+    rest.copy_from_slice(&hash[1..9]);
+    let rank = (u64::from_le_bytes(rest).leading_zeros() + 1) as u8;
+    if rank > sketch[register] {
+        sketch[register] = rank;
+    }
+}
+
+/// Estimate the number of unique bettors added to a HyperLogLog sketch
+fn estimate_sketch(sketch: &[u8; PlatformStats::SKETCH_REGISTERS]) -> u64 {
+    let m = PlatformStats::SKETCH_REGISTERS as f64;
+    let alpha = 0.7213 / (1.0 + 1.079 / m);
+    let sum: f64 = sketch.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+    let estimate = alpha * m * m / sum;
+    
+    // Small range correction using linear counting
+    let empty = sketch.iter().filter(|&&r| r == 0).count();
+    if estimate <= 2.5 * m && empty > 0 {
+        return (m * (m / empty as f64).ln()).round() as u64;
+    }
+    estimate.round() as u64
+}
+
+/// One day's activity on a factory's markets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct DayTotals {
+    /// Day number since the unix epoch
+    pub day: u32,
+    /// Entry fees paid in lamports
+    pub volume: u64,
+    /// Market joins
+    pub joins: u64,
+    /// Estimated bettors joining one of the factory's markets for the first
+    /// time, worked out when the day is rolled up
+    pub new_users: u64,
+    /// Markets created
+    pub markets_created: u64,
+    /// Markets resolved
+    pub markets_resolved: u64,
+    /// Creator and platform fees collected in lamports
+    pub fees: u64,
+}
+
+impl DayTotals {
+    pub const LEN: usize =
+        4 +  // day
+        8 +  // volume
+        8 +  // joins
+        8 +  // new_users
+        8 +  // markets_created
+        8 +  // markets_resolved
+        8;   // fees
+    
+    fn is_empty(&self) -> bool {
+        *self == DayTotals { day: self.day, ..DayTotals::default() }
+    }
+}
+
+/// A factory's activity counted by day, written by the market program via
+/// CPI once initialized, at the `platform_activity` PDA of the factory
+#[account]
+pub struct PlatformActivity {
+    /// Factory whose markets are counted
+    pub factory: Pubkey,
+    /// Day being counted
+    pub current: DayTotals,
+    /// Latest day that closed with any activity, ready to roll up
+    pub last_closed: DayTotals,
+    /// HyperLogLog registers over every bettor the factory has seen
+    pub bettor_sketch: [u8; PlatformStats::SKETCH_REGISTERS],
+    /// Bettor sketch as the current day started, which is also how the last
+    /// closed day ended since only joins change it
+    pub day_start_sketch: [u8; PlatformStats::SKETCH_REGISTERS],
+    /// Bettor sketch as the last closed day started
+    pub closed_start_sketch: [u8; PlatformStats::SKETCH_REGISTERS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformActivity {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        DayTotals::LEN + // current
+        DayTotals::LEN + // last_closed
+        PlatformStats::SKETCH_REGISTERS + // bettor_sketch
+        PlatformStats::SKETCH_REGISTERS + // day_start_sketch
+        PlatformStats::SKETCH_REGISTERS + // closed_start_sketch
+        1;   // bump
+    
+    /// Close the current day if `day` is later, keeping it for the rollup if
+    /// anything happened, and start counting `day`
+    ///
+    /// Only copies sketches, leaving the estimates to the rollup crank so
+    /// joins don't pay for them.
+    pub fn roll_over(&mut self, day: u32) {
+        if day <= self.current.day {
+            return;
+        }
+        if !self.current.is_empty() {
+            self.last_closed = self.current;
+            self.closed_start_sketch = self.day_start_sketch;
+        }
+        self.current = DayTotals { day, ..DayTotals::default() };
+        self.day_start_sketch = self.bettor_sketch;
+    }
+    
+    /// Estimated bettors first seen on the last closed day
+    pub fn closed_day_new_users(&self) -> u64 {
+        estimate_sketch(&self.day_start_sketch).saturating_sub(estimate_sketch(&self.closed_start_sketch))
+    }
+    
+    /// Count `activity` towards the day of `timestamp`
+    pub fn record(&mut self, activity: &MarketActivity, timestamp: i64) -> Result<()> {
+        self.roll_over(day_of(timestamp));
+        let today = &mut self.current;
+        match *activity {
+            MarketActivity::Created => {
+                today.markets_created = today.markets_created.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            MarketActivity::Joined { user, amount } => {
+                today.joins = today.joins.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                today.volume = today.volume.checked_add(amount)
+                    .ok_or(DashboardError::StatOverflow)?;
+                record_in_sketch(&mut self.bettor_sketch, &user);
+            },
+            MarketActivity::Resolved { fees } => {
+                today.markets_resolved = today.markets_resolved.checked_add(1)
+                    .ok_or(DashboardError::StatOverflow)?;
+                today.fees = today.fees.checked_add(fees)
+                    .ok_or(DashboardError::StatOverflow)?;
+            },
+            MarketActivity::Cancelled { .. } => {},
+        }
+        Ok(())
+    }
+}
+
+/// A factory's closed day of activity, at the `daily_rollup` PDA of the
+/// factory and day
+#[account]
+pub struct DailyRollupSnapshot {
+    /// Factory whose markets were counted
+    pub factory: Pubkey,
+    /// The day's totals
+    pub totals: DayTotals,
+    /// Rollup timestamp
+    pub rolled_up_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DailyRollupSnapshot {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        DayTotals::LEN + // totals
+        8 +  // rolled_up_at
+        1;   // bump
+}
+
+/// A market creator's volume, fees and reputation
+#[account]
+pub struct CreatorStats {
+    /// Creator's wallet address
+    pub creator: Pubkey,
+    /// Number of markets created
+    pub markets_created: u32,
+    /// Number of markets resolved
+    pub markets_resolved: u32,
+    /// Total pool across resolved markets in lamports
+    pub total_volume_hosted: u64,
+    /// Total creator fees earned in lamports
+    pub total_fees_earned: u64,
+    /// Number of resolutions overturned in disputes
+    pub disputes_lost: u32,
+    /// Bitmask of earned creator badges
+    pub badges: u8,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorStats {
+    /// Created a first market
+    pub const BADGE_FIRST_MARKET: u8 = 1 << 0;
+    /// Created 10 markets
+    pub const BADGE_ESTABLISHED: u8 = 1 << 1;
+    /// Created 100 markets
+    pub const BADGE_VETERAN: u8 = 1 << 2;
+    /// Hosted at least 100 SOL of volume
+    pub const BADGE_HIGH_VOLUME: u8 = 1 << 3;
+    /// Resolved 10 markets without losing a dispute (revoked on a lost dispute)
+    pub const BADGE_TRUSTED: u8 = 1 << 4;
+    
+    pub const HIGH_VOLUME_LAMPORTS: u64 = 100_000_000_000;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // creator
+        4 +  // markets_created
+        4 +  // markets_resolved
+        8 +  // total_volume_hosted
+        8 +  // total_fees_earned
+        4 +  // disputes_lost
+        1 +  // badges
+        8 +  // last_updated
+        1;   // bump
+    
+    /// Badges the creator currently qualifies for
+    pub fn earned_badges(&self) -> u8 {
+        let mut badges = 0;
+        if self.markets_created >= 1 {
+            badges |= Self::BADGE_FIRST_MARKET;
+        }
+        if self.markets_created >= 10 {
+            badges |= Self::BADGE_ESTABLISHED;
+        }
+        if self.markets_created >= 100 {
+            badges |= Self::BADGE_VETERAN;
+        }
+        if self.total_volume_hosted >= Self::HIGH_VOLUME_LAMPORTS {
+            badges |= Self::BADGE_HIGH_VOLUME;
+        }
+        if self.markets_resolved >= 10 && self.disputes_lost == 0 {
+            badges |= Self::BADGE_TRUSTED;
+        }
+        badges
+    }
+    
+    /// Share of resolved markets not overturned in disputes, in basis points
+    pub fn reputation_bps(&self) -> u16 {
+        if self.markets_resolved == 0 {
+            return 0;
+        }
+        let upheld = self.markets_resolved.saturating_sub(self.disputes_lost) as u64;
+        (upheld * 10_000 / self.markets_resolved as u64) as u16
+    }
+}
+
+/// Top win streaks across all users
+#[account]
+pub struct StreakLeaderboard {
+    /// Factory whose users the leaderboard ranks
+    pub factory: Pubkey,
+    /// Seconds of inactivity after which an entry loses one streak point
+    pub decay_window: i64,
+    /// Entries sorted by decayed score, highest first
+    pub entries: Vec<StreakEntry>,
+    /// Last time the decay crank ran
+    pub last_cranked: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StreakLeaderboard {
+    pub const MAX_ENTRIES: usize = 20;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // decay_window
+        4 + Self::MAX_ENTRIES * StreakEntry::LEN + // entries
+        8 +  // last_cranked
+        1;   // bump
+    
+    /// Recompute decayed scores, drop fully decayed entries, sort and truncate
+    pub fn apply_decay(&mut self, current_time: i64) {
+        let decay_window = self.decay_window;
+        for entry in self.entries.iter_mut() {
+            let idle = current_time.saturating_sub(entry.last_settled).max(0);
+            let decayed = (idle / decay_window).min(u32::MAX as i64) as u32;
+            entry.score = entry.streak.saturating_sub(decayed);
+        }
+        
+        self.entries.retain(|entry| entry.score > 0);
+        self.entries.sort_by(|a, b| b.score.cmp(&a.score).then(b.last_settled.cmp(&a.last_settled)));
+        self.entries.truncate(Self::MAX_ENTRIES);
+    }
+}
+
+/// One user's place on the streak leaderboard
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StreakEntry {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Win streak when submitted
+    pub streak: u32,
+    /// Streak after inactivity decay
+    pub score: u32,
+    /// Timestamp of the user's last settlement
+    pub last_settled: i64,
+}
+
+impl StreakEntry {
+    pub const LEN: usize = 32 + 4 + 4 + 8;
+}
+
+/// A user's stats frozen at a given week
+#[account]
+pub struct StatsSnapshot {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Week number since the unix epoch
+    pub week: u32,
+    /// Total number of markets participated in
+    pub total_markets: u32,
+    /// Number of wins
+    pub wins: u32,
+    /// Number of losses
+    pub losses: u32,
+    /// Total amount wagered in lamports
+    pub total_wagered: u64,
+    /// Total amount won in lamports
+    pub total_won: u64,
+    /// Net profit/loss in lamports
+    pub net_profit: i64,
+    /// Winning/losing streak at snapshot time
+    pub current_streak: i32,
+    /// Lifetime XP at snapshot time
+    pub xp: u64,
+    /// Level at snapshot time
+    pub level: u16,
+    /// Snapshot timestamp
+    pub taken_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StatsSnapshot {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 +  // week
+        4 +  // total_markets
+        4 +  // wins
+        4 +  // losses
+        8 +  // total_wagered
+        8 +  // total_won
+        8 +  // net_profit
+        4 +  // current_streak
+        8 +  // xp
+        2 +  // level
+        8 +  // taken_at
+        1;   // bump
+}
+
+/// A user's pick accuracy by outcome and league
+#[account]
+pub struct UserBreakdown {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Accuracy of HOME picks
+    pub home: Accuracy,
+    /// Accuracy of DRAW picks
+    pub draw: Accuracy,
+    /// Accuracy of AWAY picks
+    pub away: Accuracy,
+    /// Accuracy per league code
+    pub leagues: Vec<LeagueAccuracy>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserBreakdown {
+    pub const MAX_LEAGUES: usize = 16;
+    pub const MAX_LEAGUE_CODE_LEN: usize = 8;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        Accuracy::LEN * 3 + // home, draw, away
+        4 + Self::MAX_LEAGUES * LeagueAccuracy::LEN + // leagues
+        1;   // bump
+    
+    /// League code a match ID starts with, e.g. "EPL" for "EPL-2024-ARS-CHE",
+    /// if it is 1-8 characters
+    pub fn league_code(match_id: &str) -> Option<&str> {
+        let code = match_id.split('-').next()?;
+        (!code.is_empty() && code.len() <= Self::MAX_LEAGUE_CODE_LEN).then_some(code)
+    }
+    
+    /// Pad a league code (e.g. "EPL") into its fixed-size on-chain form
+    pub fn league_code_bytes(league_code: &str) -> [u8; 8] {
+        let mut code = [0u8; 8];
+        code[..league_code.len()].copy_from_slice(league_code.as_bytes());
+        code
+    }
+}
+
+/// Correct and incorrect pick counters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Accuracy {
+    /// Number of correct picks
+    pub correct: u32,
+    /// Number of incorrect picks
+    pub incorrect: u32,
+}
+
+impl Accuracy {
+    pub const LEN: usize = 4 + 4;
+    
+    fn record(&mut self, correct: bool) -> Result<()> {
+        let counter = if correct { &mut self.correct } else { &mut self.incorrect };
+        *counter = counter.checked_add(1).ok_or(DashboardError::StatOverflow)?;
+        Ok(())
+    }
+}
+
+/// Pick accuracy within one league
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LeagueAccuracy {
+    /// League code, zero padded (e.g. "EPL")
+    pub code: [u8; 8],
+    /// Accuracy of picks in this league
+    pub accuracy: Accuracy,
+}
+
+impl LeagueAccuracy {
+    pub const LEN: usize = 8 + Accuracy::LEN;
+}
+
+/// Record between two users across the markets they both joined
+#[account]
+pub struct HeadToHead {
+    /// Lower of the two user pubkeys
+    pub user_a: Pubkey,
+    /// Higher of the two user pubkeys
+    pub user_b: Pubkey,
+    /// Number of markets both users settled
+    pub shared_markets: u32,
+    /// Markets where only user_a picked correctly
+    pub user_a_wins: u32,
+    /// Markets where only user_b picked correctly
+    pub user_b_wins: u32,
+    /// Markets where both picked correctly
+    pub both_correct: u32,
+    /// Markets where neither picked correctly
+    pub both_incorrect: u32,
+    /// Last market recorded
+    pub last_market: Pubkey,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HeadToHead {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user_a
+        32 + // user_b
+        4 +  // shared_markets
+        4 +  // user_a_wins
+        4 +  // user_b_wins
+        4 +  // both_correct
+        4 +  // both_incorrect
+        32 + // last_market
+        8 +  // last_updated
+        1;   // bump
+}
+
+/// Marker that a market has been counted in a head-to-head record, so it
+/// never counts twice
+#[account]
+pub struct HeadToHeadMarket {
+    /// Head-to-head record the market was counted in
+    pub head_to_head: Pubkey,
+    /// Market both users entered
+    pub market: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HeadToHeadMarket {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // head_to_head
+        32 + // market
+        1;   // bump
+}
+
+/// Marker that a user's entry in one market has been counted in a record,
+/// so it never counts twice
+#[account]
+pub struct RecordedEntry {
+    /// Market the entry was in
+    pub market: Pubkey,
+    /// User who made the entry
+    pub user: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RecordedEntry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        1;   // bump
+}
+
+/// Public profile with a unique username
+#[account]
+pub struct UserProfile {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Unique username (lowercase a-z, 0-9 and underscore)
+    pub username: String,
+    /// Content hash of the avatar image (e.g. SHA-256 of an IPFS/Arweave object)
+    pub avatar_hash: [u8; 32],
+    /// Profile creation timestamp
+    pub created_at: i64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserProfile {
+    pub const MIN_USERNAME_LEN: usize = 3;
+    pub const MAX_USERNAME_LEN: usize = 32;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 + Self::MAX_USERNAME_LEN + // username
+        32 + // avatar_hash
+        8 +  // created_at
+        8 +  // updated_at
+        1;   // bump
+}
+
+/// Claims a username for one wallet, at a PDA seeded by the username
+#[account]
+pub struct UsernameRecord {
+    /// Wallet that owns this username
+    pub owner: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UsernameRecord {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // owner
+        1;   // bump
+}
+
+/// Creator fees accrued across all of a creator's markets, held as this
+/// account's lamports until claimed
+#[account]
+pub struct CreatorEarnings {
+    /// Creator's wallet address
+    pub creator: Pubkey,
+    /// Total creator fees credited in lamports
+    pub total_accrued: u64,
+    /// Total creator fees paid out in lamports
+    pub total_claimed: u64,
+    /// Number of markets that credited a fee
+    pub markets_accrued: u32,
+    /// Timestamp of the last credited fee
+    pub last_accrued_at: i64,
+    /// Timestamp of the last claim
+    pub last_claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorEarnings {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // creator
+        8 +  // total_accrued
+        8 +  // total_claimed
+        4 +  // markets_accrued
+        8 +  // last_accrued_at
+        8 +  // last_claimed_at
+        1;   // bump
+    
+    /// Accrued fees not yet paid out
+    pub fn unclaimed(&self) -> u64 {
+        self.total_accrued.saturating_sub(self.total_claimed)
+    }
+}
+
+/// Markets a user follows, shared across every device they sign in from
+#[account]
+pub struct Watchlist {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Followed markets, oldest first
+    pub markets: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Watchlist {
+    pub const MAX_MARKETS: usize = 32;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 + Self::MAX_MARKETS * 32 + // markets
+        1;   // bump
+}
+
+/// Entries a user has made since opting in to losing-streak rebates, and
+/// the streak their settled results add up to, at the `rebate_tracker` PDA
+/// of the user
+///
+/// Unlike `UserStats`, only the market program can update it, so the
+/// factory can pay rebates against it.
+#[account]
+pub struct RebateTracker {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Markets joined whose results haven't been settled yet, oldest first
+    pub open_entries: Vec<Pubkey>,
+    /// Consecutive losses among settled entries
+    pub loss_streak: u32,
+    /// Losses of the current streak already used for a rebate
+    pub rebated_losses: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RebateTracker {
+    pub const MAX_OPEN_ENTRIES: usize = 16;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 + Self::MAX_OPEN_ENTRIES * 32 + // open_entries
+        4 +  // loss_streak
+        4 +  // rebated_losses
+        1;   // bump
+    
+    /// Whether the next entry earns a rebate under a policy paying one every
+    /// `loss_streak` losses
+    ///
+    /// Every tracked entry must be settled first, so an unsettled win can't
+    /// be held back to keep a streak alive.
+    pub fn rebate_due(&self, loss_streak: u16) -> bool {
+        loss_streak > 0
+            && self.open_entries.is_empty()
+            && self.loss_streak >= self.rebated_losses.saturating_add(loss_streak as u32)
+    }
+    
+    /// Drop every open entry and the streak
+    pub fn reset(&mut self) {
+        self.open_entries.clear();
+        self.loss_streak = 0;
+        self.rebated_losses = 0;
+    }
+}
+
+/// Markets where a user holds an unclaimed win, so one account read can
+/// answer "you have N unclaimed wins"
+///
+/// Entries are added by a crank once a market resolves and removed when the
+/// reward is withdrawn.
+#[account]
+pub struct PendingClaims {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Markets with an unclaimed win, oldest first
+    pub markets: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingClaims {
+    pub const MAX_MARKETS: usize = 32;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 + Self::MAX_MARKETS * 32 + // markets
+        1;   // bump
+}
+
+/// A user's lifetime points and their tallies for recent epochs, at the
+/// `points_ledger` PDA of the user
+///
+/// Only the market program credits points, so a token distribution or
+/// seasonal rewards can be computed from chain state alone.
+#[account]
+pub struct PointsLedger {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Lifetime points earned by wagering
+    pub wager_points: u64,
+    /// Lifetime points earned by creating markets
+    pub creator_points: u64,
+    /// Points per epoch, in the slot `epoch % RECENT_POINTS_EPOCHS`
+    pub epochs: [EpochPoints; RECENT_POINTS_EPOCHS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsLedger {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        8 +  // wager_points
+        8 +  // creator_points
+        RECENT_POINTS_EPOCHS * EpochPoints::LEN + // epochs
+        1;   // bump
+    
+    /// Lifetime points
+    pub fn total(&self) -> u64 {
+        self.wager_points.saturating_add(self.creator_points)
+    }
+    
+    /// Points earned in `epoch`, or `None` once its slot has been reused
+    ///
+    /// An epoch with no credits still in range reads as zero.
+    pub fn points_in(&self, epoch: u32) -> Option<u64> {
+        let tally = &self.epochs[epoch as usize % RECENT_POINTS_EPOCHS];
+        match tally.epoch.cmp(&epoch) {
+            Ordering::Equal => Some(tally.points),
+            Ordering::Less => Some(0),
+            Ordering::Greater => None,
+        }
+    }
+    
+    /// The tally for `epoch`, clearing the slot of an older epoch
+    fn tally_mut(&mut self, epoch: u32) -> &mut EpochPoints {
+        let tally = &mut self.epochs[epoch as usize % RECENT_POINTS_EPOCHS];
+        if tally.epoch != epoch {
+            *tally = EpochPoints { epoch, points: 0 };
+        }
+        tally
+    }
+}
+
+/// Points credited in one epoch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct EpochPoints {
+    /// Week number since the unix epoch
+    pub epoch: u32,
+    /// Points credited in the week
+    pub points: u64,
+}
+
+impl EpochPoints {
+    pub const LEN: usize = 4 + 8;
+}
+
+/// Points credited across all users for recent epochs, at the
+/// `points_totals` PDA
+#[account]
+pub struct PointsTotals {
+    /// Totals per epoch, in the slot `epoch % RECENT_POINTS_EPOCHS`
+    pub epochs: [EpochPointsTotal; RECENT_POINTS_EPOCHS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsTotals {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        RECENT_POINTS_EPOCHS * EpochPointsTotal::LEN + // epochs
+        1;   // bump
+    
+    /// Totals for `epoch`, or `None` once its slot has been reused
+    pub fn total_in(&self, epoch: u32) -> Option<EpochPointsTotal> {
+        let total = self.epochs[epoch as usize % RECENT_POINTS_EPOCHS];
+        match total.epoch.cmp(&epoch) {
+            Ordering::Equal => Some(total),
+            Ordering::Less => Some(EpochPointsTotal { epoch, ..EpochPointsTotal::default() }),
+            Ordering::Greater => None,
+        }
+    }
+    
+    /// The totals for `epoch`, clearing the slot of an older epoch
+    fn total_mut(&mut self, epoch: u32) -> &mut EpochPointsTotal {
+        let total = &mut self.epochs[epoch as usize % RECENT_POINTS_EPOCHS];
+        if total.epoch != epoch {
+            *total = EpochPointsTotal { epoch, ..EpochPointsTotal::default() };
+        }
+        total
+    }
+}
+
+/// Points credited across all users in one epoch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct EpochPointsTotal {
+    /// Week number since the unix epoch
+    pub epoch: u32,
+    /// Points credited in the week
+    pub points: u64,
+    /// Users credited at least once in the week
+    pub users: u32,
+}
+
+impl EpochPointsTotal {
+    pub const LEN: usize = 4 + 8 + 4;
+}
+
+/// A user's points for a finished epoch, at the `points_snapshot` PDA of
+/// the user and epoch
+#[account]
+pub struct PointsSnapshot {
+    /// User's wallet address
+    pub user: Pubkey,
+    /// Week number since the unix epoch
+    pub epoch: u32,
+    /// Points earned in the epoch
+    pub points: u64,
+    /// Snapshot timestamp
+    pub taken_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsSnapshot {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // user
+        4 +  // epoch
+        8 +  // points
+        8 +  // taken_at
+        1;   // bump
+}
+
+/// Points credited across all users in a finished epoch, at the
+/// `points_total_snapshot` PDA of the epoch
+#[account]
+pub struct PointsTotalSnapshot {
+    /// Week number since the unix epoch
+    pub epoch: u32,
+    /// Points credited in the epoch
+    pub points: u64,
+    /// Users credited at least once in the epoch
+    pub users: u32,
+    /// Snapshot timestamp
+    pub taken_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsTotalSnapshot {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        4 +  // epoch
+        8 +  // points
+        4 +  // users
+        8 +  // taken_at
+        1;   // bump
+}
+
+// Helpers
+
+/// XP for taking part in a market regardless of the result
+pub const XP_PER_MARKET: u64 = 10;
+/// Base XP for a correct prediction
+pub const XP_PER_WIN: u64 = 50;
+/// Stake (in lamports) that earns one extra XP on a win
+pub const LAMPORTS_PER_STAKE_XP: u64 = 100_000_000;
+/// Maximum XP a single stake can contribute
+pub const MAX_STAKE_XP: u64 = 100;
+/// XP needed for level 2; level n needs XP_LEVEL_BASE * (n - 1)^2
+pub const XP_LEVEL_BASE: u64 = 100;
+/// XP cost of protecting a win streak from one loss
+pub const STREAK_FREEZE_XP_COST: u64 = 200;
+
+/// Calculate XP for a settled market
+///
+/// Wins earn base XP plus a stake bonus, multiplied by a difficulty factor
+/// between 1x and 2x that grows as fewer participants made the same pick.
+pub fn xp_for_result(market_result: &MarketResult, amount_wagered: u64, pick_share_bps: u16) -> u64 {
+    match market_result {
+        MarketResult::Win => {
+            let stake_xp = (amount_wagered / LAMPORTS_PER_STAKE_XP).min(MAX_STAKE_XP);
+            let difficulty_bps = 20_000 - pick_share_bps.min(10_000) as u64;
+            (XP_PER_WIN + stake_xp) * difficulty_bps / 10_000
+        },
+        MarketResult::Loss => XP_PER_MARKET,
+        MarketResult::Void => 0,
+    }
+}
+
+/// Participants of `market` who picked the outcome it resolved to
+fn winning_picks(market: &Market) -> Option<u32> {
+    match market.outcome {
+        Some(MatchOutcome::Home) => Some(market.home_count),
+        Some(MatchOutcome::Draw) => Some(market.draw_count),
+        Some(MatchOutcome::Away) => Some(market.away_count),
+        None => None,
+    }
+}
+
+/// Share of `market`'s participants, in basis points, who picked the outcome
+/// it resolved to; every participant counts until it has resolved
+pub fn winning_pick_share_bps(market: &Market) -> u16 {
+    match winning_picks(market) {
+        Some(picks) if market.participant_count > 0 => {
+            (picks as u64 * 10_000 / market.participant_count as u64).min(10_000) as u16
+        },
+        _ => 10_000,
+    }
+}
+
+/// Result of an entry on `prediction` in `market`, once the market has
+/// resolved for good or been called off
+fn settled_result(market: &Market, prediction: &MatchOutcome) -> Result<MarketResult> {
+    if market.status.is_cancelled() {
+        return Ok(MarketResult::Void);
+    }
+    match &market.outcome {
+        Some(outcome) if market.status.is_resolved() => {
+            require_result_final(market)?;
+            Ok(if outcome == prediction { MarketResult::Win } else { MarketResult::Loss })
+        },
+        _ => Err(DashboardError::MarketNotSettled.into()),
+    }
+}
+
+/// Outcome `market` resolved to, once it can no longer be corrected
+fn resolved_outcome(market: &Market) -> Result<MatchOutcome> {
+    match &market.outcome {
+        Some(outcome) if market.status.is_resolved() => {
+            require_result_final(market)?;
+            Ok(outcome.clone())
+        },
+        _ => Err(DashboardError::MarketNotResolved.into()),
+    }
+}
+
+/// Fail while the results signer can still correct `market`'s result
+///
+/// Stats, breakdowns and head-to-heads record outcomes once and for all, and
+/// reputation and streak leaderboards build on those stats, so they only
+/// take a result after `RESULT_CORRECTION_WINDOW_SECONDS` past the match.
+fn require_result_final(market: &Market) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let final_at = market.end_time.saturating_add(RESULT_CORRECTION_WINDOW_SECONDS);
+    if current_time <= final_at {
+        return Err(error!(DashboardError::CorrectionWindowOpen).with_values((current_time, final_at)));
+    }
+    Ok(())
+}
+
+/// Equal share of a resolved `market`'s pool after fees paid to each winner
+fn reward_per_winner(market: &Market) -> u64 {
+    let winners = winning_picks(market).unwrap_or(0).max(1);
+    market.total_pool.saturating_sub(market.fees_paid) / winners as u64
+}
+
+/// Points per lamport wagered
+pub const POINTS_PER_LAMPORT_WAGERED: u64 = 1;
+/// Points for creating a market, worth a 0.1 SOL wager
+pub const POINTS_PER_MARKET_CREATED: u64 = 100_000_000;
+/// Epochs of points tallies kept for snapshots
+pub const RECENT_POINTS_EPOCHS: usize = 8;
+
+/// Seconds in a stats snapshot period
+pub const SECONDS_PER_WEEK: i64 = 7 * 24 * 60 * 60;
+
+/// Week number since the unix epoch for a timestamp
+pub fn week_of(timestamp: i64) -> u32 {
+    (timestamp.max(0) / SECONDS_PER_WEEK) as u32
+}
+
+/// Seconds in a daily rollup period
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Day number since the unix epoch for a timestamp
+pub fn day_of(timestamp: i64) -> u32 {
+    (timestamp.max(0) / SECONDS_PER_DAY) as u32
+}
+
+/// Calculate the level reached with the given XP (starting at level 1)
+pub fn level_for_xp(xp: u64) -> u16 {
+    // Binary search for the largest step count with step^2 <= xp / XP_LEVEL_BASE
+    let units = xp / XP_LEVEL_BASE;
+    let (mut low, mut high) = (0u64, u16::MAX as u64 - 1);
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if mid * mid <= units {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    (low + 1) as u16
+}
+
+/// Validate a username is 3-32 characters of lowercase a-z, 0-9 or underscore
+fn validate_username(username: &str) -> Result<()> {
+    require!(
+        username.len() >= UserProfile::MIN_USERNAME_LEN
+            && username.len() <= UserProfile::MAX_USERNAME_LEN,
+        DashboardError::InvalidUsernameLength
+    );
+    require!(
+        username.bytes().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_'),
+        DashboardError::InvalidUsernameCharacters
+    );
+    Ok(())
+}
+
+/// Apply a signed profit delta to a running net profit total
+fn apply_profit(net_profit: i64, delta: i128) -> Result<i64> {
+    let updated = (net_profit as i128)
+        .checked_add(delta)
+        .ok_or(DashboardError::StatOverflow)?;
+    i64::try_from(updated).map_err(|_| error!(DashboardError::StatOverflow))
+}
+
+/// Calculate ROI in basis points, saturating at the i64 bounds
+fn roi_bps(net_profit: i64, total_wagered: u64) -> i64 {
+    if total_wagered == 0 {
+        return 0;
+    }
+    let roi = (net_profit as i128) * 10_000 / total_wagered as i128;
+    roi.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+// Enums
+
+/// How a market settled for a user
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MarketResult {
+    /// Prediction matched the outcome
+    Win,
+    /// Prediction missed the outcome
+    Loss,
+    /// Market was cancelled and the stake refunded
+    Void,
+}
+
+/// Market lifecycle change reported to platform stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MarketActivity {
+    /// A market was initialized
+    Created,
+    /// `user` joined a market paying `amount` lamports
+    Joined { user: Pubkey, amount: u64 },
+    /// A market was resolved, collecting `fees` lamports
+    Resolved { fees: u64 },
+    /// A market was called off, or voided after it resolved if `was_resolved`
+    Cancelled { was_resolved: bool },
+}
+
+/// Activity earning points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PointsActivity {
+    /// The user wagered `amount` lamports on a market
+    Wagered { amount: u64 },
+    /// The user created a market
+    MarketCreated,
+}
+
+impl PointsActivity {
+    /// Points the activity earns, or `None` on overflow
+    pub fn points(&self) -> Option<u64> {
+        match self {
+            PointsActivity::Wagered { amount } => amount.checked_mul(POINTS_PER_LAMPORT_WAGERED),
+            PointsActivity::MarketCreated => Some(POINTS_PER_MARKET_CREATED),
+        }
+    }
+}
+
+/// Market lifecycle change reported to creator stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum CreatorActivity {
+    /// The creator initialized a market
+    MarketCreated,
+    /// One of the creator's markets resolved with `volume` lamports pooled
+    MarketResolved { volume: u64, creator_fee: u64 },
+    /// A resolution by the creator was overturned
+    DisputeLost,
+}
+
+// Context Structures
+
+/// Accounts for `update_user_stats`
+#[derive(Accounts)]
+pub struct UpdateUserStats<'info> {
+    /// The user's stats PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::LEN,
+        seeds = [
+            b"user_stats",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// Marker that the entry has been recorded, so it counts only once
+    #[account(
+        init,
+        payer = payer,
+        space = RecordedEntry::LEN,
+        seeds = [
+            STATS_ENTRY_SEED,
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub stats_entry: Account<'info, RecordedEntry>,
+    
+    /// Factory the market was created under, holding its leaderboard qualification rules
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Market the entry was in, whose status and outcome decide the result
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's entry in the market
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// CHECK: User whose stats are updated, tied to the entry by the participant's seeds
+    pub user: UncheckedAccount<'info>,
+    
+    /// Pays for the records created on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `migrate_user_stats`
+#[derive(Accounts)]
+pub struct MigrateUserStats<'info> {
+    /// CHECK: Deserialized manually since older layouts don't match UserStats
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"user_stats",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_stats: UncheckedAccount<'info>,
+    
+    /// Owner of the stats, paying for any extra space
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `purchase_streak_freeze`
+#[derive(Accounts)]
+pub struct PurchaseStreakFreeze<'info> {
+    /// The user's stats, spending XP
+    #[account(
+        mut,
+        seeds = [
+            b"user_stats",
+            user.key().as_ref()
+        ],
+        bump = user_stats.bump,
+        has_one = user
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// Owner of the stats
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `snapshot_user_stats`
+#[derive(Accounts)]
+#[instruction(week: u32)]
+pub struct SnapshotUserStats<'info> {
+    /// Stats being snapshotted
+    #[account(
+        seeds = [
+            b"user_stats",
+            user_stats.user.as_ref()
+        ],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// Snapshot PDA for the week, created here
+    #[account(
+        init,
+        payer = payer,
+        space = StatsSnapshot::LEN,
+        seeds = [
+            b"stats_snapshot",
+            user_stats.user.as_ref(),
+            &week.to_le_bytes()
+        ],
+        bump
+    )]
+    pub stats_snapshot: Account<'info, StatsSnapshot>,
+    
+    /// Anyone can crank snapshots and pays for the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `initialize_streak_leaderboard`
+#[derive(Accounts)]
+pub struct InitializeStreakLeaderboard<'info> {
+    /// Streak leaderboard PDA of the factory, created here
+    #[account(
+        init,
+        payer = payer,
+        space = StreakLeaderboard::LEN,
+        seeds = [b"streak_leaderboard", factory.key().as_ref()],
+        bump
+    )]
+    pub streak_leaderboard: Account<'info, StreakLeaderboard>,
+    
+    /// Factory or platform factory whose users the leaderboard ranks
+    pub factory: Account<'info, Factory>,
+    
+    /// Pays for the leaderboard account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `submit_streak`
+#[derive(Accounts)]
+pub struct SubmitStreak<'info> {
+    /// The factory's leaderboard receiving the entry
+    #[account(
+        mut,
+        seeds = [b"streak_leaderboard", factory.key().as_ref()],
+        bump = streak_leaderboard.bump
+    )]
+    pub streak_leaderboard: Account<'info, StreakLeaderboard>,
+    
+    /// Stats of the user being submitted
+    #[account(
+        seeds = [
+            b"user_stats",
+            user_stats.user.as_ref()
+        ],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// Factory or platform factory the leaderboard ranks, holding its qualification rules
+    pub factory: Account<'info, Factory>,
+}
+
+/// Accounts for `decay_streak_leaderboard`
+#[derive(Accounts)]
+pub struct DecayStreakLeaderboard<'info> {
+    /// Leaderboard being decayed
+    #[account(
+        mut,
+        seeds = [b"streak_leaderboard", streak_leaderboard.factory.as_ref()],
+        bump = streak_leaderboard.bump
+    )]
+    pub streak_leaderboard: Account<'info, StreakLeaderboard>,
+}
+
+/// Accounts for `update_user_breakdown`
+#[derive(Accounts)]
+pub struct UpdateUserBreakdown<'info> {
+    /// The user's breakdown PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserBreakdown::LEN,
+        seeds = [
+            b"user_breakdown",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_breakdown: Account<'info, UserBreakdown>,
+    
+    /// Marker that the entry has been recorded, so it counts only once
+    #[account(
+        init,
+        payer = payer,
+        space = RecordedEntry::LEN,
+        seeds = [
+            BREAKDOWN_ENTRY_SEED,
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub breakdown_entry: Account<'info, RecordedEntry>,
+    
+    /// Resolved market the entry was in, whose outcome and match ID decide
+    /// the accuracy and league recorded
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's entry in the market
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// CHECK: User whose breakdown is updated, tied to the entry by the participant's seeds
+    pub user: UncheckedAccount<'info>,
+    
+    /// Pays for the records created on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `update_head_to_head`
+#[derive(Accounts)]
+pub struct UpdateHeadToHead<'info> {
+    /// Head-to-head PDA for the pair, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = HeadToHead::LEN,
+        seeds = [
+            b"head_to_head",
+            user_a.key().as_ref(),
+            user_b.key().as_ref()
+        ],
+        bump
+    )]
+    pub head_to_head: Account<'info, HeadToHead>,
+    
+    /// Marker that the market has been recorded for the pair, so it counts only once
+    #[account(
+        init,
+        payer = payer,
+        space = HeadToHeadMarket::LEN,
+        seeds = [
+            HEAD_TO_HEAD_MARKET_SEED,
+            head_to_head.key().as_ref(),
+            market.key().as_ref()
+        ],
+        bump
+    )]
+    pub head_to_head_market: Account<'info, HeadToHeadMarket>,
+    
+    /// Resolved market both users entered
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// user_a's entry in the market
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user_a.key().as_ref()
+        ],
+        bump = participant_a.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub participant_a: Account<'info, Participant>,
+    
+    /// user_b's entry in the market
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user_b.key().as_ref()
+        ],
+        bump = participant_b.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub participant_b: Account<'info, Participant>,
+    
+    /// CHECK: First user of the pair, must sort before user_b
+    #[account(
+        constraint = user_a.key() < user_b.key() @ DashboardError::UnsortedHeadToHeadPair
+    )]
+    pub user_a: UncheckedAccount<'info>,
+    
+    /// CHECK: Second user of the pair
+    pub user_b: UncheckedAccount<'info>,
+    
+    /// Pays for the records created on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `create_profile`
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct CreateProfile<'info> {
+    /// Profile PDA, created here
+    #[account(
+        init,
+        payer = user,
+        space = UserProfile::LEN,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    /// Username claim, created here
+    #[account(
+        init,
+        payer = user,
+        space = UsernameRecord::LEN,
+        seeds = [
+            b"username",
+            username.as_bytes()
+        ],
+        bump
+    )]
+    pub username_record: Account<'info, UsernameRecord>,
+    
+    /// Profile owner
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `update_profile`
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    /// Profile being updated
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump = user_profile.bump,
+        has_one = user
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    /// Profile owner
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `change_username`
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    /// Profile being renamed
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump = user_profile.bump,
+        has_one = user
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    /// Current username claim, closed to the user
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"username",
+            user_profile.username.as_bytes()
+        ],
+        bump = old_username_record.bump,
+        constraint = old_username_record.owner == user.key() @ DashboardError::UsernameNotOwned
+    )]
+    pub old_username_record: Account<'info, UsernameRecord>,
+    
+    /// New username claim, created here
+    #[account(
+        init,
+        payer = user,
+        space = UsernameRecord::LEN,
+        seeds = [
+            b"username",
+            new_username.as_bytes()
+        ],
+        bump
+    )]
+    pub new_username_record: Account<'info, UsernameRecord>,
+    
+    /// Profile owner
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `add_to_watchlist`
+#[derive(Accounts)]
+pub struct AddToWatchlist<'info> {
+    /// Watchlist PDA, created on the user's first follow
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Watchlist::LEN,
+        seeds = [
+            b"watchlist",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub watchlist: Account<'info, Watchlist>,
+    
+    /// CHECK: Market being followed; only its owner is checked
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::InvalidMarketAccount
+    )]
+    pub market: UncheckedAccount<'info>,
+    
+    /// Watchlist owner, pays for the account on first use
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `remove_from_watchlist`
+#[derive(Accounts)]
+pub struct RemoveFromWatchlist<'info> {
+    /// Watchlist being updated
+    #[account(
+        mut,
+        seeds = [
+            b"watchlist",
+            user.key().as_ref()
+        ],
+        bump = watchlist.bump,
+        has_one = user
+    )]
+    pub watchlist: Account<'info, Watchlist>,
+    
+    /// Watchlist owner
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `initialize_platform_stats`
+#[derive(Accounts)]
+pub struct InitializePlatformStats<'info> {
+    /// Platform stats PDA, created here
+    #[account(
+        init,
+        payer = payer,
+        space = PlatformStats::LEN,
+        seeds = [b"platform_stats"],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Pays for the stats account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `initialize_platform_activity`
+#[derive(Accounts)]
+pub struct InitializePlatformActivity<'info> {
+    /// Factory whose activity is counted
+    pub factory: Account<'info, Factory>,
+    
+    /// Activity PDA, created here
+    #[account(
+        init,
+        payer = payer,
+        space = PlatformActivity::LEN,
+        seeds = [b"platform_activity", factory.key().as_ref()],
+        bump
+    )]
+    pub platform_activity: Account<'info, PlatformActivity>,
+    
+    /// Pays for the activity account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `emit_daily_rollup`
+#[derive(Accounts)]
+#[instruction(day: u32)]
+pub struct EmitDailyRollup<'info> {
+    /// Activity being rolled up
+    #[account(
+        mut,
+        seeds = [b"platform_activity", platform_activity.factory.as_ref()],
+        bump = platform_activity.bump
+    )]
+    pub platform_activity: Account<'info, PlatformActivity>,
+    
+    /// Rollup PDA for the factory and day, created here
+    #[account(
+        init,
+        payer = payer,
+        space = DailyRollupSnapshot::LEN,
+        seeds = [
+            b"daily_rollup".as_ref(),
+            platform_activity.factory.as_ref(),
+            &day.to_le_bytes()
+        ],
+        bump
+    )]
+    pub daily_rollup: Account<'info, DailyRollupSnapshot>,
+    
+    /// Anyone can crank rollups and pays for the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `record_market_activity`
+#[derive(Accounts)]
+pub struct RecordMarketActivity<'info> {
+    /// Platform stats being updated
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, updated if initialized;
+    /// the market program derives the address
+    #[account(mut)]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Accounts for `record_creator_activity`
+#[derive(Accounts)]
+pub struct RecordCreatorActivity<'info> {
+    /// Creator stats PDA, created on the creator's first market
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorStats::LEN,
+        seeds = [
+            b"creator_stats",
+            creator.key().as_ref()
+        ],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
+    /// CHECK: Creator the stats belong to, vouched for by the signing market
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Pays for the stats account on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `accrue_creator_earnings`
+#[derive(Accounts)]
+pub struct AccrueCreatorEarnings<'info> {
+    /// Earnings ledger PDA, created on the creator's first fee
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorEarnings::LEN,
+        seeds = [
+            b"creator_earnings",
+            creator.key().as_ref()
+        ],
+        bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+    
+    /// CHECK: Creator the earnings belong to, vouched for by the signing market
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Pays for the ledger on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `record_pending_claim`
+#[derive(Accounts)]
+pub struct RecordPendingClaim<'info> {
+    /// Pending claims PDA, created on the user's first recorded win
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingClaims::LEN,
+        seeds = [
+            b"pending_claims",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub pending_claims: Account<'info, PendingClaims>,
+    
+    /// CHECK: Winning user, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Pays for the account on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `clear_pending_claim`
+#[derive(Accounts)]
+pub struct ClearPendingClaim<'info> {
+    /// Pending claims being pruned
+    #[account(
+        mut,
+        seeds = [
+            b"pending_claims",
+            user.key().as_ref()
+        ],
+        bump = pending_claims.bump,
+        has_one = user
+    )]
+    pub pending_claims: Account<'info, PendingClaims>,
+    
+    /// CHECK: User who withdrew, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Accounts for `dismiss_pending_claim`
+#[derive(Accounts)]
+pub struct DismissPendingClaim<'info> {
+    /// Pending claims being pruned
+    #[account(
+        mut,
+        seeds = [
+            b"pending_claims",
+            user.key().as_ref()
+        ],
+        bump = pending_claims.bump,
+        has_one = user
+    )]
+    pub pending_claims: Account<'info, PendingClaims>,
+    
+    /// Pending claims owner
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `create_rebate_tracker`
+#[derive(Accounts)]
+pub struct CreateRebateTracker<'info> {
+    /// Rebate tracker PDA, created here
+    #[account(
+        init,
+        payer = user,
+        space = RebateTracker::LEN,
+        seeds = [
+            b"rebate_tracker",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub rebate_tracker: Account<'info, RebateTracker>,
+    
+    /// User opting in, paying for the account
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `reset_rebate_tracker`
+#[derive(Accounts)]
+pub struct ResetRebateTracker<'info> {
+    /// Rebate tracker being cleared
+    #[account(
+        mut,
+        seeds = [
+            b"rebate_tracker",
+            user.key().as_ref()
+        ],
+        bump = rebate_tracker.bump,
+        has_one = user
+    )]
+    pub rebate_tracker: Account<'info, RebateTracker>,
+    
+    /// Rebate tracker owner
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `track_rebate_entry`
+#[derive(Accounts)]
+pub struct TrackRebateEntry<'info> {
+    /// Rebate tracker receiving the entry
+    #[account(
+        mut,
+        seeds = [
+            b"rebate_tracker",
+            user.key().as_ref()
+        ],
+        bump = rebate_tracker.bump,
+        has_one = user
+    )]
+    pub rebate_tracker: Account<'info, RebateTracker>,
+    
+    /// CHECK: User who joined, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Accounts for `settle_rebate_entry`
+#[derive(Accounts)]
+pub struct SettleRebateEntry<'info> {
+    /// Rebate tracker holding the entry
+    #[account(
+        mut,
+        seeds = [
+            b"rebate_tracker",
+            user.key().as_ref()
+        ],
+        bump = rebate_tracker.bump,
+        has_one = user
+    )]
+    pub rebate_tracker: Account<'info, RebateTracker>,
+    
+    /// CHECK: User whose entry settled, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Accounts for `credit_points`
+#[derive(Accounts)]
+pub struct CreditPoints<'info> {
+    /// Points ledger PDA, created on the user's first credit
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PointsLedger::LEN,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub points_ledger: Account<'info, PointsLedger>,
+    
+    /// Platform points totals PDA, created on the first credit
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PointsTotals::LEN,
+        seeds = [b"points_totals"],
+        bump
+    )]
+    pub points_totals: Account<'info, PointsTotals>,
+    
+    /// CHECK: User earning the points, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ DashboardError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ DashboardError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Pays for the points accounts on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `snapshot_points`
+#[derive(Accounts)]
+#[instruction(epoch: u32)]
+pub struct SnapshotPoints<'info> {
+    /// Ledger being snapshotted
+    #[account(
+        seeds = [
+            b"points_ledger",
+            points_ledger.user.as_ref()
+        ],
+        bump = points_ledger.bump
+    )]
+    pub points_ledger: Account<'info, PointsLedger>,
+    
+    /// Snapshot PDA for the epoch, created here
+    #[account(
+        init,
+        payer = payer,
+        space = PointsSnapshot::LEN,
+        seeds = [
+            b"points_snapshot",
+            points_ledger.user.as_ref(),
+            &epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub points_snapshot: Account<'info, PointsSnapshot>,
+    
+    /// Anyone can crank snapshots and pays for the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `snapshot_points_total`
+#[derive(Accounts)]
+#[instruction(epoch: u32)]
+pub struct SnapshotPointsTotal<'info> {
+    /// Totals being snapshotted
+    #[account(
+        seeds = [b"points_totals"],
+        bump = points_totals.bump
+    )]
+    pub points_totals: Account<'info, PointsTotals>,
+    
+    /// Snapshot PDA for the epoch, created here
+    #[account(
+        init,
+        payer = payer,
+        space = PointsTotalSnapshot::LEN,
+        seeds = [
+            b"points_total_snapshot".as_ref(),
+            &epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub points_total_snapshot: Account<'info, PointsTotalSnapshot>,
+    
+    /// Anyone can crank snapshots and pays for the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `initialize_reputation_mint`
+#[derive(Accounts)]
+pub struct InitializeReputationMint<'info> {
+    /// CHECK: Reputation mint PDA, created and initialized here by Token-2022
+    #[account(
+        mut,
+        seeds = [b"reputation_mint"],
+        bump
+    )]
+    pub reputation_mint: UncheckedAccount<'info>,
+    
+    /// Pays for the mint
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Token-2022 program
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `sync_reputation`
+#[derive(Accounts)]
+pub struct SyncReputation<'info> {
+    /// Stats the reputation balance is derived from
+    #[account(
+        seeds = [
+            b"user_stats",
+            user.key().as_ref()
+        ],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// CHECK: Reputation mint PDA, owned by Token-2022 once initialized
+    #[account(
+        mut,
+        seeds = [b"reputation_mint"],
+        bump,
+        owner = TOKEN_2022_PROGRAM_ID @ DashboardError::InvalidReputationAccount
+    )]
+    pub reputation_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's associated token account for the reputation mint,
+    /// created here if missing
+    #[account(
+        mut,
+        address = find_reputation_account_address(&user.key(), &reputation_mint.key()).0
+            @ DashboardError::InvalidReputationAccount
+    )]
+    pub reputation_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Only keys the stats and token account
+    pub user: UncheckedAccount<'info>,
+    
+    /// Anyone can crank a sync and pays for a new token account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Token-2022 program
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// CHECK: Associated token account program
+    #[account(address = ASSOCIATED_TOKEN_PROGRAM_ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `claim_creator_earnings`
+#[derive(Accounts)]
+pub struct ClaimCreatorEarnings<'info> {
+    /// Earnings ledger being paid out
+    #[account(
+        mut,
+        seeds = [
+            b"creator_earnings",
+            creator.key().as_ref()
+        ],
+        bump = creator_earnings.bump,
+        has_one = creator
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+    
+    /// Creator receiving the fees
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+// Events
+
+/// Emitted when a creator earns new badges
+#[event]
+pub struct CreatorBadgesEarned {
+    /// Creator's wallet address
+    #[index]
+    pub creator: Pubkey,
+    /// Bitmask of the badges just earned
+    pub new_badges: u8,
+    /// Bitmask of all badges held
+    pub badges: u8,
+}
+
+/// Emitted when a resolved market credits its creator fee to the creator's ledger
+#[event]
+pub struct CreatorEarningsAccrued {
+    /// Creator's wallet address
+    #[index]
+    pub creator: Pubkey,
+    /// Market the fee came from
+    pub market: Pubkey,
+    /// Fee credited in lamports
+    pub amount: u64,
+    /// Lifetime fees credited in lamports
+    pub total_accrued: u64,
+}
+
+/// Emitted when a creator claims their accrued fees
+#[event]
+pub struct CreatorEarningsClaimed {
+    /// Creator's wallet address
+    #[index]
+    pub creator: Pubkey,
+    /// Lamports paid out
+    pub amount: u64,
+    /// Lifetime fees paid out in lamports
+    pub total_claimed: u64,
+}
+
+/// Emitted when the market program credits points to a user
+#[event]
+pub struct PointsCredited {
+    /// User's wallet address
+    #[index]
+    pub user: Pubkey,
+    /// Epoch the points count toward
+    pub epoch: u32,
+    /// Activity that earned them
+    pub activity: PointsActivity,
+    /// Points credited
+    pub points: u64,
+    /// User's points in the epoch after this credit
+    pub epoch_points: u64,
+}
+
+/// Emitted when a streak freeze absorbs a loss
+#[event]
+pub struct StreakFreezeConsumed {
+    /// User's wallet address
+    #[index]
+    pub user: Pubkey,
+    /// Win streak kept by the freeze
+    pub protected_streak: i32,
+}
+
+/// Emitted when a user reaches a new level
+#[event]
+pub struct LevelUp {
+    /// User's wallet address
+    #[index]
+    pub user: Pubkey,
+    /// Level before this update
+    pub previous_level: u16,
+    /// Level after this update
+    pub new_level: u16,
+    /// Total XP after this update
+    pub xp: u64,
+}
+
+/// Emitted when a profile is created or changed
+#[event]
+pub struct ProfileUpdated {
+    /// Profile owner
+    #[index]
+    pub user: Pubkey,
+    /// Current username
+    pub username: String,
+    /// Current avatar content hash
+    pub avatar_hash: [u8; 32],
+}
+
+/// Emitted when a user's reputation tokens are minted or burned
+#[event]
+pub struct ReputationSynced {
+    /// User's wallet address
+    #[index]
+    pub user: Pubkey,
+    /// Reputation balance before the sync
+    pub previous_balance: u64,
+    /// Reputation balance after the sync
+    pub balance: u64,
+}
+
+/// Emitted when a factory's day of activity is rolled up
+#[event]
+pub struct DailyRollup {
+    /// Factory whose markets were counted
+    #[index]
+    pub factory: Pubkey,
+    /// Day number since the unix epoch
+    pub day: u32,
+    /// Entry fees paid in lamports
+    pub volume: u64,
+    /// Market joins
+    pub joins: u64,
+    /// Estimated first-time bettors
+    pub new_users: u64,
+    /// Markets created
+    pub markets_created: u64,
+    /// Markets resolved
+    pub markets_resolved: u64,
+    /// Creator and platform fees collected in lamports
+    pub fees: u64,
+}
 
 // Error Codes
 
@@ -294,4 +3617,76 @@ pub enum DashboardError {
     InvalidPageSize,
     #[msg("Invalid sort option")]
     InvalidSortOption,
+    #[msg("League code must be 1-8 characters")]
+    InvalidLeagueCode,
+    #[msg("Maximum number of tracked leagues reached")]
+    LeagueLimitReached,
+    #[msg("Head-to-head users must be passed in ascending pubkey order")]
+    UnsortedHeadToHeadPair,
+    #[msg("Signer is not part of this head-to-head pair")]
+    NotHeadToHeadMember,
+    #[msg("Market already recorded for this head-to-head pair")]
+    DuplicateHeadToHeadMarket,
+    #[msg("Username must be 3-32 characters")]
+    InvalidUsernameLength,
+    #[msg("Username may only contain lowercase letters, digits and underscores")]
+    InvalidUsernameCharacters,
+    #[msg("Username is not owned by this user")]
+    UsernameNotOwned,
+    #[msg("Pick share cannot exceed 100% (10000 bps)")]
+    InvalidPickShare,
+    #[msg("No active win streak to protect")]
+    NoActiveStreak,
+    #[msg("A streak freeze is already active")]
+    StreakFreezeAlreadyActive,
+    #[msg("Not enough unspent XP")]
+    InsufficientXp,
+    #[msg("Snapshots can only be taken for the current week")]
+    InvalidSnapshotWeek,
+    #[msg("Caller is not authorized to record market activity")]
+    UnauthorizedCaller,
+    #[msg("Account is not a valid user stats account")]
+    InvalidStatsAccount,
+    #[msg("User stats account is already on the current layout")]
+    AlreadyMigrated,
+    #[msg("Decay window must be greater than zero")]
+    InvalidDecayWindow,
+    #[msg("User has not settled enough qualifying markets for leaderboards")]
+    NotQualifiedForLeaderboard,
+    #[msg("Account is not a market")]
+    InvalidMarketAccount,
+    #[msg("Market is already on the watchlist")]
+    AlreadyWatching,
+    #[msg("Watchlist is full")]
+    WatchlistFull,
+    #[msg("Market is not on the watchlist")]
+    NotWatching,
+    #[msg("No creator earnings to claim")]
+    NothingToClaim,
+    #[msg("Creator earnings account holds less than it owes")]
+    EarningsShortfall,
+    #[msg("Market is already in the pending claims")]
+    ClaimAlreadyPending,
+    #[msg("Pending claims are full")]
+    PendingClaimsFull,
+    #[msg("Market is not in the pending claims")]
+    ClaimNotPending,
+    #[msg("No losing-streak rebate is due")]
+    RebateNotDue,
+    #[msg("Market is not an open entry in the rebate tracker")]
+    EntryNotTracked,
+    #[msg("Points epoch has not finished")]
+    PointsEpochNotFinished,
+    #[msg("Points epoch is no longer held by the ledger")]
+    PointsEpochUnavailable,
+    #[msg("Reputation mint or token account is invalid")]
+    InvalidReputationAccount,
+    #[msg("No closed day of activity to roll up for this day")]
+    RollupUnavailable,
+    #[msg("Market has not resolved or been cancelled")]
+    MarketNotSettled,
+    #[msg("Market has not resolved")]
+    MarketNotResolved,
+    #[msg("Market result can still be corrected")]
+    CorrectionWindowOpen,
 }
\ No newline at end of file
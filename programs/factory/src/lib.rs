@@ -1,7 +1,26 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::Discriminator;
+use cryptoscore_common::introspection::invoked_by;
+use cryptoscore_common::match_id::{decode_charset, encode_charset, uses_charset, Charset, DEFAULT_MATCH_ID_CHARSET};
+use cryptoscore_common::pda::{
+    find_reputation_mint_address, kickoff_day, match_id_seed, CREATION_RATE_POLICY_SEED, CREATOR_RATE_SEED,
+    KICKOFF_BUCKET_SEED, MARKET_REGISTRY_SEED, TAG_SEED,
+};
+use cryptoscore_common::rate_limit::SlidingWindow;
+use cryptoscore_common::receipt::MAX_RECEIPT_URI_BASE_LEN;
+use cryptoscore_common::tags::{
+    decode_tag, is_valid_tag, Tag, TagCategory, Tags, NO_TAG, TAGS_SPACE, TAG_LEN, TAG_SLOTS,
+};
+use cryptoscore_common::fees::{insurance_share, market_fees, settled_reward_per_winner, BPS_DENOMINATOR};
+use cryptoscore_common::{
+    FinalScore, MatchOutcome, MatchResult, OutcomeSet, DISCRIMINATOR_LEN, MARKET_PROGRAM_ID, MATCH_ID_SPACE,
+    MATCH_OUTCOME_SPACE, MATCH_RESULT_SPACE, MAX_BATCH_RESULTS, MAX_DISCOUNT_TIERS, MAX_MATCH_ID_LEN,
+    MAX_PAYOUT_SWAP_MINTS, MAX_PLATFORM_ID_LEN, MAX_RESOLUTION_GRACE_SECONDS, ORPHANED_REGISTRY_GRACE_SECONDS,
+};
+use cryptoscore_market_interface::{Market, Participant};
 
 declare_id!("5zADKCecxATSEsCuH5MJa1JdfXGeBLNwEYnkCbqdaYmZ");
-
 #[program]
 pub mod cryptoscore_factory {
     use super::*;
@@ -16,6 +35,8 @@ pub mod cryptoscore_factory {
         factory.authority = ctx.accounts.authority.key();
         factory.market_count = 0;
         factory.platform_fee_bps = platform_fee_bps;
+        factory.min_leaderboard_markets = 0;
+        factory.min_leaderboard_pool = 0;
         factory.bump = ctx.bumps.factory;
         
         msg!("Factory initialized with authority: {}, platform fee: {} bps", 
@@ -24,34 +45,554 @@ pub mod cryptoscore_factory {
         Ok(())
     }
 
+    /// Register an operator's own branded platform, with a factory of its own
+    ///
+    /// The platform factory works like the default one, with the operator as
+    /// its authority: its treasury, fee, results signer, Wormhole emitter and
+    /// policies are all seeded by its address, as are the markets created
+    /// under it, so operators share the deployed programs but nothing else.
+    pub fn create_platform(ctx: Context<CreatePlatform>, platform_id: String, platform_fee_bps: u16) -> Result<()> {
+        require!(
+            !platform_id.is_empty() && platform_id.len() <= MAX_PLATFORM_ID_LEN,
+            FactoryError::InvalidPlatformId
+        );
+        require!(platform_fee_bps <= 1000, FactoryError::InvalidPlatformFee);
+        
+        let factory = &mut ctx.accounts.factory;
+        factory.authority = ctx.accounts.operator.key();
+        factory.market_count = 0;
+        factory.platform_fee_bps = platform_fee_bps;
+        factory.min_leaderboard_markets = 0;
+        factory.min_leaderboard_pool = 0;
+        factory.bump = ctx.bumps.factory;
+        
+        let platform = &mut ctx.accounts.platform;
+        platform.operator = ctx.accounts.operator.key();
+        platform.factory = factory.key();
+        platform.platform_id = platform_id;
+        platform.created_at = Clock::get()?.unix_timestamp;
+        platform.bump = ctx.bumps.platform;
+        
+        msg!("Platform {} created with factory {}, platform fee: {} bps",
+            platform.platform_id, platform.factory, platform_fee_bps);
+        
+        Ok(())
+    }
+
+    /// Update the minimum qualification rules for leaderboard inclusion
+    pub fn update_leaderboard_qualification(
+        ctx: Context<UpdateFactoryConfig>,
+        min_leaderboard_markets: u32,
+        min_leaderboard_pool: u64,
+    ) -> Result<()> {
+        let factory = &mut ctx.accounts.factory;
+        
+        factory.min_leaderboard_markets = min_leaderboard_markets;
+        factory.min_leaderboard_pool = min_leaderboard_pool;
+        
+        msg!("Leaderboard qualification updated: {} markets, {} lamports min pool", 
+            min_leaderboard_markets, min_leaderboard_pool);
+        
+        Ok(())
+    }
+
+    /// Designate the key whose signed results can resolve markets without an oracle
+    ///
+    /// Calling it again rotates the key; results it signed earlier stop
+    /// verifying as soon as it is replaced.
+    pub fn set_results_signer(ctx: Context<SetResultsSigner>, signer: Pubkey) -> Result<()> {
+        let results_signer = &mut ctx.accounts.results_signer;
+        
+        results_signer.factory = ctx.accounts.factory.key();
+        results_signer.signer = signer;
+        results_signer.bump = ctx.bumps.results_signer;
+        
+        msg!("Results signer set to {}", signer);
+        
+        Ok(())
+    }
+
+    /// Register the source-chain oracle whose Wormhole messages can resolve
+    /// markets, e.g. a sports oracle contract on an EVM chain
+    ///
+    /// Only VAAs posted by `core_bridge` and emitted by `emitter_address` on
+    /// `emitter_chain` are accepted. Calling it again replaces the emitter.
+    pub fn set_wormhole_emitter(
+        ctx: Context<SetWormholeEmitter>,
+        core_bridge: Pubkey,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            emitter_chain != 0 && emitter_address != [0; 32],
+            FactoryError::InvalidWormholeEmitter
+        );
+        
+        let wormhole_emitter = &mut ctx.accounts.wormhole_emitter;
+        
+        wormhole_emitter.factory = ctx.accounts.factory.key();
+        wormhole_emitter.core_bridge = core_bridge;
+        wormhole_emitter.emitter_chain = emitter_chain;
+        wormhole_emitter.emitter_address = emitter_address;
+        wormhole_emitter.bump = ctx.bumps.wormhole_emitter;
+        
+        msg!("Wormhole emitter set to chain {}", emitter_chain);
+        
+        Ok(())
+    }
+
+    /// Publish final results for several matches at once as the results
+    /// signer, e.g. a whole matchweek, for `resolve_markets_batch` to consume
+    pub fn publish_results(ctx: Context<PublishResults>, batch_id: u64, results: Vec<MatchResult>) -> Result<()> {
+        require!(
+            !results.is_empty() && results.len() <= MAX_BATCH_RESULTS,
+            FactoryError::InvalidResultsBatch
+        );
+        for (i, result) in results.iter().enumerate() {
+            require!(!result.match_id.is_empty(), FactoryError::InvalidMatchId);
+            require!(result.match_id.len() <= MAX_MATCH_ID_LEN, FactoryError::MatchIdTooLong);
+            require!(
+                results[..i].iter().all(|earlier| earlier.match_id != result.match_id),
+                FactoryError::InvalidResultsBatch
+            );
+        }
+        
+        let results_batch = &mut ctx.accounts.results_batch;
+        results_batch.factory = ctx.accounts.factory.key();
+        results_batch.batch_id = batch_id;
+        results_batch.signer = ctx.accounts.signer.key();
+        results_batch.published_at = Clock::get()?.unix_timestamp;
+        results_batch.results = results;
+        results_batch.bump = ctx.bumps.results_batch;
+        
+        emit!(ResultsPublished {
+            results_batch: results_batch.key(),
+            batch_id,
+            result_count: results_batch.results.len() as u8,
+        });
+        
+        msg!("Published {} results in batch {}", results_batch.results.len(), batch_id);
+        
+        Ok(())
+    }
+
+    /// Report the score of `match_id` as the results signer while it's being
+    /// played, for frontends to show and, once `is_final`, for
+    /// `resolve_with_live_score` to resolve its market from
+    ///
+    /// The first update creates the match's live score; a final one can't be
+    /// updated again.
+    pub fn update_live_score(
+        ctx: Context<UpdateLiveScore>,
+        match_id: String,
+        home_goals: u8,
+        away_goals: u8,
+        minute: u16,
+        is_final: bool,
+    ) -> Result<()> {
+        require!(!match_id.is_empty(), FactoryError::InvalidMatchId);
+        require!(match_id.len() <= MAX_MATCH_ID_LEN, FactoryError::MatchIdTooLong);
+        
+        let live_score = &mut ctx.accounts.live_score;
+        require!(!live_score.is_final, FactoryError::LiveScoreFinal);
+        
+        live_score.factory = ctx.accounts.factory.key();
+        live_score.match_id = match_id;
+        live_score.home_goals = home_goals;
+        live_score.away_goals = away_goals;
+        live_score.minute = minute;
+        live_score.is_final = is_final;
+        live_score.signer = ctx.accounts.signer.key();
+        live_score.updated_at = Clock::get()?.unix_timestamp;
+        live_score.bump = ctx.bumps.live_score;
+        
+        emit!(LiveScoreUpdated {
+            live_score: live_score.key(),
+            match_id: live_score.match_id.clone(),
+            home_goals,
+            away_goals,
+            minute,
+            is_final,
+        });
+        
+        msg!("{} {}-{} ({}')", live_score.match_id, home_goals, away_goals, minute);
+        
+        Ok(())
+    }
+
+    /// Require markets whose pool reaches `high_stakes_pool` lamports to be
+    /// resolved by two of their creator, the results signer and `arbiter`
+    ///
+    /// A threshold of zero turns co-signed resolution off. Calling it again
+    /// updates the threshold and rotates the arbiter.
+    pub fn set_resolution_policy(
+        ctx: Context<SetResolutionPolicy>,
+        high_stakes_pool: u64,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        let policy = &mut ctx.accounts.resolution_policy;
+        
+        policy.factory = ctx.accounts.factory.key();
+        policy.high_stakes_pool = high_stakes_pool;
+        policy.arbiter = arbiter;
+        policy.bump = ctx.bumps.resolution_policy;
+        
+        msg!("Resolution policy set: co-sign pools of {} lamports or more, arbiter {}", high_stakes_pool, arbiter);
+        
+        Ok(())
+    }
+
+    /// Rebate `rebate_bps` of the entry fee on a user's next entry after
+    /// `loss_streak` consecutive settled losses, paying at most `epoch_cap`
+    /// lamports of rebates from the treasury per epoch
+    ///
+    /// A streak length of zero turns rebates off. Calling it again updates
+    /// the terms and keeps the current epoch's spending.
+    pub fn set_rebate_policy(
+        ctx: Context<SetRebatePolicy>,
+        loss_streak: u16,
+        rebate_bps: u16,
+        epoch_cap: u64,
+    ) -> Result<()> {
+        require_gte!(BPS_DENOMINATOR, rebate_bps as u64, FactoryError::InvalidRebatePolicy);
+        
+        let policy = &mut ctx.accounts.rebate_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.rebate_policy;
+        }
+        
+        policy.loss_streak = loss_streak;
+        policy.rebate_bps = rebate_bps;
+        policy.epoch_cap = epoch_cap;
+        
+        msg!("Rebate policy set: {} bps after {} losses, {} lamports per epoch", rebate_bps, loss_streak, epoch_cap);
+        
+        Ok(())
+    }
+
+    /// Discount direct entries of users whose verified record earns reputation
+    /// or who hold creator badges by their best qualifying tier, paid back from the treasury up
+    /// to `epoch_cap` lamports per epoch
+    ///
+    /// No tiers turns discounts off. Calling it again replaces the tiers and
+    /// keeps the current epoch's spending.
+    pub fn set_discount_policy(
+        ctx: Context<SetDiscountPolicy>,
+        tiers: Vec<DiscountTier>,
+        epoch_cap: u64,
+    ) -> Result<()> {
+        require_gte!(MAX_DISCOUNT_TIERS, tiers.len(), FactoryError::InvalidDiscountPolicy);
+        require!(tiers.iter().all(DiscountTier::is_valid), FactoryError::InvalidDiscountPolicy);
+        
+        let policy = &mut ctx.accounts.discount_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.reputation_mint = find_reputation_mint_address().0;
+            policy.bump = ctx.bumps.discount_policy;
+        }
+        
+        policy.tiers = tiers;
+        policy.epoch_cap = epoch_cap;
+        
+        msg!("Discount policy set: {} tiers, {} lamports per epoch", policy.tiers.len(), epoch_cap);
+        
+        Ok(())
+    }
+
+    /// Tip whoever runs a market's permissionless cranks `tip` lamports from
+    /// the treasury, paying at most `market_budget` lamports of tips per market
+    ///
+    /// A market's tips are also capped by the platform fee its pool owes, so
+    /// markets nobody joins can't be farmed for tips. A zero tip turns tips off.
+    pub fn set_keeper_tip_policy(ctx: Context<SetKeeperTipPolicy>, tip: u64, market_budget: u64) -> Result<()> {
+        require_gte!(market_budget, tip, FactoryError::InvalidKeeperTipPolicy);
+        
+        let policy = &mut ctx.accounts.keeper_tip_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.keeper_tip_policy;
+        }
+        
+        policy.tip = tip;
+        policy.market_budget = market_budget;
+        
+        msg!("Keeper tip policy set: {} lamports per crank, {} per market", tip, market_budget);
+        
+        Ok(())
+    }
+
+    /// Let winners take their payouts in any of `mints`, e.g. USDC, swapped
+    /// through Jupiter when they claim
+    ///
+    /// No mints turns swapped payouts off. Calling it again replaces the mints.
+    pub fn set_payout_swap_policy(ctx: Context<SetPayoutSwapPolicy>, mints: Vec<Pubkey>) -> Result<()> {
+        require_gte!(MAX_PAYOUT_SWAP_MINTS, mints.len(), FactoryError::InvalidPayoutSwapPolicy);
+        require!(!mints.contains(&Pubkey::default()), FactoryError::InvalidPayoutSwapPolicy);
+        
+        let policy = &mut ctx.accounts.payout_swap_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.payout_swap_policy;
+        }
+        
+        policy.mints = mints;
+        
+        msg!("Payout swap policy set: {} mints", policy.mints.len());
+        
+        Ok(())
+    }
+
+    /// Let users mint a compressed NFT receipt of each claim, recording its
+    /// market, prediction, stake and payout, into `merkle_tree`
+    ///
+    /// The tree is a Bubblegum tree whose delegate is the market program's
+    /// receipt authority PDA. Receipt URIs start with `uri_base`. The default
+    /// pubkey turns receipts off.
+    pub fn set_receipt_policy(ctx: Context<SetReceiptPolicy>, merkle_tree: Pubkey, uri_base: String) -> Result<()> {
+        require_gte!(MAX_RECEIPT_URI_BASE_LEN, uri_base.len(), FactoryError::InvalidReceiptPolicy);
+        
+        let policy = &mut ctx.accounts.receipt_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.receipt_policy;
+        }
+        
+        policy.merkle_tree = merkle_tree;
+        policy.uri_base = uri_base;
+        
+        msg!("Receipt policy set: tree {}", merkle_tree);
+        
+        Ok(())
+    }
+
+    /// Bound the grace period, after a market ends, during which only its
+    /// creator can resolve it before participants, oracles and keepers may
+    ///
+    /// Creators pick each market's grace period within
+    /// `min_grace_seconds..=max_grace_seconds` when they create it; without a
+    /// policy any period up to `MAX_RESOLUTION_GRACE_SECONDS` is allowed.
+    pub fn set_resolution_grace_policy(
+        ctx: Context<SetResolutionGracePolicy>,
+        min_grace_seconds: i64,
+        max_grace_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            0 <= min_grace_seconds
+                && min_grace_seconds <= max_grace_seconds
+                && max_grace_seconds <= MAX_RESOLUTION_GRACE_SECONDS,
+            FactoryError::InvalidResolutionGracePolicy
+        );
+        
+        let policy = &mut ctx.accounts.resolution_grace_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.resolution_grace_policy;
+        }
+        
+        policy.min_grace_seconds = min_grace_seconds;
+        policy.max_grace_seconds = max_grace_seconds;
+        
+        msg!("Resolution grace policy set: {}s to {}s", min_grace_seconds, max_grace_seconds);
+        
+        Ok(())
+    }
+
+    /// Set the characters the factory's match IDs may contain
+    ///
+    /// `allowed_characters` lists them, printable ASCII other than space;
+    /// without a policy only uppercase letters, digits and `-` are allowed.
+    /// Markets already listed keep their IDs.
+    pub fn set_match_id_policy(ctx: Context<SetMatchIdPolicy>, allowed_characters: String) -> Result<()> {
+        let charset = encode_charset(&allowed_characters).ok_or(FactoryError::InvalidMatchIdPolicy)?;
+        
+        let policy = &mut ctx.accounts.match_id_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.match_id_policy;
+        }
+        
+        policy.charset = charset;
+        
+        msg!("Match ID policy set: {}", decode_charset(charset));
+        
+        Ok(())
+    }
+
+    /// Let each wallet create at most `max_markets` markets per sliding
+    /// window of `window_seconds`, to keep spam out of the registry
+    ///
+    /// Zero markets turns the limit off. The factory authority is never
+    /// limited, and changing the window starts every creator's count afresh.
+    pub fn set_creation_rate_policy(
+        ctx: Context<SetCreationRatePolicy>,
+        max_markets: u32,
+        window_seconds: i64,
+    ) -> Result<()> {
+        require!(window_seconds > 0, FactoryError::InvalidCreationRatePolicy);
+        
+        let policy = &mut ctx.accounts.creation_rate_policy;
+        
+        // Initialize if first time
+        if policy.factory == Pubkey::default() {
+            policy.factory = ctx.accounts.factory.key();
+            policy.bump = ctx.bumps.creation_rate_policy;
+        }
+        
+        policy.max_markets = max_markets;
+        policy.window_seconds = window_seconds;
+        
+        msg!("Creation rate policy set: {} markets per {} seconds", max_markets, window_seconds);
+        
+        Ok(())
+    }
+
+    /// Waive the creator and platform fees on `market`, e.g. for community
+    /// and promotional pools, or charge them again
+    ///
+    /// A market can be made rake-free any time before it resolves, but only
+    /// goes back to charging fees while nobody has joined, since its entrants
+    /// joined expecting the whole pool.
+    pub fn set_rake_free(ctx: Context<SetRakeFree>, rake_free: bool) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(!market.status.is_resolved(), FactoryError::MarketAlreadyResolved);
+        
+        let entry = &mut ctx.accounts.rake_free_market;
+        require!(
+            rake_free || !entry.rake_free || market.participant_count == 0,
+            FactoryError::RakeFreeLocked
+        );
+        
+        // Initialize if first time
+        if entry.market == Pubkey::default() {
+            entry.factory = ctx.accounts.factory.key();
+            entry.market = market.key();
+            entry.bump = ctx.bumps.rake_free_market;
+        }
+        
+        entry.rake_free = rake_free;
+        
+        msg!("Market {} rake-free: {}", entry.market, rake_free);
+        
+        Ok(())
+    }
+
+    /// Let `governor`, e.g. the governance program's PDA, change platform
+    /// parameters alongside the factory authority
+    ///
+    /// The default pubkey removes the governor. Creates the platform config
+    /// on first use, so it has to be called once before `set_platform_param`.
+    pub fn set_governor(ctx: Context<SetGovernor>, governor: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        
+        // Initialize if first time
+        if config.factory == Pubkey::default() {
+            config.factory = ctx.accounts.factory.key();
+            config.bump = ctx.bumps.platform_config;
+        }
+        
+        config.governor = governor;
+        
+        msg!("Governor set to {}", governor);
+        
+        Ok(())
+    }
+
+    /// Change one platform parameter as the factory authority or the governor
+    pub fn set_platform_param(ctx: Context<SetPlatformParam>, param: PlatformParam) -> Result<()> {
+        require!(param.is_valid(), FactoryError::InvalidPlatformParam);
+        
+        let accounts = ctx.accounts;
+        match param {
+            PlatformParam::PlatformFeeBps(bps) => accounts.factory.platform_fee_bps = bps,
+            PlatformParam::DisputeWindow(seconds) => accounts.platform_config.dispute_window = seconds,
+            PlatformParam::TreasuryRecipient(recipient) => accounts.platform_config.treasury_recipient = recipient,
+        }
+        
+        emit!(PlatformParamSet {
+            param,
+            caller: accounts.caller.key(),
+        });
+        
+        msg!("Platform parameter set: {:?}", param);
+        
+        Ok(())
+    }
+
+    /// Allow `tag` in `category`'s slot of new markets' registry entries
+    pub fn add_tag(ctx: Context<AddTag>, category: TagCategory, tag: Tag) -> Result<()> {
+        require!(is_valid_tag(&tag), FactoryError::InvalidTag);
+        
+        let definition = &mut ctx.accounts.tag_definition;
+        definition.factory = ctx.accounts.factory.key();
+        definition.category = category;
+        definition.tag = tag;
+        definition.bump = ctx.bumps.tag_definition;
+        
+        msg!("Tag added: {:?} {}", category, decode_tag(&tag).unwrap_or_default());
+        
+        Ok(())
+    }
+
+    /// Stop allowing a tag on new markets; existing registry entries keep it
+    pub fn remove_tag(ctx: Context<RemoveTag>) -> Result<()> {
+        let definition = &ctx.accounts.tag_definition;
+        msg!("Tag removed: {:?} {}", definition.category, decode_tag(&definition.tag).unwrap_or_default());
+        
+        Ok(())
+    }
+
     /// Create a new prediction market
-    pub fn create_market(
-        ctx: Context<CreateMarket>,
+    ///
+    /// Each set slot of `tags` must be a tag the factory registered for that
+    /// slot's category, with its `TagDefinition` passed as a remaining
+    /// account, in slot order.
+    pub fn create_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMarket<'info>>,
         match_id: String,
         entry_fee: u64,
         kickoff_time: i64,
         end_time: i64,
         is_public: bool,
+        tags: Tags,
     ) -> Result<()> {
         let factory = &mut ctx.accounts.factory;
         let market_registry = &mut ctx.accounts.market_registry;
         
-        // Validate match ID is not empty
-        require!(!match_id.is_empty(), FactoryError::InvalidMatchId);
-        require!(match_id.len() <= 64, FactoryError::MatchIdTooLong);
-        
-        // Validate entry fee is non-zero
-        require!(entry_fee > 0, FactoryError::ZeroEntryFee);
-        
-        // Validate times
         let current_time = Clock::get()?.unix_timestamp;
-        require!(kickoff_time > current_time, FactoryError::InvalidKickoffTime);
-        require!(end_time > kickoff_time, FactoryError::InvalidEndTime);
+        let charset = read_match_id_charset(&ctx.accounts.match_id_policy)?;
+        validate_listing(&match_id, charset, entry_fee, kickoff_time, end_time, current_time)?;
+        
+        require_registered_tags(&factory.key(), &tags, ctx.remaining_accounts)?;
+        track_creation_rate(
+            &mut ctx.accounts.creator_rate,
+            ctx.bumps.creator_rate,
+            &ctx.accounts.creation_rate_policy,
+            factory,
+            &ctx.accounts.creator.key(),
+            current_time,
+            1,
+        )?;
         
         // Initialize market registry
         market_registry.factory = factory.key();
         market_registry.market_address = ctx.accounts.market_account.key();
         market_registry.creator = ctx.accounts.creator.key();
+        market_registry.tags = tags;
         market_registry.match_id = match_id.clone();
         market_registry.created_at = current_time;
         market_registry.is_public = is_public;
@@ -60,6 +601,16 @@ pub mod cryptoscore_factory {
         market_registry.end_time = end_time;
         market_registry.bump = ctx.bumps.market_registry;
         
+        // List the market under its kickoff day, opening the day's bucket if first
+        let kickoff_bucket = &mut ctx.accounts.kickoff_bucket;
+        if kickoff_bucket.factory == Pubkey::default() {
+            kickoff_bucket.factory = factory.key();
+            kickoff_bucket.day = kickoff_day(kickoff_time);
+            kickoff_bucket.bump = ctx.bumps.kickoff_bucket;
+        }
+        require!(kickoff_bucket.markets.len() < KickoffBucket::MAX_MARKETS, FactoryError::KickoffBucketFull);
+        kickoff_bucket.markets.push(ctx.accounts.market_account.key());
+        
         // Increment market count
         factory.market_count = factory.market_count.checked_add(1)
             .ok_or(FactoryError::MarketCountOverflow)?;
@@ -80,171 +631,3444 @@ pub mod cryptoscore_factory {
         Ok(())
     }
 
-    /// Get paginated list of markets with filtering
-    pub fn get_markets(
-        _ctx: Context<GetMarkets>,
-        filter_creator: Option<Pubkey>,
-        filter_public: Option<bool>,
-        page: u32,
-        page_size: u32,
-    ) -> Result<Vec<MarketInfo>> {
-        // This is a view function that would be called off-chain
-        // In practice, clients would fetch market registry accounts directly
-        // This is here for documentation purposes
+    /// Register and initialize a market for every fixture in one call
+    ///
+    /// Fixtures share the entry fee, visibility, outcome set, resolution grace
+    /// period and tags.
+    /// Remaining accounts are the `TagDefinition`s as in `create_market`,
+    /// then each fixture's registry entry, market PDA and kickoff bucket, in
+    /// fixture order.
+    pub fn create_market_series<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMarketSeries<'info>>,
+        fixtures: Vec<SeriesFixture>,
+        entry_fee: u64,
+        is_public: bool,
+        outcome_set: OutcomeSet,
+        resolution_grace_seconds: i64,
+        tags: Tags,
+    ) -> Result<()> {
+        require!(
+            !fixtures.is_empty() && fixtures.len() <= MAX_SERIES_FIXTURES,
+            FactoryError::InvalidSeriesLength
+        );
         
-        msg!("Getting markets - page: {}, size: {}, creator: {:?}, public: {:?}", 
-            page, page_size, filter_creator, filter_public);
+        let factory_key = ctx.accounts.factory.key();
+        let tag_count = tags.iter().filter(|tag| **tag != NO_TAG).count();
+        require_gte!(ctx.remaining_accounts.len(), tag_count, FactoryError::UnregisteredTag);
+        let (tag_accounts, market_accounts) = ctx.remaining_accounts.split_at(tag_count);
+        require_registered_tags(&factory_key, &tags, tag_accounts)?;
+        require_eq!(market_accounts.len(), fixtures.len() * 3, FactoryError::InvalidSeriesAccounts);
         
-        // Return empty vec as this is meant to be called off-chain
-        Ok(vec![])
+        let current_time = Clock::get()?.unix_timestamp;
+        track_creation_rate(
+            &mut ctx.accounts.creator_rate,
+            ctx.bumps.creator_rate,
+            &ctx.accounts.creation_rate_policy,
+            &ctx.accounts.factory,
+            &ctx.accounts.creator.key(),
+            current_time,
+            fixtures.len() as u32,
+        )?;
+        let registry_rent = Rent::get()?.minimum_balance(MarketRegistry::LEN);
+        let charset = read_match_id_charset(&ctx.accounts.match_id_policy)?;
+        
+        for (fixture, accounts) in fixtures.into_iter().zip(market_accounts.chunks_exact(3)) {
+            let SeriesFixture { match_id, kickoff_time, end_time } = fixture;
+            let (registry_info, market_info, bucket_info) = (&accounts[0], &accounts[1], &accounts[2]);
+            validate_listing(&match_id, charset, entry_fee, kickoff_time, end_time, current_time)?;
+            
+            // Create the registry entry by hand, as `create_market`'s `init` would
+            let seed = match_id_seed(&match_id);
+            let (expected, bump) = Pubkey::find_program_address(
+                &[MARKET_REGISTRY_SEED, factory_key.as_ref(), &seed],
+                &crate::ID,
+            );
+            require_keys_eq!(registry_info.key(), expected, FactoryError::InvalidSeriesAccounts);
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.creator.to_account_info(),
+                        to: registry_info.clone(),
+                    },
+                    &[&[MARKET_REGISTRY_SEED, factory_key.as_ref(), &seed, &[bump]]],
+                ),
+                registry_rent,
+                MarketRegistry::LEN as u64,
+                &crate::ID,
+            )?;
+            
+            let registry = MarketRegistry {
+                factory: factory_key,
+                market_address: market_info.key(),
+                creator: ctx.accounts.creator.key(),
+                tags,
+                match_id: match_id.clone(),
+                created_at: current_time,
+                is_public,
+                entry_fee,
+                kickoff_time,
+                end_time,
+                bump,
+            };
+            registry.try_serialize(&mut &mut registry_info.try_borrow_mut_data()?[..])?;
+            
+            list_in_kickoff_bucket(
+                bucket_info,
+                &factory_key,
+                kickoff_time,
+                market_info.key(),
+                &ctx.accounts.creator,
+                &ctx.accounts.system_program,
+            )?;
+            
+            // The market program derives and checks the market PDA itself
+            cryptoscore_market_interface::cpi::initialize_market(
+                CpiContext::new(
+                    ctx.accounts.market_program.to_account_info(),
+                    cryptoscore_market_interface::cpi::accounts::InitializeMarket {
+                        market: market_info.clone(),
+                        factory: ctx.accounts.factory.to_account_info(),
+                        creator: ctx.accounts.creator.to_account_info(),
+                        platform_stats: ctx.accounts.platform_stats.to_account_info(),
+                        platform_activity: ctx.accounts.platform_activity.to_account_info(),
+                        creator_stats: ctx.accounts.creator_stats.to_account_info(),
+                        dashboard_program: ctx.accounts.dashboard_program.to_account_info(),
+                        points_ledger: ctx.accounts.points_ledger.to_account_info(),
+                        points_totals: ctx.accounts.points_totals.to_account_info(),
+                        resolution_grace_policy: ctx.accounts.resolution_grace_policy.to_account_info(),
+                        match_id_policy: ctx.accounts.match_id_policy.to_account_info(),
+                        instructions: ctx.accounts.instructions.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                ),
+                cryptoscore_market_interface::instruction::InitializeMarket {
+                    match_id: match_id.clone(),
+                    entry_fee,
+                    kickoff_time,
+                    end_time,
+                    is_public,
+                    outcome_set,
+                    resolution_grace_seconds,
+                },
+            )?;
+            
+            let factory = &mut ctx.accounts.factory;
+            factory.market_count = factory.market_count.checked_add(1)
+                .ok_or(FactoryError::MarketCountOverflow)?;
+            
+            emit!(MarketCreated {
+                market: market_info.key(),
+                creator: ctx.accounts.creator.key(),
+                match_id,
+                entry_fee,
+                kickoff_time,
+                is_public,
+            });
+        }
+        
+        msg!("Market series created: {} markets, creator: {}",
+            market_accounts.len() / 3, ctx.accounts.creator.key());
+        
+        Ok(())
     }
-}
 
-// Account Structures
+    /// Move a registry entry created before discovery tags to the current
+    /// layout, with every tag slot unset
+    pub fn migrate_market_registry(ctx: Context<MigrateMarketRegistry>) -> Result<()> {
+        let account_info = ctx.accounts.market_registry.to_account_info();
+        
+        let migrated = {
+            let data = account_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == MarketRegistry::discriminator(),
+                FactoryError::InvalidRegistryAccount
+            );
+            require!(data.len() == LegacyMarketRegistry::LEN, FactoryError::AlreadyMigrated);
+            
+            let legacy = LegacyMarketRegistry::deserialize(&mut &data[8..])?;
+            let match_id = match_id_seed(&legacy.match_id);
+            let expected = Pubkey::create_program_address(
+                &[MARKET_REGISTRY_SEED, legacy.factory.as_ref(), &match_id, &[legacy.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| error!(FactoryError::InvalidRegistryAccount))?;
+            require_keys_eq!(account_info.key(), expected, FactoryError::InvalidRegistryAccount);
+            
+            legacy.into_current()
+        };
+        
+        // Top up rent for the larger account before reallocating
+        let required = Rent::get()?.minimum_balance(MarketRegistry::LEN);
+        let shortfall = required.saturating_sub(account_info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        
+        account_info.realloc(MarketRegistry::LEN, true)?;
+        
+        let mut data = account_info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+        
+        msg!("Migrated market registry {}", account_info.key());
+        
+        Ok(())
+    }
 
-#[account]
-pub struct Factory {
-    /// Authority that can update factory settings
-    pub authority: Pubkey,
-    /// Total number of markets created
-    pub market_count: u64,
-    /// Platform fee in basis points (100 = 1%)
-    pub platform_fee_bps: u16,
-    /// PDA bump seed
-    pub bump: u8,
-}
+    /// Close a registry entry whose market was never initialized, refunding
+    /// the creator's rent
+    ///
+    /// `create_market` registers a market the creator initializes separately,
+    /// so a failed initialization orphans the entry. Anyone can close it once
+    /// the grace period after registration passes with no account at the
+    /// market PDA.
+    pub fn invalidate_registry(ctx: Context<InvalidateRegistry>) -> Result<()> {
+        let market_registry = &ctx.accounts.market_registry;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= market_registry.created_at.saturating_add(ORPHANED_REGISTRY_GRACE_SECONDS),
+            FactoryError::RegistryGracePeriodActive
+        );
+        require!(ctx.accounts.market.data_is_empty(), FactoryError::MarketInitialized);
+        
+        // The market never existed, so it no longer counts towards the factory
+        let factory = &mut ctx.accounts.factory;
+        factory.market_count = factory.market_count.saturating_sub(1);
+        
+        emit!(MarketRegistryInvalidated {
+            market_registry: market_registry.key(),
+            market: ctx.accounts.market.key(),
+            creator: market_registry.creator,
+            match_id: market_registry.match_id.clone(),
+        });
+        
+        msg!("Invalidated market registry {} for {}", market_registry.key(), market_registry.match_id);
+        
+        Ok(())
+    }
 
-impl Factory {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // authority
-        8 +  // market_count
-        2 +  // platform_fee_bps
-        1;   // bump
-}
+    /// Record a market's platform fee in the treasury, with one receipt per market
+    ///
+    /// Called by the market program at resolution, before it moves the fee
+    /// into the treasury.
+    pub fn record_platform_fee(ctx: Context<RecordPlatformFee>, amount: u64) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        let receipt = &mut ctx.accounts.fee_receipt;
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // Initialize if first time
+        if treasury.factory == Pubkey::default() {
+            treasury.factory = ctx.accounts.factory.key();
+            treasury.bump = ctx.bumps.treasury;
+        }
+        
+        treasury.total_collected = treasury.total_collected.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        treasury.receipt_count = treasury.receipt_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        receipt.factory = treasury.factory;
+        receipt.market = ctx.accounts.market.key();
+        receipt.amount = amount;
+        receipt.collected_at = current_time;
+        receipt.bump = ctx.bumps.fee_receipt;
+        
+        emit!(PlatformFeeCollected {
+            market: receipt.market,
+            amount,
+            total_collected: treasury.total_collected,
+        });
+        
+        msg!("Platform fee of {} lamports recorded for market {}", amount, receipt.market);
+        
+        Ok(())
+    }
 
-#[account]
-pub struct MarketRegistry {
-    /// Factory that created this market
-    pub factory: Pubkey,
-    /// Market account address
-    pub market_address: Pubkey,
-    /// Creator of the market
-    pub creator: Pubkey,
-    /// Match identifier (e.g., "EPL-2024-123")
-    pub match_id: String,
-    /// Timestamp when market was created
-    pub created_at: i64,
-    /// Whether market is public or private
-    pub is_public: bool,
-    /// Entry fee in lamports
-    pub entry_fee: u64,
-    /// Match kickoff time
-    pub kickoff_time: i64,
+    /// Move collected platform fees out of the treasury, to the platform
+    /// config's treasury recipient if one is set
+    ///
+    /// The insurance fund's share of fees collected since the last sweep
+    /// moves into the fund first and can't be withdrawn.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, FactoryError::InsufficientTreasuryFunds);
+        if let Some(config) = read_platform_config(&ctx.accounts.platform_config)? {
+            require!(
+                config.allows_recipient(&ctx.accounts.recipient.key()),
+                FactoryError::InvalidTreasuryRecipient
+            );
+        }
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        if fund.factory == Pubkey::default() {
+            fund.factory = ctx.accounts.factory.key();
+            fund.bump = ctx.bumps.insurance_fund;
+        }
+        sweep_insurance_share(&mut ctx.accounts.treasury, fund)?;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        let treasury_info = treasury.to_account_info();
+        let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+        let available = treasury_info.lamports().saturating_sub(rent_minimum);
+        require_gte!(available, amount, FactoryError::InsufficientTreasuryFunds);
+        
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+        
+        treasury.total_withdrawn = treasury.total_withdrawn.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        emit!(TreasuryWithdrawn {
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            total_withdrawn: treasury.total_withdrawn,
+        });
+        
+        msg!("Withdrew {} lamports from the treasury to {}", amount, ctx.accounts.recipient.key());
+        
+        Ok(())
+    }
+
+    /// Pay a losing-streak rebate on an entry of `entry_fee` lamports to
+    /// `user` (CPI from the market program only)
+    ///
+    /// The rebate shrinks to whatever the epoch's cap and the treasury, less
+    /// the insurance fund's unswept share, can still cover. Returns the
+    /// lamports paid, which may be zero.
+    pub fn pay_streak_rebate(ctx: Context<PayStreakRebate>, entry_fee: u64) -> Result<u64> {
+        let epoch = Clock::get()?.epoch;
+        let policy = &mut ctx.accounts.rebate_policy;
+        if policy.epoch != epoch {
+            policy.epoch = epoch;
+            policy.epoch_paid = 0;
+        }
+        
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let available = treasury_spendable(&ctx.accounts.treasury, &ctx.accounts.insurance_fund)?;
+        let amount = policy.rebate(entry_fee, epoch).min(available);
+        if amount == 0 {
+            return Ok(0);
+        }
+        
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.user.try_borrow_mut_lamports()? += amount;
+        
+        policy.epoch_paid = policy.epoch_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.total_paid = policy.total_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.rebate_count = policy.rebate_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        emit!(StreakRebatePaid {
+            user: ctx.accounts.user.key(),
+            market: ctx.accounts.market.key(),
+            amount,
+            epoch_paid: policy.epoch_paid,
+        });
+        
+        msg!("Rebated {} lamports to {}", amount, ctx.accounts.user.key());
+        
+        Ok(amount)
+    }
+
+    /// Pay `user` their discount on the market's entry fee of `entry_fee`,
+    /// given the `reputation` their market-verified record earns and the
+    /// creator `badges` they hold (CPI from the market program only)
+    ///
+    /// The discount shrinks to whatever the epoch's cap and the treasury, less
+    /// the insurance fund's unswept share, can still cover.
+    pub fn pay_fee_discount(ctx: Context<PayFeeDiscount>, entry_fee: u64, reputation: u64, badges: u8) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let policy = &mut ctx.accounts.discount_policy;
+        if policy.epoch != epoch {
+            policy.epoch = epoch;
+            policy.epoch_paid = 0;
+        }
+        
+        let Some(discount_bps) = policy.discount_bps(reputation, badges) else {
+            return Ok(());
+        };
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let available = treasury_spendable(&ctx.accounts.treasury, &ctx.accounts.insurance_fund)?;
+        let amount = policy.discount(entry_fee, discount_bps, epoch).min(available);
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.user.try_borrow_mut_lamports()? += amount;
+        
+        policy.epoch_paid = policy.epoch_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.total_paid = policy.total_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.discount_count = policy.discount_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        emit!(FeeDiscountPaid {
+            user: ctx.accounts.user.key(),
+            market: ctx.accounts.market.key(),
+            discount_bps,
+            amount,
+            epoch_paid: policy.epoch_paid,
+        });
+        
+        msg!("Discounted {} lamports ({} bps) to {}", amount, discount_bps, ctx.accounts.user.key());
+        
+        Ok(())
+    }
+
+    /// Tip `keeper` for running one of the market's permissionless cranks,
+    /// given the market's `total_pool` (CPI from the market program only)
+    ///
+    /// The tip shrinks to whatever the market's budget and the treasury, less
+    /// the insurance fund's unswept share, can still cover.
+    pub fn pay_keeper_tip(ctx: Context<PayKeeperTip>, total_pool: u64) -> Result<()> {
+        let tips = &mut ctx.accounts.keeper_tips;
+        
+        // Initialize if first time
+        if tips.market == Pubkey::default() {
+            tips.market = ctx.accounts.market.key();
+            tips.bump = ctx.bumps.keeper_tips;
+        }
+        
+        let policy = &mut ctx.accounts.keeper_tip_policy;
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let available = treasury_spendable(&ctx.accounts.treasury, &ctx.accounts.insurance_fund)?;
+        let amount = policy.tip(total_pool, tips.paid).min(available);
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.keeper.try_borrow_mut_lamports()? += amount;
+        
+        tips.paid = tips.paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        tips.tip_count = tips.tip_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.total_paid = policy.total_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        policy.tip_count = policy.tip_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        emit!(KeeperTipPaid {
+            keeper: ctx.accounts.keeper.key(),
+            market: tips.market,
+            amount,
+            market_paid: tips.paid,
+        });
+        
+        msg!("Tipped {} lamports to keeper {}", amount, ctx.accounts.keeper.key());
+        
+        Ok(())
+    }
+
+    /// Open promotional voucher campaign `campaign_id`, whose vouchers cover
+    /// entry fees of up to `max_entry_fee` lamports from the treasury until
+    /// `expires_at`, spending at most `budget` lamports across the campaign
+    pub fn create_voucher_campaign(
+        ctx: Context<CreateVoucherCampaign>,
+        campaign_id: u64,
+        budget: u64,
+        max_entry_fee: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            max_entry_fee > 0 && max_entry_fee <= budget && expires_at > now,
+            FactoryError::InvalidVoucherCampaign
+        );
+        
+        let campaign = &mut ctx.accounts.voucher_campaign;
+        campaign.factory = ctx.accounts.factory.key();
+        campaign.campaign_id = campaign_id;
+        campaign.budget = budget;
+        campaign.max_entry_fee = max_entry_fee;
+        campaign.expires_at = expires_at;
+        campaign.spent = 0;
+        campaign.issued = 0;
+        campaign.redeemed = 0;
+        campaign.bump = ctx.bumps.voucher_campaign;
+        
+        msg!("Voucher campaign {} opened: {} lamports until {}", campaign_id, budget, expires_at);
+        
+        Ok(())
+    }
+
+    /// Issue `holder` a single-use voucher from a campaign that hasn't expired
+    ///
+    /// Vouchers can be issued beyond the campaign's budget; once it is spent
+    /// the remaining ones can no longer be redeemed.
+    pub fn issue_voucher(ctx: Context<IssueVoucher>, holder: Pubkey) -> Result<()> {
+        let campaign = &mut ctx.accounts.voucher_campaign;
+        require!(!campaign.is_expired(Clock::get()?.unix_timestamp), FactoryError::VoucherExpired);
+        
+        let voucher = &mut ctx.accounts.voucher;
+        voucher.campaign = campaign.key();
+        voucher.holder = holder;
+        voucher.redeemed_market = Pubkey::default();
+        voucher.redeemed_at = 0;
+        voucher.bump = ctx.bumps.voucher;
+        
+        campaign.issued = campaign.issued.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        msg!("Issued voucher {} to {}", voucher.key(), holder);
+        
+        Ok(())
+    }
+
+    /// Redeem `user`'s voucher for a market entry of `entry_fee`, paying the
+    /// fee from the treasury straight into the market (CPI from the market
+    /// program only)
+    ///
+    /// Unlike rebates and discounts the voucher never shrinks: it covers the
+    /// whole fee or the join fails.
+    pub fn redeem_voucher(ctx: Context<RedeemVoucher>, entry_fee: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &mut ctx.accounts.voucher_campaign;
+        require!(!campaign.is_expired(now), FactoryError::VoucherExpired);
+        require_gte!(campaign.max_entry_fee, entry_fee, FactoryError::VoucherEntryFeeTooHigh);
+        require_gte!(campaign.remaining(), entry_fee, FactoryError::VoucherBudgetExhausted);
+        
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let available = treasury_spendable(&ctx.accounts.treasury, &ctx.accounts.insurance_fund)?;
+        require_gte!(available, entry_fee, FactoryError::InsufficientTreasuryFunds);
+        
+        **treasury_info.try_borrow_mut_lamports()? -= entry_fee;
+        **ctx.accounts.market.try_borrow_mut_lamports()? += entry_fee;
+        
+        let voucher = &mut ctx.accounts.voucher;
+        voucher.redeemed_market = ctx.accounts.market.key();
+        voucher.redeemed_at = now;
+        
+        campaign.spent = campaign.spent.checked_add(entry_fee)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        campaign.redeemed = campaign.redeemed.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        emit!(VoucherRedeemed {
+            holder: voucher.holder,
+            market: voucher.redeemed_market,
+            campaign: campaign.key(),
+            amount: entry_fee,
+            campaign_spent: campaign.spent,
+        });
+        
+        msg!("Voucher {} covered {} lamports for {}", voucher.key(), entry_fee, voucher.holder);
+        
+        Ok(())
+    }
+
+    /// Rule that a resolved market should have resolved to `correct_outcome`
+    ///
+    /// Payouts can't be clawed back once they leave the pool, so instead the
+    /// ruling lets participants who predicted `correct_outcome` claim what they
+    /// would have won from the insurance fund, up to `claim_cap` lamports each
+    /// and `payout_cap` across the market. The factory authority can only
+    /// rule within the platform config's dispute window after the match ends;
+    /// the governor rules on disputes its court opened within the window.
+    pub fn rule_misresolution(
+        ctx: Context<RuleMisresolution>,
+        correct_outcome: MatchOutcome,
+        claim_cap: u64,
+        payout_cap: u64,
+    ) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status.is_resolved(), FactoryError::MarketNotResolved);
+        let resolved_outcome = market.outcome.clone().ok_or(FactoryError::MarketNotResolved)?;
+        require!(resolved_outcome != correct_outcome, FactoryError::InvalidRuling);
+        require_gt!(claim_cap, 0, FactoryError::InvalidInsuranceCap);
+        require_gte!(payout_cap, claim_cap, FactoryError::InvalidInsuranceCap);
+        let now = Clock::get()?.unix_timestamp;
+        let config = read_platform_config(&ctx.accounts.platform_config)?;
+        let ruler = ctx.accounts.ruler.key();
+        if ruler == ctx.accounts.factory.authority {
+            if let Some(config) = &config {
+                require!(config.dispute_open(market.end_time, now), FactoryError::DisputeWindowClosed);
+            }
+        } else {
+            require!(config.is_some_and(|config| config.governor == ruler), FactoryError::Unauthorized);
+        }
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        if fund.factory == Pubkey::default() {
+            fund.factory = ctx.accounts.factory.key();
+            fund.bump = ctx.bumps.insurance_fund;
+        }
+        
+        let ruling = &mut ctx.accounts.ruling;
+        ruling.factory = ctx.accounts.factory.key();
+        ruling.market = market.key();
+        ruling.resolved_outcome = resolved_outcome.clone();
+        ruling.correct_outcome = correct_outcome.clone();
+        ruling.claim_cap = claim_cap;
+        ruling.payout_cap = payout_cap;
+        ruling.total_paid = 0;
+        ruling.claim_count = 0;
+        ruling.ruled_at = now;
+        ruling.bump = ctx.bumps.ruling;
+        
+        emit!(MisresolutionRuled {
+            market: ruling.market,
+            resolved_outcome,
+            correct_outcome,
+            claim_cap,
+            payout_cap,
+        });
+        
+        msg!("Market {} ruled mis-resolved", ruling.market);
+        
+        Ok(())
+    }
+
+    /// Rule a market mis-resolved on its results signer's correction, after
+    /// winners have already claimed from it
+    ///
+    /// Called by the market program from `correct_result`. Participants who
+    /// predicted the corrected outcome can claim what they were owed in full,
+    /// as far as the insurance fund can pay.
+    pub fn record_result_correction(ctx: Context<RecordResultCorrection>, correct_outcome: MatchOutcome) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status.is_resolved(), FactoryError::MarketNotResolved);
+        let resolved_outcome = market.outcome.clone().ok_or(FactoryError::MarketNotResolved)?;
+        require!(resolved_outcome != correct_outcome, FactoryError::InvalidRuling);
+        
+        let winner_count = match correct_outcome {
+            MatchOutcome::Home => market.home_count,
+            MatchOutcome::Draw => market.draw_count,
+            MatchOutcome::Away => market.away_count,
+        };
+        require_gt!(winner_count, 0, FactoryError::InvalidRuling);
+        let claim_cap = settled_reward_per_winner(market.total_pool, market.fees_paid, winner_count)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        let payout_cap = claim_cap.checked_mul(winner_count as u64)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        if fund.factory == Pubkey::default() {
+            fund.factory = ctx.accounts.factory.key();
+            fund.bump = ctx.bumps.insurance_fund;
+        }
+        
+        let ruling = &mut ctx.accounts.ruling;
+        ruling.factory = ctx.accounts.factory.key();
+        ruling.market = market.key();
+        ruling.resolved_outcome = resolved_outcome.clone();
+        ruling.correct_outcome = correct_outcome.clone();
+        ruling.claim_cap = claim_cap;
+        ruling.payout_cap = payout_cap;
+        ruling.total_paid = 0;
+        ruling.claim_count = 0;
+        ruling.ruled_at = Clock::get()?.unix_timestamp;
+        ruling.bump = ctx.bumps.ruling;
+        
+        emit!(MisresolutionRuled {
+            market: ruling.market,
+            resolved_outcome,
+            correct_outcome,
+            claim_cap,
+            payout_cap,
+        });
+        
+        msg!("Market {} ruled mis-resolved by a result correction", ruling.market);
+        
+        Ok(())
+    }
+
+    /// Claim compensation for a mis-resolved market as a participant who
+    /// predicted the outcome it was ruled to have
+    ///
+    /// Pays what the participant would have won had the market resolved
+    /// correctly, capped by the ruling. Each participant can claim once.
+    pub fn insurance_claim(ctx: Context<ClaimInsurance>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let ruling = &mut ctx.accounts.ruling;
+        require!(
+            ctx.accounts.participant.prediction == ruling.correct_outcome,
+            FactoryError::NotAffectedByRuling
+        );
+        
+        let winner_count = match ruling.correct_outcome {
+            MatchOutcome::Home => market.home_count,
+            MatchOutcome::Draw => market.draw_count,
+            MatchOutcome::Away => market.away_count,
+        };
+        let owed = settled_reward_per_winner(market.total_pool, market.fees_paid, winner_count)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        let remaining = ruling.payout_cap.saturating_sub(ruling.total_paid);
+        let amount = owed.min(ruling.claim_cap).min(remaining);
+        require_gt!(amount, 0, FactoryError::InsuranceCapReached);
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        sweep_insurance_share(&mut ctx.accounts.treasury, fund)?;
+        
+        let fund_info = fund.to_account_info();
+        let rent_minimum = Rent::get()?.minimum_balance(fund_info.data_len());
+        let available = fund_info.lamports().saturating_sub(rent_minimum);
+        if available < amount {
+            return Err(error!(FactoryError::InsufficientInsuranceFunds).with_values((available, amount)));
+        }
+        
+        **fund_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.claimant.try_borrow_mut_lamports()? += amount;
+        
+        fund.total_paid = fund.total_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        fund.claim_count = fund.claim_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        ruling.total_paid = ruling.total_paid.checked_add(amount)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        ruling.claim_count = ruling.claim_count.checked_add(1)
+            .ok_or(FactoryError::TreasuryOverflow)?;
+        
+        let claim = &mut ctx.accounts.claim;
+        claim.market = market.key();
+        claim.user = ctx.accounts.claimant.key();
+        claim.amount = amount;
+        claim.claimed_at = Clock::get()?.unix_timestamp;
+        claim.bump = ctx.bumps.claim;
+        
+        emit!(InsuranceClaimPaid {
+            market: claim.market,
+            user: claim.user,
+            amount,
+            fund_balance: available - amount,
+        });
+        
+        msg!("Paid {} lamports of insurance to {} for market {}", amount, claim.user, claim.market);
+        
+        Ok(())
+    }
+
+    /// Get paginated list of markets with filtering
+    pub fn get_markets(
+        _ctx: Context<GetMarkets>,
+        filter_creator: Option<Pubkey>,
+        filter_public: Option<bool>,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<MarketInfo>> {
+        // This is a view function that would be called off-chain
+        // In practice, clients would fetch market registry accounts directly
+        // This is here for documentation purposes
+        
+        msg!("Getting markets - page: {}, size: {}, creator: {:?}, public: {:?}", 
+            page, page_size, filter_creator, filter_public);
+        
+        // Return empty vec as this is meant to be called off-chain
+        Ok(vec![])
+    }
+}
+
+// Helpers
+
+/// Most fixtures one `create_market_series` call can list
+pub const MAX_SERIES_FIXTURES: usize = 10;
+
+/// Check a market's match ID, entry fee and schedule before listing it
+fn validate_listing(
+    match_id: &str,
+    charset: Charset,
+    entry_fee: u64,
+    kickoff_time: i64,
+    end_time: i64,
+    current_time: i64,
+) -> Result<()> {
+    // Validate match ID is not empty
+    require!(!match_id.is_empty(), FactoryError::InvalidMatchId);
+    require!(match_id.len() <= MAX_MATCH_ID_LEN, FactoryError::MatchIdTooLong);
+    require!(uses_charset(match_id, charset), FactoryError::InvalidMatchIdCharacters);
+    
+    // Validate entry fee is non-zero
+    require!(entry_fee > 0, FactoryError::ZeroEntryFee);
+    
+    // Validate times
+    require!(kickoff_time > current_time, FactoryError::InvalidKickoffTime);
+    require!(end_time > kickoff_time, FactoryError::InvalidEndTime);
+    
+    Ok(())
+}
+
+/// Characters the factory's match IDs may contain, per its match ID policy
+/// if it has set one
+fn read_match_id_charset(account: &AccountInfo) -> Result<Charset> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(DEFAULT_MATCH_ID_CHARSET);
+    }
+    Ok(MatchIdPolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?.charset)
+}
+
+/// Fail unless `tag_accounts` holds, in slot order, the `TagDefinition` of
+/// every set slot of `tags` and nothing else
+/// List `market` in `factory`'s kickoff bucket for the day `kickoff_time`
+/// falls on, creating the bucket by hand as `create_market`'s
+/// `init_if_needed` would
+fn list_in_kickoff_bucket<'info>(
+    bucket_info: &AccountInfo<'info>,
+    factory: &Pubkey,
+    kickoff_time: i64,
+    market: Pubkey,
+    creator: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let day = kickoff_day(kickoff_time);
+    let (expected, bump) = Pubkey::find_program_address(
+        &[KICKOFF_BUCKET_SEED, factory.as_ref(), &day.to_le_bytes()],
+        &crate::ID,
+    );
+    require_keys_eq!(bucket_info.key(), expected, FactoryError::InvalidSeriesAccounts);
+    
+    let mut bucket = if bucket_info.data_is_empty() {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: creator.to_account_info(),
+                    to: bucket_info.clone(),
+                },
+                &[&[KICKOFF_BUCKET_SEED, factory.as_ref(), &day.to_le_bytes(), &[bump]]],
+            ),
+            Rent::get()?.minimum_balance(KickoffBucket::LEN),
+            KickoffBucket::LEN as u64,
+            &crate::ID,
+        )?;
+        KickoffBucket { factory: *factory, day, markets: Vec::new(), bump }
+    } else {
+        KickoffBucket::try_deserialize(&mut &bucket_info.try_borrow_data()?[..])?
+    };
+    require!(bucket.markets.len() < KickoffBucket::MAX_MARKETS, FactoryError::KickoffBucketFull);
+    bucket.markets.push(market);
+    
+    bucket.try_serialize(&mut &mut bucket_info.try_borrow_mut_data()?[..])
+}
+
+fn require_registered_tags(factory: &Pubkey, tags: &Tags, tag_accounts: &[AccountInfo]) -> Result<()> {
+    let set: Vec<(TagCategory, &Tag)> = TagCategory::ALL
+        .iter()
+        .map(|&category| (category, &tags[category.slot()]))
+        .filter(|(_, tag)| **tag != NO_TAG)
+        .collect();
+    require_eq!(tag_accounts.len(), set.len(), FactoryError::UnregisteredTag);
+    
+    for ((category, tag), account) in set.into_iter().zip(tag_accounts) {
+        require_keys_eq!(*account.owner, crate::ID, FactoryError::UnregisteredTag);
+        let definition = TagDefinition::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        
+        let expected = Pubkey::create_program_address(
+            &[TAG_SEED, factory.as_ref(), &[category as u8], tag, &[definition.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| error!(FactoryError::UnregisteredTag))?;
+        if account.key() != expected {
+            msg!("{:?} tag {} is not registered", category, decode_tag(tag).unwrap_or("<invalid>"));
+            return err!(FactoryError::UnregisteredTag);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Move the insurance fund's share of platform fees recorded since the last
+/// sweep out of the treasury
+fn sweep_insurance_share(treasury: &mut Account<Treasury>, fund: &mut Account<InsuranceFund>) -> Result<()> {
+    let owed = insurance_share(treasury.total_collected).saturating_sub(fund.total_funded);
+    if owed == 0 {
+        return Ok(());
+    }
+    
+    let treasury_info = treasury.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let amount = owed.min(treasury_info.lamports().saturating_sub(rent_minimum));
+    
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **fund.to_account_info().try_borrow_mut_lamports()? += amount;
+    fund.total_funded = fund.total_funded.checked_add(amount)
+        .ok_or(FactoryError::TreasuryOverflow)?;
+    
+    emit!(InsuranceFunded {
+        amount,
+        total_funded: fund.total_funded,
+    });
+    
+    Ok(())
+}
+
+/// Treasury lamports that can be paid out as rebates or discounts: all but
+/// its rent and the insurance fund's unswept share
+fn treasury_spendable(treasury: &Account<Treasury>, insurance_fund: &AccountInfo) -> Result<u64> {
+    let treasury_info = treasury.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let insurance_funded = read_insurance_funded(insurance_fund)?;
+    let insurance_owed = insurance_share(treasury.total_collected).saturating_sub(insurance_funded);
+    Ok(treasury_info.lamports().saturating_sub(rent_minimum).saturating_sub(insurance_owed))
+}
+
+/// Lamports moved into the insurance fund so far, or zero before it exists
+fn read_insurance_funded(account: &AccountInfo) -> Result<u64> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(0);
+    }
+    let fund = InsuranceFund::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(fund.total_funded)
+}
+
+/// Factory's creation rate policy, if it has set one
+fn read_creation_rate_policy(account: &AccountInfo) -> Result<Option<CreationRatePolicy>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = CreationRatePolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// Count `markets` new markets against `creator`'s rate, refusing them if the
+/// factory's creation rate policy is on and they would take the creator over
+/// its limit; the factory authority, e.g. seeding fixtures, is exempt
+fn track_creation_rate(
+    creator_rate: &mut Account<CreatorRate>,
+    bump: u8,
+    creation_rate_policy: &AccountInfo,
+    factory: &Account<Factory>,
+    creator: &Pubkey,
+    now: i64,
+    markets: u32,
+) -> Result<()> {
+    // Initialize if first time
+    if creator_rate.creator == Pubkey::default() {
+        creator_rate.factory = factory.key();
+        creator_rate.creator = *creator;
+        creator_rate.bump = bump;
+    }
+    creator_rate.total_created = creator_rate.total_created.checked_add(markets as u64)
+        .ok_or(FactoryError::MarketCountOverflow)?;
+    
+    let Some(policy) = read_creation_rate_policy(creation_rate_policy)? else {
+        return Ok(());
+    };
+    let window = policy.window_seconds;
+    if policy.max_markets > 0
+        && *creator != factory.authority
+        && !creator_rate.recent.allows(now, window, policy.max_markets, markets)
+    {
+        let recent = creator_rate.recent.count(now, window);
+        return Err(error!(FactoryError::CreationRateLimited)
+            .with_values((recent.saturating_add(markets), policy.max_markets)));
+    }
+    creator_rate.recent.record(now, window, markets);
+    
+    Ok(())
+}
+
+/// Platform config, if the factory has one yet
+fn read_platform_config(account: &AccountInfo) -> Result<Option<PlatformConfig>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let config = PlatformConfig::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(config))
+}
+
+// Account Structures
+
+/// Settings shared by every market created under it, stored at the
+/// `factory` PDA for the default platform and at the `factory` PDA of a
+/// `Platform` for an operator's own
+///
+/// Only this program can create factories, so any `Factory` account it owns
+/// is one of them and is accepted wherever a factory is expected.
+#[account]
+pub struct Factory {
+    /// Authority that can update factory settings
+    pub authority: Pubkey,
+    /// Total number of markets created
+    pub market_count: u64,
+    /// Platform fee in basis points (100 = 1%)
+    pub platform_fee_bps: u16,
+    /// Minimum qualifying markets settled before a user can enter leaderboards
+    pub min_leaderboard_markets: u32,
+    /// Minimum market pool in lamports for a settlement to count towards leaderboards
+    pub min_leaderboard_pool: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Factory {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // authority
+        8 +  // market_count
+        2 +  // platform_fee_bps
+        4 +  // min_leaderboard_markets
+        8 +  // min_leaderboard_pool
+        1;   // bump
+}
+
+/// An operator's branded instance of CryptoScore, stored at the `platform`
+/// PDA of its ID
+#[account]
+pub struct Platform {
+    /// Operator, also the authority of the platform's factory
+    pub operator: Pubkey,
+    /// The platform's factory, at the `factory` PDA of this account
+    pub factory: Pubkey,
+    /// Platform identifier (e.g., "acme-sports")
+    pub platform_id: String,
+    /// Timestamp when the platform was created
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Platform {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // operator
+        32 + // factory
+        4 + MAX_PLATFORM_ID_LEN + // platform_id
+        8 +  // created_at
+        1;   // bump
+}
+
+/// Index entry linking a match ID to its market account
+#[account]
+pub struct MarketRegistry {
+    /// Factory that created this market
+    pub factory: Pubkey,
+    /// Market account address
+    pub market_address: Pubkey,
+    /// Creator of the market
+    pub creator: Pubkey,
+    /// Discovery tags indexed by `TagCategory::slot`, all zeroes when unset;
+    /// kept ahead of `match_id` so memcmp filters can find them
+    pub tags: Tags,
+    /// Match identifier (e.g., "EPL-2024-123")
+    pub match_id: String,
+    /// Timestamp when market was created
+    pub created_at: i64,
+    /// Whether market is public or private
+    pub is_public: bool,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    /// Match kickoff time
+    pub kickoff_time: i64,
     /// Match end time
     pub end_time: i64,
     /// PDA bump seed
     pub bump: u8,
 }
 
-impl MarketRegistry {
-    pub const MAX_MATCH_ID_LEN: usize = 64;
+impl MarketRegistry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // market_address
+        32 + // creator
+        TAGS_SPACE + // tags
+        MATCH_ID_SPACE + // match_id (String with length prefix)
+        8 +  // created_at
+        1 +  // is_public
+        8 +  // entry_fee
+        8 +  // kickoff_time
+        8 +  // end_time
+        1;   // bump
+}
+
+/// `MarketRegistry` layout from before discovery tags, read only by
+/// `migrate_market_registry`
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMarketRegistry {
+    pub factory: Pubkey,
+    pub market_address: Pubkey,
+    pub creator: Pubkey,
+    pub match_id: String,
+    pub created_at: i64,
+    pub is_public: bool,
+    pub entry_fee: u64,
+    pub kickoff_time: i64,
+    pub end_time: i64,
+    pub bump: u8,
+}
+
+impl LegacyMarketRegistry {
+    pub const LEN: usize = MarketRegistry::LEN - TAGS_SPACE;
+
+    pub fn into_current(self) -> MarketRegistry {
+        MarketRegistry {
+            factory: self.factory,
+            market_address: self.market_address,
+            creator: self.creator,
+            tags: [NO_TAG; TAG_SLOTS],
+            match_id: self.match_id,
+            created_at: self.created_at,
+            is_public: self.is_public,
+            entry_fee: self.entry_fee,
+            kickoff_time: self.kickoff_time,
+            end_time: self.end_time,
+            bump: self.bump,
+        }
+    }
+}
+
+/// A tag the factory allows in one category's slot, stored at the `tag` PDA
+/// of its factory, category and tag bytes
+#[account]
+pub struct TagDefinition {
+    /// Factory the tag is registered with
+    pub factory: Pubkey,
+    /// Slot the tag may be used in
+    pub category: TagCategory,
+    /// Zero-padded tag bytes
+    pub tag: Tag,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TagDefinition {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        1 +  // category
+        TAG_LEN + // tag
+        1;   // bump
+}
+
+/// Platform key attesting match results for leagues without an oracle,
+/// stored at the `results_signer` PDA of its factory
+#[account]
+pub struct ResultsSigner {
+    /// Factory this signer resolves markets for
+    pub factory: Pubkey,
+    /// Ed25519 key signing `attestation_message`s
+    pub signer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResultsSigner {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // signer
+        1;   // bump
+}
+
+/// Source-chain oracle whose Wormhole messages resolve markets, stored at
+/// the `wormhole_emitter` PDA of its factory
+#[account]
+pub struct WormholeEmitter {
+    /// Factory this emitter resolves markets for
+    pub factory: Pubkey,
+    /// Wormhole core bridge program that owns posted VAA accounts
+    pub core_bridge: Pubkey,
+    /// Wormhole chain ID of the oracle's chain
+    pub emitter_chain: u16,
+    /// Oracle contract address, left-padded to 32 bytes
+    pub emitter_address: [u8; 32],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WormholeEmitter {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // core_bridge
+        2 +  // emitter_chain
+        32 + // emitter_address
+        1;   // bump
+}
+
+/// Match results the results signer published together, stored at the
+/// `results_batch` PDA of its factory and batch ID
+#[account]
+pub struct ResultsBatch {
+    /// Factory whose results signer published the batch
+    pub factory: Pubkey,
+    /// Caller-chosen identifier, e.g. a matchweek number
+    pub batch_id: u64,
+    /// Results signer at the time of publishing
+    pub signer: Pubkey,
+    /// Publishing timestamp
+    pub published_at: i64,
+    /// One result per match, with distinct match IDs
+    pub results: Vec<MatchResult>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResultsBatch {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // batch_id
+        32 + // signer
+        8 +  // published_at
+        4 + MATCH_RESULT_SPACE * MAX_BATCH_RESULTS + // results
+        1;   // bump
+
+    /// Published final score of `match_id`, if the batch has one
+    pub fn score(&self, match_id: &str) -> Option<FinalScore> {
+        self.results
+            .iter()
+            .find(|result| result.match_id == match_id)
+            .map(|result| result.score)
+    }
+}
+
+/// Score of a match being played, as reported by the results signer, stored
+/// at the `live_score` PDA of its factory and match ID
+#[account]
+pub struct LiveScore {
+    /// Factory whose results signer reports the score
+    pub factory: Pubkey,
+    /// Match identifier, as registered with the factory
+    pub match_id: String,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Minute of play at the last update
+    pub minute: u16,
+    /// Whether the last update was the final score
+    pub is_final: bool,
+    /// Results signer at the time of the last update
+    pub signer: Pubkey,
+    /// Timestamp of the last update
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LiveScore {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        MATCH_ID_SPACE + // match_id (String with length prefix)
+        1 +  // home_goals
+        1 +  // away_goals
+        2 +  // minute
+        1 +  // is_final
+        32 + // signer
+        8 +  // updated_at
+        1;   // bump
+
+    /// Final score of the match, once the score is final
+    pub fn final_score(&self) -> Option<FinalScore> {
+        self.is_final.then(|| FinalScore::new(self.home_goals, self.away_goals))
+    }
+}
+
+/// Pool size above which a market needs two co-signers to resolve, stored
+/// at the `resolution_policy` PDA of its factory
+#[account]
+pub struct ResolutionPolicy {
+    /// Factory whose markets the policy applies to
+    pub factory: Pubkey,
+    /// Smallest pool in lamports needing co-signed resolution; zero for none
+    pub high_stakes_pool: u64,
+    /// Independent key co-signing alongside the creator and results signer
+    pub arbiter: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResolutionPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // high_stakes_pool
+        32 + // arbiter
+        1;   // bump
+
+    /// Whether a market holding `total_pool` must be resolved by co-signers
+    pub fn requires_co_signers(&self, total_pool: u64) -> bool {
+        self.high_stakes_pool > 0 && total_pool >= self.high_stakes_pool
+    }
+}
+
+/// Entry fee rebates for users on a losing streak, paid from the treasury
+/// and stored at the `rebate_policy` PDA of its factory
+#[account]
+pub struct RebatePolicy {
+    /// Factory whose treasury funds the rebates
+    pub factory: Pubkey,
+    /// Consecutive settled losses earning a rebate on the next entry; zero for none
+    pub loss_streak: u16,
+    /// Share of the entry fee rebated, in basis points
+    pub rebate_bps: u16,
+    /// Most lamports rebated per epoch
+    pub epoch_cap: u64,
+    /// Epoch `epoch_paid` counts towards
+    pub epoch: u64,
+    /// Lamports rebated during `epoch`
+    pub epoch_paid: u64,
+    /// Total lamports rebated
+    pub total_paid: u64,
+    /// Number of rebates paid
+    pub rebate_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RebatePolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        2 +  // loss_streak
+        2 +  // rebate_bps
+        8 +  // epoch_cap
+        8 +  // epoch
+        8 +  // epoch_paid
+        8 +  // total_paid
+        8 +  // rebate_count
+        1;   // bump
+
+    /// Rebate on an entry of `entry_fee` during `epoch`, limited by what is
+    /// left of that epoch's cap but not by the treasury's balance
+    pub fn rebate(&self, entry_fee: u64, epoch: u64) -> u64 {
+        if self.loss_streak == 0 {
+            return 0;
+        }
+        let spent = if epoch == self.epoch { self.epoch_paid } else { 0 };
+        let rebate = (entry_fee as u128 * self.rebate_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        rebate.min(self.epoch_cap.saturating_sub(spent))
+    }
+}
+
+/// Entry fee discounts for reputation and badge holders, paid from the
+/// treasury of its factory
+#[account]
+pub struct DiscountPolicy {
+    /// Factory whose treasury funds the discounts
+    pub factory: Pubkey,
+    /// Dashboard's soulbound reputation mint, whose balances tiers are measured in
+    pub reputation_mint: Pubkey,
+    /// Discount tiers; users get the largest discount they qualify for
+    pub tiers: Vec<DiscountTier>,
+    /// Most lamports discounted per epoch
+    pub epoch_cap: u64,
+    /// Epoch `epoch_paid` counts towards
+    pub epoch: u64,
+    /// Lamports discounted during `epoch`
+    pub epoch_paid: u64,
+    /// Total lamports discounted
+    pub total_paid: u64,
+    /// Number of discounts paid
+    pub discount_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DiscountPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // reputation_mint
+        4 + MAX_DISCOUNT_TIERS * DiscountTier::LEN + // tiers
+        8 +  // epoch_cap
+        8 +  // epoch
+        8 +  // epoch_paid
+        8 +  // total_paid
+        8 +  // discount_count
+        1;   // bump
+
+    /// Largest discount of the tiers a holder of `reputation` tokens and
+    /// `badges` creator badges qualifies for
+    pub fn discount_bps(&self, reputation: u64, badges: u8) -> Option<u16> {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.is_met_by(reputation, badges))
+            .map(|tier| tier.discount_bps)
+            .max()
+    }
+
+    /// `discount_bps` of an entry of `entry_fee` during `epoch`, limited by
+    /// what is left of that epoch's cap but not by the treasury's balance
+    pub fn discount(&self, entry_fee: u64, discount_bps: u16, epoch: u64) -> u64 {
+        let spent = if epoch == self.epoch { self.epoch_paid } else { 0 };
+        let discount = (entry_fee as u128 * discount_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        discount.min(self.epoch_cap.saturating_sub(spent))
+    }
+}
+
+/// One discount tier, reached by earning enough reputation or holding enough
+/// creator badges
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DiscountTier {
+    /// Reputation, as earned by a verified record, qualifying for the tier; zero to ignore reputation
+    pub min_reputation: u64,
+    /// Creator badges qualifying for the tier; zero to ignore badges
+    pub min_badges: u8,
+    /// Share of the entry fee discounted, in basis points
+    pub discount_bps: u16,
+}
+
+impl DiscountTier {
+    pub const LEN: usize = 8 + 1 + 2;
+
+    /// Whether the tier has a threshold and discounts at most the whole entry fee
+    pub fn is_valid(&self) -> bool {
+        (self.min_reputation > 0 || self.min_badges > 0)
+            && self.discount_bps > 0
+            && self.discount_bps as u64 <= BPS_DENOMINATOR
+    }
+
+    /// Whether a holder of `reputation` tokens and `badges` creator badges reaches the tier
+    pub fn is_met_by(&self, reputation: u64, badges: u8) -> bool {
+        (self.min_reputation > 0 && reputation >= self.min_reputation)
+            || (self.min_badges > 0 && badges >= self.min_badges)
+    }
+}
+
+/// Tips for keepers running permissionless market cranks, paid from the
+/// treasury and stored at the `keeper_tip_policy` PDA of its factory
+#[account]
+pub struct KeeperTipPolicy {
+    /// Factory whose treasury pays the tips
+    pub factory: Pubkey,
+    /// Lamports tipped per crank; zero when tips are off
+    pub tip: u64,
+    /// Most lamports tipped per market
+    pub market_budget: u64,
+    /// Total lamports tipped
+    pub total_paid: u64,
+    /// Number of tips paid
+    pub tip_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl KeeperTipPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // tip
+        8 +  // market_budget
+        8 +  // total_paid
+        8 +  // tip_count
+        1;   // bump
+
+    /// Most lamports a market with `total_pool` can tip: the market budget,
+    /// or the platform fee its pool owes if that is less
+    pub fn budget(&self, total_pool: u64) -> u64 {
+        let platform_fee = market_fees(total_pool).map_or(0, |fees| fees.platform_fee);
+        self.market_budget.min(platform_fee)
+    }
+
+    /// Next tip on a market with `total_pool` that has tipped `market_paid`
+    /// so far, limited by its budget but not by the treasury's balance
+    pub fn tip(&self, total_pool: u64, market_paid: u64) -> u64 {
+        self.tip.min(self.budget(total_pool).saturating_sub(market_paid))
+    }
+}
+
+/// Tokens winners can swap their payouts into, stored at the
+/// `payout_swap_policy` PDA of its factory
+#[account]
+pub struct PayoutSwapPolicy {
+    /// Factory whose markets pay out in these tokens
+    pub factory: Pubkey,
+    /// Mints payouts can be swapped into; empty when swaps are off
+    pub mints: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PayoutSwapPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        4 + MAX_PAYOUT_SWAP_MINTS * 32 + // mints
+        1;   // bump
+
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        self.mints.contains(mint)
+    }
+}
+
+/// Where claim receipts are minted, stored at the `receipt_policy` PDA of its
+/// factory
+#[account]
+pub struct ReceiptPolicy {
+    /// Factory whose markets' claims get receipts
+    pub factory: Pubkey,
+    /// Bubblegum tree receipts are minted into; default when receipts are off
+    pub merkle_tree: Pubkey,
+    /// Start of every receipt's URI
+    pub uri_base: String,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReceiptPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // merkle_tree
+        4 + MAX_RECEIPT_URI_BASE_LEN + // uri_base
+        1;   // bump
+
+    pub fn enabled(&self) -> bool {
+        self.merkle_tree != Pubkey::default()
+    }
+}
+
+/// Bounds on the creator-only resolution grace period markets can set,
+/// stored at the `resolution_grace_policy` PDA of its factory
+#[account]
+pub struct ResolutionGracePolicy {
+    /// Factory whose markets are bound
+    pub factory: Pubkey,
+    /// Shortest grace period, in seconds after a market ends
+    pub min_grace_seconds: i64,
+    /// Longest grace period, in seconds after a market ends
+    pub max_grace_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResolutionGracePolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // min_grace_seconds
+        8 +  // max_grace_seconds
+        1;   // bump
+
+    pub fn allows(&self, grace_seconds: i64) -> bool {
+        (self.min_grace_seconds..=self.max_grace_seconds).contains(&grace_seconds)
+    }
+}
+
+/// Characters the factory's match IDs may contain, stored at the
+/// `match_id_policy` PDA of its factory
+#[account]
+pub struct MatchIdPolicy {
+    /// Factory whose match IDs are checked
+    pub factory: Pubkey,
+    /// Bitmap of the allowed ASCII bytes, as in `cryptoscore_common::match_id`
+    pub charset: u128,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MatchIdPolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        16 + // charset
+        1;   // bump
+}
+
+/// Most markets a wallet can create per sliding window, stored at the
+/// `creation_rate_policy` PDA of its factory
+#[account]
+pub struct CreationRatePolicy {
+    /// Factory whose creators are limited
+    pub factory: Pubkey,
+    /// Markets a wallet can create per window; zero when the limit is off
+    pub max_markets: u32,
+    /// Length of the sliding window in seconds
+    pub window_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreationRatePolicy {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        4 +  // max_markets
+        8 +  // window_seconds
+        1;   // bump
+}
+
+/// Markets one wallet has created on a factory, stored at the `creator_rate`
+/// PDA of the factory and creator
+#[account]
+pub struct CreatorRate {
+    /// Factory the markets were created on
+    pub factory: Pubkey,
+    /// Wallet creating the markets
+    pub creator: Pubkey,
+    /// Markets created recently, in windows of the creation rate policy's length
+    pub recent: SlidingWindow,
+    /// Markets created in total
+    pub total_created: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorRate {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // creator
+        SlidingWindow::LEN + // recent
+        8 +  // total_created
+        1;   // bump
+}
+
+/// Whether a market runs without creator and platform fees, stored at the
+/// `rake_free_market` PDA of the market
+#[account]
+pub struct RakeFreeMarket {
+    /// Factory the market belongs to
+    pub factory: Pubkey,
+    /// Market the flag applies to
+    pub market: Pubkey,
+    /// Whether the market's resolution takes no fees
+    pub rake_free: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RakeFreeMarket {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // market
+        1 +  // rake_free
+        1;   // bump
+}
+
+/// Markets of a factory kicking off on one unix day, in listing order,
+/// stored at the `kickoff_bucket` PDA of the factory and day
+///
+/// Lets clients show a day's or a weekend's matches with one or two
+/// account fetches instead of scanning every registry entry.
+#[account]
+pub struct KickoffBucket {
+    /// Factory the markets belong to
+    pub factory: Pubkey,
+    /// Unix day, counted from the epoch, the markets kick off on
+    pub day: u32,
+    /// Markets listed at creation, including any never initialized or since cancelled
+    pub markets: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl KickoffBucket {
+    pub const MAX_MARKETS: usize = 128;
+    
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        4 +  // day
+        4 + Self::MAX_MARKETS * 32 + // markets
+        1;   // bump
+}
+
+/// Tips paid to keepers for one market's cranks, stored at the `keeper_tips`
+/// PDA of the market
+#[account]
+pub struct KeeperTips {
+    /// Market whose cranks were tipped
+    pub market: Pubkey,
+    /// Lamports tipped so far
+    pub paid: u64,
+    /// Number of tips paid
+    pub tip_count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl KeeperTips {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        8 +  // paid
+        4 +  // tip_count
+        1;   // bump
+}
+
+/// Promotional campaign whose vouchers cover entry fees from the treasury,
+/// stored at the `voucher_campaign` PDA of its factory and campaign ID
+#[account]
+pub struct VoucherCampaign {
+    /// Factory whose treasury funds the vouchers
+    pub factory: Pubkey,
+    /// Campaign ID chosen by the factory authority
+    pub campaign_id: u64,
+    /// Most lamports the campaign's vouchers can cover in total
+    pub budget: u64,
+    /// Largest entry fee a voucher covers
+    pub max_entry_fee: u64,
+    /// Unix timestamp from which vouchers can't be issued or redeemed
+    pub expires_at: i64,
+    /// Lamports covered so far
+    pub spent: u64,
+    /// Number of vouchers issued
+    pub issued: u32,
+    /// Number of vouchers redeemed
+    pub redeemed: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoucherCampaign {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // campaign_id
+        8 +  // budget
+        8 +  // max_entry_fee
+        8 +  // expires_at
+        8 +  // spent
+        4 +  // issued
+        4 +  // redeemed
+        1;   // bump
+
+    /// Whether vouchers can no longer be issued or redeemed at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Lamports of the budget not yet covered
+    pub fn remaining(&self) -> u64 {
+        self.budget.saturating_sub(self.spent)
+    }
+}
+
+/// Single-use voucher covering one market entry, stored at the `voucher` PDA
+/// of its campaign and holder
+#[account]
+pub struct Voucher {
+    /// Campaign funding the voucher
+    pub campaign: Pubkey,
+    /// User who can redeem the voucher
+    pub holder: Pubkey,
+    /// Market the voucher was redeemed in; the default pubkey until then
+    pub redeemed_market: Pubkey,
+    /// Unix timestamp of the redemption, zero until then
+    pub redeemed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Voucher {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // campaign
+        32 + // holder
+        32 + // redeemed_market
+        8 +  // redeemed_at
+        1;   // bump
+
+    /// Whether the voucher has covered an entry
+    pub fn is_redeemed(&self) -> bool {
+        self.redeemed_market != Pubkey::default()
+    }
+}
+
+/// Platform parameters the factory authority or its governor can change,
+/// stored at the `platform_config` PDA of its factory
+#[account]
+pub struct PlatformConfig {
+    /// Factory the parameters apply to
+    pub factory: Pubkey,
+    /// Key that can change platform parameters besides the factory authority; default for none
+    pub governor: Pubkey,
+    /// Seconds after a market ends during which it can be ruled mis-resolved; zero for no limit
+    pub dispute_window: i64,
+    /// Only account treasury withdrawals can go to; default for any
+    pub treasury_recipient: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // governor
+        8 +  // dispute_window
+        32 + // treasury_recipient
+        1;   // bump
+
+    /// Whether a market that ended at `end_time` can still be disputed at `now`
+    pub fn dispute_open(&self, end_time: i64, now: i64) -> bool {
+        self.dispute_window == 0 || now <= end_time.saturating_add(self.dispute_window)
+    }
+
+    /// Whether treasury withdrawals can go to `recipient`
+    pub fn allows_recipient(&self, recipient: &Pubkey) -> bool {
+        self.treasury_recipient == Pubkey::default() || self.treasury_recipient == *recipient
+    }
+}
+
+/// Platform fees collected from resolved markets, held as this account's
+/// lamports at the `treasury` PDA of its factory
+#[account]
+pub struct Treasury {
+    /// Factory collecting the fees
+    pub factory: Pubkey,
+    /// Total platform fees recorded in lamports
+    pub total_collected: u64,
+    /// Total withdrawn by the factory authority in lamports
+    pub total_withdrawn: u64,
+    /// Number of fee receipts issued
+    pub receipt_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // total_collected
+        8 +  // total_withdrawn
+        8 +  // receipt_count
+        1;   // bump
+}
+
+/// Platform fee one market paid into the treasury, at the `fee_receipt` PDA of the market
+#[account]
+pub struct FeeReceipt {
+    /// Factory whose treasury received the fee
+    pub factory: Pubkey,
+    /// Market that paid the fee
+    pub market: Pubkey,
+    /// Fee in lamports
+    pub amount: u64,
+    /// Resolution timestamp
+    pub collected_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FeeReceipt {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // market
+        8 +  // amount
+        8 +  // collected_at
+        1;   // bump
+}
+
+/// Reserve paying out mis-resolved markets, funded by a share of platform
+/// fees and held as this account's lamports at the `insurance_fund` PDA of
+/// its factory
+#[account]
+pub struct InsuranceFund {
+    /// Factory whose fees fund it
+    pub factory: Pubkey,
+    /// Total moved in from the treasury in lamports
+    pub total_funded: u64,
+    /// Total paid out on claims in lamports
+    pub total_paid: u64,
+    /// Number of claims paid
+    pub claim_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // total_funded
+        8 +  // total_paid
+        8 +  // claim_count
+        1;   // bump
+}
+
+/// Authority ruling that a market resolved to the wrong outcome, stored at
+/// the `misresolution_ruling` PDA of the market
+#[account]
+pub struct MisresolutionRuling {
+    /// Factory the market belongs to
+    pub factory: Pubkey,
+    /// Mis-resolved market
+    pub market: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome it should have resolved to
+    pub correct_outcome: MatchOutcome,
+    /// Most one participant can claim in lamports
+    pub claim_cap: u64,
+    /// Most all claims on the market can pay in lamports
+    pub payout_cap: u64,
+    /// Paid out on claims so far in lamports
+    pub total_paid: u64,
+    /// Number of claims paid
+    pub claim_count: u32,
+    /// Ruling timestamp
+    pub ruled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MisresolutionRuling {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // market
+        MATCH_OUTCOME_SPACE + // resolved_outcome
+        MATCH_OUTCOME_SPACE + // correct_outcome
+        8 +  // claim_cap
+        8 +  // payout_cap
+        8 +  // total_paid
+        4 +  // claim_count
+        8 +  // ruled_at
+        1;   // bump
+}
+
+/// Insurance paid to one participant of a mis-resolved market, at the
+/// `insurance_claim` PDA of the market and user
+#[account]
+pub struct InsuranceClaim {
+    /// Mis-resolved market
+    pub market: Pubkey,
+    /// Participant compensated
+    pub user: Pubkey,
+    /// Lamports paid
+    pub amount: u64,
+    /// Claim timestamp
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsuranceClaim {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        8 +  // amount
+        8 +  // claimed_at
+        1;   // bump
+}
+
+// Context Structures
+
+/// Accounts for `initialize_factory`
+#[derive(Accounts)]
+pub struct InitializeFactory<'info> {
+    /// Factory PDA, created here
+    #[account(
+        init,
+        payer = authority,
+        space = Factory::LEN,
+        seeds = [b"factory"],
+        bump
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Becomes the factory authority and pays for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `create_platform`
+#[derive(Accounts)]
+#[instruction(platform_id: String)]
+pub struct CreatePlatform<'info> {
+    /// Platform PDA, created here
+    #[account(
+        init,
+        payer = operator,
+        space = Platform::LEN,
+        seeds = [b"platform", platform_id.as_bytes()],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// The platform's factory PDA, created here
+    #[account(
+        init,
+        payer = operator,
+        space = Factory::LEN,
+        seeds = [b"factory", platform.key().as_ref()],
+        bump
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Becomes the platform operator and factory authority and pays for both accounts
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for authority-only factory settings updates
+#[derive(Accounts)]
+pub struct UpdateFactoryConfig<'info> {
+    /// Factory being updated
+    #[account(
+        mut,
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Current factory authority
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for `set_results_signer`
+#[derive(Accounts)]
+pub struct SetResultsSigner<'info> {
+    /// Factory the signer resolves markets for
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Results signer PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ResultsSigner::LEN,
+        seeds = [b"results_signer", factory.key().as_ref()],
+        bump
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_wormhole_emitter`
+#[derive(Accounts)]
+pub struct SetWormholeEmitter<'info> {
+    /// Factory the emitter resolves markets for
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Wormhole emitter PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WormholeEmitter::LEN,
+        seeds = [b"wormhole_emitter", factory.key().as_ref()],
+        bump
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `publish_results`
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct PublishResults<'info> {
+    /// Factory the results are for
+    pub factory: Account<'info, Factory>,
+    
+    /// Results signer configured on the factory
+    #[account(
+        seeds = [b"results_signer", factory.key().as_ref()],
+        bump = results_signer.bump,
+        constraint = results_signer.signer == signer.key() @ FactoryError::NotResultsSigner
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Results batch, created here
+    #[account(
+        init,
+        payer = signer,
+        space = ResultsBatch::LEN,
+        seeds = [b"results_batch", factory.key().as_ref(), batch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub results_batch: Account<'info, ResultsBatch>,
+    
+    /// Results signer's key, paying for the batch
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `update_live_score`
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct UpdateLiveScore<'info> {
+    /// Factory the match is registered with
+    pub factory: Account<'info, Factory>,
+    
+    /// Results signer configured on the factory
+    #[account(
+        seeds = [b"results_signer", factory.key().as_ref()],
+        bump = results_signer.bump,
+        constraint = results_signer.signer == signer.key() @ FactoryError::NotResultsSigner
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Live score, created on the first update
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = LiveScore::LEN,
+        seeds = [b"live_score", factory.key().as_ref(), match_id_seed(&match_id).as_ref()],
+        bump
+    )]
+    pub live_score: Account<'info, LiveScore>,
+    
+    /// Results signer's key, paying for the live score
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_resolution_policy`
+#[derive(Accounts)]
+pub struct SetResolutionPolicy<'info> {
+    /// Factory whose markets the policy applies to
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Resolution policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ResolutionPolicy::LEN,
+        seeds = [b"resolution_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub resolution_policy: Account<'info, ResolutionPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_rebate_policy`
+#[derive(Accounts)]
+pub struct SetRebatePolicy<'info> {
+    /// Factory whose treasury funds the rebates
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Rebate policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RebatePolicy::LEN,
+        seeds = [b"rebate_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub rebate_policy: Account<'info, RebatePolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_discount_policy`
+#[derive(Accounts)]
+pub struct SetDiscountPolicy<'info> {
+    /// Factory whose treasury funds the discounts
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Discount policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscountPolicy::LEN,
+        seeds = [b"discount_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub discount_policy: Account<'info, DiscountPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_keeper_tip_policy`
+#[derive(Accounts)]
+pub struct SetKeeperTipPolicy<'info> {
+    /// Factory whose treasury funds the tips
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Keeper tip policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = KeeperTipPolicy::LEN,
+        seeds = [b"keeper_tip_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub keeper_tip_policy: Account<'info, KeeperTipPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_payout_swap_policy`
+#[derive(Accounts)]
+pub struct SetPayoutSwapPolicy<'info> {
+    /// Factory whose markets pay out swapped rewards
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Payout swap policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PayoutSwapPolicy::LEN,
+        seeds = [b"payout_swap_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub payout_swap_policy: Account<'info, PayoutSwapPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_receipt_policy`
+#[derive(Accounts)]
+pub struct SetReceiptPolicy<'info> {
+    /// Factory whose markets' claims get receipts
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Receipt policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReceiptPolicy::LEN,
+        seeds = [b"receipt_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub receipt_policy: Account<'info, ReceiptPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_resolution_grace_policy`
+#[derive(Accounts)]
+pub struct SetResolutionGracePolicy<'info> {
+    /// Factory whose markets' grace periods are bound
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Resolution grace policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ResolutionGracePolicy::LEN,
+        seeds = [b"resolution_grace_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub resolution_grace_policy: Account<'info, ResolutionGracePolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_match_id_policy`
+#[derive(Accounts)]
+pub struct SetMatchIdPolicy<'info> {
+    /// Factory whose match IDs are checked
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Match ID policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MatchIdPolicy::LEN,
+        seeds = [b"match_id_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub match_id_policy: Account<'info, MatchIdPolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_creation_rate_policy`
+#[derive(Accounts)]
+pub struct SetCreationRatePolicy<'info> {
+    /// Factory whose creators are limited
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Creation rate policy PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreationRatePolicy::LEN,
+        seeds = [CREATION_RATE_POLICY_SEED, factory.key().as_ref()],
+        bump
+    )]
+    pub creation_rate_policy: Account<'info, CreationRatePolicy>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_rake_free`
+#[derive(Accounts)]
+pub struct SetRakeFree<'info> {
+    /// Factory the market belongs to
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Market whose fees are waived
+    #[account(
+        seeds = [
+            b"market",
+            factory.key().as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Rake-free flag PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RakeFreeMarket::LEN,
+        seeds = [b"rake_free_market", market.key().as_ref()],
+        bump
+    )]
+    pub rake_free_market: Account<'info, RakeFreeMarket>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_governor`
+#[derive(Accounts)]
+pub struct SetGovernor<'info> {
+    /// Factory whose parameters the governor can change
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Platform config PDA, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlatformConfig::LEN,
+        seeds = [b"platform_config", factory.key().as_ref()],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_platform_param`
+#[derive(Accounts)]
+pub struct SetPlatformParam<'info> {
+    /// Factory holding the platform fee
+    #[account(mut)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Platform config holding the other parameters and the governor
+    #[account(
+        mut,
+        seeds = [b"platform_config", factory.key().as_ref()],
+        bump = platform_config.bump,
+        has_one = factory
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Factory authority or the governor
+    #[account(
+        constraint = caller.key() == factory.authority || caller.key() == platform_config.governor
+            @ FactoryError::Unauthorized
+    )]
+    pub caller: Signer<'info>,
+}
+
+/// Accounts for `add_tag`
+#[derive(Accounts)]
+#[instruction(category: TagCategory, tag: Tag)]
+pub struct AddTag<'info> {
+    /// Factory the tag is registered with
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Tag definition PDA, created here
+    #[account(
+        init,
+        payer = authority,
+        space = TagDefinition::LEN,
+        seeds = [b"tag", factory.key().as_ref(), &[category as u8], tag.as_ref()],
+        bump
+    )]
+    pub tag_definition: Account<'info, TagDefinition>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `remove_tag`
+#[derive(Accounts)]
+pub struct RemoveTag<'info> {
+    /// Factory the tag is registered with
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Tag definition being closed
+    #[account(
+        mut,
+        close = authority,
+        has_one = factory
+    )]
+    pub tag_definition: Account<'info, TagDefinition>,
+    
+    /// Current factory authority, receiving the rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for `create_market`
+#[derive(Accounts)]
+#[instruction(match_id: String, entry_fee: u64, kickoff_time: i64)]
+pub struct CreateMarket<'info> {
+    /// Factory whose market count is incremented
+    #[account(mut)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Registry entry for the match ID, created here
+    #[account(
+        init,
+        payer = creator,
+        space = MarketRegistry::LEN,
+        seeds = [
+            b"market_registry",
+            factory.key().as_ref(),
+            match_id_seed(&match_id).as_ref()
+        ],
+        bump
+    )]
+    pub market_registry: Account<'info, MarketRegistry>,
+    
+    /// CHECK: This is the market account that will be initialized by the market program
+    pub market_account: AccountInfo<'info>,
+    
+    /// Factory's listing of markets kicking off the same day, created by its first market
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = KickoffBucket::LEN,
+        seeds = [b"kickoff_bucket", factory.key().as_ref(), &kickoff_day(kickoff_time).to_le_bytes()],
+        bump
+    )]
+    pub kickoff_bucket: Account<'info, KickoffBucket>,
+    
+    /// CHECK: Factory match ID policy, read if the factory has set one
+    #[account(
+        seeds = [b"match_id_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub match_id_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory creation rate policy, read if the factory has set one
+    #[account(
+        seeds = [CREATION_RATE_POLICY_SEED, factory.key().as_ref()],
+        bump
+    )]
+    pub creation_rate_policy: UncheckedAccount<'info>,
+    
+    /// The creator's recent markets, created by their first market
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorRate::LEN,
+        seeds = [CREATOR_RATE_SEED, factory.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub creator_rate: Account<'info, CreatorRate>,
+    
+    /// Market creator, paying for the registry entry, any new kickoff bucket and their rate counter
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `create_market_series`
+///
+/// Each fixture's registry entry, market PDA and kickoff bucket follow as
+/// remaining accounts.
+#[derive(Accounts)]
+pub struct CreateMarketSeries<'info> {
+    /// Factory whose market count is incremented
+    #[account(mut)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Market creator, paying for every registry entry and market
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// CHECK: Platform stats PDA, checked by the market program
+    #[account(mut)]
+    pub platform_stats: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's daily activity PDA, checked by the market program
+    #[account(mut)]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator stats PDA, checked by the market program
+    #[account(mut)]
+    pub creator_stats: UncheckedAccount<'info>,
+    
+    /// CHECK: Dashboard program, checked by the market program
+    pub dashboard_program: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator's points ledger PDA, checked by the market program
+    #[account(mut)]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, checked by the market program
+    #[account(mut)]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: Resolution grace policy PDA, checked by the market program
+    pub resolution_grace_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory match ID policy, read if the factory has set one
+    #[account(
+        seeds = [b"match_id_policy", factory.key().as_ref()],
+        bump
+    )]
+    pub match_id_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory creation rate policy, read if the factory has set one
+    #[account(
+        seeds = [CREATION_RATE_POLICY_SEED, factory.key().as_ref()],
+        bump
+    )]
+    pub creation_rate_policy: UncheckedAccount<'info>,
+    
+    /// The creator's recent markets, created by their first market
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorRate::LEN,
+        seeds = [CREATOR_RATE_SEED, factory.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub creator_rate: Account<'info, CreatorRate>,
+    
+    /// CHECK: Market program initializing each market
+    #[account(address = MARKET_PROGRAM_ID)]
+    pub market_program: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, passed on to the market program
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `migrate_market_registry`
+#[derive(Accounts)]
+pub struct MigrateMarketRegistry<'info> {
+    /// CHECK: Deserialized manually since the older layout doesn't match MarketRegistry
+    #[account(mut, owner = crate::ID)]
+    pub market_registry: UncheckedAccount<'info>,
+    
+    /// Pays for the extra space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `invalidate_registry`
+#[derive(Accounts)]
+pub struct InvalidateRegistry<'info> {
+    /// Factory that registered the market
+    #[account(mut)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Orphaned registry entry, closed to its creator
+    #[account(
+        mut,
+        has_one = factory,
+        has_one = creator,
+        close = creator,
+        seeds = [
+            b"market_registry",
+            factory.key().as_ref(),
+            match_id_seed(&market_registry.match_id).as_ref()
+        ],
+        bump = market_registry.bump
+    )]
+    pub market_registry: Account<'info, MarketRegistry>,
+    
+    /// CHECK: Market PDA for the registry's match ID, which must hold no account
+    #[account(
+        seeds = [
+            b"market",
+            factory.key().as_ref(),
+            match_id_seed(&market_registry.match_id).as_ref()
+        ],
+        bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator who paid for the registry entry, receiving its rent
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+}
+
+/// Accounts for `record_platform_fee`
+#[derive(Accounts)]
+pub struct RecordPlatformFee<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Treasury PDA, created on the first fee
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Treasury::LEN,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Receipt for this market's fee, created here
+    #[account(
+        init,
+        payer = payer,
+        space = FeeReceipt::LEN,
+        seeds = [b"fee_receipt", market.key().as_ref()],
+        bump
+    )]
+    pub fee_receipt: Account<'info, FeeReceipt>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Pays for the receipt and, on first use, the treasury
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `withdraw_treasury`
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Factory owning the treasury
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Treasury being drawn down
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Insurance fund taking its share first, created on first use
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = InsuranceFund::LEN,
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// CHECK: Platform config, read if it exists to hold withdrawals to its treasury recipient
+    #[account(seeds = [b"platform_config", factory.key().as_ref()], bump)]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Account chosen by the authority to receive the fees, checked
+    /// against the platform config's treasury recipient
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Current factory authority, paying for the fund on first use
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `pay_streak_rebate`
+#[derive(Accounts)]
+pub struct PayStreakRebate<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Rebate terms and this epoch's spending
+    #[account(
+        mut,
+        seeds = [b"rebate_policy", factory.key().as_ref()],
+        bump = rebate_policy.bump,
+        has_one = factory
+    )]
+    pub rebate_policy: Account<'info, RebatePolicy>,
+    
+    /// Treasury paying the rebate
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Insurance fund, read if it exists so its unswept share stays in the treasury
+    #[account(seeds = [b"insurance_fund", factory.key().as_ref()], bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: User receiving the rebate, vouched for by the signing market
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+}
+
+/// Accounts for `pay_fee_discount`
+#[derive(Accounts)]
+pub struct PayFeeDiscount<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Discount tiers and this epoch's spending
+    #[account(
+        mut,
+        seeds = [b"discount_policy", factory.key().as_ref()],
+        bump = discount_policy.bump,
+        has_one = factory
+    )]
+    pub discount_policy: Account<'info, DiscountPolicy>,
+    
+    /// Treasury paying the discount
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Insurance fund, read if it exists so its unswept share stays in the treasury
+    #[account(seeds = [b"insurance_fund", factory.key().as_ref()], bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: User receiving the discount, vouched for by the signing market
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+}
+
+/// Accounts for `pay_keeper_tip`
+#[derive(Accounts)]
+pub struct PayKeeperTip<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
     
-    pub const LEN: usize = 8 + // discriminator
-        32 + // factory
-        32 + // market_address
-        32 + // creator
-        4 + Self::MAX_MATCH_ID_LEN + // match_id (String with length prefix)
-        8 +  // created_at
-        1 +  // is_public
-        8 +  // entry_fee
-        8 +  // kickoff_time
-        8 +  // end_time
-        1;   // bump
+    /// Tip terms and lifetime spending
+    #[account(
+        mut,
+        seeds = [b"keeper_tip_policy", factory.key().as_ref()],
+        bump = keeper_tip_policy.bump,
+        has_one = factory
+    )]
+    pub keeper_tip_policy: Account<'info, KeeperTipPolicy>,
+    
+    /// The market's tips so far, created on its first tip
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = KeeperTips::LEN,
+        seeds = [b"keeper_tips", market.key().as_ref()],
+        bump
+    )]
+    pub keeper_tips: Account<'info, KeeperTips>,
+    
+    /// Treasury paying the tip
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Insurance fund, read if it exists so its unswept share stays in the treasury
+    #[account(seeds = [b"insurance_fund", factory.key().as_ref()], bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program
+    #[account(
+        constraint = market.owner == &MARKET_PROGRAM_ID @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Keeper that ran the crank, fronting the tips account rent on the market's first tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
-// Context Structures
-
+/// Accounts for `create_voucher_campaign`
 #[derive(Accounts)]
-pub struct InitializeFactory<'info> {
+#[instruction(campaign_id: u64)]
+pub struct CreateVoucherCampaign<'info> {
+    /// Factory whose treasury funds the vouchers
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Voucher campaign PDA, created here
     #[account(
         init,
         payer = authority,
-        space = Factory::LEN,
-        seeds = [b"factory"],
+        space = VoucherCampaign::LEN,
+        seeds = [b"voucher_campaign", factory.key().as_ref(), campaign_id.to_le_bytes().as_ref()],
         bump
     )]
+    pub voucher_campaign: Account<'info, VoucherCampaign>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `issue_voucher`
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct IssueVoucher<'info> {
+    /// Factory running the campaign
+    #[account(
+        has_one = authority @ FactoryError::Unauthorized
+    )]
     pub factory: Account<'info, Factory>,
     
+    /// Campaign the voucher is issued from
+    #[account(
+        mut,
+        seeds = [
+            b"voucher_campaign",
+            factory.key().as_ref(),
+            voucher_campaign.campaign_id.to_le_bytes().as_ref()
+        ],
+        bump = voucher_campaign.bump,
+        has_one = factory
+    )]
+    pub voucher_campaign: Account<'info, VoucherCampaign>,
+    
+    /// Voucher PDA, created here; a holder gets at most one per campaign
+    #[account(
+        init,
+        payer = authority,
+        space = Voucher::LEN,
+        seeds = [b"voucher", voucher_campaign.key().as_ref(), holder.as_ref()],
+        bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+    
+    /// Current factory authority, paying for the voucher
     #[account(mut)]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts for `redeem_voucher`
 #[derive(Accounts)]
-#[instruction(match_id: String)]
-pub struct CreateMarket<'info> {
+pub struct RedeemVoucher<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Campaign funding the voucher
     #[account(
         mut,
-        seeds = [b"factory"],
-        bump = factory.bump
+        seeds = [
+            b"voucher_campaign",
+            factory.key().as_ref(),
+            voucher_campaign.campaign_id.to_le_bytes().as_ref()
+        ],
+        bump = voucher_campaign.bump,
+        has_one = factory
+    )]
+    pub voucher_campaign: Account<'info, VoucherCampaign>,
+    
+    /// The user's voucher from the campaign
+    #[account(
+        mut,
+        seeds = [b"voucher", voucher_campaign.key().as_ref(), user.key().as_ref()],
+        bump = voucher.bump,
+        constraint = !voucher.is_redeemed() @ FactoryError::VoucherRedeemed
     )]
+    pub voucher: Account<'info, Voucher>,
+    
+    /// Treasury paying the entry fee
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Insurance fund, read if it exists so its unswept share stays in the treasury
+    #[account(seeds = [b"insurance_fund", factory.key().as_ref()], bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Market PDA signing via the market program, receiving the entry fee
+    #[account(
+        mut,
+        constraint = market.owner == &MARKET_PROGRAM_ID @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Signer<'info>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: Voucher holder joining the market, vouched for by the signing market
+    pub user: UncheckedAccount<'info>,
+}
+
+/// Accounts for `rule_misresolution`
+#[derive(Accounts)]
+pub struct RuleMisresolution<'info> {
+    /// Factory the market belongs to
     pub factory: Account<'info, Factory>,
     
+    /// Resolved market being ruled on
+    #[account(
+        seeds = [
+            b"market",
+            factory.key().as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Ruling PDA, one per market
     #[account(
         init,
-        payer = creator,
-        space = MarketRegistry::LEN,
+        payer = payer,
+        space = MisresolutionRuling::LEN,
+        seeds = [b"misresolution_ruling", market.key().as_ref()],
+        bump
+    )]
+    pub ruling: Account<'info, MisresolutionRuling>,
+    
+    /// Insurance fund paying the claims, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = InsuranceFund::LEN,
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// CHECK: Platform config, read if it exists for its dispute window and governor
+    #[account(seeds = [b"platform_config", factory.key().as_ref()], bump)]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// Factory authority or the platform config's governor
+    pub ruler: Signer<'info>,
+    
+    /// Pays for the ruling
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `record_result_correction`
+#[derive(Accounts)]
+pub struct RecordResultCorrection<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Corrected market, its PDA signing via the market program
+    #[account(
         seeds = [
-            b"market_registry",
+            b"market",
             factory.key().as_ref(),
-            match_id.as_bytes()
+            match_id_seed(&market.match_id).as_ref()
         ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID,
+        signer @ FactoryError::UnauthorizedCaller
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Ruling PDA, one per market
+    #[account(
+        init,
+        payer = payer,
+        space = MisresolutionRuling::LEN,
+        seeds = [b"misresolution_ruling", market.key().as_ref()],
         bump
     )]
-    pub market_registry: Account<'info, MarketRegistry>,
+    pub ruling: Account<'info, MisresolutionRuling>,
     
-    /// CHECK: This is the market account that will be initialized by the market program
-    pub market_account: AccountInfo<'info>,
+    /// Insurance fund paying the claims, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = InsuranceFund::LEN,
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// CHECK: The instructions sysvar, showing the market program made the call
+    #[account(
+        address = instructions_sysvar::ID,
+        constraint = invoked_by(&instructions, &[MARKET_PROGRAM_ID]) @ FactoryError::UnauthorizedCaller
+    )]
+    pub instructions: UncheckedAccount<'info>,
     
+    /// Results signer correcting the result, paying for the ruling
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub payer: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts for `insurance_claim`
 #[derive(Accounts)]
-pub struct GetMarkets<'info> {
+pub struct ClaimInsurance<'info> {
+    /// Factory the market belongs to
+    pub factory: Account<'info, Factory>,
+    
+    /// Treasury topping up the fund before it pays
     #[account(
-        seeds = [b"factory"],
-        bump = factory.bump
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump = treasury.bump,
+        has_one = factory
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Insurance fund paying the claim
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump = insurance_fund.bump,
+        has_one = factory
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// Mis-resolved market
+    #[account(
+        seeds = [
+            b"market",
+            factory.key().as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Ruling on the market
+    #[account(
+        mut,
+        seeds = [b"misresolution_ruling", market.key().as_ref()],
+        bump = ruling.bump,
+        has_one = market
+    )]
+    pub ruling: Account<'info, MisresolutionRuling>,
+    
+    /// Claimant's entry in the market
+    #[account(
+        seeds = [b"participant", market.key().as_ref(), claimant.key().as_ref()],
+        bump = participant.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Record of the claim, so each participant claims once
+    #[account(
+        init,
+        payer = claimant,
+        space = InsuranceClaim::LEN,
+        seeds = [b"insurance_claim", market.key().as_ref(), claimant.key().as_ref()],
+        bump
     )]
+    pub claim: Account<'info, InsuranceClaim>,
+    
+    /// Participant being compensated
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the `get_markets` view
+#[derive(Accounts)]
+pub struct GetMarkets<'info> {
+    /// The factory PDA
     pub factory: Account<'info, Factory>,
 }
 
+// Instruction Arguments
+
+/// One match in a `create_market_series` call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SeriesFixture {
+    /// Match identifier (e.g., "EPL-2024-123")
+    pub match_id: String,
+    /// Match kickoff time
+    pub kickoff_time: i64,
+    /// Match end time
+    pub end_time: i64,
+}
+
+/// Platform parameter changed by `set_platform_param`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlatformParam {
+    /// `Factory::platform_fee_bps`, at most 1000
+    PlatformFeeBps(u16),
+    /// `PlatformConfig::dispute_window` in seconds, not negative
+    DisputeWindow(i64),
+    /// `PlatformConfig::treasury_recipient`
+    TreasuryRecipient(Pubkey),
+}
+
+impl PlatformParam {
+    /// Serialized size of the largest variant
+    pub const SPACE: usize = 1 + 32;
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            PlatformParam::PlatformFeeBps(bps) => *bps <= 1000,
+            PlatformParam::DisputeWindow(seconds) => *seconds >= 0,
+            PlatformParam::TreasuryRecipient(_) => true,
+        }
+    }
+}
+
 // Events
 
+/// Emitted when a market is registered with the factory
 #[event]
 pub struct MarketCreated {
+    /// Market account address
     #[index]
     pub market: Pubkey,
+    /// Creator of the market
     #[index]
     pub creator: Pubkey,
+    /// Match identifier
     pub match_id: String,
+    /// Entry fee in lamports
     pub entry_fee: u64,
+    /// Match kickoff timestamp
     pub kickoff_time: i64,
+    /// Whether market is public
     pub is_public: bool,
 }
 
+/// Emitted when the results signer publishes a batch of match results
+#[event]
+pub struct ResultsPublished {
+    /// Results batch account
+    #[index]
+    pub results_batch: Pubkey,
+    /// Caller-chosen batch identifier
+    pub batch_id: u64,
+    /// Results in the batch
+    pub result_count: u8,
+}
+
+/// Emitted when the results signer reports a match's score
+#[event]
+pub struct LiveScoreUpdated {
+    /// Live score account
+    #[index]
+    pub live_score: Pubkey,
+    /// Match identifier
+    pub match_id: String,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Minute of play
+    pub minute: u16,
+    /// Whether this is the final score
+    pub is_final: bool,
+}
+
+/// Emitted when a resolved market's platform fee is recorded in the treasury
+#[event]
+pub struct PlatformFeeCollected {
+    /// Market that paid the fee
+    #[index]
+    pub market: Pubkey,
+    /// Fee in lamports
+    pub amount: u64,
+    /// Lifetime fees recorded in lamports
+    pub total_collected: u64,
+}
+
+/// Emitted when the factory authority withdraws from the treasury
+#[event]
+pub struct TreasuryWithdrawn {
+    /// Account receiving the fees
+    #[index]
+    pub recipient: Pubkey,
+    /// Lamports withdrawn
+    pub amount: u64,
+    /// Lifetime withdrawals in lamports
+    pub total_withdrawn: u64,
+}
+
+/// Emitted when the treasury rebates part of an entry fee to a user on a losing streak
+#[event]
+pub struct StreakRebatePaid {
+    /// User receiving the rebate
+    #[index]
+    pub user: Pubkey,
+    /// Market the entry was made in
+    pub market: Pubkey,
+    /// Lamports rebated
+    pub amount: u64,
+    /// Lamports rebated so far this epoch
+    pub epoch_paid: u64,
+}
+
+/// Emitted when the treasury pays a reputation or badge holder their entry fee discount
+#[event]
+pub struct FeeDiscountPaid {
+    /// User receiving the discount
+    #[index]
+    pub user: Pubkey,
+    /// Market the entry was made in
+    pub market: Pubkey,
+    /// Discount of the user's tier, in basis points
+    pub discount_bps: u16,
+    /// Lamports discounted
+    pub amount: u64,
+    /// Lamports discounted so far this epoch
+    pub epoch_paid: u64,
+}
+
+/// Emitted when the treasury tips a keeper for running a market crank
+#[event]
+pub struct KeeperTipPaid {
+    /// Keeper receiving the tip
+    #[index]
+    pub keeper: Pubkey,
+    /// Market whose crank was run
+    pub market: Pubkey,
+    /// Lamports tipped
+    pub amount: u64,
+    /// Lamports the market has tipped so far
+    pub market_paid: u64,
+}
+
+/// Emitted when the treasury covers a market entry fee for a voucher holder
+#[event]
+pub struct VoucherRedeemed {
+    /// Holder whose entry the voucher covered
+    #[index]
+    pub holder: Pubkey,
+    /// Market the entry was made in
+    pub market: Pubkey,
+    /// Campaign funding the voucher
+    pub campaign: Pubkey,
+    /// Lamports covered
+    pub amount: u64,
+    /// Lamports the campaign has covered so far
+    pub campaign_spent: u64,
+}
+
+/// Emitted when the insurance fund's share of platform fees leaves the treasury
+#[event]
+pub struct InsuranceFunded {
+    /// Lamports moved into the fund
+    pub amount: u64,
+    /// Lifetime funding in lamports
+    pub total_funded: u64,
+}
+
+/// Emitted when the factory authority, the governor or a result correction
+/// rules a market mis-resolved
+#[event]
+pub struct MisresolutionRuled {
+    /// Mis-resolved market
+    #[index]
+    pub market: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome it should have resolved to
+    pub correct_outcome: MatchOutcome,
+    /// Most one participant can claim in lamports
+    pub claim_cap: u64,
+    /// Most all claims can pay in lamports
+    pub payout_cap: u64,
+}
+
+/// Emitted when the insurance fund compensates a participant
+#[event]
+pub struct InsuranceClaimPaid {
+    /// Mis-resolved market
+    #[index]
+    pub market: Pubkey,
+    /// Participant compensated
+    pub user: Pubkey,
+    /// Lamports paid
+    pub amount: u64,
+    /// Fund balance left above rent in lamports
+    pub fund_balance: u64,
+}
+
+/// Emitted when an orphaned registry entry is closed
+#[event]
+pub struct MarketRegistryInvalidated {
+    /// Closed registry entry
+    #[index]
+    pub market_registry: Pubkey,
+    /// Market PDA that was never initialized
+    pub market: Pubkey,
+    /// Creator refunded the entry's rent
+    pub creator: Pubkey,
+    /// Match identifier
+    pub match_id: String,
+}
+
+/// Emitted when the factory authority or the governor changes a platform parameter
+#[event]
+pub struct PlatformParamSet {
+    /// New parameter value
+    pub param: PlatformParam,
+    /// Factory authority or governor that made the change
+    pub caller: Pubkey,
+}
+
 // Return Types
 
+/// Market summary returned by `get_markets`
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MarketInfo {
+    /// Market account address
     pub market_address: Pubkey,
+    /// Creator of the market
     pub creator: Pubkey,
+    /// Match identifier
     pub match_id: String,
+    /// Timestamp when market was created
     pub created_at: i64,
+    /// Whether market is public or private
     pub is_public: bool,
+    /// Entry fee in lamports
     pub entry_fee: u64,
 }
 
@@ -266,4 +4090,92 @@ pub enum FactoryError {
     InvalidEndTime,
     #[msg("Market count overflow")]
     MarketCountOverflow,
+    #[msg("Only the factory authority can perform this action")]
+    Unauthorized,
+    #[msg("Tags are 1-16 lowercase letters, digits or hyphens")]
+    InvalidTag,
+    #[msg("Every tag must be registered with the factory for its category")]
+    UnregisteredTag,
+    #[msg("Account is not a market registry entry")]
+    InvalidRegistryAccount,
+    #[msg("Market registry entry already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("A market series needs 1-10 fixtures")]
+    InvalidSeriesLength,
+    #[msg("Each fixture needs its registry entry and market accounts, in order")]
+    InvalidSeriesAccounts,
+    #[msg("Caller is not authorized to record platform fees")]
+    UnauthorizedCaller,
+    #[msg("Treasury totals overflow")]
+    TreasuryOverflow,
+    #[msg("Treasury holds less than the requested amount")]
+    InsufficientTreasuryFunds,
+    #[msg("Market has not been resolved")]
+    MarketNotResolved,
+    #[msg("Ruling must name an outcome other than the one the market resolved to")]
+    InvalidRuling,
+    #[msg("Claim cap must be non-zero and no larger than the payout cap")]
+    InvalidInsuranceCap,
+    #[msg("Only participants who predicted the ruled outcome can claim")]
+    NotAffectedByRuling,
+    #[msg("Ruling's payout cap has been reached")]
+    InsuranceCapReached,
+    #[msg("Insurance fund holds less than the claim")]
+    InsufficientInsuranceFunds,
+    #[msg("A results batch needs 1-16 results with distinct match IDs")]
+    InvalidResultsBatch,
+    #[msg("Only the factory's results signer can publish results")]
+    NotResultsSigner,
+    #[msg("Rebate cannot exceed 100% (10000 bps) of the entry fee")]
+    InvalidRebatePolicy,
+    #[msg("Platform fee cannot exceed 10% (1000 bps) and the dispute window cannot be negative")]
+    InvalidPlatformParam,
+    #[msg("Treasury withdrawals must go to the platform config's treasury recipient")]
+    InvalidTreasuryRecipient,
+    #[msg("Market's dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Wormhole emitter needs a non-zero chain ID and address")]
+    InvalidWormholeEmitter,
+    #[msg("Discount tiers need a reputation or badge threshold and at most 10000 bps, with at most 4 tiers")]
+    InvalidDiscountPolicy,
+    #[msg("A keeper tip can't exceed the per-market tip budget")]
+    InvalidKeeperTipPolicy,
+    #[msg("Live score is already final")]
+    LiveScoreFinal,
+    #[msg("Payout swaps allow at most 8 mints, none of them the default pubkey")]
+    InvalidPayoutSwapPolicy,
+    #[msg("Receipt URI base is longer than 64 bytes")]
+    InvalidReceiptPolicy,
+    #[msg("Resolution grace bounds need 0 <= min <= max <= 7 days")]
+    InvalidResolutionGracePolicy,
+    #[msg("Platform ID must be 1 to 32 bytes")]
+    InvalidPlatformId,
+    #[msg("Registry entry is still within its grace period for the market to be initialized")]
+    RegistryGracePeriodActive,
+    #[msg("Market for this registry entry has been initialized")]
+    MarketInitialized,
+    #[msg("Match ID contains characters the factory doesn't allow")]
+    InvalidMatchIdCharacters,
+    #[msg("Match ID policy needs at least one printable ASCII character other than space")]
+    InvalidMatchIdPolicy,
+    #[msg("Market has already been resolved")]
+    MarketAlreadyResolved,
+    #[msg("Rake-free markets can't start charging fees once they have entries")]
+    RakeFreeLocked,
+    #[msg("The kickoff bucket for this day is full")]
+    KickoffBucketFull,
+    #[msg("Voucher campaigns need a non-zero max entry fee within the budget and a future expiry")]
+    InvalidVoucherCampaign,
+    #[msg("Voucher campaign has expired")]
+    VoucherExpired,
+    #[msg("Voucher has already been redeemed")]
+    VoucherRedeemed,
+    #[msg("Market's entry fee is above the most the voucher covers")]
+    VoucherEntryFeeTooHigh,
+    #[msg("Voucher campaign's budget can't cover the entry fee")]
+    VoucherBudgetExhausted,
+    #[msg("Creation rate window must be positive")]
+    InvalidCreationRatePolicy,
+    #[msg("Creator has created too many markets recently")]
+    CreationRateLimited,
 }
@@ -0,0 +1,1576 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::InstructionData;
+use cryptoscore_common::fees::BPS_DENOMINATOR;
+use cryptoscore_common::jury::{
+    committed_slot_hash, draw_jurors, jury_seed, SlotHashLookup, JURY_DRAW_DELAY_SLOTS,
+};
+use cryptoscore_common::pda::match_id_seed;
+use cryptoscore_common::{
+    MatchOutcome, DISCRIMINATOR_LEN, FACTORY_PROGRAM_ID, MARKET_PROGRAM_ID, MATCH_OUTCOME_SPACE,
+};
+use cryptoscore_factory::program::CryptoscoreFactory;
+use cryptoscore_factory::{Factory, PlatformConfig, PlatformParam};
+use cryptoscore_market_interface::Market;
+
+declare_id!("CryTSDmAGaocxE6SdUMiQmfnawgZPmHq9cDGUTeCsa5b");
+
+/// Most arbiters a court's pool holds
+pub const MAX_ARBITERS: usize = 32;
+
+/// Largest jury a court can draw
+pub const MAX_JURY_SIZE: usize = 9;
+
+#[program]
+pub mod cryptoscore_governance {
+    use super::*;
+
+    /// Put the factory's platform parameters under stake-weighted governance
+    ///
+    /// Proposals are open for voting for `voting_period` seconds and, once
+    /// passed, can be executed `timelock` seconds after voting ends. A
+    /// proposal passes with more stake for than against and at least
+    /// `quorum_bps` of the stake at its creation voting. Proposals only take
+    /// effect after the factory authority makes the governance PDA the
+    /// factory's governor with `set_governor`.
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        voting_period: i64,
+        timelock: i64,
+        quorum_bps: u16,
+        min_proposal_stake: u64,
+    ) -> Result<()> {
+        require!(
+            voting_period > 0 && timelock >= 0 && quorum_bps as u64 <= BPS_DENOMINATOR,
+            GovernanceError::InvalidGovernanceConfig
+        );
+        
+        let governance = &mut ctx.accounts.governance;
+        governance.factory = ctx.accounts.factory.key();
+        governance.voting_period = voting_period;
+        governance.timelock = timelock;
+        governance.quorum_bps = quorum_bps;
+        governance.min_proposal_stake = min_proposal_stake;
+        governance.total_staked = 0;
+        governance.proposal_count = 0;
+        governance.bump = ctx.bumps.governance;
+        
+        msg!("Governance initialized: {}s voting, {}s timelock, {} bps quorum", voting_period, timelock, quorum_bps);
+        
+        Ok(())
+    }
+
+    /// Stake `amount` lamports for voting power, held in the staker's stake position
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, GovernanceError::InvalidStakeAmount);
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.stake_position.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let position = &mut ctx.accounts.stake_position;
+        
+        // Initialize if first time
+        if position.user == Pubkey::default() {
+            position.governance = ctx.accounts.governance.key();
+            position.user = ctx.accounts.user.key();
+            position.bump = ctx.bumps.stake_position;
+        }
+        
+        position.amount = position.amount.checked_add(amount)
+            .ok_or(GovernanceError::StakeOverflow)?;
+        let governance = &mut ctx.accounts.governance;
+        governance.total_staked = governance.total_staked.checked_add(amount)
+            .ok_or(GovernanceError::StakeOverflow)?;
+        
+        msg!("Staked {} lamports for {}, {} in total", amount, position.user, position.amount);
+        
+        Ok(())
+    }
+
+    /// Withdraw `amount` staked lamports once voting has ended on every
+    /// proposal the stake voted on
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, GovernanceError::InvalidStakeAmount);
+        
+        let position = &mut ctx.accounts.stake_position;
+        require!(Clock::get()?.unix_timestamp >= position.locked_until, GovernanceError::StakeLocked);
+        require_gte!(position.amount, amount, GovernanceError::InsufficientStake);
+        
+        **position.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.user.try_borrow_mut_lamports()? += amount;
+        
+        position.amount -= amount;
+        let governance = &mut ctx.accounts.governance;
+        governance.total_staked = governance.total_staked.saturating_sub(amount);
+        
+        msg!("Unstaked {} lamports for {}, {} left", amount, position.user, position.amount);
+        
+        Ok(())
+    }
+
+    /// Propose changing one platform parameter, holding at least the
+    /// governance's minimum proposal stake
+    pub fn create_proposal(ctx: Context<CreateProposal>, param: PlatformParam) -> Result<()> {
+        require!(param.is_valid(), GovernanceError::InvalidProposal);
+        let governance = &mut ctx.accounts.governance;
+        require_gte!(
+            ctx.accounts.stake_position.amount,
+            governance.min_proposal_stake,
+            GovernanceError::InsufficientStake
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.governance = governance.key();
+        proposal.id = governance.proposal_count;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.param = param;
+        proposal.quorum_votes =
+            (governance.total_staked as u128 * governance.quorum_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        proposal.votes_for = 0;
+        proposal.votes_against = 0;
+        proposal.created_at = now;
+        proposal.voting_ends_at = now.saturating_add(governance.voting_period);
+        proposal.executable_at = proposal.voting_ends_at.saturating_add(governance.timelock);
+        proposal.executed = false;
+        proposal.bump = ctx.bumps.proposal;
+        
+        governance.proposal_count = governance.proposal_count.checked_add(1)
+            .ok_or(GovernanceError::ProposalCountOverflow)?;
+        
+        emit!(ProposalCreated {
+            proposal: proposal.key(),
+            id: proposal.id,
+            proposer: proposal.proposer,
+            param,
+            voting_ends_at: proposal.voting_ends_at,
+            executable_at: proposal.executable_at,
+        });
+        
+        msg!("Proposal {} created: {:?}", proposal.id, param);
+        
+        Ok(())
+    }
+
+    /// Vote on a proposal with all of the voter's stake
+    ///
+    /// The stake can't be withdrawn until voting on the proposal ends, so it
+    /// can't vote again from another wallet.
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(now < proposal.voting_ends_at, GovernanceError::VotingClosed);
+        
+        let position = &mut ctx.accounts.stake_position;
+        let weight = position.amount;
+        require_gt!(weight, 0, GovernanceError::NoVotingPower);
+        
+        let tally = if approve { &mut proposal.votes_for } else { &mut proposal.votes_against };
+        *tally = tally.checked_add(weight).ok_or(GovernanceError::VoteOverflow)?;
+        position.locked_until = position.locked_until.max(proposal.voting_ends_at);
+        
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.approve = approve;
+        vote_record.weight = weight;
+        vote_record.voted_at = now;
+        vote_record.bump = ctx.bumps.vote_record;
+        
+        emit!(VoteCast {
+            proposal: proposal.key(),
+            voter: vote_record.voter,
+            approve,
+            weight,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+        });
+        
+        msg!("{} voted {} on proposal {} with {} lamports", vote_record.voter, approve, proposal.id, weight);
+        
+        Ok(())
+    }
+
+    /// Execute a passed proposal once its timelock has run out, changing the
+    /// factory parameter through a CPI signed by the governance PDA
+    ///
+    /// Anyone can execute a proposal, and each executes once.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+        require!(Clock::get()?.unix_timestamp >= proposal.executable_at, GovernanceError::TimelockActive);
+        require!(proposal.passed(), GovernanceError::ProposalNotPassed);
+        
+        set_platform_param(
+            &ctx.accounts.governance,
+            &ctx.accounts.factory,
+            &ctx.accounts.platform_config,
+            &ctx.accounts.factory_program,
+            proposal.param,
+        )?;
+        
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.executed = true;
+        
+        emit!(ProposalExecuted {
+            proposal: proposal.key(),
+            id: proposal.id,
+            param: proposal.param,
+        });
+        
+        msg!("Proposal {} executed", proposal.id);
+        
+        Ok(())
+    }
+
+    /// Set up a court that hears disputes over market resolutions with juries
+    /// drawn from its staked arbiters
+    ///
+    /// Resolutions the court overturns are ruled mis-resolved on the factory
+    /// with the governance PDA as the ruler, so they only take effect once it
+    /// is the factory's governor.
+    pub fn initialize_court(ctx: Context<InitializeCourt>, terms: CourtTerms) -> Result<()> {
+        require!(terms.is_valid(), GovernanceError::InvalidCourtConfig);
+        
+        let court = &mut ctx.accounts.court;
+        court.governance = ctx.accounts.governance.key();
+        court.terms = terms;
+        court.arbiters = Vec::new();
+        court.bump = ctx.bumps.court;
+        
+        msg!(
+            "Court initialized: juries of {} with {}s to vote, {} bps slashed",
+            court.terms.jury_size,
+            court.terms.voting_period,
+            court.terms.slash_bps
+        );
+        
+        Ok(())
+    }
+
+    /// Stake `amount` lamports as an arbiter, joining the court's pool once
+    /// the stake reaches its minimum
+    pub fn join_court(ctx: Context<JoinCourt>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, GovernanceError::InvalidStakeAmount);
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.arbiter.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let arbiter = &mut ctx.accounts.arbiter;
+        
+        // Initialize if first time
+        if arbiter.user == Pubkey::default() {
+            arbiter.court = ctx.accounts.court.key();
+            arbiter.user = ctx.accounts.user.key();
+            arbiter.bump = ctx.bumps.arbiter;
+        }
+        
+        arbiter.stake = arbiter.stake.checked_add(amount)
+            .ok_or(GovernanceError::StakeOverflow)?;
+        
+        let court = &mut ctx.accounts.court;
+        require_gte!(arbiter.stake, court.terms.min_arbiter_stake, GovernanceError::InsufficientStake);
+        if !court.arbiters.contains(&arbiter.user) {
+            require!(court.arbiters.len() < MAX_ARBITERS, GovernanceError::ArbiterPoolFull);
+            court.arbiters.push(arbiter.user);
+        }
+        
+        msg!("{} staked {} lamports as an arbiter, {} in total", arbiter.user, amount, arbiter.stake);
+        
+        Ok(())
+    }
+
+    /// Leave the court's pool and withdraw the whole arbiter stake, once the
+    /// arbiter sits on no undecided jury
+    pub fn leave_court(ctx: Context<LeaveCourt>) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        ctx.accounts.court.arbiters.retain(|arbiter| *arbiter != user);
+        
+        msg!("{} left the court with {} lamports staked", user, ctx.accounts.arbiter.stake);
+        
+        Ok(())
+    }
+
+    /// Dispute a resolved market's outcome, claiming it should have been
+    /// `claimed_outcome`, by paying the court's dispute fee
+    ///
+    /// Disputes have to be opened within the platform config's dispute
+    /// window. The fee goes to the jurors who vote with the verdict. The jury
+    /// will be drawn from the hash of a slot `JURY_DRAW_DELAY_SLOTS` later.
+    pub fn open_dispute(ctx: Context<OpenDispute>, claimed_outcome: MatchOutcome) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status.is_resolved(), GovernanceError::MarketNotResolved);
+        let resolved_outcome = market.outcome.clone().ok_or(GovernanceError::MarketNotResolved)?;
+        require!(resolved_outcome != claimed_outcome, GovernanceError::InvalidDispute);
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        if let Some(config) = read_platform_config(&ctx.accounts.platform_config)? {
+            require!(config.dispute_open(market.end_time, now), GovernanceError::DisputeWindowClosed);
+        }
+        
+        let court = &ctx.accounts.court;
+        let disputer = ctx.accounts.disputer.key();
+        let available = court.arbiters.iter().filter(|arbiter| **arbiter != disputer).count();
+        require_gte!(available, court.terms.jury_size as usize, GovernanceError::NotEnoughArbiters);
+        
+        let fee = court.terms.dispute_fee;
+        if fee > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.disputer.to_account_info(),
+                        to: ctx.accounts.dispute.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+        
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.court = court.key();
+        dispute.market = market.key();
+        dispute.disputer = disputer;
+        dispute.resolved_outcome = resolved_outcome.clone();
+        dispute.claimed_outcome = claimed_outcome.clone();
+        dispute.fee = fee;
+        dispute.opened_at = now;
+        dispute.status = DisputeStatus::AwaitingJury;
+        dispute.draw_slot = clock.slot.saturating_add(JURY_DRAW_DELAY_SLOTS);
+        dispute.randomness = [0; 32];
+        dispute.jurors = Vec::new();
+        dispute.votes = Vec::new();
+        dispute.voting_ends_at = 0;
+        dispute.verdict = None;
+        dispute.juror_reward = 0;
+        dispute.bump = ctx.bumps.dispute;
+        
+        emit!(DisputeOpened {
+            dispute: dispute.key(),
+            market: dispute.market,
+            disputer,
+            resolved_outcome,
+            claimed_outcome,
+        });
+        
+        msg!("Dispute opened on market {} by {}", dispute.market, disputer);
+        
+        Ok(())
+    }
+
+    /// Draw a dispute's jury from the hash of the slot it committed to;
+    /// anyone can crank it once that slot has passed
+    ///
+    /// The jurors are drawn from the pool, excluding the disputer, by
+    /// shuffling it with the hash of the first slot from `draw_slot` on and
+    /// the dispute address, read from the slot hashes sysvar. If the sysvar
+    /// no longer holds that slot, the dispute commits to a new one instead.
+    /// `remaining_accounts` holds the drawn jurors' arbiter accounts in jury
+    /// order; their stake stays locked until the dispute is settled.
+    pub fn draw_jury<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJury<'info>>) -> Result<()> {
+        let court = &ctx.accounts.court;
+        let dispute = &mut ctx.accounts.dispute;
+        require!(dispute.status == DisputeStatus::AwaitingJury, GovernanceError::JuryAlreadyDrawn);
+        
+        let slot = Clock::get()?.slot;
+        let lookup = committed_slot_hash(&ctx.accounts.slot_hashes.try_borrow_data()?, dispute.draw_slot);
+        let randomness = match lookup {
+            SlotHashLookup::Hash(hash) => hash,
+            SlotHashLookup::Pending => {
+                return Err(error!(GovernanceError::JuryDrawPending).with_values((slot, dispute.draw_slot)));
+            },
+            SlotHashLookup::Expired => {
+                dispute.draw_slot = slot.saturating_add(JURY_DRAW_DELAY_SLOTS);
+                msg!("Slot hash expired, dispute on market {} now draws at slot {}", dispute.market, dispute.draw_slot);
+                return Ok(());
+            },
+        };
+        
+        let seed = jury_seed(&randomness, &dispute.key());
+        let jurors = draw_jurors(&court.arbiters, &dispute.disputer, court.terms.jury_size as usize, &seed)
+            .ok_or(GovernanceError::NotEnoughArbiters)?;
+        require_eq!(ctx.remaining_accounts.len(), jurors.len(), GovernanceError::JurorAccountsMismatch);
+        for (juror, info) in jurors.iter().zip(ctx.remaining_accounts) {
+            let mut arbiter = load_arbiter(info, &court.key(), juror)?;
+            arbiter.active_disputes = arbiter.active_disputes.saturating_add(1);
+            arbiter.exit(&crate::ID)?;
+        }
+        
+        dispute.randomness = randomness;
+        dispute.votes = vec![None; jurors.len()];
+        dispute.jurors = jurors;
+        dispute.voting_ends_at = Clock::get()?.unix_timestamp.saturating_add(court.terms.voting_period);
+        dispute.status = DisputeStatus::Voting;
+        
+        emit!(JuryDrawn {
+            dispute: dispute.key(),
+            market: dispute.market,
+            jurors: dispute.jurors.clone(),
+            voting_ends_at: dispute.voting_ends_at,
+        });
+        
+        msg!("Drew a jury of {} for market {}", dispute.jurors.len(), dispute.market);
+        
+        Ok(())
+    }
+
+    /// Vote on the outcome a disputed market should have had, as one of its jurors
+    pub fn cast_juror_vote(ctx: Context<CastJurorVote>, outcome: MatchOutcome) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        require!(
+            dispute.status == DisputeStatus::Voting && Clock::get()?.unix_timestamp < dispute.voting_ends_at,
+            GovernanceError::VotingClosed
+        );
+        
+        let juror = ctx.accounts.juror.key();
+        let slot = dispute.jurors.iter().position(|key| *key == juror).ok_or(GovernanceError::NotJuror)?;
+        require!(dispute.votes[slot].is_none(), GovernanceError::AlreadyVoted);
+        dispute.votes[slot] = Some(outcome.clone());
+        
+        emit!(JurorVoted {
+            dispute: dispute.key(),
+            juror,
+            outcome: outcome.clone(),
+        });
+        
+        msg!("Juror {} voted {:?} on market {}", juror, outcome, dispute.market);
+        
+        Ok(())
+    }
+
+    /// Settle a dispute once its jury's voting has ended
+    ///
+    /// The verdict is the outcome backed by more than half of the votes cast,
+    /// or the market's resolution if none is. Jurors who didn't vote for it
+    /// lose the court's `slash_bps` of their stake, and the slashed lamports
+    /// and the dispute fee are split between those who did as extra stake,
+    /// the last of them in jury order taking what doesn't split evenly; with
+    /// no such jurors, the disputer gets both. An overturned resolution
+    /// is ruled mis-resolved through a CPI to the factory signed by the
+    /// governance PDA, with the caller paying for the ruling.
+    ///
+    /// `remaining_accounts` holds the jurors' arbiter accounts in jury order.
+    pub fn settle_dispute<'info>(ctx: Context<'_, '_, 'info, 'info, SettleDispute<'info>>) -> Result<()> {
+        let dispute = &ctx.accounts.dispute;
+        require!(dispute.status == DisputeStatus::Voting, GovernanceError::DisputeNotVoting);
+        require!(Clock::get()?.unix_timestamp >= dispute.voting_ends_at, GovernanceError::VotingOpen);
+        require_eq!(ctx.remaining_accounts.len(), dispute.jurors.len(), GovernanceError::JurorAccountsMismatch);
+        
+        let verdict = dispute.jury_verdict();
+        let court_key = ctx.accounts.court.key();
+        let terms = ctx.accounts.court.terms.clone();
+        let dispute_info = dispute.to_account_info();
+        
+        let mut slashed: u64 = 0;
+        let mut majority = Vec::new();
+        for ((juror, vote), info) in dispute.jurors.iter().zip(&dispute.votes).zip(ctx.remaining_accounts) {
+            let mut arbiter = load_arbiter(info, &court_key, juror)?;
+            arbiter.active_disputes = arbiter.active_disputes.saturating_sub(1);
+            
+            if vote.as_ref() == Some(&verdict) {
+                majority.push((arbiter, info));
+                continue;
+            }
+            
+            let penalty = (arbiter.stake as u128 * terms.slash_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+            arbiter.stake -= penalty;
+            **info.try_borrow_mut_lamports()? -= penalty;
+            **dispute_info.try_borrow_mut_lamports()? += penalty;
+            slashed = slashed.checked_add(penalty).ok_or(GovernanceError::StakeOverflow)?;
+            if arbiter.stake < terms.min_arbiter_stake {
+                ctx.accounts.court.arbiters.retain(|key| key != juror);
+            }
+            arbiter.exit(&crate::ID)?;
+        }
+        
+        let pool = dispute.fee.checked_add(slashed).ok_or(GovernanceError::StakeOverflow)?;
+        let winners = majority.len() as u64;
+        let juror_reward = pool.checked_div(winners).unwrap_or(0);
+        let dust = pool - juror_reward * winners;
+        for (position, (mut arbiter, info)) in majority.into_iter().enumerate() {
+            let reward = if position as u64 + 1 == winners { juror_reward + dust } else { juror_reward };
+            arbiter.stake = arbiter.stake.checked_add(reward)
+                .ok_or(GovernanceError::StakeOverflow)?;
+            **dispute_info.try_borrow_mut_lamports()? -= reward;
+            **info.try_borrow_mut_lamports()? += reward;
+            arbiter.exit(&crate::ID)?;
+        }
+        if winners == 0 {
+            **dispute_info.try_borrow_mut_lamports()? -= pool;
+            **ctx.accounts.disputer.try_borrow_mut_lamports()? += pool;
+        }
+        
+        let overturned = verdict != dispute.resolved_outcome;
+        if overturned {
+            rule_misresolution(ctx.accounts, verdict.clone(), &terms)?;
+        }
+        
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.status = DisputeStatus::Settled;
+        dispute.verdict = Some(verdict.clone());
+        dispute.juror_reward = juror_reward;
+        
+        emit!(DisputeSettled {
+            dispute: dispute.key(),
+            market: dispute.market,
+            verdict: verdict.clone(),
+            overturned,
+            slashed,
+            juror_reward,
+        });
+        
+        msg!("Dispute on market {} settled: {:?}, overturned: {}", dispute.market, verdict, overturned);
+        
+        Ok(())
+    }
+}
+
+// Helpers
+
+/// Change a factory platform parameter, signing as the governance PDA
+fn set_platform_param<'info>(
+    governance: &Account<'info, Governance>,
+    factory: &AccountInfo<'info>,
+    platform_config: &AccountInfo<'info>,
+    factory_program: &Program<'info, CryptoscoreFactory>,
+    param: PlatformParam,
+) -> Result<()> {
+    let bump = [governance.bump];
+    let signer_seeds: &[&[u8]] = &[b"governance", governance.factory.as_ref(), &bump];
+    
+    let ix = Instruction {
+        program_id: factory_program.key(),
+        accounts: cryptoscore_factory::accounts::SetPlatformParam {
+            factory: factory.key(),
+            platform_config: platform_config.key(),
+            caller: governance.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::SetPlatformParam { param }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            factory.clone(),
+            platform_config.clone(),
+            governance.to_account_info(),
+            factory_program.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Rule a disputed market mis-resolved on the factory, signing as the
+/// governance PDA with the court's caps
+fn rule_misresolution(accounts: &SettleDispute, correct_outcome: MatchOutcome, terms: &CourtTerms) -> Result<()> {
+    let governance = &accounts.governance;
+    let bump = [governance.bump];
+    let signer_seeds: &[&[u8]] = &[b"governance", governance.factory.as_ref(), &bump];
+    
+    let ix = Instruction {
+        program_id: accounts.factory_program.key(),
+        accounts: cryptoscore_factory::accounts::RuleMisresolution {
+            factory: accounts.factory.key(),
+            market: accounts.market.key(),
+            ruling: accounts.ruling.key(),
+            insurance_fund: accounts.insurance_fund.key(),
+            platform_config: accounts.platform_config.key(),
+            ruler: governance.key(),
+            payer: accounts.payer.key(),
+            system_program: accounts.system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::RuleMisresolution {
+            correct_outcome,
+            claim_cap: terms.claim_cap,
+            payout_cap: terms.payout_cap,
+        }
+        .data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.factory.to_account_info(),
+            accounts.market.to_account_info(),
+            accounts.ruling.to_account_info(),
+            accounts.insurance_fund.to_account_info(),
+            accounts.platform_config.to_account_info(),
+            governance.to_account_info(),
+            accounts.payer.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.factory_program.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Platform config of the governed factory, if it has one yet
+fn read_platform_config(account: &AccountInfo) -> Result<Option<PlatformConfig>> {
+    if *account.owner != FACTORY_PROGRAM_ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let config = PlatformConfig::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(config))
+}
+
+/// Arbiter account of `juror` in `court`, passed in `remaining_accounts`
+fn load_arbiter<'info>(
+    info: &'info AccountInfo<'info>,
+    court: &Pubkey,
+    juror: &Pubkey,
+) -> Result<Account<'info, Arbiter>> {
+    let arbiter = Account::<Arbiter>::try_from(info)?;
+    require!(
+        arbiter.court == *court && arbiter.user == *juror,
+        GovernanceError::JurorAccountsMismatch
+    );
+    Ok(arbiter)
+}
+
+// Account Structures
+
+/// Voting rules and stake totals for a factory's platform parameters,
+/// stored at the `governance` PDA of the factory
+#[account]
+pub struct Governance {
+    /// Factory whose parameters proposals change
+    pub factory: Pubkey,
+    /// Seconds a proposal is open for voting
+    pub voting_period: i64,
+    /// Seconds between the end of voting and when a passed proposal can execute
+    pub timelock: i64,
+    /// Share of the stake at a proposal's creation that has to vote on it, in basis points
+    pub quorum_bps: u16,
+    /// Lamports a user must have staked to create a proposal
+    pub min_proposal_stake: u64,
+    /// Lamports staked across all stake positions
+    pub total_staked: u64,
+    /// Number of proposals created, and the next proposal's ID
+    pub proposal_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        8 +  // voting_period
+        8 +  // timelock
+        2 +  // quorum_bps
+        8 +  // min_proposal_stake
+        8 +  // total_staked
+        8 +  // proposal_count
+        1;   // bump
+}
+
+/// A user's staked lamports, held as this account's lamports at the
+/// `stake_position` PDA of the governance and user
+#[account]
+pub struct StakePosition {
+    /// Governance the stake votes in
+    pub governance: Pubkey,
+    /// Staker
+    pub user: Pubkey,
+    /// Lamports staked, also the voting power
+    pub amount: u64,
+    /// Time until which the stake can't be withdrawn, the end of the last vote it was cast in
+    pub locked_until: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // governance
+        32 + // user
+        8 +  // amount
+        8 +  // locked_until
+        1;   // bump
+}
+
+/// A proposed platform parameter change, at the `proposal` PDA of the
+/// governance and proposal ID
+#[account]
+pub struct Proposal {
+    /// Governance the proposal was made in
+    pub governance: Pubkey,
+    /// Sequential proposal ID
+    pub id: u64,
+    /// User who made the proposal
+    pub proposer: Pubkey,
+    /// Parameter change executed if the proposal passes
+    pub param: PlatformParam,
+    /// Stake that has to vote for the proposal to pass
+    pub quorum_votes: u64,
+    /// Stake voting for the change
+    pub votes_for: u64,
+    /// Stake voting against the change
+    pub votes_against: u64,
+    /// Timestamp when the proposal was created
+    pub created_at: i64,
+    /// Timestamp when voting ends
+    pub voting_ends_at: i64,
+    /// Timestamp from which the proposal can execute if passed
+    pub executable_at: i64,
+    /// Whether the change has been made
+    pub executed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // governance
+        8 +  // id
+        32 + // proposer
+        PlatformParam::SPACE + // param
+        8 +  // quorum_votes
+        8 +  // votes_for
+        8 +  // votes_against
+        8 +  // created_at
+        8 +  // voting_ends_at
+        8 +  // executable_at
+        1 +  // executed
+        1;   // bump
+
+    /// Whether more stake voted for than against and the votes reached quorum
+    pub fn passed(&self) -> bool {
+        self.votes_for > self.votes_against && self.votes_for.saturating_add(self.votes_against) >= self.quorum_votes
+    }
+}
+
+/// One user's vote on a proposal, at the `vote_record` PDA of the proposal and voter
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Voter
+    pub voter: Pubkey,
+    /// Whether the vote was for the change
+    pub approve: bool,
+    /// Lamports staked when the vote was cast
+    pub weight: u64,
+    /// Timestamp of the vote
+    pub voted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // proposal
+        32 + // voter
+        1 +  // approve
+        8 +  // weight
+        8 +  // voted_at
+        1;   // bump
+}
+
+/// Dispute court of a governance and its arbiter pool, stored at the
+/// `court` PDA of the governance
+#[account]
+pub struct Court {
+    /// Governance whose PDA rules on overturned resolutions
+    pub governance: Pubkey,
+    /// Jury, stake and ruling settings
+    pub terms: CourtTerms,
+    /// Arbiters staking at least the minimum, whom juries are drawn from
+    pub arbiters: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Court {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // governance
+        CourtTerms::SPACE + // terms
+        4 + 32 * MAX_ARBITERS + // arbiters
+        1;   // bump
+}
+
+/// A user's arbiter stake, held as this account's lamports at the `arbiter`
+/// PDA of the court and user
+#[account]
+pub struct Arbiter {
+    /// Court the arbiter sits in
+    pub court: Pubkey,
+    /// Arbiter
+    pub user: Pubkey,
+    /// Lamports staked, slashed for voting against verdicts
+    pub stake: u64,
+    /// Juries the arbiter was drawn for whose disputes aren't settled yet
+    pub active_disputes: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Arbiter {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // court
+        32 + // user
+        8 +  // stake
+        4 +  // active_disputes
+        1;   // bump
+}
+
+/// A disputed market resolution and its jury, at the `dispute` PDA of the
+/// court and market
+#[account]
+pub struct Dispute {
+    /// Court hearing the dispute
+    pub court: Pubkey,
+    /// Disputed market
+    pub market: Pubkey,
+    /// User who opened the dispute
+    pub disputer: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome the disputer claims is correct
+    pub claimed_outcome: MatchOutcome,
+    /// Dispute fee paid in lamports
+    pub fee: u64,
+    /// Timestamp when the dispute was opened
+    pub opened_at: i64,
+    /// Stage of the dispute
+    pub status: DisputeStatus,
+    /// Slot whose hash, or the next one's if it was skipped, draws the jury
+    pub draw_slot: u64,
+    /// Slot hash the jury was drawn with
+    pub randomness: [u8; 32],
+    /// Drawn jurors
+    pub jurors: Vec<Pubkey>,
+    /// Each juror's vote, by jury position
+    pub votes: Vec<Option<MatchOutcome>>,
+    /// Timestamp when jury voting ends
+    pub voting_ends_at: i64,
+    /// Outcome the jury settled on
+    pub verdict: Option<MatchOutcome>,
+    /// Lamports each juror voting for the verdict earned
+    pub juror_reward: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Dispute {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // court
+        32 + // market
+        32 + // disputer
+        MATCH_OUTCOME_SPACE + // resolved_outcome
+        MATCH_OUTCOME_SPACE + // claimed_outcome
+        8 +  // fee
+        8 +  // opened_at
+        1 +  // status
+        8 +  // draw_slot
+        32 + // randomness
+        4 + 32 * MAX_JURY_SIZE + // jurors
+        4 + (1 + MATCH_OUTCOME_SPACE) * MAX_JURY_SIZE + // votes
+        8 +  // voting_ends_at
+        1 + MATCH_OUTCOME_SPACE + // verdict
+        8 +  // juror_reward
+        1;   // bump
+
+    /// Outcome backed by more than half of the votes cast, or the market's
+    /// resolution if no outcome is
+    pub fn jury_verdict(&self) -> MatchOutcome {
+        let cast = self.votes.iter().flatten().count();
+        [MatchOutcome::Home, MatchOutcome::Draw, MatchOutcome::Away]
+            .into_iter()
+            .find(|outcome| self.votes.iter().flatten().filter(|vote| *vote == outcome).count() * 2 > cast)
+            .unwrap_or_else(|| self.resolved_outcome.clone())
+    }
+}
+
+/// Stage of a dispute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum DisputeStatus {
+    /// Waiting for the committed slot to draw the jury
+    AwaitingJury,
+    /// Jurors are voting
+    Voting,
+    /// Verdict reached and stakes settled
+    Settled,
+}
+
+// Context Structures
+
+/// Accounts for `initialize_governance`
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    /// Factory put under governance
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+        seeds::program = FACTORY_PROGRAM_ID,
+        has_one = authority @ GovernanceError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Governance PDA, one per factory
+    #[account(
+        init,
+        payer = authority,
+        space = Governance::LEN,
+        seeds = [b"governance", factory.key().as_ref()],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `stake`
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    /// Governance the stake votes in
+    #[account(
+        mut,
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Staker's stake position, created on first use
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakePosition::LEN,
+        seeds = [b"stake_position", governance.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    /// Staker, paying the stake and the account
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `unstake`
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    /// Governance the stake votes in
+    #[account(
+        mut,
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Staker's stake position
+    #[account(
+        mut,
+        seeds = [b"stake_position", governance.key().as_ref(), user.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    /// Staker receiving the lamports
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `create_proposal`
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Governance the proposal is made in
+    #[account(
+        mut,
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Proposer's stake position
+    #[account(
+        seeds = [b"stake_position", governance.key().as_ref(), proposer.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    /// Proposal PDA, numbered by the governance's proposal count
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [b"proposal", governance.key().as_ref(), &governance.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// Proposer, paying for the account
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `cast_vote`
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    /// Governance the proposal was made in
+    #[account(
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Proposal being voted on
+    #[account(
+        mut,
+        seeds = [b"proposal", governance.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = governance
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// Voter's stake position, locked until voting ends
+    #[account(
+        mut,
+        seeds = [b"stake_position", governance.key().as_ref(), voter.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    /// Vote record PDA, one per proposal and voter
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    /// Voter, paying for the vote record
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `execute_proposal`
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// Governance PDA signing as the factory's governor
+    #[account(
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Passed proposal being executed
+    #[account(
+        mut,
+        seeds = [b"proposal", governance.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = governance
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// CHECK: Governed factory, validated by the factory program
+    #[account(mut, address = governance.factory)]
+    pub factory: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's platform config, validated by the factory program
+    #[account(
+        mut,
+        seeds = [b"platform_config", governance.factory.as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// Factory program receiving the parameter change
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+}
+
+/// Accounts for `initialize_court`
+#[derive(Accounts)]
+pub struct InitializeCourt<'info> {
+    /// Factory whose market resolutions the court hears disputes on
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+        seeds::program = FACTORY_PROGRAM_ID,
+        has_one = authority @ GovernanceError::Unauthorized
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Governance of the factory
+    #[account(
+        seeds = [b"governance", factory.key().as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Court PDA, one per governance
+    #[account(
+        init,
+        payer = authority,
+        space = Court::LEN,
+        seeds = [b"court", governance.key().as_ref()],
+        bump
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Current factory authority, paying for the account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `join_court`
+#[derive(Accounts)]
+pub struct JoinCourt<'info> {
+    /// Court being joined
+    #[account(
+        mut,
+        seeds = [b"court", court.governance.as_ref()],
+        bump = court.bump
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Arbiter's stake, created on first use
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Arbiter::LEN,
+        seeds = [b"arbiter", court.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub arbiter: Account<'info, Arbiter>,
+    
+    /// Arbiter, paying the stake and the account
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `leave_court`
+#[derive(Accounts)]
+pub struct LeaveCourt<'info> {
+    /// Court being left
+    #[account(
+        mut,
+        seeds = [b"court", court.governance.as_ref()],
+        bump = court.bump
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Arbiter's stake, closed to the arbiter
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"arbiter", court.key().as_ref(), user.key().as_ref()],
+        bump = arbiter.bump,
+        constraint = arbiter.active_disputes == 0 @ GovernanceError::ArbiterOnJury
+    )]
+    pub arbiter: Account<'info, Arbiter>,
+    
+    /// Arbiter receiving the stake
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `open_dispute`
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    /// Governance of the market's factory
+    #[account(
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Court hearing the dispute
+    #[account(
+        seeds = [b"court", governance.key().as_ref()],
+        bump = court.bump,
+        has_one = governance
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Resolved market being disputed
+    #[account(
+        seeds = [
+            b"market",
+            governance.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        seeds::program = MARKET_PROGRAM_ID
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: Factory's platform config, read if it exists to enforce its dispute window
+    #[account(
+        seeds = [b"platform_config", governance.factory.as_ref()],
+        bump,
+        seeds::program = FACTORY_PROGRAM_ID
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// Dispute PDA, one per court and market
+    #[account(
+        init,
+        payer = disputer,
+        space = Dispute::LEN,
+        seeds = [b"dispute", court.key().as_ref(), market.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// User disputing the resolution, paying the fee and the account
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `draw_jury`
+#[derive(Accounts)]
+pub struct DrawJury<'info> {
+    /// Court hearing the dispute
+    #[account(
+        seeds = [b"court", court.governance.as_ref()],
+        bump = court.bump
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Dispute the jury is drawn for
+    #[account(
+        mut,
+        seeds = [b"dispute", court.key().as_ref(), dispute.market.as_ref()],
+        bump = dispute.bump,
+        has_one = court
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// CHECK: The slot hashes sysvar, holding the hash the jury is drawn with
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+/// Accounts for `cast_juror_vote`
+#[derive(Accounts)]
+pub struct CastJurorVote<'info> {
+    /// Dispute being voted on
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.court.as_ref(), dispute.market.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// One of the dispute's jurors
+    pub juror: Signer<'info>,
+}
+
+/// Accounts for `settle_dispute`
+#[derive(Accounts)]
+pub struct SettleDispute<'info> {
+    /// Governance PDA signing a ruling as the factory's governor
+    #[account(
+        seeds = [b"governance", governance.factory.as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    /// Court hearing the dispute
+    #[account(
+        mut,
+        seeds = [b"court", governance.key().as_ref()],
+        bump = court.bump,
+        has_one = governance
+    )]
+    pub court: Account<'info, Court>,
+    
+    /// Dispute being settled
+    #[account(
+        mut,
+        seeds = [b"dispute", court.key().as_ref(), market.key().as_ref()],
+        bump = dispute.bump,
+        has_one = court,
+        has_one = market,
+        has_one = disputer
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// CHECK: Disputer, refunded when no juror voted for the verdict
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+    
+    /// CHECK: Governed factory, validated by the factory program
+    #[account(address = governance.factory)]
+    pub factory: UncheckedAccount<'info>,
+    
+    /// CHECK: Disputed market, validated by the dispute and the factory program
+    pub market: UncheckedAccount<'info>,
+    
+    /// CHECK: Misresolution ruling PDA, created by the factory program if the resolution is overturned
+    #[account(mut)]
+    pub ruling: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's insurance fund, validated by the factory program
+    #[account(mut)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's platform config, validated by the factory program
+    #[account(
+        seeds = [b"platform_config", governance.factory.as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// Caller, paying for a ruling
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Factory program receiving a ruling
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction Arguments
+
+/// Jury, stake and ruling settings of a court
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CourtTerms {
+    /// Jurors per dispute, odd and at most `MAX_JURY_SIZE`
+    pub jury_size: u8,
+    /// Seconds jurors have to vote once drawn
+    pub voting_period: i64,
+    /// Lamports an arbiter must stake to be drawn
+    pub min_arbiter_stake: u64,
+    /// Lamports a disputer pays, split between jurors voting for the verdict
+    pub dispute_fee: u64,
+    /// Share of stake jurors not voting for the verdict lose, in basis points
+    pub slash_bps: u16,
+    /// Most one participant can claim on an overturned market in lamports
+    pub claim_cap: u64,
+    /// Most all claims on an overturned market can pay in lamports
+    pub payout_cap: u64,
+}
+
+impl CourtTerms {
+    pub const SPACE: usize = 1 + 8 + 8 + 8 + 2 + 8 + 8;
+
+    /// Whether juries are odd and drawable, voting takes time and the caps
+    /// would be accepted by the factory
+    pub fn is_valid(&self) -> bool {
+        let jury_size = self.jury_size as usize;
+        jury_size % 2 == 1
+            && jury_size <= MAX_JURY_SIZE
+            && self.voting_period > 0
+            && self.slash_bps as u64 <= BPS_DENOMINATOR
+            && self.claim_cap > 0
+            && self.payout_cap >= self.claim_cap
+    }
+}
+
+// Events
+
+/// Emitted when a user proposes a platform parameter change
+#[event]
+pub struct ProposalCreated {
+    /// Proposal account
+    #[index]
+    pub proposal: Pubkey,
+    /// Sequential proposal ID
+    pub id: u64,
+    /// User who made the proposal
+    pub proposer: Pubkey,
+    /// Proposed parameter change
+    pub param: PlatformParam,
+    /// Timestamp when voting ends
+    pub voting_ends_at: i64,
+    /// Timestamp from which the proposal can execute if passed
+    pub executable_at: i64,
+}
+
+/// Emitted when a staker votes on a proposal
+#[event]
+pub struct VoteCast {
+    /// Proposal voted on
+    #[index]
+    pub proposal: Pubkey,
+    /// Voter
+    pub voter: Pubkey,
+    /// Whether the vote was for the change
+    pub approve: bool,
+    /// Lamports of stake behind the vote
+    pub weight: u64,
+    /// Stake voting for so far
+    pub votes_for: u64,
+    /// Stake voting against so far
+    pub votes_against: u64,
+}
+
+/// Emitted when a passed proposal changes the platform parameter
+#[event]
+pub struct ProposalExecuted {
+    /// Executed proposal
+    #[index]
+    pub proposal: Pubkey,
+    /// Sequential proposal ID
+    pub id: u64,
+    /// Parameter change made
+    pub param: PlatformParam,
+}
+
+/// Emitted when a user disputes a market's resolution
+#[event]
+pub struct DisputeOpened {
+    /// Dispute account
+    #[index]
+    pub dispute: Pubkey,
+    /// Disputed market
+    pub market: Pubkey,
+    /// User who opened the dispute
+    pub disputer: Pubkey,
+    /// Outcome the market resolved to
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome the disputer claims is correct
+    pub claimed_outcome: MatchOutcome,
+}
+
+/// Emitted when a dispute's jury is drawn
+#[event]
+pub struct JuryDrawn {
+    /// Dispute the jury hears
+    #[index]
+    pub dispute: Pubkey,
+    /// Disputed market
+    pub market: Pubkey,
+    /// Drawn jurors
+    pub jurors: Vec<Pubkey>,
+    /// Timestamp when jury voting ends
+    pub voting_ends_at: i64,
+}
+
+/// Emitted when a juror votes on a dispute
+#[event]
+pub struct JurorVoted {
+    /// Dispute voted on
+    #[index]
+    pub dispute: Pubkey,
+    /// Juror
+    pub juror: Pubkey,
+    /// Outcome the juror voted for
+    pub outcome: MatchOutcome,
+}
+
+/// Emitted when a dispute's verdict is reached and jurors are paid or slashed
+#[event]
+pub struct DisputeSettled {
+    /// Settled dispute
+    #[index]
+    pub dispute: Pubkey,
+    /// Disputed market
+    pub market: Pubkey,
+    /// Outcome the jury settled on
+    pub verdict: MatchOutcome,
+    /// Whether the verdict overturned the resolution
+    pub overturned: bool,
+    /// Lamports slashed from jurors not voting for the verdict
+    pub slashed: u64,
+    /// Lamports each juror voting for the verdict earned
+    pub juror_reward: u64,
+}
+
+// Error Codes
+
+#[error_code]
+pub enum GovernanceError {
+    #[msg("Only the factory authority can set up governance")]
+    Unauthorized,
+    #[msg("Voting period must be positive, the timelock not negative and quorum at most 10000 bps")]
+    InvalidGovernanceConfig,
+    #[msg("Stake amount must be greater than zero")]
+    InvalidStakeAmount,
+    #[msg("Not enough lamports staked")]
+    InsufficientStake,
+    #[msg("Stake is locked until voting ends on the proposals it voted on")]
+    StakeLocked,
+    #[msg("Stake totals overflow")]
+    StakeOverflow,
+    #[msg("Proposed platform fee cannot exceed 10% (1000 bps) and a dispute window cannot be negative")]
+    InvalidProposal,
+    #[msg("Proposal count overflow")]
+    ProposalCountOverflow,
+    #[msg("Voting on this proposal has ended")]
+    VotingClosed,
+    #[msg("Voter has no stake")]
+    NoVotingPower,
+    #[msg("Vote totals overflow")]
+    VoteOverflow,
+    #[msg("Proposal's timelock has not run out")]
+    TimelockActive,
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Juries must be odd and at most 9, voting must take time, slashing at most 10000 bps and caps ordered")]
+    InvalidCourtConfig,
+    #[msg("Court's arbiter pool is full")]
+    ArbiterPoolFull,
+    #[msg("Arbiter sits on a jury whose dispute isn't settled")]
+    ArbiterOnJury,
+    #[msg("Market has not been resolved")]
+    MarketNotResolved,
+    #[msg("Disputed outcome must differ from the resolved outcome")]
+    InvalidDispute,
+    #[msg("Market's dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Not enough arbiters to draw a jury")]
+    NotEnoughArbiters,
+    #[msg("Dispute's jury has already been drawn")]
+    JuryAlreadyDrawn,
+    #[msg("Arbiter accounts don't match the jury")]
+    JurorAccountsMismatch,
+    #[msg("Signer is not on the dispute's jury")]
+    NotJuror,
+    #[msg("Juror has already voted")]
+    AlreadyVoted,
+    #[msg("Dispute is not in jury voting")]
+    DisputeNotVoting,
+    #[msg("Jury voting has not ended")]
+    VotingOpen,
+    #[msg("Dispute's draw slot has not been hashed yet")]
+    JuryDrawPending,
+}
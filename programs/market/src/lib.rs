@@ -1,4 +1,56 @@
+use std::collections::BTreeSet;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::{get_return_data, invoke, invoke_signed};
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::{Discriminator, InstructionData};
+use cryptoscore_common::attestation::{attestation_message, parse_ed25519_instruction};
+use cryptoscore_common::commitment::{prediction_commitment, SALT_LEN};
+use cryptoscore_common::fees::{
+    first_correct_bonus, implied_payout_bps, interest_share, share_with_charity, split_pool, split_rake_free_pool,
+    PoolSplit, BPS_DENOMINATOR, MAX_CHARITY_POOL_SHARE_BPS, MAX_POOL,
+};
+use cryptoscore_common::match_id::{uses_charset, DEFAULT_MATCH_ID_CHARSET};
+use cryptoscore_common::merkle::{append_leaf, frontier_root, verify_winner, winner_leaf, WINNERS_TREE_DEPTH};
+use cryptoscore_common::pda::{
+    market_signer_seeds, match_id_seed, LIVE_SCORE_SEED, PARTICIPANT_ROSTER_SEED, ROSTER_PAGE_SEED,
+};
+use cryptoscore_common::receipt::{
+    mint_receipt, receipt_name, receipt_uri, BUBBLEGUM_PROGRAM_ID, COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID,
+};
+use cryptoscore_common::reputation::reputation_balance;
+use cryptoscore_common::stake_pool::{
+    deposit_sol, initialize_token_account, parse_token_account, withdraw_sol, StakePoolAccounts, STAKE_POOL_PROGRAM_ID,
+    TOKEN_ACCOUNT_LEN, TOKEN_PROGRAM_ID,
+};
+use cryptoscore_common::swap::{sync_native, JUPITER_PROGRAM_ID, NATIVE_MINT};
+use cryptoscore_common::token_gate::TokenGateRule;
+use cryptoscore_common::wormhole::{parse_posted_vaa, parse_result_payload};
+use cryptoscore_common::{
+    CLAIM_WINDOW_SECONDS, DISCRIMINATOR_LEN, FINAL_SCORE_SPACE, HANDICAP_STYLE_SPACE, MARKET_STATUS_SPACE,
+    MATCH_ID_SPACE, MATCH_OUTCOME_SPACE, MAX_CO_ADMINS, MAX_IN_PLAY_LOCK_MINUTE, MAX_MATCH_ID_LEN, MAX_PROP_PLAYERS,
+    MAX_RESOLUTION_GRACE_SECONDS, ODDS_HISTORY_SAMPLES, ODDS_SAMPLE_INTERVAL_SECONDS, ODDS_SAMPLE_SPACE,
+    OUTCOME_SET_SPACE, PROP_KIND_SPACE, QUEUED_JOIN_LEAD_SECONDS, RESULT_CORRECTION_WINDOW_SECONDS, ROSTER_PAGE_SIZE,
+    SCORELINE_SPACE, SCORE_GRID_BUCKETS, SCORE_GRID_MAX_GOALS,
+};
+use cryptoscore_dashboard::program::CryptoscoreDashboard;
+use cryptoscore_dashboard::{
+    CreatorActivity, CreatorStats, MarketActivity, MarketResult, PlatformStats, PointsActivity, RebateTracker,
+    UserStats,
+};
+use cryptoscore_factory::program::CryptoscoreFactory;
+use cryptoscore_factory::{
+    DiscountPolicy, Factory, KeeperTipPolicy, LiveScore, MatchIdPolicy, PayoutSwapPolicy, RakeFreeMarket, RebatePolicy,
+    ReceiptPolicy, ResolutionGracePolicy, ResolutionPolicy, ResultsBatch, ResultsSigner, WormholeEmitter,
+};
+
+pub use cryptoscore_common::{
+    FinalScore, HandicapStyle, MarketStatus, MatchOutcome, OddsSample, OutcomeSet, PropKind, Scoreline,
+};
 
 declare_id!("BJmMs142koLJvkutSzWchPGn2CJNGqTtGQV5g3Xt87PU");
 
@@ -7,6 +59,18 @@ pub mod cryptoscore_market {
     use super::*;
 
     /// Initialize a new prediction market
+    ///
+    /// `outcome_set` is fixed for the market's life: `OutcomeSet::WinLose`
+    /// markets, for sports without draws, refuse draw predictions and
+    /// can't resolve to a draw.
+    ///
+    /// For `resolution_grace_seconds` after `end_time` only the creator can
+    /// resolve the market; participants, oracle attestations and keepers
+    /// have to wait it out. The factory's resolution grace policy bounds it.
+    ///
+    /// `match_id` may only use the characters the factory's match ID policy
+    /// allows, by default uppercase letters, digits and `-`.
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_market(
         ctx: Context<InitializeMarket>,
         match_id: String,
@@ -14,12 +78,19 @@ pub mod cryptoscore_market {
         kickoff_time: i64,
         end_time: i64,
         is_public: bool,
+        outcome_set: OutcomeSet,
+        resolution_grace_seconds: i64,
     ) -> Result<()> {
         let market = &mut ctx.accounts.market;
         
         // Validate match ID
         require!(!match_id.is_empty(), MarketError::InvalidMatchId);
-        require!(match_id.len() <= 64, MarketError::MatchIdTooLong);
+        require!(match_id.len() <= MAX_MATCH_ID_LEN, MarketError::MatchIdTooLong);
+        let charset = match read_match_id_policy(&ctx.accounts.match_id_policy)? {
+            Some(policy) => policy.charset,
+            None => DEFAULT_MATCH_ID_CHARSET,
+        };
+        require!(uses_charset(&match_id, charset), MarketError::InvalidMatchIdCharacters);
         
         // Validate entry fee
         require!(entry_fee > 0, MarketError::ZeroEntryFee);
@@ -29,6 +100,15 @@ pub mod cryptoscore_market {
         require!(kickoff_time > current_time, MarketError::InvalidKickoffTime);
         require!(end_time > kickoff_time, MarketError::InvalidEndTime);
         
+        // Validate the grace period against the factory's bounds
+        let (min_grace, max_grace) = match read_resolution_grace_policy(&ctx.accounts.resolution_grace_policy)? {
+            Some(policy) => (policy.min_grace_seconds, policy.max_grace_seconds),
+            None => (0, MAX_RESOLUTION_GRACE_SECONDS),
+        };
+        if !(min_grace..=max_grace).contains(&resolution_grace_seconds) {
+            return Err(error!(MarketError::InvalidResolutionGrace).with_values((resolution_grace_seconds, max_grace)));
+        }
+        
         // Initialize market state
         market.factory = ctx.accounts.factory.key();
         market.creator = ctx.accounts.creator.key();
@@ -45,6 +125,45 @@ pub mod cryptoscore_market {
         market.away_count = 0;
         market.is_public = is_public;
         market.bump = ctx.bumps.market;
+        market.fees_paid = 0;
+        market.total_claimed = 0;
+        market.co_admins = [Pubkey::default(); MAX_CO_ADMINS];
+        market.outcome_set = outcome_set;
+        market.resolution_grace_seconds = resolution_grace_seconds;
+        market.final_score = None;
+        market.joined_at_sum = 0;
+        market.queued_join_count = 0;
+        
+        // Report the new market to platform and creator stats
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Created,
+        )?;
+        record_creator_activity(
+            market,
+            &ctx.accounts.creator_stats,
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.creator,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            CreatorActivity::MarketCreated,
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.creator,
+            &ctx.accounts.creator,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::MarketCreated,
+        )?;
         
         msg!("Market initialized: {}, creator: {}", 
             ctx.accounts.market.key(), ctx.accounts.creator.key());
@@ -53,71 +172,181 @@ pub mod cryptoscore_market {
     }
 
     /// Join a market with a prediction
+    ///
+    /// On token-gated markets the user's token account, followed for
+    /// collection gates by the NFT's metadata, comes as remaining accounts.
+    /// Users whose market-verified record earns reputation, or who hold
+    /// creator badges, get the factory's discount on the entry fee paid back
+    /// from the treasury.
+    ///
+    /// Passing one of the user's vouchers and its campaign has the treasury
+    /// pay the entry fee instead of the user; such entries get no rebate or
+    /// discount on top.
+    ///
+    /// `note_hash` commits to a note about the prediction, e.g. a written
+    /// rationale the user reveals later; all zeros leaves no note.
+    ///
+    /// Safe to retry: if the user has already joined on the same prediction
+    /// the join is a no-op, and a join on a different prediction is refused
+    /// with `AlreadyJoined`.
     pub fn join_market(
         ctx: Context<JoinMarket>,
         prediction: MatchOutcome,
+        note_hash: [u8; 32],
     ) -> Result<()> {
         let market = &mut ctx.accounts.market;
         let participant = &mut ctx.accounts.participant;
         
+        // An existing participant means an earlier attempt at this join landed
+        if participant.user != Pubkey::default() {
+            if participant.prediction != prediction {
+                return Err(error!(MarketError::AlreadyJoined)
+                    .with_values((format!("{:?}", participant.prediction), format!("{:?}", prediction))));
+            }
+            msg!("{} already joined market {}", participant.user, market.key());
+            return Ok(());
+        }
+        
         // Validate market is open
         require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
         
-        // Validate kickoff time hasn't passed
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require_token_gate(&ctx.accounts.token_gate, &ctx.accounts.user.key(), ctx.remaining_accounts)?;
+        
+        // Validate kickoff time hasn't passed, or the in-play lock hasn't been reached
         let current_time = Clock::get()?.unix_timestamp;
-        require!(current_time < market.kickoff_time, MarketError::MarketAlreadyStarted);
+        require_join_window(market, &ctx.accounts.in_play_lock, &ctx.accounts.live_score, current_time)?;
         
-        // Transfer entry fee from user to market
-        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.user.key(),
-            &market.key(),
-            market.entry_fee,
-        );
-        anchor_lang::solana_program::program::invoke(
-            &transfer_ix,
-            &[
-                ctx.accounts.user.to_account_info(),
-                market.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-        )?;
+        // Transfer entry fee from user to market, or from the treasury for a voucher
+        let voucher_paid = match (&ctx.accounts.voucher_campaign, &ctx.accounts.voucher) {
+            (Some(voucher_campaign), Some(voucher)) => {
+                redeem_voucher(
+                    market,
+                    &ctx.accounts.factory,
+                    voucher_campaign,
+                    voucher,
+                    &ctx.accounts.treasury,
+                    &ctx.accounts.insurance_fund,
+                    &ctx.accounts.user,
+                    &ctx.accounts.factory_program,
+                    &ctx.accounts.instructions,
+                )?;
+                true
+            }
+            (None, None) => {
+                let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+                    &ctx.accounts.user.key(),
+                    &market.key(),
+                    market.entry_fee,
+                );
+                anchor_lang::solana_program::program::invoke(
+                    &transfer_ix,
+                    &[
+                        ctx.accounts.user.to_account_info(),
+                        market.to_account_info(),
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                )?;
+                false
+            }
+            _ => return err!(MarketError::IncompleteVoucher),
+        };
         
-        // Initialize participant
-        participant.market = market.key();
-        participant.user = ctx.accounts.user.key();
-        participant.prediction = prediction.clone();
-        participant.joined_at = current_time;
-        participant.has_withdrawn = false;
+        // Initialize participant and update market stats
         participant.bump = ctx.bumps.participant;
+        record_join(market, participant, ctx.accounts.user.key(), prediction.clone(), current_time)?;
+        participant.note_hash = note_hash;
+        track_earliest_entry(&ctx.accounts.first_correct_bonus, participant)?;
+        append_to_roster(
+            market,
+            &ctx.accounts.roster,
+            ctx.accounts.user.key(),
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+        )?;
         
-        // Update market stats
-        market.total_pool = market.total_pool.checked_add(market.entry_fee)
-            .ok_or(MarketError::PoolOverflow)?;
-        market.participant_count = market.participant_count.checked_add(1)
-            .ok_or(MarketError::ParticipantOverflow)?;
-        
-        // Update prediction counts
-        match prediction {
-            MatchOutcome::Home => {
-                market.home_count = market.home_count.checked_add(1)
-                    .ok_or(MarketError::CountOverflow)?;
-            },
-            MatchOutcome::Draw => {
-                market.draw_count = market.draw_count.checked_add(1)
-                    .ok_or(MarketError::CountOverflow)?;
-            },
-            MatchOutcome::Away => {
-                market.away_count = market.away_count.checked_add(1)
-                    .ok_or(MarketError::CountOverflow)?;
+        // Report the join to platform stats
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user: ctx.accounts.user.key(),
+                amount: market.entry_fee,
             },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
+        
+        // Track the entry for losing-streak rebates, rebating it first if one is due
+        if let Some(tracker) = read_rebate_tracker(&ctx.accounts.rebate_tracker)? {
+            let rebated = match read_rebate_policy(&ctx.accounts.rebate_policy)? {
+                Some(policy) if !voucher_paid && tracker.rebate_due(policy.loss_streak) => {
+                    let paid = claim_streak_rebate(
+                        market,
+                        &ctx.accounts.factory,
+                        &ctx.accounts.rebate_policy,
+                        &ctx.accounts.treasury,
+                        &ctx.accounts.insurance_fund,
+                        &ctx.accounts.user,
+                        &ctx.accounts.factory_program,
+                        &ctx.accounts.instructions,
+                    )?;
+                    paid > 0
+                }
+                _ => false,
+            };
+            track_rebate_entry(
+                market,
+                &ctx.accounts.rebate_tracker,
+                &ctx.accounts.user,
+                &ctx.accounts.dashboard_program,
+                &ctx.accounts.instructions,
+                rebated,
+            )?;
+        }
+        
+        // Discount the entry for reputation and badge holders who paid it themselves
+        if let Some(policy) = read_discount_policy(&ctx.accounts.discount_policy)?.filter(|_| !voucher_paid) {
+            let reputation = read_reputation(&ctx.accounts.user_stats)?;
+            let badges = read_creator_badges(&ctx.accounts.creator_stats)?;
+            if policy.discount_bps(reputation, badges).is_some() {
+                claim_fee_discount(
+                    market,
+                    &ctx.accounts.factory,
+                    &ctx.accounts.discount_policy,
+                    &ctx.accounts.treasury,
+                    &ctx.accounts.insurance_fund,
+                    &ctx.accounts.user,
+                    &ctx.accounts.factory_program,
+                    &ctx.accounts.instructions,
+                    reputation,
+                    badges,
+                )?;
+            }
         }
         
+        require_solvent(market)?;
+        
         // Emit event
         emit!(PredictionMade {
             market: market.key(),
             user: ctx.accounts.user.key(),
             prediction,
             timestamp: current_time,
+            entry_index: participant.entry_index,
         });
         
         msg!("User {} joined market with prediction: {:?}", 
@@ -126,466 +355,9402 @@ pub mod cryptoscore_market {
         Ok(())
     }
 
-    /// Resolve market with match outcome and distribute fees
-    pub fn resolve_market(
-        ctx: Context<ResolveMarket>,
-        outcome: MatchOutcome,
-    ) -> Result<()> {
+    /// Check every precondition of `join_market` on `prediction` for a user
+    /// without moving funds or writing any account
+    ///
+    /// Fails with the error the join would fail with, so frontends can
+    /// simulate it to show why a join won't go through before asking for a
+    /// signature; the user need not sign. On token-gated markets the user's
+    /// holdings proof comes as remaining accounts, as for `join_market`. The
+    /// balance check covers the entry fee and the participant account's rent.
+    pub fn validate_join(ctx: Context<ValidateJoin>, prediction: MatchOutcome) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let user = ctx.accounts.user.key();
+        
+        // A join on the same prediction as an existing entry is a no-op
+        let participant_info = ctx.accounts.participant.to_account_info();
+        if *participant_info.owner == crate::ID && !participant_info.data_is_empty() {
+            let participant = Participant::try_deserialize(&mut &participant_info.try_borrow_data()?[..])?;
+            if participant.prediction != prediction {
+                return Err(error!(MarketError::AlreadyJoined)
+                    .with_values((format!("{:?}", participant.prediction), format!("{:?}", prediction))));
+            }
+            msg!("{} already joined market {}", user, market.key());
+            return Ok(());
+        }
+        
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require_token_gate(&ctx.accounts.token_gate, &user, ctx.remaining_accounts)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_join_window(market, &ctx.accounts.in_play_lock, &ctx.accounts.live_score, current_time)?;
+        
+        // Count the entry on a copy of the market to hit the join's limits
+        let mut joined = (**market).clone();
+        add_entry(&mut joined, current_time)?;
+        count_prediction(&mut joined, &prediction)?;
+        
+        let needed = market.entry_fee
+            .checked_add(Rent::get()?.minimum_balance(Participant::LEN))
+            .ok_or(MarketError::PoolOverflow)?;
+        let balance = ctx.accounts.user.lamports();
+        if balance < needed {
+            return Err(error!(MarketError::InsufficientBalance).with_values((balance, needed)));
+        }
+        
+        msg!("{} can join market {} with prediction: {:?}", user, market.key(), prediction);
+        
+        Ok(())
+    }
+
+    /// Escrow an entry on `prediction` that joins only if, at the queue
+    /// deadline, it would pay at least `min_payout_bps` of the entry fee
+    ///
+    /// The deadline is `QUEUED_JOIN_LEAD_SECONDS` before kickoff, when a crank
+    /// either executes the join or refunds it. The escrow also covers the
+    /// participant account's rent, which reimburses whoever executes it.
+    pub fn queue_join(ctx: Context<QueueJoin>, prediction: MatchOutcome, min_payout_bps: u64) -> Result<()> {
         let market = &mut ctx.accounts.market;
-        let resolver = ctx.accounts.resolver.key();
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_gt!(min_payout_bps, 0, MarketError::InvalidPayoutCondition);
+        require_ungated(&ctx.accounts.token_gate)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require_offered(market, &prediction)?;
         
-        // Validate resolver is either creator or a participant
-        let is_creator = resolver == market.creator;
-        let is_participant = ctx.accounts.participant.is_some();
+        let current_time = Clock::get()?.unix_timestamp;
+        let deadline = market.queued_join_deadline();
+        require_gt!(deadline, current_time, MarketError::JoinQueueClosed);
         
-        require!(
-            is_creator || is_participant,
-            MarketError::UnauthorizedResolver
+        let participant_rent = Rent::get()?.minimum_balance(Participant::LEN);
+        let escrow = market.entry_fee.checked_add(participant_rent)
+            .ok_or(MarketError::PoolOverflow)?;
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &ctx.accounts.queued_join.key(),
+            escrow,
         );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.queued_join.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
         
-        // If participant, validate they actually joined this market
-        if let Some(participant) = &ctx.accounts.participant {
-            require!(
-                participant.market == market.key(),
-                MarketError::UnauthorizedResolver
-            );
-        }
+        let queued = &mut ctx.accounts.queued_join;
+        queued.market = market.key();
+        queued.user = ctx.accounts.user.key();
+        queued.prediction = prediction.clone();
+        queued.min_payout_bps = min_payout_bps;
+        queued.entry_fee = market.entry_fee;
+        queued.participant_rent = participant_rent;
+        queued.queued_at = current_time;
+        queued.bump = ctx.bumps.queued_join;
+        market.queued_join_count = market.queued_join_count.checked_add(1)
+            .ok_or(MarketError::CountOverflow)?;
+        
+        emit!(JoinQueued {
+            market: queued.market,
+            user: queued.user,
+            prediction,
+            min_payout_bps,
+        });
+        
+        msg!("User {} queued a join needing a {} bps payout", queued.user, min_payout_bps);
         
-        // Validate market is not already resolved
-        require!(market.status != MarketStatus::Resolved, MarketError::MarketAlreadyResolved);
+        Ok(())
+    }
+
+    /// Join the market on a queued entry whose payout condition holds
+    ///
+    /// Anyone can crank it between the queue deadline and kickoff, fronting
+    /// the rent of any roster page the entry opens.
+    pub fn execute_queued_join(ctx: Context<ExecuteQueuedJoin>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let queued = &ctx.accounts.queued_join;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require_ungated(&ctx.accounts.token_gate)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
         
-        // Validate end time has passed
         let current_time = Clock::get()?.unix_timestamp;
-        require!(current_time >= market.end_time, MarketError::MarketNotEnded);
+        require_gte!(current_time, market.queued_join_deadline(), MarketError::QueuedJoinNotDue);
+        require_gt!(market.kickoff_time, current_time, MarketError::MarketAlreadyStarted);
         
-        // Calculate and distribute fees before updating market status
-        let total_pool = market.total_pool;
+        let payout_bps = queued_payout_bps(market, queued)?;
+        if payout_bps < queued.min_payout_bps {
+            return Err(error!(MarketError::PayoutConditionNotMet).with_values((payout_bps, queued.min_payout_bps)));
+        }
         
-        // Calculate fees (2% creator + 3% platform = 5% total)
-        let creator_fee = total_pool.checked_mul(200).unwrap() / 10000; // 2%
-        let platform_fee = total_pool.checked_mul(300).unwrap() / 10000; // 3%
-        let total_fees = creator_fee.checked_add(platform_fee)
-            .ok_or(MarketError::CalculationError)?;
+        // Fund the entry and reimburse the cranker's rent; the rest returns
+        // to the user when the queued join closes
+        let queued_info = queued.to_account_info();
+        **queued_info.try_borrow_mut_lamports()? -= queued.entry_fee + queued.participant_rent;
+        **market.to_account_info().try_borrow_mut_lamports()? += queued.entry_fee;
+        **ctx.accounts.cranker.try_borrow_mut_lamports()? += queued.participant_rent;
+        market.queued_join_count = market.queued_join_count.saturating_sub(1);
         
-        // Validate we have enough funds for fees
-        require!(
-            market.to_account_info().lamports() >= total_fees,
-            MarketError::InsufficientFunds
-        );
+        let participant = &mut ctx.accounts.participant;
+        participant.bump = ctx.bumps.participant;
+        record_join(market, participant, queued.user, queued.prediction.clone(), current_time)?;
+        track_earliest_entry(&ctx.accounts.first_correct_bonus, participant)?;
+        append_to_roster(market, &ctx.accounts.roster, queued.user, &ctx.accounts.cranker, &ctx.accounts.system_program)?;
         
-        // Transfer creator fee
-        if creator_fee > 0 {
-            **market.to_account_info().try_borrow_mut_lamports()? = market
-                .to_account_info()
-                .lamports()
-                .checked_sub(creator_fee)
-                .ok_or(MarketError::InsufficientFunds)?;
-            
-            **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? = ctx
-                .accounts
-                .creator
-                .to_account_info()
-                .lamports()
-                .checked_add(creator_fee)
-                .ok_or(MarketError::CalculationError)?;
-        }
-        
-        // Transfer platform fee
-        if platform_fee > 0 {
-            **market.to_account_info().try_borrow_mut_lamports()? = market
-                .to_account_info()
-                .lamports()
-                .checked_sub(platform_fee)
-                .ok_or(MarketError::InsufficientFunds)?;
-            
-            **ctx.accounts.platform.to_account_info().try_borrow_mut_lamports()? = ctx
-                .accounts
-                .platform
-                .to_account_info()
-                .lamports()
-                .checked_add(platform_fee)
-                .ok_or(MarketError::CalculationError)?;
-        }
-        
-        // Update market status and outcome
-        market.status = MarketStatus::Resolved;
-        market.outcome = Some(outcome.clone());
-        
-        // Calculate winner count
-        let winner_count = match outcome {
-            MatchOutcome::Home => market.home_count,
-            MatchOutcome::Draw => market.draw_count,
-            MatchOutcome::Away => market.away_count,
-        };
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user: queued.user,
+                amount: market.entry_fee,
+            },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.cranker,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
         
-        // Emit events
-        emit!(MarketResolved {
+        require_solvent(market)?;
+        
+        emit!(PredictionMade {
             market: market.key(),
-            outcome: outcome.clone(),
-            winner_count,
-            total_pool: market.total_pool,
+            user: queued.user,
+            prediction: queued.prediction.clone(),
+            timestamp: current_time,
+            entry_index: participant.entry_index,
         });
         
-        emit!(FeesDistributed {
+        tip_keeper(market, &ctx.accounts.tip, &ctx.accounts.cranker)?;
+        
+        msg!("Queued join for {} executed at a {} bps payout", queued.user, payout_bps);
+        
+        Ok(())
+    }
+
+    /// Return a queued entry's escrow to its user
+    ///
+    /// The user can withdraw it at any time. Anyone else can once the deadline
+    /// passes with the payout condition unmet, or once the market has started
+    /// or stopped taking entries.
+    pub fn refund_queued_join(ctx: Context<RefundQueuedJoin>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let queued = &ctx.accounts.queued_join;
+        
+        if ctx.accounts.signer.key() != queued.user {
+            let current_time = Clock::get()?.unix_timestamp;
+            let expired = market.status != MarketStatus::Open || current_time >= market.kickoff_time;
+            let unmet = current_time >= market.queued_join_deadline()
+                && queued_payout_bps(market, queued)? < queued.min_payout_bps;
+            require!(expired || unmet, MarketError::QueuedJoinPending);
+        }
+        market.queued_join_count = market.queued_join_count.saturating_sub(1);
+        
+        emit!(QueuedJoinRefunded {
             market: market.key(),
-            creator: market.creator,
-            creator_fee,
-            platform: ctx.accounts.platform.key(),
-            platform_fee,
-            total_fees,
+            user: queued.user,
+            amount: queued.to_account_info().lamports(),
         });
         
-        msg!("Market resolved with outcome: {:?}, winners: {}", 
-            market.outcome, winner_count);
-        msg!("Fees distributed - Creator: {} lamports, Platform: {} lamports", 
-            creator_fee, platform_fee);
+        msg!("Queued join for {} refunded", queued.user);
         
         Ok(())
     }
 
-    /// Withdraw rewards for winning participants
-    pub fn withdraw_rewards(ctx: Context<WithdrawRewards>) -> Result<()> {
+    /// Follow `leader` so their predictions can be copied with `copy_join`
+    pub fn follow(ctx: Context<FollowLeader>) -> Result<()> {
+        let follower = ctx.accounts.follower.key();
+        let leader = ctx.accounts.leader.key();
+        require_keys_neq!(follower, leader, MarketError::CannotFollowSelf);
+        
+        let follow = &mut ctx.accounts.follow;
+        follow.follower = follower;
+        follow.leader = leader;
+        follow.followed_at = Clock::get()?.unix_timestamp;
+        follow.copies = 0;
+        follow.bump = ctx.bumps.follow;
+        
+        msg!("{} followed {}", follower, leader);
+        
+        Ok(())
+    }
+
+    /// Stop following a leader, reclaiming the follow's rent
+    pub fn unfollow(ctx: Context<UnfollowLeader>) -> Result<()> {
+        msg!("{} unfollowed {}", ctx.accounts.follower.key(), ctx.accounts.follow.leader);
+        
+        Ok(())
+    }
+
+    /// Join a market on the same prediction as a followed leader, paying the
+    /// copier's own entry fee
+    ///
+    /// `CopyJoined` attributes the entry to the leader so copy fees can be
+    /// shared with them off-chain. Token-gated markets need the same proof
+    /// of holdings as `join_market`.
+    pub fn copy_join(ctx: Context<CopyJoin>) -> Result<()> {
         let market = &mut ctx.accounts.market;
-        let participant = &mut ctx.accounts.participant;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require_token_gate(&ctx.accounts.token_gate, &ctx.accounts.user.key(), ctx.remaining_accounts)?;
         
-        // Validate market is resolved
-        require!(market.status == MarketStatus::Resolved, MarketError::MarketNotResolved);
+        let current_time = Clock::get()?.unix_timestamp;
+        require_join_window(market, &ctx.accounts.in_play_lock, &ctx.accounts.live_score, current_time)?;
         
-        // Validate participant hasn't withdrawn
-        require!(!participant.has_withdrawn, MarketError::AlreadyWithdrawn);
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &market.key(),
+            market.entry_fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                market.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
         
-        // Validate participant is a winner
-        let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
-        require!(participant.prediction == *outcome, MarketError::NotAWinner);
+        let user = ctx.accounts.user.key();
+        let prediction = ctx.accounts.leader_participant.prediction.clone();
+        let participant = &mut ctx.accounts.participant;
+        participant.bump = ctx.bumps.participant;
+        record_join(market, participant, user, prediction.clone(), current_time)?;
+        track_earliest_entry(&ctx.accounts.first_correct_bonus, participant)?;
+        append_to_roster(market, &ctx.accounts.roster, user, &ctx.accounts.user, &ctx.accounts.system_program)?;
         
-        // Calculate winner count
-        let winner_count = match outcome {
-            MatchOutcome::Home => market.home_count,
-            MatchOutcome::Draw => market.draw_count,
-            MatchOutcome::Away => market.away_count,
-        };
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user,
+                amount: market.entry_fee,
+            },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
         
-        // Validate there are winners
-        require!(winner_count > 0, MarketError::NoWinners);
+        require_solvent(market)?;
         
-        // Calculate fees (2% creator + 3% platform = 5% total)
-        let creator_fee = market.total_pool.checked_mul(200).unwrap() / 10000; // 2%
-        let platform_fee = market.total_pool.checked_mul(300).unwrap() / 10000; // 3%
-        let total_fees = creator_fee.checked_add(platform_fee)
-            .ok_or(MarketError::CalculationError)?;
-        
-        // Calculate prize pool after fees (fees already distributed during resolution)
-        let prize_pool = market.total_pool.checked_sub(total_fees)
-            .ok_or(MarketError::CalculationError)?;
-        
-        // Calculate individual reward
-        let reward = prize_pool.checked_div(winner_count as u64)
-            .ok_or(MarketError::CalculationError)?;
-        
-        // Transfer reward to participant
-        **market.to_account_info().try_borrow_mut_lamports()? = market
-            .to_account_info()
-            .lamports()
-            .checked_sub(reward)
-            .ok_or(MarketError::InsufficientFunds)?;
-        
-        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? = ctx
-            .accounts
-            .user
-            .to_account_info()
-            .lamports()
-            .checked_add(reward)
-            .ok_or(MarketError::CalculationError)?;
-        
-        // Mark as withdrawn
-        participant.has_withdrawn = true;
+        let follow = &mut ctx.accounts.follow;
+        follow.copies = follow.copies.saturating_add(1);
         
-        // Emit event
-        emit!(RewardClaimed {
+        emit!(PredictionMade {
             market: market.key(),
-            user: ctx.accounts.user.key(),
-            amount: reward,
+            user,
+            prediction: prediction.clone(),
+            timestamp: current_time,
+            entry_index: participant.entry_index,
+        });
+        emit!(CopyJoined {
+            market: market.key(),
+            follower: user,
+            leader: follow.leader,
+            prediction,
+            amount: market.entry_fee,
         });
         
-        msg!("User {} withdrew reward: {} lamports", 
-            ctx.accounts.user.key(), reward);
+        msg!("User {} copied {} into market {}", user, follow.leader, market.key());
         
         Ok(())
     }
-}
 
-// Account Structures
+    /// Switch a private market to committed predictions, so entries stay
+    /// hidden until kickoff (creator or co-admin, before anyone joins)
+    pub fn enable_commit_reveal(ctx: Context<EnableCommitReveal>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require!(!market.is_public, MarketError::CommitRevealPrivateOnly);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require_ungated(&ctx.accounts.token_gate)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        
+        let commit_reveal = &mut ctx.accounts.commit_reveal;
+        commit_reveal.market = market.key();
+        commit_reveal.commitments = 0;
+        commit_reveal.reveals = 0;
+        commit_reveal.bump = ctx.bumps.commit_reveal;
+        
+        msg!("Market {} now takes committed predictions", market.key());
+        
+        Ok(())
+    }
 
-#[account]
-pub struct Market {
-    /// Factory that created this market
-    pub factory: Pubkey,
-    /// Creator of the market
-    pub creator: Pubkey,
-    /// Match identifier
-    pub match_id: String,
-    /// Entry fee in lamports
-    pub entry_fee: u64,
-    /// Match kickoff timestamp
-    pub kickoff_time: i64,
-    /// Match end timestamp
-    pub end_time: i64,
-    /// Current market status
-    pub status: MarketStatus,
-    /// Match outcome (if resolved)
-    pub outcome: Option<MatchOutcome>,
-    /// Total pool in lamports
-    pub total_pool: u64,
-    /// Total number of participants
-    pub participant_count: u32,
-    /// Number of HOME predictions
-    pub home_count: u32,
-    /// Number of DRAW predictions
-    pub draw_count: u32,
-    /// Number of AWAY predictions
-    pub away_count: u32,
-    /// Whether market is public
-    pub is_public: bool,
-    /// PDA bump seed
-    pub bump: u8,
-}
+    /// Limit a market to holders of an SPL token, e.g. a club's fan token, or
+    /// of an NFT from a verified Metaplex collection (creator or co-admin,
+    /// before anyone joins)
+    ///
+    /// Joiners then pass their token account, followed for collections by
+    /// the NFT's metadata account, as remaining accounts. Queued joins and
+    /// committed predictions aren't available on gated markets.
+    pub fn set_token_gate(ctx: Context<SetTokenGate>, rule: TokenGateRule) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require!(rule.is_valid(), MarketError::InvalidTokenGate);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        
+        let token_gate = &mut ctx.accounts.token_gate;
+        token_gate.market = market.key();
+        token_gate.rule = rule;
+        token_gate.bump = ctx.bumps.token_gate;
+        
+        msg!("Market {} now only takes holders' joins", market.key());
+        
+        Ok(())
+    }
 
-impl Market {
-    pub const MAX_MATCH_ID_LEN: usize = 64;
-    
-    pub const LEN: usize = 8 + // discriminator
-        32 + // factory
-        32 + // creator
-        4 + Self::MAX_MATCH_ID_LEN + // match_id
-        8 +  // entry_fee
-        8 +  // kickoff_time
-        8 +  // end_time
-        1 +  // status
-        1 + 1 + // outcome (Option<MatchOutcome>)
-        8 +  // total_pool
-        4 +  // participant_count
-        4 +  // home_count
-        4 +  // draw_count
-        4 +  // away_count
-        1 +  // is_public
-        1;   // bump
-}
+    /// Keep an on-chain roster of the market's participants, paged
+    /// `ROSTER_PAGE_SIZE` to a `roster_page` PDA, so they can be enumerated
+    /// without scanning program accounts (creator or co-admin, before anyone
+    /// joins)
+    ///
+    /// Every join then lists its user on the last page, with the entry that
+    /// opens a new page paying its rent. Committed predictions are listed
+    /// when they are revealed.
+    pub fn enable_participant_roster(ctx: Context<EnableParticipantRoster>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        
+        let roster = &mut ctx.accounts.participant_roster;
+        roster.market = market.key();
+        roster.count = 0;
+        roster.bump = ctx.bumps.participant_roster;
+        
+        msg!("Market {} now lists its participants on-chain", market.key());
+        
+        Ok(())
+    }
 
-#[account]
-pub struct Participant {
-    /// Market this participant joined
-    pub market: Pubkey,
-    /// User's wallet address
-    pub user: Pubkey,
-    /// User's prediction
-    pub prediction: MatchOutcome,
-    /// Timestamp when joined
-    pub joined_at: i64,
-    /// Whether rewards have been withdrawn
-    pub has_withdrawn: bool,
-    /// PDA bump seed
+    /// Make the market a charity market: at resolution its creator and
+    /// platform fees, and `pool_share_bps` of the prize pool on top, all go
+    /// to `charity` (creator or co-admin, before anyone joins)
+    ///
+    /// Resolution then has to pass the recorded charity's account, and
+    /// reports the donation with `CharityDonated` in place of
+    /// `FeesDistributed`. The charity can't be the market or one of its
+    /// admins.
+    pub fn pledge_to_charity(ctx: Context<PledgeToCharity>, charity: Pubkey, pool_share_bps: u16) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require!(
+            charity != Pubkey::default() && charity != market.key() && !market.is_admin(&charity),
+            MarketError::InvalidCharity
+        );
+        require_gte!(MAX_CHARITY_POOL_SHARE_BPS, pool_share_bps, MarketError::InvalidCharity);
+        
+        let pledge = &mut ctx.accounts.charity_pledge;
+        pledge.market = market.key();
+        pledge.charity = charity;
+        pledge.pool_share_bps = pool_share_bps;
+        pledge.donated = 0;
+        pledge.bump = ctx.bumps.charity_pledge;
+        
+        emit!(CharityPledged {
+            market: market.key(),
+            charity,
+            pool_share_bps,
+        });
+        
+        msg!("Market {} now gives its fees and {} bps of its pool to {}", market.key(), pool_share_bps, charity);
+        
+        Ok(())
+    }
+
+    /// Offer a bonus to the market's earliest correct entry: at resolution
+    /// `bonus_bps` of the creator fee is set aside for the first entry, by
+    /// `entry_index`, on the winning outcome, and paid with its reward
+    /// (creator or co-admin, before anyone joins)
+    ///
+    /// Charity, player prop and correct-score markets take no creator fee on
+    /// an outcome, so they never pay one.
+    pub fn offer_first_correct_bonus(ctx: Context<OfferFirstCorrectBonus>, bonus_bps: u16) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require!(
+            bonus_bps > 0 && u64::from(bonus_bps) <= BPS_DENOMINATOR,
+            MarketError::InvalidFirstCorrectBonus
+        );
+        
+        let bonus = &mut ctx.accounts.first_correct_bonus;
+        bonus.market = market.key();
+        bonus.bonus_bps = bonus_bps;
+        bonus.earliest = [None; 3];
+        bonus.winner = None;
+        bonus.bonus = 0;
+        bonus.claimed = false;
+        bonus.bump = ctx.bumps.first_correct_bonus;
+        
+        msg!("Market {} now pays {} bps of its creator fee to its first correct entry", market.key(), bonus_bps);
+        
+        Ok(())
+    }
+
+    /// Chain the market to `follow_up`, another of the creator's markets on
+    /// the same factory kicking off after this one ends, so its winners can
+    /// let their rewards ride into it with `roll_rewards` (creator or
+    /// co-admin, before resolution)
+    pub fn set_follow_up_market(ctx: Context<SetFollowUpMarket>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let follow_up = &ctx.accounts.follow_up;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require!(
+            follow_up.key() != market.key()
+                && follow_up.creator == market.creator
+                && follow_up.factory == market.factory
+                && follow_up.kickoff_time >= market.end_time,
+            MarketError::InvalidFollowUpMarket
+        );
+        
+        let chain = &mut ctx.accounts.market_chain;
+        chain.market = market.key();
+        chain.follow_up = follow_up.key();
+        chain.rolled = 0;
+        chain.bump = ctx.bumps.market_chain;
+        
+        msg!("Market {} now rolls rewards into market {}", market.key(), follow_up.key());
+        
+        Ok(())
+    }
+
+    /// Keep the market taking joins after kickoff, until the match's live
+    /// score reaches `lock_minute` or, with `lock_on_goal`, the first goal
+    /// (creator or co-admin, before kickoff)
+    ///
+    /// Joins after kickoff then have to pass the live score the factory's
+    /// results signer reports for the match, and stop for good once it is
+    /// final or the market has ended.
+    pub fn enable_in_play(ctx: Context<EnableInPlay>, lock_minute: u16, lock_on_goal: bool) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_gt!(market.kickoff_time, Clock::get()?.unix_timestamp, MarketError::MarketAlreadyStarted);
+        if lock_minute == 0 || lock_minute > MAX_IN_PLAY_LOCK_MINUTE {
+            return Err(error!(MarketError::InvalidInPlayLock).with_values((lock_minute, MAX_IN_PLAY_LOCK_MINUTE)));
+        }
+        
+        let (live_score, _) = Pubkey::find_program_address(
+            &[LIVE_SCORE_SEED, market.factory.as_ref(), match_id_seed(&market.match_id).as_ref()],
+            &cryptoscore_factory::ID,
+        );
+        let lock = &mut ctx.accounts.in_play_lock;
+        lock.market = market.key();
+        lock.live_score = live_score;
+        lock.lock_minute = lock_minute;
+        lock.lock_on_goal = lock_on_goal;
+        lock.bump = ctx.bumps.in_play_lock;
+        
+        emit!(InPlayEnabled {
+            market: market.key(),
+            live_score,
+            lock_minute,
+            lock_on_goal,
+        });
+        
+        msg!("Market {} takes joins in play until minute {}", market.key(), lock_minute);
+        
+        Ok(())
+    }
+
+    /// Make the market a player prop market on `kind`, with `player_ids` as
+    /// its outcomes (creator or co-admin, before anyone joins)
+    ///
+    /// Entries then pick a listed player with `join_prop`, and the factory's
+    /// results signer resolves the market with `resolve_prop` by the ID of
+    /// the player it reports. Prop markets don't take match predictions or
+    /// committed predictions.
+    ///
+    /// `PropKind::Outright` markets list team IDs instead and run for a
+    /// season: entries close at kickoff, which can be months out, and the
+    /// results signer records standings checkpoints with
+    /// `record_outright_checkpoint` until the winner is known.
+    pub fn list_prop_players(ctx: Context<ListPropPlayers>, kind: PropKind, player_ids: Vec<u32>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require_eq!(market.queued_join_count, 0, MarketError::MarketHasQueuedJoins);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require!(
+            *ctx.accounts.score_grid.owner != crate::ID || ctx.accounts.score_grid.data_is_empty(),
+            MarketError::CorrectScoreMarket
+        );
+        
+        let unique: BTreeSet<&u32> = player_ids.iter().collect();
+        if player_ids.is_empty() || player_ids.len() > MAX_PROP_PLAYERS || unique.len() != player_ids.len() {
+            return Err(error!(MarketError::InvalidPropPlayers).with_values((player_ids.len(), MAX_PROP_PLAYERS)));
+        }
+        
+        let registry = &mut ctx.accounts.prop_registry;
+        registry.market = market.key();
+        registry.kind = kind;
+        registry.pick_counts = vec![0; player_ids.len()];
+        registry.player_ids = player_ids;
+        registry.winning_player = None;
+        registry.bump = ctx.bumps.prop_registry;
+        
+        emit!(PropPlayersListed {
+            market: market.key(),
+            kind,
+            player_ids: registry.player_ids.clone(),
+        });
+        
+        msg!("Market {} now takes {:?} picks on {} players", market.key(), kind, registry.player_ids.len());
+        
+        Ok(())
+    }
+
+    /// Enter a player prop market on `player_id`, one of its listed players
+    ///
+    /// Entries close at kickoff, or at the in-play lock if the creator has
+    /// enabled one. Token-gated markets need the same proof of holdings as
+    /// `join_market`.
+    pub fn join_prop(ctx: Context<JoinProp>, player_id: u32) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_token_gate(&ctx.accounts.token_gate, &ctx.accounts.user.key(), ctx.remaining_accounts)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_join_window(market, &ctx.accounts.in_play_lock, &ctx.accounts.live_score, current_time)?;
+        
+        let registry = &mut ctx.accounts.prop_registry;
+        let slot = registry.player_slot(player_id).ok_or_else(|| {
+            error!(MarketError::PropPlayerNotListed).with_values((player_id, registry.player_ids.len()))
+        })?;
+        
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &market.key(),
+            market.entry_fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                market.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        add_entry(market, current_time)?;
+        registry.pick_counts[slot] = registry.pick_counts[slot].checked_add(1)
+            .ok_or(MarketError::CountOverflow)?;
+        
+        let entry = &mut ctx.accounts.prop_entry;
+        entry.market = market.key();
+        entry.user = ctx.accounts.user.key();
+        entry.player_id = player_id;
+        entry.joined_at = current_time;
+        entry.has_withdrawn = false;
+        entry.bump = ctx.bumps.prop_entry;
+        
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user: ctx.accounts.user.key(),
+                amount: market.entry_fee,
+            },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
+        
+        emit!(PropPicked {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            player_id,
+        });
+        
+        msg!("User {} picked player {} on market {}", ctx.accounts.user.key(), player_id, market.key());
+        
+        Ok(())
+    }
+
+    /// Make the market a correct-score market (creator or co-admin, before
+    /// anyone joins)
+    ///
+    /// Entries then pick a final score with `join_correct_score`: an exact
+    /// scoreline with at most `SCORE_GRID_MAX_GOALS` a side, or
+    /// `Scoreline::AnyOther` for every score off the grid. The market
+    /// resolves from the match's final live score with
+    /// `resolve_correct_score`. Correct-score markets don't take match
+    /// predictions or committed predictions.
+    pub fn enable_correct_score(ctx: Context<EnableCorrectScore>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require_eq!(market.queued_join_count, 0, MarketError::MarketHasQueuedJoins);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require!(
+            *ctx.accounts.prop_registry.owner != crate::ID || ctx.accounts.prop_registry.data_is_empty(),
+            MarketError::PropMarket
+        );
+        
+        let grid = &mut ctx.accounts.score_grid;
+        grid.market = market.key();
+        grid.pick_counts = [0; SCORE_GRID_BUCKETS];
+        grid.result = None;
+        grid.bump = ctx.bumps.score_grid;
+        
+        emit!(CorrectScoreEnabled {
+            market: market.key(),
+        });
+        
+        msg!("Market {} now takes correct-score picks", market.key());
+        
+        Ok(())
+    }
+
+    /// Enter a correct-score market on `scoreline`
+    ///
+    /// Exact scorelines must be on the grid; any other score is picked with
+    /// `Scoreline::AnyOther`. Entries close as on `join_prop`, and
+    /// token-gated markets need the same proof of holdings as `join_market`.
+    pub fn join_correct_score(ctx: Context<JoinCorrectScore>, scoreline: Scoreline) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_token_gate(&ctx.accounts.token_gate, &ctx.accounts.user.key(), ctx.remaining_accounts)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_join_window(market, &ctx.accounts.in_play_lock, &ctx.accounts.live_score, current_time)?;
+        
+        let bucket = scoreline.bucket().ok_or_else(|| {
+            error!(MarketError::InvalidScoreline).with_values((format!("{:?}", scoreline), SCORE_GRID_MAX_GOALS))
+        })?;
+        
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &market.key(),
+            market.entry_fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                market.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        add_entry(market, current_time)?;
+        let grid = &mut ctx.accounts.score_grid;
+        grid.pick_counts[bucket] = grid.pick_counts[bucket].checked_add(1)
+            .ok_or(MarketError::CountOverflow)?;
+        
+        let entry = &mut ctx.accounts.score_entry;
+        entry.market = market.key();
+        entry.user = ctx.accounts.user.key();
+        entry.scoreline = scoreline;
+        entry.joined_at = current_time;
+        entry.has_withdrawn = false;
+        entry.bump = ctx.bumps.score_entry;
+        
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user: ctx.accounts.user.key(),
+                amount: market.entry_fee,
+            },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
+        
+        emit!(ScorePicked {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            scoreline,
+        });
+        
+        msg!("User {} picked {:?} on market {}", ctx.accounts.user.key(), scoreline, market.key());
+        
+        Ok(())
+    }
+
+    /// Make the market a handicap market, quoting `home_line` and
+    /// `away_line` half goals for each side (creator or co-admin, before
+    /// anyone joins)
+    ///
+    /// The lines mirror each other, e.g. -3 and 3 for home -1.5. Entries
+    /// join as usual and the market resolves with `resolve_handicap`, which
+    /// applies the line to the final score. Asian markets refund every entry
+    /// when a whole-goal line leaves the sides level; European markets only
+    /// take whole-goal lines and settle a level result as a draw.
+    pub fn set_handicap(ctx: Context<SetHandicap>, style: HandicapStyle, home_line: i8, away_line: i8) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_eq!(market.participant_count, 0, MarketError::MarketHasEntries);
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        if !style.allows_line(home_line) || home_line.checked_add(away_line) != Some(0) {
+            return Err(error!(MarketError::InvalidHandicapLine).with_values((home_line, away_line)));
+        }
+        
+        let handicap = &mut ctx.accounts.handicap;
+        handicap.market = market.key();
+        handicap.style = style;
+        handicap.home_line = home_line;
+        handicap.away_line = away_line;
+        handicap.bump = ctx.bumps.handicap;
+        
+        emit!(HandicapSet {
+            market: market.key(),
+            style,
+            home_line,
+            away_line,
+        });
+        
+        msg!("Market {} now settles on a {:?} handicap of {} half goals", market.key(), style, home_line);
+        
+        Ok(())
+    }
+
+    /// Enter a commit–reveal market with `commitment`, the
+    /// `prediction_commitment` of a prediction revealed after kickoff
+    ///
+    /// The entry fee joins the pool now; it stays there if the prediction is
+    /// never revealed.
+    pub fn commit_prediction(ctx: Context<CommitPrediction>, commitment: [u8; 32]) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gt!(market.kickoff_time, current_time, MarketError::MarketAlreadyStarted);
+        
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &market.key(),
+            market.entry_fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                market.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        let entry_index = market.participant_count;
+        add_entry(market, current_time)?;
+        
+        let user = ctx.accounts.user.key();
+        let committed = &mut ctx.accounts.commitment;
+        committed.market = market.key();
+        committed.user = user;
+        committed.commitment = commitment;
+        committed.committed_at = current_time;
+        committed.bump = ctx.bumps.commitment;
+        committed.entry_index = entry_index;
+        
+        let commit_reveal = &mut ctx.accounts.commit_reveal;
+        commit_reveal.commitments = commit_reveal.commitments.checked_add(1)
+            .ok_or(MarketError::ParticipantOverflow)?;
+        
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user,
+                amount: market.entry_fee,
+            },
+        )?;
+        credit_points(
+            market,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: market.entry_fee },
+        )?;
+        
+        require_solvent(market)?;
+        
+        emit!(PredictionCommitted {
+            market: market.key(),
+            user,
+            timestamp: current_time,
+        });
+        
+        msg!("User {} committed a prediction", user);
+        
+        Ok(())
+    }
+
+    /// Reveal a committed prediction, becoming a participant on it
+    ///
+    /// Reveals open at kickoff and close when the match ends, before it can
+    /// be resolved. A cancelled market accepts them at any time so committed
+    /// entry fees can be refunded.
+    pub fn reveal_prediction(
+        ctx: Context<RevealPrediction>,
+        prediction: MatchOutcome,
+        salt: [u8; SALT_LEN],
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let committed = &ctx.accounts.commitment;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let in_window = !market.status.is_resolved()
+            && current_time >= market.kickoff_time
+            && current_time < market.end_time;
+        require!(in_window || market.status.is_cancelled(), MarketError::RevealWindowClosed);
+        
+        let expected = prediction_commitment(&market.key(), &committed.user, &prediction, &salt);
+        require!(expected == committed.commitment, MarketError::InvalidReveal);
+        
+        let participant = &mut ctx.accounts.participant;
+        participant.market = market.key();
+        participant.user = committed.user;
+        participant.prediction = prediction.clone();
+        participant.joined_at = committed.committed_at;
+        participant.has_withdrawn = false;
+        participant.bump = ctx.bumps.participant;
+        participant.entry_index = committed.entry_index;
+        count_prediction(market, &prediction)?;
+        track_earliest_entry(&ctx.accounts.first_correct_bonus, participant)?;
+        append_to_roster(
+            market,
+            &ctx.accounts.roster,
+            committed.user,
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+        )?;
+        
+        let commit_reveal = &mut ctx.accounts.commit_reveal;
+        commit_reveal.reveals = commit_reveal.reveals.saturating_add(1);
+        
+        emit!(PredictionMade {
+            market: market.key(),
+            user: committed.user,
+            prediction: prediction.clone(),
+            timestamp: current_time,
+            entry_index: participant.entry_index,
+        });
+        
+        msg!("User {} revealed prediction: {:?}", committed.user, prediction);
+        
+        Ok(())
+    }
+
+    /// Resolve market with the match's final score and distribute fees
+    ///
+    /// The outcome is derived from `home_goals` and `away_goals` rather than
+    /// picked by the resolver, and the score is kept on the market.
+    pub fn resolve_market(
+        ctx: Context<ResolveMarket>,
+        home_goals: u8,
+        away_goals: u8,
+    ) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let resolver = ctx.accounts.resolver.key();
+        
+        // Validate resolver is either creator or a participant
+        let is_creator = resolver == market.creator;
+        let is_participant = ctx.accounts.participant.is_some();
+        
+        require!(
+            is_creator || is_participant,
+            MarketError::UnauthorizedResolver
+        );
+        
+        // If participant, validate they actually joined this market
+        if let Some(participant) = &ctx.accounts.participant {
+            require!(
+                participant.market == market.key(),
+                MarketError::UnauthorizedResolver
+            );
+        }
+        
+        require_single_resolver(ctx.accounts)?;
+        settle_resolution(ctx.accounts, FinalScore::new(home_goals, away_goals))
+    }
+
+    /// Resolve with a final score attested by the platform's results signer
+    ///
+    /// For leagues without a decentralized oracle. The instruction right
+    /// before this one must be an ed25519 program check of the results
+    /// signer's signature over `attestation_message(match_id, score,
+    /// attested_at)`, so anyone can relay the result but only the signer can
+    /// author it. The signer may never be the market's creator.
+    pub fn resolve_with_attestation(
+        ctx: Context<ResolveWithAttestation>,
+        home_goals: u8,
+        away_goals: u8,
+        attested_at: i64,
+    ) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let results_signer = ctx.accounts.results_signer.signer;
+        require_keys_neq!(results_signer, market.creator, MarketError::CreatorIsResultsSigner);
+        
+        // Results are signed after the final whistle, never ahead of the clock
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gte!(attested_at, market.end_time, MarketError::InvalidAttestation);
+        require_gte!(current_time, attested_at, MarketError::InvalidAttestation);
+        
+        let score = FinalScore::new(home_goals, away_goals);
+        let message = attestation_message(&market.match_id, &score, attested_at);
+        verify_attestation(&ctx.accounts.resolution.instructions, &results_signer, &message)?;
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        emit!(ResultAttested {
+            market: market.key(),
+            signer: results_signer,
+            outcome: score.outcome(),
+            attested_at,
+        });
+        
+        settle_resolution(&mut ctx.accounts.resolution, score)?;
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve with a result bridged from another chain's oracle through Wormhole
+    ///
+    /// `posted_vaa` must be a VAA the factory's registered core bridge has
+    /// already verified and posted, emitted by the registered oracle and
+    /// carrying a `result_payload` for this market's match. Anyone can relay
+    /// it, as the guardians' signatures vouch for the result.
+    pub fn resolve_with_vaa(ctx: Context<ResolveWithVaa>) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+        
+        let (score, attested_at, sequence) = {
+            let data = ctx.accounts.posted_vaa.try_borrow_data()?;
+            let vaa = parse_posted_vaa(&data).ok_or_else(|| error!(MarketError::InvalidVaa))?;
+            require!(
+                vaa.emitter_chain == wormhole_emitter.emitter_chain
+                    && vaa.emitter_address == wormhole_emitter.emitter_address,
+                MarketError::UnknownVaaEmitter
+            );
+            
+            let result = parse_result_payload(vaa.payload).ok_or_else(|| error!(MarketError::InvalidVaa))?;
+            require!(result.match_id == market.match_id, MarketError::VaaMatchMismatch);
+            (result.score, result.timestamp, vaa.sequence)
+        };
+        
+        // Same timing rule as signed attestations
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gte!(attested_at, market.end_time, MarketError::InvalidVaa);
+        require_gte!(current_time, attested_at, MarketError::InvalidVaa);
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        emit!(ResultBridged {
+            market: market.key(),
+            emitter_chain: wormhole_emitter.emitter_chain,
+            sequence,
+            outcome: score.outcome(),
+            attested_at,
+        });
+        
+        settle_resolution(&mut ctx.accounts.resolution, score)?;
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve from the final update of the match's live score, the same
+    /// account frontends show during the match
+    ///
+    /// Anyone can relay it once the results signer has marked the score
+    /// final after the market's end time. As with attestations, the signer
+    /// may never be the market's creator.
+    pub fn resolve_with_live_score(ctx: Context<ResolveWithLiveScore>) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let live_score = &ctx.accounts.live_score;
+        require_keys_neq!(live_score.signer, market.creator, MarketError::CreatorIsResultsSigner);
+        
+        let score = live_score.final_score().ok_or_else(|| error!(MarketError::LiveScoreNotFinal))?;
+        require_gte!(live_score.updated_at, market.end_time, MarketError::LiveScoreNotFinal);
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        emit!(ResultAttested {
+            market: market.key(),
+            signer: live_score.signer,
+            outcome: score.outcome(),
+            attested_at: live_score.updated_at,
+        });
+        
+        settle_resolution(&mut ctx.accounts.resolution, score)?;
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve a player prop market with the ID of the player who scored
+    /// next or was booked, or `None` if no listed player did
+    ///
+    /// Only the factory's results signer can resolve, signing as the
+    /// resolver, and never on a market it created. Fees come out of the pool
+    /// as for match markets; with no winning entries the pool stays in the
+    /// vault until it is swept.
+    pub fn resolve_prop(ctx: Context<ResolveProp>, player_id: Option<u32>) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let results_signer = ctx.accounts.results_signer.signer;
+        require_keys_neq!(results_signer, market.creator, MarketError::CreatorIsResultsSigner);
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        let registry_info = ctx.accounts.resolution.prop_registry.to_account_info();
+        let mut registry = read_prop_registry(&registry_info)?.ok_or(MarketError::NotAPropMarket)?;
+        let winner_count = match player_id {
+            Some(player_id) => {
+                let slot = registry.player_slot(player_id).ok_or_else(|| {
+                    error!(MarketError::PropPlayerNotListed).with_values((player_id, registry.player_ids.len()))
+                })?;
+                registry.pick_counts[slot]
+            }
+            None => 0,
+        };
+        
+        settle_pool(&mut ctx.accounts.resolution, None)?;
+        registry.winning_player = player_id;
+        registry.try_serialize(&mut &mut registry_info.try_borrow_mut_data()?[..])?;
+        
+        emit!(PropResolved {
+            market: ctx.accounts.resolution.market.key(),
+            kind: registry.kind,
+            player_id,
+            winner_count,
+            total_pool: ctx.accounts.resolution.market.total_pool,
+        });
+        
+        msg!("Prop market resolved with player: {:?}, winners: {}", player_id, winner_count);
+        
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Record a standings checkpoint on an outright market as the factory's
+    /// results signer, eliminating `eliminated`, the listed teams that can
+    /// no longer win
+    ///
+    /// Eliminations carry over between checkpoints, and at least one team
+    /// must be left. Once only one is, the outright is decided: entries
+    /// close, and anyone can settle it with `settle_outright_early`.
+    pub fn record_outright_checkpoint(ctx: Context<RecordOutrightCheckpoint>, eliminated: Vec<u32>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_keys_neq!(ctx.accounts.signer.key(), market.creator, MarketError::CreatorIsResultsSigner);
+        let registry = &ctx.accounts.prop_registry;
+        require!(registry.kind == PropKind::Outright, MarketError::NotAnOutrightMarket);
+        
+        // The first checkpoint creates the standings
+        let standings = &mut ctx.accounts.outright_standings;
+        if standings.checkpoint_count == 0 {
+            standings.market = market.key();
+            standings.eliminated = vec![false; registry.player_ids.len()];
+            standings.bump = ctx.bumps.outright_standings;
+        }
+        for team_id in &eliminated {
+            let slot = registry.player_slot(*team_id).ok_or_else(|| {
+                error!(MarketError::PropPlayerNotListed).with_values((*team_id, registry.player_ids.len()))
+            })?;
+            standings.eliminated[slot] = true;
+        }
+        let remaining = standings.remaining();
+        require_gt!(remaining, 0, MarketError::InvalidOutrightCheckpoint);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        standings.checkpoint_count = standings.checkpoint_count.checked_add(1)
+            .ok_or(MarketError::CountOverflow)?;
+        standings.last_checkpoint_at = current_time;
+        
+        // Nobody can enter a decided outright on its known winner
+        if remaining == 1 && market.kickoff_time > current_time {
+            market.kickoff_time = current_time;
+        }
+        
+        emit!(OutrightCheckpointed {
+            market: market.key(),
+            checkpoint: standings.checkpoint_count,
+            eliminated,
+            remaining: remaining as u32,
+        });
+        
+        msg!("Outright market {} checkpoint {}: {} teams left", market.key(), standings.checkpoint_count, remaining);
+        
+        Ok(())
+    }
+
+    /// Settle an outright market on its last team standing before the
+    /// season ends, relayed by anyone
+    ///
+    /// The market's end is brought forward to now and it resolves as
+    /// `resolve_prop` would on that team, tipping the relayer.
+    pub fn settle_outright_early(ctx: Context<SettleOutrightEarly>) -> Result<()> {
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        let registry_info = ctx.accounts.resolution.prop_registry.to_account_info();
+        let mut registry = read_prop_registry(&registry_info)?.ok_or(MarketError::NotAPropMarket)?;
+        require!(registry.kind == PropKind::Outright, MarketError::NotAnOutrightMarket);
+        let standings = &ctx.accounts.outright_standings;
+        let slot = standings.decided_slot().ok_or_else(|| {
+            error!(MarketError::OutrightUndecided).with_values((standings.remaining(), standings.checkpoint_count))
+        })?;
+        let team_id = registry.player_ids[slot];
+        
+        let market = &mut ctx.accounts.resolution.market;
+        market.end_time = market.end_time.min(Clock::get()?.unix_timestamp);
+        
+        settle_pool(&mut ctx.accounts.resolution, None)?;
+        registry.winning_player = Some(team_id);
+        registry.try_serialize(&mut &mut registry_info.try_borrow_mut_data()?[..])?;
+        
+        emit!(PropResolved {
+            market: ctx.accounts.resolution.market.key(),
+            kind: registry.kind,
+            player_id: Some(team_id),
+            winner_count: registry.pick_counts[slot],
+            total_pool: ctx.accounts.resolution.market.total_pool,
+        });
+        
+        msg!("Outright market settled early on team {}, winners: {}", team_id, registry.pick_counts[slot]);
+        
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve a correct-score market from the final score its factory's
+    /// results signer reported
+    ///
+    /// Relayed like `resolve_with_live_score`. A final score off the grid
+    /// settles on the shared `Scoreline::AnyOther` bucket; with no entries
+    /// on the settled scoreline the pool stays in the vault until it is
+    /// swept.
+    pub fn resolve_correct_score(ctx: Context<ResolveCorrectScore>) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let live_score = &ctx.accounts.live_score;
+        require_keys_neq!(live_score.signer, market.creator, MarketError::CreatorIsResultsSigner);
+        require!(live_score.is_final, MarketError::LiveScoreNotFinal);
+        require_gte!(live_score.updated_at, market.end_time, MarketError::LiveScoreNotFinal);
+        require_single_resolver(&ctx.accounts.resolution)?;
+        
+        let grid_info = ctx.accounts.resolution.score_grid.to_account_info();
+        let mut grid = read_score_grid(&grid_info)?.ok_or(MarketError::NotACorrectScoreMarket)?;
+        let (home_goals, away_goals) = (live_score.home_goals, live_score.away_goals);
+        let scoreline = Scoreline::from_score(home_goals, away_goals);
+        let winner_count = grid.winner_count(&scoreline);
+        
+        settle_pool(&mut ctx.accounts.resolution, None)?;
+        ctx.accounts.resolution.market.final_score = Some(FinalScore::new(home_goals, away_goals));
+        grid.result = Some(scoreline);
+        grid.try_serialize(&mut &mut grid_info.try_borrow_mut_data()?[..])?;
+        
+        emit!(CorrectScoreResolved {
+            market: ctx.accounts.resolution.market.key(),
+            home_goals,
+            away_goals,
+            scoreline,
+            winner_count,
+            total_pool: ctx.accounts.resolution.market.total_pool,
+        });
+        
+        msg!(
+            "Correct-score market resolved at {}-{} as {:?}, winners: {}",
+            home_goals, away_goals, scoreline, winner_count
+        );
+        
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve a handicap market from the final score its factory's results
+    /// signer reported, with the market's line applied
+    ///
+    /// Relayed like `resolve_with_live_score`. The side that wins after the
+    /// line settles the market as any other outcome would; an Asian push
+    /// cancels it instead, fee-free, so every entry can `claim_refund`.
+    pub fn resolve_handicap(ctx: Context<ResolveHandicap>) -> Result<()> {
+        let market = &ctx.accounts.resolution.market;
+        let live_score = &ctx.accounts.live_score;
+        require_keys_neq!(live_score.signer, market.creator, MarketError::CreatorIsResultsSigner);
+        require!(live_score.is_final, MarketError::LiveScoreNotFinal);
+        require_gte!(live_score.updated_at, market.end_time, MarketError::LiveScoreNotFinal);
+        require_single_resolver(&ctx.accounts.resolution)?;
+        require_match_entries(&ctx.accounts.resolution.prop_registry, &ctx.accounts.resolution.score_grid)?;
+        
+        let handicap = read_handicap(&ctx.accounts.resolution.handicap)?.ok_or(MarketError::NotAHandicapMarket)?;
+        let (home_goals, away_goals) = (live_score.home_goals, live_score.away_goals);
+        let outcome = handicap.style.settle(home_goals, away_goals, handicap.home_line);
+        
+        emit!(HandicapResolved {
+            market: market.key(),
+            home_goals,
+            away_goals,
+            home_line: handicap.home_line,
+            outcome: outcome.clone(),
+        });
+        
+        match outcome {
+            Some(outcome) => settle_pool(&mut ctx.accounts.resolution, Some(outcome))?,
+            None => {
+                let market = &mut ctx.accounts.resolution.market;
+                require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+                transition(market, MarketStatus::Cancelled)?;
+                require_solvent(market)?;
+                
+                emit!(MarketCancelled {
+                    market: market.key(),
+                    cancelled_by: ctx.accounts.resolution.resolver.key(),
+                    total_pool: market.total_pool,
+                });
+                
+                msg!("Handicap market {} pushed at {}-{}; entries are refunded", market.key(), home_goals, away_goals);
+            }
+        }
+        ctx.accounts.resolution.market.final_score = Some(FinalScore::new(home_goals, away_goals));
+        
+        tip_keeper(&ctx.accounts.resolution.market, &ctx.accounts.tip, &ctx.accounts.resolution.resolver)
+    }
+
+    /// Resolve several markets from one published results batch, e.g. a whole
+    /// matchweek, with any relayer as the resolver
+    ///
+    /// `remaining_accounts` holds each market's `resolve_market` accounts in
+    /// turn, with no participant and with the charity of a charity market. Every market is checked and settled as
+    /// `resolve_with_attestation` would settle it with the batch's result.
+    pub fn resolve_markets_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveMarketsBatch<'info>>,
+    ) -> Result<()> {
+        let results_batch = &ctx.accounts.results_batch;
+        let mut accounts = ctx.remaining_accounts;
+        require!(!accounts.is_empty(), MarketError::EmptyResolutionBatch);
+        
+        while !accounts.is_empty() {
+            let mut resolution = ResolveMarket::try_accounts(
+                ctx.program_id,
+                &mut accounts,
+                &[],
+                &mut ResolveMarketBumps::default(),
+                &mut BTreeSet::new(),
+            )?;
+            let score = batch_score(results_batch, &resolution.market)?;
+            require_single_resolver(&resolution)?;
+            
+            emit!(ResultAttested {
+                market: resolution.market.key(),
+                signer: results_batch.signer,
+                outcome: score.outcome(),
+                attested_at: results_batch.published_at,
+            });
+            
+            settle_resolution(&mut resolution, score)?;
+            // Persist each market before the next one is loaded
+            resolution.exit(ctx.program_id)?;
+        }
+        
+        Ok(())
+    }
+
+    /// Sign off on the final score as one of a high-stakes market's three
+    /// resolvers
+    ///
+    /// Markets whose pool reaches the factory's `high_stakes_pool` resolve
+    /// only once two of the creator, the results signer and the policy's
+    /// arbiter agree on the score: the first signs here and the second
+    /// settles the market with `co_sign_resolution`. Signing again replaces
+    /// the earlier score.
+    pub fn sign_resolution(ctx: Context<SignResolution>, home_goals: u8, away_goals: u8) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gte!(current_time, market.end_time, MarketError::MarketNotEnded);
+        require!(
+            ctx.accounts.resolution_policy.requires_co_signers(market.total_pool),
+            MarketError::CoSignersNotRequired
+        );
+        
+        let signer = ctx.accounts.signer.key();
+        let co_signers = co_signer_keys(market, &ctx.accounts.results_signer, &ctx.accounts.resolution_policy);
+        let slot = co_signer_slot(&co_signers, &signer)?;
+        
+        let score = FinalScore::new(home_goals, away_goals);
+        let votes = &mut ctx.accounts.resolution_votes;
+        votes.market = market.key();
+        votes.scores[slot] = Some(score);
+        votes.bump = ctx.bumps.resolution_votes;
+        
+        emit!(ResolutionSigned {
+            market: market.key(),
+            signer,
+            outcome: score.outcome(),
+        });
+        
+        msg!("{} signed {}-{} for market {}", signer, home_goals, away_goals, market.key());
+        
+        Ok(())
+    }
+
+    /// Resolve a high-stakes market as the second of its resolvers to sign
+    /// the same final score
+    ///
+    /// The co-signer is the resolver and pays for the settlement CPIs; the
+    /// first signature must come from a different one of the three keys.
+    pub fn co_sign_resolution(ctx: Context<CoSignResolution>, home_goals: u8, away_goals: u8) -> Result<()> {
+        let resolution = &ctx.accounts.resolution;
+        let policy = read_resolution_policy(&resolution.resolution_policy)?
+            .ok_or(MarketError::CoSignersNotRequired)?;
+        
+        let co_signers = co_signer_keys(&resolution.market, &ctx.accounts.results_signer, &policy);
+        let slot = co_signer_slot(&co_signers, &resolution.resolver.key())?;
+        let score = FinalScore::new(home_goals, away_goals);
+        let votes = &ctx.accounts.resolution_votes;
+        let first = (0..CO_SIGNER_SLOTS)
+            .find(|&other| other != slot && votes.scores[other] == Some(score))
+            .ok_or(MarketError::MissingCoSignature)?;
+        
+        emit!(ResolutionCoSigned {
+            market: resolution.market.key(),
+            outcome: score.outcome(),
+            signers: [co_signers[first], co_signers[slot]],
+        });
+        
+        settle_resolution(&mut ctx.accounts.resolution, score)
+    }
+
+    /// Correct the final score of a resolved match market as its factory's
+    /// results signer, up to `RESULT_CORRECTION_WINDOW_SECONDS` after it ends
+    ///
+    /// For results changed after the match, e.g. by a forfeit or an appeal.
+    /// A correction keeping the outcome only updates the score. One changing
+    /// it re-resolves the market if nobody has claimed yet: any first-correct
+    /// bonus moves to the new outcome's earliest entry and the winners root
+    /// starts over, while fees, which don't depend on the outcome, stay paid.
+    /// Payouts can't be clawed back once winners have claimed, so instead the
+    /// factory rules the market mis-resolved and the corrected outcome's
+    /// participants claim what they were owed from the insurance fund; an
+    /// unclaimed first-correct bonus goes back to the vault to be swept.
+    pub fn correct_result(ctx: Context<CorrectResult>, home_goals: u8, away_goals: u8) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require_keys_neq!(ctx.accounts.results_signer.signer, market.creator, MarketError::CreatorIsResultsSigner);
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        let resolved_outcome = market.outcome.clone().ok_or(MarketError::NoOutcome)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require!(
+            *ctx.accounts.handicap.owner != crate::ID || ctx.accounts.handicap.data_is_empty(),
+            MarketError::HandicapMarket
+        );
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let closes_at = market.end_time.saturating_add(RESULT_CORRECTION_WINDOW_SECONDS);
+        if current_time > closes_at {
+            return Err(error!(MarketError::CorrectionWindowClosed).with_values((current_time, closes_at)));
+        }
+        
+        let score = FinalScore::new(home_goals, away_goals);
+        require!(market.final_score != Some(score), MarketError::ResultUnchanged);
+        let outcome = score.outcome();
+        require_offered(market, &outcome)?;
+        
+        let claimed = market.status != MarketStatus::Resolved || market.total_claimed > 0;
+        let re_resolved = outcome != resolved_outcome && !claimed;
+        if outcome == resolved_outcome || re_resolved {
+            if re_resolved {
+                reassign_first_correct_bonus(market, &ctx.accounts.first_correct_bonus, &outcome)?;
+                restart_winners_root(market, &ctx.accounts.winners_root, &outcome)?;
+            }
+            let market = &mut ctx.accounts.market;
+            market.outcome = Some(outcome.clone());
+            market.final_score = Some(score);
+        } else if market.winner_count(&outcome) > 0 {
+            // The resolved outcome's earliest entry is no longer owed the bonus, so it's swept instead
+            return_first_correct_bonus(market, &ctx.accounts.first_correct_bonus)?;
+            rule_result_correction(ctx.accounts, outcome.clone())?;
+        }
+        
+        emit!(ResultCorrected {
+            market: ctx.accounts.market.key(),
+            signer: ctx.accounts.signer.key(),
+            resolved_outcome,
+            outcome: outcome.clone(),
+            home_goals,
+            away_goals,
+            re_resolved,
+        });
+        
+        msg!("Corrected the result of market {} to {}-{}", ctx.accounts.market.key(), home_goals, away_goals);
+        
+        Ok(())
+    }
+
+    /// Apply a tracked entry's result to its user's losing streak for rebates
+    ///
+    /// Permissionless so a crank can settle every tracked entry once its
+    /// market is cancelled or its result can no longer be corrected, tipped
+    /// by the factory if it has set a keeper tip policy.
+    pub fn settle_rebate_entry(ctx: Context<SettleRebateEntry>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        
+        let market_result = if market.status.is_cancelled() {
+            MarketResult::Void
+        } else if market.status.is_resolved() {
+            let current_time = Clock::get()?.unix_timestamp;
+            let final_at = market.end_time.saturating_add(RESULT_CORRECTION_WINDOW_SECONDS);
+            if current_time <= final_at {
+                return Err(error!(MarketError::CorrectionWindowOpen).with_values((current_time, final_at)));
+            }
+            let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
+            if ctx.accounts.participant.prediction == *outcome {
+                MarketResult::Win
+            } else {
+                MarketResult::Loss
+            }
+        } else {
+            return err!(MarketError::MarketNotResolved);
+        };
+        
+        apply_rebate_result(
+            market,
+            &ctx.accounts.rebate_tracker,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            market_result,
+        )?;
+        tip_keeper(market, &ctx.accounts.tip, &ctx.accounts.keeper)
+    }
+
+    /// List a winner's unclaimed reward in their dashboard pending claims
+    ///
+    /// Permissionless so a crank can record every winner once the market
+    /// resolves, fronting the account rent on the user's first win and
+    /// tipped by the factory if it has set a keeper tip policy.
+    pub fn record_pending_claim(ctx: Context<RecordPendingClaim>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let participant = &ctx.accounts.participant;
+        
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        require!(market.status != MarketStatus::Settled, MarketError::ClaimsClosed);
+        require!(!participant.has_withdrawn, MarketError::AlreadyWithdrawn);
+        
+        let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
+        require!(participant.prediction == *outcome, MarketError::NotAWinner);
+        
+        list_pending_claim(
+            market,
+            &ctx.accounts.pending_claims,
+            &ctx.accounts.user,
+            &ctx.accounts.cranker,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+        )?;
+        tip_keeper(market, &ctx.accounts.tip, &ctx.accounts.cranker)
+    }
+
+    /// Fold the next roster entries into the merkle root of the market's
+    /// winners, passing their participant accounts as the remaining
+    /// accounts in roster order
+    ///
+    /// Permissionless so a crank can work through the roster once the market
+    /// resolves, one page at most per call; the call reaching the end of the
+    /// roster publishes the root. Each winner's leaf is `winner_leaf` of
+    /// their pubkey and reward, so anyone holding the root can check a win
+    /// with `verify_winner`. The first call fronts the root account's rent,
+    /// and each is tipped by the factory if it has set a keeper tip policy.
+    pub fn build_winners_root<'info>(ctx: Context<'_, '_, 'info, 'info, BuildWinnersRoot<'info>>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
+        
+        let winners_root = &mut ctx.accounts.winners_root;
+        if winners_root.market == Pubkey::default() {
+            // Every winner is owed the same share of the pool
+            let winner_count = match outcome {
+                MatchOutcome::Home => market.home_count,
+                MatchOutcome::Draw => market.draw_count,
+                MatchOutcome::Away => market.away_count,
+            };
+            winners_root.market = market.key();
+            winners_root.reward = match winner_count {
+                0 => 0,
+                _ => market.reward_per_winner(winner_count).ok_or_else(|| {
+                    error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, winner_count))
+                })?,
+            };
+            winners_root.bump = ctx.bumps.winners_root;
+        }
+        require!(!winners_root.published, MarketError::WinnersRootPublished);
+        
+        let roster_count = ctx.accounts.participant_roster.count;
+        let participants = ctx.remaining_accounts;
+        if winners_root.cursor < roster_count {
+            // Only this program writes roster pages, so their fields can be trusted
+            let page_info = ctx.accounts.roster_page.to_account_info();
+            require_keys_eq!(*page_info.owner, crate::ID, MarketError::InvalidRosterAccount);
+            let roster_page = RosterPage::try_deserialize(&mut &page_info.try_borrow_data()?[..])?;
+            let page = winners_root.cursor / ROSTER_PAGE_SIZE as u32;
+            require!(
+                roster_page.market == market.key() && roster_page.page == page,
+                MarketError::InvalidRosterAccount
+            );
+            
+            let first = winners_root.cursor as usize % ROSTER_PAGE_SIZE;
+            let entries = roster_page.listed().get(first..).unwrap_or_default();
+            require!(
+                !participants.is_empty() && participants.len() <= entries.len(),
+                MarketError::RosterParticipantMismatch
+            );
+            
+            for (info, user) in participants.iter().zip(entries) {
+                let participant = Account::<Participant>::try_from(info)?;
+                require!(
+                    participant.market == market.key() && participant.user == *user,
+                    MarketError::RosterParticipantMismatch
+                );
+                
+                if participant.prediction == *outcome {
+                    let (count, leaf) = (winners_root.winners, winner_leaf(user, winners_root.reward));
+                    require!(
+                        append_leaf(&mut winners_root.frontier, count, leaf),
+                        MarketError::ParticipantOverflow
+                    );
+                    winners_root.winners += 1;
+                }
+            }
+            winners_root.cursor += participants.len() as u32;
+        } else {
+            require!(participants.is_empty(), MarketError::RosterParticipantMismatch);
+        }
+        
+        if winners_root.cursor == roster_count {
+            winners_root.root = frontier_root(&winners_root.frontier, winners_root.winners);
+            winners_root.published = true;
+            
+            emit!(WinnersRootPublished {
+                market: market.key(),
+                root: winners_root.root,
+                winners: winners_root.winners,
+                reward: winners_root.reward,
+            });
+            
+            msg!("Published the winners root of market {} ({} winners)", market.key(), winners_root.winners);
+        }
+        
+        tip_keeper(market, &ctx.accounts.tip, &ctx.accounts.cranker)
+    }
+
+    /// Withdraw rewards for winning participants
+    pub fn withdraw_rewards(ctx: Context<WithdrawRewards>) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.participant.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        ])?;
+        
+        let market = &mut ctx.accounts.market;
+        let participant = &mut ctx.accounts.participant;
+        let reward = winner_reward(market, participant)?;
+        
+        // Transfer reward to participant, with the first-correct bonus if it's theirs
+        transfer_from_vault(market, &ctx.accounts.user.to_account_info(), reward)?;
+        record_reward_claim(market, participant, reward)?;
+        let bonus = claim_first_correct_bonus(market, &ctx.accounts.first_correct_bonus, &ctx.accounts.user)?;
+        
+        // Emit event
+        emit!(RewardClaimed {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            amount: reward,
+        });
+        
+        // Prune the reward from the user's pending claims, if they have any
+        if ctx.accounts.pending_claims.owner == &ctx.accounts.dashboard_program.key() {
+            prune_pending_claim(
+                &ctx.accounts.market,
+                &ctx.accounts.pending_claims,
+                &ctx.accounts.user,
+                &ctx.accounts.dashboard_program,
+                &ctx.accounts.instructions,
+            )?;
+        }
+        
+        msg!("User {} withdrew reward: {} lamports, bonus: {} lamports", 
+            ctx.accounts.user.key(), reward, bonus);
+        
+        Ok(())
+    }
+
+    /// Let a winning reward ride into the market's follow-up market instead
+    /// of paying it out, entering the winner there on `prediction`
+    ///
+    /// The follow-up's entry fee is staked from the reward, and the rest and
+    /// any first-correct bonus are paid out as usual. A `RolledEntry` links
+    /// the new entry to this market and counts the winner's streak of
+    /// consecutive rolls, for promotions. The follow-up must still be taking
+    /// plain, ungated match entries before kickoff.
+    pub fn roll_rewards(ctx: Context<RollRewards>, prediction: MatchOutcome) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.participant.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        ])?;
+        
+        let follow_up = &ctx.accounts.follow_up;
+        require!(follow_up.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        require_plain_entries(&ctx.accounts.commit_reveal)?;
+        require_match_entries(&ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+        require_ungated(&ctx.accounts.token_gate)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gt!(follow_up.kickoff_time, current_time, MarketError::MarketAlreadyStarted);
+        let entry_fee = follow_up.entry_fee;
+        
+        // Pay out the reward as `withdraw_rewards` would
+        let market = &mut ctx.accounts.market;
+        let participant = &mut ctx.accounts.participant;
+        let reward = winner_reward(market, participant)?;
+        if reward < entry_fee {
+            return Err(error!(MarketError::RewardBelowEntryFee).with_values((reward, entry_fee)));
+        }
+        transfer_from_vault(market, &ctx.accounts.user.to_account_info(), reward)?;
+        record_reward_claim(market, participant, reward)?;
+        let bonus = claim_first_correct_bonus(market, &ctx.accounts.first_correct_bonus, &ctx.accounts.user)?;
+        
+        emit!(RewardClaimed {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            amount: reward,
+        });
+        
+        if ctx.accounts.pending_claims.owner == &ctx.accounts.dashboard_program.key() {
+            prune_pending_claim(
+                &ctx.accounts.market,
+                &ctx.accounts.pending_claims,
+                &ctx.accounts.user,
+                &ctx.accounts.dashboard_program,
+                &ctx.accounts.instructions,
+            )?;
+        }
+        
+        // Stake the follow-up's entry fee from it
+        let follow_up = &mut ctx.accounts.follow_up;
+        let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &follow_up.key(),
+            entry_fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                follow_up.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        let user = ctx.accounts.user.key();
+        let entry = &mut ctx.accounts.entry;
+        entry.bump = ctx.bumps.entry;
+        record_join(follow_up, entry, user, prediction.clone(), current_time)?;
+        track_earliest_entry(&ctx.accounts.follow_up_first_correct_bonus, entry)?;
+        append_to_roster(follow_up, &ctx.accounts.roster, user, &ctx.accounts.user, &ctx.accounts.system_program)?;
+        
+        record_activity(
+            follow_up,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Joined {
+                user,
+                amount: entry_fee,
+            },
+        )?;
+        credit_points(
+            follow_up,
+            &ctx.accounts.points_ledger,
+            &ctx.accounts.points_totals,
+            &ctx.accounts.user,
+            &ctx.accounts.user,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            &ctx.accounts.system_program,
+            PointsActivity::Wagered { amount: entry_fee },
+        )?;
+        
+        require_solvent(follow_up)?;
+        
+        // Link the entry to the reward that funded it
+        let streak = match read_rolled_entry(&ctx.accounts.previous_rolled_entry)? {
+            Some(previous) => previous.streak.saturating_add(1),
+            None => 1,
+        };
+        let rolled_entry = &mut ctx.accounts.rolled_entry;
+        rolled_entry.market = follow_up.key();
+        rolled_entry.user = user;
+        rolled_entry.source_market = ctx.accounts.market.key();
+        rolled_entry.reward = reward;
+        rolled_entry.streak = streak;
+        rolled_entry.rolled_at = current_time;
+        rolled_entry.bump = ctx.bumps.rolled_entry;
+        
+        let chain = &mut ctx.accounts.market_chain;
+        chain.rolled = chain.rolled.saturating_add(1);
+        
+        emit!(PredictionMade {
+            market: follow_up.key(),
+            user,
+            prediction,
+            timestamp: current_time,
+            entry_index: entry.entry_index,
+        });
+        emit!(RewardRolled {
+            market: ctx.accounts.market.key(),
+            follow_up: follow_up.key(),
+            user,
+            reward,
+            entry_fee,
+            streak,
+        });
+        
+        msg!("User {} rolled {} lamports into market {} (streak {}), bonus: {} lamports",
+            user, reward, follow_up.key(), streak, bonus);
+        
+        Ok(())
+    }
+
+    /// Withdraw a winning reward swapped into one of the factory's payout
+    /// tokens, e.g. USDC, through Jupiter
+    ///
+    /// The reward is wrapped in the user's wrapped SOL account, then
+    /// `route_data` and the remaining accounts, a Jupiter route quoted
+    /// off-chain, swap it into `output_tokens`, which must receive at least
+    /// `min_amount_out`. The market never signs the route, so it can only
+    /// spend what the user was paid.
+    pub fn withdraw_rewards_swapped<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawRewardsSwapped<'info>>,
+        route_data: Vec<u8>,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.participant.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.wrapped_sol.to_account_info(),
+            &ctx.accounts.output_tokens.to_account_info(),
+        ])?;
+        
+        let user = ctx.accounts.user.key();
+        let (wrapped_mint, wrapped_owner, _) = read_token_account(&ctx.accounts.wrapped_sol)?;
+        require!(wrapped_mint == NATIVE_MINT && wrapped_owner == user, MarketError::InvalidSwapAccount);
+        let (output_mint, output_owner, balance_before) = read_token_account(&ctx.accounts.output_tokens)?;
+        require_keys_eq!(output_owner, user, MarketError::InvalidSwapAccount);
+        if !ctx.accounts.payout_swap_policy.allows(&output_mint) {
+            return Err(error!(MarketError::PayoutMintNotAllowed)
+                .with_pubkeys((output_mint, ctx.accounts.payout_swap_policy.key())));
+        }
+        
+        let market = &mut ctx.accounts.market;
+        let participant = &mut ctx.accounts.participant;
+        let reward = winner_reward(market, participant)?;
+        
+        // Wrap the reward, then let the user's own signature carry the swap
+        transfer_from_vault(market, &ctx.accounts.wrapped_sol.to_account_info(), reward)?;
+        record_reward_claim(market, participant, reward)?;
+        // A first-correct bonus is paid in SOL, outside the swap
+        claim_first_correct_bonus(market, &ctx.accounts.first_correct_bonus, &ctx.accounts.user)?;
+        invoke(
+            &sync_native(&ctx.accounts.wrapped_sol.key()),
+            &[ctx.accounts.wrapped_sol.to_account_info(), ctx.accounts.token_program.to_account_info()],
+        )?;
+        let route = Instruction {
+            program_id: JUPITER_PROGRAM_ID,
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: route_data,
+        };
+        let mut route_infos = ctx.remaining_accounts.to_vec();
+        route_infos.push(ctx.accounts.jupiter_program.to_account_info());
+        invoke(&route, &route_infos)?;
+        
+        let (_, _, balance_after) = read_token_account(&ctx.accounts.output_tokens)?;
+        let amount_out = balance_after.saturating_sub(balance_before);
+        if amount_out < min_amount_out {
+            return Err(error!(MarketError::SwapSlippageExceeded).with_values((amount_out, min_amount_out)));
+        }
+        
+        emit!(RewardClaimed {
+            market: ctx.accounts.market.key(),
+            user,
+            amount: reward,
+        });
+        emit!(RewardSwapped {
+            market: ctx.accounts.market.key(),
+            user,
+            lamports: reward,
+            output_mint,
+            amount_out,
+        });
+        
+        // Prune the reward from the user's pending claims, if they have any
+        if ctx.accounts.pending_claims.owner == &ctx.accounts.dashboard_program.key() {
+            prune_pending_claim(
+                &ctx.accounts.market,
+                &ctx.accounts.pending_claims,
+                &ctx.accounts.user,
+                &ctx.accounts.dashboard_program,
+                &ctx.accounts.instructions,
+            )?;
+        }
+        
+        msg!("User {} withdrew reward: {} lamports swapped for {} of {}", user, reward, amount_out, output_mint);
+        
+        Ok(())
+    }
+
+    /// Mint a compressed NFT receipt of the user's withdrawn reward or
+    /// refund into the factory's receipt tree, for their records
+    ///
+    /// The receipt encodes the market, prediction, stake and payout in its
+    /// URI. The user pays for it, and each claim gets one receipt. Meant to
+    /// follow `withdraw_rewards` or `claim_refund` in the same transaction.
+    pub fn mint_claim_receipt(ctx: Context<MintClaimReceipt>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let participant = &ctx.accounts.participant;
+        let policy = &ctx.accounts.receipt_policy;
+        require!(participant.has_withdrawn, MarketError::ClaimNotWithdrawn);
+        
+        // Only winners and refunded participants withdraw, so a resolved
+        // market's claim is always a winning one
+        let payout = if market.status.is_cancelled() {
+            market.refund_per_participant()
+        } else {
+            let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
+            market
+                .reward_per_winner(market.winner_count(outcome))
+                .ok_or(MarketError::RewardCalculationFailed)?
+        };
+        
+        let receipt = &mut ctx.accounts.claim_receipt;
+        receipt.market = market.key();
+        receipt.user = ctx.accounts.user.key();
+        receipt.prediction = participant.prediction.clone();
+        receipt.stake = market.entry_fee;
+        receipt.payout = payout;
+        receipt.minted_at = Clock::get()?.unix_timestamp;
+        receipt.bump = ctx.bumps.claim_receipt;
+        
+        let authority_bump = [ctx.bumps.receipt_authority];
+        let authority_seeds: [&[u8]; 2] = [b"receipt_authority", &authority_bump];
+        invoke_signed(
+            &mint_receipt(
+                &policy.merkle_tree,
+                &receipt.user,
+                &receipt.user,
+                &ctx.accounts.receipt_authority.key(),
+                receipt_name(&market.match_id),
+                receipt_uri(&policy.uri_base, &receipt.market, &receipt.prediction, receipt.stake, payout),
+            ),
+            &[
+                ctx.accounts.tree_config.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.merkle_tree.to_account_info(),
+                ctx.accounts.receipt_authority.to_account_info(),
+                ctx.accounts.log_wrapper.to_account_info(),
+                ctx.accounts.compression_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.bubblegum_program.to_account_info(),
+            ],
+            &[&authority_seeds],
+        )?;
+        
+        emit!(ClaimReceiptMinted {
+            market: receipt.market,
+            user: receipt.user,
+            merkle_tree: policy.merkle_tree,
+            prediction: receipt.prediction.clone(),
+            stake: receipt.stake,
+            payout,
+        });
+        
+        msg!("Minted a claim receipt of market {} for user {}", receipt.market, receipt.user);
+        
+        Ok(())
+    }
+
+    /// Withdraw a winning player prop pick's share of the pool, or its
+    /// refund if the market was cancelled
+    pub fn withdraw_prop_rewards(ctx: Context<WithdrawPropRewards>) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.prop_entry.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        ])?;
+        
+        let market = &mut ctx.accounts.market;
+        let registry = &ctx.accounts.prop_registry;
+        let entry = &mut ctx.accounts.prop_entry;
+        require!(!entry.has_withdrawn, MarketError::AlreadyWithdrawn);
+        
+        // Cancelled prop markets refund each entry's equal share, as `claim_refund` does
+        if matches!(market.status, MarketStatus::Cancelled | MarketStatus::Refunding) {
+            let amount = market.refund_per_participant();
+            transfer_from_vault(market, &ctx.accounts.user.to_account_info(), amount)?;
+            entry.has_withdrawn = true;
+            record_payout(market, amount)?;
+            if market.status == MarketStatus::Cancelled {
+                transition(market, MarketStatus::Refunding)?;
+            }
+            emit!(RefundClaimed {
+                market: market.key(),
+                user: ctx.accounts.user.key(),
+                amount,
+            });
+            return Ok(());
+        }
+        
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        require!(market.status != MarketStatus::Settled, MarketError::ClaimsClosed);
+        if registry.winning_player != Some(entry.player_id) {
+            return Err(error!(MarketError::NotAWinner)
+                .with_values((entry.player_id, format!("{:?}", registry.winning_player))));
+        }
+        
+        let winner_count = registry.player_slot(entry.player_id).map_or(0, |slot| registry.pick_counts[slot]);
+        require!(winner_count > 0, MarketError::NoWinners);
+        let reward = market.reward_per_winner(winner_count).ok_or_else(|| {
+            error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, winner_count))
+        })?;
+        
+        transfer_from_vault(market, &ctx.accounts.user.to_account_info(), reward)?;
+        entry.has_withdrawn = true;
+        record_payout(market, reward)?;
+        if market.status == MarketStatus::Resolved {
+            transition(market, MarketStatus::Claiming)?;
+        }
+        
+        emit!(RewardClaimed {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            amount: reward,
+        });
+        
+        msg!("User {} withdrew prop reward: {} lamports", ctx.accounts.user.key(), reward);
+        
+        Ok(())
+    }
+
+    /// Withdraw a winning correct-score pick's share of the pool, or its
+    /// refund if the market was cancelled
+    ///
+    /// Every pick on the settled scoreline shares the pool equally, so an
+    /// `AnyOther` pick wins on any score off the grid.
+    pub fn withdraw_score_rewards(ctx: Context<WithdrawScoreRewards>) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.score_entry.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        ])?;
+        
+        let market = &mut ctx.accounts.market;
+        let grid = &ctx.accounts.score_grid;
+        let entry = &mut ctx.accounts.score_entry;
+        require!(!entry.has_withdrawn, MarketError::AlreadyWithdrawn);
+        
+        // Cancelled correct-score markets refund each entry's equal share, as `claim_refund` does
+        if matches!(market.status, MarketStatus::Cancelled | MarketStatus::Refunding) {
+            let amount = market.refund_per_participant();
+            transfer_from_vault(market, &ctx.accounts.user.to_account_info(), amount)?;
+            entry.has_withdrawn = true;
+            record_payout(market, amount)?;
+            if market.status == MarketStatus::Cancelled {
+                transition(market, MarketStatus::Refunding)?;
+            }
+            emit!(RefundClaimed {
+                market: market.key(),
+                user: ctx.accounts.user.key(),
+                amount,
+            });
+            return Ok(());
+        }
+        
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        require!(market.status != MarketStatus::Settled, MarketError::ClaimsClosed);
+        if grid.result != Some(entry.scoreline) {
+            return Err(error!(MarketError::NotAWinner)
+                .with_values((format!("{:?}", entry.scoreline), format!("{:?}", grid.result))));
+        }
+        
+        let winner_count = grid.winner_count(&entry.scoreline);
+        require!(winner_count > 0, MarketError::NoWinners);
+        let reward = market.reward_per_winner(winner_count).ok_or_else(|| {
+            error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, winner_count))
+        })?;
+        
+        transfer_from_vault(market, &ctx.accounts.user.to_account_info(), reward)?;
+        entry.has_withdrawn = true;
+        record_payout(market, reward)?;
+        if market.status == MarketStatus::Resolved {
+            transition(market, MarketStatus::Claiming)?;
+        }
+        
+        emit!(RewardClaimed {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            amount: reward,
+        });
+        
+        msg!("User {} withdrew correct-score reward: {} lamports", ctx.accounts.user.key(), reward);
+        
+        Ok(())
+    }
+
+    /// Sweep rewards left unclaimed after the claim window to the platform,
+    /// with any first-correct bonus its winner hasn't claimed
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+        require_distinct(&[&ctx.accounts.market.to_account_info(), &ctx.accounts.platform])?;
+        
+        let market = &mut ctx.accounts.market;
+        
+        // Validate market is resolved
+        require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+        
+        // Validate the claim window has closed
+        let current_time = Clock::get()?.unix_timestamp;
+        let claim_deadline = market.end_time.checked_add(CLAIM_WINDOW_SECONDS).ok_or_else(|| {
+            error!(MarketError::InvalidClaimDeadline).with_values((market.end_time, CLAIM_WINDOW_SECONDS))
+        })?;
+        require_gte!(current_time, claim_deadline, MarketError::ClaimWindowOpen);
+        
+        // The bonus counts as paid out at resolution, so once back in the vault it's swept with the rest
+        return_first_correct_bonus(market, &ctx.accounts.first_correct_bonus)?;
+        
+        // Keep the market rent-exempt so its record survives the sweep
+        let amount = vault_surplus(market)?;
+        require!(amount > 0, MarketError::NothingToSweep);
+        
+        transfer_from_vault(market, &ctx.accounts.platform, amount)?;
+        record_payout(market, amount)?;
+        transition(market, MarketStatus::Settled)?;
+        
+        emit!(UnclaimedSwept {
+            market: market.key(),
+            platform: ctx.accounts.platform.key(),
+            amount,
+        });
+        
+        msg!("Swept {} unclaimed lamports from market {}", amount, market.key());
+        
+        Ok(())
+    }
+
+    /// Appoint the users who can administer the market alongside the creator,
+    /// replacing any earlier appointments (creator only)
+    ///
+    /// Unused slots take the default pubkey; passing all defaults removes
+    /// every co-admin.
+    pub fn set_co_admins(ctx: Context<SetCoAdmins>, co_admins: [Pubkey; MAX_CO_ADMINS]) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        
+        for (i, co_admin) in co_admins.iter().enumerate() {
+            if *co_admin == Pubkey::default() {
+                continue;
+            }
+            require_keys_neq!(*co_admin, market.creator, MarketError::InvalidCoAdmin);
+            require!(!co_admins[..i].contains(co_admin), MarketError::InvalidCoAdmin);
+        }
+        
+        market.co_admins = co_admins;
+        
+        emit!(CoAdminsUpdated {
+            market: market.key(),
+            co_admins,
+        });
+        
+        msg!("Market {} co-admins updated", market.key());
+        
+        Ok(())
+    }
+
+    /// Call off a market so participants can reclaim their entry fees
+    ///
+    /// The creator and co-admins can cancel until kickoff, the factory
+    /// authority at any time before resolution.
+    pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let canceller = ctx.accounts.canceller.key();
+        
+        // Resolved markets only reach the refund path through `emergency_void`
+        require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+        
+        if canceller != ctx.accounts.factory.authority {
+            require!(market.is_admin(&canceller), MarketError::UnauthorizedCanceller);
+            let current_time = Clock::get()?.unix_timestamp;
+            require_gt!(market.kickoff_time, current_time, MarketError::MarketAlreadyStarted);
+        }
+        
+        transition(market, MarketStatus::Cancelled)?;
+        require_solvent(market)?;
+        
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Cancelled { was_resolved: false },
+        )?;
+        
+        emit!(MarketCancelled {
+            market: market.key(),
+            cancelled_by: canceller,
+            total_pool: market.total_pool,
+        });
+        
+        msg!("Market {} cancelled by {}", market.key(), canceller);
+        
+        Ok(())
+    }
+
+    /// Return a participant's entry fee from a cancelled market
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        require_distinct(&[
+            &ctx.accounts.market.to_account_info(),
+            &ctx.accounts.participant.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        ])?;
+        
+        let market = &mut ctx.accounts.market;
+        let participant = &mut ctx.accounts.participant;
+        
+        // Validate market is cancelled and still refunding
+        require!(
+            matches!(market.status, MarketStatus::Cancelled | MarketStatus::Refunding),
+            MarketError::MarketNotCancelled
+        );
+        
+        // Validate participant hasn't been refunded
+        require!(!participant.has_withdrawn, MarketError::AlreadyWithdrawn);
+        
+        // Yield from staking the idle pool is shared by how long each entry sat in it
+        let amount = match read_pool_yield(&ctx.accounts.yield_position)? {
+            Some((interest, unwound_at)) => market
+                .refund_with_interest(participant.joined_at, interest, unwound_at)
+                .ok_or_else(|| {
+                    error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, interest))
+                })?,
+            None => market.refund_per_participant(),
+        };
+        transfer_from_vault(market, &ctx.accounts.user.to_account_info(), amount)?;
+        participant.has_withdrawn = true;
+        record_payout(market, amount)?;
+        
+        // The first refund moves the market into its refunding phase
+        if market.status == MarketStatus::Cancelled {
+            transition(market, MarketStatus::Refunding)?;
+        }
+        
+        emit!(RefundClaimed {
+            market: market.key(),
+            user: ctx.accounts.user.key(),
+            amount,
+        });
+        
+        msg!("User {} refunded {} lamports", ctx.accounts.user.key(), amount);
+        
+        Ok(())
+    }
+
+    /// Mark a cancelled market closed once every entry fee has been refunded
+    pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        
+        // Each unrefunded participant leaves at least their share of the pool
+        // without its staking yield in the vault
+        let interest = read_pool_yield(&ctx.accounts.yield_position)?.map_or(0, |(interest, _)| interest);
+        let outstanding = vault_surplus(market)?;
+        require_gt!(market.principal_refund(interest), outstanding, MarketError::RefundsOutstanding);
+        
+        transition(market, MarketStatus::Closed)?;
+        require_solvent(market)?;
+        
+        emit!(MarketClosed {
+            market: market.key(),
+        });
+        
+        msg!("Market {} closed", market.key());
+        
+        Ok(())
+    }
+
+    /// Compile a fully settled market's totals into its settlement report,
+    /// relayed by anyone once it is swept or closed
+    ///
+    /// The report splits the pool into fees, winners' rewards, refunds and
+    /// the rounding dust of the equal split, alongside what was actually
+    /// paid out and what the vault still holds, so the market's conservation
+    /// of funds can be checked from one account.
+    pub fn compile_settlement_report(ctx: Context<CompileSettlementReport>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(
+            matches!(market.status, MarketStatus::Settled | MarketStatus::Closed),
+            MarketError::MarketNotSettled
+        );
+        require_solvent(market)?;
+        
+        let after_fees = market.total_pool.checked_sub(market.fees_paid).ok_or_else(|| {
+            error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, market.fees_paid))
+        })?;
+        let (winners, rewards_out, refunds_out) = if market.status == MarketStatus::Closed {
+            // Every participant was refunded before the market could close
+            (0, 0, market.total_claimed)
+        } else {
+            let winners = settled_winner_count(market, &ctx.accounts.prop_registry, &ctx.accounts.score_grid)?;
+            let reward = market.reward_per_winner(winners).unwrap_or(0);
+            (winners, reward * winners as u64, 0)
+        };
+        let dust = after_fees.checked_sub(rewards_out + refunds_out).ok_or_else(|| {
+            error!(MarketError::RewardCalculationFailed).with_values((after_fees, rewards_out + refunds_out))
+        })?;
+        
+        let report = &mut ctx.accounts.settlement_report;
+        report.market = market.key();
+        report.factory = market.factory;
+        report.status = market.status.clone();
+        report.participants = market.participant_count;
+        report.winners = winners;
+        report.total_in = market.total_pool;
+        report.fees_out = market.fees_paid;
+        report.rewards_out = rewards_out;
+        report.refunds_out = refunds_out;
+        report.dust = dust;
+        report.paid_out = market.total_claimed;
+        report.vault_remaining = vault_surplus(market)?;
+        report.compiled_at = Clock::get()?.unix_timestamp;
+        report.bump = ctx.bumps.settlement_report;
+        
+        emit!(SettlementReportCompiled {
+            market: report.market,
+            total_in: report.total_in,
+            fees_out: report.fees_out,
+            rewards_out,
+            refunds_out,
+            dust,
+        });
+        
+        msg!("Settlement report of market {}: {} in, {} fees, {} rewards, {} refunds, {} dust",
+            report.market, report.total_in, report.fees_out, rewards_out, refunds_out, dust);
+        
+        Ok(())
+    }
+
+    /// Kill switch for a live incident: send a market down the refund path
+    ///
+    /// Unlike `cancel_market` this also works on a resolved market nobody
+    /// has claimed from yet, refunding what's left after fees along with any
+    /// first-correct bonus set aside. It takes no recipient accounts and
+    /// leaves the outcome untouched, so the factory authority can only ever
+    /// unlock refunds with it.
+    pub fn emergency_void(ctx: Context<EmergencyVoid>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let previous = market.status.clone();
+        
+        transition(market, MarketStatus::Cancelled)?;
+        let bonus = return_first_correct_bonus(market, &ctx.accounts.first_correct_bonus)?;
+        market.fees_paid = market.fees_paid.saturating_sub(bonus);
+        require_solvent(market)?;
+        
+        record_activity(
+            market,
+            &ctx.accounts.platform_stats,
+            &ctx.accounts.platform_activity,
+            &ctx.accounts.dashboard_program,
+            &ctx.accounts.instructions,
+            MarketActivity::Cancelled { was_resolved: previous.is_resolved() },
+        )?;
+        
+        emit!(MarketCancelled {
+            market: market.key(),
+            cancelled_by: ctx.accounts.authority.key(),
+            total_pool: market.liability(),
+        });
+        
+        msg!("Emergency void of market {} from {:?}; refunding {} lamports each",
+            market.key(), previous, market.refund_per_participant());
+        
+        Ok(())
+    }
+
+    /// Check that the vault still covers everything the market owes
+    ///
+    /// Fails with `InsolventMarket` after emitting `SolvencyViolation`, so
+    /// monitors can simulate it for every market and watch the logs.
+    pub fn assert_solvency(ctx: Context<AssertSolvency>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require_solvent(market)?;
+        
+        msg!("Market {} holds {} lamports against {} owed",
+            market.key(), vault_surplus(market)?, market.liability());
+        
+        Ok(())
+    }
+
+    /// Preview how the market would settle if it resolved to `outcome` now,
+    /// without moving funds or writing any account
+    ///
+    /// Applies the fee, charity, first-correct bonus and reward math of
+    /// resolution and returns the result as return data, so frontends can
+    /// simulate it before the market is resolved. Assumes nobody else joins.
+    pub fn preview_settlement(ctx: Context<PreviewSettlement>, outcome: MatchOutcome) -> Result<SettlementPreview> {
+        let market = &ctx.accounts.market;
+        require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+        require_offered(market, &outcome)?;
+        
+        let total_pool = market.total_pool;
+        let split = split_fees(total_pool, is_rake_free(&ctx.accounts.rake_free_market)?)?;
+        let (mut creator_fee, mut platform_fee) = (split.fees.creator_fee, split.fees.platform_fee);
+        let (mut charity_donation, mut bonus, mut prize_pool) = (0, 0, split.prize_pool);
+        
+        // Charity markets donate in place of fees; otherwise a bonus may come out of the creator fee
+        if let Some(pledge) = read_charity_pledge(&ctx.accounts.charity_pledge)? {
+            let charity = share_with_charity(split, pledge.pool_share_bps)
+                .map_err(|_| error!(MarketError::FeeOverflow).with_values((total_pool, MAX_POOL)))?;
+            (creator_fee, platform_fee) = (0, 0);
+            (charity_donation, prize_pool) = (charity.donation, charity.prize_pool);
+        } else if let Some(offer) = read_first_correct_bonus(&ctx.accounts.first_correct_bonus)? {
+            if offer.earliest[FirstCorrectBonus::slot(&outcome)].is_some() {
+                bonus = first_correct_bonus(creator_fee, offer.bonus_bps);
+                creator_fee -= bonus;
+            }
+        }
+        
+        // Without winners nothing is claimed, so the whole prize pool is left over
+        let winner_count = market.winner_count(&outcome);
+        let reward_per_winner = prize_pool.checked_div(u64::from(winner_count)).unwrap_or(0);
+        let dust = prize_pool - reward_per_winner * u64::from(winner_count);
+        
+        msg!("Market {} on {:?}: {} winners of {} lamports each, {} left over",
+            market.key(), outcome, winner_count, reward_per_winner, dust);
+        
+        Ok(SettlementPreview {
+            outcome,
+            winner_count,
+            reward_per_winner,
+            creator_fee,
+            platform_fee,
+            charity_donation,
+            first_correct_bonus: bonus,
+            dust,
+        })
+    }
+
+    /// Migrate a market account to the current layout
+    ///
+    /// For markets that predate fee and payout tracking, fees are recomputed
+    /// from the pool for resolved markets, and whatever the vault no longer
+    /// holds is counted as already paid out. Markets that only lack co-admin
+    /// slots, an outcome set, a resolution grace period, a final score, a
+    /// join time sum or a queued join count are grown with every slot empty,
+    /// taking `OutcomeSet::WinDrawWin`, no grace period, no recorded score, no
+    /// summed join times and no counted queued joins.
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        let account_info = ctx.accounts.market.to_account_info();
+        
+        let migrated = {
+            let data = account_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Market::discriminator(),
+                MarketError::InvalidMarketAccount
+            );
+            if [
+                Market::PRE_CO_ADMIN_LEN,
+                Market::PRE_OUTCOME_SET_LEN,
+                Market::PRE_RESOLUTION_GRACE_LEN,
+                Market::PRE_FINAL_SCORE_LEN,
+                Market::PRE_JOINED_AT_SUM_LEN,
+                Market::PRE_QUEUED_JOIN_COUNT_LEN,
+            ]
+            .contains(&data.len())
+            {
+                // The realloc below zeroes the new fields, which leaves them empty or at their first variant
+                None
+            } else {
+                require!(data.len() == LegacyMarket::LEN, MarketError::AlreadyMigrated);
+                Some(legacy_market(&account_info, &data)?)
+            }
+        };
+        
+        grow_account(&account_info, &ctx.accounts.payer, &ctx.accounts.system_program, Market::LEN)?;
+        
+        match migrated {
+            Some(migrated) => {
+                let mut data = account_info.try_borrow_mut_data()?;
+                migrated.try_serialize(&mut &mut data[..])?;
+                
+                msg!("Migrated market {}: fees paid {}, claimed {}",
+                    account_info.key(), migrated.fees_paid, migrated.total_claimed);
+            }
+            None => msg!("Migrated market {}: grown to the current layout", account_info.key()),
+        }
+        
+        Ok(())
+    }
+
+    /// Grow a participant account that predates join notes or entry indexes
+    /// to the current layout, leaving it without a note and unindexed
+    pub fn migrate_participant(ctx: Context<MigrateParticipant>) -> Result<()> {
+        let account_info = ctx.accounts.participant.to_account_info();
+        
+        {
+            let data = account_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Participant::discriminator(),
+                MarketError::InvalidParticipantAccount
+            );
+            require!(
+                [Participant::PRE_NOTE_LEN, Participant::PRE_ENTRY_INDEX_LEN].contains(&data.len()),
+                MarketError::AlreadyMigrated
+            );
+        }
+        
+        // The realloc zeroes a missing note hash, which leaves it empty, but
+        // a zero entry index would claim the market's first entry
+        grow_account(&account_info, &ctx.accounts.payer, &ctx.accounts.system_program, Participant::LEN)?;
+        account_info.try_borrow_mut_data()?[Participant::PRE_ENTRY_INDEX_LEN..]
+            .copy_from_slice(&Participant::UNINDEXED.to_le_bytes());
+        
+        msg!("Migrated participant {}: grown to the current layout", account_info.key());
+        
+        Ok(())
+    }
+
+    /// Park the pool in an SPL stake pool's liquid staking token while the match is played
+    ///
+    /// Opt-in per market by the factory authority, between kickoff and the
+    /// end of the match. The vault keeps only its rent until
+    /// `unwind_idle_pool` brings the pool back, so resolution, cancellation
+    /// and payouts all fail the solvency check in the meantime.
+    pub fn stake_idle_pool(ctx: Context<StakeIdlePool>, min_pool_tokens: u64) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            market.kickoff_time <= current_time && current_time < market.end_time,
+            MarketError::StakingWindowClosed
+        );
+        
+        // Stake exactly what the market owes, leaving rent and any surplus behind
+        require_solvent(market)?;
+        let amount = market.liability();
+        require_gt!(amount, 0, MarketError::NothingToStake);
+        
+        let pool = require_stake_pool_accounts(
+            &ctx.accounts.stake_pool,
+            [
+                &ctx.accounts.withdraw_authority,
+                &ctx.accounts.reserve_stake,
+                &ctx.accounts.manager_fee_account,
+                &ctx.accounts.pool_mint,
+            ],
+        )?;
+        
+        // Create the market's pool token account
+        let market_key = market.key();
+        let tokens_bump = [ctx.bumps.pool_tokens];
+        let tokens_seeds: [&[u8]; 3] = [b"yield_tokens", market_key.as_ref(), &tokens_bump];
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.pool_tokens.to_account_info(),
+                },
+                &[&tokens_seeds],
+            ),
+            Rent::get()?.minimum_balance(TOKEN_ACCOUNT_LEN),
+            TOKEN_ACCOUNT_LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        )?;
+        invoke(
+            &initialize_token_account(&ctx.accounts.pool_tokens.key(), &pool.pool_mint, &market_key),
+            &[
+                ctx.accounts.pool_tokens.to_account_info(),
+                ctx.accounts.pool_mint.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+        )?;
+        
+        // The vault holds data, so the pool reaches the stake pool through a
+        // system-owned escrow that can sign the deposit
+        transfer_from_vault(market, &ctx.accounts.yield_escrow, amount)?;
+        let escrow_bump = [ctx.bumps.yield_escrow];
+        let escrow_seeds: [&[u8]; 3] = [b"yield_escrow", market_key.as_ref(), &escrow_bump];
+        invoke_signed(
+            &deposit_sol(&pool, &ctx.accounts.yield_escrow.key(), &ctx.accounts.pool_tokens.key(), amount),
+            &[
+                ctx.accounts.stake_pool.to_account_info(),
+                ctx.accounts.withdraw_authority.to_account_info(),
+                ctx.accounts.reserve_stake.to_account_info(),
+                ctx.accounts.yield_escrow.to_account_info(),
+                ctx.accounts.pool_tokens.to_account_info(),
+                ctx.accounts.manager_fee_account.to_account_info(),
+                ctx.accounts.pool_mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.stake_pool_program.to_account_info(),
+            ],
+            &[&escrow_seeds],
+        )?;
+        
+        let pool_tokens = pool_token_balance(&ctx.accounts.pool_tokens)?;
+        require_gte!(pool_tokens, min_pool_tokens, MarketError::StakeSlippageExceeded);
+        
+        let position = &mut ctx.accounts.yield_position;
+        position.market = market_key;
+        position.stake_pool = pool.stake_pool;
+        position.pool_mint = pool.pool_mint;
+        position.staked = amount;
+        position.pool_tokens = pool_tokens;
+        position.staked_at = current_time;
+        position.returned = 0;
+        position.unwound_at = None;
+        position.bump = ctx.bumps.yield_position;
+        
+        emit!(PoolStaked {
+            market: market_key,
+            stake_pool: pool.stake_pool,
+            lamports: amount,
+            pool_tokens,
+        });
+        
+        msg!("Staked {} lamports of market {} for {} pool tokens", amount, market_key, pool_tokens);
+        
+        Ok(())
+    }
+
+    /// Bring a staked pool back into the vault, adding the yield to the pool
+    ///
+    /// The factory authority can unwind at any time. Once the match has
+    /// ended anyone can, so a staked pool never holds up resolution, but
+    /// then the loss against the staked amount is capped at
+    /// `MAX_UNWIND_LOSS_BPS` on top of `min_lamports`.
+    pub fn unwind_idle_pool(ctx: Context<UnwindIdlePool>, min_lamports: u64) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        let position = &mut ctx.accounts.yield_position;
+        require!(position.unwound_at.is_none(), MarketError::PoolNotStaked);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let by_authority = ctx.accounts.caller.key() == ctx.accounts.factory.authority;
+        if !by_authority {
+            require_gte!(current_time, market.end_time, MarketError::UnauthorizedUnwind);
+        }
+        
+        let pool = require_stake_pool_accounts(
+            &ctx.accounts.stake_pool,
+            [
+                &ctx.accounts.withdraw_authority,
+                &ctx.accounts.reserve_stake,
+                &ctx.accounts.manager_fee_account,
+                &ctx.accounts.pool_mint,
+            ],
+        )?;
+        
+        // Burn every pool token the market holds, paying the SOL straight into the vault
+        let pool_tokens = pool_token_balance(&ctx.accounts.pool_tokens)?;
+        let vault = market.to_account_info();
+        let balance_before = vault.lamports();
+        let match_id = match_id_seed(&market.match_id);
+        let bump = [market.bump];
+        let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+        invoke_signed(
+            &withdraw_sol(&pool, &market.key(), &ctx.accounts.pool_tokens.key(), &market.key(), pool_tokens),
+            &[
+                ctx.accounts.stake_pool.to_account_info(),
+                ctx.accounts.withdraw_authority.to_account_info(),
+                vault.clone(),
+                ctx.accounts.pool_tokens.to_account_info(),
+                ctx.accounts.reserve_stake.to_account_info(),
+                ctx.accounts.manager_fee_account.to_account_info(),
+                ctx.accounts.pool_mint.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.stake_history.to_account_info(),
+                ctx.accounts.stake_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.stake_pool_program.to_account_info(),
+            ],
+            &[&signer_seeds],
+        )?;
+        
+        let returned = vault.lamports().saturating_sub(balance_before);
+        require_gte!(returned, min_lamports, MarketError::StakeSlippageExceeded);
+        if !by_authority {
+            let max_loss = (position.staked as u128 * MAX_UNWIND_LOSS_BPS as u128 / BPS_DENOMINATOR as u128) as u64;
+            require_gte!(returned, position.staked - max_loss, MarketError::StakeSlippageExceeded);
+        }
+        
+        // Nothing has been paid out while staked, so the pool is what came back
+        market.total_pool = market
+            .total_pool
+            .saturating_sub(position.staked)
+            .checked_add(returned)
+            .ok_or_else(|| error!(MarketError::BalanceOverflow).with_values((market.total_pool, returned)))?;
+        position.returned = returned;
+        position.unwound_at = Some(current_time);
+        require_solvent(market)?;
+        
+        emit!(PoolUnwound {
+            market: market.key(),
+            staked: position.staked,
+            returned,
+        });
+        
+        msg!("Unwound market {}: staked {} lamports, returned {}", market.key(), position.staked, returned);
+        
+        Ok(())
+    }
+
+    /// Append the market's current prediction distribution to its odds history
+    ///
+    /// Anyone can crank it, at most once per `ODDS_SAMPLE_INTERVAL_SECONDS`,
+    /// while the market takes entries; the first caller pays for the history.
+    pub fn record_odds_sample(ctx: Context<RecordOddsSample>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        require!(market.status == MarketStatus::Open, MarketError::MarketNotOpen);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require_gt!(market.kickoff_time, current_time, MarketError::MarketAlreadyStarted);
+        
+        let history = &mut ctx.accounts.odds_history;
+        if let Some(last) = history.latest() {
+            let next_due = last.timestamp.saturating_add(ODDS_SAMPLE_INTERVAL_SECONDS);
+            if current_time < next_due {
+                return Err(error!(MarketError::OddsSampleTooSoon).with_values((current_time, next_due)));
+            }
+        }
+        
+        history.market = market.key();
+        history.bump = ctx.bumps.odds_history;
+        history.push(OddsSample {
+            timestamp: current_time,
+            home_count: market.home_count,
+            draw_count: market.draw_count,
+            away_count: market.away_count,
+            total_pool: market.total_pool,
+        });
+        
+        msg!("Sampled odds for market {} ({} samples)", market.key(), history.len);
+        
+        Ok(())
+    }
+}
+
+// Helpers
+
+/// Creator, results signer and arbiter, in that order
+pub const CO_SIGNER_SLOTS: usize = 3;
+
+/// Largest loss against the staked amount, in basis points, at which anyone
+/// but the factory authority may unwind a staked pool
+pub const MAX_UNWIND_LOSS_BPS: u64 = 50;
+
+/// Move a market to `next`, the only place its status changes after creation
+fn transition(market: &mut Market, next: MarketStatus) -> Result<()> {
+    if !market.status.can_transition_to(&next) {
+        return Err(error!(MarketError::InvalidStatusTransition)
+            .with_values((format!("{:?}", market.status), format!("{:?}", next))));
+    }
+    market.status = next;
+    Ok(())
+}
+
+/// Pay fees and record the final score and the outcome derived from it once
+/// a resolution has been authorized
+fn settle_resolution(accounts: &mut ResolveMarket, score: FinalScore) -> Result<()> {
+    require_match_entries(&accounts.prop_registry, &accounts.score_grid)?;
+    require!(
+        *accounts.handicap.owner != crate::ID || accounts.handicap.data_is_empty(),
+        MarketError::HandicapMarket
+    );
+    settle_pool(accounts, Some(score.outcome()))?;
+    accounts.market.final_score = Some(score);
+    Ok(())
+}
+
+/// Pay fees, or a charity market's donation, and mark the market resolved
+/// with `outcome`, which player prop and correct-score markets leave unset
+fn settle_pool(accounts: &mut ResolveMarket, outcome: Option<MatchOutcome>) -> Result<()> {
+    // The resolver may be the creator; no other writable slots may alias
+    require_distinct(&[
+        &accounts.market.to_account_info(),
+        &accounts.creator,
+        &accounts.treasury,
+        &accounts.platform_stats.to_account_info(),
+        &accounts.platform_activity.to_account_info(),
+        &accounts.creator_stats.to_account_info(),
+        &accounts.creator_earnings.to_account_info(),
+        &accounts.fee_receipt,
+        &accounts.charity_pledge,
+        &accounts.prop_registry,
+        &accounts.score_grid,
+        &accounts.first_correct_bonus,
+    ])?;
+    if let Some(outcome) = &outcome {
+        require_offered(&accounts.market, outcome)?;
+    }
+    
+    // Charity markets give their fees and pledged pool share to the charity instead
+    if let Some(pledge) = read_charity_pledge(&accounts.charity_pledge)? {
+        return settle_charity_resolution(accounts, pledge, outcome);
+    }
+    
+    let resolver = accounts.resolver.key();
+    let market = &mut accounts.market;
+    
+    // Validate market is not already resolved
+    require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+    
+    // Validate end time, and for anyone but the creator the grace period, has passed
+    let current_time = Clock::get()?.unix_timestamp;
+    require_resolvable_by(market, &resolver, current_time)?;
+    
+    // A staked pool has to be unwound back into the vault before any fee leaves it
+    require_solvent(market)?;
+    
+    // Calculate and distribute fees before updating market status
+    let total_pool = market.total_pool;
+    
+    // Calculate fees (2% creator + 3% platform = 5% total, none if rake-free)
+    let split = split_fees(total_pool, is_rake_free(&accounts.rake_free_market)?)?;
+    let (creator_fee, platform_fee) = (split.fees.creator_fee, split.fees.platform_fee);
+    let total_fees = split.total_fees;
+    
+    // Validate we have enough funds for fees without touching the rent reserve
+    require_gte!(vault_surplus(market)?, total_fees, MarketError::InsufficientFunds);
+    
+    // A first-correct bonus, if the creator offered one, comes out of their fee
+    let bonus = set_aside_first_correct_bonus(market, &accounts.first_correct_bonus, outcome.as_ref(), creator_fee)?;
+    let creator_fee = creator_fee - bonus;
+    
+    // Credit the creator fee to the creator's ledger, then move it there
+    // for them to claim later
+    if creator_fee > 0 {
+        accrue_creator_earnings(
+            market,
+            &accounts.creator_earnings,
+            &accounts.creator,
+            &accounts.resolver,
+            &accounts.dashboard_program,
+            &accounts.instructions,
+            &accounts.system_program,
+            creator_fee,
+        )?;
+        transfer_from_vault(market, &accounts.creator_earnings, creator_fee)?;
+    }
+    
+    // Record the platform fee with a receipt, then move it to the treasury
+    if platform_fee > 0 {
+        record_platform_fee(
+            market,
+            &accounts.factory,
+            &accounts.treasury,
+            &accounts.fee_receipt,
+            &accounts.resolver,
+            &accounts.factory_program,
+            &accounts.instructions,
+            &accounts.system_program,
+            platform_fee,
+        )?;
+        transfer_from_vault(market, &accounts.treasury, platform_fee)?;
+    }
+    
+    market.fees_paid = total_fees;
+    require_solvent(market)?;
+    
+    // Update market status and outcome
+    transition(market, MarketStatus::Resolved)?;
+    market.outcome = outcome.clone();
+    
+    // Report the resolution to platform and creator stats
+    record_activity(
+        market,
+        &accounts.platform_stats,
+        &accounts.platform_activity,
+        &accounts.dashboard_program,
+        &accounts.instructions,
+        MarketActivity::Resolved { fees: total_fees },
+    )?;
+    record_creator_activity(
+        market,
+        &accounts.creator_stats.to_account_info(),
+        &accounts.creator,
+        &accounts.resolver,
+        &accounts.dashboard_program,
+        &accounts.instructions,
+        &accounts.system_program,
+        CreatorActivity::MarketResolved {
+            volume: total_pool,
+            creator_fee,
+        },
+    )?;
+    
+    // Emit events; prop and correct-score markets report their result with their own events
+    if let Some(outcome) = outcome {
+        let winner_count = market.winner_count(&outcome);
+        emit!(MarketResolved {
+            market: market.key(),
+            outcome,
+            winner_count,
+            total_pool: market.total_pool,
+        });
+        msg!("Market resolved with outcome: {:?}, winners: {}", market.outcome, winner_count);
+    }
+    
+    emit!(FeesDistributed {
+        market: market.key(),
+        creator: market.creator,
+        creator_fee,
+        platform: accounts.treasury.key(),
+        platform_fee,
+        total_fees,
+    });
+    
+    msg!("Fees distributed - Creator: {} lamports, Platform: {} lamports", 
+        creator_fee, platform_fee);
+    
+    Ok(())
+}
+
+/// Resolve a charity market, giving its fees and pledged share of the prize
+/// pool to the charity recorded in `pledge`
+fn settle_charity_resolution(
+    accounts: &mut ResolveMarket,
+    mut pledge: CharityPledge,
+    outcome: Option<MatchOutcome>,
+) -> Result<()> {
+    let charity = accounts.charity.as_ref().ok_or(MarketError::CharityMismatch)?.to_account_info();
+    require_keys_eq!(charity.key(), pledge.charity, MarketError::CharityMismatch);
+    require_distinct(&[
+        &accounts.market.to_account_info(),
+        &accounts.platform_stats.to_account_info(),
+        &accounts.platform_activity.to_account_info(),
+        &accounts.creator_stats.to_account_info(),
+        &accounts.charity_pledge,
+        &charity,
+    ])?;
+    
+    let resolver = accounts.resolver.key();
+    let market = &mut accounts.market;
+    require!(!market.status.is_resolved(), MarketError::MarketAlreadyResolved);
+    let current_time = Clock::get()?.unix_timestamp;
+    require_resolvable_by(market, &resolver, current_time)?;
+    require_solvent(market)?;
+    
+    // A rake-free charity market has no fees to waive, so donates only the pool share
+    let total_pool = market.total_pool;
+    let split = split_fees(total_pool, is_rake_free(&accounts.rake_free_market)?)?;
+    let split = share_with_charity(split, pledge.pool_share_bps)
+        .map_err(|_| error!(MarketError::FeeOverflow).with_values((total_pool, MAX_POOL)))?;
+    require_gte!(vault_surplus(market)?, split.donation, MarketError::InsufficientFunds);
+    
+    if split.donation > 0 {
+        transfer_from_vault(market, &charity, split.donation)?;
+    }
+    market.fees_paid = split.donation;
+    require_solvent(market)?;
+    
+    pledge.donated = split.donation;
+    pledge.try_serialize(&mut &mut accounts.charity_pledge.try_borrow_mut_data()?[..])?;
+    
+    transition(market, MarketStatus::Resolved)?;
+    market.outcome = outcome.clone();
+    
+    // Neither the creator nor the platform earns anything from the market
+    record_activity(
+        market,
+        &accounts.platform_stats,
+        &accounts.platform_activity,
+        &accounts.dashboard_program,
+        &accounts.instructions,
+        MarketActivity::Resolved { fees: 0 },
+    )?;
+    record_creator_activity(
+        market,
+        &accounts.creator_stats.to_account_info(),
+        &accounts.creator,
+        &accounts.resolver,
+        &accounts.dashboard_program,
+        &accounts.instructions,
+        &accounts.system_program,
+        CreatorActivity::MarketResolved {
+            volume: total_pool,
+            creator_fee: 0,
+        },
+    )?;
+    
+    if let Some(outcome) = outcome {
+        let winner_count = market.winner_count(&outcome);
+        emit!(MarketResolved {
+            market: market.key(),
+            outcome,
+            winner_count,
+            total_pool,
+        });
+        msg!("Market resolved with outcome: {:?}, winners: {}", market.outcome, winner_count);
+    }
+    
+    emit!(CharityDonated {
+        market: market.key(),
+        charity: pledge.charity,
+        fees: split.fees,
+        pool_share: split.pool_share,
+        prize_pool: split.prize_pool,
+    });
+    
+    msg!("Donated to {} - Fees: {} lamports, Pool share: {} lamports", pledge.charity, split.fees, split.pool_share);
+    
+    Ok(())
+}
+
+fn read_charity_pledge(account: &AccountInfo) -> Result<Option<CharityPledge>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let pledge = CharityPledge::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(pledge))
+}
+
+/// Whether the factory has made the market rake-free
+fn is_rake_free(account: &AccountInfo) -> Result<bool> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(false);
+    }
+    let entry = RakeFreeMarket::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(entry.rake_free)
+}
+
+/// Split `total_pool` into fees and prize pool, taking no fees if `rake_free`
+fn split_fees(total_pool: u64, rake_free: bool) -> Result<PoolSplit> {
+    if rake_free {
+        return Ok(split_rake_free_pool(total_pool));
+    }
+    split_pool(total_pool).map_err(|_| error!(MarketError::FeeOverflow).with_values((total_pool, MAX_POOL)))
+}
+
+fn read_first_correct_bonus(account: &AccountInfo) -> Result<Option<FirstCorrectBonus>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let bonus = FirstCorrectBonus::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(bonus))
+}
+
+/// Read the rolled entry at `account`, if the entry it belongs to was funded by a rolled reward
+fn read_rolled_entry(account: &AccountInfo) -> Result<Option<RolledEntry>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let rolled_entry = RolledEntry::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(rolled_entry))
+}
+
+/// Record `participant` as the earliest entry on their prediction, if the
+/// market offers a first-correct bonus and no earlier entry has made it
+fn track_earliest_entry(bonus_account: &AccountInfo, participant: &Participant) -> Result<()> {
+    let Some(mut bonus) = read_first_correct_bonus(bonus_account)? else {
+        return Ok(());
+    };
+    let earliest = &mut bonus.earliest[FirstCorrectBonus::slot(&participant.prediction)];
+    if earliest.is_some_and(|entry| entry.entry_index < participant.entry_index) {
+        return Ok(());
+    }
+    
+    *earliest = Some(EarliestEntry {
+        user: participant.user,
+        entry_index: participant.entry_index,
+    });
+    bonus.try_serialize(&mut &mut bonus_account.try_borrow_mut_data()?[..])
+}
+
+/// Move the first-correct bonus out of `creator_fee` into its account for
+/// the earliest entry on `outcome`, returning the lamports set aside
+fn set_aside_first_correct_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+    outcome: Option<&MatchOutcome>,
+    creator_fee: u64,
+) -> Result<u64> {
+    let Some(mut bonus) = read_first_correct_bonus(bonus_account)? else {
+        return Ok(0);
+    };
+    let Some(winner) = outcome.and_then(|outcome| bonus.earliest[FirstCorrectBonus::slot(outcome)]) else {
+        return Ok(0);
+    };
+    
+    // Both accounts are the program's own, so lamports move without a CPI
+    let amount = first_correct_bonus(creator_fee, bonus.bonus_bps);
+    transfer_to_bonus(market, bonus_account, &bonus, amount)?;
+    
+    bonus.winner = Some(winner.user);
+    bonus.bonus = amount;
+    bonus.try_serialize(&mut &mut bonus_account.try_borrow_mut_data()?[..])?;
+    
+    msg!("First-correct bonus of {} lamports set aside for {}", amount, winner.user);
+    Ok(amount)
+}
+
+/// Move a first-correct bonus set aside at resolution to the earliest entry
+/// on the corrected `outcome`, handing it back to the vault if there is none
+///
+/// A market that set nothing aside already paid the creator their whole fee,
+/// so it offers no bonus on the corrected outcome either.
+fn reassign_first_correct_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+    outcome: &MatchOutcome,
+) -> Result<()> {
+    let Some(mut bonus) = read_first_correct_bonus(bonus_account)? else {
+        return Ok(());
+    };
+    if bonus.bonus == 0 {
+        return Ok(());
+    }
+    
+    bonus.winner = bonus.earliest[FirstCorrectBonus::slot(outcome)].as_ref().map(|entry| entry.user);
+    if bonus.winner.is_none() {
+        transfer_from_bonus(market, bonus_account, &bonus, &market.to_account_info(), bonus.bonus)?;
+        bonus.bonus = 0;
+    }
+    bonus.try_serialize(&mut &mut bonus_account.try_borrow_mut_data()?[..])
+}
+
+/// Hand a first-correct bonus set aside at resolution and still unclaimed
+/// back to the vault, returning the lamports returned
+///
+/// For markets voided, swept or ruled mis-resolved after resolution, whose
+/// bonus winner is no longer owed it.
+fn return_first_correct_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+) -> Result<u64> {
+    let Some(mut bonus) = read_first_correct_bonus(bonus_account)? else {
+        return Ok(0);
+    };
+    if bonus.claimed || bonus.bonus == 0 {
+        return Ok(0);
+    }
+    
+    let amount = bonus.bonus;
+    transfer_from_bonus(market, bonus_account, &bonus, &market.to_account_info(), amount)?;
+    bonus.winner = None;
+    bonus.bonus = 0;
+    bonus.try_serialize(&mut &mut bonus_account.try_borrow_mut_data()?[..])?;
+    
+    msg!("First-correct bonus of {} lamports returned to the vault", amount);
+    Ok(amount)
+}
+
+/// Pay `user` the first-correct bonus if it was set aside for them and is
+/// still unclaimed, returning the lamports paid
+fn claim_first_correct_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+) -> Result<u64> {
+    let Some(mut bonus) = read_first_correct_bonus(bonus_account)? else {
+        return Ok(0);
+    };
+    if bonus.claimed || bonus.winner != Some(user.key()) {
+        return Ok(0);
+    }
+    
+    transfer_from_bonus(market, bonus_account, &bonus, user, bonus.bonus)?;
+    bonus.claimed = true;
+    bonus.try_serialize(&mut &mut bonus_account.try_borrow_mut_data()?[..])?;
+    
+    Ok(bonus.bonus)
+}
+
+/// Reward owed to `participant` on a resolved market still paying claims,
+/// from the pool after fees or a charity donation (both already paid out
+/// during resolution)
+fn winner_reward(market: &Market, participant: &Participant) -> Result<u64> {
+    require!(market.status.is_resolved(), MarketError::MarketNotResolved);
+    require!(market.status != MarketStatus::Settled, MarketError::ClaimsClosed);
+    require!(!participant.has_withdrawn, MarketError::AlreadyWithdrawn);
+    
+    let outcome = market.outcome.as_ref().ok_or(MarketError::NoOutcome)?;
+    if participant.prediction != *outcome {
+        return Err(error!(MarketError::NotAWinner)
+            .with_values((format!("{:?}", participant.prediction), format!("{:?}", outcome))));
+    }
+    
+    let winner_count = market.winner_count(outcome);
+    require!(winner_count > 0, MarketError::NoWinners);
+    
+    market
+        .reward_per_winner(winner_count)
+        .ok_or_else(|| error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, winner_count)))
+}
+
+/// Mark `participant`'s reward paid; the first claim moves the market into
+/// its claiming phase
+fn record_reward_claim(market: &mut Account<Market>, participant: &mut Participant, reward: u64) -> Result<()> {
+    participant.has_withdrawn = true;
+    record_payout(market, reward)?;
+    if market.status == MarketStatus::Resolved {
+        transition(market, MarketStatus::Claiming)?;
+    }
+    Ok(())
+}
+
+/// Winners a settled market paid out to: entries on the winning player of
+/// a prop market, on the winning scoreline of a correct-score market, or
+/// on the outcome of any other market
+fn settled_winner_count(market: &Market, prop_registry: &AccountInfo, score_grid: &AccountInfo) -> Result<u32> {
+    if let Some(grid) = read_score_grid(score_grid)? {
+        return Ok(grid.result.map_or(0, |scoreline| grid.winner_count(&scoreline)));
+    }
+    if let Some(registry) = read_prop_registry(prop_registry)? {
+        let slot = registry.winning_player.and_then(|player_id| registry.player_slot(player_id));
+        return Ok(slot.map_or(0, |slot| registry.pick_counts[slot]));
+    }
+    Ok(market.outcome.as_ref().map_or(0, |outcome| market.winner_count(outcome)))
+}
+
+/// Count lamports paid out of the pool to winners, refunds or a sweep
+fn record_payout(market: &mut Account<Market>, amount: u64) -> Result<()> {
+    market.total_claimed = market
+        .total_claimed
+        .checked_add(amount)
+        .ok_or_else(|| error!(MarketError::PayoutOverflow).with_values((market.total_claimed, amount)))?;
+    require_solvent(market)
+}
+
+/// Fail unless the market has ended and, for anyone but its creator, its
+/// resolution grace period has run out
+///
+/// Settlements of co-signed markets go through the resolver that calls
+/// `co_sign_resolution`, so during the grace period that has to be the creator.
+fn require_resolvable_by(market: &Market, resolver: &Pubkey, current_time: i64) -> Result<()> {
+    require_gte!(current_time, market.end_time, MarketError::MarketNotEnded);
+    if *resolver != market.creator {
+        require_gte!(current_time, market.grace_ends_at(), MarketError::ResolutionGracePeriod);
+    }
+    Ok(())
+}
+
+/// Fail, emitting `SolvencyViolation`, if the vault holds less than the market owes
+fn require_solvent(market: &Account<Market>) -> Result<()> {
+    let available = vault_surplus(market)?;
+    let liability = market.liability();
+    if available < liability {
+        emit!(SolvencyViolation {
+            market: market.key(),
+            available,
+            liability,
+        });
+        return Err(error!(MarketError::InsolventMarket).with_values((available, liability)));
+    }
+    Ok(())
+}
+
+/// Fail if any two of `accounts` share a key
+///
+/// Anchor 0.30 accepts one writable account in several slots, and writes
+/// through aliased slots can redirect lamports or overwrite each other.
+fn require_distinct(accounts: &[&AccountInfo]) -> Result<()> {
+    for (index, account) in accounts.iter().enumerate() {
+        if accounts[index + 1..].iter().any(|other| other.key == account.key) {
+            msg!("Account {} passed in more than one slot", account.key);
+            return err!(MarketError::DuplicateAccount);
+        }
+    }
+    Ok(())
+}
+
+/// Yield the market's staked pool earned above what was staked, and when it
+/// was unwound, if the pool was staked and unwound at a profit
+fn read_pool_yield(account: &AccountInfo) -> Result<Option<(u64, i64)>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let position = YieldPosition::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(match position.unwound_at {
+        Some(unwound_at) if position.returned > position.staked => {
+            Some((position.returned - position.staked, unwound_at))
+        }
+        _ => None,
+    })
+}
+
+/// Lamports the vault holds above its rent-exempt minimum
+fn vault_surplus(market: &Account<Market>) -> Result<u64> {
+    let vault = market.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(vault.data_len());
+    Ok(vault.lamports().saturating_sub(rent_minimum))
+}
+
+/// Initialize `participant` for `user`'s entry on `prediction` and count it
+/// in the market, once its entry fee has reached the vault
+fn record_join(
+    market: &mut Account<Market>,
+    participant: &mut Participant,
+    user: Pubkey,
+    prediction: MatchOutcome,
+    joined_at: i64,
+) -> Result<()> {
+    participant.market = market.key();
+    participant.user = user;
+    participant.prediction = prediction.clone();
+    participant.joined_at = joined_at;
+    participant.has_withdrawn = false;
+    participant.entry_index = market.participant_count;
+    
+    add_entry(market, joined_at)?;
+    count_prediction(market, &prediction)
+}
+
+/// Add one entry fee and participant to the market's totals
+fn add_entry(market: &mut Market, joined_at: i64) -> Result<()> {
+    market.total_pool = market.total_pool.checked_add(market.entry_fee)
+        .ok_or(MarketError::PoolOverflow)?;
+    market.participant_count = market.participant_count.checked_add(1)
+        .ok_or(MarketError::ParticipantOverflow)?;
+    market.joined_at_sum = market.joined_at_sum.checked_add(joined_at as i128)
+        .ok_or(MarketError::ParticipantOverflow)?;
+    
+    Ok(())
+}
+
+/// Count a participant's prediction towards its outcome
+fn count_prediction(market: &mut Market, prediction: &MatchOutcome) -> Result<()> {
+    require_offered(market, prediction)?;
+    match prediction {
+        MatchOutcome::Home => {
+            market.home_count = market.home_count.checked_add(1)
+                .ok_or(MarketError::CountOverflow)?;
+        },
+        MatchOutcome::Draw => {
+            market.draw_count = market.draw_count.checked_add(1)
+                .ok_or(MarketError::CountOverflow)?;
+        },
+        MatchOutcome::Away => {
+            market.away_count = market.away_count.checked_add(1)
+                .ok_or(MarketError::CountOverflow)?;
+        },
+    }
+    
+    Ok(())
+}
+
+/// Fail if `outcome` isn't one of the market's outcomes, e.g. a draw in a
+/// sport without draws
+fn require_offered(market: &Market, outcome: &MatchOutcome) -> Result<()> {
+    if !market.outcome_set.offers(outcome) {
+        return Err(error!(MarketError::OutcomeNotOffered)
+            .with_values((format!("{:?}", outcome), format!("{:?}", market.outcome_set))));
+    }
+    Ok(())
+}
+
+/// Fail if the market only takes committed predictions
+fn require_plain_entries(commit_reveal: &AccountInfo) -> Result<()> {
+    require!(
+        *commit_reveal.owner != crate::ID || commit_reveal.data_is_empty(),
+        MarketError::CommitRevealRequired
+    );
+    Ok(())
+}
+
+/// Refuse joins after kickoff, unless the market is in play and the match's
+/// live score hasn't reached its lock
+///
+/// An in-play match whose live score hasn't been reported yet counts as
+/// goalless at minute zero.
+fn require_join_window(
+    market: &Account<Market>,
+    in_play_lock: &AccountInfo,
+    live_score: &AccountInfo,
+    now: i64,
+) -> Result<()> {
+    if market.kickoff_time > now {
+        return Ok(());
+    }
+    if *in_play_lock.owner != crate::ID || in_play_lock.data_is_empty() {
+        return Err(error!(MarketError::MarketAlreadyStarted).with_values((market.kickoff_time, now)));
+    }
+    let lock = InPlayLock::try_deserialize(&mut &in_play_lock.try_borrow_data()?[..])?;
+    require_keys_eq!(live_score.key(), lock.live_score, MarketError::InvalidLiveScore);
+    require_gt!(market.end_time, now, MarketError::InPlayLocked);
+    
+    if *live_score.owner != cryptoscore_factory::ID || live_score.data_is_empty() {
+        return Ok(());
+    }
+    let score = LiveScore::try_deserialize(&mut &live_score.try_borrow_data()?[..])?;
+    if lock.is_locked_by(&score) {
+        return Err(error!(MarketError::InPlayLocked)
+            .with_values((format!("{}-{}", score.home_goals, score.away_goals), score.minute)));
+    }
+    Ok(())
+}
+
+/// Fail if the market only takes player prop or correct-score picks
+fn require_match_entries(prop_registry: &AccountInfo, score_grid: &AccountInfo) -> Result<()> {
+    require!(
+        *prop_registry.owner != crate::ID || prop_registry.data_is_empty(),
+        MarketError::PropMarket
+    );
+    require!(
+        *score_grid.owner != crate::ID || score_grid.data_is_empty(),
+        MarketError::CorrectScoreMarket
+    );
+    Ok(())
+}
+
+fn read_prop_registry(account: &AccountInfo) -> Result<Option<PropRegistry>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let registry = PropRegistry::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(registry))
+}
+
+fn read_handicap(account: &AccountInfo) -> Result<Option<Handicap>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let handicap = Handicap::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(handicap))
+}
+
+fn read_score_grid(account: &AccountInfo) -> Result<Option<ScoreGrid>> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let grid = ScoreGrid::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(grid))
+}
+
+/// Refuse entries that can't prove holdings on a token-gated market
+fn require_ungated(token_gate: &AccountInfo) -> Result<()> {
+    require!(
+        *token_gate.owner != crate::ID || token_gate.data_is_empty(),
+        MarketError::TokenGated
+    );
+    Ok(())
+}
+
+/// Check `user` holds what the market's token gate requires, if it has one
+///
+/// `proof` leads with the user's token account, followed for collection
+/// gates by the NFT's metadata account.
+fn require_token_gate(token_gate: &AccountInfo, user: &Pubkey, proof: &[AccountInfo]) -> Result<()> {
+    if *token_gate.owner != crate::ID || token_gate.data_is_empty() {
+        return Ok(());
+    }
+    let gate = TokenGate::try_deserialize(&mut &token_gate.try_borrow_data()?[..])?;
+    
+    let token_account = proof.first().ok_or_else(|| error!(MarketError::TokenGateNotMet))?;
+    require_keys_eq!(*token_account.owner, TOKEN_PROGRAM_ID, MarketError::TokenGateNotMet);
+    
+    let token_data = token_account.try_borrow_data()?;
+    let met = match proof.get(1) {
+        Some(metadata) => {
+            let metadata_data = metadata.try_borrow_data()?;
+            gate.rule.is_met_by(user, &token_data, Some((metadata.owner, &metadata_data)))
+        }
+        None => gate.rule.is_met_by(user, &token_data, None),
+    };
+    require!(met, MarketError::TokenGateNotMet);
+    
+    Ok(())
+}
+
+/// List `user` on the last page of the market's participant roster if the
+/// creator has enabled one, with `payer` funding the page if `user` opens it
+fn append_to_roster<'info>(
+    market: &Account<'info, Market>,
+    roster: &RosterSlot<'info>,
+    user: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let market_key = market.key();
+    let (roster_address, _) = Pubkey::find_program_address(
+        &[PARTICIPANT_ROSTER_SEED, market_key.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(roster.participant_roster.key(), roster_address, MarketError::InvalidRosterAccount);
+    
+    let roster_info = roster.participant_roster.to_account_info();
+    if *roster_info.owner != crate::ID || roster_info.data_is_empty() {
+        return Ok(());
+    }
+    let mut participant_roster = ParticipantRoster::try_deserialize(&mut &roster_info.try_borrow_data()?[..])?;
+    
+    let page = participant_roster.count / ROSTER_PAGE_SIZE as u32;
+    let slot = participant_roster.count as usize % ROSTER_PAGE_SIZE;
+    let page_seed = page.to_le_bytes();
+    let (page_address, bump) = Pubkey::find_program_address(
+        &[ROSTER_PAGE_SEED, market_key.as_ref(), &page_seed],
+        &crate::ID,
+    );
+    let page_info = roster.roster_page.to_account_info();
+    require_keys_eq!(page_info.key(), page_address, MarketError::InvalidRosterAccount);
+    
+    let mut roster_page = if slot == 0 {
+        // Open the page by hand, as an `init` constraint would
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: page_info.clone(),
+                },
+                &[&[ROSTER_PAGE_SEED, market_key.as_ref(), &page_seed, &[bump]]],
+            ),
+            Rent::get()?.minimum_balance(RosterPage::LEN),
+            RosterPage::LEN as u64,
+            &crate::ID,
+        )?;
+        RosterPage {
+            market: market_key,
+            page,
+            users: [Pubkey::default(); ROSTER_PAGE_SIZE],
+            len: 0,
+            bump,
+        }
+    } else {
+        RosterPage::try_deserialize(&mut &page_info.try_borrow_data()?[..])?
+    };
+    
+    roster_page.users[slot] = user;
+    roster_page.len += 1;
+    roster_page.try_serialize(&mut &mut page_info.try_borrow_mut_data()?[..])?;
+    
+    participant_roster.count = participant_roster.count.checked_add(1)
+        .ok_or(MarketError::ParticipantOverflow)?;
+    participant_roster.try_serialize(&mut &mut roster_info.try_borrow_mut_data()?[..])?;
+    
+    Ok(())
+}
+
+/// Payout, in basis points of the entry fee, a queued join would get if it
+/// joined the market as it stands
+fn queued_payout_bps(market: &Market, queued: &QueuedJoin) -> Result<u64> {
+    let side_count = match queued.prediction {
+        MatchOutcome::Home => market.home_count,
+        MatchOutcome::Draw => market.draw_count,
+        MatchOutcome::Away => market.away_count,
+    };
+    implied_payout_bps(market.total_pool, side_count, queued.entry_fee)
+        .ok_or_else(|| error!(MarketError::PoolOverflow))
+}
+
+/// The factory's resolution policy, if it has set one
+fn read_resolution_policy(account: &AccountInfo) -> Result<Option<ResolutionPolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = ResolutionPolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// Fail if the market's pool is large enough that it must be co-signed
+fn require_single_resolver(accounts: &ResolveMarket) -> Result<()> {
+    if let Some(policy) = read_resolution_policy(&accounts.resolution_policy)? {
+        let total_pool = accounts.market.total_pool;
+        if policy.requires_co_signers(total_pool) {
+            return Err(error!(MarketError::CoSignersRequired).with_values((total_pool, policy.high_stakes_pool)));
+        }
+    }
+    Ok(())
+}
+
+/// Outcome `results_batch` publishes for `market`, held to the same checks
+/// as an attested result
+fn batch_score(results_batch: &ResultsBatch, market: &Market) -> Result<FinalScore> {
+    require_keys_eq!(results_batch.factory, market.factory, MarketError::ResultNotInBatch);
+    require_keys_neq!(results_batch.signer, market.creator, MarketError::CreatorIsResultsSigner);
+    require_gte!(results_batch.published_at, market.end_time, MarketError::InvalidAttestation);
+    results_batch
+        .score(&market.match_id)
+        .ok_or_else(|| error!(MarketError::ResultNotInBatch))
+}
+
+/// Keys allowed to co-sign a high-stakes market's resolution, one per slot
+fn co_signer_keys(
+    market: &Market,
+    results_signer: &ResultsSigner,
+    policy: &ResolutionPolicy,
+) -> [Pubkey; CO_SIGNER_SLOTS] {
+    [market.creator, results_signer.signer, policy.arbiter]
+}
+
+/// Slot of `signer` among the co-signers
+///
+/// A key holding several roles only ever gets the first, so it can never
+/// supply both signatures.
+fn co_signer_slot(co_signers: &[Pubkey; CO_SIGNER_SLOTS], signer: &Pubkey) -> Result<usize> {
+    co_signers
+        .iter()
+        .position(|key| key == signer)
+        .ok_or_else(|| error!(MarketError::UnauthorizedResolver))
+}
+
+/// Read the stake pool's own record of its accounts, failing unless `given`
+/// are its withdraw authority, reserve, manager fee account and mint in turn
+fn require_stake_pool_accounts(stake_pool: &AccountInfo, given: [&AccountInfo; 4]) -> Result<StakePoolAccounts> {
+    let pool = StakePoolAccounts::parse(stake_pool.key(), &stake_pool.try_borrow_data()?)
+        .ok_or(MarketError::InvalidStakePool)?;
+    let expected = [pool.withdraw_authority, pool.reserve_stake, pool.manager_fee_account, pool.pool_mint];
+    for (account, expected) in given.into_iter().zip(expected) {
+        require_keys_eq!(account.key(), expected, MarketError::InvalidStakePool);
+    }
+    Ok(pool)
+}
+
+/// Pool tokens held by a market's `yield_tokens` account
+fn pool_token_balance(pool_tokens: &AccountInfo) -> Result<u64> {
+    require_keys_eq!(*pool_tokens.owner, TOKEN_PROGRAM_ID, MarketError::InvalidStakePool);
+    let (_, _, amount) = parse_token_account(&pool_tokens.try_borrow_data()?).ok_or(MarketError::InvalidStakePool)?;
+    Ok(amount)
+}
+
+/// Mint, owner and balance of an SPL token account used in a payout swap
+fn read_token_account(account: &AccountInfo) -> Result<(Pubkey, Pubkey, u64)> {
+    require_keys_eq!(*account.owner, TOKEN_PROGRAM_ID, MarketError::InvalidSwapAccount);
+    parse_token_account(&account.try_borrow_data()?).ok_or_else(|| error!(MarketError::InvalidSwapAccount))
+}
+
+/// Move lamports out of a market's vault, the only place the program debits
+/// lamports besides the first-correct bonus transfers
+///
+/// The vault must be the market's own PDA and stay rent-exempt, and the
+/// recipient must not alias it or any other account this program owns.
+fn transfer_from_vault<'info>(
+    market: &Account<'info, Market>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let vault = market_vault(market)?;
+    require_keys_neq!(recipient.key(), vault.key(), MarketError::DuplicateAccount);
+    require_keys_neq!(*recipient.owner, crate::ID, MarketError::DuplicateAccount);
+    
+    move_lamports(&vault, recipient, amount)
+}
+
+/// Move lamports from a market's vault into its first-correct bonus account
+///
+/// Both must be the market's own PDAs, and the vault stays rent-exempt as
+/// in `transfer_from_vault`.
+fn transfer_to_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+    bonus: &FirstCorrectBonus,
+    amount: u64,
+) -> Result<()> {
+    let vault = market_vault(market)?;
+    require_bonus_account(market, bonus_account, bonus)?;
+    require_keys_neq!(bonus_account.key(), vault.key(), MarketError::DuplicateAccount);
+    
+    move_lamports(&vault, bonus_account, amount)
+}
+
+/// Move lamports out of a market's first-correct bonus account, back to the
+/// vault or to a recipient the program doesn't own
+///
+/// The bonus account must be the market's own PDA and stay rent-exempt.
+fn transfer_from_bonus<'info>(
+    market: &Account<'info, Market>,
+    bonus_account: &AccountInfo<'info>,
+    bonus: &FirstCorrectBonus,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require_bonus_account(market, bonus_account, bonus)?;
+    require_keys_neq!(recipient.key(), bonus_account.key(), MarketError::DuplicateAccount);
+    if recipient.key() == market.key() {
+        market_vault(market)?;
+    } else {
+        require_keys_neq!(*recipient.owner, crate::ID, MarketError::DuplicateAccount);
+    }
+    
+    move_lamports(bonus_account, recipient, amount)
+}
+
+/// The market's own vault, checked against its PDA
+fn market_vault<'info>(market: &Account<'info, Market>) -> Result<AccountInfo<'info>> {
+    let vault = market.to_account_info();
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let expected = Pubkey::create_program_address(&market_signer_seeds(&market.factory, &match_id, &bump), &crate::ID)
+        .map_err(|_| error!(MarketError::InvalidVault))?;
+    require_keys_eq!(vault.key(), expected, MarketError::InvalidVault);
+    require_keys_eq!(*vault.owner, crate::ID, MarketError::InvalidVault);
+    Ok(vault)
+}
+
+/// Fail unless `bonus_account` is the market's first-correct bonus PDA
+fn require_bonus_account(
+    market: &Account<Market>,
+    bonus_account: &AccountInfo,
+    bonus: &FirstCorrectBonus,
+) -> Result<()> {
+    let market_key = market.key();
+    let expected = Pubkey::create_program_address(
+        &[b"first_correct_bonus", market_key.as_ref(), &[bonus.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(MarketError::InvalidBonusAccount))?;
+    require_keys_eq!(bonus_account.key(), expected, MarketError::InvalidBonusAccount);
+    require_keys_eq!(*bonus_account.owner, crate::ID, MarketError::InvalidBonusAccount);
+    require_keys_eq!(bonus.market, market_key, MarketError::InvalidBonusAccount);
+    Ok(())
+}
+
+/// Debit `amount` from `from`, which stays rent-exempt, and credit it to `to`
+///
+/// Both balances are read and written with no CPI in between, so nothing
+/// can re-enter and observe a half-applied transfer.
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_balance = from.lamports();
+    let remaining = from_balance
+        .checked_sub(amount)
+        .ok_or_else(|| error!(MarketError::InsufficientFunds).with_values((from_balance, amount)))?;
+    // Settlement must never leave the market's accounts open to garbage collection
+    let rent_minimum = Rent::get()?.minimum_balance(from.data_len());
+    require_gte!(remaining, rent_minimum, MarketError::BelowRentExemption);
+    
+    let to_balance = to.lamports();
+    let credited = to_balance
+        .checked_add(amount)
+        .ok_or_else(|| error!(MarketError::BalanceOverflow).with_values((to_balance, amount)))?;
+    
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? = credited;
+    
+    Ok(())
+}
+
+/// Fail unless the previous instruction is an ed25519 program check of
+/// `signer`'s signature over exactly `message`
+///
+/// The ed25519 program fails the whole transaction on a bad signature, so
+/// finding its instruction with the right key and message is proof enough.
+fn verify_attestation(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require_gt!(current, 0, MarketError::MissingAttestation);
+    
+    let verify = load_instruction_at_checked(usize::from(current - 1), instructions)?;
+    require_keys_eq!(verify.program_id, ed25519_program::ID, MarketError::MissingAttestation);
+    
+    let (attested_by, attested) =
+        parse_ed25519_instruction(&verify.data).ok_or_else(|| error!(MarketError::InvalidAttestation))?;
+    require_keys_eq!(attested_by, *signer, MarketError::InvalidAttestation);
+    require!(attested == message, MarketError::InvalidAttestation);
+    
+    Ok(())
+}
+
+/// Report market activity to the dashboard's platform stats, signing as the market PDA
+fn record_activity<'info>(
+    market: &Account<'info, Market>,
+    platform_stats: &Account<'info, PlatformStats>,
+    platform_activity: &AccountInfo<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    activity: MarketActivity,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::RecordMarketActivity {
+            platform_stats: platform_stats.key(),
+            platform_activity: platform_activity.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::RecordMarketActivity { activity }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            platform_stats.to_account_info(),
+            platform_activity.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Reallocate an account migrated to a larger layout to `len` bytes, with
+/// `payer` topping up its rent first
+fn grow_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    len: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?.minimum_balance(len).saturating_sub(account_info.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    
+    account_info.realloc(len, true)?;
+    Ok(())
+}
+
+/// Decode a market in the layout that predates fee and payout tracking,
+/// checking it sits at the address its own fields derive
+fn legacy_market(account_info: &AccountInfo, data: &[u8]) -> Result<Market> {
+    let legacy = LegacyMarket::deserialize(&mut &data[8..])?;
+    let match_id = match_id_seed(&legacy.match_id);
+    let bump = [legacy.bump];
+    let expected = Pubkey::create_program_address(
+        &market_signer_seeds(&legacy.factory, &match_id, &bump),
+        &crate::ID,
+    )
+    .map_err(|_| error!(MarketError::InvalidMarketAccount))?;
+    require_keys_eq!(account_info.key(), expected, MarketError::InvalidMarketAccount);
+    
+    let surplus = account_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(LegacyMarket::LEN));
+    Ok(legacy.into_current(surplus))
+}
+
+/// Report creator activity to the dashboard's creator stats, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn record_creator_activity<'info>(
+    market: &Account<'info, Market>,
+    creator_stats: &AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    activity: CreatorActivity,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::RecordCreatorActivity {
+            creator_stats: creator_stats.key(),
+            creator: creator.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            payer: payer.key(),
+            system_program: system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::RecordCreatorActivity { activity }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            creator_stats.clone(),
+            creator.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Credit `user` points in the dashboard's points ledger, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn credit_points<'info>(
+    market: &Account<'info, Market>,
+    points_ledger: &AccountInfo<'info>,
+    points_totals: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    activity: PointsActivity,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::CreditPoints {
+            points_ledger: points_ledger.key(),
+            points_totals: points_totals.key(),
+            user: user.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            payer: payer.key(),
+            system_program: system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::CreditPoints { activity }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            points_ledger.clone(),
+            points_totals.clone(),
+            user.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            payer.clone(),
+            system_program.to_account_info(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Credit a creator fee to the dashboard's creator earnings ledger, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn accrue_creator_earnings<'info>(
+    market: &Account<'info, Market>,
+    creator_earnings: &AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::AccrueCreatorEarnings {
+            creator_earnings: creator_earnings.key(),
+            creator: creator.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            payer: payer.key(),
+            system_program: system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::AccrueCreatorEarnings { amount }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            creator_earnings.clone(),
+            creator.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Read the user's rebate tracker, if they have opted in to rebates
+fn read_rebate_tracker(account: &AccountInfo) -> Result<Option<RebateTracker>> {
+    if *account.owner != cryptoscore_dashboard::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let tracker = RebateTracker::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(tracker))
+}
+
+/// Read the factory's rebate policy, if it has set one
+fn read_rebate_policy(account: &AccountInfo) -> Result<Option<RebatePolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = RebatePolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// Have the factory treasury rebate part of the market's entry fee to
+/// `user`, signing as the market PDA, and return the lamports it paid
+#[allow(clippy::too_many_arguments)]
+fn claim_streak_rebate<'info>(
+    market: &Account<'info, Market>,
+    factory: &Account<'info, Factory>,
+    rebate_policy: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    factory_program: &Program<'info, CryptoscoreFactory>,
+    instructions: &AccountInfo<'info>,
+) -> Result<u64> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: factory_program.key(),
+        accounts: cryptoscore_factory::accounts::PayStreakRebate {
+            factory: factory.key(),
+            rebate_policy: rebate_policy.key(),
+            treasury: treasury.key(),
+            insurance_fund: insurance_fund.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            user: user.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::PayStreakRebate { entry_fee: market.entry_fee }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            factory.to_account_info(),
+            rebate_policy.clone(),
+            treasury.clone(),
+            insurance_fund.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            user.clone(),
+            factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    match get_return_data() {
+        Some((program_id, data)) if program_id == factory_program.key() => {
+            u64::try_from_slice(&data).map_err(|_| error!(MarketError::MissingRebateAmount))
+        }
+        _ => err!(MarketError::MissingRebateAmount),
+    }
+}
+
+/// Read the factory's discount policy, if it has set one
+fn read_discount_policy(account: &AccountInfo) -> Result<Option<DiscountPolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = DiscountPolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// Reputation the wins and losses read from market accounts in the user's
+/// stats earn, zero if they have no stats or haven't migrated them
+fn read_reputation(account: &AccountInfo) -> Result<u64> {
+    if *account.owner != cryptoscore_dashboard::ID || account.data_len() < UserStats::LEN {
+        return Ok(0);
+    }
+    let stats = UserStats::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(reputation_balance(stats.verified_wins, stats.verified_losses))
+}
+
+/// Number of creator badges in the user's creator stats, zero if they have never created a market
+fn read_creator_badges(account: &AccountInfo) -> Result<u8> {
+    if *account.owner != cryptoscore_dashboard::ID || account.data_is_empty() {
+        return Ok(0);
+    }
+    let stats = CreatorStats::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(stats.badges.count_ones() as u8)
+}
+
+/// Have the factory treasury pay `user` their reputation or badge discount on
+/// the market's entry fee, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn claim_fee_discount<'info>(
+    market: &Account<'info, Market>,
+    factory: &Account<'info, Factory>,
+    discount_policy: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    factory_program: &Program<'info, CryptoscoreFactory>,
+    instructions: &AccountInfo<'info>,
+    reputation: u64,
+    badges: u8,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: factory_program.key(),
+        accounts: cryptoscore_factory::accounts::PayFeeDiscount {
+            factory: factory.key(),
+            discount_policy: discount_policy.key(),
+            treasury: treasury.key(),
+            insurance_fund: insurance_fund.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            user: user.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::PayFeeDiscount {
+            entry_fee: market.entry_fee,
+            reputation,
+            badges,
+        }
+        .data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            factory.to_account_info(),
+            discount_policy.clone(),
+            treasury.clone(),
+            insurance_fund.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            user.clone(),
+            factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Have the factory treasury pay the market's entry fee for `user` with
+/// their voucher from `voucher_campaign`, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn redeem_voucher<'info>(
+    market: &Account<'info, Market>,
+    factory: &Account<'info, Factory>,
+    voucher_campaign: &AccountInfo<'info>,
+    voucher: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    factory_program: &Program<'info, CryptoscoreFactory>,
+    instructions: &AccountInfo<'info>,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: factory_program.key(),
+        accounts: cryptoscore_factory::accounts::RedeemVoucher {
+            factory: factory.key(),
+            voucher_campaign: voucher_campaign.key(),
+            voucher: voucher.key(),
+            treasury: treasury.key(),
+            insurance_fund: insurance_fund.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            user: user.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::RedeemVoucher { entry_fee: market.entry_fee }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            factory.to_account_info(),
+            voucher_campaign.clone(),
+            voucher.clone(),
+            treasury.clone(),
+            insurance_fund.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            user.clone(),
+            factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Have the factory treasury tip `keeper` for running one of the market's
+/// permissionless cranks, signing as the market PDA, if the factory has
+/// turned keeper tips on
+fn tip_keeper<'info>(
+    market: &Account<'info, Market>,
+    tip: &KeeperTip<'info>,
+    keeper: &Signer<'info>,
+) -> Result<()> {
+    require_keys_eq!(tip.factory.key(), market.factory, MarketError::FactoryMismatch);
+    match read_keeper_tip_policy(&tip.keeper_tip_policy)? {
+        Some(policy) if policy.tip > 0 => {}
+        _ => return Ok(()),
+    }
+    
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: tip.factory_program.key(),
+        accounts: cryptoscore_factory::accounts::PayKeeperTip {
+            factory: tip.factory.key(),
+            keeper_tip_policy: tip.keeper_tip_policy.key(),
+            keeper_tips: tip.keeper_tips.key(),
+            treasury: tip.treasury.key(),
+            insurance_fund: tip.insurance_fund.key(),
+            market: market.key(),
+            instructions: tip.instructions.key(),
+            keeper: keeper.key(),
+            system_program: tip.system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::PayKeeperTip { total_pool: market.total_pool }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            tip.factory.to_account_info(),
+            tip.keeper_tip_policy.to_account_info(),
+            tip.keeper_tips.to_account_info(),
+            tip.treasury.to_account_info(),
+            tip.insurance_fund.to_account_info(),
+            market.to_account_info(),
+            tip.instructions.to_account_info(),
+            keeper.to_account_info(),
+            tip.system_program.to_account_info(),
+            tip.factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// The factory's resolution grace policy, if it has set one
+fn read_resolution_grace_policy(account: &AccountInfo) -> Result<Option<ResolutionGracePolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = ResolutionGracePolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// The factory's match ID policy, if it has set one
+fn read_match_id_policy(account: &AccountInfo) -> Result<Option<MatchIdPolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = MatchIdPolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// The factory's keeper tip policy, if it has set one
+fn read_keeper_tip_policy(account: &AccountInfo) -> Result<Option<KeeperTipPolicy>> {
+    if *account.owner != cryptoscore_factory::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let policy = KeeperTipPolicy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(Some(policy))
+}
+
+/// Add an entry to the user's dashboard rebate tracker, signing as the market PDA
+fn track_rebate_entry<'info>(
+    market: &Account<'info, Market>,
+    rebate_tracker: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    rebated: bool,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::TrackRebateEntry {
+            rebate_tracker: rebate_tracker.key(),
+            user: user.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::TrackRebateEntry { rebated }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            rebate_tracker.clone(),
+            user.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Settle an entry in the user's dashboard rebate tracker, signing as the market PDA
+fn apply_rebate_result<'info>(
+    market: &Account<'info, Market>,
+    rebate_tracker: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    market_result: MarketResult,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::SettleRebateEntry {
+            rebate_tracker: rebate_tracker.key(),
+            user: user.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::SettleRebateEntry { market_result }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            rebate_tracker.clone(),
+            user.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Add a market to the user's dashboard pending claims, signing as the market PDA
+fn list_pending_claim<'info>(
+    market: &Account<'info, Market>,
+    pending_claims: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::RecordPendingClaim {
+            pending_claims: pending_claims.key(),
+            user: user.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            payer: payer.key(),
+            system_program: system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::RecordPendingClaim {}.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            pending_claims.clone(),
+            user.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Remove a market from the user's dashboard pending claims, signing as the market PDA
+fn prune_pending_claim<'info>(
+    market: &Account<'info, Market>,
+    pending_claims: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    dashboard_program: &Program<'info, CryptoscoreDashboard>,
+    instructions: &AccountInfo<'info>,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: dashboard_program.key(),
+        accounts: cryptoscore_dashboard::accounts::ClearPendingClaim {
+            pending_claims: pending_claims.key(),
+            user: user.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_dashboard::instruction::ClearPendingClaim {}.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            pending_claims.clone(),
+            user.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            dashboard_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Record a platform fee in the factory treasury's ledger, signing as the market PDA
+#[allow(clippy::too_many_arguments)]
+fn record_platform_fee<'info>(
+    market: &Account<'info, Market>,
+    factory: &Account<'info, Factory>,
+    treasury: &AccountInfo<'info>,
+    fee_receipt: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    factory_program: &Program<'info, CryptoscoreFactory>,
+    instructions: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: factory_program.key(),
+        accounts: cryptoscore_factory::accounts::RecordPlatformFee {
+            factory: factory.key(),
+            treasury: treasury.key(),
+            fee_receipt: fee_receipt.key(),
+            market: market.key(),
+            instructions: instructions.key(),
+            payer: payer.key(),
+            system_program: system_program.key(),
+        }
+        .to_account_metas(Some(true)),
+        data: cryptoscore_factory::instruction::RecordPlatformFee { amount }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            factory.to_account_info(),
+            treasury.clone(),
+            fee_receipt.clone(),
+            market.to_account_info(),
+            instructions.clone(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Have the factory rule `market` mis-resolved to `outcome` on its results
+/// signer's correction, signing as the market PDA
+fn rule_result_correction(accounts: &CorrectResult, outcome: MatchOutcome) -> Result<()> {
+    let market = &accounts.market;
+    let match_id = match_id_seed(&market.match_id);
+    let bump = [market.bump];
+    let signer_seeds = market_signer_seeds(&market.factory, &match_id, &bump);
+    
+    let ix = Instruction {
+        program_id: accounts.factory_program.key(),
+        accounts: cryptoscore_factory::accounts::RecordResultCorrection {
+            factory: accounts.factory.key(),
+            market: market.key(),
+            ruling: accounts.misresolution_ruling.key(),
+            insurance_fund: accounts.insurance_fund.key(),
+            instructions: accounts.instructions.key(),
+            payer: accounts.signer.key(),
+            system_program: accounts.system_program.key(),
+        }
+        .to_account_metas(None),
+        data: cryptoscore_factory::instruction::RecordResultCorrection { correct_outcome: outcome }.data(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.factory.to_account_info(),
+            market.to_account_info(),
+            accounts.misresolution_ruling.to_account_info(),
+            accounts.insurance_fund.to_account_info(),
+            accounts.instructions.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.factory_program.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+    
+    Ok(())
+}
+
+/// Start `market`'s winners root over for its corrected `outcome`, if a
+/// keeper has begun building one
+fn restart_winners_root(market: &Account<Market>, account: &AccountInfo, outcome: &MatchOutcome) -> Result<()> {
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(());
+    }
+    let mut winners_root = WinnersRoot::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    
+    let winner_count = market.winner_count(outcome);
+    winners_root.reward = match winner_count {
+        0 => 0,
+        _ => market.reward_per_winner(winner_count).ok_or_else(|| {
+            error!(MarketError::RewardCalculationFailed).with_values((market.total_pool, winner_count))
+        })?,
+    };
+    winners_root.cursor = 0;
+    winners_root.winners = 0;
+    winners_root.frontier = [[0; 32]; WINNERS_TREE_DEPTH];
+    winners_root.root = [0; 32];
+    winners_root.published = false;
+    winners_root.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+// Account Structures
+
+/// A single match prediction market, holding the entry fee pool in its own lamports
+#[account]
+pub struct Market {
+    /// Factory that created this market
+    pub factory: Pubkey,
+    /// Creator of the market
+    pub creator: Pubkey,
+    /// Match identifier
+    pub match_id: String,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    /// Match kickoff timestamp
+    pub kickoff_time: i64,
+    /// Match end timestamp
+    pub end_time: i64,
+    /// Current market status
+    pub status: MarketStatus,
+    /// Match outcome (if resolved)
+    pub outcome: Option<MatchOutcome>,
+    /// Total pool in lamports
+    pub total_pool: u64,
+    /// Total number of participants
+    pub participant_count: u32,
+    /// Number of HOME predictions
+    pub home_count: u32,
+    /// Number of DRAW predictions
+    pub draw_count: u32,
+    /// Number of AWAY predictions
+    pub away_count: u32,
+    /// Whether market is public
+    pub is_public: bool,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Creator and platform fees paid at resolution
+    pub fees_paid: u64,
+    /// Rewards, refunds and swept funds paid out of the pool
+    pub total_claimed: u64,
+    /// Users who can administer the market alongside the creator; unused
+    /// slots hold the default pubkey
+    pub co_admins: [Pubkey; MAX_CO_ADMINS],
+    /// Outcomes the market takes predictions on and resolves to
+    pub outcome_set: OutcomeSet,
+    /// Seconds after `end_time` during which only the creator can resolve
+    pub resolution_grace_seconds: i64,
+    /// Final score the market resolved from; unset for player prop markets
+    /// and markets resolved before scores were recorded
+    pub final_score: Option<FinalScore>,
+    /// Sum of every entry's join time, weighting refunds' share of staking
+    /// yield; zero for markets migrated from before it was tracked
+    pub joined_at_sum: i128,
+    /// Queued joins escrowed and not yet executed or refunded; zero for
+    /// markets migrated from before it was tracked
+    pub queued_join_count: u32,
+}
+
+impl Market {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // factory
+        32 + // creator
+        MATCH_ID_SPACE + // match_id
+        8 +  // entry_fee
+        8 +  // kickoff_time
+        8 +  // end_time
+        MARKET_STATUS_SPACE + // status
+        1 + MATCH_OUTCOME_SPACE + // outcome (Option<MatchOutcome>)
+        8 +  // total_pool
+        4 +  // participant_count
+        4 +  // home_count
+        4 +  // draw_count
+        4 +  // away_count
+        1 +  // is_public
+        1 +  // bump
+        8 +  // fees_paid
+        8 +  // total_claimed
+        32 * MAX_CO_ADMINS + // co_admins
+        OUTCOME_SET_SPACE + // outcome_set
+        8 +  // resolution_grace_seconds
+        1 + FINAL_SCORE_SPACE + // final_score (Option<FinalScore>)
+        16 + // joined_at_sum
+        4;   // queued_join_count
+
+    /// Size before queued joins were counted; such markets only need to grow
+    pub const PRE_QUEUED_JOIN_COUNT_LEN: usize = Market::LEN - 4;
+
+    /// Size before join times were summed; such markets only need to grow
+    pub const PRE_JOINED_AT_SUM_LEN: usize = Market::PRE_QUEUED_JOIN_COUNT_LEN - 16;
+
+    /// Size before final scores were recorded; such markets only need to grow
+    pub const PRE_FINAL_SCORE_LEN: usize = Market::PRE_JOINED_AT_SUM_LEN - (1 + FINAL_SCORE_SPACE);
+
+    /// Size before resolution grace periods were added; such markets only need to grow
+    pub const PRE_RESOLUTION_GRACE_LEN: usize = Market::PRE_FINAL_SCORE_LEN - 8;
+
+    /// Size before outcome sets were added; such markets only need to grow
+    pub const PRE_OUTCOME_SET_LEN: usize = Market::PRE_RESOLUTION_GRACE_LEN - OUTCOME_SET_SPACE;
+
+    /// Size before co-admins were added; such markets only need to grow
+    pub const PRE_CO_ADMIN_LEN: usize = Market::PRE_OUTCOME_SET_LEN - 32 * MAX_CO_ADMINS;
+
+    /// When anyone besides the creator may resolve the market
+    pub fn grace_ends_at(&self) -> i64 {
+        self.end_time.saturating_add(self.resolution_grace_seconds)
+    }
+
+    /// Whether `key` is the creator or one of the appointed co-admins
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        *key == self.creator || (*key != Pubkey::default() && self.co_admins.contains(key))
+    }
+
+    /// Equal share of the pool left after fees, returned to each participant
+    /// of a cancelled market; the entry fee unless it was voided after resolution
+    pub fn refund_per_participant(&self) -> u64 {
+        self.principal_refund(0)
+    }
+
+    /// Equal share of the pool left after fees and `interest` earned by
+    /// staking it, the least any participant of a cancelled market is owed
+    pub fn principal_refund(&self, interest: u64) -> u64 {
+        match self.participant_count {
+            0 => self.entry_fee,
+            count => self.total_pool.saturating_sub(self.fees_paid).saturating_sub(interest) / count as u64,
+        }
+    }
+
+    /// Refund owed to a participant of a cancelled market who joined at
+    /// `joined_at`, once its staked pool earned `interest` until `unwound_at`:
+    /// their principal refund plus a share of the interest weighted by how
+    /// long they were in the pool
+    ///
+    /// Every entry joined before the match ended, so weighting up to its end,
+    /// or the unwinding if later, counts each of them. Markets without summed
+    /// join times split the interest equally.
+    pub fn refund_with_interest(&self, joined_at: i64, interest: u64, unwound_at: i64) -> Option<u64> {
+        if self.joined_at_sum == 0 {
+            return Some(self.refund_per_participant());
+        }
+        let until = self.end_time.max(unwound_at);
+        let share = interest_share(interest, joined_at, until, self.participant_count, self.joined_at_sum)?;
+        self.principal_refund(interest).checked_add(share)
+    }
+
+    /// Participants who predicted `outcome`
+    pub fn winner_count(&self, outcome: &MatchOutcome) -> u32 {
+        match outcome {
+            MatchOutcome::Home => self.home_count,
+            MatchOutcome::Draw => self.draw_count,
+            MatchOutcome::Away => self.away_count,
+        }
+    }
+
+    /// Equal share of the pool left after resolution took its fees or
+    /// charity donation, for each of `winner_count` winners
+    pub fn reward_per_winner(&self, winner_count: u32) -> Option<u64> {
+        self.total_pool.checked_sub(self.fees_paid)?.checked_div(winner_count as u64)
+    }
+
+    /// When queued joins stop being accepted and start being settled
+    pub fn queued_join_deadline(&self) -> i64 {
+        self.kickoff_time.saturating_sub(QUEUED_JOIN_LEAD_SECONDS)
+    }
+
+    /// Lamports the vault must still hold for fees and payouts not yet made
+    pub fn liability(&self) -> u64 {
+        self.total_pool
+            .saturating_sub(self.fees_paid)
+            .saturating_sub(self.total_claimed)
+    }
+}
+
+/// Market layout before fees and payouts were tracked, kept for migration
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMarket {
+    /// Factory that created this market
+    pub factory: Pubkey,
+    /// Creator of the market
+    pub creator: Pubkey,
+    /// Match identifier
+    pub match_id: String,
+    /// Entry fee in lamports
+    pub entry_fee: u64,
+    /// Match kickoff timestamp
+    pub kickoff_time: i64,
+    /// Match end timestamp
+    pub end_time: i64,
+    /// Current market status
+    pub status: MarketStatus,
+    /// Match outcome (if resolved)
+    pub outcome: Option<MatchOutcome>,
+    /// Total pool in lamports
+    pub total_pool: u64,
+    /// Total number of participants
+    pub participant_count: u32,
+    /// Number of HOME predictions
+    pub home_count: u32,
+    /// Number of DRAW predictions
+    pub draw_count: u32,
+    /// Number of AWAY predictions
+    pub away_count: u32,
+    /// Whether market is public
+    pub is_public: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LegacyMarket {
+    pub const LEN: usize = Market::PRE_CO_ADMIN_LEN - 8 - 8;
+
+    /// Current layout, treating pool funds missing from `surplus` as paid out
+    fn into_current(self, surplus: u64) -> Market {
+        let fees_paid = if self.status.is_resolved() {
+            split_pool(self.total_pool).map_or(0, |split| split.total_fees)
+        } else {
+            0
+        };
+        let total_claimed = self.total_pool.saturating_sub(fees_paid).saturating_sub(surplus);
+        
+        Market {
+            factory: self.factory,
+            creator: self.creator,
+            match_id: self.match_id,
+            entry_fee: self.entry_fee,
+            kickoff_time: self.kickoff_time,
+            end_time: self.end_time,
+            status: self.status,
+            outcome: self.outcome,
+            total_pool: self.total_pool,
+            participant_count: self.participant_count,
+            home_count: self.home_count,
+            draw_count: self.draw_count,
+            away_count: self.away_count,
+            is_public: self.is_public,
+            bump: self.bump,
+            fees_paid,
+            total_claimed,
+            co_admins: [Pubkey::default(); MAX_CO_ADMINS],
+            outcome_set: OutcomeSet::WinDrawWin,
+            resolution_grace_seconds: 0,
+            final_score: None,
+            joined_at_sum: 0,
+            queued_join_count: 0,
+        }
+    }
+}
+
+/// A user's prediction in one market
+#[account]
+pub struct Participant {
+    /// Market this participant joined
+    pub market: Pubkey,
+    /// User's wallet address
+    pub user: Pubkey,
+    /// User's prediction
+    pub prediction: MatchOutcome,
+    /// Timestamp when joined
+    pub joined_at: i64,
+    /// Whether rewards have been withdrawn
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Hash of the user's note on their prediction, e.g. a rationale
+    /// revealed later; all zeros when they left none
+    pub note_hash: [u8; 32],
+    /// Position of the entry among the market's entries, from 0, for
+    /// deterministic tiebreakers and first-N bonuses; committed entries
+    /// keep their place from the commit
+    pub entry_index: u32,
+}
+
+impl Participant {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        MATCH_OUTCOME_SPACE + // prediction
+        8 +  // joined_at
+        1 +  // has_withdrawn
+        1 +  // bump
+        32 + // note_hash
+        4;   // entry_index
+
+    /// `entry_index` of participants that joined before entries were indexed
+    pub const UNINDEXED: u32 = u32::MAX;
+
+    /// Size before entry indexes were added
+    pub const PRE_ENTRY_INDEX_LEN: usize = Participant::LEN - 4;
+
+    /// Size before join notes were added
+    pub const PRE_NOTE_LEN: usize = Participant::PRE_ENTRY_INDEX_LEN - 32;
+}
+
+/// Entry escrowed until the queue deadline, joining only if its payout
+/// condition holds, at the `queued_join` PDA of the market and user
+#[account]
+pub struct QueuedJoin {
+    /// Market to join
+    pub market: Pubkey,
+    /// User joining
+    pub user: Pubkey,
+    /// Outcome to join on
+    pub prediction: MatchOutcome,
+    /// Smallest acceptable payout in basis points of the entry fee
+    pub min_payout_bps: u64,
+    /// Entry fee escrowed in lamports
+    pub entry_fee: u64,
+    /// Participant account rent escrowed for whoever executes the join
+    pub participant_rent: u64,
+    /// Queueing timestamp
+    pub queued_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QueuedJoin {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        MATCH_OUTCOME_SPACE + // prediction
+        8 +  // min_payout_bps
+        8 +  // entry_fee
+        8 +  // participant_rent
+        8 +  // queued_at
+        1;   // bump
+}
+
+/// A follower copying a leader's predictions, at the `follow` PDA of the
+/// follower and leader
+#[account]
+pub struct Follow {
+    /// User copying predictions
+    pub follower: Pubkey,
+    /// User whose predictions are copied
+    pub leader: Pubkey,
+    /// Following timestamp
+    pub followed_at: i64,
+    /// Markets joined by copying the leader
+    pub copies: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Follow {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // follower
+        32 + // leader
+        8 +  // followed_at
+        4 +  // copies
+        1;   // bump
+}
+
+/// Commit–reveal mode of a private market, at the `commit_reveal` PDA of
+/// its market; plain joins are refused while it exists
+#[account]
+pub struct CommitReveal {
+    /// Market taking committed predictions
+    pub market: Pubkey,
+    /// Predictions committed
+    pub commitments: u32,
+    /// Commitments revealed so far
+    pub reveals: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CommitReveal {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        4 +  // commitments
+        4 +  // reveals
+        1;   // bump
+}
+
+/// Holdings a market's joiners must prove, at the `token_gate` PDA of its
+/// market; only holders can join while it exists
+#[account]
+pub struct TokenGate {
+    /// Market being gated
+    pub market: Pubkey,
+    /// What joiners must hold
+    pub rule: TokenGateRule,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TokenGate {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        TokenGateRule::SPACE + // rule
+        1;   // bump
+}
+
+/// Charity a market gives its fees and a share of its prize pool to, at the
+/// `charity_pledge` PDA of its market
+#[account]
+pub struct CharityPledge {
+    /// Market making the donation
+    pub market: Pubkey,
+    /// Charity receiving it at resolution
+    pub charity: Pubkey,
+    /// Share of the prize pool given on top of the fees, in basis points
+    pub pool_share_bps: u16,
+    /// Lamports given at resolution
+    pub donated: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CharityPledge {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // charity
+        2 +  // pool_share_bps
+        8 +  // donated
+        1;   // bump
+}
+
+/// Earliest entry on one outcome of a market offering a first-correct bonus
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EarliestEntry {
+    /// User who made the entry
+    pub user: Pubkey,
+    /// The entry's `entry_index`
+    pub entry_index: u32,
+}
+
+/// Bonus out of the creator fee for a market's earliest correct entry, at
+/// the `first_correct_bonus` PDA of its market
+#[account]
+pub struct FirstCorrectBonus {
+    /// Market paying the bonus
+    pub market: Pubkey,
+    /// Share of the creator fee paid as the bonus, in basis points
+    pub bonus_bps: u16,
+    /// Earliest entry on home, draw and away so far
+    pub earliest: [Option<EarliestEntry>; 3],
+    /// Earliest entry on the winning outcome, set at resolution
+    pub winner: Option<Pubkey>,
+    /// Lamports set aside for the winner at resolution, held by this account
+    pub bonus: u64,
+    /// Whether the winner has been paid the bonus
+    pub claimed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FirstCorrectBonus {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        2 +  // bonus_bps
+        3 * (1 + 32 + 4) + // earliest
+        1 + 32 + // winner
+        8 +  // bonus
+        1 +  // claimed
+        1;   // bump
+    
+    /// Slot of `outcome` in `earliest`
+    pub fn slot(outcome: &MatchOutcome) -> usize {
+        match outcome {
+            MatchOutcome::Home => 0,
+            MatchOutcome::Draw => 1,
+            MatchOutcome::Away => 2,
+        }
+    }
+}
+
+/// How a market would settle on an outcome, returned by `preview_settlement`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SettlementPreview {
+    /// Outcome the preview assumes
+    pub outcome: MatchOutcome,
+    /// Participants who predicted it
+    pub winner_count: u32,
+    /// What each winner could withdraw
+    pub reward_per_winner: u64,
+    /// Creator fee, less any first-correct bonus
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+    /// Charity market's donation, in place of both fees
+    pub charity_donation: u64,
+    /// First-correct bonus taken out of the creator fee
+    pub first_correct_bonus: u64,
+    /// Prize pool left in the vault once every winner withdraws
+    pub dust: u64,
+}
+
+/// Follow-up market a market's winners can roll their rewards into, at
+/// the `market_chain` PDA of its market
+#[account]
+pub struct MarketChain {
+    /// Market whose winners roll over
+    pub market: Pubkey,
+    /// Market the rewards roll into
+    pub follow_up: Pubkey,
+    /// Rewards rolled into the follow-up so far
+    pub rolled: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketChain {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // follow_up
+        4 +  // rolled
+        1;   // bump
+}
+
+/// An entry funded by a reward rolled from an earlier market, at the
+/// `rolled_entry` PDA of the market entered and its user
+#[account]
+pub struct RolledEntry {
+    /// Market entered
+    pub market: Pubkey,
+    /// User who rolled their reward
+    pub user: Pubkey,
+    /// Market whose reward funded the entry
+    pub source_market: Pubkey,
+    /// Reward rolled in lamports, before the entry fee was staked from it
+    pub reward: u64,
+    /// Consecutive markets the user has rolled into, counting this one
+    pub streak: u32,
+    /// Timestamp of the roll
+    pub rolled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RolledEntry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        32 + // source_market
+        8 +  // reward
+        4 +  // streak
+        8 +  // rolled_at
+        1;   // bump
+}
+
+/// Minute and goal lock of a market taking joins in play, at the
+/// `in_play_lock` PDA of its market
+#[account]
+pub struct InPlayLock {
+    /// Market taking joins in play
+    pub market: Pubkey,
+    /// Factory live score of the market's match
+    pub live_score: Pubkey,
+    /// Minute of play from which joins are refused
+    pub lock_minute: u16,
+    /// Whether the first goal also stops joins
+    pub lock_on_goal: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InPlayLock {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // live_score
+        2 +  // lock_minute
+        1 +  // lock_on_goal
+        1;   // bump
+    
+    /// Whether `score` stops joins
+    pub fn is_locked_by(&self, score: &LiveScore) -> bool {
+        score.is_final ||
+            score.minute >= self.lock_minute ||
+            (self.lock_on_goal && (score.home_goals > 0 || score.away_goals > 0))
+    }
+}
+
+/// Players a player prop market's entries pick from, at the `prop_registry`
+/// PDA of its market
+#[account]
+pub struct PropRegistry {
+    /// Prop market
+    pub market: Pubkey,
+    /// Player-level event the market settles on
+    pub kind: PropKind,
+    /// Oracle IDs of the players entries can pick
+    pub player_ids: Vec<u32>,
+    /// Entries on each listed player, in `player_ids` order
+    pub pick_counts: Vec<u32>,
+    /// Player the results signer reported at resolution, if any listed one
+    pub winning_player: Option<u32>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PropRegistry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        PROP_KIND_SPACE + // kind
+        4 + 4 * MAX_PROP_PLAYERS + // player_ids
+        4 + 4 * MAX_PROP_PLAYERS + // pick_counts
+        1 + 4 + // winning_player
+        1;   // bump
+    
+    /// Position of `player_id` in the listed players
+    pub fn player_slot(&self, player_id: u32) -> Option<usize> {
+        self.player_ids.iter().position(|listed| *listed == player_id)
+    }
+}
+
+/// Latest standings of an outright market, at the `outright_standings` PDA
+/// of its market
+#[account]
+pub struct OutrightStandings {
+    /// Outright market
+    pub market: Pubkey,
+    /// Whether each listed team can no longer win, in `player_ids` order
+    pub eliminated: Vec<bool>,
+    /// Checkpoints the results signer has recorded
+    pub checkpoint_count: u32,
+    /// When the latest checkpoint was recorded
+    pub last_checkpoint_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OutrightStandings {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        4 + MAX_PROP_PLAYERS + // eliminated
+        4 +  // checkpoint_count
+        8 +  // last_checkpoint_at
+        1;   // bump
+    
+    /// Listed teams still able to win
+    pub fn remaining(&self) -> usize {
+        self.eliminated.iter().filter(|eliminated| !**eliminated).count()
+    }
+    
+    /// Position of the last team standing, once only one is left
+    pub fn decided_slot(&self) -> Option<usize> {
+        match self.remaining() {
+            1 => self.eliminated.iter().position(|eliminated| !eliminated),
+            _ => None,
+        }
+    }
+}
+
+/// Pick counts of a correct-score market, at the `score_grid` PDA of its
+/// market
+#[account]
+pub struct ScoreGrid {
+    /// Correct-score market
+    pub market: Pubkey,
+    /// Entries on each scoreline, indexed by `Scoreline::bucket`
+    pub pick_counts: [u32; SCORE_GRID_BUCKETS],
+    /// Scoreline the final score settled on, once resolved
+    pub result: Option<Scoreline>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ScoreGrid {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        4 * SCORE_GRID_BUCKETS + // pick_counts
+        1 + SCORELINE_SPACE + // result
+        1;   // bump
+    
+    /// Entries on `scoreline`, none for an exact score off the grid
+    pub fn winner_count(&self, scoreline: &Scoreline) -> u32 {
+        scoreline.bucket().map_or(0, |bucket| self.pick_counts[bucket])
+    }
+}
+
+/// Handicap line of a handicap market, at the `handicap` PDA of its market
+#[account]
+pub struct Handicap {
+    /// Handicap market
+    pub market: Pubkey,
+    /// How a level result after the line settles
+    pub style: HandicapStyle,
+    /// Half goals added to the home side's score
+    pub home_line: i8,
+    /// Half goals added to the away side's score, mirroring `home_line`
+    pub away_line: i8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Handicap {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        HANDICAP_STYLE_SPACE + // style
+        1 +  // home_line
+        1 +  // away_line
+        1;   // bump
+}
+
+/// A user's pick on a correct-score market, at the `score_entry` PDA of the
+/// market and user
+#[account]
+pub struct ScoreEntry {
+    /// Correct-score market entered
+    pub market: Pubkey,
+    /// User who entered
+    pub user: Pubkey,
+    /// Picked final score
+    pub scoreline: Scoreline,
+    /// When the user entered
+    pub joined_at: i64,
+    /// Whether the user has withdrawn their reward or refund
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ScoreEntry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        SCORELINE_SPACE + // scoreline
+        8 +  // joined_at
+        1 +  // has_withdrawn
+        1;   // bump
+}
+
+/// A user's pick on a player prop market, at the `prop_entry` PDA of the
+/// market and user
+#[account]
+pub struct PropEntry {
+    /// Prop market entered
+    pub market: Pubkey,
+    /// User who entered
+    pub user: Pubkey,
+    /// Oracle ID of the picked player
+    pub player_id: u32,
+    /// When the user entered
+    pub joined_at: i64,
+    /// Whether the user has withdrawn their reward or refund
+    pub has_withdrawn: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PropEntry {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        4 +  // player_id
+        8 +  // joined_at
+        1 +  // has_withdrawn
+        1;   // bump
+}
+
+/// Hidden prediction awaiting its reveal, at the `commitment` PDA of the
+/// market and user
+#[account]
+pub struct Commitment {
+    /// Market entered
+    pub market: Pubkey,
+    /// User who committed
+    pub user: Pubkey,
+    /// `prediction_commitment` of the hidden prediction
+    pub commitment: [u8; 32],
+    /// Commitment timestamp
+    pub committed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Position of the entry among the market's entries, kept on reveal
+    pub entry_index: u32,
+}
+
+impl Commitment {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        32 + // commitment
+        8 +  // committed_at
+        1 +  // bump
+        4;   // entry_index
+}
+
+/// Final scores signed by a high-stakes market's co-signers, at the
+/// `resolution_votes` PDA of its market
+#[account]
+pub struct ResolutionVotes {
+    /// Market being resolved
+    pub market: Pubkey,
+    /// Score each of the creator, results signer and arbiter signed, if any
+    pub scores: [Option<FinalScore>; CO_SIGNER_SLOTS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResolutionVotes {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        CO_SIGNER_SLOTS * (1 + FINAL_SCORE_SPACE) + // scores
+        1;   // bump
+}
+
+/// A market pool parked in an SPL stake pool during the match, at the
+/// `yield_position` PDA of its market
+#[account]
+pub struct YieldPosition {
+    /// Market whose pool was staked
+    pub market: Pubkey,
+    /// Stake pool holding it
+    pub stake_pool: Pubkey,
+    /// Liquid staking token received
+    pub pool_mint: Pubkey,
+    /// Lamports taken from the vault
+    pub staked: u64,
+    /// Pool tokens minted for them
+    pub pool_tokens: u64,
+    /// Staking timestamp
+    pub staked_at: i64,
+    /// Lamports returned to the vault on unwinding
+    pub returned: u64,
+    /// Unwinding timestamp, unset while the pool is staked
+    pub unwound_at: Option<i64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl YieldPosition {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // stake_pool
+        32 + // pool_mint
+        8 +  // staked
+        8 +  // pool_tokens
+        8 +  // staked_at
+        8 +  // returned
+        1 + 8 + // unwound_at (Option<i64>)
+        1;   // bump
+}
+
+/// Ring buffer of a market's prediction distribution over time, at the
+/// `odds_history` PDA of its market
+#[account]
+pub struct OddsHistory {
+    /// Market sampled
+    pub market: Pubkey,
+    /// Samples in slot order; `head` is the oldest once the buffer is full
+    pub samples: [OddsSample; ODDS_HISTORY_SAMPLES],
+    /// Slot the next sample is written to
+    pub head: u8,
+    /// Samples recorded, up to `ODDS_HISTORY_SAMPLES`
+    pub len: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OddsHistory {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        ODDS_HISTORY_SAMPLES * ODDS_SAMPLE_SPACE + // samples
+        1 +  // head
+        1 +  // len
+        1;   // bump
+
+    /// Write `sample` over the oldest slot once the buffer is full
+    pub fn push(&mut self, sample: OddsSample) {
+        self.samples[self.head as usize] = sample;
+        self.head = ((self.head as usize + 1) % ODDS_HISTORY_SAMPLES) as u8;
+        self.len = self.len.saturating_add(1).min(ODDS_HISTORY_SAMPLES as u8);
+    }
+
+    /// Most recent sample, if any
+    pub fn latest(&self) -> Option<&OddsSample> {
+        (self.len > 0).then(|| &self.samples[(self.head as usize + ODDS_HISTORY_SAMPLES - 1) % ODDS_HISTORY_SAMPLES])
+    }
+
+    /// Recorded samples, oldest first
+    pub fn chronological(&self) -> Vec<OddsSample> {
+        let start = (self.head as usize + ODDS_HISTORY_SAMPLES - self.len as usize) % ODDS_HISTORY_SAMPLES;
+        (0..self.len as usize)
+            .map(|i| self.samples[(start + i) % ODDS_HISTORY_SAMPLES])
+            .collect()
+    }
+}
+
+/// Count of a market's listed participants, at the `participant_roster`
+/// PDA of its market; joins are listed on its pages while it exists
+#[account]
+pub struct ParticipantRoster {
+    /// Market whose participants are listed
+    pub market: Pubkey,
+    /// Participants listed across every page
+    pub count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParticipantRoster {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        4 +  // count
+        1;   // bump
+    
+    /// Pages opened so far
+    pub fn pages(&self) -> u32 {
+        self.count.div_ceil(ROSTER_PAGE_SIZE as u32)
+    }
+}
+
+/// One page of a market's participant roster, at the `roster_page` PDA of
+/// its market and page number
+#[account]
+pub struct RosterPage {
+    /// Market whose participants are listed
+    pub market: Pubkey,
+    /// Page number, from 0
+    pub page: u32,
+    /// Participants in joining order; the first `len` slots are filled
+    pub users: [Pubkey; ROSTER_PAGE_SIZE],
+    /// Participants listed on this page
+    pub len: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RosterPage {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        4 +  // page
+        32 * ROSTER_PAGE_SIZE + // users
+        1 +  // len
+        1;   // bump
+    
+    /// The listed participants
+    pub fn listed(&self) -> &[Pubkey] {
+        &self.users[..self.len as usize]
+    }
+}
+
+/// Merkle root of a resolved market's winners, at the `winners_root` PDA of
+/// its market, built from its participant roster
+#[account]
+pub struct WinnersRoot {
+    /// Market whose winners are proven
+    pub market: Pubkey,
+    /// Reward every winner is owed, in lamports
+    pub reward: u64,
+    /// Roster entries folded in so far
+    pub cursor: u32,
+    /// Winners appended to the tree so far
+    pub winners: u32,
+    /// Last left node at each level of the tree
+    pub frontier: [[u8; 32]; WINNERS_TREE_DEPTH],
+    /// Root over every winner, set once published
+    pub root: [u8; 32],
+    /// Whether the whole roster has been folded in
+    pub published: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WinnersRoot {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        8 +  // reward
+        4 +  // cursor
+        4 +  // winners
+        32 * WINNERS_TREE_DEPTH + // frontier
+        32 + // root
+        1 +  // published
+        1;   // bump
+    
+    /// Whether `proof` shows `user` won as winner number `index`
+    pub fn verify(&self, user: &Pubkey, index: u32, proof: &[[u8; 32]]) -> bool {
+        self.published && verify_winner(&self.root, user, self.reward, index, proof)
+    }
+}
+
+/// Record of a claim whose compressed NFT receipt was minted, at the
+/// `claim_receipt` PDA of its market and user
+#[account]
+pub struct ClaimReceipt {
+    /// Market the claim was made on
+    pub market: Pubkey,
+    /// Claiming user
+    pub user: Pubkey,
+    /// User's prediction
+    pub prediction: MatchOutcome,
+    /// Entry fee staked, in lamports
+    pub stake: u64,
+    /// Reward or refund withdrawn, in lamports
+    pub payout: u64,
+    /// Minting timestamp
+    pub minted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimReceipt {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // user
+        MATCH_OUTCOME_SPACE + // prediction
+        8 +  // stake
+        8 +  // payout
+        8 +  // minted_at
+        1;   // bump
+}
+
+/// Where a fully settled market's pool went, at the `settlement_report` PDA
+/// of its market
+///
+/// `total_in` always equals `fees_out + rewards_out + refunds_out + dust`;
+/// `paid_out` and `vault_remaining` show how much of the rest has left the vault.
+#[account]
+pub struct SettlementReport {
+    /// Market reported on
+    pub market: Pubkey,
+    /// Factory that created it
+    pub factory: Pubkey,
+    /// `Settled` once swept, or `Closed` once refunded
+    pub status: MarketStatus,
+    /// Participants who entered
+    pub participants: u32,
+    /// Winners the pool was split between
+    pub winners: u32,
+    /// Entry fees pooled, in lamports
+    pub total_in: u64,
+    /// Creator and platform fees, or the charity donation, paid at resolution
+    pub fees_out: u64,
+    /// Winners' equal shares, whether claimed or swept unclaimed
+    pub rewards_out: u64,
+    /// Entry fees refunded
+    pub refunds_out: u64,
+    /// Remainder of the equal split, swept with unclaimed rewards or left in the vault
+    pub dust: u64,
+    /// Rewards, refunds and sweeps paid out of the vault
+    pub paid_out: u64,
+    /// Lamports above rent still in the vault
+    pub vault_remaining: u64,
+    /// Compilation timestamp
+    pub compiled_at: i64,
+    /// PDA bump seed
     pub bump: u8,
 }
 
-impl Participant {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // market
-        32 + // user
-        1 +  // prediction
-        8 +  // joined_at
-        1 +  // has_withdrawn
-        1;   // bump
+impl SettlementReport {
+    pub const LEN: usize = DISCRIMINATOR_LEN +
+        32 + // market
+        32 + // factory
+        MARKET_STATUS_SPACE + // status
+        4 +  // participants
+        4 +  // winners
+        8 +  // total_in
+        8 +  // fees_out
+        8 +  // rewards_out
+        8 +  // refunds_out
+        8 +  // dust
+        8 +  // paid_out
+        8 +  // vault_remaining
+        8 +  // compiled_at
+        1;   // bump
+}
+
+// Context Structures
+
+/// Accounts for `initialize_market`
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct InitializeMarket<'info> {
+    /// Market PDA, created here
+    #[account(
+        init,
+        payer = creator,
+        space = Market::LEN,
+        seeds = [
+            b"market",
+            factory.key().as_ref(),
+            match_id_seed(&match_id).as_ref()
+        ],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: Factory account that created this market
+    pub factory: AccountInfo<'info>,
+    
+    /// Market creator, paying for the account
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", factory.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator stats PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"creator_stats",
+            creator.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub creator_stats: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The creator's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            creator.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory resolution grace policy, read if the factory has set one
+    #[account(
+        seeds = [b"resolution_grace_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub resolution_grace_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory match ID policy, read if the factory has set one
+    #[account(
+        seeds = [b"match_id_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub match_id_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `join_market`
+#[derive(Accounts)]
+pub struct JoinMarket<'info> {
+    /// Market being joined
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Participant PDA for the user, created here unless an earlier attempt
+    /// at the join already created it
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Participant::LEN,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Joining user, paying the entry fee
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: Commit–reveal settings, which rule out plain joins if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: In-play lock, which keeps joins open after kickoff if the creator has enabled it
+    #[account(seeds = [b"in_play_lock", market.key().as_ref()], bump)]
+    pub in_play_lock: UncheckedAccount<'info>,
+    
+    /// CHECK: The match's live score, checked against the in-play lock on joins after kickoff
+    pub live_score: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out match predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's rebate tracker, updated via CPI if the user has opted in to rebates
+    #[account(
+        mut,
+        seeds = [b"rebate_tracker", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub rebate_tracker: UncheckedAccount<'info>,
+    
+    /// Factory the market was created under, funding rebates and discounts
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
+    
+    /// CHECK: Factory rebate policy, read if the factory has set one
+    #[account(
+        mut,
+        seeds = [b"rebate_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub rebate_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory treasury paying any rebate or discount
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory insurance fund, whose unswept share the treasury keeps back from rebates and discounts
+    #[account(
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory discount policy, read if the factory has set one
+    #[account(
+        mut,
+        seeds = [b"discount_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub discount_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's dashboard stats, whose verified record decides their reputation
+    #[account(
+        seeds = [b"user_stats", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub user_stats: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's creator stats, whose badges count if the user has created markets
+    #[account(
+        seeds = [b"creator_stats", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub creator_stats: UncheckedAccount<'info>,
+    
+    /// Factory program paying rebates and discounts
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, tracking the earliest entry on each outcome if the creator has offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// CHECK: Campaign of a voucher covering the entry fee, checked by the factory program
+    #[account(mut)]
+    pub voucher_campaign: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: The user's voucher covering the entry fee, checked by the factory program
+    #[account(mut)]
+    pub voucher: Option<UncheckedAccount<'info>>,
+    
+    /// Roster page listing the user
+    pub roster: RosterSlot<'info>,
+}
+
+/// Accounts for `validate_join`
+///
+/// On token-gated markets the user's holdings proof follows as remaining
+/// accounts.
+#[derive(Accounts)]
+pub struct ValidateJoin<'info> {
+    /// Market the join is checked against
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: The user's participant PDA, read if an earlier join created it
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub participant: UncheckedAccount<'info>,
+    
+    /// CHECK: Wallet whose join is checked; it need not sign
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: Commit–reveal settings, which rule out plain joins if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: In-play lock, which keeps joins open after kickoff if the creator has enabled it
+    #[account(seeds = [b"in_play_lock", market.key().as_ref()], bump)]
+    pub in_play_lock: UncheckedAccount<'info>,
+    
+    /// CHECK: The match's live score, checked against the in-play lock on joins after kickoff
+    pub live_score: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out match predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+}
+
+/// Accounts for `queue_join`
+#[derive(Accounts)]
+pub struct QueueJoin<'info> {
+    /// Market to join, counting the queued join
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Escrow for the entry, created here
+    #[account(
+        init,
+        payer = user,
+        space = QueuedJoin::LEN,
+        seeds = [b"queued_join", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub queued_join: Account<'info, QueuedJoin>,
+    
+    /// User queueing the join, paying the escrow
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: Token gate, which rules out queued joins if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out match predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `execute_queued_join`
+#[derive(Accounts)]
+pub struct ExecuteQueuedJoin<'info> {
+    /// Market being joined
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Queued entry, closed back to its user once executed
+    #[account(
+        mut,
+        seeds = [b"queued_join", market.key().as_ref(), user.key().as_ref()],
+        bump = queued_join.bump,
+        has_one = market,
+        has_one = user,
+        close = user
+    )]
+    pub queued_join: Account<'info, QueuedJoin>,
+    
+    /// Participant PDA for the queued user, created here
+    #[account(
+        init,
+        payer = cranker,
+        space = Participant::LEN,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// CHECK: User who queued the join, receiving the escrow's leftover rent
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    
+    /// Anyone executing the join, fronting the participant rent and any new points accounts
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: Commit–reveal settings, which rule out plain joins if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out match predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: The queued user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, tracking the earliest entry on each outcome if the creator has offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// Roster page listing the queued user
+    pub roster: RosterSlot<'info>,
+    
+    /// Keeper tip for the cranker
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `refund_queued_join`
+#[derive(Accounts)]
+pub struct RefundQueuedJoin<'info> {
+    /// Market the join was queued for
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Queued entry, closed back to its user
+    #[account(
+        mut,
+        seeds = [b"queued_join", market.key().as_ref(), user.key().as_ref()],
+        bump = queued_join.bump,
+        has_one = market,
+        has_one = user,
+        close = user
+    )]
+    pub queued_join: Account<'info, QueuedJoin>,
+    
+    /// CHECK: User who queued the join, receiving the escrow
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    
+    /// The user, or anyone once the join can no longer execute
+    pub signer: Signer<'info>,
+}
+
+/// Accounts for `follow`
+#[derive(Accounts)]
+pub struct FollowLeader<'info> {
+    /// Follow relationship, created here
+    #[account(
+        init,
+        payer = follower,
+        space = Follow::LEN,
+        seeds = [b"follow", follower.key().as_ref(), leader.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+    
+    /// CHECK: Any user can be followed
+    pub leader: UncheckedAccount<'info>,
+    
+    /// User following, paying the rent
+    #[account(mut)]
+    pub follower: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `unfollow`
+#[derive(Accounts)]
+pub struct UnfollowLeader<'info> {
+    /// Follow relationship, closed back to the follower
+    #[account(
+        mut,
+        seeds = [b"follow", follower.key().as_ref(), follow.leader.as_ref()],
+        bump = follow.bump,
+        has_one = follower,
+        close = follower
+    )]
+    pub follow: Account<'info, Follow>,
+    
+    #[account(mut)]
+    pub follower: Signer<'info>,
+}
+
+/// Accounts for `copy_join`
+#[derive(Accounts)]
+pub struct CopyJoin<'info> {
+    /// Market being joined
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's follow of the leader being copied
+    #[account(
+        mut,
+        seeds = [b"follow", user.key().as_ref(), follow.leader.as_ref()],
+        bump = follow.bump
+    )]
+    pub follow: Account<'info, Follow>,
+    
+    /// Leader's entry in this market, whose prediction is copied
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            follow.leader.as_ref()
+        ],
+        bump = leader_participant.bump
+    )]
+    pub leader_participant: Account<'info, Participant>,
+    
+    /// Participant PDA for the user, created here
+    #[account(
+        init,
+        payer = user,
+        space = Participant::LEN,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Copying user, paying the entry fee
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: Commit–reveal settings, which rule out plain joins if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: In-play lock, which keeps joins open after kickoff if the creator has enabled it
+    #[account(seeds = [b"in_play_lock", market.key().as_ref()], bump)]
+    pub in_play_lock: UncheckedAccount<'info>,
+    
+    /// CHECK: The match's live score, checked against the in-play lock on joins after kickoff
+    pub live_score: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out match predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, tracking the earliest entry on each outcome if the creator has offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// Roster page listing the user
+    pub roster: RosterSlot<'info>,
+}
+
+/// Accounts for `enable_commit_reveal`
+#[derive(Accounts)]
+pub struct EnableCommitReveal<'info> {
+    /// Market switching to committed predictions
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Commit–reveal settings, created here
+    #[account(
+        init,
+        payer = admin,
+        space = CommitReveal::LEN,
+        seeds = [b"commit_reveal", market.key().as_ref()],
+        bump
+    )]
+    pub commit_reveal: Account<'info, CommitReveal>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// CHECK: Token gate, which rules out committed predictions if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out committed predictions if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out committed predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `enable_participant_roster`
+#[derive(Accounts)]
+pub struct EnableParticipantRoster<'info> {
+    /// Market listing its participants
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Participant roster, created here
+    #[account(
+        init,
+        payer = admin,
+        space = ParticipantRoster::LEN,
+        seeds = [b"participant_roster", market.key().as_ref()],
+        bump
+    )]
+    pub participant_roster: Account<'info, ParticipantRoster>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_token_gate`
+#[derive(Accounts)]
+pub struct SetTokenGate<'info> {
+    /// Market being gated
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Token gate, created here
+    #[account(
+        init,
+        payer = admin,
+        space = TokenGate::LEN,
+        seeds = [b"token_gate", market.key().as_ref()],
+        bump
+    )]
+    pub token_gate: Account<'info, TokenGate>,
+    
+    /// CHECK: Commit–reveal settings, which rule out token gates if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `pledge_to_charity`
+#[derive(Accounts)]
+pub struct PledgeToCharity<'info> {
+    /// Market being made a charity market
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Charity pledge, created here
+    #[account(
+        init,
+        payer = admin,
+        space = CharityPledge::LEN,
+        seeds = [b"charity_pledge", market.key().as_ref()],
+        bump
+    )]
+    pub charity_pledge: Account<'info, CharityPledge>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `offer_first_correct_bonus`
+#[derive(Accounts)]
+pub struct OfferFirstCorrectBonus<'info> {
+    /// Market offering the bonus
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// First-correct bonus, created here
+    #[account(
+        init,
+        payer = admin,
+        space = FirstCorrectBonus::LEN,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: Account<'info, FirstCorrectBonus>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_follow_up_market`
+#[derive(Accounts)]
+pub struct SetFollowUpMarket<'info> {
+    /// Market whose winners roll over
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Market the rewards roll into
+    #[account(
+        seeds = [
+            b"market",
+            follow_up.factory.as_ref(),
+            match_id_seed(&follow_up.match_id).as_ref()
+        ],
+        bump = follow_up.bump
+    )]
+    pub follow_up: Account<'info, Market>,
+    
+    /// Market chain, created on first use and repointed after
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MarketChain::LEN,
+        seeds = [b"market_chain", market.key().as_ref()],
+        bump
+    )]
+    pub market_chain: Account<'info, MarketChain>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `enable_in_play`
+#[derive(Accounts)]
+pub struct EnableInPlay<'info> {
+    /// Market taking joins in play
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// In-play lock, created here
+    #[account(
+        init,
+        payer = admin,
+        space = InPlayLock::LEN,
+        seeds = [b"in_play_lock", market.key().as_ref()],
+        bump
+    )]
+    pub in_play_lock: Account<'info, InPlayLock>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `list_prop_players`
+#[derive(Accounts)]
+pub struct ListPropPlayers<'info> {
+    /// Market becoming a player prop market
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Prop registry, created here
+    #[account(
+        init,
+        payer = admin,
+        space = PropRegistry::LEN,
+        seeds = [b"prop_registry", market.key().as_ref()],
+        bump
+    )]
+    pub prop_registry: Account<'info, PropRegistry>,
+    
+    /// CHECK: Commit–reveal settings, which rule out player props if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out player props if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `join_prop`
+///
+/// On token-gated markets the user's holdings proof follows as remaining
+/// accounts.
+#[derive(Accounts)]
+pub struct JoinProp<'info> {
+    /// Prop market being entered
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's listed players, counting the pick
+    #[account(
+        mut,
+        seeds = [b"prop_registry", market.key().as_ref()],
+        bump = prop_registry.bump,
+        has_one = market
+    )]
+    pub prop_registry: Account<'info, PropRegistry>,
+    
+    /// The user's pick, created here
+    #[account(
+        init,
+        payer = user,
+        space = PropEntry::LEN,
+        seeds = [b"prop_entry", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub prop_entry: Account<'info, PropEntry>,
+    
+    /// Entering user, paying the entry fee
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: In-play lock, which keeps entries open after kickoff if the creator has enabled it
+    #[account(seeds = [b"in_play_lock", market.key().as_ref()], bump)]
+    pub in_play_lock: UncheckedAccount<'info>,
+    
+    /// CHECK: The match's live score, checked against the in-play lock on entries after kickoff
+    pub live_score: UncheckedAccount<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `withdraw_prop_rewards`
+#[derive(Accounts)]
+pub struct WithdrawPropRewards<'info> {
+    /// Prop market paying the reward or refund
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's listed players and winning player
+    #[account(
+        seeds = [b"prop_registry", market.key().as_ref()],
+        bump = prop_registry.bump,
+        has_one = market
+    )]
+    pub prop_registry: Account<'info, PropRegistry>,
+    
+    /// The user's pick
+    #[account(
+        mut,
+        seeds = [b"prop_entry", market.key().as_ref(), user.key().as_ref()],
+        bump = prop_entry.bump,
+        has_one = market,
+        has_one = user
+    )]
+    pub prop_entry: Account<'info, PropEntry>,
+    
+    /// User withdrawing
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `enable_correct_score`
+#[derive(Accounts)]
+pub struct EnableCorrectScore<'info> {
+    /// Market becoming a correct-score market
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Score grid, created here
+    #[account(
+        init,
+        payer = admin,
+        space = ScoreGrid::LEN,
+        seeds = [b"score_grid", market.key().as_ref()],
+        bump
+    )]
+    pub score_grid: Account<'info, ScoreGrid>,
+    
+    /// CHECK: Commit–reveal settings, which rule out correct-score picks if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", market.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: Player prop registry, which rules out correct-score picks if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `set_handicap`
+#[derive(Accounts)]
+pub struct SetHandicap<'info> {
+    /// Market becoming a handicap market
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        constraint = market.is_admin(&admin.key()) @ MarketError::NotMarketAdmin
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Handicap line, created here
+    #[account(
+        init,
+        payer = admin,
+        space = Handicap::LEN,
+        seeds = [b"handicap", market.key().as_ref()],
+        bump
+    )]
+    pub handicap: Account<'info, Handicap>,
+    
+    /// CHECK: Player prop registry, which rules out a handicap if the creator has listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out a handicap if the creator has enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// Market creator or co-admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `join_correct_score`
+///
+/// On token-gated markets the user's holdings proof follows as remaining
+/// accounts.
+#[derive(Accounts)]
+pub struct JoinCorrectScore<'info> {
+    /// Correct-score market being entered
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's score grid, counting the pick
+    #[account(
+        mut,
+        seeds = [b"score_grid", market.key().as_ref()],
+        bump = score_grid.bump,
+        has_one = market
+    )]
+    pub score_grid: Account<'info, ScoreGrid>,
+    
+    /// The user's pick, created here
+    #[account(
+        init,
+        payer = user,
+        space = ScoreEntry::LEN,
+        seeds = [b"score_entry", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub score_entry: Account<'info, ScoreEntry>,
+    
+    /// Entering user, paying the entry fee
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: Token gate, whose holdings the user must prove if the creator has set one
+    #[account(seeds = [b"token_gate", market.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: In-play lock, which keeps entries open after kickoff if the creator has enabled it
+    #[account(seeds = [b"in_play_lock", market.key().as_ref()], bump)]
+    pub in_play_lock: UncheckedAccount<'info>,
+    
+    /// CHECK: The match's live score, checked against the in-play lock on entries after kickoff
+    pub live_score: UncheckedAccount<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `withdraw_score_rewards`
+#[derive(Accounts)]
+pub struct WithdrawScoreRewards<'info> {
+    /// Correct-score market paying the reward or refund
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's pick counts and settled scoreline
+    #[account(
+        seeds = [b"score_grid", market.key().as_ref()],
+        bump = score_grid.bump,
+        has_one = market
+    )]
+    pub score_grid: Account<'info, ScoreGrid>,
+    
+    /// The user's pick
+    #[account(
+        mut,
+        seeds = [b"score_entry", market.key().as_ref(), user.key().as_ref()],
+        bump = score_entry.bump,
+        has_one = market,
+        has_one = user
+    )]
+    pub score_entry: Account<'info, ScoreEntry>,
+    
+    /// User withdrawing
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Accounts for `commit_prediction`
+#[derive(Accounts)]
+pub struct CommitPrediction<'info> {
+    /// Market being entered
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Commit–reveal settings, present only if the creator enabled them
+    #[account(
+        mut,
+        seeds = [b"commit_reveal", market.key().as_ref()],
+        bump = commit_reveal.bump
+    )]
+    pub commit_reveal: Account<'info, CommitReveal>,
+    
+    /// Commitment for the user, created here
+    #[account(
+        init,
+        payer = user,
+        space = Commitment::LEN,
+        seeds = [b"commitment", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, Commitment>,
+    
+    /// Committing user, paying the entry fee
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `reveal_prediction`
+#[derive(Accounts)]
+pub struct RevealPrediction<'info> {
+    /// Market the prediction was committed in
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Commit–reveal settings of the market
+    #[account(
+        mut,
+        seeds = [b"commit_reveal", market.key().as_ref()],
+        bump = commit_reveal.bump
+    )]
+    pub commit_reveal: Account<'info, CommitReveal>,
+    
+    /// Commitment being revealed, closed back to its user
+    #[account(
+        mut,
+        seeds = [b"commitment", market.key().as_ref(), user.key().as_ref()],
+        bump = commitment.bump,
+        has_one = market,
+        has_one = user,
+        close = user
+    )]
+    pub commitment: Account<'info, Commitment>,
+    
+    /// Participant PDA for the user, created here
+    #[account(
+        init,
+        payer = user,
+        space = Participant::LEN,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, tracking the earliest entry on each outcome if the creator has offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// Roster page listing the user
+    pub roster: RosterSlot<'info>,
+}
+
+/// Accounts for `resolve_market`
+#[derive(Accounts)]
+pub struct ResolveMarket<'info> {
+    /// Market being resolved
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Market creator or one of its participants
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+    
+    /// Market creator, whose fee accrues to their earnings ledger
+    /// CHECK: This account is validated against market.creator
+    #[account(
+        constraint = creator.key() == market.creator @ MarketError::InvalidCreator
+    )]
+    pub creator: AccountInfo<'info>,
+    
+    /// Factory the market was created under, owning the treasury
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
+    
+    /// CHECK: Factory treasury receiving the platform fee, created on first use by the factory program
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// Optional participant account - if provided, validates resolver is a participant
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            resolver.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Option<Account<'info, Participant>>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Creator stats updated via CPI
+    #[account(
+        mut,
+        seeds = [
+            b"creator_stats",
+            market.creator.as_ref()
+        ],
+        bump = creator_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
+    /// CHECK: Creator earnings PDA receiving the creator fee, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"creator_earnings",
+            market.creator.as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub creator_earnings: UncheckedAccount<'info>,
+    
+    /// CHECK: Receipt for this market's platform fee, created by the factory program
+    #[account(
+        mut,
+        seeds = [b"fee_receipt", market.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub fee_receipt: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: Factory resolution policy, read if the factory has set one
+    #[account(
+        seeds = [b"resolution_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub resolution_policy: UncheckedAccount<'info>,
+    
+    /// Factory program recording the platform fee
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Charity pledge, which routes the fees to its charity if the creator has made one
+    #[account(
+        mut,
+        seeds = [b"charity_pledge", market.key().as_ref()],
+        bump
+    )]
+    pub charity_pledge: UncheckedAccount<'info>,
+    
+    /// CHECK: Charity receiving a charity market's donation, checked against its pledge
+    #[account(mut)]
+    pub charity: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Player prop registry, which records the winning player on prop markets
+    #[account(
+        mut,
+        seeds = [b"prop_registry", market.key().as_ref()],
+        bump
+    )]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which records the settled scoreline on correct-score markets
+    #[account(
+        mut,
+        seeds = [b"score_grid", market.key().as_ref()],
+        bump
+    )]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: Handicap line, which rules out resolving handicap markets by the plain result
+    #[account(seeds = [b"handicap", market.key().as_ref()], bump)]
+    pub handicap: UncheckedAccount<'info>,
+    
+    /// CHECK: First-correct bonus, which takes its share of the creator fee if the creator has offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's rake-free flag, which waives the market's fees if set
+    #[account(
+        seeds = [b"rake_free_market", market.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub rake_free_market: UncheckedAccount<'info>,
+}
+
+/// Accounts for `resolve_with_attestation`
+#[derive(Accounts)]
+pub struct ResolveWithAttestation<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Results signer configured on the market's factory
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_with_vaa`
+#[derive(Accounts)]
+pub struct ResolveWithVaa<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Source-chain oracle registered on the market's factory
+    #[account(
+        seeds = [b"wormhole_emitter", resolution.market.factory.as_ref()],
+        bump = wormhole_emitter.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+    
+    /// CHECK: Posted VAA, trusted only when owned by the registered core
+    /// bridge and parsed in the handler
+    #[account(owner = wormhole_emitter.core_bridge @ MarketError::InvalidVaa)]
+    pub posted_vaa: UncheckedAccount<'info>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_with_live_score`
+#[derive(Accounts)]
+pub struct ResolveWithLiveScore<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Live score of the market's match
+    #[account(
+        seeds = [
+            b"live_score",
+            resolution.market.factory.as_ref(),
+            match_id_seed(&resolution.market.match_id).as_ref()
+        ],
+        bump = live_score.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub live_score: Account<'info, LiveScore>,
+    
+    /// Results signer configured on the market's factory, which must still be
+    /// the key that reported the score
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == live_score.signer @ MarketError::StaleLiveScore
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_prop`
+#[derive(Accounts)]
+pub struct ResolveProp<'info> {
+    /// Same accounts as `resolve_market`, with the results signer as the
+    /// resolver and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Results signer configured on the market's factory, which must be the resolver
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == resolution.resolver.key() @ MarketError::NotResultsSigner
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Keeper tip for the results signer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `record_outright_checkpoint`
+#[derive(Accounts)]
+pub struct RecordOutrightCheckpoint<'info> {
+    /// Outright market, whose entries close once it is decided
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's listed teams
+    #[account(
+        seeds = [b"prop_registry", market.key().as_ref()],
+        bump = prop_registry.bump,
+        has_one = market
+    )]
+    pub prop_registry: Account<'info, PropRegistry>,
+    
+    /// Standings, created on the first checkpoint
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = OutrightStandings::LEN,
+        seeds = [b"outright_standings", market.key().as_ref()],
+        bump
+    )]
+    pub outright_standings: Account<'info, OutrightStandings>,
+    
+    /// Results signer configured on the market's factory
+    #[account(
+        seeds = [b"results_signer", market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == signer.key() @ MarketError::NotResultsSigner
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Results signer's key, paying for the standings
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `settle_outright_early`
+#[derive(Accounts)]
+pub struct SettleOutrightEarly<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// The market's standings, which must have one team left
+    #[account(
+        seeds = [b"outright_standings", resolution.market.key().as_ref()],
+        bump = outright_standings.bump
+    )]
+    pub outright_standings: Account<'info, OutrightStandings>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_correct_score`
+#[derive(Accounts)]
+pub struct ResolveCorrectScore<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Live score of the market's match
+    #[account(
+        seeds = [
+            b"live_score",
+            resolution.market.factory.as_ref(),
+            match_id_seed(&resolution.market.match_id).as_ref()
+        ],
+        bump = live_score.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub live_score: Account<'info, LiveScore>,
+    
+    /// Results signer configured on the market's factory, which must still be
+    /// the key that reported the score
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == live_score.signer @ MarketError::StaleLiveScore
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_handicap`
+#[derive(Accounts)]
+pub struct ResolveHandicap<'info> {
+    /// Same accounts as `resolve_market`, with any relayer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Live score of the market's match
+    #[account(
+        seeds = [
+            b"live_score",
+            resolution.market.factory.as_ref(),
+            match_id_seed(&resolution.market.match_id).as_ref()
+        ],
+        bump = live_score.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub live_score: Account<'info, LiveScore>,
+    
+    /// Results signer configured on the market's factory, which must still be
+    /// the key that reported the score
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == live_score.signer @ MarketError::StaleLiveScore
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Keeper tip for the relayer
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `resolve_markets_batch`, followed by each market's
+/// `resolve_market` accounts
+#[derive(Accounts)]
+pub struct ResolveMarketsBatch<'info> {
+    /// Results published by the factory's results signer
+    pub results_batch: Account<'info, ResultsBatch>,
+    
+    /// Results signer configured on the batch's factory, which must still be
+    /// the key that published it
+    #[account(
+        seeds = [b"results_signer", results_batch.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == results_batch.signer @ MarketError::StaleResultsBatch
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+}
+
+/// Accounts for `sign_resolution`
+#[derive(Accounts)]
+pub struct SignResolution<'info> {
+    /// High-stakes market being signed off
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Results signer configured on the market's factory
+    #[account(
+        seeds = [b"results_signer", market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Resolution policy configured on the market's factory
+    #[account(
+        seeds = [b"resolution_policy", market.factory.as_ref()],
+        bump = resolution_policy.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub resolution_policy: Account<'info, ResolutionPolicy>,
+    
+    /// Signatures collected so far, created by the first signer
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ResolutionVotes::LEN,
+        seeds = [b"resolution_votes", market.key().as_ref()],
+        bump
+    )]
+    pub resolution_votes: Account<'info, ResolutionVotes>,
+    
+    /// Creator, results signer or arbiter
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `co_sign_resolution`
+#[derive(Accounts)]
+pub struct CoSignResolution<'info> {
+    /// Same accounts as `resolve_market`, with the co-signer as the resolver
+    /// and no participant
+    pub resolution: ResolveMarket<'info>,
+    
+    /// Results signer configured on the market's factory
+    #[account(
+        seeds = [b"results_signer", resolution.market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// Signatures collected so far
+    #[account(
+        seeds = [b"resolution_votes", resolution.market.key().as_ref()],
+        bump = resolution_votes.bump
+    )]
+    pub resolution_votes: Account<'info, ResolutionVotes>,
+}
+
+/// Accounts for `correct_result`
+#[derive(Accounts)]
+pub struct CorrectResult<'info> {
+    /// Resolved market whose result is corrected
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Factory the market was created under
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Results signer configured on the market's factory, which must sign the correction
+    #[account(
+        seeds = [b"results_signer", market.factory.as_ref()],
+        bump = results_signer.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = results_signer.signer == signer.key() @ MarketError::NotResultsSigner
+    )]
+    pub results_signer: Account<'info, ResultsSigner>,
+    
+    /// The results signer's key, paying for a mis-resolution ruling if one is needed
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// CHECK: Player prop registry, which rules out correcting prop markets
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, which rules out correcting correct-score markets
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: Handicap line, which rules out correcting handicap markets by the plain result
+    #[account(seeds = [b"handicap", market.key().as_ref()], bump)]
+    pub handicap: UncheckedAccount<'info>,
+    
+    /// CHECK: First-correct bonus, which moves to the corrected outcome when the market re-resolves
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// CHECK: Winners root, which starts over when the market re-resolves
+    #[account(
+        mut,
+        seeds = [b"winners_root", market.key().as_ref()],
+        bump
+    )]
+    pub winners_root: UncheckedAccount<'info>,
+    
+    /// CHECK: Mis-resolution ruling, created by the factory program if winners have already claimed
+    #[account(
+        mut,
+        seeds = [b"misresolution_ruling", market.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub misresolution_ruling: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory insurance fund paying the ruling's claims, created on first use by the factory program
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Factory program recording the ruling
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `withdraw_rewards`
+#[derive(Accounts)]
+pub struct WithdrawRewards<'info> {
+    /// Resolved market paying the reward
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's participant account
+    #[account(
+        mut,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Winning user receiving the reward
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: The user's pending claims PDA, pruned if the dashboard program has created it
+    #[account(
+        mut,
+        seeds = [b"pending_claims", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub pending_claims: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the pending claims CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, paid with the reward if it was set aside for the user
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+}
+
+/// Accounts for `roll_rewards`
+#[derive(Accounts)]
+pub struct RollRewards<'info> {
+    /// Resolved market paying the reward
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+    
+    /// The user's participant account
+    #[account(
+        mut,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Winning user rolling the reward, paying the new accounts' rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: First-correct bonus, paid with the reward if it was set aside for the user
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's pending claims PDA, pruned if the dashboard program has created it
+    #[account(
+        mut,
+        seeds = [b"pending_claims", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub pending_claims: UncheckedAccount<'info>,
+    
+    /// Market chain naming the follow-up
+    #[account(
+        mut,
+        seeds = [b"market_chain", market.key().as_ref()],
+        bump = market_chain.bump,
+        has_one = follow_up @ MarketError::NotFollowUpMarket
+    )]
+    pub market_chain: Account<'info, MarketChain>,
+    
+    /// CHECK: The user's rolled entry in the market, which extends their streak if the market's own entry was rolled
+    #[account(seeds = [b"rolled_entry", market.key().as_ref(), user.key().as_ref()], bump)]
+    pub previous_rolled_entry: UncheckedAccount<'info>,
+    
+    /// Follow-up market being joined
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            follow_up.factory.as_ref(),
+            match_id_seed(&follow_up.match_id).as_ref()
+        ],
+        bump = follow_up.bump
+    )]
+    pub follow_up: Box<Account<'info, Market>>,
+    
+    /// The user's participant PDA in the follow-up, created here
+    #[account(
+        init,
+        payer = user,
+        space = Participant::LEN,
+        seeds = [
+            b"participant",
+            follow_up.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub entry: Account<'info, Participant>,
+    
+    /// Rolled entry linking the follow-up entry to the reward, created here
+    #[account(
+        init,
+        payer = user,
+        space = RolledEntry::LEN,
+        seeds = [b"rolled_entry", follow_up.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub rolled_entry: Account<'info, RolledEntry>,
+    
+    /// CHECK: The follow-up's commit–reveal settings, which rule out plain joins if the creator has enabled them
+    #[account(seeds = [b"commit_reveal", follow_up.key().as_ref()], bump)]
+    pub commit_reveal: UncheckedAccount<'info>,
+    
+    /// CHECK: The follow-up's token gate, which rules out rolled entries if the creator has set one
+    #[account(seeds = [b"token_gate", follow_up.key().as_ref()], bump)]
+    pub token_gate: UncheckedAccount<'info>,
+    
+    /// CHECK: The follow-up's player prop registry, which rules out match predictions if the creator has listed
+    /// players
+    #[account(seeds = [b"prop_registry", follow_up.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: The follow-up's correct-score grid, which rules out match predictions if the creator has enabled one
+    #[account(seeds = [b"score_grid", follow_up.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// CHECK: The follow-up's first-correct bonus, tracking the earliest entry on each outcome if it offers one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", follow_up.key().as_ref()],
+        bump
+    )]
+    pub follow_up_first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the follow-up's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", follow_up.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's points ledger PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [
+            b"points_ledger",
+            user.key().as_ref()
+        ],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_ledger: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform points totals PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"points_totals"],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub points_totals: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the pending claims, stats and points CPIs
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Roster page of the follow-up listing the user
+    pub roster: RosterSlot<'info>,
+}
+
+/// Accounts for `withdraw_rewards_swapped`, followed by the Jupiter route's
+/// accounts as remaining accounts
+#[derive(Accounts)]
+pub struct WithdrawRewardsSwapped<'info> {
+    /// Resolved market paying the reward
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's participant account
+    #[account(
+        mut,
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Winning user, signing the swap
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Tokens the market's factory lets winners swap into
+    #[account(
+        seeds = [b"payout_swap_policy", market.factory.as_ref()],
+        bump = payout_swap_policy.bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub payout_swap_policy: Account<'info, PayoutSwapPolicy>,
+    
+    /// CHECK: The user's wrapped SOL token account the reward is paid into, checked in the handler
+    #[account(mut)]
+    pub wrapped_sol: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's token account receiving the swapped reward, checked in the handler
+    #[account(mut)]
+    pub output_tokens: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's pending claims PDA, pruned if the dashboard program has created it
+    #[account(
+        mut,
+        seeds = [b"pending_claims", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub pending_claims: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the pending claims CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: Jupiter aggregator program running the route
+    #[account(address = JUPITER_PROGRAM_ID)]
+    pub jupiter_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL token program
+    #[account(address = TOKEN_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: First-correct bonus, paid with the reward if it was set aside for the user
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+}
+
+/// Accounts for `mint_claim_receipt`
+#[derive(Accounts)]
+pub struct MintClaimReceipt<'info> {
+    /// Market the claim was made on
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's participant account
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// Claiming user, owning and paying for the receipt
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Tree the market's factory mints receipts into
+    #[account(
+        seeds = [b"receipt_policy", market.factory.as_ref()],
+        bump = receipt_policy.bump,
+        seeds::program = cryptoscore_factory::ID,
+        constraint = receipt_policy.enabled() @ MarketError::ReceiptsDisabled
+    )]
+    pub receipt_policy: Account<'info, ReceiptPolicy>,
+    
+    /// Marks the claim's receipt minted
+    #[account(
+        init,
+        payer = user,
+        space = ClaimReceipt::LEN,
+        seeds = [b"claim_receipt", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    
+    /// CHECK: Tree delegate and verified creator of every receipt, signing as a PDA
+    #[account(seeds = [b"receipt_authority"], bump)]
+    pub receipt_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum's config of the tree, checked by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+    
+    /// CHECK: The factory's receipt tree
+    #[account(mut, address = receipt_policy.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex Bubblegum program minting the receipt
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL Noop program logging the new leaf
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL account compression program holding the tree
+    #[account(address = COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `settle_rebate_entry`
+#[derive(Accounts)]
+pub struct SettleRebateEntry<'info> {
+    /// Resolved or cancelled market the entry was made in
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The user's participant account
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// CHECK: User whose entry settled
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's rebate tracker, updated via CPI
+    #[account(
+        mut,
+        seeds = [b"rebate_tracker", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub rebate_tracker: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the rebate tracker CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Anyone settling the entry, fronting the market's tip tally rent on its first tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    
+    /// Keeper tip for the settlement
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `record_pending_claim`
+#[derive(Accounts)]
+pub struct RecordPendingClaim<'info> {
+    /// Resolved market the reward is owed from
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The winner's participant account
+    #[account(
+        seeds = [
+            b"participant",
+            market.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, Participant>,
+    
+    /// CHECK: Winning user the claim is listed for
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's pending claims PDA, created on first use by the dashboard program
+    #[account(
+        mut,
+        seeds = [b"pending_claims", user.key().as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub pending_claims: UncheckedAccount<'info>,
+    
+    /// Anyone recording the claim, fronting the pending claims rent on first use
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    /// Dashboard program receiving the pending claims CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Keeper tip for the cranker
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts for `build_winners_root`
+///
+/// The participant accounts of the roster entries being folded in follow as
+/// remaining accounts.
+#[derive(Accounts)]
+pub struct BuildWinnersRoot<'info> {
+    /// Resolved market whose winners are proven
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The market's participant roster
+    #[account(
+        seeds = [b"participant_roster", market.key().as_ref()],
+        bump = participant_roster.bump
+    )]
+    pub participant_roster: Account<'info, ParticipantRoster>,
+    
+    /// CHECK: Roster page holding the next entries, checked against the root's cursor; unused once every entry is
+    /// folded in
+    pub roster_page: UncheckedAccount<'info>,
+    
+    /// Winners root, created by the first call
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = WinnersRoot::LEN,
+        seeds = [b"winners_root", market.key().as_ref()],
+        bump
+    )]
+    pub winners_root: Account<'info, WinnersRoot>,
+    
+    /// Anyone building the root, fronting its rent on the first call
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Keeper tip for the cranker
+    pub tip: KeeperTip<'info>,
+}
+
+/// Accounts tipping whoever runs a permissionless crank from the factory
+/// treasury, used only if the factory has set a keeper tip policy
+#[derive(Accounts)]
+pub struct KeeperTip<'info> {
+    /// Factory whose treasury pays the tip, checked against the market's
+    pub factory: Account<'info, Factory>,
+    
+    /// CHECK: Factory keeper tip policy, read if the factory has set one
+    #[account(
+        seeds = [b"keeper_tip_policy", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub keeper_tip_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: The market's tip tally, created on its first tip and checked by the factory program
+    #[account(mut)]
+    pub keeper_tips: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory treasury paying the tip
+    #[account(
+        mut,
+        seeds = [b"treasury", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory insurance fund, whose unswept share the treasury keeps back from tips
+    #[account(
+        seeds = [b"insurance_fund", factory.key().as_ref()],
+        bump,
+        seeds::program = factory_program.key()
+    )]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// Factory program paying the tip
+    pub factory_program: Program<'info, CryptoscoreFactory>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
-// Enums
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum MarketStatus {
-    Open,
-    Live,
-    Resolved,
-    Cancelled,
+/// Accounts listing a new participant on the market's roster, used only if
+/// the creator has enabled one
+#[derive(Accounts)]
+pub struct RosterSlot<'info> {
+    /// CHECK: The market's participant roster, checked against its PDA and read if the creator has enabled one
+    #[account(mut)]
+    pub participant_roster: UncheckedAccount<'info>,
+    
+    /// CHECK: The roster's last page, or the next one if that is full; checked against the roster's count and
+    /// created by the entry opening it
+    #[account(mut)]
+    pub roster_page: UncheckedAccount<'info>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
-pub enum MatchOutcome {
-    Home,
-    Draw,
-    Away,
+/// Accounts for `sweep_unclaimed`
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    /// Resolved market past its claim window
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Factory the market was created under, holding the sweep authority
+    #[account(
+        address = market.factory,
+        has_one = authority @ MarketError::UnauthorizedSweeper
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Factory authority
+    pub authority: Signer<'info>,
+    
+    /// Platform account receiving the swept funds
+    /// CHECK: This is the platform's designated fee collection account
+    #[account(mut)]
+    pub platform: AccountInfo<'info>,
+    
+    /// CHECK: First-correct bonus, whose unclaimed bonus is swept if the creator offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
 }
 
-// Context Structures
+/// Accounts for `set_co_admins`
+#[derive(Accounts)]
+pub struct SetCoAdmins<'info> {
+    /// Market whose co-admins are replaced
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump,
+        has_one = creator
+    )]
+    pub market: Account<'info, Market>,
+    
+    pub creator: Signer<'info>,
+}
 
+/// Accounts for `cancel_market`
 #[derive(Accounts)]
-#[instruction(match_id: String)]
-pub struct InitializeMarket<'info> {
+pub struct CancelMarket<'info> {
+    /// Market being called off
     #[account(
-        init,
-        payer = creator,
-        space = Market::LEN,
+        mut,
         seeds = [
             b"market",
-            factory.key().as_ref(),
-            match_id.as_bytes()
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
         ],
-        bump
+        bump = market.bump
     )]
     pub market: Account<'info, Market>,
     
-    /// CHECK: Factory account that created this market
-    pub factory: AccountInfo<'info>,
+    /// Factory the market was created under, holding the cancel authority
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
     
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    /// Market creator or factory authority
+    pub canceller: Signer<'info>,    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
     
-    pub system_program: Program<'info, System>,
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
 }
 
+/// Accounts for `claim_refund`
 #[derive(Accounts)]
-pub struct JoinMarket<'info> {
+pub struct ClaimRefund<'info> {
+    /// Cancelled market returning the entry fee
     #[account(
         mut,
         seeds = [
             b"market",
             market.factory.as_ref(),
-            market.match_id.as_bytes()
+            match_id_seed(&market.match_id).as_ref()
         ],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
     
+    /// The user's participant account
     #[account(
-        init,
-        payer = user,
-        space = Participant::LEN,
+        mut,
         seeds = [
             b"participant",
             market.key().as_ref(),
             user.key().as_ref()
         ],
-        bump
+        bump = participant.bump
     )]
     pub participant: Account<'info, Participant>,
     
+    /// CHECK: Yield position, whose staking yield is shared out by join time if the pool was staked
+    #[account(seeds = [b"yield_position", market.key().as_ref()], bump)]
+    pub yield_position: UncheckedAccount<'info>,
+    
+    /// Participant receiving the refund
     #[account(mut)]
     pub user: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts for `close_market`
 #[derive(Accounts)]
-pub struct ResolveMarket<'info> {
+pub struct CloseMarket<'info> {
+    /// Cancelled market with every entry fee refunded
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: Yield position, whose staking yield refunds paid out on top of the pool's equal split
+    #[account(seeds = [b"yield_position", market.key().as_ref()], bump)]
+    pub yield_position: UncheckedAccount<'info>,
+}
+
+/// Accounts for `compile_settlement_report`
+#[derive(Accounts)]
+pub struct CompileSettlementReport<'info> {
+    /// Swept or closed market
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: Player prop registry, whose winning player settled the market if the creator listed players
+    #[account(seeds = [b"prop_registry", market.key().as_ref()], bump)]
+    pub prop_registry: UncheckedAccount<'info>,
+    
+    /// CHECK: Correct-score grid, whose result settled the market if the creator enabled one
+    #[account(seeds = [b"score_grid", market.key().as_ref()], bump)]
+    pub score_grid: UncheckedAccount<'info>,
+    
+    /// Report, compiled once
+    #[account(
+        init,
+        payer = cranker,
+        space = SettlementReport::LEN,
+        seeds = [b"settlement_report", market.key().as_ref()],
+        bump
+    )]
+    pub settlement_report: Account<'info, SettlementReport>,
+    
+    /// Anyone compiling the report, paying its rent
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `emergency_void`
+#[derive(Accounts)]
+pub struct EmergencyVoid<'info> {
+    /// Market being voided
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Factory the market was created under, holding the void authority
+    #[account(
+        address = market.factory,
+        has_one = authority @ MarketError::UnauthorizedVoider
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Factory authority
+    pub authority: Signer<'info>,    
+    /// Platform-wide stats updated via CPI
+    #[account(
+        mut,
+        seeds = [b"platform_stats"],
+        bump = platform_stats.bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Daily activity of the market's factory, counted by the
+    /// dashboard program once initialized
+    #[account(
+        mut,
+        seeds = [b"platform_activity", market.factory.as_ref()],
+        bump,
+        seeds::program = dashboard_program.key()
+    )]
+    pub platform_activity: UncheckedAccount<'info>,
+    
+    /// Dashboard program receiving the stats CPI
+    pub dashboard_program: Program<'info, CryptoscoreDashboard>,
+    
+    /// CHECK: The instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: First-correct bonus, whose bonus set aside is refunded if the creator offered one
+    #[account(
+        mut,
+        seeds = [b"first_correct_bonus", market.key().as_ref()],
+        bump
+    )]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+}
+
+/// Accounts for `assert_solvency`
+#[derive(Accounts)]
+pub struct AssertSolvency<'info> {
+    /// Market whose vault is checked
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Accounts for `preview_settlement`
+#[derive(Accounts)]
+pub struct PreviewSettlement<'info> {
+    /// Market whose settlement is previewed
+    #[account(
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// CHECK: Charity pledge, read if the creator has made one
+    #[account(seeds = [b"charity_pledge", market.key().as_ref()], bump)]
+    pub charity_pledge: UncheckedAccount<'info>,
+    
+    /// CHECK: First-correct bonus, read if the creator has offered one
+    #[account(seeds = [b"first_correct_bonus", market.key().as_ref()], bump)]
+    pub first_correct_bonus: UncheckedAccount<'info>,
+    
+    /// CHECK: Factory's rake-free flag, read if the factory has set one
+    #[account(
+        seeds = [b"rake_free_market", market.key().as_ref()],
+        bump,
+        seeds::program = cryptoscore_factory::ID
+    )]
+    pub rake_free_market: UncheckedAccount<'info>,
+}
+
+/// Accounts for `migrate_market`
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    /// CHECK: Deserialized manually since the older layout doesn't match Market
+    #[account(mut, owner = crate::ID)]
+    pub market: UncheckedAccount<'info>,
+    
+    /// Pays for the extra space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `migrate_participant`
+#[derive(Accounts)]
+pub struct MigrateParticipant<'info> {
+    /// CHECK: Checked manually since the older layout doesn't match Participant
+    #[account(mut, owner = crate::ID)]
+    pub participant: UncheckedAccount<'info>,
+    
+    /// Pays for the extra space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `stake_idle_pool`
+#[derive(Accounts)]
+pub struct StakeIdlePool<'info> {
+    /// Market whose pool is staked
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            market.factory.as_ref(),
+            match_id_seed(&market.match_id).as_ref()
+        ],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// Factory the market was created under, holding the staking authority
+    #[account(
+        address = market.factory,
+        has_one = authority @ MarketError::UnauthorizedStaker
+    )]
+    pub factory: Account<'info, Factory>,
+    
+    /// Position recording what was staked, one per market
+    #[account(
+        init,
+        payer = authority,
+        space = YieldPosition::LEN,
+        seeds = [b"yield_position", market.key().as_ref()],
+        bump
+    )]
+    pub yield_position: Account<'info, YieldPosition>,
+    
+    /// CHECK: System account the pool passes through, signing the deposit as a PDA
+    #[account(
+        mut,
+        seeds = [b"yield_escrow", market.key().as_ref()],
+        bump
+    )]
+    pub yield_escrow: UncheckedAccount<'info>,
+    
+    /// CHECK: Token account for the pool tokens, created here and owned by the market
+    #[account(
+        mut,
+        seeds = [b"yield_tokens", market.key().as_ref()],
+        bump
+    )]
+    pub pool_tokens: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL stake pool receiving the deposit
+    #[account(mut, owner = STAKE_POOL_PROGRAM_ID @ MarketError::InvalidStakePool)]
+    pub stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    pub withdraw_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    #[account(mut)]
+    pub pool_mint: UncheckedAccount<'info>,
+    
+    /// Factory authority, paying for the position and token account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: SPL stake pool program
+    #[account(address = STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL token program
+    #[account(address = TOKEN_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `unwind_idle_pool`
+#[derive(Accounts)]
+pub struct UnwindIdlePool<'info> {
+    /// Market whose pool returns to its vault
     #[account(
         mut,
         seeds = [
             b"market",
             market.factory.as_ref(),
-            market.match_id.as_bytes()
+            match_id_seed(&market.match_id).as_ref()
         ],
         bump = market.bump
     )]
-    pub market: Account<'info, Market>,
-    
-    pub resolver: Signer<'info>,
+    pub market: Account<'info, Market>,
+    
+    /// Factory the market was created under
+    #[account(address = market.factory)]
+    pub factory: Account<'info, Factory>,
+    
+    /// Position being unwound
+    #[account(
+        mut,
+        seeds = [b"yield_position", market.key().as_ref()],
+        bump = yield_position.bump,
+        has_one = stake_pool @ MarketError::InvalidStakePool,
+        has_one = pool_mint @ MarketError::InvalidStakePool
+    )]
+    pub yield_position: Account<'info, YieldPosition>,
     
-    /// Market creator account for fee distribution
-    /// CHECK: This account is validated against market.creator
+    /// CHECK: The market's pool token account, read as it's burned
     #[account(
         mut,
-        constraint = creator.key() == market.creator @ MarketError::InvalidCreator
+        seeds = [b"yield_tokens", market.key().as_ref()],
+        bump
     )]
-    pub creator: AccountInfo<'info>,
+    pub pool_tokens: UncheckedAccount<'info>,
     
-    /// Platform account for fee distribution
-    /// CHECK: This is the platform's designated fee collection account
+    /// CHECK: Stake pool the position was opened in
     #[account(mut)]
-    pub platform: AccountInfo<'info>,
+    pub stake_pool: UncheckedAccount<'info>,
     
-    /// Optional participant account - if provided, validates resolver is a participant
-    #[account(
-        seeds = [
-            b"participant",
-            market.key().as_ref(),
-            resolver.key().as_ref()
-        ],
-        bump = participant.bump
-    )]
-    pub participant: Option<Account<'info, Participant>>,
+    /// CHECK: Checked against the stake pool's data
+    pub withdraw_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Checked against the stake pool's data and the position
+    #[account(mut)]
+    pub pool_mint: UncheckedAccount<'info>,
+    
+    /// Factory authority at any time, anyone once the match has ended
+    pub caller: Signer<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    /// CHECK: Stake history sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    
+    /// CHECK: Native stake program
+    #[account(address = anchor_lang::solana_program::stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL stake pool program
+    #[account(address = STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL token program
+    #[account(address = TOKEN_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
 }
 
+/// Accounts for `record_odds_sample`
 #[derive(Accounts)]
-pub struct WithdrawRewards<'info> {
+pub struct RecordOddsSample<'info> {
+    /// Market being sampled
     #[account(
-        mut,
         seeds = [
             b"market",
             market.factory.as_ref(),
-            market.match_id.as_bytes()
+            match_id_seed(&market.match_id).as_ref()
         ],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
     
+    /// Odds history, created by the first sample
     #[account(
-        mut,
-        seeds = [
-            b"participant",
-            market.key().as_ref(),
-            user.key().as_ref()
-        ],
-        bump = participant.bump
+        init_if_needed,
+        payer = cranker,
+        space = OddsHistory::LEN,
+        seeds = [b"odds_history", market.key().as_ref()],
+        bump
     )]
-    pub participant: Account<'info, Participant>,
+    pub odds_history: Box<Account<'info, OddsHistory>>,
     
+    /// Anyone recording the sample
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub cranker: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 // Events
 
+/// Emitted when a user joins a market
 #[event]
 pub struct PredictionMade {
+    /// Market joined
     #[index]
     pub market: Pubkey,
+    /// Joining user
     #[index]
     pub user: Pubkey,
+    /// User's prediction
     pub prediction: MatchOutcome,
+    /// Timestamp when joined
     pub timestamp: i64,
+    /// Position of the entry among the market's entries, from 0
+    pub entry_index: u32,
 }
 
+/// Emitted when a market's outcome is set
 #[event]
 pub struct MarketResolved {
+    /// Resolved market
     #[index]
     pub market: Pubkey,
+    /// Match outcome
     pub outcome: MatchOutcome,
+    /// Participants who predicted the outcome
     pub winner_count: u32,
+    /// Total pool in lamports before fees
     pub total_pool: u64,
 }
 
+/// Emitted when a market is resolved from the results signer's attestation,
+/// ahead of its `MarketResolved`
+#[event]
+pub struct ResultAttested {
+    /// Market being resolved
+    #[index]
+    pub market: Pubkey,
+    /// Results signer whose signature was verified
+    pub signer: Pubkey,
+    /// Attested outcome
+    pub outcome: MatchOutcome,
+    /// Time the signer attested the result
+    pub attested_at: i64,
+}
+
+/// Emitted when the results signer corrects a resolved market's result
+#[event]
+pub struct ResultCorrected {
+    /// Corrected market
+    #[index]
+    pub market: Pubkey,
+    /// Results signer who made the correction
+    pub signer: Pubkey,
+    /// Outcome the market resolved to before the correction
+    pub resolved_outcome: MatchOutcome,
+    /// Outcome of the corrected score
+    pub outcome: MatchOutcome,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Whether the market re-resolved to `outcome`; a changed outcome that
+    /// didn't was ruled mis-resolved for insurance claims instead
+    pub re_resolved: bool,
+}
+
+/// Emitted when a market is resolved from a Wormhole VAA, ahead of its
+/// `MarketResolved`
+#[event]
+pub struct ResultBridged {
+    /// Market being resolved
+    #[index]
+    pub market: Pubkey,
+    /// Wormhole chain ID of the oracle that published the result
+    pub emitter_chain: u16,
+    /// Oracle's sequence number for the message
+    pub sequence: u64,
+    /// Bridged outcome
+    pub outcome: MatchOutcome,
+    /// Time the oracle observed the result
+    pub attested_at: i64,
+}
+
+/// Emitted when a winner withdraws their reward
 #[event]
 pub struct RewardClaimed {
+    /// Market paying the reward
     #[index]
     pub market: Pubkey,
+    /// Winning user
     #[index]
     pub user: Pubkey,
+    /// Reward in lamports
     pub amount: u64,
 }
 
+/// Emitted when a winner rolls their reward into a follow-up market
+#[event]
+pub struct RewardRolled {
+    /// Market paying the reward
+    #[index]
+    pub market: Pubkey,
+    /// Follow-up market entered with it
+    #[index]
+    pub follow_up: Pubkey,
+    /// Winning user
+    pub user: Pubkey,
+    /// Reward in lamports
+    pub reward: u64,
+    /// Follow-up entry fee staked from the reward in lamports
+    pub entry_fee: u64,
+    /// Consecutive markets the user has rolled into
+    pub streak: u32,
+}
+
+/// Emitted when a winner takes their reward swapped into another token
+#[event]
+pub struct RewardSwapped {
+    /// Market paying the reward
+    #[index]
+    pub market: Pubkey,
+    /// Winning user
+    #[index]
+    pub user: Pubkey,
+    /// Reward in lamports, swapped from wrapped SOL
+    pub lamports: u64,
+    /// Token the reward was swapped into
+    pub output_mint: Pubkey,
+    /// Base units of `output_mint` received
+    pub amount_out: u64,
+}
+
+/// Emitted when a claim's compressed NFT receipt is minted
+#[event]
+pub struct ClaimReceiptMinted {
+    /// Market the claim was made on
+    #[index]
+    pub market: Pubkey,
+    /// Claiming user, owning the receipt
+    #[index]
+    pub user: Pubkey,
+    /// Tree the receipt was minted into
+    pub merkle_tree: Pubkey,
+    /// User's prediction
+    pub prediction: MatchOutcome,
+    /// Entry fee staked, in lamports
+    pub stake: u64,
+    /// Reward or refund withdrawn, in lamports
+    pub payout: u64,
+}
+
+/// Emitted when resolution pays out creator and platform fees
 #[event]
 pub struct FeesDistributed {
+    /// Resolved market
     #[index]
     pub market: Pubkey,
+    /// Market creator
     #[index]
     pub creator: Pubkey,
+    /// Creator fee in lamports, less any first-correct bonus
     pub creator_fee: u64,
+    /// Factory treasury receiving the platform fee
     #[index]
     pub platform: Pubkey,
+    /// Platform fee in lamports
     pub platform_fee: u64,
+    /// Sum of creator and platform fees, including any first-correct bonus
     pub total_fees: u64,
 }
 
+/// Emitted when a market's creator pledges its fees to a charity
+#[event]
+pub struct CharityPledged {
+    /// Charity market
+    #[index]
+    pub market: Pubkey,
+    /// Charity receiving the donation at resolution
+    #[index]
+    pub charity: Pubkey,
+    /// Share of the prize pool given on top of the fees, in basis points
+    pub pool_share_bps: u16,
+}
+
+/// Emitted when resolution gives a charity market's fees and pledged pool
+/// share to its charity, in place of `FeesDistributed`
+#[event]
+pub struct CharityDonated {
+    /// Resolved market
+    #[index]
+    pub market: Pubkey,
+    /// Charity receiving the donation
+    #[index]
+    pub charity: Pubkey,
+    /// Creator and platform fees given, in lamports
+    pub fees: u64,
+    /// Share of the prize pool given, in lamports
+    pub pool_share: u64,
+    /// Prize pool left for winners, in lamports
+    pub prize_pool: u64,
+}
+
+/// Emitted when a market's creator keeps it taking joins in play
+#[event]
+pub struct InPlayEnabled {
+    /// In-play market
+    #[index]
+    pub market: Pubkey,
+    /// Factory live score the lock is checked against
+    pub live_score: Pubkey,
+    /// Minute of play from which joins are refused
+    pub lock_minute: u16,
+    /// Whether the first goal also stops joins
+    pub lock_on_goal: bool,
+}
+
+/// Emitted when a market's creator makes it a player prop market
+#[event]
+pub struct PropPlayersListed {
+    /// Prop market
+    #[index]
+    pub market: Pubkey,
+    /// Player-level event the market settles on
+    pub kind: PropKind,
+    /// Oracle IDs of the players entries can pick
+    pub player_ids: Vec<u32>,
+}
+
+/// Emitted when a user picks a player on a prop market
+#[event]
+pub struct PropPicked {
+    /// Prop market
+    #[index]
+    pub market: Pubkey,
+    /// Entering user
+    #[index]
+    pub user: Pubkey,
+    /// Oracle ID of the picked player
+    pub player_id: u32,
+}
+
+/// Emitted when the results signer resolves a player prop market, in place
+/// of `MarketResolved`
+#[event]
+pub struct PropResolved {
+    /// Resolved prop market
+    #[index]
+    pub market: Pubkey,
+    /// Player-level event the market settled on
+    pub kind: PropKind,
+    /// Oracle ID of the reported player, or `None` if no listed player
+    pub player_id: Option<u32>,
+    /// Entries on the reported player
+    pub winner_count: u32,
+    /// Total pool in lamports
+    pub total_pool: u64,
+}
+
+/// Emitted when the results signer records a standings checkpoint on an
+/// outright market
+#[event]
+pub struct OutrightCheckpointed {
+    /// Outright market
+    #[index]
+    pub market: Pubkey,
+    /// Checkpoint number, from 1
+    pub checkpoint: u32,
+    /// Team IDs this checkpoint eliminated
+    pub eliminated: Vec<u32>,
+    /// Listed teams still able to win
+    pub remaining: u32,
+}
+
+/// Emitted when a market's creator makes it a correct-score market
+#[event]
+pub struct CorrectScoreEnabled {
+    /// Correct-score market
+    #[index]
+    pub market: Pubkey,
+}
+
+/// Emitted when a user picks a final score on a correct-score market
+#[event]
+pub struct ScorePicked {
+    /// Correct-score market
+    #[index]
+    pub market: Pubkey,
+    /// Entering user
+    #[index]
+    pub user: Pubkey,
+    /// Picked final score
+    pub scoreline: Scoreline,
+}
+
+/// Emitted when a correct-score market resolves, in place of
+/// `MarketResolved`
+#[event]
+pub struct CorrectScoreResolved {
+    /// Resolved correct-score market
+    #[index]
+    pub market: Pubkey,
+    /// Final home goals
+    pub home_goals: u8,
+    /// Final away goals
+    pub away_goals: u8,
+    /// Scoreline the final score settled on
+    pub scoreline: Scoreline,
+    /// Entries on the settled scoreline
+    pub winner_count: u32,
+    /// Total pool in lamports
+    pub total_pool: u64,
+}
+
+/// Emitted when a market's creator makes it a handicap market
+#[event]
+pub struct HandicapSet {
+    /// Handicap market
+    #[index]
+    pub market: Pubkey,
+    /// How a level result after the line settles
+    pub style: HandicapStyle,
+    /// Half goals added to the home side's score
+    pub home_line: i8,
+    /// Half goals added to the away side's score
+    pub away_line: i8,
+}
+
+/// Emitted when a handicap market's line is applied to the final score,
+/// before it settles or, on a push, is cancelled
+#[event]
+pub struct HandicapResolved {
+    /// Handicap market
+    #[index]
+    pub market: Pubkey,
+    /// Final home goals
+    pub home_goals: u8,
+    /// Final away goals
+    pub away_goals: u8,
+    /// Half goals added to the home side's score
+    pub home_line: i8,
+    /// Winning side after the line, or `None` for a push
+    pub outcome: Option<MatchOutcome>,
+}
+
+/// Emitted when unclaimed rewards are swept after the claim window
+#[event]
+pub struct UnclaimedSwept {
+    /// Swept market
+    #[index]
+    pub market: Pubkey,
+    /// Account receiving the swept funds
+    pub platform: Pubkey,
+    /// Swept amount in lamports
+    pub amount: u64,
+}
+
+/// Emitted when a market is called off
+#[event]
+pub struct MarketCancelled {
+    /// Cancelled market
+    #[index]
+    pub market: Pubkey,
+    /// Creator or factory authority that cancelled it
+    pub cancelled_by: Pubkey,
+    /// Lamports held for refunds
+    pub total_pool: u64,
+}
+
+/// Emitted when a participant reclaims their entry fee
+#[event]
+pub struct RefundClaimed {
+    /// Cancelled market
+    #[index]
+    pub market: Pubkey,
+    /// Refunded user
+    #[index]
+    pub user: Pubkey,
+    /// Refund in lamports
+    pub amount: u64,
+}
+
+/// Emitted when a market's vault holds less than it owes
+///
+/// Raised from the failing transaction, so it shows up in the logs of the
+/// rejected state change or of an `assert_solvency` simulation.
+#[event]
+pub struct SolvencyViolation {
+    /// Insolvent market
+    #[index]
+    pub market: Pubkey,
+    /// Lamports held above the rent-exempt minimum
+    pub available: u64,
+    /// Lamports owed for fees and payouts not yet made
+    pub liability: u64,
+}
+
+/// Emitted when a fully settled market's settlement report is compiled
+#[event]
+pub struct SettlementReportCompiled {
+    /// Market reported on
+    #[index]
+    pub market: Pubkey,
+    /// Entry fees pooled, in lamports
+    pub total_in: u64,
+    /// Fees or charity donation paid at resolution
+    pub fees_out: u64,
+    /// Winners' equal shares
+    pub rewards_out: u64,
+    /// Entry fees refunded
+    pub refunds_out: u64,
+    /// Remainder of the equal split
+    pub dust: u64,
+}
+
+/// Emitted when a cancelled market has refunded everyone
+#[event]
+pub struct MarketClosed {
+    /// Closed market
+    #[index]
+    pub market: Pubkey,
+}
+
+/// Emitted when a user escrows a conditional join
+#[event]
+pub struct JoinQueued {
+    /// Market to join
+    #[index]
+    pub market: Pubkey,
+    /// User joining
+    pub user: Pubkey,
+    /// Outcome to join on
+    pub prediction: MatchOutcome,
+    /// Smallest acceptable payout in basis points of the entry fee
+    pub min_payout_bps: u64,
+}
+
+/// Emitted when a queued join's escrow returns to its user
+#[event]
+pub struct QueuedJoinRefunded {
+    /// Market the join was queued for
+    #[index]
+    pub market: Pubkey,
+    /// User refunded
+    pub user: Pubkey,
+    /// Lamports returned, including the escrow's rent
+    pub amount: u64,
+}
+
+/// Emitted when a user enters a commit–reveal market with a hidden prediction
+#[event]
+pub struct PredictionCommitted {
+    /// Market entered
+    #[index]
+    pub market: Pubkey,
+    /// User who committed
+    pub user: Pubkey,
+    /// Commitment timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a market creator replaces the market's co-admins
+#[event]
+pub struct CoAdminsUpdated {
+    /// Market administered
+    #[index]
+    pub market: Pubkey,
+    /// New co-admins, with the default pubkey in unused slots
+    pub co_admins: [Pubkey; MAX_CO_ADMINS],
+}
+
+/// Emitted when a follower joins a market by copying a leader
+#[event]
+pub struct CopyJoined {
+    /// Market joined
+    #[index]
+    pub market: Pubkey,
+    /// User who copied the prediction
+    pub follower: Pubkey,
+    /// User credited with it
+    pub leader: Pubkey,
+    /// Prediction copied
+    pub prediction: MatchOutcome,
+    /// Entry fee the follower paid
+    pub amount: u64,
+}
+
+/// Emitted when one of a high-stakes market's resolvers signs an outcome
+#[event]
+pub struct ResolutionSigned {
+    /// Market being resolved
+    #[index]
+    pub market: Pubkey,
+    /// Creator, results signer or arbiter
+    pub signer: Pubkey,
+    /// Outcome signed
+    pub outcome: MatchOutcome,
+}
+
+/// Emitted when a second signature resolves a high-stakes market
+#[event]
+pub struct ResolutionCoSigned {
+    /// Market resolved
+    #[index]
+    pub market: Pubkey,
+    /// Outcome both signed
+    pub outcome: MatchOutcome,
+    /// First signer, then the co-signer who settled it
+    pub signers: [Pubkey; 2],
+}
+
+/// Emitted when a market's pool is staked for the match
+#[event]
+pub struct PoolStaked {
+    /// Market whose pool was staked
+    #[index]
+    pub market: Pubkey,
+    /// Stake pool holding it
+    pub stake_pool: Pubkey,
+    /// Lamports staked
+    pub lamports: u64,
+    /// Pool tokens received
+    pub pool_tokens: u64,
+}
+
+/// Emitted when a staked pool returns to the market vault
+#[event]
+pub struct PoolUnwound {
+    /// Market whose pool was unwound
+    #[index]
+    pub market: Pubkey,
+    /// Lamports staked
+    pub staked: u64,
+    /// Lamports returned, the pool's new size
+    pub returned: u64,
+}
+
+/// Emitted when every roster entry of a resolved market is folded into its
+/// winners root
+#[event]
+pub struct WinnersRootPublished {
+    /// Resolved market
+    #[index]
+    pub market: Pubkey,
+    /// Merkle root over the winners' `winner_leaf`s, in roster order
+    pub root: [u8; 32],
+    /// Winners under the root
+    pub winners: u32,
+    /// Reward each winner is owed, in lamports
+    pub reward: u64,
+}
+
 // Error Codes
 
 #[error_code]
@@ -626,10 +9791,223 @@ pub enum MarketError {
     NotAWinner,
     #[msg("No winners in this market")]
     NoWinners,
+    /// No longer raised; superseded by the specific calculation errors below
     #[msg("Calculation error")]
     CalculationError,
     #[msg("Insufficient funds in market")]
     InsufficientFunds,
     #[msg("Invalid creator account")]
     InvalidCreator,
+    #[msg("Claim window has not closed yet")]
+    ClaimWindowOpen,
+    #[msg("No unclaimed funds to sweep")]
+    NothingToSweep,
+    #[msg("Only the factory authority can sweep unclaimed funds")]
+    UnauthorizedSweeper,
+    #[msg("Fee calculation overflowed for this pool")]
+    FeeOverflow,
+    #[msg("Reward calculation failed for this pool and winner count")]
+    RewardCalculationFailed,
+    #[msg("Recipient balance would overflow")]
+    BalanceOverflow,
+    #[msg("Claim deadline is out of range")]
+    InvalidClaimDeadline,
+    #[msg("Lamports can only be moved out of the market's own vault")]
+    InvalidVault,
+    #[msg("The same account was passed for two roles that must differ")]
+    DuplicateAccount,
+    #[msg("Transfer would leave the market below rent exemption")]
+    BelowRentExemption,
+    #[msg("Market cannot move to that status from its current one")]
+    InvalidStatusTransition,
+    #[msg("Market is settled; claims are closed")]
+    ClaimsClosed,
+    #[msg("Only the creator or a co-admin before kickoff, or the factory authority, can cancel")]
+    UnauthorizedCanceller,
+    #[msg("Market is not cancelled")]
+    MarketNotCancelled,
+    #[msg("Entry fees are still waiting to be refunded")]
+    RefundsOutstanding,
+    #[msg("Creator and platform fee accounts must differ")]
+    CreatorIsPlatform,
+    #[msg("Market vault holds less than the market owes")]
+    InsolventMarket,
+    #[msg("Total paid out overflowed")]
+    PayoutOverflow,
+    #[msg("Account is not a market of this program")]
+    InvalidMarketAccount,
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("Only the factory authority can void markets")]
+    UnauthorizedVoider,
+    #[msg("Expected an ed25519 signature check right before this instruction")]
+    MissingAttestation,
+    #[msg("Result attestation does not match the results signer, market, outcome or time")]
+    InvalidAttestation,
+    #[msg("Market creator cannot attest its own result")]
+    CreatorIsResultsSigner,
+    #[msg("Only the factory authority can stake a market pool")]
+    UnauthorizedStaker,
+    #[msg("Pools can only be staked between kickoff and the end of the match")]
+    StakingWindowClosed,
+    #[msg("Market pool is empty")]
+    NothingToStake,
+    #[msg("Account does not belong to the stake pool")]
+    InvalidStakePool,
+    #[msg("Stake pool returned less than the slippage bound allows")]
+    StakeSlippageExceeded,
+    #[msg("Market pool is not staked")]
+    PoolNotStaked,
+    #[msg("Only the factory authority can unwind before the match ends")]
+    UnauthorizedUnwind,
+    #[msg("Market pool is large enough that two resolvers must co-sign")]
+    CoSignersRequired,
+    #[msg("Market pool is below the co-signed resolution threshold")]
+    CoSignersNotRequired,
+    #[msg("No other resolver has signed this outcome")]
+    MissingCoSignature,
+    #[msg("Minimum payout must be non-zero")]
+    InvalidPayoutCondition,
+    #[msg("Joins can only be queued until shortly before kickoff")]
+    JoinQueueClosed,
+    #[msg("Queued joins execute from the queue deadline until kickoff")]
+    QueuedJoinNotDue,
+    #[msg("Joining would pay less than the queued minimum")]
+    PayoutConditionNotMet,
+    #[msg("Only the user can withdraw a queued join that may still execute")]
+    QueuedJoinPending,
+    #[msg("Odds were sampled too recently")]
+    OddsSampleTooSoon,
+    #[msg("Users cannot follow themselves")]
+    CannotFollowSelf,
+    #[msg("This market only takes committed predictions")]
+    CommitRevealRequired,
+    #[msg("Only private markets can hide predictions until kickoff")]
+    CommitRevealPrivateOnly,
+    #[msg("Market already has entries")]
+    MarketHasEntries,
+    #[msg("Predictions are revealed between kickoff and the end of the match")]
+    RevealWindowClosed,
+    #[msg("Prediction and salt don't match the commitment")]
+    InvalidReveal,
+    #[msg("Co-admins must be distinct and can't include the creator")]
+    InvalidCoAdmin,
+    #[msg("Only the market creator or a co-admin can do this")]
+    NotMarketAdmin,
+    #[msg("Pass at least one market's resolution accounts")]
+    EmptyResolutionBatch,
+    #[msg("Results batch has no result for this market")]
+    ResultNotInBatch,
+    #[msg("Results batch was published by a results signer that has since been replaced")]
+    StaleResultsBatch,
+    #[msg("Factory did not report the rebate it paid")]
+    MissingRebateAmount,
+    #[msg("Account is not a posted VAA carrying a valid result for this market")]
+    InvalidVaa,
+    #[msg("VAA was not emitted by the factory's registered oracle")]
+    UnknownVaaEmitter,
+    #[msg("VAA reports a different match")]
+    VaaMatchMismatch,
+    #[msg("Token gates need a mint and a positive minimum balance, or a collection")]
+    InvalidTokenGate,
+    #[msg("Joiner's token account and metadata don't show the holdings this market is gated on")]
+    TokenGateNotMet,
+    #[msg("Token-gated markets only take direct and copied joins")]
+    TokenGated,
+    #[msg("Participant roster or roster page doesn't match the market's next roster slot")]
+    InvalidRosterAccount,
+    #[msg("Winners root has already been published")]
+    WinnersRootPublished,
+    #[msg("Participant accounts don't match the next entries of the roster page")]
+    RosterParticipantMismatch,
+    #[msg("User has already joined this market on a different prediction")]
+    AlreadyJoined,
+    #[msg("User can't cover the entry fee and participant account rent")]
+    InsufficientBalance,
+    #[msg("Charity can't be unset, the market or one of its admins, and can get at most half the prize pool")]
+    InvalidCharity,
+    #[msg("Charity account doesn't match the market's charity pledge")]
+    CharityMismatch,
+    #[msg("Match's live score hasn't been marked final since the market ended")]
+    LiveScoreNotFinal,
+    #[msg("Live score was reported by a results signer that has since been replaced")]
+    StaleLiveScore,
+    #[msg("In-play lock minute must be between 1 and the latest lockable minute")]
+    InvalidInPlayLock,
+    #[msg("Live score account isn't the one the market's in-play lock follows")]
+    InvalidLiveScore,
+    #[msg("In-play market stopped taking joins at its lock minute, a goal or the final whistle")]
+    InPlayLocked,
+    #[msg("Prop markets list between one and the maximum number of players, each once")]
+    InvalidPropPlayers,
+    #[msg("Player prop markets only take player picks and resolve by player ID")]
+    PropMarket,
+    #[msg("Market doesn't list players")]
+    NotAPropMarket,
+    #[msg("Player isn't listed on this prop market")]
+    PropPlayerNotListed,
+    #[msg("Only the factory's results signer can resolve player props")]
+    NotResultsSigner,
+    #[msg("Exact scorelines must be on the correct-score grid; pick any other score for the rest")]
+    InvalidScoreline,
+    #[msg("Correct-score markets only take scoreline picks and resolve from the final score")]
+    CorrectScoreMarket,
+    #[msg("Market doesn't take correct-score picks")]
+    NotACorrectScoreMarket,
+    #[msg("Handicap lines must mirror each other within the limit, in whole goals on European markets")]
+    InvalidHandicapLine,
+    #[msg("Handicap markets resolve from the final score with their line applied")]
+    HandicapMarket,
+    #[msg("Market has no handicap line")]
+    NotAHandicapMarket,
+    #[msg("Market is not an outright market")]
+    NotAnOutrightMarket,
+    #[msg("An outright checkpoint must leave at least one team able to win")]
+    InvalidOutrightCheckpoint,
+    #[msg("Outright market still has more than one team able to win")]
+    OutrightUndecided,
+    #[msg("Market doesn't offer this outcome, e.g. a draw in a sport without draws")]
+    OutcomeNotOffered,
+    #[msg("Factory doesn't let payouts be swapped into this token")]
+    PayoutMintNotAllowed,
+    #[msg("Swapped payouts need the user's wrapped SOL account and an output token account they own")]
+    InvalidSwapAccount,
+    #[msg("Swap returned less than the minimum amount out")]
+    SwapSlippageExceeded,
+    #[msg("Market's factory doesn't mint claim receipts")]
+    ReceiptsDisabled,
+    #[msg("Receipts can only be minted for a withdrawn reward or refund")]
+    ClaimNotWithdrawn,
+    #[msg("Market isn't fully settled: it must be swept, or closed after refunds")]
+    MarketNotSettled,
+    #[msg("Resolution grace period is outside the factory's bounds")]
+    InvalidResolutionGrace,
+    #[msg("Only the creator can resolve the market during its resolution grace period")]
+    ResolutionGracePeriod,
+    #[msg("Account is not a participant of this program")]
+    InvalidParticipantAccount,
+    #[msg("Factory is not the one the market was created under")]
+    FactoryMismatch,
+    #[msg("Match ID contains characters the factory doesn't allow")]
+    InvalidMatchIdCharacters,
+    #[msg("First-correct bonus must be between 1 and 10000 bps of the creator fee")]
+    InvalidFirstCorrectBonus,
+    #[msg("The window for correcting this market's result has closed")]
+    CorrectionWindowClosed,
+    #[msg("The corrected result is the one the market already has")]
+    ResultUnchanged,
+    #[msg("Follow-up must be another market of the same creator and factory, kicking off after this one ends")]
+    InvalidFollowUpMarket,
+    #[msg("Market is not the follow-up this market's rewards roll into")]
+    NotFollowUpMarket,
+    #[msg("Reward is less than the follow-up market's entry fee")]
+    RewardBelowEntryFee,
+    #[msg("A voucher needs both the voucher and its campaign")]
+    IncompleteVoucher,
+    #[msg("Market has queued joins waiting to execute")]
+    MarketHasQueuedJoins,
+    #[msg("Lamports can only be moved out of the market's own first-correct bonus account")]
+    InvalidBonusAccount,
+    #[msg("Market result can still be corrected")]
+    CorrectionWindowOpen,
 }
\ No newline at end of file
@@ -82,6 +82,29 @@ for program in "${PROGRAMS[@]}"; do
         ALL_SYNCED=false
     fi
     
+    # Check every instruction, instruction account and type carries docs
+    UNDOCUMENTED=$(python3 - "$BUILD_FILE" <<'EOF_PY'
+import json, sys
+idl = json.load(open(sys.argv[1]))
+missing = []
+for ix in idl.get("instructions", []):
+    if not ix.get("docs"):
+        missing.append(ix["name"])
+    for account in ix.get("accounts", []):
+        if not account.get("docs") and account["name"] != "system_program":
+            missing.append(ix["name"] + "." + account["name"])
+for ty in idl.get("types", []):
+    if not ty.get("docs"):
+        missing.append(ty["name"])
+print(" ".join(missing))
+EOF_PY
+)
+    if [ -z "$UNDOCUMENTED" ]; then
+        echo -e "   ${GREEN}✅ Documented${NC}"
+    else
+        echo -e "   ${YELLOW}⚠️  Missing docs: $UNDOCUMENTED${NC}"
+    fi
+    
     echo ""
 done
 
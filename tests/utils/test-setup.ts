@@ -93,6 +93,7 @@ export async function initializeFactory(
     // Check if factory already exists
     await context.factoryProgram.account.factory.fetch(context.factoryPda);
     console.log("Factory already initialized");
+    await initializePlatformStats(context);
     return;
   } catch (error) {
     // Factory doesn't exist, initialize it
@@ -109,6 +110,34 @@ export async function initializeFactory(
     .rpc();
 
   console.log("Factory initialized successfully");
+
+  await initializePlatformStats(context);
+}
+
+/**
+ * Initialize the dashboard platform stats account if not already initialized
+ */
+export async function initializePlatformStats(context: TestContext): Promise<void> {
+  const [platformStatsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_stats")],
+    context.dashboardProgram.programId
+  );
+
+  try {
+    await context.dashboardProgram.account.platformStats.fetch(platformStatsPda);
+    return;
+  } catch (error) {
+    // Platform stats don't exist, initialize them
+  }
+
+  await context.dashboardProgram.methods
+    .initializePlatformStats()
+    .accounts({
+      platformStats: platformStatsPda,
+      payer: context.authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
 }
 
 /**
@@ -361,25 +390,54 @@ export function getUserStatsPda(
 }
 
 /**
- * Update user stats for dashboard testing
+ * Create a past market, join it as the user and resolve it, leaving an
+ * entry ready to be recorded in the user's stats
+ */
+export async function settleEntry(
+  context: TestContext,
+  user: TestUser,
+  matchId: string,
+  prediction: { home: {} } | { draw: {} } | { away: {} },
+  outcome: { home: {} } | { draw: {} } | { away: {} }
+): Promise<TestMarket> {
+  const market = await createPastMarket(context, { matchId });
+  await joinMarket(context, market, user, prediction);
+  await resolveMarket(context, market, outcome);
+
+  return market;
+}
+
+/**
+ * Record a user's entry in a settled market in their stats, cranked by the
+ * authority
  */
 export async function updateUserStats(
   context: TestContext,
   user: TestUser,
-  marketResult: { win: {} } | { loss: {} },
-  amountWagered: BN,
-  amountWon: BN
+  market: PublicKey
 ): Promise<void> {
   const userStatsPda = getUserStatsPda(context.dashboardProgram, user.publicKey);
+  const [statsEntryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stats_entry"), market.toBuffer(), user.publicKey.toBuffer()],
+    context.dashboardProgram.programId
+  );
+  const [participantPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("participant"), market.toBuffer(), user.publicKey.toBuffer()],
+    context.marketProgram.programId
+  );
 
   await context.dashboardProgram.methods
-    .updateUserStats(marketResult, amountWagered, amountWon)
+    .updateUserStats()
     .accounts({
       userStats: userStatsPda,
+      statsEntry: statsEntryPda,
+      factory: context.factoryPda,
+      market,
+      participant: participantPda,
       user: user.publicKey,
+      payer: context.authority.publicKey,
       systemProgram: SystemProgram.programId,
     })
-    .signers([user.keypair])
     .rpc();
 }
 
@@ -398,6 +456,7 @@ export const PREDICTIONS = {
 export const MARKET_RESULTS = {
   WIN: { win: {} },
   LOSS: { loss: {} },
+  VOID: { void: {} },
 } as const;
 
 /**
@@ -0,0 +1,142 @@
+//! Fuzzes arbitrary join, resolve and withdraw sequences against a model of the
+//! market vault, applying the program's checks and the shared fee math.
+//!
+//! Panics (and so reports a crash) if any sequence pays more than the pool,
+//! pays a participant twice, overdraws the vault or leaves more dust than
+//! there are winners.
+
+use arbitrary::Arbitrary;
+use cryptoscore_common::fees::{market_fees, prize_pool_after_fees, reward_per_winner};
+use cryptoscore_common::MatchOutcome;
+use honggfuzz::fuzz;
+
+/// Rent-exempt minimum of a market account, which stays in the vault
+const RENT: u64 = 2_500_000;
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Outcome {
+    Home,
+    Draw,
+    Away,
+}
+
+impl From<Outcome> for MatchOutcome {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Home => MatchOutcome::Home,
+            Outcome::Draw => MatchOutcome::Draw,
+            Outcome::Away => MatchOutcome::Away,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Action {
+    Join(Outcome),
+    Resolve(Outcome),
+    Withdraw(u16),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Scenario {
+    entry_fee: u64,
+    actions: Vec<Action>,
+}
+
+struct Participant {
+    prediction: MatchOutcome,
+    has_withdrawn: bool,
+}
+
+#[derive(Default)]
+struct Market {
+    vault: u64,
+    total_pool: u64,
+    outcome: Option<MatchOutcome>,
+    participants: Vec<Participant>,
+    paid: u64,
+}
+
+impl Market {
+    fn winner_count(&self, outcome: &MatchOutcome) -> u32 {
+        self.participants.iter().filter(|p| p.prediction == *outcome).count() as u32
+    }
+
+    fn join(&mut self, entry_fee: u64, prediction: MatchOutcome) -> Option<()> {
+        if self.outcome.is_some() {
+            return None;
+        }
+        self.total_pool = self.total_pool.checked_add(entry_fee)?;
+        self.vault = self.vault.checked_add(entry_fee)?;
+        self.participants.push(Participant { prediction, has_withdrawn: false });
+        Some(())
+    }
+
+    fn resolve(&mut self, outcome: MatchOutcome) -> Option<()> {
+        if self.outcome.is_some() {
+            return None;
+        }
+        let fees = market_fees(self.total_pool)?.total()?;
+        self.vault = self.vault.checked_sub(fees).expect("fees overdraw the vault");
+        self.outcome = Some(outcome);
+        Some(())
+    }
+
+    fn withdraw(&mut self, index: usize) -> Option<()> {
+        let outcome = self.outcome.clone()?;
+        let winner_count = self.winner_count(&outcome);
+        let participant = self.participants.get(index)?;
+        if participant.has_withdrawn || participant.prediction != outcome || winner_count == 0 {
+            return None;
+        }
+
+        let reward = reward_per_winner(self.total_pool, winner_count)?;
+        self.vault = self.vault.checked_sub(reward).expect("withdrawal overdraws the vault");
+        self.paid += reward;
+        self.participants[index].has_withdrawn = true;
+        Some(())
+    }
+}
+
+fn run(scenario: Scenario) {
+    let entry_fee = scenario.entry_fee.max(1);
+    let mut market = Market { vault: RENT, ..Market::default() };
+
+    for action in scenario.actions {
+        // Rejected actions leave the market untouched, as a failed transaction would
+        let _ = match action {
+            Action::Join(outcome) => market.join(entry_fee, outcome.into()),
+            Action::Resolve(outcome) => market.resolve(outcome.into()),
+            Action::Withdraw(index) => market.withdraw(index as usize),
+        };
+    }
+
+    let Some(outcome) = market.outcome.clone() else {
+        return;
+    };
+    let Some(prize) = prize_pool_after_fees(market.total_pool) else {
+        return;
+    };
+    assert!(market.paid <= prize, "paid {} of a {} prize pool", market.paid, prize);
+    assert!(market.vault >= RENT, "vault dropped below rent");
+
+    let winner_count = market.winner_count(&outcome);
+    let all_claimed = market
+        .participants
+        .iter()
+        .filter(|p| p.prediction == outcome)
+        .all(|p| p.has_withdrawn);
+    if winner_count > 0 && all_claimed {
+        assert!(market.vault - RENT < winner_count as u64, "dust exceeds winner count");
+    }
+}
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            if let Ok(scenario) = Scenario::arbitrary(&mut arbitrary::Unstructured::new(data)) {
+                run(scenario);
+            }
+        });
+    }
+}